@@ -45,6 +45,9 @@ use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, marker::PhantomData, path::PathBuf};
 
+// (start, size) of the signal arrays for every template
+type SignalArrays = HashMap<String, Vec<(usize, usize)>>;
+
 /// The simplification level applied during constraint generation
 #[derive(
     Debug, Default, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord, Hash,
@@ -182,10 +185,36 @@ where
         output_mappings
     }
 
+    // Circom keeps the sizes of the signal arrays only in the vcp, so we collect them
+    // per template before the vcp is consumed. The offsets are relative to the first
+    // signal of the component.
+    fn get_signal_arrays(&self, vcp: &VCP) -> SignalArrays {
+        vcp.templates
+            .iter()
+            .map(|templ| {
+                let signals = templ
+                    .wires
+                    .iter()
+                    .filter_map(|wire| match wire {
+                        Wire::TSignal(s) => Some((s.dag_local_id, s.size)),
+                        _ => None,
+                    })
+                    .collect_vec();
+                let first = signals.iter().map(|(id, _)| *id).min().unwrap_or_default();
+                let arrays = signals
+                    .into_iter()
+                    .filter(|(_, size)| *size > 1)
+                    .map(|(id, size)| (id - first, size))
+                    .collect();
+                (templ.header.clone(), arrays)
+            })
+            .collect()
+    }
+
     fn build_circuit(
         &self,
         program_archive: ProgramArchive,
-    ) -> Result<(CircomCircuit, OutputMapping)> {
+    ) -> Result<(CircomCircuit, OutputMapping, SignalArrays)> {
         let build_config = BuildConfig {
             no_rounds: if let SimplificationLevel::O2(r) = self.config.simplification {
                 r
@@ -205,6 +234,7 @@ where
         let (_, vcp) = circom_constraint_generation::build_circuit(program_archive, build_config)
             .map_err(|_| eyre!("cannot build vcp"))?;
         let output_mapping = self.get_output_mapping(&vcp);
+        let signal_arrays = self.get_signal_arrays(&vcp);
 
         let flags = CompilationFlags {
             main_inputs_log: false,
@@ -213,6 +243,7 @@ where
         Ok((
             CircomCircuit::build(vcp, flags, &self.config.version),
             output_mapping,
+            signal_arrays,
         ))
     }
 
@@ -335,8 +366,8 @@ where
             OperatorType::BitAnd => self.emit_opcode(MpcOpCode::BitAnd),
             OperatorType::BitXor => self.emit_opcode(MpcOpCode::BitXOr),
             OperatorType::PrefixSub => self.emit_opcode(MpcOpCode::Neg),
-            OperatorType::BoolNot => todo!(),
-            OperatorType::Complement => todo!(),
+            OperatorType::BoolNot => self.emit_opcode(MpcOpCode::BoolNot),
+            OperatorType::Complement => self.emit_opcode(MpcOpCode::BitNot),
             OperatorType::ToAddress => {
                 self.emit_opcode(MpcOpCode::ToIndex);
             }
@@ -505,53 +536,15 @@ where
                 }
             });
         match &call_bucket.return_info {
-            ReturnType::Intermediate { op_aux_no: _ } => todo!(),
+            ReturnType::Intermediate { op_aux_no: _ } => {
+                // the result is used within an expression, therefore we
+                // leave the single return value on the field stack
+                self.emit_opcode(MpcOpCode::Call(call_bucket.symbol.clone(), 1));
+            }
             ReturnType::Final(final_data) => {
-                if get_size_from_size_option(&final_data.context.size) == 1 {
-                    self.emit_opcode(MpcOpCode::Call(call_bucket.symbol.clone(), 1));
-                    self.emit_store_opcodes(&final_data.dest, &final_data.dest_address_type, 1);
-                } else {
-                    match &final_data.dest {
-                        LocationRule::Indexed {
-                            location,
-                            template_header: _,
-                        } => {
-                            let inner: &Instruction = location;
-                            if let Instruction::Value(value_bucket) = inner {
-                                self.emit_opcode(MpcOpCode::Call(
-                                    call_bucket.symbol.clone(),
-                                    get_size_from_size_option(&final_data.context.size),
-                                ));
-                                debug_assert!(matches!(value_bucket.parse_as, ValueType::U32));
-                                self.emit_opcode(MpcOpCode::PushIndex(value_bucket.value));
-                            } else {
-                                panic!("Another way for multiple return vals???");
-                            }
-                        }
-                        LocationRule::Mapped {
-                            signal_code: _,
-                            indexes: _,
-                        } => {
-                            todo!();
-                        }
-                    };
-                    match &final_data.dest_address_type {
-                        AddressType::Variable => self.emit_opcode(MpcOpCode::StoreVars(
-                            get_size_from_size_option(&final_data.context.size),
-                        )),
-                        AddressType::Signal => self.emit_opcode(MpcOpCode::StoreSignals(
-                            get_size_from_size_option(&final_data.context.size),
-                        )),
-                        AddressType::SubcmpSignal {
-                            cmp_address: _,
-                            uniform_parallel_value: _,
-                            is_output: _,
-                            input_information: _,
-                        } => {
-                            todo!()
-                        }
-                    }
-                }
+                let size = get_size_from_size_option(&final_data.context.size);
+                self.emit_opcode(MpcOpCode::Call(call_bucket.symbol.clone(), size));
+                self.emit_store_opcodes(&final_data.dest, &final_data.dest_address_type, size);
             }
         }
     }
@@ -652,7 +645,7 @@ where
     fn parse_inner(mut self) -> Result<CoCircomCompilerParsed<P::ScalarField>> {
         tracing::debug!("compiler starts parsing..");
         let program_archive = self.get_program_archive()?;
-        let (circuit, output_mapping) = self.build_circuit(program_archive)?;
+        let (circuit, output_mapping, mut signal_arrays) = self.build_circuit(program_archive)?;
         tracing::debug!("output mapping: {output_mapping:?}");
         let constant_table = circuit
             .c_producer
@@ -679,11 +672,28 @@ where
                 .iter()
                 .map(|p| p.length.iter().product::<usize>())
                 .sum::<usize>();
+            //the params are stored one after another at the start of the vars
+            let var_arrays = fun
+                .params
+                .iter()
+                .scan(0, |start, p| {
+                    let len = p.length.iter().product::<usize>();
+                    *start += len;
+                    Some((*start - len, len))
+                })
+                .filter(|(_, len)| *len > 1)
+                .collect_vec();
+            let var_arrays = collect_var_arrays(&fun.body, var_arrays, fun.max_number_of_vars);
             tracing::debug!("# params: {}", params_length);
             tracing::debug!("function has {} opcodes", new_code_block.len());
             self.fun_decls.insert(
                 fun.header.clone(),
-                FunDecl::new(params_length, fun.max_number_of_vars, new_code_block),
+                FunDecl::new(
+                    params_length,
+                    fun.max_number_of_vars,
+                    var_arrays,
+                    new_code_block,
+                ),
             );
         }
         for templ in circuit.templates.iter() {
@@ -701,13 +711,19 @@ where
             } else {
                 vec![]
             };
+            let var_arrays = collect_var_arrays(&templ.body, vec![], templ.var_stack_depth);
             self.templ_decls.insert(
                 templ.header.clone(),
                 TemplateDecl::new(
                     templ.header.clone(),
                     templ.number_of_inputs,
+                    templ.number_of_inputs
+                        + templ.number_of_outputs
+                        + templ.number_of_intermediates,
                     templ.number_of_components,
                     templ.var_stack_depth,
+                    var_arrays,
+                    signal_arrays.remove(&templ.header).unwrap_or_default(),
                     mappings,
                     new_code_block,
                 ),
@@ -748,6 +764,184 @@ fn get_size_from_size_option(size_option: &SizeOption) -> usize {
     }
 }
 
+// Circom does not keep the var declarations in its IR, only the addresses at which the vars are
+// accessed. An access with a computed offset marks the start of an array, which ends at the next
+// address that is accessed on its own (e.g., the next var). Accesses of more than one var at a
+// constant address (e.g., initializing an array or assigning the result of a function) and the
+// given arrays (e.g., the params of a function) are exact. As a constant access of a single
+// element also ends an array, shared indices beyond it fail the bounds check instead of touching
+// other vars.
+fn collect_var_arrays(
+    instr_list: &InstructionList,
+    mut arrays: Vec<(usize, usize)>,
+    vars: usize,
+) -> Vec<(usize, usize)> {
+    let mut indexed = vec![];
+    let mut constant = vec![];
+    instr_list
+        .iter()
+        .for_each(|inst| collect_var_accesses(inst, &mut indexed, &mut constant));
+    arrays.extend(constant.iter().copied().filter(|(_, size)| *size > 1));
+    let bounds = constant
+        .iter()
+        .chain(arrays.iter())
+        .map(|(start, _)| *start)
+        .chain(indexed.iter().copied())
+        .sorted()
+        .dedup()
+        .collect_vec();
+    let exact = arrays.clone();
+    for start in indexed.into_iter().sorted().dedup() {
+        if exact
+            .iter()
+            .any(|(array, size)| (*array..array + size).contains(&start))
+        {
+            continue;
+        }
+        let end = bounds
+            .iter()
+            .copied()
+            .find(|bound| *bound > start)
+            .unwrap_or(vars)
+            .min(vars);
+        if end > start {
+            arrays.push((start, end - start));
+        }
+    }
+    arrays.into_iter().sorted().dedup().collect()
+}
+
+// Collects the start of every var access with a computed offset and the (address, size) of every
+// var access at a constant address.
+fn collect_var_accesses(
+    inst: &Instruction,
+    indexed: &mut Vec<usize>,
+    constant: &mut Vec<(usize, usize)>,
+) {
+    fn add_access(
+        location: &LocationRule,
+        address_type: &AddressType,
+        size: &SizeOption,
+        indexed: &mut Vec<usize>,
+        constant: &mut Vec<(usize, usize)>,
+    ) {
+        match location {
+            LocationRule::Indexed {
+                location,
+                template_header: _,
+            } => {
+                if let AddressType::Variable = address_type {
+                    match &**location {
+                        Instruction::Value(value_bucket) => {
+                            constant.push((value_bucket.value, get_size_from_size_option(size)))
+                        }
+                        Instruction::Compute(compute_bucket) => {
+                            //the start of the array is the constant summand of the address
+                            let start = match compute_bucket.op {
+                                OperatorType::AddAddress => compute_bucket
+                                    .stack
+                                    .iter()
+                                    .find_map(|inst| match &**inst {
+                                        Instruction::Value(value_bucket) => {
+                                            Some(value_bucket.value)
+                                        }
+                                        _ => None,
+                                    })
+                                    .unwrap_or_default(),
+                                _ => 0,
+                            };
+                            indexed.push(start);
+                        }
+                        _ => {}
+                    }
+                }
+                collect_var_accesses(location, indexed, constant);
+            }
+            LocationRule::Mapped {
+                signal_code: _,
+                indexes,
+            } => {
+                for access in indexes.iter() {
+                    if let AccessType::Indexed(indexed_info) = access {
+                        for inst in indexed_info.indexes.iter() {
+                            collect_var_accesses(inst, indexed, constant);
+                        }
+                    }
+                }
+            }
+        }
+        if let AddressType::SubcmpSignal { cmp_address, .. } = address_type {
+            collect_var_accesses(cmp_address, indexed, constant);
+        }
+    }
+    fn collect_all(
+        instr_list: &InstructionList,
+        indexed: &mut Vec<usize>,
+        constant: &mut Vec<(usize, usize)>,
+    ) {
+        for inst in instr_list.iter() {
+            collect_var_accesses(inst, indexed, constant);
+        }
+    }
+    match inst {
+        Instruction::Value(_) | Instruction::CreateCmp(_) => {}
+        Instruction::Load(load_bucket) => add_access(
+            &load_bucket.src,
+            &load_bucket.address_type,
+            &load_bucket.context.size,
+            indexed,
+            constant,
+        ),
+        Instruction::Store(store_bucket) => {
+            collect_var_accesses(&store_bucket.src, indexed, constant);
+            add_access(
+                &store_bucket.dest,
+                &store_bucket.dest_address_type,
+                &store_bucket.context.size,
+                indexed,
+                constant,
+            );
+        }
+        Instruction::Compute(compute_bucket) => {
+            collect_all(&compute_bucket.stack, indexed, constant)
+        }
+        Instruction::Call(call_bucket) => {
+            collect_all(&call_bucket.arguments, indexed, constant);
+            if let ReturnType::Final(final_data) = &call_bucket.return_info {
+                add_access(
+                    &final_data.dest,
+                    &final_data.dest_address_type,
+                    &final_data.context.size,
+                    indexed,
+                    constant,
+                );
+            }
+        }
+        Instruction::Branch(branch_bucket) => {
+            collect_var_accesses(&branch_bucket.cond, indexed, constant);
+            collect_all(&branch_bucket.if_branch, indexed, constant);
+            collect_all(&branch_bucket.else_branch, indexed, constant);
+        }
+        Instruction::Return(return_bucket) => {
+            collect_var_accesses(&return_bucket.value, indexed, constant)
+        }
+        Instruction::Assert(assert_bucket) => {
+            collect_var_accesses(&assert_bucket.evaluate, indexed, constant)
+        }
+        Instruction::Log(log_bucket) => {
+            for to_log in log_bucket.argsprint.iter() {
+                if let LogBucketArg::LogExp(log_expr) = to_log {
+                    collect_var_accesses(log_expr, indexed, constant);
+                }
+            }
+        }
+        Instruction::Loop(loop_bucket) => {
+            collect_var_accesses(&loop_bucket.continue_condition, indexed, constant);
+            collect_all(&loop_bucket.body, indexed, constant);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use ark_bn254::Bn254;
@@ -11,6 +11,12 @@
 //! Arithmetic (A) and Binary (B) Shares, which is necessary for circom's witness extension. The current implementation of the MPC-VM is somewhat naive with respect to
 //! run-time optimization. We eagerly communicate after every non-linear operation and perform many unnecessary conversions between A and B shares.
 //!
+//! Vars can be read and written and signals can be read with shared indices, which is done obliviously on the whole array the index points into.
+//! As circom does not keep the declarations of vars, the compiler recovers the size of a var array from the accesses to it. An array ends
+//! at the next address that is accessed on its own, which may also be an element of the array that is accessed at a constant index.
+//! Whether a shared index is in bounds of this array is opened to all parties, as the plain witness extension fails for
+//! out-of-bounds accesses as well. Only this single bit is revealed, not the index itself.
+//!
 //! Major changes and optimizations are expected in the near future.
 
 mod accelerator;
@@ -99,6 +99,9 @@ pub trait VmCircomWitnessExtension<F: PrimeField> {
     /// Computes the bitwise AND of the VM-types a and b: c = a & b.
     fn bit_and(&mut self, a: Self::VmType, b: Self::VmType) -> Result<Self::VmType>;
 
+    /// Computes the bitwise NOT of the VM-type a over the bit size of the field: c = ~a. The result is reduced modulo the field's prime.
+    fn bit_not(&mut self, a: Self::VmType) -> Result<Self::VmType>;
+
     /// Outputs whether a is zero (true) or not (false). This values is output in plain! Thus, if a is secret shared, the result is opened.
     fn is_zero(&mut self, a: Self::VmType, allow_secret_inputs: bool) -> Result<bool>;
 
@@ -108,6 +111,22 @@ pub trait VmCircomWitnessExtension<F: PrimeField> {
     /// Transforms a public field element into a usize if possible.
     fn to_index(&mut self, a: Self::VmType) -> Result<usize>;
 
+    /// Reads `amount` consecutive values starting at the position `index` from `values`. If the position is secret shared, the read is oblivious, i.e., it does not reveal the position. The position has to be at most `values.len() - amount`.
+    fn read_at_index(
+        &mut self,
+        values: &[Self::VmType],
+        index: Self::VmType,
+        amount: usize,
+    ) -> Result<Vec<Self::VmType>>;
+
+    /// Writes `new_values` to consecutive positions starting at the position `index` in `values`. If the position is secret shared, the write is oblivious, i.e., all values that can be addressed by the position are updated. The position has to be at most `values.len() - new_values.len()`.
+    fn write_at_index(
+        &mut self,
+        values: &mut [Self::VmType],
+        index: Self::VmType,
+        new_values: Vec<Self::VmType>,
+    ) -> Result<()>;
+
     /// Opens the VM-type a. If a is secret shared, it gets reconstructed.
     fn open(&mut self, a: Self::VmType) -> Result<F>;
 
@@ -1,6 +1,7 @@
 use super::VmCircomWitnessExtension;
 use crate::mpc_vm::VMConfig;
 use ark_ff::{One, PrimeField};
+use eyre::bail;
 use eyre::eyre;
 use eyre::Result;
 use num_bigint::BigUint;
@@ -111,6 +112,9 @@ impl<F: PrimeField> VmCircomWitnessExtension<F> for CircomPlainVmWitnessExtensio
     }
 
     fn modulo(&mut self, a: Self::VmType, b: Self::VmType) -> Result<Self::VmType> {
+        if b.is_zero() {
+            bail!("Cannot divide by zero");
+        }
         let a = to_bigint!(a);
         let b = to_bigint!(b);
         Ok(F::from(a % b))
@@ -127,6 +131,9 @@ impl<F: PrimeField> VmCircomWitnessExtension<F> for CircomPlainVmWitnessExtensio
 
     fn int_div(&mut self, a: Self::VmType, b: Self::VmType) -> Result<Self::VmType> {
         tracing::debug!("trying to divide {a}/{b}");
+        if b.is_zero() {
+            bail!("Cannot divide by zero");
+        }
         let lhs = to_u128!(a);
         let rhs = to_u128!(b);
         Ok(F::from(lhs / rhs))
@@ -226,9 +233,43 @@ impl<F: PrimeField> VmCircomWitnessExtension<F> for CircomPlainVmWitnessExtensio
         Ok(F::from(lhs & rhs))
     }
 
+    fn bit_not(&mut self, a: Self::VmType) -> Result<Self::VmType> {
+        let a = to_bigint!(a);
+        let mask = (BigUint::one() << F::MODULUS_BIT_SIZE) - BigUint::one();
+        Ok(F::from(a ^ mask))
+    }
+
     fn to_index(&mut self, a: Self::VmType) -> Result<usize> {
         Ok(to_usize!(a))
     }
+
+    fn read_at_index(
+        &mut self,
+        values: &[Self::VmType],
+        index: Self::VmType,
+        amount: usize,
+    ) -> Result<Vec<Self::VmType>> {
+        let index = to_usize!(index);
+        let values = values
+            .get(index..index + amount)
+            .ok_or(eyre!("Index {index} out of bounds"))?;
+        Ok(values.to_vec())
+    }
+
+    fn write_at_index(
+        &mut self,
+        values: &mut [Self::VmType],
+        index: Self::VmType,
+        new_values: Vec<Self::VmType>,
+    ) -> Result<()> {
+        let index = to_usize!(index);
+        let values = values
+            .get_mut(index..index + new_values.len())
+            .ok_or(eyre!("Index {index} out of bounds"))?;
+        values.clone_from_slice(&new_values);
+        Ok(())
+    }
+
     fn open(&mut self, a: Self::VmType) -> Result<F> {
         Ok(a)
     }
@@ -5,10 +5,15 @@ use super::{
 use crate::mpc_vm::VMConfig;
 use ark_ff::{One, PrimeField};
 use eyre::{bail, eyre};
-use mpc_core::protocols::rep3::{
-    arithmetic, binary, conversion,
-    network::{IoContext, Rep3Network},
-    Rep3BigUintShare, Rep3PrimeFieldShare,
+use mpc_core::{
+    lut::LookupTableProvider,
+    protocols::rep3::{
+        arithmetic, binary, conversion,
+        gadgets::{int_div, pow},
+        lut::NaiveRep3LookupTable,
+        network::{IoContext, Rep3Network},
+        Rep3BigUintShare, Rep3PrimeFieldShare,
+    },
 };
 use num_bigint::BigUint;
 use num_traits::cast::ToPrimitive;
@@ -65,6 +70,7 @@ impl<F: PrimeField> Default for Rep3VmType<F> {
 pub struct CircomRep3VmWitnessExtension<F: PrimeField, N: Rep3Network> {
     io_context0: IoContext<N>,
     io_context1: IoContext<N>,
+    lut_provider: NaiveRep3LookupTable<N>,
     plain: CircomPlainVmWitnessExtension<F>,
 }
 
@@ -73,9 +79,11 @@ impl<F: PrimeField, N: Rep3Network> CircomRep3VmWitnessExtension<F, N> {
         let mut io_context = IoContext::init(network)?;
         io_context.set_a2b_type(a2b_type);
         let io_context_fork = io_context.fork()?;
+        let lut_provider = NaiveRep3LookupTable::new(io_context.fork()?);
         Ok(Self {
             io_context0: io_context,
             io_context1: io_context_fork,
+            lut_provider,
             plain: CircomPlainVmWitnessExtension::default(),
         })
     }
@@ -97,6 +105,53 @@ impl<F: PrimeField, N: Rep3Network> CircomRep3VmWitnessExtension<F, N> {
         let p_half_plus_one = F::from(modulus / two + one);
         arithmetic::sub_shared_by_public(z, p_half_plus_one, self.io_context0.id)
     }
+
+    /// Computes the integer division with remainder of a by b, where the values are interpreted as unsigned integers and at least one of them is shared. Returns the quotient and the remainder.
+    ///
+    /// Fails for a divisor of zero, same as the plain VM. For a shared divisor, we therefore open whether it is zero, but nothing else about it.
+    fn int_div_rem(
+        &mut self,
+        a: Rep3VmType<F>,
+        b: Rep3VmType<F>,
+    ) -> eyre::Result<(ArithmeticShare<F>, ArithmeticShare<F>)> {
+        let a = match a {
+            Rep3VmType::Public(a) => arithmetic::promote_to_trivial_share(self.io_context0.id, a),
+            Rep3VmType::Arithmetic(a) => a,
+            Rep3VmType::Binary(a) => conversion::b2a_selector(&a, &mut self.io_context0)?,
+        };
+        let b = match b {
+            Rep3VmType::Public(b) => {
+                if b.is_zero() {
+                    bail!("Cannot divide by zero");
+                }
+                return Ok(int_div::int_div_rem_by_public(a, b, &mut self.io_context0)?);
+            }
+            Rep3VmType::Arithmetic(b) => b,
+            Rep3VmType::Binary(b) => conversion::b2a_selector(&b, &mut self.io_context0)?,
+        };
+        //the gadget does not detect a shared divisor of zero by itself
+        if arithmetic::is_zero(b, &mut self.io_context0)? {
+            bail!("Cannot divide by zero");
+        }
+        Ok(int_div::int_div_rem(a, b, &mut self.io_context0)?)
+    }
+
+    /// Initializes a LUT that maps the positions of the values to the values.
+    fn init_index_map(
+        &mut self,
+        values: &[Rep3VmType<F>],
+    ) -> eyre::Result<Vec<(ArithmeticShare<F>, ArithmeticShare<F>)>> {
+        let id = self.io_context0.id;
+        let values = values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let key = arithmetic::promote_to_trivial_share(id, F::from(i as u64));
+                Ok((key, self.to_share(value.to_owned())?))
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        Ok(self.lut_provider.init_map(values))
+    }
 }
 
 impl<F: PrimeField, N: Rep3Network> VmCircomWitnessExtension<F>
@@ -261,16 +316,23 @@ impl<F: PrimeField, N: Rep3Network> VmCircomWitnessExtension<F>
     fn int_div(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
         match (a, b) {
             (Rep3VmType::Public(a), Rep3VmType::Public(b)) => Ok(self.plain.int_div(a, b)?.into()),
-            _ => todo!("Shared int_div not implemented"),
+            (a, b) => {
+                let (quotient, _) = self.int_div_rem(a, b)?;
+                Ok(quotient.into())
+            }
         }
     }
 
     fn pow(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
         match (a, b) {
             (Rep3VmType::Public(a), Rep3VmType::Public(b)) => Ok(self.plain.pow(a, b)?.into()),
-            (Rep3VmType::Binary(a), Rep3VmType::Public(b)) => {
+            (Rep3VmType::Binary(a), b) => {
                 let a = conversion::b2a_selector(&a, &mut self.io_context0)?;
-                self.pow(a.into(), b.into())
+                self.pow(a.into(), b)
+            }
+            (a, Rep3VmType::Binary(b)) => {
+                let b = conversion::b2a_selector(&b, &mut self.io_context0)?;
+                self.pow(a, b.into())
             }
             (Rep3VmType::Arithmetic(a), Rep3VmType::Public(b)) => {
                 if b.is_zero() {
@@ -278,14 +340,22 @@ impl<F: PrimeField, N: Rep3Network> VmCircomWitnessExtension<F>
                 }
                 Ok(arithmetic::pow_public(a, b, &mut self.io_context0)?.into())
             }
-            _ => todo!("pow with shared exponent not implemented"),
+            (Rep3VmType::Public(a), Rep3VmType::Arithmetic(b)) => {
+                Ok(pow::pow_public_by_shared(a, b, &mut self.io_context0)?.into())
+            }
+            (Rep3VmType::Arithmetic(a), Rep3VmType::Arithmetic(b)) => {
+                Ok(pow::pow_by_shared(a, b, &mut self.io_context0)?.into())
+            }
         }
     }
 
     fn modulo(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
         match (a, b) {
             (Rep3VmType::Public(a), Rep3VmType::Public(b)) => Ok(self.plain.modulo(a, b)?.into()),
-            (_, _) => todo!("Shared mod not implemented"),
+            (a, b) => {
+                let (_, remainder) = self.int_div_rem(a, b)?;
+                Ok(remainder.into())
+            }
         }
     }
 
@@ -561,13 +631,13 @@ impl<F: PrimeField, N: Rep3Network> VmCircomWitnessExtension<F>
     fn shift_r(&mut self, a: Self::VmType, b: Self::VmType) -> eyre::Result<Self::VmType> {
         match (a, b) {
             (Rep3VmType::Public(a), Rep3VmType::Public(b)) => Ok(self.plain.shift_r(a, b)?.into()),
-            (Rep3VmType::Public(a), Rep3VmType::Arithmetic(_))
-            | (Rep3VmType::Public(a), Rep3VmType::Binary(_)) => {
+            (Rep3VmType::Public(a), b) => {
                 // some special casing
                 if a == F::zero() {
                     return Ok(Rep3VmType::Public(F::zero()));
                 }
-                todo!("Shared shift_right (public by shared) not implemented");
+                let a = binary::promote_to_trivial_share(self.io_context0.id, &a.into());
+                self.shift_r(a.into(), b)
             }
             (Rep3VmType::Arithmetic(a), Rep3VmType::Public(b)) => {
                 let bits = conversion::a2b_selector(a, &mut self.io_context0)?;
@@ -576,7 +646,24 @@ impl<F: PrimeField, N: Rep3Network> VmCircomWitnessExtension<F>
             (Rep3VmType::Binary(a), Rep3VmType::Public(b)) => {
                 Ok(binary::shift_r_public(&a, b).into())
             }
-            (_, _) => todo!("Shared shift_right not implemented"),
+            (Rep3VmType::Arithmetic(a), Rep3VmType::Arithmetic(b)) => {
+                let (a, b) = join!(
+                    conversion::a2b_selector(a, &mut self.io_context0),
+                    conversion::a2b_selector(b, &mut self.io_context1)
+                );
+                Ok(binary::shift_r_by_shared(&a?, &b?, &mut self.io_context0)?.into())
+            }
+            (Rep3VmType::Arithmetic(a), Rep3VmType::Binary(b)) => {
+                let a = conversion::a2b_selector(a, &mut self.io_context0)?;
+                Ok(binary::shift_r_by_shared(&a, &b, &mut self.io_context0)?.into())
+            }
+            (Rep3VmType::Binary(a), Rep3VmType::Arithmetic(b)) => {
+                let b = conversion::a2b_selector(b, &mut self.io_context0)?;
+                Ok(binary::shift_r_by_shared(&a, &b, &mut self.io_context0)?.into())
+            }
+            (Rep3VmType::Binary(a), Rep3VmType::Binary(b)) => {
+                Ok(binary::shift_r_by_shared(&a, &b, &mut self.io_context0)?.into())
+            }
         }
     }
 
@@ -607,7 +694,24 @@ impl<F: PrimeField, N: Rep3Network> VmCircomWitnessExtension<F>
             (Rep3VmType::Binary(a), Rep3VmType::Public(b)) => {
                 Ok(binary::shift_l_public(&a, b).into())
             }
-            (_, _) => todo!("Shared shift_right not implemented"),
+            (Rep3VmType::Arithmetic(a), Rep3VmType::Arithmetic(b)) => {
+                let b = conversion::a2b_selector(b, &mut self.io_context0)?;
+                self.shift_l(a.into(), b.into())
+            }
+            (Rep3VmType::Arithmetic(a), Rep3VmType::Binary(b)) => {
+                Ok(binary::shift_l_by_shared(a, &b, &mut self.io_context0)?.into())
+            }
+            (Rep3VmType::Binary(a), Rep3VmType::Arithmetic(b)) => {
+                let (a, b) = join!(
+                    conversion::b2a_selector(&a, &mut self.io_context0),
+                    conversion::a2b_selector(b, &mut self.io_context1)
+                );
+                Ok(binary::shift_l_by_shared(a?, &b?, &mut self.io_context0)?.into())
+            }
+            (Rep3VmType::Binary(a), Rep3VmType::Binary(b)) => {
+                let a = conversion::b2a_selector(&a, &mut self.io_context0)?;
+                Ok(binary::shift_l_by_shared(a, &b, &mut self.io_context0)?.into())
+            }
         }
     }
 
@@ -784,6 +888,22 @@ impl<F: PrimeField, N: Rep3Network> VmCircomWitnessExtension<F>
         }
     }
 
+    fn bit_not(&mut self, a: Self::VmType) -> eyre::Result<Self::VmType> {
+        match a {
+            Rep3VmType::Public(a) => Ok(self.plain.bit_not(a)?.into()),
+            Rep3VmType::Arithmetic(a) => {
+                // a < 2^MODULUS_BIT_SIZE, therefore ~a = mask - a, which we can compute
+                // locally and which is reduced modulo the prime like in the plain VM
+                let mask = (BigUint::one() << F::MODULUS_BIT_SIZE) - BigUint::one();
+                Ok(arithmetic::sub_public_by_shared(F::from(mask), a, self.io_context0.id).into())
+            }
+            Rep3VmType::Binary(a) => {
+                let a = conversion::b2a_selector(&a, &mut self.io_context0)?;
+                self.bit_not(a.into())
+            }
+        }
+    }
+
     fn is_zero(&mut self, a: Self::VmType, allow_secret_inputs: bool) -> eyre::Result<bool> {
         if !allow_secret_inputs && self.is_shared(&a)? {
             bail!("allow_secret_inputs is false and input is shared");
@@ -814,6 +934,66 @@ impl<F: PrimeField, N: Rep3Network> VmCircomWitnessExtension<F>
         }
     }
 
+    fn read_at_index(
+        &mut self,
+        values: &[Self::VmType],
+        index: Self::VmType,
+        amount: usize,
+    ) -> eyre::Result<Vec<Self::VmType>> {
+        let index = match index {
+            Rep3VmType::Public(index) => {
+                let index = to_usize!(index);
+                let values = values
+                    .get(index..index + amount)
+                    .ok_or(eyre!("Index {index} out of bounds"))?;
+                return Ok(values.to_vec());
+            }
+            index => self.to_share(index)?,
+        };
+        let Some(num_positions) = (values.len() + 1).checked_sub(amount) else {
+            bail!("Cannot read {amount} values from {} values", values.len());
+        };
+        let mut result = Vec::with_capacity(amount);
+        for offset in 0..amount {
+            let map = self.init_index_map(&values[offset..offset + num_positions])?;
+            result.push(self.lut_provider.get_from_lut(index, &map)?.into());
+        }
+        Ok(result)
+    }
+
+    fn write_at_index(
+        &mut self,
+        values: &mut [Self::VmType],
+        index: Self::VmType,
+        new_values: Vec<Self::VmType>,
+    ) -> eyre::Result<()> {
+        let index = match index {
+            Rep3VmType::Public(index) => {
+                let index = to_usize!(index);
+                let values = values
+                    .get_mut(index..index + new_values.len())
+                    .ok_or(eyre!("Index {index} out of bounds"))?;
+                values.clone_from_slice(&new_values);
+                return Ok(());
+            }
+            index => self.to_share(index)?,
+        };
+        let amount = new_values.len();
+        let Some(num_positions) = (values.len() + 1).checked_sub(amount) else {
+            bail!("Cannot write {amount} values to {} values", values.len());
+        };
+        for (offset, new_value) in new_values.into_iter().enumerate() {
+            let new_value = self.to_share(new_value)?;
+            let window = &mut values[offset..offset + num_positions];
+            let mut map = self.init_index_map(window)?;
+            self.lut_provider.write_to_lut(index, new_value, &mut map)?;
+            for (value, (_, written)) in window.iter_mut().zip(map) {
+                *value = written.into();
+            }
+        }
+        Ok(())
+    }
+
     fn open(&mut self, a: Self::VmType) -> eyre::Result<F> {
         match a {
             Rep3VmType::Public(a) => Ok(a),
@@ -60,22 +60,43 @@ pub type PlainWitnessExtension<F> = WitnessExtension<F, CircomPlainVmWitnessExte
 /// This is the only supported protocol at the moment.
 pub type Rep3WitnessExtension<F, N> = WitnessExtension<F, CircomRep3VmWitnessExtension<F, N>>;
 
-type ConsumedFunCtx<T> = (usize, usize, Vec<T>, Arc<CodeBlock>, Vec<(T, Vec<T>)>);
+type ConsumedFunCtx<T> = (
+    usize,
+    usize,
+    Vec<T>,
+    Arc<Vec<(usize, usize)>>,
+    Arc<CodeBlock>,
+    Vec<(T, Vec<T>)>,
+);
 
 #[derive(Default, Clone)]
 struct IfCtxStack<F: PrimeField, C: VmCircomWitnessExtension<F>>(Vec<IfCtx<F, C>>);
 
+/// An index on the index stack. Shared indices are split into a public base
+/// (e.g., the start of an array) and a shared offset relative to it.
+#[derive(Clone)]
+enum VmIndex<T> {
+    Public(usize),
+    Shared { base: usize, offset: T },
+}
+
 #[derive(Default, Clone)]
 struct Component<F: PrimeField, C: VmCircomWitnessExtension<F>> {
     symbol: String,
     amount_vars: usize,
     provided_input_signals: usize,
     input_signals: usize,
+    /// the number of signals of this component without sub-components
+    signals: usize,
+    /// (start, size) of the var arrays of the template
+    var_arrays: Arc<Vec<(usize, usize)>>,
+    /// (start, size) of the signal arrays of this component
+    signal_arrays: Vec<(usize, usize)>,
     current_return_vals: usize,
     /// the offset inside the signals array
     my_offset: usize,
     field_stack: Stack<C::VmType>,
-    index_stack: Stack<usize>,
+    index_stack: Stack<VmIndex<C::VmType>>,
     if_stack: IfCtxStack<F, C>,
     functions_ctx: Stack<FunctionCtx<C::VmType>>,
     mappings: Vec<usize>,
@@ -197,6 +218,7 @@ struct FunctionCtx<T> {
     ip: usize,
     return_vals: usize,
     vars: Vec<T>,
+    var_arrays: Arc<Vec<(usize, usize)>>,
     body: Arc<CodeBlock>,
     shared_return_vals: Vec<(T, Vec<T>)>,
 }
@@ -206,6 +228,7 @@ impl<T> FunctionCtx<T> {
         ip: usize,
         return_vals: usize,
         vars: Vec<T>,
+        var_arrays: Arc<Vec<(usize, usize)>>,
         body: Arc<CodeBlock>,
         shared_return_vals: Vec<(T, Vec<T>)>,
     ) -> Self {
@@ -213,6 +236,7 @@ impl<T> FunctionCtx<T> {
             ip,
             return_vals,
             vars,
+            var_arrays,
             body,
             shared_return_vals,
         }
@@ -223,6 +247,7 @@ impl<T> FunctionCtx<T> {
             self.ip,
             self.return_vals,
             self.vars,
+            self.var_arrays,
             self.body,
             self.shared_return_vals,
         )
@@ -236,6 +261,9 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
             amount_vars: templ_decl.vars,
             provided_input_signals: 0,
             input_signals: templ_decl.input_signals,
+            signals: templ_decl.signals,
+            var_arrays: Arc::clone(&templ_decl.var_arrays),
+            signal_arrays: templ_decl.signal_arrays.clone(),
             current_return_vals: 0,
             my_offset: signal_offset,
             field_stack: Stack::default(),
@@ -261,14 +289,60 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
 
     #[inline(always)]
     fn push_index(&mut self, val: usize) {
-        self.index_stack.push(val)
+        self.index_stack.push(VmIndex::Public(val))
+    }
+
+    #[inline(always)]
+    fn pop_index(&mut self) -> Result<usize> {
+        match self.index_stack.pop() {
+            VmIndex::Public(index) => Ok(index),
+            VmIndex::Shared { .. } => {
+                bail!("Shared indices are only supported for loading/storing vars and loading signals")
+            }
+        }
     }
 
     #[inline(always)]
-    fn pop_index(&mut self) -> usize {
+    fn pop_vm_index(&mut self) -> VmIndex<C::VmType> {
         self.index_stack.pop()
     }
 
+    /// Finds the array that contains `base` and checks that the `amount` elements at the shared
+    /// index lie inside of it. Returns the range of the array and the shared offset relative to its
+    /// start. Whether the index is in bounds is opened, as the plain VM fails in this case as well.
+    fn shared_array_access(
+        protocol: &mut C,
+        arrays: &[(usize, usize)],
+        available: usize,
+        base: usize,
+        offset: C::VmType,
+        amount: usize,
+    ) -> Result<(std::ops::Range<usize>, C::VmType)> {
+        let Some((start, size)) = arrays
+            .iter()
+            .filter(|(start, size)| (*start..start + size).contains(&base))
+            .max_by_key(|(_, size)| *size)
+            .copied()
+        else {
+            bail!("Cannot use a shared index at {base}, as it does not point into an array of known size");
+        };
+        if start + size > available {
+            bail!("Array at {start} with size {size} exceeds the {available} available elements");
+        }
+        let Some(positions) = (size + 1).checked_sub(amount) else {
+            bail!("Cannot access {amount} elements of array at {start} with size {size}");
+        };
+        let offset = protocol.add(offset, C::VmType::from(F::from((base - start) as u64)))?;
+        //circom compares signed values, therefore we check both bounds
+        let lower = protocol.ge(offset.clone(), C::VmType::from(F::zero()))?;
+        let upper = protocol.lt(offset.clone(), C::VmType::from(F::from(positions as u64)))?;
+        let in_bounds = protocol.bool_and(lower, upper)?;
+        if protocol.is_zero(in_bounds, true)? {
+            bail!("Shared index out of bounds for array at {start} with size {size}");
+        }
+        Ok((start..start + size, offset))
+    }
+
     #[allow(dead_code)]
     fn debug_code_block(code_block: Arc<CodeBlock>) {
         for (idx, inst) in code_block.iter().enumerate() {
@@ -284,6 +358,7 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
         let mut ip = 0;
         let mut current_body = Arc::clone(&self.component_body);
         let mut current_vars = vec![C::VmType::default(); self.amount_vars];
+        let mut current_var_arrays = Arc::clone(&self.var_arrays);
         let mut current_shared_ret_vals = vec![];
         loop {
             let inst = &current_body[ip];
@@ -295,20 +370,40 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
                     self.push_field(constant);
                 }
                 op_codes::MpcOpCode::PushIndex(index) => self.push_index(*index),
-                op_codes::MpcOpCode::LoadSignals(amount) => {
-                    let index = self.pop_index();
-                    let start = self.my_offset + index;
-                    ctx.signals[start..start + amount]
-                        .iter()
-                        .cloned()
-                        .for_each(|signal| {
-                            tracing::debug!("pushing signal {signal}");
-                            self.push_field(signal);
-                        });
-                }
+                op_codes::MpcOpCode::LoadSignals(amount) => match self.pop_vm_index() {
+                    VmIndex::Public(index) => {
+                        let start = self.my_offset + index;
+                        ctx.signals[start..start + amount]
+                            .iter()
+                            .cloned()
+                            .for_each(|signal| {
+                                tracing::debug!("pushing signal {signal}");
+                                self.push_field(signal);
+                            });
+                    }
+                    VmIndex::Shared { base, offset } => {
+                        //we can only read obliviously from our own signals
+                        let (array, offset) = Self::shared_array_access(
+                            protocol,
+                            &self.signal_arrays,
+                            self.signals,
+                            base,
+                            offset,
+                            *amount,
+                        )?;
+                        let array = self.my_offset + array.start..self.my_offset + array.end;
+                        protocol
+                            .read_at_index(&ctx.signals[array], offset, *amount)?
+                            .into_iter()
+                            .for_each(|signal| {
+                                tracing::debug!("pushing signal {signal}");
+                                self.push_field(signal);
+                            });
+                    }
+                },
                 op_codes::MpcOpCode::StoreSignals(amount) => {
                     //get index
-                    let index = self.pop_index();
+                    let index = self.pop_index()?;
                     if self.if_stack.is_shared() {
                         let shared_condition = self.if_stack.get_shared_condition();
                         for i in 0..*amount {
@@ -323,30 +418,72 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
                         }
                     }
                 }
-                op_codes::MpcOpCode::LoadVars(amount) => {
-                    let index = self.pop_index();
-                    current_vars[index..index + amount]
-                        .iter()
-                        .cloned()
-                        .for_each(|signal| {
-                            self.push_field(signal);
-                        });
-                }
-                op_codes::MpcOpCode::StoreVars(amount) => {
-                    let index = self.pop_index();
-                    if self.if_stack.is_shared() {
-                        let cond = self.if_stack.get_shared_condition();
-                        for i in 0..*amount {
-                            let old = current_vars[index + amount - i - 1].clone();
-                            current_vars[index + amount - i - 1] =
-                                protocol.cmux(cond.clone(), self.pop_field(), old)?;
+                op_codes::MpcOpCode::LoadVars(amount) => match self.pop_vm_index() {
+                    VmIndex::Public(index) => {
+                        current_vars[index..index + amount]
+                            .iter()
+                            .cloned()
+                            .for_each(|signal| {
+                                self.push_field(signal);
+                            });
+                    }
+                    VmIndex::Shared { base, offset } => {
+                        let (array, offset) = Self::shared_array_access(
+                            protocol,
+                            &current_var_arrays,
+                            current_vars.len(),
+                            base,
+                            offset,
+                            *amount,
+                        )?;
+                        protocol
+                            .read_at_index(&current_vars[array], offset, *amount)?
+                            .into_iter()
+                            .for_each(|var| {
+                                self.push_field(var);
+                            });
+                    }
+                },
+                op_codes::MpcOpCode::StoreVars(amount) => match self.pop_vm_index() {
+                    VmIndex::Public(index) => {
+                        if self.if_stack.is_shared() {
+                            let cond = self.if_stack.get_shared_condition();
+                            for i in 0..*amount {
+                                let old = current_vars[index + amount - i - 1].clone();
+                                current_vars[index + amount - i - 1] =
+                                    protocol.cmux(cond.clone(), self.pop_field(), old)?;
+                            }
+                        } else {
+                            for i in 0..*amount {
+                                current_vars[index + amount - i - 1] = self.pop_field();
+                            }
                         }
-                    } else {
-                        for i in 0..*amount {
-                            current_vars[index + amount - i - 1] = self.pop_field();
+                    }
+                    VmIndex::Shared { base, offset } => {
+                        let mut new_vars = (0..*amount).map(|_| self.pop_field()).collect_vec();
+                        new_vars.reverse();
+                        let (array, offset) = Self::shared_array_access(
+                            protocol,
+                            &current_var_arrays,
+                            current_vars.len(),
+                            base,
+                            offset,
+                            *amount,
+                        )?;
+                        if self.if_stack.is_shared() {
+                            let cond = self.if_stack.get_shared_condition();
+                            let old_vars = protocol.read_at_index(
+                                &current_vars[array.clone()],
+                                offset.clone(),
+                                *amount,
+                            )?;
+                            new_vars = izip!(new_vars, old_vars)
+                                .map(|(new, old)| protocol.cmux(cond.clone(), new, old))
+                                .collect::<Result<Vec<_>>>()?;
                         }
+                        protocol.write_at_index(&mut current_vars[array], offset, new_vars)?;
                     }
-                }
+                },
                 op_codes::MpcOpCode::Call(symbol, return_vals) => {
                     tracing::debug!("Calling {symbol}");
                     let fun_decl = ctx.fun_decls.get(symbol).ok_or(eyre!(
@@ -364,6 +501,10 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
                         //TODO we need to perform a full ReturnFun here with shared returns and with arrays
                         //for time being we support only sqrt therefore just assert that and push on stack
                         assert_eq!(result.len(), 1);
+                        //remove the parameters, the result may be used in an expression
+                        for _ in 0..fun_decl.num_params {
+                            self.pop_field();
+                        }
                         self.push_field(result.pop().unwrap());
                     } else {
                         let mut func_vars = vec![C::VmType::default(); fun_decl.vars];
@@ -374,6 +515,10 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
                         {
                             func_vars[idx] = param.clone();
                         }
+                        //remove the parameters, the return value may be used in an expression
+                        for _ in 0..fun_decl.num_params {
+                            self.pop_field();
+                        }
                         std::mem::swap(&mut func_vars, &mut current_vars);
                         let mut next_shared_ret_vals = vec![];
                         std::mem::swap(&mut current_shared_ret_vals, &mut next_shared_ret_vals);
@@ -383,11 +528,13 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
                             ip,
                             self.current_return_vals,
                             func_vars,
+                            Arc::clone(&current_var_arrays),
                             Arc::clone(&current_body),
                             next_shared_ret_vals,
                         ));
                         //set size of return value
                         self.current_return_vals = *return_vals;
+                        current_var_arrays = Arc::clone(&fun_decl.var_arrays);
                         current_body = Arc::clone(&fun_decl.body);
                         ip = 0;
                         continue;
@@ -395,8 +542,8 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
                 }
                 op_codes::MpcOpCode::CreateCmp(symbol, amount) => {
                     let new_components = {
-                        let offset_jump = self.pop_index();
-                        let relative_offset = self.pop_index();
+                        let offset_jump = self.pop_index()?;
+                        let relative_offset = self.pop_index()?;
                         let templ_decl = ctx.templ_decls.get(symbol).ok_or(eyre!(
                             "{symbol} not found in template declarations. This must be a bug"
                         ))?;
@@ -419,8 +566,8 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
                     }
                 }
                 op_codes::MpcOpCode::OutputSubComp(mapped, signal_code, amount) => {
-                    let sub_comp_index = self.pop_index();
-                    let mut index = self.pop_index();
+                    let sub_comp_index = self.pop_index()?;
+                    let mut index = self.pop_index()?;
                     let component = &mut self.sub_components[sub_comp_index];
                     if *mapped {
                         index += component.mappings[*signal_code];
@@ -435,8 +582,8 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
                         !self.if_stack.is_shared(),
                         "Cannot be shared when providing inputs for sub component"
                     );
-                    let sub_comp_index = self.pop_index();
-                    let mut index = self.pop_index();
+                    let sub_comp_index = self.pop_index()?;
+                    let mut index = self.pop_index()?;
                     //we cannot borrow later therefore we need to pop from stack here and push later
                     let mut input_signals = vec![C::VmType::default(); *amount];
                     for i in 0..*amount {
@@ -596,6 +743,10 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
                     let lhs = self.pop_field();
                     self.push_field(protocol.bool_and(lhs, rhs)?);
                 }
+                op_codes::MpcOpCode::BoolNot => {
+                    let x = self.pop_field();
+                    self.push_field(protocol.bool_not(x)?);
+                }
                 op_codes::MpcOpCode::BitOr => {
                     let rhs = self.pop_field();
                     let lhs = self.pop_field();
@@ -611,19 +762,89 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
                     let lhs = self.pop_field();
                     self.push_field(protocol.bit_xor(lhs, rhs)?);
                 }
+                op_codes::MpcOpCode::BitNot => {
+                    let x = self.pop_field();
+                    self.push_field(protocol.bit_not(x)?);
+                }
                 op_codes::MpcOpCode::AddIndex => {
-                    let rhs = self.pop_index();
-                    let lhs = self.pop_index();
-                    self.push_index(lhs + rhs);
+                    let rhs = self.pop_vm_index();
+                    let lhs = self.pop_vm_index();
+                    let index = match (lhs, rhs) {
+                        (VmIndex::Public(lhs), VmIndex::Public(rhs)) => VmIndex::Public(lhs + rhs),
+                        (VmIndex::Public(public), VmIndex::Shared { base, offset })
+                        | (VmIndex::Shared { base, offset }, VmIndex::Public(public)) => {
+                            VmIndex::Shared {
+                                base: base + public,
+                                offset,
+                            }
+                        }
+                        (
+                            VmIndex::Shared {
+                                base: lhs_base,
+                                offset: lhs_offset,
+                            },
+                            VmIndex::Shared {
+                                base: rhs_base,
+                                offset: rhs_offset,
+                            },
+                        ) => VmIndex::Shared {
+                            base: lhs_base + rhs_base,
+                            offset: protocol.add(lhs_offset, rhs_offset)?,
+                        },
+                    };
+                    self.index_stack.push(index);
                 }
                 op_codes::MpcOpCode::MulIndex => {
-                    let rhs = self.pop_index();
-                    let lhs = self.pop_index();
-                    self.push_index(lhs * rhs);
+                    let rhs = self.pop_vm_index();
+                    let lhs = self.pop_vm_index();
+                    let index = match (lhs, rhs) {
+                        (VmIndex::Public(lhs), VmIndex::Public(rhs)) => VmIndex::Public(lhs * rhs),
+                        (VmIndex::Public(public), VmIndex::Shared { base, offset })
+                        | (VmIndex::Shared { base, offset }, VmIndex::Public(public)) => {
+                            VmIndex::Shared {
+                                base: base * public,
+                                offset: protocol
+                                    .mul(offset, C::VmType::from(F::from(public as u64)))?,
+                            }
+                        }
+                        (
+                            VmIndex::Shared {
+                                base: lhs_base,
+                                offset: lhs_offset,
+                            },
+                            VmIndex::Shared {
+                                base: rhs_base,
+                                offset: rhs_offset,
+                            },
+                        ) => {
+                            //(lb + lo) * (rb + ro) = lb * rb + (lo * ro + lb * ro + rb * lo),
+                            //so that the public part of the product is kept as the base
+                            let lhs_base_field = C::VmType::from(F::from(lhs_base as u64));
+                            let rhs_base_field = C::VmType::from(F::from(rhs_base as u64));
+                            let cross_lhs = protocol.mul(lhs_offset.clone(), rhs_base_field)?;
+                            let cross_rhs = protocol.mul(rhs_offset.clone(), lhs_base_field)?;
+                            let offset = protocol.mul(lhs_offset, rhs_offset)?;
+                            let offset = protocol.add(offset, cross_lhs)?;
+                            VmIndex::Shared {
+                                base: lhs_base * rhs_base,
+                                offset: protocol.add(offset, cross_rhs)?,
+                            }
+                        }
+                    };
+                    self.index_stack.push(index);
                 }
                 op_codes::MpcOpCode::ToIndex => {
                     let signal = self.pop_field();
-                    self.push_index(protocol.to_index(signal)?);
+                    if protocol.is_shared(&signal)? {
+                        //a shared signal has no public part, the array it indexes is
+                        //attributed by the address that is added to it afterwards
+                        self.index_stack.push(VmIndex::Shared {
+                            base: 0,
+                            offset: signal,
+                        });
+                    } else {
+                        self.push_index(protocol.to_index(signal)?);
+                    }
                 }
                 op_codes::MpcOpCode::JumpBack(jump_backward) => {
                     ip -= jump_backward;
@@ -672,6 +893,7 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
                             self.handle_shared_fun_return(protocol, &current_shared_ret_vals)?;
                         } else {
                             //copy the return value
+                            self.index_stack.pop_stack_frame();
                             self.field_stack
                                 .pop_stack_frame()
                                 .into_iter()
@@ -680,7 +902,7 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
                                 });
                         }
                     } else {
-                        let start = self.pop_index();
+                        let start = self.pop_index()?;
                         let end = self.current_return_vals;
                         //check whether we need to pad some return values
                         //if we return an array with different sizes
@@ -731,22 +953,36 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
                                 });
                         }
                     }
-                    let (old_ip, old_return_vals, mut old_vars, old_body, shared_return_vals) =
-                        self.functions_ctx.pop().consume();
+                    let (
+                        old_ip,
+                        old_return_vals,
+                        mut old_vars,
+                        old_var_arrays,
+                        old_body,
+                        shared_return_vals,
+                    ) = self.functions_ctx.pop().consume();
                     ip = old_ip;
                     self.current_return_vals = old_return_vals;
                     current_shared_ret_vals = shared_return_vals;
                     std::mem::swap(&mut current_vars, &mut old_vars);
+                    current_var_arrays = old_var_arrays;
                     current_body = old_body;
                 }
                 op_codes::MpcOpCode::ReturnSharedIfFun => {
                     self.handle_shared_fun_return(protocol, &current_shared_ret_vals)?;
-                    let (old_ip, old_return_vals, mut old_vars, old_body, shared_return_vals) =
-                        self.functions_ctx.pop().consume();
+                    let (
+                        old_ip,
+                        old_return_vals,
+                        mut old_vars,
+                        old_var_arrays,
+                        old_body,
+                        shared_return_vals,
+                    ) = self.functions_ctx.pop().consume();
                     ip = old_ip;
                     self.current_return_vals = old_return_vals;
                     current_shared_ret_vals = shared_return_vals;
                     std::mem::swap(&mut current_vars, &mut old_vars);
+                    current_var_arrays = old_var_arrays;
                     current_body = old_body;
                 }
                 op_codes::MpcOpCode::Log => {
@@ -783,6 +1019,9 @@ impl<F: PrimeField, C: VmCircomWitnessExtension<F>> Component<F, C> {
         protocol: &mut C,
         current_shared_ret_vals: &[(C::VmType, Vec<C::VmType>)],
     ) -> Result<()> {
+        //we return for sure, therefore we leave the stack frames of the function
+        self.index_stack.pop_stack_frame();
+        self.field_stack.pop_stack_frame();
         let mut acc = vec![protocol.public_zero(); self.current_return_vals];
         for (cond, maybe_ret_vals) in current_shared_ret_vals {
             for (acc, x) in izip!(
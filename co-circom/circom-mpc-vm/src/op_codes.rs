@@ -103,12 +103,16 @@ pub enum MpcOpCode {
     BoolOr,
     /// Pops two boolean values from the field stack, computes their boolean AND, and pushes the result onto the stack.
     BoolAnd,
+    /// Pops a boolean value from the field stack, computes its boolean NOT, and pushes the result onto the stack.
+    BoolNot,
     /// Pops two elements from the field stack, computes their bitwise OR, and pushes the result onto the stack.
     BitOr,
     /// Pops two elements from the field stack, computes their bitwise AND, and pushes the result onto the stack.
     BitAnd,
     /// Pops two elements from the field stack, computes their bitwise XOR, and pushes the result onto the stack.
     BitXOr,
+    /// Pops an element from the field stack, computes its bitwise NOT over the bit size of the field, and pushes the result onto the stack.
+    BitNot,
     /// Pops two elements from the field stack, shifts the first popped value right by the number of bits specified by the second, and pushes the result onto the stack.
     ShiftR,
     /// Pops two elements from the field stack, shifts the first popped value left by the number of bits specified by the second, and pushes the result onto the stack.
@@ -119,8 +123,8 @@ pub enum MpcOpCode {
     AddIndex,
     /// Pops an element from the field stack, tries to cast it to a `usize`, and stores it on the index stack.
     ///
-    /// Returns an error if this is not possible. This typically happens if the target field element is a
-    /// secret shared value, which would leak the value.
+    /// If the element is a secret shared value, it is stored as a shared index instead. Shared indices can
+    /// only be used to load vars, store vars, and load signals.
     ToIndex,
     /// Jumps backwards by the specified number of lines in the [`CodeBlock`].
     JumpBack(usize),
@@ -175,9 +179,11 @@ impl std::fmt::Display for MpcOpCode {
             MpcOpCode::Neq => "NOT_EQUAL_OP".to_owned(),
             MpcOpCode::BoolOr => "BOOL_OR_OP".to_owned(),
             MpcOpCode::BoolAnd => "BOOL_AND_OP".to_owned(),
+            MpcOpCode::BoolNot => "BOOL_NOT_OP".to_owned(),
             MpcOpCode::BitOr => "BIT_OR_OP".to_owned(),
             MpcOpCode::BitAnd => "BIT_AND_OP".to_owned(),
             MpcOpCode::BitXOr => "BIT_XOR_OP".to_owned(),
+            MpcOpCode::BitNot => "BIT_NOT_OP".to_owned(),
             MpcOpCode::ShiftR => "RIGHT_SHIFT_OP".to_owned(),
             MpcOpCode::ShiftL => "LEFT_SHIFT_OP".to_owned(),
             MpcOpCode::AddIndex => "ADD_INDEX_OP".to_owned(),
@@ -15,7 +15,8 @@ use eyre::Result;
 /// A template declaration.
 ///
 /// Stores all necessary information to create a component, including the [`CodeBlock`],
-/// the number of input signals, sub-components, and vars.
+/// the number of input signals, own signals, sub-components, and vars. Additionally, it stores the
+/// (start, size) of the var and signal arrays, which bound accesses with shared indices.
 ///
 /// > **Warning**: Users should usually not interact directly with this struct. It is only public because the
 /// > compiler requires these declarations, and the compiler is a separate crate due to licensing constraints.
@@ -23,8 +24,11 @@ use eyre::Result;
 pub struct TemplateDecl {
     pub(crate) symbol: String,
     pub(crate) input_signals: usize,
+    pub(crate) signals: usize,
     pub(crate) sub_components: usize,
     pub(crate) vars: usize,
+    pub(crate) var_arrays: Arc<Vec<(usize, usize)>>,
+    pub(crate) signal_arrays: Vec<(usize, usize)>,
     pub(crate) mappings: Vec<usize>,
     pub(crate) body: Arc<CodeBlock>,
 }
@@ -34,16 +38,22 @@ impl TemplateDecl {
     pub fn new(
         symbol: String,
         input_signals: usize,
+        signals: usize,
         sub_components: usize,
         vars: usize,
+        var_arrays: Vec<(usize, usize)>,
+        signal_arrays: Vec<(usize, usize)>,
         mappings: Vec<usize>,
         body: CodeBlock,
     ) -> Self {
         Self {
             symbol,
             input_signals,
+            signals,
             sub_components,
             vars,
+            var_arrays: Arc::new(var_arrays),
+            signal_arrays,
             mappings,
             body: Arc::new(body),
         }
@@ -53,7 +63,7 @@ impl TemplateDecl {
 /// An unconstrained function declaration.
 ///
 /// Stores all necessary information to call the function, including the [`CodeBlock`],
-/// the number of params, and vars.
+/// the number of params, vars, and the (start, size) of the var arrays.
 ///
 /// > **Warning**: Users should usually not interact directly with this struct. It is only public because the
 /// > compiler requires these declarations, and the compiler is a separate crate due to licensing constraints.
@@ -61,15 +71,22 @@ impl TemplateDecl {
 pub struct FunDecl {
     pub(crate) num_params: usize,
     pub(crate) vars: usize,
+    pub(crate) var_arrays: Arc<Vec<(usize, usize)>>,
     pub(crate) body: Arc<CodeBlock>,
 }
 
 impl FunDecl {
     /// Creates a new function declaration. Only the MPC-compiler should use this method!
-    pub fn new(num_params: usize, vars: usize, body: CodeBlock) -> Self {
+    pub fn new(
+        num_params: usize,
+        vars: usize,
+        var_arrays: Vec<(usize, usize)>,
+        body: CodeBlock,
+    ) -> Self {
         Self {
             num_params,
             vars,
+            var_arrays: Arc::new(var_arrays),
             body: Arc::new(body),
         }
     }
@@ -1,7 +1,8 @@
 use std::{fmt, io};
 
 use ark_ff::PrimeField;
-use mpc_core::lut::LookupTableProvider;
+use mpc_core::{gadgets::poseidon2::Poseidon2, lut::LookupTableProvider};
+use num_bigint::BigUint;
 
 pub(super) mod plain;
 pub(super) mod rep3;
@@ -81,6 +82,223 @@ pub trait NoirWitnessExtensionProtocol<F: PrimeField> {
         lut: &mut <Self::Lookup as LookupTableProvider<F>>::SecretSharedMap,
     ) -> io::Result<()>;
 
+    /// Returns all values stored in a LUT, ordered by their index. This is used to provide
+    /// memory blocks as inputs to unconstrained (Brillig) functions.
+    fn get_lut_values(
+        lut: &<Self::Lookup as LookupTableProvider<F>>::SecretSharedMap,
+    ) -> Vec<Self::AcvmType>;
+
+    /// Adds two ACVM-types: \[c\] = \[lhs\] + \[rhs\].
+    fn add(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> Self::AcvmType;
+
+    /// Subtracts two ACVM-types: \[c\] = \[lhs\] - \[rhs\].
+    fn sub(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> Self::AcvmType;
+
+    /// Multiplies two ACVM-types: \[c\] = \[lhs\] * \[rhs\].
+    fn mul(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> io::Result<Self::AcvmType>;
+
+    /// Divides two ACVM-types in the field: \[c\] = \[lhs\] / \[rhs\].
+    fn div(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> eyre::Result<Self::AcvmType>;
+
+    /// Computes a CMUX: If \[cond\] is 1, returns \[truthy\], otherwise returns \[falsy\]. The condition must be 0 or 1.
+    fn cmux(
+        &mut self,
+        cond: Self::AcvmType,
+        truthy: Self::AcvmType,
+        falsy: Self::AcvmType,
+    ) -> io::Result<Self::AcvmType>;
+
+    /// Returns 1 if \[lhs\] == \[rhs\] and 0 otherwise.
+    fn equal(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType)
+        -> eyre::Result<Self::AcvmType>;
+
+    /// Returns 1 if \[lhs\] < \[rhs\] and 0 otherwise. The values are compared as unsigned integers.
+    fn lt(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> eyre::Result<Self::AcvmType>;
+
+    /// Reduces an ACVM-type modulo 2^bit_size: \[c\] = \[a\] mod 2^bit_size.
+    fn truncate(&mut self, a: Self::AcvmType, bit_size: u32) -> eyre::Result<Self::AcvmType>;
+
+    /// Computes the integer division \[lhs\] / \[rhs\] of two unsigned integers with at most bit_size bits.
+    /// Fails for a public divisor of zero. A shared divisor of zero cannot be detected without opening it and
+    /// results in a value of at most bit_size bits, but not in an error.
+    fn int_div(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType>;
+
+    /// Computes the bitwise AND of two unsigned integers with at most bit_size bits.
+    fn bitwise_and(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType>;
+
+    /// Computes the bitwise OR of two unsigned integers with at most bit_size bits.
+    fn bitwise_or(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType>;
+
+    /// Computes the bitwise XOR of two unsigned integers with at most bit_size bits.
+    fn bitwise_xor(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType>;
+
+    /// Shifts an unsigned integer with at most bit_size bits to the right by a public amount.
+    fn shift_right(
+        &mut self,
+        a: Self::AcvmType,
+        shift: u32,
+        bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType>;
+
+    /// Decomposes an unsigned integer with at most total_bit_size bits into chunks of at most decompose_bit_size bits. The chunks are ordered from the least significant to the most significant one.
+    fn decompose(
+        &mut self,
+        input: Self::AcvmType,
+        total_bit_size: usize,
+        decompose_bit_size: usize,
+    ) -> io::Result<Vec<Self::AcvmType>>;
+
+    /// Slices an unsigned integer with at most input_bit_size bits with respect to the given public bases, i.e., input = s_0 + b_0 * (s_1 + b_1 * (s_2 + ...)). Returns the slices s_i.
+    fn slice_variable_bases(
+        &mut self,
+        input: Self::AcvmType,
+        input_bit_size: usize,
+        bases: &[u64],
+    ) -> io::Result<Vec<Self::AcvmType>>;
+
+    /// Translates a shared index into a shared one-hot vector of size len, i.e., the resulting vector is 1 at the position of the index and 0 everywhere else.
+    fn one_hot_vector_from_shared_index(
+        &mut self,
+        index: Self::ArithmeticShare,
+        len: usize,
+    ) -> io::Result<Vec<Self::ArithmeticShare>>;
+
+    /// Sorts a vector of unsigned integers, where only the lowest bit_size bits are considered.
+    fn sort(
+        &mut self,
+        inputs: &[Self::AcvmType],
+        bit_size: usize,
+    ) -> io::Result<Vec<Self::AcvmType>>;
+
+    /// Computes the SHA256 compression function on the 8 32-bit words of the state and the 16 32-bit words of the message block. Returns the new state.
+    fn sha256_compression(
+        &mut self,
+        state: &[Self::AcvmType; 8],
+        message: &[Self::AcvmType; 16],
+    ) -> eyre::Result<Vec<Self::AcvmType>>;
+
+    /// Computes the Keccak-f[1600] permutation on the 25 64-bit lanes of the state. Returns the new state.
+    fn keccakf1600(&mut self, state: &[Self::AcvmType; 25]) -> eyre::Result<Vec<Self::AcvmType>>;
+
+    /// Computes the Blake2s compression function on the 8 32-bit words of the state and the 16 32-bit words of the message block. The counter is the number of bytes hashed so far, including the current block. Returns the new state.
+    fn blake2s_compression(
+        &mut self,
+        state: &[Self::AcvmType; 8],
+        message: &[Self::AcvmType; 16],
+        counter: u64,
+        last_block: bool,
+    ) -> eyre::Result<Vec<Self::AcvmType>>;
+
+    /// Computes the Blake3 compression function on the 8 32-bit words of the chaining value and the 16 32-bit words of the message block, where block_len is the number of message bytes in the block. Returns the new chaining value.
+    fn blake3_compression(
+        &mut self,
+        chaining_value: &[Self::AcvmType; 8],
+        message: &[Self::AcvmType; 16],
+        counter: u64,
+        block_len: u32,
+        flags: u32,
+    ) -> eyre::Result<Vec<Self::AcvmType>>;
+
+    /// Encrypts the plaintext with AES-128 in CBC mode under the 16 bytes of the key and the IV, where each value is a byte. The plaintext has to be padded already, i.e., its length is a multiple of 16. Returns the ciphertext bytes.
+    fn aes128_encrypt_cbc(
+        &mut self,
+        plaintext: &[Self::AcvmType],
+        iv: &[Self::AcvmType; 16],
+        key: &[Self::AcvmType; 16],
+    ) -> eyre::Result<Vec<Self::AcvmType>>;
+
+    /// Computes (a * b + sum(to_add) - sum(to_sub)) / modulus over the integers, where all values are non-native integers given as little-endian limbs of limb_bits bits. The limbs of the inputs may have up to input_limb_bits bits, to_add and to_sub may contain the limbs of multiple values, and the numerator is required to be non-negative. Returns the limbs of the quotient and the remainder, each consisting of as many limbs as a.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    fn non_native_mul_add_div_rem(
+        &mut self,
+        a: &[Self::AcvmType],
+        b: &[Self::AcvmType],
+        to_add: &[Self::AcvmType],
+        to_sub: &[Self::AcvmType],
+        modulus: &BigUint,
+        limb_bits: usize,
+        input_limb_bits: usize,
+    ) -> eyre::Result<(Vec<Self::AcvmType>, Vec<Self::AcvmType>)>;
+
+    /// Computes the inverse of the non-native integer a modulo the prime modulus, where a is given as little-endian limbs of limb_bits bits, each having up to input_limb_bits bits. Zero is mapped to zero. Returns the limbs of the inverse.
+    fn non_native_inverse(
+        &mut self,
+        a: &[Self::AcvmType],
+        modulus: &BigUint,
+        limb_bits: usize,
+        input_limb_bits: usize,
+    ) -> eyre::Result<Vec<Self::AcvmType>>;
+
+    /// Computes the external matrix multiplication of Poseidon2 on the state in place. Since this is a linear operation, it does not require communication.
+    fn poseidon2_matmul_external_inplace<const T: usize, const D: u64>(
+        &self,
+        input: &mut [Self::AcvmType; T],
+    );
+
+    /// Computes the external round r of Poseidon2 (i.e., round constants, S-box on the full state and external matrix multiplication) on the state in place.
+    fn poseidon2_external_round_inplace<const T: usize, const D: u64>(
+        &mut self,
+        input: &mut [Self::AcvmType; T],
+        r: usize,
+        poseidon2: &Poseidon2<F, T, D>,
+    ) -> io::Result<()>;
+
+    /// Computes the internal round r of Poseidon2 (i.e., round constant, S-box on the first element and internal matrix multiplication) on the state in place.
+    fn poseidon2_internal_round_inplace<const T: usize, const D: u64>(
+        &mut self,
+        input: &mut [Self::AcvmType; T],
+        r: usize,
+        poseidon2: &Poseidon2<F, T, D>,
+    ) -> io::Result<()>;
+
+    /// Computes the Poseidon2 permutation on the state in place.
+    fn poseidon2_permutation_inplace<const T: usize, const D: u64>(
+        &mut self,
+        input: &mut [Self::AcvmType; T],
+        poseidon2: &Poseidon2<F, T, D>,
+    ) -> io::Result<()> {
+        // Linear layer at beginning
+        self.poseidon2_matmul_external_inplace::<T, D>(input);
+
+        // First set of external rounds
+        for r in 0..poseidon2.params.rounds_f_beginning {
+            self.poseidon2_external_round_inplace(input, r, poseidon2)?;
+        }
+
+        // Internal rounds
+        for r in 0..poseidon2.params.rounds_p {
+            self.poseidon2_internal_round_inplace(input, r, poseidon2)?;
+        }
+
+        // Remaining external rounds
+        for r in poseidon2.params.rounds_f_beginning
+            ..poseidon2.params.rounds_f_beginning + poseidon2.params.rounds_f_end
+        {
+            self.poseidon2_external_round_inplace(input, r, poseidon2)?;
+        }
+        Ok(())
+    }
+
     /// Returns true if the value is shared
     fn is_shared(a: &Self::AcvmType) -> bool;
 
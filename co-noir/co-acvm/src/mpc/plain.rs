@@ -3,7 +3,12 @@ use std::io;
 use std::marker::PhantomData;
 
 use ark_ff::PrimeField;
-use mpc_core::lut::{LookupTableProvider, PlainLookupTableProvider};
+use mpc_core::{
+    gadgets::{aes, blake, poseidon2::Poseidon2},
+    lut::{LookupTableProvider, PlainLookupTableProvider},
+};
+use num_bigint::BigUint;
+use sha2::digest::generic_array::GenericArray;
 
 use super::NoirWitnessExtensionProtocol;
 
@@ -13,6 +18,55 @@ pub struct PlainAcvmSolver<F: PrimeField> {
     phantom_data: PhantomData<F>,
 }
 
+impl<F: PrimeField> PlainAcvmSolver<F> {
+    /// Composes little-endian limbs of limb_bits bits to a non-native integer. The limbs may be larger than limb_bits bits.
+    fn compose_limbs(limbs: &[F], limb_bits: usize) -> BigUint {
+        limbs.iter().rev().fold(BigUint::from(0u64), |acc, limb| {
+            let limb: BigUint = (*limb).into();
+            (acc << limb_bits) + limb
+        })
+    }
+
+    /// Splits a non-native integer into num_limbs little-endian limbs of limb_bits bits.
+    fn split_into_limbs(
+        mut value: BigUint,
+        num_limbs: usize,
+        limb_bits: usize,
+    ) -> eyre::Result<Vec<F>> {
+        if value.bits() > (num_limbs * limb_bits) as u64 {
+            eyre::bail!("non-native value does not fit into {num_limbs} limbs of {limb_bits} bits");
+        }
+        let mask = (BigUint::from(1u64) << limb_bits) - 1u64;
+        let mut result = Vec::with_capacity(num_limbs);
+        for _ in 0..num_limbs {
+            result.push(F::from(&value & &mask));
+            value >>= limb_bits;
+        }
+        Ok(result)
+    }
+
+    /// Translates the given field elements into 32-bit words, failing if one of them does not fit into 32 bits.
+    fn words_to_u32<const N: usize>(name: &str, words: &[F; N]) -> eyre::Result<[u32; N]> {
+        let mut result = [0u32; N];
+        for (des, word) in result.iter_mut().zip(words.iter()) {
+            let word: BigUint = (*word).into();
+            *des = u32::try_from(word).map_err(|_| eyre::eyre!("{name}: input is not a u32"))?;
+        }
+        Ok(result)
+    }
+
+    /// Translates the given field elements into bytes, failing if one of them does not fit into 8 bits.
+    fn bytes_to_u8(name: &str, bytes: &[F]) -> eyre::Result<Vec<u8>> {
+        bytes
+            .iter()
+            .map(|byte| {
+                let byte: BigUint = (*byte).into();
+                u8::try_from(byte).map_err(|_| eyre::eyre!("{name}: input is not a u8"))
+            })
+            .collect()
+    }
+}
+
 impl<F: PrimeField> NoirWitnessExtensionProtocol<F> for PlainAcvmSolver<F> {
     type Lookup = PlainLookupTableProvider<F>;
     type ArithmeticShare = F;
@@ -82,6 +136,349 @@ impl<F: PrimeField> NoirWitnessExtensionProtocol<F> for PlainAcvmSolver<F> {
         self.plain_lut.write_to_lut(index, value, map)
     }
 
+    fn get_lut_values(lut: &HashMap<F, F>) -> Vec<Self::AcvmType> {
+        (0..lut.len())
+            .map(|idx| lut[&F::from(u64::try_from(idx).expect("usize fits into u64"))])
+            .collect()
+    }
+
+    fn add(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> Self::AcvmType {
+        lhs + rhs
+    }
+
+    fn sub(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> Self::AcvmType {
+        lhs - rhs
+    }
+
+    fn mul(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> io::Result<Self::AcvmType> {
+        Ok(lhs * rhs)
+    }
+
+    fn div(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> eyre::Result<Self::AcvmType> {
+        if rhs.is_zero() {
+            eyre::bail!("cannot divide by zero");
+        }
+        Ok(lhs / rhs)
+    }
+
+    fn cmux(
+        &mut self,
+        cond: Self::AcvmType,
+        truthy: Self::AcvmType,
+        falsy: Self::AcvmType,
+    ) -> io::Result<Self::AcvmType> {
+        assert!(cond.is_one() || cond.is_zero());
+        if cond.is_one() {
+            Ok(truthy)
+        } else {
+            Ok(falsy)
+        }
+    }
+
+    fn equal(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+    ) -> eyre::Result<Self::AcvmType> {
+        Ok(F::from(lhs == rhs))
+    }
+
+    fn lt(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> eyre::Result<Self::AcvmType> {
+        Ok(F::from(lhs < rhs))
+    }
+
+    fn truncate(&mut self, a: Self::AcvmType, bit_size: u32) -> eyre::Result<Self::AcvmType> {
+        let a: BigUint = a.into();
+        let mask = (BigUint::from(1u64) << bit_size) - 1u64;
+        Ok(F::from(a & mask))
+    }
+
+    fn int_div(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        _bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType> {
+        if rhs.is_zero() {
+            eyre::bail!("cannot divide by zero");
+        }
+        let lhs: BigUint = lhs.into();
+        let rhs: BigUint = rhs.into();
+        Ok(F::from(lhs / rhs))
+    }
+
+    fn bitwise_and(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        _bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType> {
+        let lhs: BigUint = lhs.into();
+        let rhs: BigUint = rhs.into();
+        Ok(F::from(lhs & rhs))
+    }
+
+    fn bitwise_or(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        _bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType> {
+        let lhs: BigUint = lhs.into();
+        let rhs: BigUint = rhs.into();
+        Ok(F::from(lhs | rhs))
+    }
+
+    fn bitwise_xor(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        _bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType> {
+        let lhs: BigUint = lhs.into();
+        let rhs: BigUint = rhs.into();
+        Ok(F::from(lhs ^ rhs))
+    }
+
+    fn shift_right(
+        &mut self,
+        a: Self::AcvmType,
+        shift: u32,
+        _bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType> {
+        let a: BigUint = a.into();
+        Ok(F::from(a >> shift))
+    }
+
+    fn decompose(
+        &mut self,
+        input: Self::AcvmType,
+        total_bit_size: usize,
+        decompose_bit_size: usize,
+    ) -> io::Result<Vec<Self::AcvmType>> {
+        let mut input: BigUint = input.into();
+        let mask = (BigUint::from(1u64) << decompose_bit_size) - 1u64;
+        let num_decomps = total_bit_size.div_ceil(decompose_bit_size);
+
+        let mut result = Vec::with_capacity(num_decomps);
+        for _ in 0..num_decomps {
+            result.push(F::from(&input & &mask));
+            input >>= decompose_bit_size;
+        }
+        Ok(result)
+    }
+
+    fn slice_variable_bases(
+        &mut self,
+        input: Self::AcvmType,
+        _input_bit_size: usize,
+        bases: &[u64],
+    ) -> io::Result<Vec<Self::AcvmType>> {
+        let mut input: BigUint = input.into();
+
+        let mut result = Vec::with_capacity(bases.len());
+        for &base in bases {
+            result.push(F::from(&input % base));
+            input /= base;
+        }
+        Ok(result)
+    }
+
+    fn one_hot_vector_from_shared_index(
+        &mut self,
+        index: Self::ArithmeticShare,
+        len: usize,
+    ) -> io::Result<Vec<Self::ArithmeticShare>> {
+        let index: BigUint = index.into();
+        let index = usize::try_from(index).map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidInput, "Index does not fit into usize")
+        })?;
+        if index >= len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Index is out of bounds",
+            ));
+        }
+
+        let mut result = vec![F::zero(); len];
+        result[index] = F::one();
+        Ok(result)
+    }
+
+    fn sort(
+        &mut self,
+        inputs: &[Self::AcvmType],
+        bit_size: usize,
+    ) -> io::Result<Vec<Self::AcvmType>> {
+        let mask = (BigUint::from(1u64) << bit_size) - 1u64;
+        let mut result = inputs
+            .iter()
+            .map(|x| BigUint::from(*x) & &mask)
+            .collect::<Vec<_>>();
+        result.sort();
+        Ok(result.into_iter().map(F::from).collect())
+    }
+
+    fn sha256_compression(
+        &mut self,
+        state: &[Self::AcvmType; 8],
+        message: &[Self::AcvmType; 16],
+    ) -> eyre::Result<Vec<Self::AcvmType>> {
+        let to_u32 = |x: &F| -> eyre::Result<u32> {
+            let x: BigUint = (*x).into();
+            u32::try_from(x).map_err(|_| eyre::eyre!("sha256_compression: input is not a u32"))
+        };
+
+        let mut state_u32 = [0u32; 8];
+        for (s, x) in state_u32.iter_mut().zip(state.iter()) {
+            *s = to_u32(x)?;
+        }
+        let mut block = [0u8; 64];
+        for (chunk, x) in block.chunks_exact_mut(4).zip(message.iter()) {
+            chunk.copy_from_slice(&to_u32(x)?.to_be_bytes());
+        }
+
+        sha2::compress256(&mut state_u32, &[GenericArray::clone_from_slice(&block)]);
+        Ok(state_u32.into_iter().map(F::from).collect())
+    }
+
+    fn keccakf1600(&mut self, state: &[Self::AcvmType; 25]) -> eyre::Result<Vec<Self::AcvmType>> {
+        let mut state_u64 = [0u64; 25];
+        for (s, x) in state_u64.iter_mut().zip(state.iter()) {
+            let x: BigUint = (*x).into();
+            *s = u64::try_from(x).map_err(|_| eyre::eyre!("keccakf1600: input is not a u64"))?;
+        }
+
+        keccak::f1600(&mut state_u64);
+        Ok(state_u64.into_iter().map(F::from).collect())
+    }
+
+    fn blake2s_compression(
+        &mut self,
+        state: &[Self::AcvmType; 8],
+        message: &[Self::AcvmType; 16],
+        counter: u64,
+        last_block: bool,
+    ) -> eyre::Result<Vec<Self::AcvmType>> {
+        let state = Self::words_to_u32("blake2s_compression", state)?;
+        let message = Self::words_to_u32("blake2s_compression", message)?;
+        let result = blake::blake2s_compression(&state, &message, counter, last_block);
+        Ok(result.into_iter().map(F::from).collect())
+    }
+
+    fn blake3_compression(
+        &mut self,
+        chaining_value: &[Self::AcvmType; 8],
+        message: &[Self::AcvmType; 16],
+        counter: u64,
+        block_len: u32,
+        flags: u32,
+    ) -> eyre::Result<Vec<Self::AcvmType>> {
+        let chaining_value = Self::words_to_u32("blake3_compression", chaining_value)?;
+        let message = Self::words_to_u32("blake3_compression", message)?;
+        let result =
+            blake::blake3_compression(&chaining_value, &message, counter, block_len, flags);
+        Ok(result.into_iter().map(F::from).collect())
+    }
+
+    fn aes128_encrypt_cbc(
+        &mut self,
+        plaintext: &[Self::AcvmType],
+        iv: &[Self::AcvmType; 16],
+        key: &[Self::AcvmType; 16],
+    ) -> eyre::Result<Vec<Self::AcvmType>> {
+        let plaintext = Self::bytes_to_u8("aes128_encrypt", plaintext)?;
+        let iv = Self::bytes_to_u8("aes128_encrypt", iv)?;
+        let key = Self::bytes_to_u8("aes128_encrypt", key)?;
+        if plaintext.len() % aes::AES128_BLOCK_BYTES != 0 {
+            eyre::bail!("aes128_encrypt: the plaintext is not padded");
+        }
+        let iv = iv.try_into().expect("iv has 16 bytes");
+        let key = key.try_into().expect("key has 16 bytes");
+        let result = aes::aes128_encrypt_cbc(&plaintext, &iv, &key);
+        Ok(result.into_iter().map(F::from).collect())
+    }
+
+    fn non_native_mul_add_div_rem(
+        &mut self,
+        a: &[Self::AcvmType],
+        b: &[Self::AcvmType],
+        to_add: &[Self::AcvmType],
+        to_sub: &[Self::AcvmType],
+        modulus: &BigUint,
+        limb_bits: usize,
+        _input_limb_bits: usize,
+    ) -> eyre::Result<(Vec<Self::AcvmType>, Vec<Self::AcvmType>)> {
+        let num_limbs = a.len();
+        if num_limbs == 0
+            || b.len() != num_limbs
+            || to_add.len() % num_limbs != 0
+            || to_sub.len() % num_limbs != 0
+        {
+            eyre::bail!(
+                "non_native_mul_add_div_rem: all values must have the same number of limbs"
+            );
+        }
+
+        let mut numerator = Self::compose_limbs(a, limb_bits) * Self::compose_limbs(b, limb_bits);
+        for limbs in to_add.chunks_exact(num_limbs) {
+            numerator += Self::compose_limbs(limbs, limb_bits);
+        }
+        let to_sub = to_sub
+            .chunks_exact(num_limbs)
+            .map(|limbs| Self::compose_limbs(limbs, limb_bits))
+            .sum::<BigUint>();
+        if to_sub > numerator {
+            eyre::bail!("non_native_mul_add_div_rem: the numerator is negative");
+        }
+        numerator -= to_sub;
+
+        let quotient = Self::split_into_limbs(&numerator / modulus, num_limbs, limb_bits)?;
+        let remainder = Self::split_into_limbs(numerator % modulus, num_limbs, limb_bits)?;
+        Ok((quotient, remainder))
+    }
+
+    fn non_native_inverse(
+        &mut self,
+        a: &[Self::AcvmType],
+        modulus: &BigUint,
+        limb_bits: usize,
+        _input_limb_bits: usize,
+    ) -> eyre::Result<Vec<Self::AcvmType>> {
+        // Fermat's little theorem, which maps zero to zero
+        let num_limbs = a.len();
+        let a = Self::compose_limbs(a, limb_bits);
+        let inverse = a.modpow(&(modulus - 2u64), modulus);
+        Self::split_into_limbs(inverse, num_limbs, limb_bits)
+    }
+
+    fn poseidon2_matmul_external_inplace<const T: usize, const D: u64>(
+        &self,
+        input: &mut [Self::AcvmType; T],
+    ) {
+        Poseidon2::<F, T, D>::matmul_external(input);
+    }
+
+    fn poseidon2_external_round_inplace<const T: usize, const D: u64>(
+        &mut self,
+        input: &mut [Self::AcvmType; T],
+        r: usize,
+        poseidon2: &Poseidon2<F, T, D>,
+    ) -> io::Result<()> {
+        poseidon2.external_round(input, r);
+        Ok(())
+    }
+
+    fn poseidon2_internal_round_inplace<const T: usize, const D: u64>(
+        &mut self,
+        input: &mut [Self::AcvmType; T],
+        r: usize,
+        poseidon2: &Poseidon2<F, T, D>,
+    ) -> io::Result<()> {
+        poseidon2.internal_round(input, r);
+        Ok(())
+    }
+
     fn is_shared(_: &Self::AcvmType) -> bool {
         false
     }
@@ -2,16 +2,19 @@ use std::marker::PhantomData;
 
 use ark_ff::PrimeField;
 use itertools::{izip, Itertools};
-use mpc_core::protocols::rep3::arithmetic;
+use mpc_core::protocols::rep3::{arithmetic, binary, conversion, gadgets, yao};
 use mpc_core::{
+    gadgets::poseidon2::Poseidon2,
     lut::LookupTableProvider,
     protocols::rep3::{
         lut::NaiveRep3LookupTable,
         network::{IoContext, Rep3Network},
-        Rep3PrimeFieldShare,
+        Rep3BigUintShare, Rep3PrimeFieldShare,
     },
 };
+use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
+use std::array;
 
 use super::plain::PlainAcvmSolver;
 use super::NoirWitnessExtensionProtocol;
@@ -25,18 +28,73 @@ pub struct Rep3AcvmSolver<F: PrimeField, N: Rep3Network> {
 }
 
 impl<F: PrimeField, N: Rep3Network> Rep3AcvmSolver<F, N> {
-    // TODO remove unwrap
-    pub(crate) fn new(network: N) -> Self {
+    pub fn new(network: N) -> std::io::Result<Self> {
         let plain_solver = PlainAcvmSolver::<F>::default();
-        let mut io_context = IoContext::init(network).unwrap();
-        let forked = io_context.fork().unwrap();
-        Self {
+        let mut io_context = IoContext::init(network)?;
+        let forked = io_context.fork()?;
+        Ok(Self {
             lut_provider: NaiveRep3LookupTable::new(forked),
             io_context,
             plain_solver,
             phantom_data: PhantomData,
+        })
+    }
+
+    /// Consumes the solver and returns the underlying network, e.g., to reuse it for the prover.
+    pub fn into_network(self) -> N {
+        self.io_context.network
+    }
+
+    fn to_binary(&mut self, a: Rep3AcvmType<F>) -> std::io::Result<Rep3BigUintShare<F>> {
+        match a {
+            Rep3AcvmType::Public(public) => Ok(binary::promote_to_trivial_share(
+                self.io_context.id,
+                &public.into(),
+            )),
+            Rep3AcvmType::Shared(shared) => conversion::a2b_selector(shared, &mut self.io_context),
         }
     }
+
+    fn to_shared(&self, a: Rep3AcvmType<F>) -> ArithmeticShare<F> {
+        match a {
+            Rep3AcvmType::Public(public) => {
+                arithmetic::promote_to_trivial_share(self.io_context.id, public)
+            }
+            Rep3AcvmType::Shared(shared) => shared,
+        }
+    }
+
+    fn bit_mask(bit_size: u32) -> BigUint {
+        (BigUint::from(1u64) << bit_size) - 1u64
+    }
+
+    fn poseidon2_sbox_shared<const D: u64>(
+        &mut self,
+        input: &mut [ArithmeticShare<F>],
+    ) -> std::io::Result<()> {
+        // Square-and-multiply over the bits of D, where the multiplications of all elements are batched
+        let mut result = input.to_vec();
+        for bit in (0..D.ilog2()).rev() {
+            result = arithmetic::mul_vec(&result, &result, &mut self.io_context)?;
+            if (D >> bit) & 1 == 1 {
+                result = arithmetic::mul_vec(&result, input, &mut self.io_context)?;
+            }
+        }
+        input.copy_from_slice(&result);
+        Ok(())
+    }
+
+    fn poseidon2_matmul_shared<const T: usize>(
+        input: &mut [ArithmeticShare<F>; T],
+        matmul: impl Fn(&mut [F; T]),
+    ) {
+        // The matrix multiplications are linear, thus we can apply them to both components of the replicated shares
+        let mut a = input.map(|x| x.a);
+        let mut b = input.map(|x| x.b);
+        matmul(&mut a);
+        matmul(&mut b);
+        *input = array::from_fn(|i| ArithmeticShare::new(a[i], b[i]));
+    }
 }
 
 // TODO maybe we want to merge that with the Rep3VmType?? Atm we do not need
@@ -271,6 +329,698 @@ impl<F: PrimeField, N: Rep3Network> NoirWitnessExtensionProtocol<F> for Rep3Acvm
         }
     }
 
+    fn get_lut_values(
+        lut: &<Self::Lookup as LookupTableProvider<F>>::SecretSharedMap,
+    ) -> Vec<Self::AcvmType> {
+        lut.iter()
+            .map(|(_, value)| Rep3AcvmType::Shared(*value))
+            .collect()
+    }
+
+    fn add(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> Self::AcvmType {
+        let id = self.io_context.id;
+        match (lhs, rhs) {
+            (Rep3AcvmType::Public(lhs), Rep3AcvmType::Public(rhs)) => {
+                Rep3AcvmType::Public(lhs + rhs)
+            }
+            (Rep3AcvmType::Public(public), Rep3AcvmType::Shared(shared))
+            | (Rep3AcvmType::Shared(shared), Rep3AcvmType::Public(public)) => {
+                Rep3AcvmType::Shared(arithmetic::add_public(shared, public, id))
+            }
+            (Rep3AcvmType::Shared(lhs), Rep3AcvmType::Shared(rhs)) => {
+                Rep3AcvmType::Shared(arithmetic::add(lhs, rhs))
+            }
+        }
+    }
+
+    fn sub(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> Self::AcvmType {
+        let id = self.io_context.id;
+        match (lhs, rhs) {
+            (Rep3AcvmType::Public(lhs), Rep3AcvmType::Public(rhs)) => {
+                Rep3AcvmType::Public(lhs - rhs)
+            }
+            (Rep3AcvmType::Public(lhs), Rep3AcvmType::Shared(rhs)) => {
+                Rep3AcvmType::Shared(arithmetic::sub_public_by_shared(lhs, rhs, id))
+            }
+            (Rep3AcvmType::Shared(lhs), Rep3AcvmType::Public(rhs)) => {
+                Rep3AcvmType::Shared(arithmetic::sub_shared_by_public(lhs, rhs, id))
+            }
+            (Rep3AcvmType::Shared(lhs), Rep3AcvmType::Shared(rhs)) => {
+                Rep3AcvmType::Shared(arithmetic::sub(lhs, rhs))
+            }
+        }
+    }
+
+    fn mul(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+    ) -> std::io::Result<Self::AcvmType> {
+        let mut result = Rep3AcvmType::default();
+        self.solve_mul_term(F::one(), lhs, rhs, &mut result)?;
+        Ok(result)
+    }
+
+    fn div(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> eyre::Result<Self::AcvmType> {
+        // solve_equation computes -c/q_l
+        let neg_lhs = self.acvm_mul_with_public(-F::one(), lhs);
+        self.solve_equation(rhs, neg_lhs)
+    }
+
+    fn cmux(
+        &mut self,
+        cond: Self::AcvmType,
+        truthy: Self::AcvmType,
+        falsy: Self::AcvmType,
+    ) -> std::io::Result<Self::AcvmType> {
+        match cond {
+            Rep3AcvmType::Public(cond) => {
+                assert!(cond.is_one() || cond.is_zero());
+                if cond.is_one() {
+                    Ok(truthy)
+                } else {
+                    Ok(falsy)
+                }
+            }
+            Rep3AcvmType::Shared(cond) => {
+                let b_min_a = self.sub(truthy, falsy.clone());
+                let d = self.mul(cond.into(), b_min_a)?;
+                Ok(self.add(falsy, d))
+            }
+        }
+    }
+
+    fn equal(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+    ) -> eyre::Result<Self::AcvmType> {
+        let result = match (lhs, rhs) {
+            (Rep3AcvmType::Public(lhs), Rep3AcvmType::Public(rhs)) => {
+                Rep3AcvmType::Public(self.plain_solver.equal(lhs, rhs)?)
+            }
+            (Rep3AcvmType::Public(public), Rep3AcvmType::Shared(shared))
+            | (Rep3AcvmType::Shared(shared), Rep3AcvmType::Public(public)) => {
+                arithmetic::eq_public(shared, public, &mut self.io_context)?.into()
+            }
+            (Rep3AcvmType::Shared(lhs), Rep3AcvmType::Shared(rhs)) => {
+                arithmetic::eq(lhs, rhs, &mut self.io_context)?.into()
+            }
+        };
+        Ok(result)
+    }
+
+    fn lt(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> eyre::Result<Self::AcvmType> {
+        let result = match (lhs, rhs) {
+            (Rep3AcvmType::Public(lhs), Rep3AcvmType::Public(rhs)) => {
+                Rep3AcvmType::Public(self.plain_solver.lt(lhs, rhs)?)
+            }
+            (Rep3AcvmType::Public(lhs), Rep3AcvmType::Shared(rhs)) => {
+                arithmetic::gt_public(rhs, lhs, &mut self.io_context)?.into()
+            }
+            (Rep3AcvmType::Shared(lhs), Rep3AcvmType::Public(rhs)) => {
+                arithmetic::lt_public(lhs, rhs, &mut self.io_context)?.into()
+            }
+            (Rep3AcvmType::Shared(lhs), Rep3AcvmType::Shared(rhs)) => {
+                arithmetic::lt(lhs, rhs, &mut self.io_context)?.into()
+            }
+        };
+        Ok(result)
+    }
+
+    fn truncate(&mut self, a: Self::AcvmType, bit_size: u32) -> eyre::Result<Self::AcvmType> {
+        match a {
+            Rep3AcvmType::Public(a) => Ok(Rep3AcvmType::Public(
+                self.plain_solver.truncate(a, bit_size)?,
+            )),
+            Rep3AcvmType::Shared(a) => {
+                let bits = conversion::a2b_selector(a, &mut self.io_context)?;
+                let masked = binary::and_with_public(&bits, &Self::bit_mask(bit_size));
+                Ok(conversion::b2a_selector(&masked, &mut self.io_context)?.into())
+            }
+        }
+    }
+
+    fn int_div(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType> {
+        match (lhs, rhs) {
+            (Rep3AcvmType::Public(lhs), Rep3AcvmType::Public(rhs)) => Ok(Rep3AcvmType::Public(
+                self.plain_solver.int_div(lhs, rhs, bit_size)?,
+            )),
+            (Rep3AcvmType::Shared(lhs), Rep3AcvmType::Public(rhs)) => {
+                if rhs.is_zero() {
+                    eyre::bail!("cannot divide by zero");
+                }
+                let (quotient, _) =
+                    gadgets::int_div::int_div_rem_by_public(lhs, rhs, &mut self.io_context)?;
+                Ok(quotient.into())
+            }
+            (lhs, rhs) => {
+                let lhs = self.to_shared(lhs);
+                let rhs = self.to_shared(rhs);
+                let (quotient, _) = gadgets::int_div::int_div_rem(lhs, rhs, &mut self.io_context)?;
+                // A shared divisor of zero results in a quotient exceeding the bit size, so we reduce it
+                if bit_size < F::MODULUS_BIT_SIZE {
+                    self.truncate(quotient.into(), bit_size)
+                } else {
+                    Ok(quotient.into())
+                }
+            }
+        }
+    }
+
+    fn bitwise_and(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType> {
+        let result = match (lhs, rhs) {
+            (Rep3AcvmType::Public(lhs), Rep3AcvmType::Public(rhs)) => {
+                Rep3AcvmType::Public(self.plain_solver.bitwise_and(lhs, rhs, bit_size)?)
+            }
+            (Rep3AcvmType::Public(public), Rep3AcvmType::Shared(shared))
+            | (Rep3AcvmType::Shared(shared), Rep3AcvmType::Public(public)) => {
+                let bits = conversion::a2b_selector(shared, &mut self.io_context)?;
+                let result = binary::and_with_public(&bits, &public.into());
+                conversion::b2a_selector(&result, &mut self.io_context)?.into()
+            }
+            (lhs, rhs) => {
+                let lhs = self.to_binary(lhs)?;
+                let rhs = self.to_binary(rhs)?;
+                let result = binary::and(&lhs, &rhs, &mut self.io_context)?;
+                conversion::b2a_selector(&result, &mut self.io_context)?.into()
+            }
+        };
+        Ok(result)
+    }
+
+    fn bitwise_or(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType> {
+        let id = self.io_context.id;
+        let result = match (lhs, rhs) {
+            (Rep3AcvmType::Public(lhs), Rep3AcvmType::Public(rhs)) => {
+                Rep3AcvmType::Public(self.plain_solver.bitwise_or(lhs, rhs, bit_size)?)
+            }
+            (Rep3AcvmType::Public(public), Rep3AcvmType::Shared(shared))
+            | (Rep3AcvmType::Shared(shared), Rep3AcvmType::Public(public)) => {
+                let bits = conversion::a2b_selector(shared, &mut self.io_context)?;
+                let result = binary::or_public(&bits, &public.into(), id);
+                conversion::b2a_selector(&result, &mut self.io_context)?.into()
+            }
+            (lhs, rhs) => {
+                let lhs = self.to_binary(lhs)?;
+                let rhs = self.to_binary(rhs)?;
+                let result = binary::or(&lhs, &rhs, &mut self.io_context)?;
+                conversion::b2a_selector(&result, &mut self.io_context)?.into()
+            }
+        };
+        Ok(result)
+    }
+
+    fn bitwise_xor(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType> {
+        let id = self.io_context.id;
+        let result = match (lhs, rhs) {
+            (Rep3AcvmType::Public(lhs), Rep3AcvmType::Public(rhs)) => {
+                Rep3AcvmType::Public(self.plain_solver.bitwise_xor(lhs, rhs, bit_size)?)
+            }
+            (Rep3AcvmType::Public(public), Rep3AcvmType::Shared(shared))
+            | (Rep3AcvmType::Shared(shared), Rep3AcvmType::Public(public)) => {
+                let bits = conversion::a2b_selector(shared, &mut self.io_context)?;
+                let result = binary::xor_public(&bits, &public.into(), id);
+                conversion::b2a_selector(&result, &mut self.io_context)?.into()
+            }
+            (lhs, rhs) => {
+                let lhs = self.to_binary(lhs)?;
+                let rhs = self.to_binary(rhs)?;
+                let result = binary::xor(&lhs, &rhs);
+                conversion::b2a_selector(&result, &mut self.io_context)?.into()
+            }
+        };
+        Ok(result)
+    }
+
+    fn shift_right(
+        &mut self,
+        a: Self::AcvmType,
+        shift: u32,
+        bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType> {
+        match a {
+            Rep3AcvmType::Public(a) => Ok(Rep3AcvmType::Public(
+                self.plain_solver.shift_right(a, shift, bit_size)?,
+            )),
+            Rep3AcvmType::Shared(a) => {
+                let bits = conversion::a2b_selector(a, &mut self.io_context)?;
+                let shifted = binary::shift_r_public(&bits, F::from(shift));
+                Ok(conversion::b2a_selector(&shifted, &mut self.io_context)?.into())
+            }
+        }
+    }
+
+    fn decompose(
+        &mut self,
+        input: Self::AcvmType,
+        total_bit_size: usize,
+        decompose_bit_size: usize,
+    ) -> std::io::Result<Vec<Self::AcvmType>> {
+        match input {
+            Rep3AcvmType::Public(input) => Ok(self
+                .plain_solver
+                .decompose(input, total_bit_size, decompose_bit_size)?
+                .into_iter()
+                .map(Rep3AcvmType::Public)
+                .collect()),
+            Rep3AcvmType::Shared(input) => Ok(yao::decompose_arithmetic(
+                input,
+                &mut self.io_context,
+                total_bit_size,
+                decompose_bit_size,
+            )?
+            .into_iter()
+            .map(Rep3AcvmType::Shared)
+            .collect()),
+        }
+    }
+
+    fn slice_variable_bases(
+        &mut self,
+        input: Self::AcvmType,
+        input_bit_size: usize,
+        bases: &[u64],
+    ) -> std::io::Result<Vec<Self::AcvmType>> {
+        match input {
+            Rep3AcvmType::Public(input) => Ok(self
+                .plain_solver
+                .slice_variable_bases(input, input_bit_size, bases)?
+                .into_iter()
+                .map(Rep3AcvmType::Public)
+                .collect()),
+            Rep3AcvmType::Shared(input) => Ok(yao::slice_arithmetic_variable_bases(
+                input,
+                &mut self.io_context,
+                input_bit_size,
+                bases,
+            )?
+            .into_iter()
+            .map(Rep3AcvmType::Shared)
+            .collect()),
+        }
+    }
+
+    fn one_hot_vector_from_shared_index(
+        &mut self,
+        index: Self::ArithmeticShare,
+        len: usize,
+    ) -> std::io::Result<Vec<Self::ArithmeticShare>> {
+        gadgets::ohv::ohv_from_index(index, len, &mut self.io_context)
+    }
+
+    fn sort(
+        &mut self,
+        inputs: &[Self::AcvmType],
+        bit_size: usize,
+    ) -> std::io::Result<Vec<Self::AcvmType>> {
+        if inputs.iter().all(|x| !Self::is_shared(x)) {
+            let inputs = inputs
+                .iter()
+                .map(|x| Self::get_public(x).expect("Already checked it is public"))
+                .collect_vec();
+            return Ok(self
+                .plain_solver
+                .sort(&inputs, bit_size)?
+                .into_iter()
+                .map(Rep3AcvmType::Public)
+                .collect());
+        }
+
+        let inputs = inputs
+            .iter()
+            .map(|x| self.to_shared(x.to_owned()))
+            .collect_vec();
+        Ok(
+            gadgets::sort::batcher_odd_even_merge_sort_yao(
+                &inputs,
+                &mut self.io_context,
+                bit_size,
+            )?
+            .into_iter()
+            .map(Rep3AcvmType::Shared)
+            .collect(),
+        )
+    }
+
+    fn sha256_compression(
+        &mut self,
+        state: &[Self::AcvmType; 8],
+        message: &[Self::AcvmType; 16],
+    ) -> eyre::Result<Vec<Self::AcvmType>> {
+        if state
+            .iter()
+            .chain(message.iter())
+            .all(|x| !Self::is_shared(x))
+        {
+            let state = state
+                .clone()
+                .map(|x| Self::get_public(&x).expect("Already checked it is public"));
+            let message = message
+                .clone()
+                .map(|x| Self::get_public(&x).expect("Already checked it is public"));
+            return Ok(self
+                .plain_solver
+                .sha256_compression(&state, &message)?
+                .into_iter()
+                .map(Rep3AcvmType::Public)
+                .collect());
+        }
+
+        let state = state.clone().map(|x| self.to_shared(x));
+        let message = message.clone().map(|x| self.to_shared(x));
+        Ok(
+            yao::sha256_compression(&state, &message, &mut self.io_context)?
+                .into_iter()
+                .map(Rep3AcvmType::Shared)
+                .collect(),
+        )
+    }
+
+    fn keccakf1600(&mut self, state: &[Self::AcvmType; 25]) -> eyre::Result<Vec<Self::AcvmType>> {
+        if state.iter().all(|x| !Self::is_shared(x)) {
+            let state = state
+                .clone()
+                .map(|x| Self::get_public(&x).expect("Already checked it is public"));
+            return Ok(self
+                .plain_solver
+                .keccakf1600(&state)?
+                .into_iter()
+                .map(Rep3AcvmType::Public)
+                .collect());
+        }
+
+        let state = state.clone().map(|x| self.to_shared(x));
+        Ok(yao::keccakf1600(&state, &mut self.io_context)?
+            .into_iter()
+            .map(Rep3AcvmType::Shared)
+            .collect())
+    }
+
+    fn blake2s_compression(
+        &mut self,
+        state: &[Self::AcvmType; 8],
+        message: &[Self::AcvmType; 16],
+        counter: u64,
+        last_block: bool,
+    ) -> eyre::Result<Vec<Self::AcvmType>> {
+        if state
+            .iter()
+            .chain(message.iter())
+            .all(|x| !Self::is_shared(x))
+        {
+            let state = state
+                .clone()
+                .map(|x| Self::get_public(&x).expect("Already checked it is public"));
+            let message = message
+                .clone()
+                .map(|x| Self::get_public(&x).expect("Already checked it is public"));
+            return Ok(self
+                .plain_solver
+                .blake2s_compression(&state, &message, counter, last_block)?
+                .into_iter()
+                .map(Rep3AcvmType::Public)
+                .collect());
+        }
+
+        let state = state.clone().map(|x| self.to_shared(x));
+        let message = message.clone().map(|x| self.to_shared(x));
+        Ok(
+            yao::blake2s_compression(&state, &message, counter, last_block, &mut self.io_context)?
+                .into_iter()
+                .map(Rep3AcvmType::Shared)
+                .collect(),
+        )
+    }
+
+    fn blake3_compression(
+        &mut self,
+        chaining_value: &[Self::AcvmType; 8],
+        message: &[Self::AcvmType; 16],
+        counter: u64,
+        block_len: u32,
+        flags: u32,
+    ) -> eyre::Result<Vec<Self::AcvmType>> {
+        if chaining_value
+            .iter()
+            .chain(message.iter())
+            .all(|x| !Self::is_shared(x))
+        {
+            let chaining_value = chaining_value
+                .clone()
+                .map(|x| Self::get_public(&x).expect("Already checked it is public"));
+            let message = message
+                .clone()
+                .map(|x| Self::get_public(&x).expect("Already checked it is public"));
+            return Ok(self
+                .plain_solver
+                .blake3_compression(&chaining_value, &message, counter, block_len, flags)?
+                .into_iter()
+                .map(Rep3AcvmType::Public)
+                .collect());
+        }
+
+        let chaining_value = chaining_value.clone().map(|x| self.to_shared(x));
+        let message = message.clone().map(|x| self.to_shared(x));
+        Ok(yao::blake3_compression(
+            &chaining_value,
+            &message,
+            counter,
+            block_len,
+            flags,
+            &mut self.io_context,
+        )?
+        .into_iter()
+        .map(Rep3AcvmType::Shared)
+        .collect())
+    }
+
+    fn aes128_encrypt_cbc(
+        &mut self,
+        plaintext: &[Self::AcvmType],
+        iv: &[Self::AcvmType; 16],
+        key: &[Self::AcvmType; 16],
+    ) -> eyre::Result<Vec<Self::AcvmType>> {
+        if plaintext
+            .iter()
+            .chain(iv.iter())
+            .chain(key.iter())
+            .all(|x| !Self::is_shared(x))
+        {
+            let plaintext = plaintext
+                .iter()
+                .map(|x| Self::get_public(x).expect("Already checked it is public"))
+                .collect::<Vec<_>>();
+            let iv = iv
+                .clone()
+                .map(|x| Self::get_public(&x).expect("Already checked it is public"));
+            let key = key
+                .clone()
+                .map(|x| Self::get_public(&x).expect("Already checked it is public"));
+            return Ok(self
+                .plain_solver
+                .aes128_encrypt_cbc(&plaintext, &iv, &key)?
+                .into_iter()
+                .map(Rep3AcvmType::Public)
+                .collect());
+        }
+
+        let plaintext = plaintext
+            .iter()
+            .map(|x| self.to_shared(x.to_owned()))
+            .collect::<Vec<_>>();
+        let iv = iv.clone().map(|x| self.to_shared(x));
+        let key = key.clone().map(|x| self.to_shared(x));
+        Ok(
+            yao::aes128_encrypt_cbc(&plaintext, &iv, &key, &mut self.io_context)?
+                .into_iter()
+                .map(Rep3AcvmType::Shared)
+                .collect(),
+        )
+    }
+
+    fn non_native_mul_add_div_rem(
+        &mut self,
+        a: &[Self::AcvmType],
+        b: &[Self::AcvmType],
+        to_add: &[Self::AcvmType],
+        to_sub: &[Self::AcvmType],
+        modulus: &BigUint,
+        limb_bits: usize,
+        input_limb_bits: usize,
+    ) -> eyre::Result<(Vec<Self::AcvmType>, Vec<Self::AcvmType>)> {
+        let inputs = [a, b, to_add, to_sub];
+        if inputs.iter().all(|x| x.iter().all(|x| !Self::is_shared(x))) {
+            let [a, b, to_add, to_sub] = inputs.map(|x| {
+                x.iter()
+                    .map(|x| Self::get_public(x).expect("Already checked it is public"))
+                    .collect::<Vec<_>>()
+            });
+            let (quotient, remainder) = self.plain_solver.non_native_mul_add_div_rem(
+                &a,
+                &b,
+                &to_add,
+                &to_sub,
+                modulus,
+                limb_bits,
+                input_limb_bits,
+            )?;
+            return Ok((
+                quotient.into_iter().map(Rep3AcvmType::Public).collect(),
+                remainder.into_iter().map(Rep3AcvmType::Public).collect(),
+            ));
+        }
+
+        let [a, b, to_add, to_sub] = inputs.map(|x| {
+            x.iter()
+                .map(|x| self.to_shared(x.to_owned()))
+                .collect::<Vec<_>>()
+        });
+        let (quotient, remainder) = yao::non_native_mul_add_div_rem(
+            &a,
+            &b,
+            &to_add,
+            &to_sub,
+            modulus,
+            limb_bits,
+            input_limb_bits,
+            &mut self.io_context,
+        )?;
+        Ok((
+            quotient.into_iter().map(Rep3AcvmType::Shared).collect(),
+            remainder.into_iter().map(Rep3AcvmType::Shared).collect(),
+        ))
+    }
+
+    fn non_native_inverse(
+        &mut self,
+        a: &[Self::AcvmType],
+        modulus: &BigUint,
+        limb_bits: usize,
+        input_limb_bits: usize,
+    ) -> eyre::Result<Vec<Self::AcvmType>> {
+        if a.iter().all(|x| !Self::is_shared(x)) {
+            let a = a
+                .iter()
+                .map(|x| Self::get_public(x).expect("Already checked it is public"))
+                .collect::<Vec<_>>();
+            return Ok(self
+                .plain_solver
+                .non_native_inverse(&a, modulus, limb_bits, input_limb_bits)?
+                .into_iter()
+                .map(Rep3AcvmType::Public)
+                .collect());
+        }
+
+        let a = a
+            .iter()
+            .map(|x| self.to_shared(x.to_owned()))
+            .collect::<Vec<_>>();
+        Ok(yao::non_native_inverse(
+            &a,
+            modulus,
+            limb_bits,
+            input_limb_bits,
+            &mut self.io_context,
+        )?
+        .into_iter()
+        .map(Rep3AcvmType::Shared)
+        .collect())
+    }
+
+    fn poseidon2_matmul_external_inplace<const T: usize, const D: u64>(
+        &self,
+        input: &mut [Self::AcvmType; T],
+    ) {
+        if input.iter().all(|x| !Self::is_shared(x)) {
+            let mut state = input
+                .clone()
+                .map(|x| Self::get_public(&x).expect("Already checked it is public"));
+            Poseidon2::<F, T, D>::matmul_external(&mut state);
+            *input = state.map(Rep3AcvmType::Public);
+            return;
+        }
+
+        let mut shared = input.clone().map(|x| self.to_shared(x));
+        Self::poseidon2_matmul_shared(&mut shared, Poseidon2::<F, T, D>::matmul_external);
+        *input = shared.map(Rep3AcvmType::Shared);
+    }
+
+    fn poseidon2_external_round_inplace<const T: usize, const D: u64>(
+        &mut self,
+        input: &mut [Self::AcvmType; T],
+        r: usize,
+        poseidon2: &Poseidon2<F, T, D>,
+    ) -> std::io::Result<()> {
+        if input.iter().all(|x| !Self::is_shared(x)) {
+            let mut state = input
+                .clone()
+                .map(|x| Self::get_public(&x).expect("Already checked it is public"));
+            poseidon2.external_round(&mut state, r);
+            *input = state.map(Rep3AcvmType::Public);
+            return Ok(());
+        }
+
+        let mut shared = input.clone().map(|x| self.to_shared(x));
+        for (s, rc) in shared
+            .iter_mut()
+            .zip(poseidon2.params.round_constants_external[r].iter())
+        {
+            arithmetic::add_assign_public(s, *rc, self.io_context.id);
+        }
+        self.poseidon2_sbox_shared::<D>(&mut shared)?;
+        Self::poseidon2_matmul_shared(&mut shared, Poseidon2::<F, T, D>::matmul_external);
+        *input = shared.map(Rep3AcvmType::Shared);
+        Ok(())
+    }
+
+    fn poseidon2_internal_round_inplace<const T: usize, const D: u64>(
+        &mut self,
+        input: &mut [Self::AcvmType; T],
+        r: usize,
+        poseidon2: &Poseidon2<F, T, D>,
+    ) -> std::io::Result<()> {
+        if input.iter().all(|x| !Self::is_shared(x)) {
+            let mut state = input
+                .clone()
+                .map(|x| Self::get_public(&x).expect("Already checked it is public"));
+            poseidon2.internal_round(&mut state, r);
+            *input = state.map(Rep3AcvmType::Public);
+            return Ok(());
+        }
+
+        let mut shared = input.clone().map(|x| self.to_shared(x));
+        arithmetic::add_assign_public(
+            &mut shared[0],
+            poseidon2.params.round_constants_internal[r],
+            self.io_context.id,
+        );
+        self.poseidon2_sbox_shared::<D>(&mut shared[..1])?;
+        Self::poseidon2_matmul_shared(&mut shared, |x| poseidon2.matmul_internal(x));
+        *input = shared.map(Rep3AcvmType::Shared);
+        Ok(())
+    }
+
     fn is_shared(a: &Self::AcvmType) -> bool {
         matches!(a, Rep3AcvmType::Shared(_))
     }
@@ -1,11 +1,18 @@
 use crate::PlainAcvmSolver;
 use ark_ff::PrimeField;
-use mpc_core::protocols::{
-    rep3::{lut::NaiveRep3LookupTable, network::Rep3MpcNet},
-    shamir::{arithmetic, network::ShamirNetwork, ShamirPrimeFieldShare, ShamirProtocol},
+use mpc_core::{
+    gadgets::poseidon2::Poseidon2,
+    lut::LookupTableProvider,
+    protocols::shamir::{
+        arithmetic,
+        lut::{self, ShamirLookupTable},
+        network::ShamirNetwork,
+        ShamirPrimeFieldShare, ShamirProtocol,
+    },
 };
+use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
-use std::marker::PhantomData;
+use std::{array, marker::PhantomData};
 
 use super::NoirWitnessExtensionProtocol;
 
@@ -16,7 +23,7 @@ pub struct ShamirAcvmSolver<F: PrimeField, N: ShamirNetwork> {
 }
 
 impl<F: PrimeField, N: ShamirNetwork> ShamirAcvmSolver<F, N> {
-    pub(crate) fn new(protocol: ShamirProtocol<F, N>) -> Self {
+    pub fn new(protocol: ShamirProtocol<F, N>) -> Self {
         let plain_solver = PlainAcvmSolver::<F>::default();
         Self {
             protocol,
@@ -24,6 +31,44 @@ impl<F: PrimeField, N: ShamirNetwork> ShamirAcvmSolver<F, N> {
             phantom_data: PhantomData,
         }
     }
+
+    /// Consumes the solver and returns the underlying network, e.g., to reuse it for the prover.
+    pub fn into_network(self) -> N {
+        self.protocol.network
+    }
+
+    fn to_shared(a: ShamirAcvmType<F>) -> ShamirPrimeFieldShare<F> {
+        match a {
+            ShamirAcvmType::Public(public) => arithmetic::promote_to_trivial_share(public),
+            ShamirAcvmType::Shared(shared) => shared,
+        }
+    }
+
+    fn poseidon2_sbox_shared<const D: u64>(
+        &mut self,
+        input: &mut [ShamirPrimeFieldShare<F>],
+    ) -> std::io::Result<()> {
+        // Square-and-multiply over the bits of D, where the multiplications of all elements are batched
+        let mut result = input.to_vec();
+        for bit in (0..D.ilog2()).rev() {
+            result = arithmetic::mul_vec(&result, &result, &mut self.protocol)?;
+            if (D >> bit) & 1 == 1 {
+                result = arithmetic::mul_vec(&result, input, &mut self.protocol)?;
+            }
+        }
+        input.copy_from_slice(&result);
+        Ok(())
+    }
+
+    fn poseidon2_matmul_shared<const T: usize>(
+        input: &mut [ShamirPrimeFieldShare<F>; T],
+        matmul: impl Fn(&mut [F; T]),
+    ) {
+        // The matrix multiplications are linear, thus we can apply them directly to the shares
+        let mut state = input.map(|x| x.inner());
+        matmul(&mut state);
+        *input = array::from_fn(|i| ShamirPrimeFieldShare::new(state[i]));
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize, PartialEq)]
@@ -83,7 +128,8 @@ impl<F: PrimeField> From<ShamirPrimeFieldShare<F>> for ShamirAcvmType<F> {
 }
 
 impl<F: PrimeField, N: ShamirNetwork> NoirWitnessExtensionProtocol<F> for ShamirAcvmSolver<F, N> {
-    type Lookup = NaiveRep3LookupTable<Rep3MpcNet>; // This is just a dummy and unused
+    // The LUT operations reuse the preprocessed randomness of the protocol, thus we call the lut functions directly instead of holding a provider
+    type Lookup = ShamirLookupTable<F, N>;
 
     type ArithmeticShare = ShamirPrimeFieldShare<F>;
 
@@ -195,26 +241,583 @@ impl<F: PrimeField, N: ShamirNetwork> NoirWitnessExtensionProtocol<F> for Shamir
 
     fn init_lut_by_acvm_type(
         &mut self,
-        _values: Vec<Self::AcvmType>,
-    ) -> <Self::Lookup as mpc_core::lut::LookupTableProvider<F>>::SecretSharedMap {
-        panic!("init_lut_by_acvm_type: Operation atm not supported")
+        values: Vec<Self::AcvmType>,
+    ) -> <Self::Lookup as LookupTableProvider<F>>::SecretSharedMap {
+        let keys = (0..values.len())
+            .map(|idx| F::from(u64::try_from(idx).expect("usize fits into u64")))
+            .collect();
+        let values = values.into_iter().map(Self::to_shared).collect();
+        lut::ShamirLutMap::new(keys, values)
     }
 
     fn read_lut_by_acvm_type(
         &mut self,
-        _index: &Self::AcvmType,
-        _lut: &<Self::Lookup as mpc_core::lut::LookupTableProvider<F>>::SecretSharedMap,
+        index: &Self::AcvmType,
+        lut: &<Self::Lookup as LookupTableProvider<F>>::SecretSharedMap,
     ) -> std::io::Result<Self::AcvmType> {
-        panic!("read_lut_by_acvm_type: Operation atm not supported")
+        let value = match index {
+            ShamirAcvmType::Public(public) => {
+                let position = lut.position(*public).ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!("read_lut_by_acvm_type: index {public} out of bounds"),
+                    )
+                })?;
+                lut.values()[position]
+            }
+            ShamirAcvmType::Shared(shared) => lut::read_from_lut(*shared, lut, &mut self.protocol)?,
+        };
+        Ok(ShamirAcvmType::Shared(value))
     }
 
     fn write_lut_by_acvm_type(
         &mut self,
-        _index: Self::AcvmType,
-        _value: Self::AcvmType,
-        _lut: &mut <Self::Lookup as mpc_core::lut::LookupTableProvider<F>>::SecretSharedMap,
+        index: Self::AcvmType,
+        value: Self::AcvmType,
+        lut: &mut <Self::Lookup as LookupTableProvider<F>>::SecretSharedMap,
+    ) -> std::io::Result<()> {
+        let value = Self::to_shared(value);
+        match index {
+            ShamirAcvmType::Public(public) => {
+                // A write to an unknown key is ignored
+                if let Some(position) = lut.position(public) {
+                    lut.values_mut()[position] = value;
+                }
+                Ok(())
+            }
+            ShamirAcvmType::Shared(shared) => {
+                lut::write_to_lut(shared, value, lut, &mut self.protocol)
+            }
+        }
+    }
+
+    fn get_lut_values(
+        lut: &<Self::Lookup as LookupTableProvider<F>>::SecretSharedMap,
+    ) -> Vec<Self::AcvmType> {
+        lut.values()
+            .iter()
+            .map(|value| ShamirAcvmType::Shared(*value))
+            .collect()
+    }
+
+    fn add(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> Self::AcvmType {
+        match (lhs, rhs) {
+            (ShamirAcvmType::Public(lhs), ShamirAcvmType::Public(rhs)) => {
+                ShamirAcvmType::Public(lhs + rhs)
+            }
+            (ShamirAcvmType::Public(public), ShamirAcvmType::Shared(shared))
+            | (ShamirAcvmType::Shared(shared), ShamirAcvmType::Public(public)) => {
+                ShamirAcvmType::Shared(arithmetic::add_public(shared, public))
+            }
+            (ShamirAcvmType::Shared(lhs), ShamirAcvmType::Shared(rhs)) => {
+                ShamirAcvmType::Shared(arithmetic::add(lhs, rhs))
+            }
+        }
+    }
+
+    fn sub(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> Self::AcvmType {
+        match (lhs, rhs) {
+            (ShamirAcvmType::Public(lhs), ShamirAcvmType::Public(rhs)) => {
+                ShamirAcvmType::Public(lhs - rhs)
+            }
+            (ShamirAcvmType::Public(lhs), ShamirAcvmType::Shared(rhs)) => {
+                ShamirAcvmType::Shared(arithmetic::add_public(arithmetic::neg(rhs), lhs))
+            }
+            (ShamirAcvmType::Shared(lhs), ShamirAcvmType::Public(rhs)) => {
+                ShamirAcvmType::Shared(arithmetic::add_public(lhs, -rhs))
+            }
+            (ShamirAcvmType::Shared(lhs), ShamirAcvmType::Shared(rhs)) => {
+                ShamirAcvmType::Shared(arithmetic::sub(lhs, rhs))
+            }
+        }
+    }
+
+    fn mul(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+    ) -> std::io::Result<Self::AcvmType> {
+        let mut result = ShamirAcvmType::default();
+        self.solve_mul_term(F::one(), lhs, rhs, &mut result)?;
+        Ok(result)
+    }
+
+    fn div(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> eyre::Result<Self::AcvmType> {
+        // solve_equation computes -c/q_l
+        let neg_lhs = self.acvm_mul_with_public(-F::one(), lhs);
+        self.solve_equation(rhs, neg_lhs)
+    }
+
+    fn cmux(
+        &mut self,
+        cond: Self::AcvmType,
+        truthy: Self::AcvmType,
+        falsy: Self::AcvmType,
+    ) -> std::io::Result<Self::AcvmType> {
+        match cond {
+            ShamirAcvmType::Public(cond) => {
+                assert!(cond.is_one() || cond.is_zero());
+                if cond.is_one() {
+                    Ok(truthy)
+                } else {
+                    Ok(falsy)
+                }
+            }
+            ShamirAcvmType::Shared(cond) => {
+                let b_min_a = self.sub(truthy, falsy.clone());
+                let d = self.mul(cond.into(), b_min_a)?;
+                Ok(self.add(falsy, d))
+            }
+        }
+    }
+
+    fn equal(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+    ) -> eyre::Result<Self::AcvmType> {
+        match (lhs, rhs) {
+            (ShamirAcvmType::Public(lhs), ShamirAcvmType::Public(rhs)) => Ok(
+                ShamirAcvmType::Public(self.plain_solver.equal(lhs, rhs)?),
+            ),
+            _ => eyre::bail!("equal: Operation atm not supported for shared values"),
+        }
+    }
+
+    fn lt(&mut self, lhs: Self::AcvmType, rhs: Self::AcvmType) -> eyre::Result<Self::AcvmType> {
+        match (lhs, rhs) {
+            (ShamirAcvmType::Public(lhs), ShamirAcvmType::Public(rhs)) => {
+                Ok(ShamirAcvmType::Public(self.plain_solver.lt(lhs, rhs)?))
+            }
+            _ => eyre::bail!("lt: Operation atm not supported for shared values"),
+        }
+    }
+
+    fn truncate(&mut self, a: Self::AcvmType, bit_size: u32) -> eyre::Result<Self::AcvmType> {
+        match a {
+            ShamirAcvmType::Public(a) => Ok(ShamirAcvmType::Public(
+                self.plain_solver.truncate(a, bit_size)?,
+            )),
+            _ => eyre::bail!("truncate: Operation atm not supported for shared values"),
+        }
+    }
+
+    fn int_div(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType> {
+        match (lhs, rhs) {
+            (ShamirAcvmType::Public(lhs), ShamirAcvmType::Public(rhs)) => Ok(
+                ShamirAcvmType::Public(self.plain_solver.int_div(lhs, rhs, bit_size)?),
+            ),
+            // The integer division requires garbled circuits, which are only available for REP3
+            _ => eyre::bail!(
+                "int_div: integer division of shared values is not supported for Shamir, use REP3 instead"
+            ),
+        }
+    }
+
+    fn bitwise_and(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType> {
+        match (lhs, rhs) {
+            (ShamirAcvmType::Public(lhs), ShamirAcvmType::Public(rhs)) => Ok(
+                ShamirAcvmType::Public(self.plain_solver.bitwise_and(lhs, rhs, bit_size)?),
+            ),
+            _ => eyre::bail!("bitwise_and: Operation atm not supported for shared values"),
+        }
+    }
+
+    fn bitwise_or(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType> {
+        match (lhs, rhs) {
+            (ShamirAcvmType::Public(lhs), ShamirAcvmType::Public(rhs)) => Ok(
+                ShamirAcvmType::Public(self.plain_solver.bitwise_or(lhs, rhs, bit_size)?),
+            ),
+            _ => eyre::bail!("bitwise_or: Operation atm not supported for shared values"),
+        }
+    }
+
+    fn bitwise_xor(
+        &mut self,
+        lhs: Self::AcvmType,
+        rhs: Self::AcvmType,
+        bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType> {
+        match (lhs, rhs) {
+            (ShamirAcvmType::Public(lhs), ShamirAcvmType::Public(rhs)) => Ok(
+                ShamirAcvmType::Public(self.plain_solver.bitwise_xor(lhs, rhs, bit_size)?),
+            ),
+            _ => eyre::bail!("bitwise_xor: Operation atm not supported for shared values"),
+        }
+    }
+
+    fn shift_right(
+        &mut self,
+        a: Self::AcvmType,
+        shift: u32,
+        bit_size: u32,
+    ) -> eyre::Result<Self::AcvmType> {
+        match a {
+            ShamirAcvmType::Public(a) => Ok(ShamirAcvmType::Public(
+                self.plain_solver.shift_right(a, shift, bit_size)?,
+            )),
+            _ => eyre::bail!("shift_right: Operation atm not supported for shared values"),
+        }
+    }
+
+    fn decompose(
+        &mut self,
+        input: Self::AcvmType,
+        total_bit_size: usize,
+        decompose_bit_size: usize,
+    ) -> std::io::Result<Vec<Self::AcvmType>> {
+        match input {
+            ShamirAcvmType::Public(input) => Ok(self
+                .plain_solver
+                .decompose(input, total_bit_size, decompose_bit_size)?
+                .into_iter()
+                .map(ShamirAcvmType::Public)
+                .collect()),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "decompose: Operation atm not supported for shared values",
+            )),
+        }
+    }
+
+    fn slice_variable_bases(
+        &mut self,
+        input: Self::AcvmType,
+        input_bit_size: usize,
+        bases: &[u64],
+    ) -> std::io::Result<Vec<Self::AcvmType>> {
+        match input {
+            ShamirAcvmType::Public(input) => Ok(self
+                .plain_solver
+                .slice_variable_bases(input, input_bit_size, bases)?
+                .into_iter()
+                .map(ShamirAcvmType::Public)
+                .collect()),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "slice_variable_bases: Operation atm not supported for shared values",
+            )),
+        }
+    }
+
+    fn one_hot_vector_from_shared_index(
+        &mut self,
+        index: Self::ArithmeticShare,
+        len: usize,
+    ) -> std::io::Result<Vec<Self::ArithmeticShare>> {
+        let keys = (0..len)
+            .map(|idx| F::from(u64::try_from(idx).expect("usize fits into u64")))
+            .collect::<Vec<_>>();
+        lut::one_hot_vector(index, &keys, &mut self.protocol)
+    }
+
+    fn sort(
+        &mut self,
+        inputs: &[Self::AcvmType],
+        bit_size: usize,
+    ) -> std::io::Result<Vec<Self::AcvmType>> {
+        let inputs = inputs
+            .iter()
+            .map(|x| {
+                Self::get_public(x).ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Unsupported,
+                        "sort: Operation atm not supported for shared values",
+                    )
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Ok(self
+            .plain_solver
+            .sort(&inputs, bit_size)?
+            .into_iter()
+            .map(ShamirAcvmType::Public)
+            .collect())
+    }
+
+    fn sha256_compression(
+        &mut self,
+        state: &[Self::AcvmType; 8],
+        message: &[Self::AcvmType; 16],
+    ) -> eyre::Result<Vec<Self::AcvmType>> {
+        let mut state_public = [F::zero(); 8];
+        let mut message_public = [F::zero(); 16];
+        for (des, src) in state_public
+            .iter_mut()
+            .chain(message_public.iter_mut())
+            .zip(state.iter().chain(message.iter()))
+        {
+            match src {
+                ShamirAcvmType::Public(src) => *des = *src,
+                _ => {
+                    eyre::bail!("sha256_compression: Operation atm not supported for shared values")
+                }
+            }
+        }
+        Ok(self
+            .plain_solver
+            .sha256_compression(&state_public, &message_public)?
+            .into_iter()
+            .map(ShamirAcvmType::Public)
+            .collect())
+    }
+
+    fn keccakf1600(&mut self, state: &[Self::AcvmType; 25]) -> eyre::Result<Vec<Self::AcvmType>> {
+        let mut state_public = [F::zero(); 25];
+        for (des, src) in state_public.iter_mut().zip(state.iter()) {
+            match src {
+                ShamirAcvmType::Public(src) => *des = *src,
+                _ => eyre::bail!("keccakf1600: Operation atm not supported for shared values"),
+            }
+        }
+        Ok(self
+            .plain_solver
+            .keccakf1600(&state_public)?
+            .into_iter()
+            .map(ShamirAcvmType::Public)
+            .collect())
+    }
+
+    fn blake2s_compression(
+        &mut self,
+        state: &[Self::AcvmType; 8],
+        message: &[Self::AcvmType; 16],
+        counter: u64,
+        last_block: bool,
+    ) -> eyre::Result<Vec<Self::AcvmType>> {
+        let mut state_public = [F::zero(); 8];
+        let mut message_public = [F::zero(); 16];
+        for (des, src) in state_public
+            .iter_mut()
+            .chain(message_public.iter_mut())
+            .zip(state.iter().chain(message.iter()))
+        {
+            match src {
+                ShamirAcvmType::Public(src) => *des = *src,
+                _ => {
+                    eyre::bail!(
+                        "blake2s_compression: Operation atm not supported for shared values"
+                    )
+                }
+            }
+        }
+        Ok(self
+            .plain_solver
+            .blake2s_compression(&state_public, &message_public, counter, last_block)?
+            .into_iter()
+            .map(ShamirAcvmType::Public)
+            .collect())
+    }
+
+    fn blake3_compression(
+        &mut self,
+        chaining_value: &[Self::AcvmType; 8],
+        message: &[Self::AcvmType; 16],
+        counter: u64,
+        block_len: u32,
+        flags: u32,
+    ) -> eyre::Result<Vec<Self::AcvmType>> {
+        let mut chaining_value_public = [F::zero(); 8];
+        let mut message_public = [F::zero(); 16];
+        for (des, src) in chaining_value_public
+            .iter_mut()
+            .chain(message_public.iter_mut())
+            .zip(chaining_value.iter().chain(message.iter()))
+        {
+            match src {
+                ShamirAcvmType::Public(src) => *des = *src,
+                _ => {
+                    eyre::bail!("blake3_compression: Operation atm not supported for shared values")
+                }
+            }
+        }
+        Ok(self
+            .plain_solver
+            .blake3_compression(
+                &chaining_value_public,
+                &message_public,
+                counter,
+                block_len,
+                flags,
+            )?
+            .into_iter()
+            .map(ShamirAcvmType::Public)
+            .collect())
+    }
+
+    fn aes128_encrypt_cbc(
+        &mut self,
+        plaintext: &[Self::AcvmType],
+        iv: &[Self::AcvmType; 16],
+        key: &[Self::AcvmType; 16],
+    ) -> eyre::Result<Vec<Self::AcvmType>> {
+        let mut plaintext_public = vec![F::zero(); plaintext.len()];
+        let mut iv_public = [F::zero(); 16];
+        let mut key_public = [F::zero(); 16];
+        for (des, src) in plaintext_public
+            .iter_mut()
+            .chain(iv_public.iter_mut())
+            .chain(key_public.iter_mut())
+            .zip(plaintext.iter().chain(iv.iter()).chain(key.iter()))
+        {
+            match src {
+                ShamirAcvmType::Public(src) => *des = *src,
+                _ => {
+                    eyre::bail!("aes128_encrypt_cbc: Operation atm not supported for shared values")
+                }
+            }
+        }
+        Ok(self
+            .plain_solver
+            .aes128_encrypt_cbc(&plaintext_public, &iv_public, &key_public)?
+            .into_iter()
+            .map(ShamirAcvmType::Public)
+            .collect())
+    }
+
+    fn non_native_mul_add_div_rem(
+        &mut self,
+        a: &[Self::AcvmType],
+        b: &[Self::AcvmType],
+        to_add: &[Self::AcvmType],
+        to_sub: &[Self::AcvmType],
+        modulus: &BigUint,
+        limb_bits: usize,
+        input_limb_bits: usize,
+    ) -> eyre::Result<(Vec<Self::AcvmType>, Vec<Self::AcvmType>)> {
+        let mut public = [a, b, to_add, to_sub].map(|_| Vec::new());
+        for (des, src) in public.iter_mut().zip([a, b, to_add, to_sub]) {
+            for x in src.iter() {
+                match x {
+                    ShamirAcvmType::Public(x) => des.push(*x),
+                    _ => eyre::bail!(
+                        "non_native_mul_add_div_rem: Operation atm not supported for shared values"
+                    ),
+                }
+            }
+        }
+        let [a, b, to_add, to_sub] = public;
+        let (quotient, remainder) = self.plain_solver.non_native_mul_add_div_rem(
+            &a,
+            &b,
+            &to_add,
+            &to_sub,
+            modulus,
+            limb_bits,
+            input_limb_bits,
+        )?;
+        Ok((
+            quotient.into_iter().map(ShamirAcvmType::Public).collect(),
+            remainder.into_iter().map(ShamirAcvmType::Public).collect(),
+        ))
+    }
+
+    fn non_native_inverse(
+        &mut self,
+        a: &[Self::AcvmType],
+        modulus: &BigUint,
+        limb_bits: usize,
+        input_limb_bits: usize,
+    ) -> eyre::Result<Vec<Self::AcvmType>> {
+        let mut a_public = Vec::with_capacity(a.len());
+        for x in a.iter() {
+            match x {
+                ShamirAcvmType::Public(x) => a_public.push(*x),
+                _ => {
+                    eyre::bail!("non_native_inverse: Operation atm not supported for shared values")
+                }
+            }
+        }
+        Ok(self
+            .plain_solver
+            .non_native_inverse(&a_public, modulus, limb_bits, input_limb_bits)?
+            .into_iter()
+            .map(ShamirAcvmType::Public)
+            .collect())
+    }
+
+    fn poseidon2_matmul_external_inplace<const T: usize, const D: u64>(
+        &self,
+        input: &mut [Self::AcvmType; T],
+    ) {
+        if input.iter().all(|x| !Self::is_shared(x)) {
+            let mut state = input
+                .clone()
+                .map(|x| Self::get_public(&x).expect("Already checked it is public"));
+            Poseidon2::<F, T, D>::matmul_external(&mut state);
+            *input = state.map(ShamirAcvmType::Public);
+            return;
+        }
+
+        let mut shared = input.clone().map(Self::to_shared);
+        Self::poseidon2_matmul_shared(&mut shared, Poseidon2::<F, T, D>::matmul_external);
+        *input = shared.map(ShamirAcvmType::Shared);
+    }
+
+    fn poseidon2_external_round_inplace<const T: usize, const D: u64>(
+        &mut self,
+        input: &mut [Self::AcvmType; T],
+        r: usize,
+        poseidon2: &Poseidon2<F, T, D>,
     ) -> std::io::Result<()> {
-        panic!("write_lut_by_acvm_type: Operation atm not supported")
+        if input.iter().all(|x| !Self::is_shared(x)) {
+            let mut state = input
+                .clone()
+                .map(|x| Self::get_public(&x).expect("Already checked it is public"));
+            poseidon2.external_round(&mut state, r);
+            *input = state.map(ShamirAcvmType::Public);
+            return Ok(());
+        }
+
+        let mut shared = input.clone().map(Self::to_shared);
+        for (s, rc) in shared
+            .iter_mut()
+            .zip(poseidon2.params.round_constants_external[r].iter())
+        {
+            arithmetic::add_assign_public(s, *rc);
+        }
+        self.poseidon2_sbox_shared::<D>(&mut shared)?;
+        Self::poseidon2_matmul_shared(&mut shared, Poseidon2::<F, T, D>::matmul_external);
+        *input = shared.map(ShamirAcvmType::Shared);
+        Ok(())
+    }
+
+    fn poseidon2_internal_round_inplace<const T: usize, const D: u64>(
+        &mut self,
+        input: &mut [Self::AcvmType; T],
+        r: usize,
+        poseidon2: &Poseidon2<F, T, D>,
+    ) -> std::io::Result<()> {
+        if input.iter().all(|x| !Self::is_shared(x)) {
+            let mut state = input
+                .clone()
+                .map(|x| Self::get_public(&x).expect("Already checked it is public"));
+            poseidon2.internal_round(&mut state, r);
+            *input = state.map(ShamirAcvmType::Public);
+            return Ok(());
+        }
+
+        let mut shared = input.clone().map(Self::to_shared);
+        arithmetic::add_assign_public(&mut shared[0], poseidon2.params.round_constants_internal[r]);
+        self.poseidon2_sbox_shared::<D>(&mut shared[..1])?;
+        Self::poseidon2_matmul_shared(&mut shared, |x| poseidon2.matmul_internal(x));
+        *input = shared.map(ShamirAcvmType::Shared);
+        Ok(())
     }
 
     fn is_shared(a: &Self::AcvmType) -> bool {
@@ -1,7 +1,10 @@
 use acir::{
     acir_field::GenericFieldElement,
-    circuit::{Circuit, ExpressionWidth, Opcode, Program},
-    native_types::{WitnessMap, WitnessStack},
+    circuit::{
+        brillig::BrilligBytecode, AssertionPayload, Circuit, ExpressionWidth, Opcode,
+        OpcodeLocation, Program,
+    },
+    native_types::{Expression, Witness, WitnessMap, WitnessStack},
     FieldElement,
 };
 use ark_ff::PrimeField;
@@ -13,10 +16,11 @@ use mpc_core::{
         shamir::{network::ShamirNetwork, ShamirPreprocessing, ShamirProtocol},
     },
 };
-use noirc_abi::{input_parser::Format, Abi, MAIN_RETURN_NAME};
+use noirc_abi::Abi;
 use noirc_artifacts::program::ProgramArtifact;
+use num_bigint::BigUint;
 use partial_abi::PublicMarker;
-use std::{collections::BTreeMap, io, path::PathBuf};
+use std::{collections::BTreeMap, fmt, io, path::PathBuf};
 
 use crate::mpc::{
     plain::PlainAcvmSolver, rep3::Rep3AcvmSolver, shamir::ShamirAcvmSolver,
@@ -26,8 +30,16 @@ use crate::mpc::{
 pub(crate) const CO_EXPRESSION_WIDTH: ExpressionWidth = ExpressionWidth::Bounded { width: 4 };
 
 mod assert_zero_solver;
+mod bigint_solver;
+mod blackbox_solver;
+mod brillig_solver;
+pub mod differential;
+mod ecdsa_solver;
+mod embedded_curve_solver;
 mod memory_solver;
+mod non_native_field;
 pub mod partial_abi;
+mod schnorr_solver;
 
 pub type PlainCoSolver<F> = CoSolver<PlainAcvmSolver<F>, F>;
 pub type Rep3CoSolver<F, N> = CoSolver<Rep3AcvmSolver<F, N>, F>;
@@ -61,6 +73,59 @@ pub enum CoAcvmError {
     IOError(#[from] io::Error),
     #[error(transparent)]
     UnrecoverableError(#[from] eyre::Report),
+    #[error(transparent)]
+    UnsatisfiedConstraint(#[from] UnsatisfiedConstraint),
+}
+
+/// Describes an opcode of the program that does not hold for the extended witness.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub struct UnsatisfiedConstraint {
+    /// The index of the ACIR function the opcode belongs to.
+    pub function_index: usize,
+    /// The index of the opcode in the (transformed) ACIR function.
+    pub opcode_index: usize,
+    /// The assertion message of the Noir program attached to the opcode, if any.
+    pub assert_message: Option<String>,
+    /// The value the constraint evaluates to instead of zero. Shared values are only known if they were opened for debugging.
+    pub value: Option<String>,
+}
+
+impl fmt::Display for UnsatisfiedConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot satisfy constraint at opcode {} of function {}",
+            self.opcode_index, self.function_index
+        )?;
+        if let Some(message) = &self.assert_message {
+            write!(f, ": {message}")?;
+        }
+        if let Some(value) = &self.value {
+            write!(f, " (evaluates to {value})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the assertion message of the Noir program for the given opcode of the function.
+fn assert_message<F>(function: &Circuit<F>, opcode_index: usize) -> Option<String> {
+    function
+        .assert_messages
+        .iter()
+        .find_map(|(location, payload)| match (location, payload) {
+            (OpcodeLocation::Acir(index), AssertionPayload::StaticString(message))
+                if *index == opcode_index =>
+            {
+                Some(message.to_owned())
+            }
+            // the payload of dynamic messages may depend on private values, so we only report the selector
+            (OpcodeLocation::Acir(index), AssertionPayload::Dynamic(selector, _))
+                if *index == opcode_index =>
+            {
+                Some(format!("assertion with error selector {selector}"))
+            }
+            _ => None,
+        })
 }
 
 pub struct CoSolver<T, F>
@@ -71,12 +136,19 @@ where
     driver: T,
     abi: Abi,
     functions: Vec<Circuit<GenericFieldElement<F>>>,
-    // maybe this can be an array. lets see..
+    unconstrained_functions: Vec<BrilligBytecode<GenericFieldElement<F>>>,
+    // the witness maps of the currently active ACIR calls. The last one belongs to the function we are solving at the moment
     witness_map: Vec<WitnessMap<T::AcvmType>>,
-    // there will a more fields added as we add functionality
+    // the witnesses of already solved ACIR calls
+    witness_stack: WitnessStack<T::AcvmType>,
+    // the function we are solving at the moment
     function_index: usize,
-    // the memory blocks
+    // the memory blocks of the function we are solving at the moment
     memory_access: IntMap<<T::Lookup as LookupTableProvider<F>>::SecretSharedMap>,
+    // the bigints of the function we are solving at the moment, given by their reduced limbs and their modulus
+    bigints: IntMap<(Vec<T::AcvmType>, BigUint)>,
+    // whether shared values of AssertZero opcodes are opened to check the constraint (for debugging only)
+    open_assertions: bool,
 }
 
 impl<T> CoSolver<T, ark_bn254::Fr>
@@ -95,13 +167,24 @@ where
         if abi.is_empty() {
             Ok(WitnessMap::default())
         } else {
-            let input_string = std::fs::read_to_string(PathBuf::from(path))?;
-            let mut input_map = Format::Toml.parse(&input_string, abi)?;
-            let return_value = input_map.remove(MAIN_RETURN_NAME);
-            // TACEO TODO the return value can be none for the witness extension
-            // do we want to keep it like that? Seems not necessary but maybe
-            // we need it for proving/verifying
-            Ok(abi.encode(&input_map, return_value.clone())?)
+            let path = PathBuf::from(path);
+            let input_string = std::fs::read_to_string(&path)?;
+            let encoded = Self::encode_input_file(
+                &input_string,
+                partial_abi::input_format(&path),
+                abi,
+                false,
+            )?;
+            // All members are present, as missing ones are an error for complete input files
+            let mut witness = WitnessMap::default();
+            for (index, value) in encoded
+                .into_iter()
+                .flat_map(|(_, values)| values.into_iter().flatten())
+                .enumerate()
+            {
+                witness.insert(Witness(index as u32), value);
+            }
+            Ok(witness)
         }
     }
 
@@ -117,17 +200,16 @@ where
         if abi.is_empty() {
             Ok(BTreeMap::default())
         } else {
-            let input_string = std::fs::read_to_string(PathBuf::from(path))?;
-            let abi_ = Self::create_partial_abi(&input_string, abi)?;
-            let mut input_map = Format::Toml.parse(&input_string, &abi_)?;
-            let return_value = input_map.remove(MAIN_RETURN_NAME);
-            // TACEO TODO the return value can be none for the witness extension
-            // do we want to keep it like that? Seems not necessary but maybe
-            // we need it for proving/verifying
-            let encoded = abi_.encode(&input_map, return_value.clone())?;
+            let path = PathBuf::from(path);
+            let input_string = std::fs::read_to_string(&path)?;
+            let encoded = Self::encode_input_file(
+                &input_string,
+                partial_abi::input_format(&path),
+                abi,
+                true,
+            )?;
             Ok(Self::create_string_map(
                 abi,
-                &abi_,
                 encoded,
                 &program.functions[Self::DEFAULT_FUNCTION_INDEX].public_parameters,
             )?)
@@ -154,10 +236,7 @@ where
     where
         PathBuf: From<P>,
     {
-        let mut witness_map =
-            vec![WitnessMap::default(); compiled_program.bytecode.functions.len()];
-        witness_map[Self::DEFAULT_FUNCTION_INDEX] =
-            Self::read_abi_bn254(prover_path, &compiled_program.abi)?;
+        let witness_map = vec![Self::read_abi_bn254(prover_path, &compiled_program.abi)?];
         Ok(Self {
             driver,
             abi: compiled_program.abi,
@@ -168,9 +247,13 @@ where
                 // ignore the transformation mapping for now
                 .map(|function| acvm::compiler::transform(function, CO_EXPRESSION_WIDTH).0)
                 .collect::<Vec<_>>(),
+            unconstrained_functions: compiled_program.bytecode.unconstrained_functions,
             witness_map,
+            witness_stack: WitnessStack::default(),
             function_index: Self::DEFAULT_FUNCTION_INDEX,
             memory_access: IntMap::new(),
+            bigints: IntMap::new(),
+            open_assertions: false,
         })
     }
 
@@ -179,9 +262,7 @@ where
         compiled_program: ProgramArtifact,
         witness: WitnessMap<T::AcvmType>,
     ) -> eyre::Result<Self> {
-        let mut witness_map =
-            vec![WitnessMap::default(); compiled_program.bytecode.functions.len()];
-        witness_map[Self::DEFAULT_FUNCTION_INDEX] = witness;
+        let witness_map = vec![witness];
         Ok(Self {
             driver,
             abi: compiled_program.abi,
@@ -192,9 +273,13 @@ where
                 // ignore the transformation mapping for now
                 .map(|function| acvm::compiler::transform(function, CO_EXPRESSION_WIDTH).0)
                 .collect::<Vec<_>>(),
+            unconstrained_functions: compiled_program.bytecode.unconstrained_functions,
             witness_map,
+            witness_stack: WitnessStack::default(),
             function_index: Self::DEFAULT_FUNCTION_INDEX,
             memory_access: IntMap::new(),
+            bigints: IntMap::new(),
+            open_assertions: false,
         })
     }
 }
@@ -208,7 +293,7 @@ impl<N: Rep3Network> Rep3CoSolver<ark_bn254::Fr, N> {
     where
         PathBuf: From<P>,
     {
-        Self::new_bn254(Rep3AcvmSolver::new(network), compiled_program, prover_path)
+        Self::new_bn254(Rep3AcvmSolver::new(network)?, compiled_program, prover_path)
     }
 
     pub fn from_network_with_witness(
@@ -218,7 +303,7 @@ impl<N: Rep3Network> Rep3CoSolver<ark_bn254::Fr, N> {
             <Rep3AcvmSolver<ark_bn254::Fr, N> as NoirWitnessExtensionProtocol::<ark_bn254::Fr>>::AcvmType,
         >,
     ) -> eyre::Result<Self> {
-        Self::new_bn254_with_witness(Rep3AcvmSolver::new(network), compiled_program, witness)
+        Self::new_bn254_with_witness(Rep3AcvmSolver::new(network)?, compiled_program, witness)
     }
 }
 
@@ -317,7 +402,17 @@ where
 {
     #[inline(always)]
     fn witness(&mut self) -> &mut WitnessMap<T::AcvmType> {
-        &mut self.witness_map[self.function_index]
+        self.witness_map
+            .last_mut()
+            .expect("there is always a witness map for the current function")
+    }
+
+    /// Configures whether shared values of fully solved AssertZero opcodes are opened, such that violated constraints are reported together with their value.
+    ///
+    /// Opening reveals whether each of these constraints holds to all parties, hence this is only intended for debugging. Otherwise only public values are checked, and violated constraints on shared values surface when proving.
+    pub fn with_opened_assertions(mut self, open_assertions: bool) -> Self {
+        self.open_assertions = open_assertions;
+        self
     }
 }
 
@@ -327,7 +422,10 @@ where
     F: PrimeField,
 {
     fn open_results(&mut self, function: &Circuit<GenericFieldElement<F>>) -> CoAcvmResult<()> {
-        let witness_map = &mut self.witness_map[self.function_index];
+        let witness_map = self
+            .witness_map
+            .last_mut()
+            .expect("there is always a witness map for the current function");
 
         let mut vec = Vec::with_capacity(function.return_values.0.len());
         for index in function.return_values.0.iter() {
@@ -347,32 +445,183 @@ where
         Ok(())
     }
 
-    pub fn solve(mut self) -> CoAcvmResult<WitnessStack<T::AcvmType>> {
-        let functions = std::mem::take(&mut self.functions);
+    fn solve_opcodes(
+        &mut self,
+        functions: &[Circuit<GenericFieldElement<F>>],
+        unconstrained_functions: &[BrilligBytecode<GenericFieldElement<F>>],
+    ) -> CoAcvmResult<()> {
+        let function = &functions[self.function_index];
+        for (opcode_index, opcode) in function.opcodes.iter().enumerate() {
+            self.solve_opcode(functions, unconstrained_functions, opcode, opcode_index)
+                .map_err(|err| match err {
+                    // constraints violated in called functions already carry their message
+                    CoAcvmError::UnsatisfiedConstraint(mut err)
+                        if matches!(opcode, Opcode::AssertZero(_)) =>
+                    {
+                        err.assert_message = assert_message(function, opcode_index);
+                        CoAcvmError::UnsatisfiedConstraint(err)
+                    }
+                    CoAcvmError::UnrecoverableError(err) => {
+                        CoAcvmError::UnrecoverableError(err.wrap_err(format!(
+                            "while solving opcode {opcode_index} of function {}",
+                            self.function_index
+                        )))
+                    }
+                    err => err,
+                })?;
+        }
+        Ok(())
+    }
 
-        for opcode in functions[self.function_index].opcodes.iter() {
-            match opcode {
-                Opcode::AssertZero(expr) => self.solve_assert_zero(expr)?,
-                Opcode::MemoryInit {
-                    block_id,
-                    init,
-                    block_type: _, // apparently not used
-                } => self.solve_memory_init_block(*block_id, init)?,
-                Opcode::MemoryOp {
-                    block_id,
-                    op,
-                    predicate,
-                } => self.solve_memory_op(*block_id, op, predicate.to_owned())?,
-                _ => todo!("non assert zero opcode detected, not supported yet"),
+    fn solve_opcode(
+        &mut self,
+        functions: &[Circuit<GenericFieldElement<F>>],
+        unconstrained_functions: &[BrilligBytecode<GenericFieldElement<F>>],
+        opcode: &Opcode<GenericFieldElement<F>>,
+        opcode_index: usize,
+    ) -> CoAcvmResult<()> {
+        match opcode {
+            Opcode::AssertZero(expr) => self.solve_assert_zero(expr, opcode_index)?,
+            Opcode::MemoryInit {
+                block_id,
+                init,
+                block_type: _, // apparently not used
+            } => self.solve_memory_init_block(*block_id, init)?,
+            Opcode::MemoryOp {
+                block_id,
+                op,
+                predicate,
+            } => self.solve_memory_op(*block_id, op, predicate.to_owned())?,
+            Opcode::BrilligCall {
+                id,
+                inputs,
+                outputs,
+                predicate,
+            } => {
+                let bytecode = &unconstrained_functions
+                    .get(*id as usize)
+                    .ok_or(eyre::eyre!("unknown brillig function {id}"))?
+                    .bytecode;
+                self.solve_brillig_call(bytecode, inputs, outputs, predicate.as_ref())?
             }
+            Opcode::Call {
+                id,
+                inputs,
+                outputs,
+                predicate,
+            } => self.solve_call(
+                functions,
+                unconstrained_functions,
+                *id as usize,
+                inputs,
+                outputs,
+                predicate.as_ref(),
+            )?,
+            Opcode::BlackBoxFuncCall(bb_func) => self.solve_blackbox(bb_func)?,
+            // the location is added in solve_opcodes
+            opcode => Err(eyre::eyre!("unsupported opcode {opcode}"))?,
+        }
+        Ok(())
+    }
+
+    fn solve_call(
+        &mut self,
+        functions: &[Circuit<GenericFieldElement<F>>],
+        unconstrained_functions: &[BrilligBytecode<GenericFieldElement<F>>],
+        id: usize,
+        inputs: &[Witness],
+        outputs: &[Witness],
+        predicate: Option<&Expression<GenericFieldElement<F>>>,
+    ) -> CoAcvmResult<()> {
+        tracing::trace!("solving call to function {id}");
+        if id == Self::DEFAULT_FUNCTION_INDEX || id >= functions.len() {
+            Err(eyre::eyre!("invalid call to function {id}"))?;
+        }
+        if let Some(predicate) = predicate {
+            let predicate = self.evaluate_expression(predicate)?;
+            if T::is_public_zero(&predicate) {
+                tracing::trace!("predicate is false - we write zeros!");
+                for output in outputs.iter() {
+                    self.witness().insert(*output, T::public_zero());
+                }
+                return Ok(());
+            } else if !T::is_public_one(&predicate) {
+                Err(eyre::eyre!(
+                    "predicate must be public and either zero or one"
+                ))?;
+            }
+        }
+        // the inputs of the callee are the first witnesses of its witness map
+        let mut initial_witness = WitnessMap::default();
+        for (idx, input) in inputs.iter().enumerate() {
+            let value = self.witness().get(input).cloned().ok_or(eyre::eyre!(
+                "input {} of call to function {id} is not initialized",
+                input.0
+            ))?;
+            initial_witness.insert(
+                Witness(u32::try_from(idx).expect("usize fits into u32")),
+                value,
+            );
         }
+
+        // enter the callee
+        self.witness_map.push(initial_witness);
+        let caller_memory = std::mem::replace(&mut self.memory_access, IntMap::new());
+        let caller_bigints = std::mem::replace(&mut self.bigints, IntMap::new());
+        let caller_index = std::mem::replace(&mut self.function_index, id);
+        let result = self.solve_opcodes(functions, unconstrained_functions);
+        self.function_index = caller_index;
+        self.memory_access = caller_memory;
+        self.bigints = caller_bigints;
+        let callee_witness = self
+            .witness_map
+            .pop()
+            .expect("we pushed the witness map of the callee");
+        result?;
+
+        let return_values = &functions[id].return_values.0;
+        if return_values.len() != outputs.len() {
+            Err(eyre::eyre!(
+                "function {id} returns {} values, but call expects {}",
+                return_values.len(),
+                outputs.len()
+            ))?;
+        }
+        for (output, return_value) in outputs.iter().zip(return_values.iter()) {
+            let value = callee_witness
+                .get(return_value)
+                .cloned()
+                .ok_or(eyre::eyre!(
+                    "return value {} of function {id} was not solved",
+                    return_value.0
+                ))?;
+            self.witness().insert(*output, value);
+        }
+        self.witness_stack.push(
+            u32::try_from(id).expect("usize fits into u32"),
+            callee_witness,
+        );
+        Ok(())
+    }
+
+    pub fn solve(mut self) -> CoAcvmResult<WitnessStack<T::AcvmType>> {
+        let functions = std::mem::take(&mut self.functions);
+        let unconstrained_functions = std::mem::take(&mut self.unconstrained_functions);
+
+        self.solve_opcodes(&functions, &unconstrained_functions)?;
         tracing::trace!("we are done! Opening results...");
-        self.open_results(&functions[self.function_index])?;
+        self.open_results(&functions[Self::DEFAULT_FUNCTION_INDEX])?;
         tracing::trace!("Done! Wrap things up.");
-        let mut witness_stack = WitnessStack::default();
-        for (idx, witness) in self.witness_map.into_iter().rev().enumerate() {
-            witness_stack.push(u32::try_from(idx).expect("usize fits into u32"), witness);
-        }
+        // the witness of the main function is on top of the stack
+        let main_witness = self
+            .witness_map
+            .pop()
+            .expect("there is always a witness map for the main function");
+        let mut witness_stack = self.witness_stack;
+        witness_stack.push(
+            u32::try_from(Self::DEFAULT_FUNCTION_INDEX).expect("usize fits into u32"),
+            main_witness,
+        );
         Ok(witness_stack)
     }
 }
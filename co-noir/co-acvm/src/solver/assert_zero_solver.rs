@@ -1,9 +1,9 @@
 use acir::{acir_field::GenericFieldElement, native_types::Expression, AcirField};
-use ark_ff::PrimeField;
+use ark_ff::{PrimeField, Zero};
 
 use crate::{mpc::NoirWitnessExtensionProtocol, solver::solver_utils};
 
-use super::{CoAcvmResult, CoSolver};
+use super::{CoAcvmResult, CoSolver, UnsatisfiedConstraint};
 
 impl<T, F> CoSolver<T, F>
 where
@@ -102,9 +102,33 @@ where
         Ok(simplified)
     }
 
+    /// Checks that a fully solved AssertZero opcode holds. Public values are always checked, whereas shared values are only opened and checked if configured for debugging.
+    fn check_assert_zero(&mut self, value: T::AcvmType, opcode_index: usize) -> CoAcvmResult<()> {
+        let value = if let Some(value) = T::get_public(&value) {
+            value
+        } else if let (true, Some(share)) = (self.open_assertions, T::get_shared(&value)) {
+            tracing::trace!("opening shared value for debugging");
+            self.driver.open_many(&[share])?[0]
+        } else {
+            tracing::trace!("value is shared, cannot check without opening");
+            return Ok(());
+        };
+        if value.is_zero() {
+            Ok(())
+        } else {
+            Err(UnsatisfiedConstraint {
+                function_index: self.function_index,
+                opcode_index,
+                assert_message: None,
+                value: Some(value.to_string()),
+            })?
+        }
+    }
+
     pub(super) fn solve_assert_zero(
         &mut self,
         expr: &Expression<GenericFieldElement<F>>,
+        opcode_index: usize,
     ) -> CoAcvmResult<()> {
         //first evaluate the already existing terms
         tracing::trace!(
@@ -118,9 +142,9 @@ where
         // also if we are here and have more than one linear combination, we
         // cannot solve the expression
         if simplified.linear_combinations.is_empty() {
-            // we are done
-            tracing::trace!("nothing to do for us");
-            Ok(())
+            // we are done, only the constraint itself remains to be checked
+            tracing::trace!("nothing to solve for us");
+            self.check_assert_zero(simplified.q_c, opcode_index)
         } else if simplified.linear_combinations.len() == 1 {
             //we can solve it!
             tracing::trace!("solving equation...");
@@ -0,0 +1,114 @@
+use acir::{
+    acir_field::GenericFieldElement, circuit::opcodes::FunctionInput, native_types::Witness,
+    BlackBoxFunc,
+};
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+
+use crate::mpc::NoirWitnessExtensionProtocol;
+
+use super::{non_native_field::NonNativeField, CoAcvmResult, CoSolver};
+
+/// The maximum number of bytes of a bigint, which suffices for all supported moduli.
+const MAX_BIGINT_BYTES: usize = 32;
+
+/// Returns the moduli supported by the bigint blackboxes, i.e., the base and scalar fields of BN254, secp256k1, and secp256r1.
+fn supported_moduli() -> [BigUint; 6] {
+    [
+        ark_bn254::Fq::MODULUS.into(),
+        ark_bn254::Fr::MODULUS.into(),
+        ark_secp256k1::Fq::MODULUS.into(),
+        ark_secp256k1::Fr::MODULUS.into(),
+        ark_secp256r1::Fq::MODULUS.into(),
+        ark_secp256r1::Fr::MODULUS.into(),
+    ]
+}
+
+impl<T, F> CoSolver<T, F>
+where
+    T: NoirWitnessExtensionProtocol<F>,
+    F: PrimeField,
+{
+    fn get_bigint(&self, id: u32, func: BlackBoxFunc) -> CoAcvmResult<(Vec<T::AcvmType>, BigUint)> {
+        Ok(self
+            .bigints
+            .get(id.into())
+            .cloned()
+            .ok_or(eyre::eyre!("{func}: bigint {id} is not initialized"))?)
+    }
+
+    pub(super) fn solve_bigint_from_le_bytes(
+        &mut self,
+        inputs: &[FunctionInput<GenericFieldElement<F>>],
+        modulus: &[u8],
+        output: u32,
+    ) -> CoAcvmResult<()> {
+        let modulus = BigUint::from_bytes_le(modulus);
+        if !supported_moduli().contains(&modulus) {
+            Err(eyre::eyre!(
+                "bigint_from_le_bytes: modulus {modulus} is not supported"
+            ))?;
+        }
+        if inputs.len() > MAX_BIGINT_BYTES {
+            Err(eyre::eyre!(
+                "bigint_from_le_bytes: expected at most {MAX_BIGINT_BYTES} bytes, got {}",
+                inputs.len()
+            ))?;
+        }
+        let mut bytes = self.get_function_input_bytes(inputs)?;
+        bytes.reverse();
+
+        let field = NonNativeField::<F, T>::new(modulus.to_owned());
+        let value = NonNativeField::<F, T>::from_be_bytes(&mut self.driver, &bytes);
+        let value = field.reduce(&mut self.driver, &value)?;
+        self.bigints.insert(output.into(), (value, modulus));
+        Ok(())
+    }
+
+    pub(super) fn solve_bigint_to_le_bytes(
+        &mut self,
+        input: u32,
+        outputs: &[Witness],
+    ) -> CoAcvmResult<()> {
+        if outputs.len() > MAX_BIGINT_BYTES {
+            Err(eyre::eyre!(
+                "bigint_to_le_bytes: expected at most {MAX_BIGINT_BYTES} bytes, got {}",
+                outputs.len()
+            ))?;
+        }
+        let (value, _) = self.get_bigint(input, BlackBoxFunc::BigIntToLeBytes)?;
+        let bytes = NonNativeField::<F, T>::to_le_bytes(&mut self.driver, &value, outputs.len())?;
+        for (output, byte) in outputs.iter().zip(bytes) {
+            self.witness().insert(*output, byte);
+        }
+        Ok(())
+    }
+
+    pub(super) fn solve_bigint_op(
+        &mut self,
+        lhs: u32,
+        rhs: u32,
+        output: u32,
+        func: BlackBoxFunc,
+    ) -> CoAcvmResult<()> {
+        let (lhs, modulus) = self.get_bigint(lhs, func)?;
+        let (rhs, rhs_modulus) = self.get_bigint(rhs, func)?;
+        if modulus != rhs_modulus {
+            Err(eyre::eyre!(
+                "{func}: the moduli of the operands do not match"
+            ))?;
+        }
+
+        let field = NonNativeField::<F, T>::new(modulus.to_owned());
+        let driver = &mut self.driver;
+        let result = match func {
+            BlackBoxFunc::BigIntAdd => field.add(driver, &lhs, &rhs)?,
+            BlackBoxFunc::BigIntSub => field.sub(driver, &lhs, &rhs)?,
+            BlackBoxFunc::BigIntMul => field.mul(driver, &lhs, &rhs)?,
+            BlackBoxFunc::BigIntDiv => field.div(driver, &lhs, &rhs)?,
+            _ => Err(eyre::eyre!("{func} is not a bigint operation"))?,
+        };
+        self.bigints.insert(output.into(), (result, modulus));
+        Ok(())
+    }
+}
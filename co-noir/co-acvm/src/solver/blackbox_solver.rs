@@ -0,0 +1,557 @@
+use acir::{
+    acir_field::GenericFieldElement,
+    circuit::opcodes::{BlackBoxFuncCall, ConstantOrWitnessEnum, FunctionInput},
+    native_types::Witness,
+    AcirField, BlackBoxFunc,
+};
+use ark_ff::PrimeField;
+use mpc_core::gadgets::{
+    aes::aes128_padding_len,
+    blake::{
+        BLAKE2S_PARAM, BLAKE3_CHUNK_BYTES, BLAKE3_CHUNK_END, BLAKE3_CHUNK_START, BLAKE3_ROOT,
+        BLAKE_BLOCK_BYTES, BLAKE_IV,
+    },
+    poseidon2::Poseidon2,
+};
+use num_bigint::BigUint;
+
+use crate::mpc::NoirWitnessExtensionProtocol;
+
+use super::{CoAcvmResult, CoSolver};
+
+/// The initial state of the SHA256 hash function.
+const SHA256_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// The rate of Keccak256 in bytes, i.e., the number of message bytes absorbed per permutation.
+const KECCAK256_RATE: usize = 136;
+
+impl<T, F> CoSolver<T, F>
+where
+    T: NoirWitnessExtensionProtocol<F>,
+    F: PrimeField,
+{
+    pub(super) fn get_function_input(
+        &mut self,
+        input: &FunctionInput<GenericFieldElement<F>>,
+    ) -> CoAcvmResult<T::AcvmType> {
+        match input.input_ref() {
+            ConstantOrWitnessEnum::Constant(constant) => {
+                Ok(T::AcvmType::from(constant.into_repr()))
+            }
+            ConstantOrWitnessEnum::Witness(witness) => {
+                Ok(self.witness().get(witness).cloned().ok_or(eyre::eyre!(
+                    "blackbox input {} is not initialized",
+                    witness.0
+                ))?)
+            }
+        }
+    }
+
+    pub(super) fn get_function_input_bytes(
+        &mut self,
+        inputs: &[FunctionInput<GenericFieldElement<F>>],
+    ) -> CoAcvmResult<Vec<T::AcvmType>> {
+        inputs
+            .iter()
+            .map(|input| self.get_function_input(input))
+            .collect()
+    }
+
+    /// Translates the inputs of a hash function into bytes, where each input is big-endian encoded.
+    fn get_function_input_message(
+        &mut self,
+        inputs: &[FunctionInput<GenericFieldElement<F>>],
+    ) -> CoAcvmResult<Vec<T::AcvmType>> {
+        let mut message = Vec::with_capacity(inputs.len());
+        for input in inputs.iter() {
+            let num_bytes = (input.num_bits() as usize).div_ceil(8);
+            let value = self.get_function_input(input)?;
+            if num_bytes == 1 {
+                message.push(value);
+            } else {
+                let mut bytes = self.driver.decompose(value, num_bytes * 8, 8)?;
+                bytes.reverse();
+                message.extend(bytes);
+            }
+        }
+        Ok(message)
+    }
+
+    /// Composes a block of at most 64 bytes into 16 little-endian 32-bit words, padding it with zeros.
+    fn compose_le_words(&mut self, block: &[T::AcvmType]) -> [T::AcvmType; 16] {
+        let mut words: [T::AcvmType; 16] = Default::default();
+        for (word, bytes) in words.iter_mut().zip(block.chunks(4)) {
+            for byte in bytes.iter().rev() {
+                let shifted = self
+                    .driver
+                    .acvm_mul_with_public(F::from(256u64), word.to_owned());
+                *word = self.driver.add(shifted, byte.to_owned());
+            }
+        }
+        words
+    }
+
+    /// Writes the little-endian byte representation of the 8 32-bit words to the outputs.
+    fn write_le_words(
+        &mut self,
+        words: Vec<T::AcvmType>,
+        outputs: &[Witness; 32],
+    ) -> CoAcvmResult<()> {
+        for (word, outputs) in words.into_iter().zip(outputs.chunks_exact(4)) {
+            let bytes = self.driver.decompose(word, 32, 8)?;
+            for (output, byte) in outputs.iter().zip(bytes) {
+                self.witness().insert(*output, byte);
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn solve_blackbox(
+        &mut self,
+        bb_func: &BlackBoxFuncCall<GenericFieldElement<F>>,
+    ) -> CoAcvmResult<()> {
+        tracing::trace!("solving blackbox {}", bb_func.name());
+        match bb_func {
+            BlackBoxFuncCall::AES128Encrypt {
+                inputs,
+                iv,
+                key,
+                outputs,
+            } => self.solve_aes128_encrypt(inputs, iv.as_ref(), key.as_ref(), outputs)?,
+            BlackBoxFuncCall::AND { lhs, rhs, output } => {
+                let lhs_value = self.get_function_input(lhs)?;
+                let rhs_value = self.get_function_input(rhs)?;
+                let result = self
+                    .driver
+                    .bitwise_and(lhs_value, rhs_value, lhs.num_bits())?;
+                self.witness().insert(*output, result);
+            }
+            BlackBoxFuncCall::XOR { lhs, rhs, output } => {
+                let lhs_value = self.get_function_input(lhs)?;
+                let rhs_value = self.get_function_input(rhs)?;
+                let result = self
+                    .driver
+                    .bitwise_xor(lhs_value, rhs_value, lhs.num_bits())?;
+                self.witness().insert(*output, result);
+            }
+            BlackBoxFuncCall::RANGE { input } => self.solve_range(input)?,
+            BlackBoxFuncCall::SHA256 { inputs, outputs } => {
+                self.solve_sha256(inputs, outputs.as_ref())?
+            }
+            BlackBoxFuncCall::Sha256Compression {
+                inputs,
+                hash_values,
+                outputs,
+            } => self.solve_sha256_compression(
+                inputs.as_ref(),
+                hash_values.as_ref(),
+                outputs.as_ref(),
+            )?,
+            BlackBoxFuncCall::Blake2s { inputs, outputs } => {
+                self.solve_blake2s(inputs, outputs.as_ref())?
+            }
+            BlackBoxFuncCall::Blake3 { inputs, outputs } => {
+                self.solve_blake3(inputs, outputs.as_ref())?
+            }
+            BlackBoxFuncCall::Keccak256 {
+                inputs,
+                var_message_size,
+                outputs,
+            } => self.solve_keccak256(inputs, var_message_size, outputs.as_ref())?,
+            BlackBoxFuncCall::Keccakf1600 { inputs, outputs } => {
+                self.solve_keccakf1600(inputs.as_ref(), outputs.as_ref())?
+            }
+            BlackBoxFuncCall::Poseidon2Permutation {
+                inputs,
+                outputs,
+                len,
+            } => self.solve_poseidon2_permutation(inputs, outputs, *len)?,
+            BlackBoxFuncCall::EcdsaSecp256k1 {
+                public_key_x,
+                public_key_y,
+                signature,
+                hashed_message,
+                output,
+            } => self.solve_ecdsa::<ark_secp256k1::Config>(
+                public_key_x.as_ref(),
+                public_key_y.as_ref(),
+                signature.as_ref(),
+                hashed_message.as_ref(),
+                output,
+            )?,
+            BlackBoxFuncCall::EcdsaSecp256r1 {
+                public_key_x,
+                public_key_y,
+                signature,
+                hashed_message,
+                output,
+            } => self.solve_ecdsa::<ark_secp256r1::Config>(
+                public_key_x.as_ref(),
+                public_key_y.as_ref(),
+                signature.as_ref(),
+                hashed_message.as_ref(),
+                output,
+            )?,
+            BlackBoxFuncCall::MultiScalarMul {
+                points,
+                scalars,
+                outputs,
+            } => self.solve_multi_scalar_mul(points, scalars, outputs)?,
+            BlackBoxFuncCall::EmbeddedCurveAdd {
+                input1,
+                input2,
+                outputs,
+            } => self.solve_embedded_curve_add(input1.as_ref(), input2.as_ref(), outputs)?,
+            BlackBoxFuncCall::PedersenCommitment {
+                inputs,
+                domain_separator,
+                outputs,
+            } => self.solve_pedersen_commitment(inputs, *domain_separator, outputs)?,
+            BlackBoxFuncCall::PedersenHash {
+                inputs,
+                domain_separator,
+                output,
+            } => self.solve_pedersen_hash(inputs, *domain_separator, *output)?,
+            BlackBoxFuncCall::SchnorrVerify {
+                public_key_x,
+                public_key_y,
+                signature,
+                message,
+                output,
+            } => self.solve_schnorr_verify(
+                public_key_x,
+                public_key_y,
+                signature.as_ref(),
+                message,
+                *output,
+            )?,
+            BlackBoxFuncCall::BigIntAdd { lhs, rhs, output } => {
+                self.solve_bigint_op(*lhs, *rhs, *output, BlackBoxFunc::BigIntAdd)?
+            }
+            BlackBoxFuncCall::BigIntSub { lhs, rhs, output } => {
+                self.solve_bigint_op(*lhs, *rhs, *output, BlackBoxFunc::BigIntSub)?
+            }
+            BlackBoxFuncCall::BigIntMul { lhs, rhs, output } => {
+                self.solve_bigint_op(*lhs, *rhs, *output, BlackBoxFunc::BigIntMul)?
+            }
+            BlackBoxFuncCall::BigIntDiv { lhs, rhs, output } => {
+                self.solve_bigint_op(*lhs, *rhs, *output, BlackBoxFunc::BigIntDiv)?
+            }
+            BlackBoxFuncCall::BigIntFromLeBytes {
+                inputs,
+                modulus,
+                output,
+            } => self.solve_bigint_from_le_bytes(inputs, modulus, *output)?,
+            BlackBoxFuncCall::BigIntToLeBytes { input, outputs } => {
+                self.solve_bigint_to_le_bytes(*input, outputs)?
+            }
+            // The proof is verified while building the circuit, there are no outputs to solve
+            BlackBoxFuncCall::RecursiveAggregation { .. } => {}
+            _ => Err(eyre::eyre!(
+                "blackbox function {} is not supported yet",
+                bb_func.name()
+            ))?,
+        }
+        Ok(())
+    }
+
+    /// Checks that the input fits into the bit size of the RANGE constraint. This is only possible
+    /// for public values: a shared value violating the constraint is not detected during the
+    /// witness extension and only leads to an unsatisfied circuit when the proof is created.
+    fn solve_range(&mut self, input: &FunctionInput<GenericFieldElement<F>>) -> CoAcvmResult<()> {
+        let value = self.get_function_input(input)?;
+        if let Some(value) = T::get_public(&value) {
+            let value: BigUint = value.into();
+            if value.bits() > u64::from(input.num_bits()) {
+                Err(eyre::eyre!(
+                    "value {value} does not fit into {} bits",
+                    input.num_bits()
+                ))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn solve_aes128_encrypt(
+        &mut self,
+        inputs: &[FunctionInput<GenericFieldElement<F>>],
+        iv: &[FunctionInput<GenericFieldElement<F>>; 16],
+        key: &[FunctionInput<GenericFieldElement<F>>; 16],
+        outputs: &[Witness],
+    ) -> CoAcvmResult<()> {
+        let mut plaintext = self.get_function_input_bytes(inputs)?;
+        let mut iv_bytes: [T::AcvmType; 16] = Default::default();
+        for (des, input) in iv_bytes.iter_mut().zip(iv.iter()) {
+            *des = self.get_function_input(input)?;
+        }
+        let mut key_bytes: [T::AcvmType; 16] = Default::default();
+        for (des, input) in key_bytes.iter_mut().zip(key.iter()) {
+            *des = self.get_function_input(input)?;
+        }
+
+        // PKCS#7 padding, i.e., n bytes of value n, where n is between 1 and 16
+        let padding = aes128_padding_len(plaintext.len());
+        plaintext.extend(vec![T::AcvmType::from(F::from(padding as u64)); padding]);
+        if outputs.len() != plaintext.len() {
+            Err(eyre::eyre!(
+                "aes128_encrypt: expected {} outputs, but got {}",
+                plaintext.len(),
+                outputs.len()
+            ))?;
+        }
+
+        let ciphertext = self
+            .driver
+            .aes128_encrypt_cbc(&plaintext, &iv_bytes, &key_bytes)?;
+        for (output, byte) in outputs.iter().zip(ciphertext) {
+            self.witness().insert(*output, byte);
+        }
+        Ok(())
+    }
+
+    fn solve_sha256(
+        &mut self,
+        inputs: &[FunctionInput<GenericFieldElement<F>>],
+        outputs: &[Witness; 32],
+    ) -> CoAcvmResult<()> {
+        let mut message = self.get_function_input_message(inputs)?;
+
+        // Padding: 0x80, then zeros up to 56 mod 64 bytes, then the bit length as u64
+        let message_bits = u64::try_from(message.len() * 8).expect("usize fits into u64");
+        message.push(T::AcvmType::from(F::from(0x80u64)));
+        while message.len() % 64 != 56 {
+            message.push(T::public_zero());
+        }
+        message.extend(
+            message_bits
+                .to_be_bytes()
+                .map(|byte| T::AcvmType::from(F::from(byte))),
+        );
+
+        let mut state = SHA256_IV.map(|word| T::AcvmType::from(F::from(word)));
+        for block in message.chunks_exact(64) {
+            let mut words: [T::AcvmType; 16] = Default::default();
+            for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+                for byte in bytes {
+                    let shifted = self
+                        .driver
+                        .acvm_mul_with_public(F::from(256u64), word.to_owned());
+                    *word = self.driver.add(shifted, byte.to_owned());
+                }
+            }
+            let new_state = self.driver.sha256_compression(&state, &words)?;
+            for (des, src) in state.iter_mut().zip(new_state) {
+                *des = src;
+            }
+        }
+
+        // The output is the big-endian byte representation of the state
+        for (word, outputs) in state.into_iter().zip(outputs.chunks_exact(4)) {
+            let bytes = self.driver.decompose(word, 32, 8)?;
+            for (output, byte) in outputs.iter().zip(bytes.into_iter().rev()) {
+                self.witness().insert(*output, byte);
+            }
+        }
+        Ok(())
+    }
+
+    fn solve_sha256_compression(
+        &mut self,
+        inputs: &[FunctionInput<GenericFieldElement<F>>; 16],
+        hash_values: &[FunctionInput<GenericFieldElement<F>>; 8],
+        outputs: &[Witness; 8],
+    ) -> CoAcvmResult<()> {
+        let mut message: [T::AcvmType; 16] = Default::default();
+        for (des, input) in message.iter_mut().zip(inputs.iter()) {
+            *des = self.get_function_input(input)?;
+        }
+        let mut state: [T::AcvmType; 8] = Default::default();
+        for (des, input) in state.iter_mut().zip(hash_values.iter()) {
+            *des = self.get_function_input(input)?;
+        }
+
+        let result = self.driver.sha256_compression(&state, &message)?;
+        for (output, value) in outputs.iter().zip(result) {
+            self.witness().insert(*output, value);
+        }
+        Ok(())
+    }
+
+    fn solve_blake2s(
+        &mut self,
+        inputs: &[FunctionInput<GenericFieldElement<F>>],
+        outputs: &[Witness; 32],
+    ) -> CoAcvmResult<()> {
+        let message = self.get_function_input_message(inputs)?;
+        let state = self.blake2s(&message)?;
+        self.write_le_words(state.to_vec(), outputs)
+    }
+
+    /// Hashes the message bytes with Blake2s and returns the output as 8 32-bit words, whose little-endian byte representation is the digest.
+    pub(super) fn blake2s(&mut self, message: &[T::AcvmType]) -> CoAcvmResult<[T::AcvmType; 8]> {
+        let mut state = BLAKE_IV;
+        state[0] ^= BLAKE2S_PARAM;
+        let mut state = state.map(|word| T::AcvmType::from(F::from(word)));
+
+        // The empty message is hashed as a single block of zeros
+        let num_blocks = message.len().div_ceil(BLAKE_BLOCK_BYTES).max(1);
+        for i in 0..num_blocks {
+            let end = ((i + 1) * BLAKE_BLOCK_BYTES).min(message.len());
+            let words = self.compose_le_words(&message[i * BLAKE_BLOCK_BYTES..end]);
+            let counter = u64::try_from(end).expect("usize fits into u64");
+            let new_state =
+                self.driver
+                    .blake2s_compression(&state, &words, counter, i == num_blocks - 1)?;
+            for (des, src) in state.iter_mut().zip(new_state) {
+                *des = src;
+            }
+        }
+        Ok(state)
+    }
+
+    fn solve_blake3(
+        &mut self,
+        inputs: &[FunctionInput<GenericFieldElement<F>>],
+        outputs: &[Witness; 32],
+    ) -> CoAcvmResult<()> {
+        let message = self.get_function_input_message(inputs)?;
+        // Larger messages would require to build the Blake3 hash tree out of multiple chunks
+        if message.len() > BLAKE3_CHUNK_BYTES {
+            Err(eyre::eyre!(
+                "blake3: messages larger than {BLAKE3_CHUNK_BYTES} bytes are not supported"
+            ))?;
+        }
+
+        let mut chaining_value = BLAKE_IV.map(|word| T::AcvmType::from(F::from(word)));
+        let num_blocks = message.len().div_ceil(BLAKE_BLOCK_BYTES).max(1);
+        for i in 0..num_blocks {
+            let start = i * BLAKE_BLOCK_BYTES;
+            let end = (start + BLAKE_BLOCK_BYTES).min(message.len());
+            let words = self.compose_le_words(&message[start..end]);
+            let mut flags = 0;
+            if i == 0 {
+                flags |= BLAKE3_CHUNK_START;
+            }
+            if i == num_blocks - 1 {
+                flags |= BLAKE3_CHUNK_END | BLAKE3_ROOT;
+            }
+            let block_len = u32::try_from(end - start).expect("block length fits into u32");
+            let new_chaining_value =
+                self.driver
+                    .blake3_compression(&chaining_value, &words, 0, block_len, flags)?;
+            for (des, src) in chaining_value.iter_mut().zip(new_chaining_value) {
+                *des = src;
+            }
+        }
+
+        self.write_le_words(chaining_value.to_vec(), outputs)
+    }
+
+    fn solve_keccak256(
+        &mut self,
+        inputs: &[FunctionInput<GenericFieldElement<F>>],
+        var_message_size: &FunctionInput<GenericFieldElement<F>>,
+        outputs: &[Witness; 32],
+    ) -> CoAcvmResult<()> {
+        let mut message = self.get_function_input_message(inputs)?;
+
+        // The message size determines the padding, thus it has to be public
+        let message_size = self.get_function_input(var_message_size)?;
+        let message_size = T::get_public(&message_size).ok_or(eyre::eyre!(
+            "keccak256: the message size must not be shared"
+        ))?;
+        let message_size: BigUint = message_size.into();
+        let message_size = usize::try_from(message_size)
+            .ok()
+            .filter(|size| *size <= message.len())
+            .ok_or(eyre::eyre!(
+                "keccak256: the message size is larger than the input length {}",
+                message.len()
+            ))?;
+        message.truncate(message_size);
+
+        // Padding: 0x01, then zeros, and 0x80 in the last byte of the block
+        message.push(T::AcvmType::from(F::from(0x01u64)));
+        while message.len() % KECCAK256_RATE != 0 {
+            message.push(T::public_zero());
+        }
+        let last = message.last_mut().expect("message is not empty");
+        *last = self
+            .driver
+            .add(last.to_owned(), T::AcvmType::from(F::from(0x80u64)));
+
+        let mut state: [T::AcvmType; 25] = Default::default();
+        for (i, block) in message.chunks_exact(KECCAK256_RATE).enumerate() {
+            // The lanes are the little-endian composition of 8 bytes
+            for (lane, bytes) in state.iter_mut().zip(block.chunks_exact(8)) {
+                let mut value = T::public_zero();
+                for byte in bytes.iter().rev() {
+                    let shifted = self.driver.acvm_mul_with_public(F::from(256u64), value);
+                    value = self.driver.add(shifted, byte.to_owned());
+                }
+                *lane = if i == 0 {
+                    value
+                } else {
+                    self.driver.bitwise_xor(lane.to_owned(), value, 64)?
+                };
+            }
+            let new_state = self.driver.keccakf1600(&state)?;
+            for (des, src) in state.iter_mut().zip(new_state) {
+                *des = src;
+            }
+        }
+
+        // The output is the little-endian byte representation of the first 4 lanes
+        for (lane, outputs) in state.into_iter().zip(outputs.chunks_exact(8)) {
+            let bytes = self.driver.decompose(lane, 64, 8)?;
+            for (output, byte) in outputs.iter().zip(bytes) {
+                self.witness().insert(*output, byte);
+            }
+        }
+        Ok(())
+    }
+
+    fn solve_keccakf1600(
+        &mut self,
+        inputs: &[FunctionInput<GenericFieldElement<F>>; 25],
+        outputs: &[Witness; 25],
+    ) -> CoAcvmResult<()> {
+        let mut state: [T::AcvmType; 25] = Default::default();
+        for (des, input) in state.iter_mut().zip(inputs.iter()) {
+            *des = self.get_function_input(input)?;
+        }
+
+        let result = self.driver.keccakf1600(&state)?;
+        for (output, value) in outputs.iter().zip(result) {
+            self.witness().insert(*output, value);
+        }
+        Ok(())
+    }
+
+    fn solve_poseidon2_permutation(
+        &mut self,
+        inputs: &[FunctionInput<GenericFieldElement<F>>],
+        outputs: &[Witness],
+        len: u32,
+    ) -> CoAcvmResult<()> {
+        // Barretenberg only supports the Poseidon2 permutation with a state size of 4
+        if len != 4 || inputs.len() != 4 || outputs.len() != 4 {
+            Err(eyre::eyre!(
+                "Poseidon2 permutation is only supported for a state size of 4, got {len}"
+            ))?;
+        }
+        let mut state: [T::AcvmType; 4] = Default::default();
+        for (des, input) in state.iter_mut().zip(inputs.iter()) {
+            *des = self.get_function_input(input)?;
+        }
+        let poseidon2 = Poseidon2::<F, 4, 5>::default();
+        self.driver
+            .poseidon2_permutation_inplace(&mut state, &poseidon2)?;
+        for (output, value) in outputs.iter().zip(state) {
+            self.witness().insert(*output, value);
+        }
+        Ok(())
+    }
+}
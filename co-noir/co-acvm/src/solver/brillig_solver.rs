@@ -0,0 +1,712 @@
+use acir::{
+    acir_field::GenericFieldElement,
+    brillig::{
+        BinaryFieldOp, BinaryIntOp, BitSize, BlackBoxOp, MemoryAddress, Opcode as BrilligOpcode,
+    },
+    circuit::brillig::{BrilligInputs, BrilligOutputs},
+    native_types::Expression,
+};
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+use num_traits::{One, ToPrimitive};
+
+use crate::mpc::NoirWitnessExtensionProtocol;
+
+use super::{CoAcvmResult, CoSolver};
+
+/// A Brillig VM operating on [`NoirWitnessExtensionProtocol::AcvmType`]s. Public values are
+/// computed locally, whereas shared values are handled by the MPC driver. Control flow
+/// (jumps, memory addresses, shift amounts) must be public, as we cannot branch on secrets.
+struct CoBrilligVM<'a, T, F>
+where
+    T: NoirWitnessExtensionProtocol<F>,
+    F: PrimeField,
+{
+    driver: &'a mut T,
+    bytecode: &'a [BrilligOpcode<GenericFieldElement<F>>],
+    calldata: Vec<T::AcvmType>,
+    memory: Vec<T::AcvmType>,
+    call_stack: Vec<usize>,
+    program_counter: usize,
+}
+
+impl<'a, T, F> CoBrilligVM<'a, T, F>
+where
+    T: NoirWitnessExtensionProtocol<F>,
+    F: PrimeField,
+{
+    fn new(
+        driver: &'a mut T,
+        bytecode: &'a [BrilligOpcode<GenericFieldElement<F>>],
+        calldata: Vec<T::AcvmType>,
+    ) -> Self {
+        Self {
+            driver,
+            bytecode,
+            calldata,
+            memory: Vec::new(),
+            call_stack: Vec::new(),
+            program_counter: 0,
+        }
+    }
+
+    fn read(&self, address: &MemoryAddress) -> T::AcvmType {
+        self.memory
+            .get(address.to_usize())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn write(&mut self, address: usize, value: T::AcvmType) {
+        if address >= self.memory.len() {
+            self.memory.resize(address + 1, T::public_zero());
+        }
+        self.memory[address] = value;
+    }
+
+    fn public_to_usize(value: &T::AcvmType, purpose: &str) -> CoAcvmResult<usize> {
+        let value: BigUint = T::get_public(value)
+            .ok_or(eyre::eyre!(
+                "{purpose} must be public in brillig, but is shared"
+            ))?
+            .into();
+        Ok(value
+            .to_usize()
+            .ok_or(eyre::eyre!("{purpose} does not fit into usize"))?)
+    }
+
+    fn read_usize(&self, address: &MemoryAddress, purpose: &str) -> CoAcvmResult<usize> {
+        Self::public_to_usize(&self.read(address), purpose)
+    }
+
+    fn two_pow(exponent: u32) -> F {
+        F::from(2u64).pow([u64::from(exponent)])
+    }
+
+    /// Multiplies two integers modulo 2^bit_size. The product is computed over the integers, as
+    /// it may exceed the field for large bit sizes (e.g., u128).
+    fn wrapping_mul(lhs: BigUint, rhs: BigUint, bit_size: u32) -> F {
+        let mask = (BigUint::one() << bit_size) - BigUint::one();
+        F::from((lhs * rhs) & mask)
+    }
+
+    /// Shared integer operations are computed in the field and truncated afterwards. This fails
+    /// for results with more bits than the field, as they would wrap around the modulus.
+    fn check_no_field_overflow(result_bits: u32, operation: &str) -> CoAcvmResult<()> {
+        if result_bits >= F::MODULUS_BIT_SIZE {
+            Err(eyre::eyre!(
+                "{operation} of shared integers with a {result_bits}-bit result would overflow the field, which is not supported in brillig"
+            ))?;
+        }
+        Ok(())
+    }
+
+    /// Decomposes the input into num_limbs limbs of the given radix, starting with the least
+    /// significant one. Higher limbs of the input which do not fit into num_limbs are dropped.
+    fn to_radix(
+        &mut self,
+        input: T::AcvmType,
+        radix: u32,
+        num_limbs: usize,
+    ) -> CoAcvmResult<Vec<T::AcvmType>> {
+        if radix < 2 {
+            Err(eyre::eyre!("radix {radix} is not supported"))?;
+        }
+        if num_limbs == 0 {
+            return Ok(vec![]);
+        }
+        if radix.is_power_of_two() {
+            let limb_bits = radix.ilog2() as usize;
+            let total_bits = (limb_bits * num_limbs).min(F::MODULUS_BIT_SIZE as usize);
+            let mut limbs = self.driver.decompose(input, total_bits, limb_bits)?;
+            // the limbs beyond the size of the field are zero
+            limbs.resize(num_limbs, T::public_zero());
+            Ok(limbs)
+        } else {
+            let bases = vec![u64::from(radix); num_limbs];
+            Ok(self
+                .driver
+                .slice_variable_bases(input, F::MODULUS_BIT_SIZE as usize, &bases)?)
+        }
+    }
+
+    fn less_than_equals(
+        &mut self,
+        lhs: T::AcvmType,
+        rhs: T::AcvmType,
+    ) -> CoAcvmResult<T::AcvmType> {
+        // lhs <= rhs is equivalent to !(rhs < lhs)
+        let gt = self.driver.lt(rhs, lhs)?;
+        Ok(self.driver.sub(T::AcvmType::from(F::one()), gt))
+    }
+
+    fn process_binary_field_op(
+        &mut self,
+        op: &BinaryFieldOp,
+        lhs: T::AcvmType,
+        rhs: T::AcvmType,
+    ) -> CoAcvmResult<T::AcvmType> {
+        let result = match op {
+            BinaryFieldOp::Add => self.driver.add(lhs, rhs),
+            BinaryFieldOp::Sub => self.driver.sub(lhs, rhs),
+            BinaryFieldOp::Mul => self.driver.mul(lhs, rhs)?,
+            BinaryFieldOp::Div => self.driver.div(lhs, rhs)?,
+            BinaryFieldOp::IntegerDiv => self.driver.int_div(lhs, rhs, F::MODULUS_BIT_SIZE)?,
+            BinaryFieldOp::Equals => self.driver.equal(lhs, rhs)?,
+            BinaryFieldOp::LessThan => self.driver.lt(lhs, rhs)?,
+            BinaryFieldOp::LessThanEquals => self.less_than_equals(lhs, rhs)?,
+        };
+        Ok(result)
+    }
+
+    fn process_binary_int_op(
+        &mut self,
+        op: &BinaryIntOp,
+        bit_size: u32,
+        lhs: T::AcvmType,
+        rhs: T::AcvmType,
+    ) -> CoAcvmResult<T::AcvmType> {
+        let result = match op {
+            BinaryIntOp::Add => {
+                let sum = self.driver.add(lhs, rhs);
+                self.driver.truncate(sum, bit_size)?
+            }
+            BinaryIntOp::Sub => {
+                // add 2^bit_size to prevent an underflow in the field
+                let lhs = self
+                    .driver
+                    .add(lhs, T::AcvmType::from(Self::two_pow(bit_size)));
+                let diff = self.driver.sub(lhs, rhs);
+                self.driver.truncate(diff, bit_size)?
+            }
+            BinaryIntOp::Mul => match (T::get_public(&lhs), T::get_public(&rhs)) {
+                (Some(lhs), Some(rhs)) => {
+                    T::AcvmType::from(Self::wrapping_mul(lhs.into(), rhs.into(), bit_size))
+                }
+                _ => {
+                    Self::check_no_field_overflow(2 * bit_size, "multiplication")?;
+                    let product = self.driver.mul(lhs, rhs)?;
+                    self.driver.truncate(product, bit_size)?
+                }
+            },
+            BinaryIntOp::Div => self.driver.int_div(lhs, rhs, bit_size)?,
+            BinaryIntOp::Equals => self.driver.equal(lhs, rhs)?,
+            BinaryIntOp::LessThan => self.driver.lt(lhs, rhs)?,
+            BinaryIntOp::LessThanEquals => self.less_than_equals(lhs, rhs)?,
+            BinaryIntOp::And => self.driver.bitwise_and(lhs, rhs, bit_size)?,
+            BinaryIntOp::Or => self.driver.bitwise_or(lhs, rhs, bit_size)?,
+            BinaryIntOp::Xor => self.driver.bitwise_xor(lhs, rhs, bit_size)?,
+            BinaryIntOp::Shl => {
+                let shift = Self::public_to_usize(&rhs, "shift amount")?;
+                if shift >= bit_size as usize {
+                    T::public_zero()
+                } else if let Some(lhs) = T::get_public(&lhs) {
+                    let pow = BigUint::one() << shift;
+                    T::AcvmType::from(Self::wrapping_mul(lhs.into(), pow, bit_size))
+                } else {
+                    Self::check_no_field_overflow(bit_size + shift as u32, "left shift")?;
+                    let shifted = self
+                        .driver
+                        .acvm_mul_with_public(Self::two_pow(shift as u32), lhs);
+                    self.driver.truncate(shifted, bit_size)?
+                }
+            }
+            BinaryIntOp::Shr => {
+                let shift = Self::public_to_usize(&rhs, "shift amount")?;
+                if shift >= bit_size as usize {
+                    T::public_zero()
+                } else {
+                    self.driver.shift_right(lhs, shift as u32, bit_size)?
+                }
+            }
+        };
+        Ok(result)
+    }
+
+    /// Runs the bytecode and returns the return data of the function.
+    fn run(mut self) -> CoAcvmResult<Vec<T::AcvmType>> {
+        loop {
+            let Some(opcode) = self.bytecode.get(self.program_counter) else {
+                // running past the end of the bytecode is a halt without return data
+                return Ok(vec![]);
+            };
+            tracing::trace!("brillig opcode {}: {:?}", self.program_counter, opcode);
+            match opcode {
+                BrilligOpcode::BinaryFieldOp {
+                    destination,
+                    op,
+                    lhs,
+                    rhs,
+                } => {
+                    let lhs = self.read(lhs);
+                    let rhs = self.read(rhs);
+                    let result = self.process_binary_field_op(op, lhs, rhs)?;
+                    self.write(destination.to_usize(), result);
+                }
+                BrilligOpcode::BinaryIntOp {
+                    destination,
+                    op,
+                    bit_size,
+                    lhs,
+                    rhs,
+                } => {
+                    let lhs = self.read(lhs);
+                    let rhs = self.read(rhs);
+                    let result = self.process_binary_int_op(op, u32::from(*bit_size), lhs, rhs)?;
+                    self.write(destination.to_usize(), result);
+                }
+                BrilligOpcode::Cast {
+                    destination,
+                    source,
+                    bit_size,
+                } => {
+                    let value = self.read(source);
+                    let value = match bit_size {
+                        BitSize::Field => value,
+                        BitSize::Integer(bit_size) => {
+                            self.driver.truncate(value, u32::from(*bit_size))?
+                        }
+                    };
+                    self.write(destination.to_usize(), value);
+                }
+                BrilligOpcode::JumpIfNot {
+                    condition,
+                    location,
+                } => {
+                    if self.read_usize(condition, "jump condition")? == 0 {
+                        self.program_counter = *location;
+                        continue;
+                    }
+                }
+                BrilligOpcode::JumpIf {
+                    condition,
+                    location,
+                } => {
+                    if self.read_usize(condition, "jump condition")? != 0 {
+                        self.program_counter = *location;
+                        continue;
+                    }
+                }
+                BrilligOpcode::Jump { location } => {
+                    self.program_counter = *location;
+                    continue;
+                }
+                BrilligOpcode::CalldataCopy {
+                    destination_address,
+                    size_address,
+                    offset_address,
+                } => {
+                    let size = self.read_usize(size_address, "calldata size")?;
+                    let offset = self.read_usize(offset_address, "calldata offset")?;
+                    if offset + size > self.calldata.len() {
+                        Err(eyre::eyre!(
+                            "calldata copy out of bounds: offset {offset}, size {size}, but calldata has length {}",
+                            self.calldata.len()
+                        ))?;
+                    }
+                    let destination = destination_address.to_usize();
+                    for i in 0..size {
+                        let value = self.calldata[offset + i].clone();
+                        self.write(destination + i, value);
+                    }
+                }
+                BrilligOpcode::Call { location } => {
+                    self.call_stack.push(self.program_counter);
+                    self.program_counter = *location;
+                    continue;
+                }
+                BrilligOpcode::Const {
+                    destination,
+                    bit_size: _,
+                    value,
+                } => {
+                    self.write(destination.to_usize(), T::AcvmType::from(value.into_repr()));
+                }
+                BrilligOpcode::IndirectConst {
+                    destination_pointer,
+                    bit_size: _,
+                    value,
+                } => {
+                    let destination = self.read_usize(destination_pointer, "memory address")?;
+                    self.write(destination, T::AcvmType::from(value.into_repr()));
+                }
+                BrilligOpcode::Return => {
+                    let return_location = self.call_stack.pop().ok_or(eyre::eyre!(
+                        "brillig return opcode hit, but callstack already empty"
+                    ))?;
+                    self.program_counter = return_location;
+                }
+                BrilligOpcode::ForeignCall { function, .. } => {
+                    // printing is the only oracle we can safely ignore
+                    if function != "print" {
+                        Err(eyre::eyre!(
+                            "foreign call {function} is not supported in brillig"
+                        ))?;
+                    }
+                }
+                BrilligOpcode::Mov {
+                    destination,
+                    source,
+                } => {
+                    let value = self.read(source);
+                    self.write(destination.to_usize(), value);
+                }
+                BrilligOpcode::ConditionalMov {
+                    destination,
+                    source_a,
+                    source_b,
+                    condition,
+                } => {
+                    let condition = self.read(condition);
+                    let a = self.read(source_a);
+                    let b = self.read(source_b);
+                    let value = match T::get_public(&condition) {
+                        Some(condition) if condition.is_zero() => b,
+                        Some(_) => a,
+                        None => self.driver.cmux(condition, a, b)?,
+                    };
+                    self.write(destination.to_usize(), value);
+                }
+                BrilligOpcode::Load {
+                    destination,
+                    source_pointer,
+                } => {
+                    let source = self.read_usize(source_pointer, "memory address")?;
+                    let value = self.memory.get(source).cloned().unwrap_or_default();
+                    self.write(destination.to_usize(), value);
+                }
+                BrilligOpcode::Store {
+                    destination_pointer,
+                    source,
+                } => {
+                    let destination = self.read_usize(destination_pointer, "memory address")?;
+                    let value = self.read(source);
+                    self.write(destination, value);
+                }
+                BrilligOpcode::BlackBox(BlackBoxOp::ToRadix {
+                    input,
+                    radix,
+                    output,
+                }) => {
+                    let input = self.read(input);
+                    let limbs = self.to_radix(input, *radix, output.size)?;
+                    let destination = self.read_usize(&output.pointer, "memory address")?;
+                    for (i, limb) in limbs.into_iter().enumerate() {
+                        self.write(destination + i, limb);
+                    }
+                }
+                BrilligOpcode::BlackBox(op) => {
+                    Err(eyre::eyre!(
+                        "blackbox {op:?} is not supported in brillig yet"
+                    ))?;
+                }
+                BrilligOpcode::Trap { .. } => {
+                    Err(eyre::eyre!("brillig function trapped"))?;
+                }
+                BrilligOpcode::Stop {
+                    return_data_offset,
+                    return_data_size,
+                } => {
+                    let return_data = (*return_data_offset
+                        ..*return_data_offset + *return_data_size)
+                        .map(|address| self.memory.get(address).cloned().unwrap_or_default())
+                        .collect();
+                    return Ok(return_data);
+                }
+            }
+            self.program_counter += 1;
+        }
+    }
+}
+
+impl<T, F> CoSolver<T, F>
+where
+    T: NoirWitnessExtensionProtocol<F>,
+    F: PrimeField,
+{
+    fn write_brillig_outputs(
+        &mut self,
+        outputs: &[BrilligOutputs],
+        mut values: impl Iterator<Item = T::AcvmType>,
+    ) -> CoAcvmResult<()> {
+        for output in outputs.iter() {
+            let witnesses = match output {
+                BrilligOutputs::Simple(witness) => std::slice::from_ref(witness),
+                BrilligOutputs::Array(witnesses) => witnesses.as_slice(),
+            };
+            for witness in witnesses {
+                let value = values.next().ok_or(eyre::eyre!(
+                    "brillig function returned less values than expected"
+                ))?;
+                self.witness().insert(*witness, value);
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn solve_brillig_call(
+        &mut self,
+        bytecode: &[BrilligOpcode<GenericFieldElement<F>>],
+        inputs: &[BrilligInputs<GenericFieldElement<F>>],
+        outputs: &[BrilligOutputs],
+        predicate: Option<&Expression<GenericFieldElement<F>>>,
+    ) -> CoAcvmResult<()> {
+        tracing::trace!("solving brillig call");
+        if let Some(predicate) = predicate {
+            let predicate = self.evaluate_expression(predicate)?;
+            if T::is_public_zero(&predicate) {
+                tracing::trace!("predicate is false - we write zeros!");
+                return self.write_brillig_outputs(outputs, std::iter::repeat(T::public_zero()));
+            } else if !T::is_public_one(&predicate) {
+                Err(eyre::eyre!(
+                    "predicate must be public and either zero or one"
+                ))?;
+            }
+        }
+        let mut calldata = Vec::new();
+        for input in inputs.iter() {
+            match input {
+                BrilligInputs::Single(expr) => calldata.push(self.evaluate_expression(expr)?),
+                BrilligInputs::Array(exprs) => {
+                    for expr in exprs.iter() {
+                        calldata.push(self.evaluate_expression(expr)?);
+                    }
+                }
+                BrilligInputs::MemoryArray(block_id) => {
+                    let lut = self
+                        .memory_access
+                        .get(block_id.0.into())
+                        .ok_or(eyre::eyre!(
+                            "tried to access block {} but not present",
+                            block_id.0
+                        ))?;
+                    calldata.extend(T::get_lut_values(lut));
+                }
+            }
+        }
+        let return_data = CoBrilligVM::new(&mut self.driver, bytecode, calldata).run()?;
+        self.write_brillig_outputs(outputs, return_data.into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PlainAcvmSolver, Rep3AcvmSolver, Rep3AcvmType};
+    use acir::brillig::{HeapArray, HeapVector, IntegerBitSize};
+    use mpc_core::protocols::{local_network::Rep3LocalNetwork, rep3};
+
+    type F = ark_bn254::Fr;
+    type Opcode = BrilligOpcode<GenericFieldElement<F>>;
+
+    const U128: IntegerBitSize = IntegerBitSize::U128;
+
+    fn constant(destination: usize, value: F) -> Opcode {
+        BrilligOpcode::Const {
+            destination: MemoryAddress(destination),
+            bit_size: BitSize::Field,
+            value: GenericFieldElement::from_repr(value),
+        }
+    }
+
+    /// Copies the calldata to the memory addresses `3..3 + size`.
+    fn copy_calldata(size: usize) -> Vec<Opcode> {
+        vec![
+            constant(0, F::from(size as u64)),
+            constant(1, F::from(0u64)),
+            BrilligOpcode::CalldataCopy {
+                destination_address: MemoryAddress(3),
+                size_address: MemoryAddress(0),
+                offset_address: MemoryAddress(1),
+            },
+        ]
+    }
+
+    fn mul_u128() -> Vec<Opcode> {
+        let mut bytecode = copy_calldata(2);
+        bytecode.extend([
+            BrilligOpcode::BinaryIntOp {
+                destination: MemoryAddress(5),
+                op: BinaryIntOp::Mul,
+                bit_size: U128,
+                lhs: MemoryAddress(3),
+                rhs: MemoryAddress(4),
+            },
+            BrilligOpcode::Stop {
+                return_data_offset: 5,
+                return_data_size: 1,
+            },
+        ]);
+        bytecode
+    }
+
+    fn run_plain(bytecode: &[Opcode], calldata: Vec<F>) -> CoAcvmResult<Vec<F>> {
+        let mut driver = PlainAcvmSolver::<F>::default();
+        CoBrilligVM::new(&mut driver, bytecode, calldata).run()
+    }
+
+    /// Runs the bytecode with all calldata shared between three parties and returns the
+    /// result of every party.
+    fn run_rep3(bytecode: &[Opcode], calldata: &[F]) -> Vec<CoAcvmResult<Vec<Rep3AcvmType<F>>>> {
+        let [share0, share1, share2] =
+            rep3::share_field_elements(calldata, &mut rand::thread_rng());
+        std::thread::scope(|scope| {
+            let parties = Rep3LocalNetwork::new()
+                .into_iter()
+                .zip([share0, share1, share2])
+                .map(|(net, shares)| {
+                    scope.spawn(move || {
+                        let mut driver = Rep3AcvmSolver::<F, _>::new(net)?;
+                        let calldata = shares.into_iter().map(Rep3AcvmType::from).collect();
+                        CoBrilligVM::new(&mut driver, bytecode, calldata).run()
+                    })
+                })
+                .collect::<Vec<_>>();
+            parties
+                .into_iter()
+                .map(|party| party.join().expect("party panicked"))
+                .collect()
+        })
+    }
+
+    #[test]
+    fn public_u128_mul_wraps_around_2_pow_128() {
+        let lhs = u128::MAX - 4;
+        let rhs = (1u128 << 127) + 3;
+        let result = run_plain(&mul_u128(), vec![F::from(lhs), F::from(rhs)]).unwrap();
+        assert_eq!(result, vec![F::from(lhs.wrapping_mul(rhs))]);
+    }
+
+    #[test]
+    fn public_u128_shl_wraps_around_2_pow_128() {
+        let lhs = u128::MAX - 4;
+        let mut bytecode = copy_calldata(2);
+        bytecode.extend([
+            BrilligOpcode::BinaryIntOp {
+                destination: MemoryAddress(5),
+                op: BinaryIntOp::Shl,
+                bit_size: U128,
+                lhs: MemoryAddress(3),
+                rhs: MemoryAddress(4),
+            },
+            BrilligOpcode::Stop {
+                return_data_offset: 5,
+                return_data_size: 1,
+            },
+        ]);
+        let result = run_plain(&bytecode, vec![F::from(lhs), F::from(100u64)]).unwrap();
+        assert_eq!(result, vec![F::from(lhs << 100)]);
+    }
+
+    #[test]
+    fn shared_u128_mul_is_an_error() {
+        let calldata = [F::from(u128::MAX - 4), F::from(3u64)];
+        for result in run_rep3(&mul_u128(), &calldata) {
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn shared_jump_condition_is_an_error() {
+        let mut bytecode = copy_calldata(1);
+        bytecode.extend([
+            BrilligOpcode::JumpIf {
+                condition: MemoryAddress(3),
+                location: 0,
+            },
+            BrilligOpcode::Stop {
+                return_data_offset: 3,
+                return_data_size: 1,
+            },
+        ]);
+        for result in run_rep3(&bytecode, &[F::from(1u64)]) {
+            assert!(result.is_err());
+        }
+    }
+
+    fn to_radix(radix: u32, num_limbs: usize) -> Vec<Opcode> {
+        let mut bytecode = copy_calldata(1);
+        bytecode.extend([
+            constant(4, F::from(10u64)),
+            BrilligOpcode::BlackBox(BlackBoxOp::ToRadix {
+                input: MemoryAddress(3),
+                radix,
+                output: HeapArray {
+                    pointer: MemoryAddress(4),
+                    size: num_limbs,
+                },
+            }),
+            BrilligOpcode::Stop {
+                return_data_offset: 10,
+                return_data_size: num_limbs,
+            },
+        ]);
+        bytecode
+    }
+
+    #[test]
+    fn to_radix_decomposes_little_endian() {
+        let result = run_plain(&to_radix(2, 6), vec![F::from(11u64)]).unwrap();
+        let expected = [1u64, 1, 0, 1, 0, 0].map(F::from);
+        assert_eq!(result, expected);
+
+        let result = run_plain(&to_radix(10, 3), vec![F::from(1234u64)]).unwrap();
+        let expected = [4u64, 3, 2].map(F::from);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn to_radix_bits_of_the_whole_field() {
+        let input = -F::from(1u64);
+        let result = run_plain(&to_radix(2, 256), vec![input]).unwrap();
+        let input: BigUint = input.into();
+        for (i, bit) in result.into_iter().enumerate() {
+            assert_eq!(bit, F::from(input.bit(i as u64)));
+        }
+    }
+
+    #[test]
+    fn unsupported_foreign_call_is_an_error() {
+        let bytecode = [BrilligOpcode::ForeignCall {
+            function: "get_secret".to_owned(),
+            destinations: vec![],
+            destination_value_types: vec![],
+            inputs: vec![],
+            input_value_types: vec![],
+        }];
+        assert!(run_plain(&bytecode, vec![]).is_err());
+    }
+
+    #[test]
+    fn print_foreign_call_is_ignored() {
+        let bytecode = [
+            BrilligOpcode::ForeignCall {
+                function: "print".to_owned(),
+                destinations: vec![],
+                destination_value_types: vec![],
+                inputs: vec![],
+                input_value_types: vec![],
+            },
+            BrilligOpcode::Stop {
+                return_data_offset: 0,
+                return_data_size: 0,
+            },
+        ];
+        assert_eq!(run_plain(&bytecode, vec![]).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn blackbox_opcode_is_an_error() {
+        let bytecode = [BrilligOpcode::BlackBox(BlackBoxOp::Blake2s {
+            message: HeapVector {
+                pointer: MemoryAddress(0),
+                size: MemoryAddress(1),
+            },
+            output: HeapArray {
+                pointer: MemoryAddress(2),
+                size: 32,
+            },
+        })];
+        assert!(run_plain(&bytecode, vec![]).is_err());
+    }
+}
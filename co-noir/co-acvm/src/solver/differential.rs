@@ -0,0 +1,212 @@
+//! Differential checks of the witness extension in MPC against the plain witness extension.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    path::Path,
+};
+
+use acir::native_types::{Witness, WitnessMap, WitnessStack};
+use ark_ff::PrimeField;
+use eyre::{bail, WrapErr};
+use itertools::{izip, EitherOrBoth, Itertools};
+use mpc_core::protocols::{local_network::Rep3LocalNetwork, rep3};
+use noirc_artifacts::program::ProgramArtifact;
+use rand::{CryptoRng, Rng};
+
+use crate::Rep3AcvmType;
+
+use super::{partial_abi::PublicMarker, PlainCoSolver, Rep3CoSolver};
+
+type LocalRep3CoSolver = Rep3CoSolver<ark_bn254::Fr, Rep3LocalNetwork>;
+
+/// The first witness for which the witness extension in MPC deviates from the plain one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WitnessDivergence<F> {
+    /// The position of the witness map in the witness stack, in the order the functions were solved.
+    pub stack_position: usize,
+    /// The index of the ACIR function the witness belongs to.
+    pub function_index: u32,
+    /// The diverging witness.
+    pub witness: Witness,
+    /// The value computed by the plain witness extension, if any.
+    pub plain: Option<F>,
+    /// The reconstructed value computed in MPC, if any.
+    pub mpc: Option<F>,
+}
+
+impl<F: fmt::Display> fmt::Display for WitnessDivergence<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = |value: &Option<F>| {
+            value
+                .as_ref()
+                .map_or_else(|| "missing".to_owned(), ToString::to_string)
+        };
+        write!(
+            f,
+            "witness {} of function {} (stack position {}) diverges: plain {}, MPC {}",
+            self.witness.0,
+            self.function_index,
+            self.stack_position,
+            value(&self.plain),
+            value(&self.mpc)
+        )
+    }
+}
+
+/// Returns the items of the witness stack in the order they were pushed, i.e., the order in which the functions were solved.
+fn stack_items<T>(mut stack: WitnessStack<T>) -> Vec<(u32, BTreeMap<Witness, T>)> {
+    let mut items = Vec::with_capacity(stack.length());
+    while let Some(item) = stack.pop() {
+        items.push((item.index, item.witness.into_iter().collect()));
+    }
+    items.reverse();
+    items
+}
+
+/// Reconstructs the witness stack from the REP3 witness stacks of the three parties.
+pub fn combine_rep3_witness_stacks<F: PrimeField>(
+    stacks: [WitnessStack<Rep3AcvmType<F>>; 3],
+) -> eyre::Result<WitnessStack<F>> {
+    let [a, b, c] = stacks.map(stack_items);
+    if a.len() != b.len() || b.len() != c.len() {
+        bail!("the witness stacks of the parties have different lengths");
+    }
+    let mut result = WitnessStack::default();
+    for ((index, a), (index_b, b), (index_c, c)) in izip!(a, b, c) {
+        if index != index_b || index != index_c {
+            bail!("the witness stacks of the parties belong to different functions");
+        }
+        if a.len() != b.len() || b.len() != c.len() {
+            bail!("the parties solved different witnesses of function {index}");
+        }
+        let mut witness_map = WitnessMap::default();
+        for ((witness, a), (witness_b, b), (witness_c, c)) in izip!(a, b, c) {
+            if witness != witness_b || witness != witness_c {
+                bail!("the parties solved different witnesses of function {index}");
+            }
+            let value = match (a, b, c) {
+                (Rep3AcvmType::Public(a), Rep3AcvmType::Public(b), Rep3AcvmType::Public(c))
+                    if a == b && b == c =>
+                {
+                    a
+                }
+                (Rep3AcvmType::Shared(a), Rep3AcvmType::Shared(b), Rep3AcvmType::Shared(c)) => {
+                    rep3::combine_field_element(a, b, c)
+                }
+                _ => bail!(
+                    "the parties disagree on witness {} of function {index}",
+                    witness.0
+                ),
+            };
+            witness_map.insert(witness, value);
+        }
+        result.push(index, witness_map);
+    }
+    Ok(result)
+}
+
+/// Compares two witness stacks and returns the first witness in which they differ. Fails if the stacks do not belong to the same sequence of function calls.
+pub fn first_divergence<F: PrimeField>(
+    plain: WitnessStack<F>,
+    mpc: WitnessStack<F>,
+) -> eyre::Result<Option<WitnessDivergence<F>>> {
+    let plain = stack_items(plain);
+    let mpc = stack_items(mpc);
+    let (plain_len, mpc_len) = (plain.len(), mpc.len());
+    for (stack_position, items) in plain.into_iter().zip_longest(mpc).enumerate() {
+        let EitherOrBoth::Both((function_index, plain), (mpc_index, mpc)) = items else {
+            bail!(
+                "the plain witness extension solved {plain_len} functions, but the one in MPC solved {mpc_len}"
+            );
+        };
+        if function_index != mpc_index {
+            bail!(
+                "the call stacks diverge at position {stack_position}: the plain witness extension solved function {function_index}, but the one in MPC solved function {mpc_index}"
+            );
+        }
+        let witnesses = plain.keys().chain(mpc.keys()).collect::<BTreeSet<_>>();
+        for witness in witnesses {
+            let (plain, mpc) = (plain.get(witness), mpc.get(witness));
+            if plain != mpc {
+                return Ok(Some(WitnessDivergence {
+                    stack_position,
+                    function_index,
+                    witness: *witness,
+                    plain: plain.copied(),
+                    mpc: mpc.copied(),
+                }));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Runs the witness extension of the program on the given input file (`Prover.toml` or JSON) once with the plain solver and once with three REP3 parties connected by an in-process network, and returns the first witness in which the reconstructed result deviates from the plain one.
+///
+/// As when splitting the input, private inputs are secret-shared and public inputs remain public.
+pub fn compare_rep3_with_plain<P: AsRef<Path>, R: Rng + CryptoRng>(
+    program: &ProgramArtifact,
+    input: P,
+    rng: &mut R,
+) -> eyre::Result<Option<WitnessDivergence<ark_bn254::Fr>>> {
+    let input = input.as_ref().to_path_buf();
+    let plain = PlainCoSolver::init_plain_driver(program.to_owned(), &input)?
+        .solve()
+        .wrap_err("while running the plain witness extension")?;
+
+    let inputs = LocalRep3CoSolver::partially_read_abi_bn254_fieldelement(
+        &input,
+        &program.abi,
+        &program.bytecode,
+    )?;
+    let mut shares: [BTreeMap<String, Rep3AcvmType<ark_bn254::Fr>>; 3] = Default::default();
+    for (name, value) in inputs {
+        match value {
+            PublicMarker::Public(value) => {
+                for share in shares.iter_mut() {
+                    share.insert(name.to_owned(), Rep3AcvmType::Public(value.into_repr()));
+                }
+            }
+            PublicMarker::Private(value) => {
+                let value_shares = rep3::share_field_element(value.into_repr(), rng);
+                for (share, value_share) in shares.iter_mut().zip(value_shares) {
+                    share.insert(name.to_owned(), Rep3AcvmType::Shared(value_share));
+                }
+            }
+        }
+    }
+
+    let mpc = std::thread::scope(|scope| {
+        let parties = Rep3LocalNetwork::new()
+            .into_iter()
+            .zip(shares)
+            .map(|(net, share)| {
+                scope.spawn(move || {
+                    let witness =
+                        LocalRep3CoSolver::witness_map_from_string_map(share, &program.abi)?;
+                    let solver = LocalRep3CoSolver::from_network_with_witness(
+                        net,
+                        program.to_owned(),
+                        witness,
+                    )?;
+                    Ok::<_, eyre::Report>(solver.solve()?)
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut stacks = Vec::with_capacity(3);
+        for (id, party) in parties.into_iter().enumerate() {
+            let stack = party
+                .join()
+                .map_err(|_| eyre::eyre!("party {id} panicked"))?
+                .wrap_err_with(|| format!("while running the witness extension of party {id}"))?;
+            stacks.push(stack);
+        }
+        Ok::<_, eyre::Report>(stacks)
+    })?;
+    let mpc = combine_rep3_witness_stacks(
+        mpc.try_into()
+            .map_err(|_| eyre::eyre!("expected the witness stacks of three parties"))?,
+    )?;
+    first_divergence(plain, mpc)
+}
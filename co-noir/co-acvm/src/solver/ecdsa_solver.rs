@@ -0,0 +1,224 @@
+use acir::{
+    acir_field::GenericFieldElement, circuit::opcodes::FunctionInput, native_types::Witness,
+};
+use ark_ec::short_weierstrass::SWCurveConfig;
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+
+use crate::mpc::NoirWitnessExtensionProtocol;
+
+use super::{
+    non_native_field::{NonNativeField, NUM_LIMBS},
+    CoAcvmResult, CoSolver,
+};
+
+/// A point in projective coordinates (X : Y : Z) over the non-native base field.
+type ProjectivePoint<T, F> = [Vec<<T as NoirWitnessExtensionProtocol<F>>::AcvmType>; 3];
+
+/// ECDSA verification over a short Weierstrass curve, where the coordinates and the scalars are non-native integers.
+struct EcdsaVerifier<F: PrimeField, T: NoirWitnessExtensionProtocol<F>> {
+    base_field: NonNativeField<F, T>,
+    scalar_field: NonNativeField<F, T>,
+    scalar_bits: usize,
+    coeff_a: BigUint,
+    coeff_b3: BigUint,
+    generator: ProjectivePoint<T, F>,
+}
+
+impl<F: PrimeField, T: NoirWitnessExtensionProtocol<F>> EcdsaVerifier<F, T> {
+    fn new<C: SWCurveConfig>() -> Self
+    where
+        C::BaseField: PrimeField,
+    {
+        let base_modulus: BigUint = C::BaseField::MODULUS.into();
+        let coeff_a: BigUint = C::COEFF_A.into_bigint().into();
+        let coeff_b: BigUint = C::COEFF_B.into_bigint().into();
+        let coeff_b3 = (coeff_b * 3u64) % &base_modulus;
+        let generator_x: BigUint = C::GENERATOR.x.into_bigint().into();
+        let generator_y: BigUint = C::GENERATOR.y.into_bigint().into();
+        Self {
+            base_field: NonNativeField::new(base_modulus),
+            scalar_field: NonNativeField::new(C::ScalarField::MODULUS.into()),
+            scalar_bits: C::ScalarField::MODULUS_BIT_SIZE as usize,
+            coeff_a,
+            coeff_b3,
+            generator: [
+                NonNativeField::<F, T>::constant(&generator_x),
+                NonNativeField::<F, T>::constant(&generator_y),
+                NonNativeField::<F, T>::one(),
+            ],
+        }
+    }
+
+    /// Adds two points using the complete addition formulas for short Weierstrass curves (Algorithm 1 of Renes, Costello and Batina, "Complete addition formulas for prime order elliptic curves"). The formulas also cover doubling and the point at infinity.
+    fn add_points(
+        &self,
+        driver: &mut T,
+        p1: &ProjectivePoint<T, F>,
+        p2: &ProjectivePoint<T, F>,
+    ) -> eyre::Result<ProjectivePoint<T, F>> {
+        let fq = &self.base_field;
+        let [x1, y1, z1] = p1;
+        let [x2, y2, z2] = p2;
+
+        let t0 = fq.mul(driver, x1, x2)?;
+        let t1 = fq.mul(driver, y1, y2)?;
+        let t2 = fq.mul(driver, z1, z2)?;
+        let t3 = fq.add(driver, x1, y1)?;
+        let t4 = fq.add(driver, x2, y2)?;
+        let t3 = fq.mul(driver, &t3, &t4)?;
+        let t4 = fq.add(driver, &t0, &t1)?;
+        let t3 = fq.sub(driver, &t3, &t4)?;
+        let t4 = fq.add(driver, x1, z1)?;
+        let t5 = fq.add(driver, x2, z2)?;
+        let t4 = fq.mul(driver, &t4, &t5)?;
+        let t5 = fq.add(driver, &t0, &t2)?;
+        let t4 = fq.sub(driver, &t4, &t5)?;
+        let t5 = fq.add(driver, y1, z1)?;
+        let x3 = fq.add(driver, y2, z2)?;
+        let t5 = fq.mul(driver, &t5, &x3)?;
+        let x3 = fq.add(driver, &t1, &t2)?;
+        let t5 = fq.sub(driver, &t5, &x3)?;
+        let z3 = fq.mul_by_constant(driver, &t4, &self.coeff_a)?;
+        let x3 = fq.mul_by_constant(driver, &t2, &self.coeff_b3)?;
+        let z3 = fq.add(driver, &x3, &z3)?;
+        let x3 = fq.sub(driver, &t1, &z3)?;
+        let z3 = fq.add(driver, &t1, &z3)?;
+        let y3 = fq.mul(driver, &x3, &z3)?;
+        let t1 = fq.add(driver, &t0, &t0)?;
+        let t1 = fq.add(driver, &t1, &t0)?;
+        let t2 = fq.mul_by_constant(driver, &t2, &self.coeff_a)?;
+        let t4 = fq.mul_by_constant(driver, &t4, &self.coeff_b3)?;
+        let t1 = fq.add(driver, &t1, &t2)?;
+        let t2 = fq.sub(driver, &t0, &t2)?;
+        let t2 = fq.mul_by_constant(driver, &t2, &self.coeff_a)?;
+        let t4 = fq.add(driver, &t4, &t2)?;
+        let t0 = fq.mul(driver, &t1, &t4)?;
+        let y3 = fq.add(driver, &y3, &t0)?;
+        let t0 = fq.mul(driver, &t5, &t4)?;
+        let x3 = fq.mul(driver, &t3, &x3)?;
+        let x3 = fq.sub(driver, &x3, &t0)?;
+        let t0 = fq.mul(driver, &t3, &t1)?;
+        let z3 = fq.mul(driver, &t5, &z3)?;
+        let z3 = fq.add(driver, &z3, &t0)?;
+
+        Ok([x3, y3, z3])
+    }
+
+    /// Selects one of the four points depending on the bits, i.e., returns points[b1 + 2 * b2].
+    fn select_point(
+        driver: &mut T,
+        b1: &T::AcvmType,
+        b2: &T::AcvmType,
+        points: [&ProjectivePoint<T, F>; 4],
+    ) -> eyre::Result<ProjectivePoint<T, F>> {
+        let mut result: ProjectivePoint<T, F> = Default::default();
+        for (coordinate, des) in result.iter_mut().enumerate() {
+            for i in 0..NUM_LIMBS {
+                let lo = driver.cmux(
+                    b1.to_owned(),
+                    points[1][coordinate][i].to_owned(),
+                    points[0][coordinate][i].to_owned(),
+                )?;
+                let hi = driver.cmux(
+                    b1.to_owned(),
+                    points[3][coordinate][i].to_owned(),
+                    points[2][coordinate][i].to_owned(),
+                )?;
+                des.push(driver.cmux(b2.to_owned(), hi, lo)?);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns 1 if (r, s) is a valid signature of the hashed message z under the public key (x, y) and 0 otherwise. The inputs are not reduced.
+    ///
+    /// Following barretenberg, the result only depends on whether x(u1 * G + u2 * Q) equals r modulo the group order. Signatures with r or s outside of [1, n), high s values, or public keys not on the curve make the circuit unsatisfiable.
+    fn verify(
+        &self,
+        driver: &mut T,
+        public_key_x: &[T::AcvmType],
+        public_key_y: &[T::AcvmType],
+        r: &[T::AcvmType],
+        s: &[T::AcvmType],
+        z: &[T::AcvmType],
+    ) -> eyre::Result<T::AcvmType> {
+        let fr = &self.scalar_field;
+        let fq = &self.base_field;
+
+        let s_inv = fr.inverse(driver, s)?;
+        let u1 = fr.mul(driver, z, &s_inv)?;
+        let u2 = fr.mul(driver, r, &s_inv)?;
+        let u1_bits = NonNativeField::<F, T>::to_bits(driver, &u1, self.scalar_bits)?;
+        let u2_bits = NonNativeField::<F, T>::to_bits(driver, &u2, self.scalar_bits)?;
+
+        // Shamir's trick: double-and-add with a lookup into {O, G, Q, G + Q}
+        let infinity = [
+            NonNativeField::<F, T>::zero(),
+            NonNativeField::<F, T>::one(),
+            NonNativeField::<F, T>::zero(),
+        ];
+        let public_key = [
+            fq.reduce(driver, public_key_x)?,
+            fq.reduce(driver, public_key_y)?,
+            NonNativeField::<F, T>::one(),
+        ];
+        let sum = self.add_points(driver, &self.generator, &public_key)?;
+        let table = [&infinity, &self.generator, &public_key, &sum];
+
+        let mut accumulator = infinity.clone();
+        for (b1, b2) in u1_bits.iter().zip(u2_bits.iter()).rev() {
+            accumulator = self.add_points(driver, &accumulator, &accumulator)?;
+            let summand = Self::select_point(driver, b1, b2, table)?;
+            accumulator = self.add_points(driver, &accumulator, &summand)?;
+        }
+
+        // The point at infinity has Z = 0, which is inverted to 0 and thus never matches r != 0
+        let z_inv = fq.inverse(driver, &accumulator[2])?;
+        let x = fq.mul(driver, &accumulator[0], &z_inv)?;
+        let x = fr.reduce(driver, &x)?;
+        NonNativeField::<F, T>::is_equal(driver, &x, r)
+    }
+}
+
+impl<T, F> CoSolver<T, F>
+where
+    T: NoirWitnessExtensionProtocol<F>,
+    F: PrimeField,
+{
+    pub(super) fn solve_ecdsa<C: SWCurveConfig>(
+        &mut self,
+        public_key_x: &[FunctionInput<GenericFieldElement<F>>],
+        public_key_y: &[FunctionInput<GenericFieldElement<F>>],
+        signature: &[FunctionInput<GenericFieldElement<F>>],
+        hashed_message: &[FunctionInput<GenericFieldElement<F>>],
+        output: &Witness,
+    ) -> CoAcvmResult<()>
+    where
+        C::BaseField: PrimeField,
+    {
+        if signature.len() != 64 {
+            Err(eyre::eyre!(
+                "ecdsa: expected a signature of 64 bytes, got {}",
+                signature.len()
+            ))?;
+        }
+        // The inputs are big-endian encoded bytes, longer hashes are truncated to the size of the group order
+        let public_key_x = self.get_function_input_bytes(public_key_x)?;
+        let public_key_y = self.get_function_input_bytes(public_key_y)?;
+        let signature = self.get_function_input_bytes(signature)?;
+        let hashed_message =
+            self.get_function_input_bytes(&hashed_message[..hashed_message.len().min(32)])?;
+
+        let public_key_x = NonNativeField::<F, T>::from_be_bytes(&mut self.driver, &public_key_x);
+        let public_key_y = NonNativeField::<F, T>::from_be_bytes(&mut self.driver, &public_key_y);
+        let r = NonNativeField::<F, T>::from_be_bytes(&mut self.driver, &signature[..32]);
+        let s = NonNativeField::<F, T>::from_be_bytes(&mut self.driver, &signature[32..]);
+        let z = NonNativeField::<F, T>::from_be_bytes(&mut self.driver, &hashed_message);
+
+        let verifier = EcdsaVerifier::<F, T>::new::<C>();
+        let result = verifier.verify(&mut self.driver, &public_key_x, &public_key_y, &r, &s, &z)?;
+        self.witness().insert(*output, result);
+        Ok(())
+    }
+}
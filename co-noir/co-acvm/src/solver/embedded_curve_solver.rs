@@ -0,0 +1,307 @@
+use acir::{
+    acir_field::GenericFieldElement, circuit::opcodes::FunctionInput, native_types::Witness,
+};
+use ark_ff::{One, PrimeField};
+use mpc_core::gadgets::pedersen::{
+    derive_generators, DEFAULT_DOMAIN_SEPARATOR, LENGTH_GENERATOR_DOMAIN_SEPARATOR,
+};
+
+use crate::mpc::NoirWitnessExtensionProtocol;
+
+use super::{CoAcvmResult, CoSolver};
+
+/// The number of bits of the low limb of a scalar of the embedded curve.
+const SCALAR_LO_BITS: usize = 128;
+/// The number of bits of the high limb of a scalar of the embedded curve.
+const SCALAR_HI_BITS: usize = 126;
+
+/// A point on the embedded curve in projective coordinates (X : Y : Z). The point at infinity is (0 : 1 : 0).
+pub(super) type ProjectivePoint<T, F> = [<T as NoirWitnessExtensionProtocol<F>>::AcvmType; 3];
+
+/// Arithmetic on the embedded curve (Grumpkin) y^2 = x^3 - 17, whose base field is the native field. The coordinates of the points may be shared, so all operations are expressed with the driver.
+pub(super) struct EmbeddedCurve<F: PrimeField> {
+    coeff_b3: F,
+}
+
+impl<F: PrimeField> EmbeddedCurve<F> {
+    pub(super) fn new() -> Self {
+        Self {
+            coeff_b3: -F::from(51u64),
+        }
+    }
+
+    fn infinity<T: NoirWitnessExtensionProtocol<F>>() -> ProjectivePoint<T, F> {
+        [
+            T::public_zero(),
+            T::AcvmType::from(F::one()),
+            T::public_zero(),
+        ]
+    }
+
+    /// Translates a public affine point into projective coordinates.
+    pub(super) fn from_native<T: NoirWitnessExtensionProtocol<F>>(
+        (x, y): (F, F),
+    ) -> ProjectivePoint<T, F> {
+        [
+            T::AcvmType::from(x),
+            T::AcvmType::from(y),
+            T::AcvmType::from(F::one()),
+        ]
+    }
+
+    /// Translates an affine point (x, y, is_infinite) into projective coordinates.
+    pub(super) fn from_affine<T: NoirWitnessExtensionProtocol<F>>(
+        driver: &mut T,
+        x: T::AcvmType,
+        y: T::AcvmType,
+        is_infinite: T::AcvmType,
+    ) -> eyre::Result<ProjectivePoint<T, F>> {
+        let x = driver.cmux(is_infinite.to_owned(), T::public_zero(), x)?;
+        let y = driver.cmux(is_infinite.to_owned(), T::AcvmType::from(F::one()), y)?;
+        let mut z = driver.acvm_mul_with_public(-F::one(), is_infinite);
+        driver.acvm_add_assign_with_public(F::one(), &mut z);
+        Ok([x, y, z])
+    }
+
+    /// Translates a projective point into affine coordinates (x, y, is_infinite), where the point at infinity is (0, 0, 1).
+    pub(super) fn to_affine<T: NoirWitnessExtensionProtocol<F>>(
+        driver: &mut T,
+        point: ProjectivePoint<T, F>,
+    ) -> eyre::Result<[T::AcvmType; 3]> {
+        let [x, y, z] = point;
+        // z_inv = 1 / (z + is_infinite) - is_infinite, which is 0 for the point at infinity
+        let is_infinite = driver.equal(z.to_owned(), T::public_zero())?;
+        let shifted = driver.add(z, is_infinite.to_owned());
+        let z_inv = driver.div(T::AcvmType::from(F::one()), shifted)?;
+        let z_inv = driver.sub(z_inv, is_infinite.to_owned());
+        let x = driver.mul(x, z_inv.to_owned())?;
+        let y = driver.mul(y, z_inv)?;
+        Ok([x, y, is_infinite])
+    }
+
+    /// Adds two points using the complete addition formulas for short Weierstrass curves with a = 0 (Algorithm 7 of Renes, Costello and Batina, "Complete addition formulas for prime order elliptic curves"). The formulas also cover doubling and the point at infinity.
+    fn add<T: NoirWitnessExtensionProtocol<F>>(
+        &self,
+        driver: &mut T,
+        p1: &ProjectivePoint<T, F>,
+        p2: &ProjectivePoint<T, F>,
+    ) -> eyre::Result<ProjectivePoint<T, F>> {
+        let [x1, y1, z1] = p1.to_owned();
+        let [x2, y2, z2] = p2.to_owned();
+
+        let t0 = driver.mul(x1.to_owned(), x2.to_owned())?;
+        let t1 = driver.mul(y1.to_owned(), y2.to_owned())?;
+        let t2 = driver.mul(z1.to_owned(), z2.to_owned())?;
+        let t3 = driver.add(x1.to_owned(), y1.to_owned());
+        let t4 = driver.add(x2.to_owned(), y2.to_owned());
+        let t3 = driver.mul(t3, t4)?;
+        let t4 = driver.add(t0.to_owned(), t1.to_owned());
+        let t3 = driver.sub(t3, t4);
+        let t4 = driver.add(y1, z1.to_owned());
+        let x3 = driver.add(y2, z2.to_owned());
+        let t4 = driver.mul(t4, x3)?;
+        let x3 = driver.add(t1.to_owned(), t2.to_owned());
+        let t4 = driver.sub(t4, x3);
+        let x3 = driver.add(x1, z1);
+        let y3 = driver.add(x2, z2);
+        let x3 = driver.mul(x3, y3)?;
+        let y3 = driver.add(t0.to_owned(), t2.to_owned());
+        let y3 = driver.sub(x3, y3);
+        let x3 = driver.add(t0.to_owned(), t0.to_owned());
+        let t0 = driver.add(x3, t0);
+        let t2 = driver.acvm_mul_with_public(self.coeff_b3, t2);
+        let z3 = driver.add(t1.to_owned(), t2.to_owned());
+        let t1 = driver.sub(t1, t2);
+        let y3 = driver.acvm_mul_with_public(self.coeff_b3, y3);
+        let x3 = driver.mul(t4.to_owned(), y3.to_owned())?;
+        let t2 = driver.mul(t3.to_owned(), t1.to_owned())?;
+        let x3 = driver.sub(t2, x3);
+        let y3 = driver.mul(y3, t0.to_owned())?;
+        let t1 = driver.mul(t1, z3.to_owned())?;
+        let y3 = driver.add(t1, y3);
+        let t0 = driver.mul(t0, t3)?;
+        let z3 = driver.mul(z3, t4)?;
+        let z3 = driver.add(z3, t0);
+
+        Ok([x3, y3, z3])
+    }
+
+    /// Returns the point if the bit is 1 and the point at infinity otherwise.
+    fn select<T: NoirWitnessExtensionProtocol<F>>(
+        driver: &mut T,
+        bit: &T::AcvmType,
+        point: &ProjectivePoint<T, F>,
+    ) -> eyre::Result<ProjectivePoint<T, F>> {
+        let infinity = Self::infinity::<T>();
+        let mut result = Self::infinity::<T>();
+        for (des, (p, o)) in result.iter_mut().zip(point.iter().zip(infinity)) {
+            *des = driver.cmux(bit.to_owned(), p.to_owned(), o)?;
+        }
+        Ok(result)
+    }
+
+    /// Computes sum_i scalars_i * points_i with a simultaneous double-and-add (Straus). The scalars are given as their bits, ordered from the least significant to the most significant one, and may differ in length.
+    pub(super) fn multi_scalar_mul<T: NoirWitnessExtensionProtocol<F>>(
+        &self,
+        driver: &mut T,
+        points: &[ProjectivePoint<T, F>],
+        scalar_bits: &[Vec<T::AcvmType>],
+    ) -> eyre::Result<ProjectivePoint<T, F>> {
+        let num_bits = scalar_bits.iter().map(Vec::len).max().unwrap_or_default();
+        let mut accumulator = Self::infinity::<T>();
+        for i in (0..num_bits).rev() {
+            accumulator = self.add(driver, &accumulator, &accumulator)?;
+            for (point, bits) in points.iter().zip(scalar_bits.iter()) {
+                let Some(bit) = bits.get(i) else {
+                    continue;
+                };
+                let summand = Self::select(driver, bit, point)?;
+                accumulator = self.add(driver, &accumulator, &summand)?;
+            }
+        }
+        Ok(accumulator)
+    }
+}
+
+impl<T, F> CoSolver<T, F>
+where
+    T: NoirWitnessExtensionProtocol<F>,
+    F: PrimeField,
+{
+    fn get_embedded_curve_point(
+        &mut self,
+        input: &[FunctionInput<GenericFieldElement<F>>],
+    ) -> CoAcvmResult<ProjectivePoint<T, F>> {
+        let x = self.get_function_input(&input[0])?;
+        let y = self.get_function_input(&input[1])?;
+        let is_infinite = self.get_function_input(&input[2])?;
+        Ok(EmbeddedCurve::<F>::from_affine(
+            &mut self.driver,
+            x,
+            y,
+            is_infinite,
+        )?)
+    }
+
+    fn insert_embedded_curve_point(
+        &mut self,
+        point: ProjectivePoint<T, F>,
+        outputs: &(Witness, Witness, Witness),
+    ) -> CoAcvmResult<()> {
+        let [x, y, is_infinite] = EmbeddedCurve::<F>::to_affine(&mut self.driver, point)?;
+        self.witness().insert(outputs.0, x);
+        self.witness().insert(outputs.1, y);
+        self.witness().insert(outputs.2, is_infinite);
+        Ok(())
+    }
+
+    pub(super) fn solve_multi_scalar_mul(
+        &mut self,
+        points: &[FunctionInput<GenericFieldElement<F>>],
+        scalars: &[FunctionInput<GenericFieldElement<F>>],
+        outputs: &(Witness, Witness, Witness),
+    ) -> CoAcvmResult<()> {
+        if points.len() % 3 != 0 || scalars.len() != 2 * (points.len() / 3) {
+            Err(eyre::eyre!(
+                "multi_scalar_mul: expected 3 inputs per point and 2 inputs per scalar, got {} and {}",
+                points.len(),
+                scalars.len()
+            ))?;
+        }
+        let curve = EmbeddedCurve::new();
+        let mut projective_points = Vec::with_capacity(points.len() / 3);
+        let mut scalar_bits = Vec::with_capacity(points.len() / 3);
+        for (point, scalar) in points.chunks_exact(3).zip(scalars.chunks_exact(2)) {
+            projective_points.push(self.get_embedded_curve_point(point)?);
+            // The scalars are given as a low limb of 128 bits and a high limb of the remaining bits
+            let lo = self.get_function_input(&scalar[0])?;
+            let hi = self.get_function_input(&scalar[1])?;
+            let mut bits = self.driver.decompose(lo, SCALAR_LO_BITS, 1)?;
+            bits.extend(self.driver.decompose(hi, SCALAR_HI_BITS, 1)?);
+            scalar_bits.push(bits);
+        }
+
+        let result = curve.multi_scalar_mul(&mut self.driver, &projective_points, &scalar_bits)?;
+        self.insert_embedded_curve_point(result, outputs)
+    }
+
+    pub(super) fn solve_embedded_curve_add(
+        &mut self,
+        input1: &[FunctionInput<GenericFieldElement<F>>],
+        input2: &[FunctionInput<GenericFieldElement<F>>],
+        outputs: &(Witness, Witness, Witness),
+    ) -> CoAcvmResult<()> {
+        let curve = EmbeddedCurve::new();
+        let p1 = self.get_embedded_curve_point(input1)?;
+        let p2 = self.get_embedded_curve_point(input2)?;
+        let result = curve.add(&mut self.driver, &p1, &p2)?;
+        self.insert_embedded_curve_point(result, outputs)
+    }
+
+    /// Computes the Pedersen commitment sum_i inputs_i * G_{hash_index + i}, optionally including the length generator as used by the Pedersen hash.
+    fn pedersen_commitment(
+        &mut self,
+        inputs: &[T::AcvmType],
+        hash_index: u32,
+        with_length: bool,
+    ) -> CoAcvmResult<ProjectivePoint<T, F>> {
+        let curve = EmbeddedCurve::new();
+        let generators =
+            derive_generators::<F>(DEFAULT_DOMAIN_SEPARATOR, inputs.len(), hash_index as usize);
+        let mut points = Vec::with_capacity(inputs.len() + 1);
+        let mut scalar_bits = Vec::with_capacity(inputs.len() + 1);
+        if with_length {
+            let length_generator =
+                derive_generators::<F>(LENGTH_GENERATOR_DOMAIN_SEPARATOR, 1, 0)[0];
+            points.push(EmbeddedCurve::<F>::from_native::<T>(length_generator));
+            let length = T::AcvmType::from(F::from(inputs.len() as u64));
+            scalar_bits.push(self.driver.decompose(length, usize::BITS as usize, 1)?);
+        }
+        for (input, generator) in inputs.iter().zip(generators) {
+            points.push(EmbeddedCurve::<F>::from_native::<T>(generator));
+            scalar_bits.push(self.driver.decompose(
+                input.to_owned(),
+                F::MODULUS_BIT_SIZE as usize,
+                1,
+            )?);
+        }
+        Ok(curve.multi_scalar_mul(&mut self.driver, &points, &scalar_bits)?)
+    }
+
+    pub(super) fn solve_pedersen_commitment(
+        &mut self,
+        inputs: &[FunctionInput<GenericFieldElement<F>>],
+        domain_separator: u32,
+        outputs: &(Witness, Witness),
+    ) -> CoAcvmResult<()> {
+        let inputs = self.get_function_input_bytes(inputs)?;
+        let result = self.pedersen_commitment(&inputs, domain_separator, false)?;
+        let [x, y, _] = EmbeddedCurve::<F>::to_affine(&mut self.driver, result)?;
+        self.witness().insert(outputs.0, x);
+        self.witness().insert(outputs.1, y);
+        Ok(())
+    }
+
+    /// Computes the Pedersen hash of the inputs, i.e., the x-coordinate of their Pedersen commitment to which the number of inputs times the length generator is added.
+    pub(super) fn pedersen_hash(
+        &mut self,
+        inputs: &[T::AcvmType],
+        hash_index: u32,
+    ) -> CoAcvmResult<T::AcvmType> {
+        let result = self.pedersen_commitment(inputs, hash_index, true)?;
+        let [x, _, _] = EmbeddedCurve::<F>::to_affine(&mut self.driver, result)?;
+        Ok(x)
+    }
+
+    pub(super) fn solve_pedersen_hash(
+        &mut self,
+        inputs: &[FunctionInput<GenericFieldElement<F>>],
+        domain_separator: u32,
+        output: Witness,
+    ) -> CoAcvmResult<()> {
+        let inputs = self.get_function_input_bytes(inputs)?;
+        let result = self.pedersen_hash(&inputs, domain_separator)?;
+        self.witness().insert(output, result);
+        Ok(())
+    }
+}
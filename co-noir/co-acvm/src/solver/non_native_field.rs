@@ -0,0 +1,187 @@
+use std::marker::PhantomData;
+
+use ark_ff::{One, PrimeField, Zero};
+use num_bigint::BigUint;
+
+use crate::mpc::NoirWitnessExtensionProtocol;
+
+/// The number of bits per limb of a non-native integer. Using a multiple of 8 allows to compose the limbs from bytes without communication.
+pub(super) const LIMB_BITS: usize = 64;
+/// The number of limbs of a non-native integer. The additional fifth limb leaves room for the quotients of the modular reductions.
+pub(super) const NUM_LIMBS: usize = 5;
+
+/// Arithmetic modulo a non-native prime. The values are given as NUM_LIMBS little-endian limbs of LIMB_BITS bits and all results are fully reduced.
+pub(super) struct NonNativeField<F: PrimeField, T: NoirWitnessExtensionProtocol<F>> {
+    modulus: BigUint,
+    modulus_limbs: Vec<T::AcvmType>,
+    phantom_data: PhantomData<F>,
+}
+
+impl<F: PrimeField, T: NoirWitnessExtensionProtocol<F>> NonNativeField<F, T> {
+    pub(super) fn new(modulus: BigUint) -> Self {
+        let modulus_limbs = Self::constant(&modulus);
+        Self {
+            modulus,
+            modulus_limbs,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub(super) fn constant(value: &BigUint) -> Vec<T::AcvmType> {
+        let mask = (BigUint::one() << LIMB_BITS) - 1u64;
+        (0..NUM_LIMBS)
+            .map(|i| T::AcvmType::from(F::from((value >> (i * LIMB_BITS)) & &mask)))
+            .collect()
+    }
+
+    pub(super) fn zero() -> Vec<T::AcvmType> {
+        Self::constant(&BigUint::zero())
+    }
+
+    pub(super) fn one() -> Vec<T::AcvmType> {
+        Self::constant(&BigUint::one())
+    }
+
+    /// Composes a non-native integer from big-endian bytes. The result is not reduced.
+    pub(super) fn from_be_bytes(driver: &mut T, bytes: &[T::AcvmType]) -> Vec<T::AcvmType> {
+        let mut limbs = Self::zero();
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks(LIMB_BITS / 8)) {
+            for byte in chunk {
+                let shifted = driver.acvm_mul_with_public(F::from(256u64), limb.to_owned());
+                *limb = driver.add(shifted, byte.to_owned());
+            }
+        }
+        limbs
+    }
+
+    /// Decomposes a reduced non-native integer into its num_bits least significant bits, ordered from the least significant to the most significant one.
+    pub(super) fn to_bits(
+        driver: &mut T,
+        a: &[T::AcvmType],
+        num_bits: usize,
+    ) -> eyre::Result<Vec<T::AcvmType>> {
+        let mut bits = Vec::with_capacity(num_bits);
+        for limb in a.iter().take(num_bits.div_ceil(LIMB_BITS)) {
+            bits.extend(driver.decompose(limb.to_owned(), LIMB_BITS, 1)?);
+        }
+        bits.truncate(num_bits);
+        Ok(bits)
+    }
+
+    /// Decomposes a reduced non-native integer into its num_bytes least significant bytes, ordered from the least significant to the most significant one.
+    pub(super) fn to_le_bytes(
+        driver: &mut T,
+        a: &[T::AcvmType],
+        num_bytes: usize,
+    ) -> eyre::Result<Vec<T::AcvmType>> {
+        let mut bytes = Vec::with_capacity(num_bytes);
+        for limb in a.iter().take((num_bytes * 8).div_ceil(LIMB_BITS)) {
+            bytes.extend(driver.decompose(limb.to_owned(), LIMB_BITS, 8)?);
+        }
+        bytes.resize(num_bytes, T::public_zero());
+        Ok(bytes)
+    }
+
+    /// Computes (a * b + sum(to_add) - sum(to_sub)) mod p.
+    pub(super) fn mul_add(
+        &self,
+        driver: &mut T,
+        a: &[T::AcvmType],
+        b: &[T::AcvmType],
+        to_add: &[&[T::AcvmType]],
+        to_sub: &[&[T::AcvmType]],
+    ) -> eyre::Result<Vec<T::AcvmType>> {
+        let (_, remainder) = driver.non_native_mul_add_div_rem(
+            a,
+            b,
+            &to_add.concat(),
+            &to_sub.concat(),
+            &self.modulus,
+            LIMB_BITS,
+            LIMB_BITS,
+        )?;
+        Ok(remainder)
+    }
+
+    pub(super) fn reduce(
+        &self,
+        driver: &mut T,
+        a: &[T::AcvmType],
+    ) -> eyre::Result<Vec<T::AcvmType>> {
+        self.mul_add(driver, a, &Self::one(), &[], &[])
+    }
+
+    pub(super) fn add(
+        &self,
+        driver: &mut T,
+        a: &[T::AcvmType],
+        b: &[T::AcvmType],
+    ) -> eyre::Result<Vec<T::AcvmType>> {
+        self.mul_add(driver, a, &Self::one(), &[b], &[])
+    }
+
+    /// Computes (a - b) mod p, where b has to be reduced.
+    pub(super) fn sub(
+        &self,
+        driver: &mut T,
+        a: &[T::AcvmType],
+        b: &[T::AcvmType],
+    ) -> eyre::Result<Vec<T::AcvmType>> {
+        self.mul_add(driver, a, &Self::one(), &[&self.modulus_limbs], &[b])
+    }
+
+    pub(super) fn mul(
+        &self,
+        driver: &mut T,
+        a: &[T::AcvmType],
+        b: &[T::AcvmType],
+    ) -> eyre::Result<Vec<T::AcvmType>> {
+        self.mul_add(driver, a, b, &[], &[])
+    }
+
+    /// Computes (a / b) mod p, where the division by zero results in zero.
+    pub(super) fn div(
+        &self,
+        driver: &mut T,
+        a: &[T::AcvmType],
+        b: &[T::AcvmType],
+    ) -> eyre::Result<Vec<T::AcvmType>> {
+        let b_inv = self.inverse(driver, b)?;
+        self.mul(driver, a, &b_inv)
+    }
+
+    pub(super) fn mul_by_constant(
+        &self,
+        driver: &mut T,
+        a: &[T::AcvmType],
+        constant: &BigUint,
+    ) -> eyre::Result<Vec<T::AcvmType>> {
+        if constant.is_zero() {
+            return Ok(Self::zero());
+        }
+        self.mul(driver, a, &Self::constant(constant))
+    }
+
+    /// Computes the inverse modulo p, where zero is mapped to zero.
+    pub(super) fn inverse(
+        &self,
+        driver: &mut T,
+        a: &[T::AcvmType],
+    ) -> eyre::Result<Vec<T::AcvmType>> {
+        driver.non_native_inverse(a, &self.modulus, LIMB_BITS, LIMB_BITS)
+    }
+
+    /// Returns 1 if the reduced values a and b are equal and 0 otherwise.
+    pub(super) fn is_equal(
+        driver: &mut T,
+        a: &[T::AcvmType],
+        b: &[T::AcvmType],
+    ) -> eyre::Result<T::AcvmType> {
+        let mut result = T::AcvmType::from(F::one());
+        for (a, b) in a.iter().zip(b.iter()) {
+            let equal = driver.equal(a.to_owned(), b.to_owned())?;
+            result = driver.mul(result, equal)?;
+        }
+        Ok(result)
+    }
+}
@@ -1,27 +1,48 @@
 use super::CoSolver;
 use crate::mpc::NoirWitnessExtensionProtocol;
-use acir::{circuit::PublicInputs, native_types::WitnessMap, FieldElement};
+use acir::{
+    circuit::PublicInputs,
+    native_types::{Witness, WitnessMap},
+    AcirField, FieldElement,
+};
+use ark_ff::PrimeField;
 use eyre::eyre;
-use noirc_abi::Abi;
+use noirc_abi::{input_parser::Format, Abi, AbiType, Sign, MAIN_RETURN_NAME};
+use num_bigint::BigUint;
+use num_traits::{Num, Zero};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
-// This type is copied from the noirc_abi crate, as it is not public. I just need it to be able to parse input.toml files.
+// This type is adapted from the noirc_abi crate, as it is not public. It covers the values of both input.toml and input.json files.
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(untagged)]
-enum TomlTypes {
+pub(crate) enum InputTypes {
     // This is most likely going to be a hex string
     // But it is possible to support UTF-8
     String(String),
     // Just a regular integer, that can fit in 64 bits
     // Note that the toml spec specifies that all numbers are represented as `i64`s.
     Integer(u64),
+    // Negative integers, only valid for signed integers and fields
+    NegativeInteger(i64),
     // Simple boolean flag
     Bool(bool),
-    // Array of TomlTypes
-    Array(Vec<TomlTypes>),
-    // Struct of TomlTypes
-    Table(BTreeMap<String, TomlTypes>),
+    // Array of InputTypes
+    Array(Vec<InputTypes>),
+    // Struct of InputTypes
+    Table(BTreeMap<String, InputTypes>),
+}
+
+impl InputTypes {
+    fn describe(&self) -> &'static str {
+        match self {
+            InputTypes::String(_) => "a string",
+            InputTypes::Integer(_) | InputTypes::NegativeInteger(_) => "an integer",
+            InputTypes::Bool(_) => "a boolean",
+            InputTypes::Array(_) => "an array",
+            InputTypes::Table(_) => "a table",
+        }
+    }
 }
 
 pub enum PublicMarker<F> {
@@ -29,90 +50,616 @@ pub enum PublicMarker<F> {
     Private(F),
 }
 
+/// Returns the format of an input file based on its extension, i.e., JSON for `.json` files and TOML otherwise.
+pub fn input_format(path: &std::path::Path) -> Format {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("json") => Format::Json,
+        _ => Format::Toml,
+    }
+}
+
+fn parse_input_file(
+    input_string: &str,
+    format: Format,
+) -> eyre::Result<BTreeMap<String, InputTypes>> {
+    let data = match format {
+        Format::Json => serde_json::from_str(input_string)?,
+        Format::Toml => toml::from_str(input_string)?,
+    };
+    Ok(data)
+}
+
+fn field_from_biguint(value: BigUint) -> FieldElement {
+    FieldElement::from_repr(ark_bn254::Fr::from(value))
+}
+
+/// Parses a (possibly negative) decimal or hex string into its sign and magnitude.
+fn parse_str_to_biguint(value: &str, path: &str) -> eyre::Result<(bool, BigUint)> {
+    let (negative, magnitude) = match value.strip_prefix('-') {
+        Some(magnitude) => (true, magnitude),
+        None => (false, value),
+    };
+    let parsed = match magnitude.strip_prefix("0x") {
+        Some(hex) => BigUint::from_str_radix(hex, 16),
+        None => BigUint::from_str_radix(magnitude, 10),
+    };
+    let parsed = parsed.map_err(|err| {
+        eyre!("invalid input for `{path}`: cannot parse \"{value}\" as a number: {err}")
+    })?;
+    Ok((negative, parsed))
+}
+
+/// Returns the sign and magnitude of a numeric input value.
+fn numeric_value(value: &InputTypes, path: &str) -> eyre::Result<(bool, BigUint)> {
+    match value {
+        InputTypes::String(string) => parse_str_to_biguint(string, path),
+        InputTypes::Integer(integer) => Ok((false, BigUint::from(*integer))),
+        InputTypes::NegativeInteger(integer) => {
+            Ok((*integer < 0, BigUint::from(integer.unsigned_abs())))
+        }
+        InputTypes::Bool(flag) => Ok((false, BigUint::from(u8::from(*flag)))),
+        InputTypes::Array(_) | InputTypes::Table(_) => Err(eyre!(
+            "invalid input for `{path}`: expected a number, found {}",
+            value.describe()
+        )),
+    }
+}
+
+/// A segment of a path into an ABI parameter as used in the keys of input files, e.g., `points[1].y` or `plains[2..5]`.
+enum PathSegment {
+    /// A struct field or a tuple element
+    Member(String),
+    /// An array element
+    Index(usize),
+    /// A slice of an array, given by its start and its (exclusive) end
+    Range(usize, usize),
+}
+
+/// Splits an input key into the name of the parameter and the path into it.
+fn parse_input_path(key: &str) -> eyre::Result<(&str, Vec<PathSegment>)> {
+    let invalid = || eyre!("invalid input path `{key}`");
+    let end = key.find(['.', '[']).unwrap_or(key.len());
+    let (name, mut rest) = key.split_at(end);
+    let mut segments = Vec::new();
+    while !rest.is_empty() {
+        if let Some(member) = rest.strip_prefix('.') {
+            let end = member.find(['.', '[']).unwrap_or(member.len());
+            if end == 0 {
+                Err(invalid())?;
+            }
+            segments.push(PathSegment::Member(member[..end].to_owned()));
+            rest = &member[end..];
+        } else if let Some(index) = rest.strip_prefix('[') {
+            let end = index.find(']').ok_or_else(invalid)?;
+            let segment = match index[..end].split_once("..") {
+                Some((start, end)) => PathSegment::Range(
+                    start.trim().parse().map_err(|_| invalid())?,
+                    end.trim().parse().map_err(|_| invalid())?,
+                ),
+                None => PathSegment::Index(index[..end].trim().parse().map_err(|_| invalid())?),
+            };
+            segments.push(segment);
+            rest = &index[end + 1..];
+        } else {
+            Err(invalid())?;
+        }
+    }
+    Ok((name, segments))
+}
+
+/// Resolves a path into a parameter of the given type to the offset of the first field element of the addressed member and its type.
+fn resolve_input_path(
+    typ: &AbiType,
+    segments: &[PathSegment],
+    key: &str,
+) -> eyre::Result<(usize, AbiType)> {
+    let mut offset = 0;
+    let mut typ = typ.to_owned();
+    for segment in segments {
+        typ = match (segment, &typ) {
+            (PathSegment::Index(index), AbiType::Array { length, typ }) => {
+                if *index >= *length as usize {
+                    Err(eyre!(
+                        "invalid input path `{key}`: index {index} is out of bounds for an array of length {length}"
+                    ))?;
+                }
+                offset += index * typ.field_count() as usize;
+                typ.as_ref().to_owned()
+            }
+            (PathSegment::Range(start, end), AbiType::Array { length, typ }) => {
+                if start >= end || *end > *length as usize {
+                    Err(eyre!(
+                        "invalid input path `{key}`: slice {start}..{end} is invalid for an array of length {length}"
+                    ))?;
+                }
+                offset += start * typ.field_count() as usize;
+                AbiType::Array {
+                    length: (end - start) as u32,
+                    typ: typ.to_owned(),
+                }
+            }
+            (PathSegment::Member(name), AbiType::Struct { fields, .. }) => {
+                let position = fields
+                    .iter()
+                    .position(|(field, _)| field == name)
+                    .ok_or(eyre!(
+                        "invalid input path `{key}`: unknown struct field `{name}`"
+                    ))?;
+                offset += fields[..position]
+                    .iter()
+                    .map(|(_, typ)| typ.field_count() as usize)
+                    .sum::<usize>();
+                fields[position].1.to_owned()
+            }
+            (PathSegment::Member(index), AbiType::Tuple { fields }) => {
+                let index = index
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|index| *index < fields.len())
+                    .ok_or(eyre!(
+                        "invalid input path `{key}`: `{index}` is not an element of a tuple of {} elements",
+                        fields.len()
+                    ))?;
+                offset += fields[..index]
+                    .iter()
+                    .map(|typ| typ.field_count() as usize)
+                    .sum::<usize>();
+                fields[index].to_owned()
+            }
+            _ => Err(eyre!(
+                "invalid input path `{key}`: the path does not match the type of the parameter"
+            ))?,
+        };
+    }
+    Ok((offset, typ))
+}
+
+/// Returns the path of the member of a value of the given type that contains the field element with the given index, e.g., `points[1].y`.
+fn member_path(typ: &AbiType, index: usize, path: String) -> String {
+    match typ {
+        AbiType::Array { typ, .. } => {
+            let field_count = typ.field_count() as usize;
+            if field_count == 0 {
+                return path;
+            }
+            member_path(
+                typ,
+                index % field_count,
+                format!("{path}[{}]", index / field_count),
+            )
+        }
+        AbiType::Struct { fields, .. } => {
+            let mut index = index;
+            for (name, typ) in fields.iter() {
+                let field_count = typ.field_count() as usize;
+                if index < field_count {
+                    return member_path(typ, index, format!("{path}.{name}"));
+                }
+                index -= field_count;
+            }
+            path
+        }
+        AbiType::Tuple { fields } => {
+            let mut index = index;
+            for (i, typ) in fields.iter().enumerate() {
+                let field_count = typ.field_count() as usize;
+                if index < field_count {
+                    return member_path(typ, index, format!("{path}.{i}"));
+                }
+                index -= field_count;
+            }
+            path
+        }
+        AbiType::String { .. } => format!("{path}[{index}]"),
+        AbiType::Field | AbiType::Integer { .. } | AbiType::Boolean => path,
+    }
+}
+
+/// Translates an input value into its field elements according to the ABI type, where the field elements are ordered as in the witness of the circuit.
+/// If `partial` is set, struct fields may be missing, and their field elements are `None`.
+fn encode_value(
+    value: &InputTypes,
+    typ: &AbiType,
+    path: &str,
+    partial: bool,
+    encoded: &mut Vec<Option<FieldElement>>,
+) -> eyre::Result<()> {
+    match typ {
+        AbiType::Field => {
+            let (negative, magnitude) = numeric_value(value, path)?;
+            let modulus: BigUint = ark_bn254::Fr::MODULUS.into();
+            if magnitude >= modulus {
+                Err(eyre!(
+                    "invalid input for `{path}`: value exceeds the field modulus {modulus}"
+                ))?;
+            }
+            let field = field_from_biguint(magnitude);
+            encoded.push(Some(if negative { -field } else { field }));
+        }
+        AbiType::Integer { sign, width } => {
+            let (negative, magnitude) = numeric_value(value, path)?;
+            let value = match sign {
+                Sign::Unsigned => {
+                    if negative || magnitude.bits() > u64::from(*width) {
+                        Err(eyre!(
+                            "invalid input for `{path}`: value does not fit into a u{width}"
+                        ))?;
+                    }
+                    magnitude
+                }
+                Sign::Signed => {
+                    // Negative values are encoded in two's complement
+                    let bound = BigUint::from(1u64) << (*width - 1);
+                    if (negative && magnitude > bound) || (!negative && magnitude >= bound) {
+                        Err(eyre!(
+                            "invalid input for `{path}`: value does not fit into an i{width}"
+                        ))?;
+                    }
+                    if negative && !magnitude.is_zero() {
+                        (BigUint::from(1u64) << *width) - magnitude
+                    } else {
+                        magnitude
+                    }
+                }
+            };
+            encoded.push(Some(field_from_biguint(value)));
+        }
+        AbiType::Boolean => {
+            let flag = match value {
+                InputTypes::Bool(flag) => *flag,
+                _ => {
+                    let (negative, magnitude) = numeric_value(value, path)?;
+                    if negative || magnitude.bits() > 1 {
+                        Err(eyre!(
+                            "invalid input for `{path}`: expected a boolean, found {}",
+                            value.describe()
+                        ))?;
+                    }
+                    magnitude.bits() == 1
+                }
+            };
+            encoded.push(Some(FieldElement::from(u128::from(flag))));
+        }
+        AbiType::String { length } => {
+            let InputTypes::String(string) = value else {
+                return Err(eyre!(
+                    "invalid input for `{path}`: expected a string, found {}",
+                    value.describe()
+                ));
+            };
+            if string.len() != *length as usize {
+                Err(eyre!(
+                    "invalid input for `{path}`: expected a string of {length} bytes, found {} bytes",
+                    string.len()
+                ))?;
+            }
+            encoded.extend(
+                string
+                    .bytes()
+                    .map(|byte| Some(FieldElement::from(u128::from(byte)))),
+            );
+        }
+        AbiType::Array { length, typ } => {
+            let InputTypes::Array(elements) = value else {
+                return Err(eyre!(
+                    "invalid input for `{path}`: expected an array, found {}",
+                    value.describe()
+                ));
+            };
+            if elements.len() != *length as usize {
+                Err(eyre!(
+                    "invalid input for `{path}`: expected an array of length {length}, found length {}",
+                    elements.len()
+                ))?;
+            }
+            for (i, element) in elements.iter().enumerate() {
+                encode_value(element, typ, &format!("{path}[{i}]"), partial, encoded)?;
+            }
+        }
+        AbiType::Tuple { fields } => {
+            let InputTypes::Array(elements) = value else {
+                return Err(eyre!(
+                    "invalid input for `{path}`: expected a tuple given as an array, found {}",
+                    value.describe()
+                ));
+            };
+            if elements.len() != fields.len() {
+                Err(eyre!(
+                    "invalid input for `{path}`: expected a tuple of {} elements, found {}",
+                    fields.len(),
+                    elements.len()
+                ))?;
+            }
+            for (i, (element, typ)) in elements.iter().zip(fields.iter()).enumerate() {
+                encode_value(element, typ, &format!("{path}.{i}"), partial, encoded)?;
+            }
+        }
+        AbiType::Struct { fields, .. } => {
+            let InputTypes::Table(table) = value else {
+                return Err(eyre!(
+                    "invalid input for `{path}`: expected a struct, found {}",
+                    value.describe()
+                ));
+            };
+            if let Some(unknown) = table
+                .keys()
+                .find(|key| !fields.iter().any(|(name, _)| name == *key))
+            {
+                Err(eyre!(
+                    "invalid input for `{path}`: unknown struct field `{unknown}`"
+                ))?;
+            }
+            for (name, typ) in fields.iter() {
+                let field_path = format!("{path}.{name}");
+                match table.get(name) {
+                    Some(element) => encode_value(element, typ, &field_path, partial, encoded)?,
+                    None if partial => {
+                        encoded.extend(std::iter::repeat(None).take(typ.field_count() as usize))
+                    }
+                    None => Err(eyre!("missing input for `{field_path}`"))?,
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn next_field(
+    fields: &mut impl Iterator<Item = FieldElement>,
+    path: &str,
+) -> eyre::Result<FieldElement> {
+    fields
+        .next()
+        .ok_or(eyre!("too few values to decode `{path}`"))
+}
+
+/// Translates field elements back into an ABI-typed JSON value, consuming as many field elements as the ABI type requires. This is the inverse of the encoding of input files, where fields are written as hex strings and integers as decimal strings.
+pub fn decode_value_to_json(
+    typ: &AbiType,
+    fields: &mut impl Iterator<Item = FieldElement>,
+    path: &str,
+) -> eyre::Result<serde_json::Value> {
+    let value = match typ {
+        AbiType::Field => {
+            serde_json::Value::String(format!("0x{}", next_field(fields, path)?.to_hex()))
+        }
+        AbiType::Integer { sign, width } => {
+            let value: BigUint = next_field(fields, path)?.into_repr().into();
+            if value.bits() > u64::from(*width) {
+                Err(eyre!(
+                    "cannot decode `{path}`: value does not fit into {width} bits"
+                ))?;
+            }
+            let is_negative = matches!(sign, Sign::Signed) && value.bit(u64::from(*width) - 1);
+            let string = if is_negative {
+                format!("-{}", (BigUint::from(1u64) << *width) - value)
+            } else {
+                value.to_string()
+            };
+            serde_json::Value::String(string)
+        }
+        AbiType::Boolean => {
+            let value = next_field(fields, path)?;
+            if value.num_bits() > 1 {
+                Err(eyre!("cannot decode `{path}`: value is not a boolean"))?;
+            }
+            serde_json::Value::Bool(value.is_one())
+        }
+        AbiType::String { length } => {
+            let mut bytes = Vec::with_capacity(*length as usize);
+            for _ in 0..*length {
+                let byte = next_field(fields, path)?;
+                let byte = u8::try_from(byte.to_u128())
+                    .ok()
+                    .filter(|_| byte.num_bits() <= 8)
+                    .ok_or(eyre!("cannot decode `{path}`: value is not a byte"))?;
+                bytes.push(byte);
+            }
+            let string = String::from_utf8(bytes)
+                .map_err(|_| eyre!("cannot decode `{path}`: string is not valid UTF-8"))?;
+            serde_json::Value::String(string)
+        }
+        AbiType::Array { length, typ } => serde_json::Value::Array(
+            (0..*length)
+                .map(|i| decode_value_to_json(typ, fields, &format!("{path}[{i}]")))
+                .collect::<eyre::Result<_>>()?,
+        ),
+        AbiType::Tuple { fields: types } => serde_json::Value::Array(
+            types
+                .iter()
+                .enumerate()
+                .map(|(i, typ)| decode_value_to_json(typ, fields, &format!("{path}.{i}")))
+                .collect::<eyre::Result<_>>()?,
+        ),
+        AbiType::Struct { fields: types, .. } => serde_json::Value::Object(
+            types
+                .iter()
+                .map(|(name, typ)| {
+                    let value = decode_value_to_json(typ, fields, &format!("{path}.{name}"))?;
+                    Ok((name.to_owned(), value))
+                })
+                .collect::<eyre::Result<_>>()?,
+        ),
+    };
+    Ok(value)
+}
+
+/// The name of the i-th flattened field element of a parameter in the string map.
+fn flattened_name(arg_name: &str, typ_field_len: u32, i: u32) -> String {
+    if typ_field_len == 1 {
+        arg_name.to_owned()
+    } else {
+        format!("{}[{}]", arg_name, i)
+    }
+}
+
 impl<T> CoSolver<T, ark_bn254::Fr>
 where
     T: NoirWitnessExtensionProtocol<ark_bn254::Fr>,
 {
-    pub(crate) fn create_partial_abi(input_string: &str, abi: &Abi) -> eyre::Result<Abi> {
-        let mut res_abi = Abi {
-            parameters: Vec::new(),
-            return_type: abi.return_type.clone(),
-            error_types: BTreeMap::new(),
-        };
-
-        // Parse input.toml into a BTreeMap.
-        let data: BTreeMap<String, TomlTypes> = toml::from_str(input_string)?;
+    /// Encodes the parameters of the ABI that are present in the input file. Besides parameter names, the keys of the input file may be paths into parameters, e.g., `points[1].y` or `plains[2..5]`, such that multiple input owners can contribute members of the same struct or array.
+    /// Missing members are an error if `partial` is false and are skipped otherwise, i.e., their field elements are `None`. Parameters of which no member is present are omitted with a warning.
+    pub(crate) fn encode_input_file(
+        input_string: &str,
+        format: Format,
+        abi: &Abi,
+        partial: bool,
+    ) -> eyre::Result<Vec<(String, Vec<Option<FieldElement>>)>> {
+        let data = parse_input_file(input_string, format)?;
 
-        // Make a new Abi with only the parameters that are present in the input file.
-        for param in abi.parameters.iter() {
-            let arg_name = &param.name;
-            let value = data.get(arg_name);
-            match value {
-                Some(_) => {
-                    res_abi.parameters.push(param.clone());
+        let mut encoded_params = abi
+            .parameters
+            .iter()
+            .map(|param| vec![None; param.typ.field_count() as usize])
+            .collect::<Vec<_>>();
+        for (key, value) in data.iter() {
+            let (name, segments) = parse_input_path(key)?;
+            let Some(position) = abi.parameters.iter().position(|param| param.name == name) else {
+                if key != MAIN_RETURN_NAME {
+                    tracing::warn!("Input {} is not a parameter of the circuit", key);
                 }
-                None => {
-                    tracing::warn!("Argument {} is missing from the input file", arg_name);
+                continue;
+            };
+            let param = &abi.parameters[position];
+            let (offset, typ) = resolve_input_path(&param.typ, &segments, key)?;
+            let mut encoded = Vec::with_capacity(typ.field_count() as usize);
+            encode_value(value, &typ, key, partial, &mut encoded)?;
+
+            for (i, value) in encoded.into_iter().enumerate() {
+                let Some(value) = value else {
+                    continue;
+                };
+                let des = &mut encoded_params[position][offset + i];
+                if des.is_some() {
+                    Err(eyre!(
+                        "duplicate input for `{}`",
+                        member_path(&param.typ, offset + i, param.name.to_owned())
+                    ))?;
                 }
+                *des = Some(value);
             }
         }
 
-        Ok(res_abi)
+        let mut res = Vec::with_capacity(abi.parameters.len());
+        for (param, encoded) in abi.parameters.iter().zip(encoded_params) {
+            let arg_name = &param.name;
+            if !partial {
+                if let Some(missing) = encoded.iter().position(Option::is_none) {
+                    Err(eyre!(
+                        "missing input for `{}`",
+                        member_path(&param.typ, missing, arg_name.to_owned())
+                    ))?;
+                }
+            }
+            if encoded.iter().all(Option::is_none) {
+                tracing::warn!("Argument {} is missing from the input file", arg_name);
+            } else {
+                res.push((arg_name.to_owned(), encoded));
+            }
+        }
+        Ok(res)
     }
 
     pub(crate) fn create_string_map(
         original_abi: &Abi,
-        partial_abi: &Abi,
-        witness: WitnessMap<FieldElement>,
+        encoded: Vec<(String, Vec<Option<FieldElement>>)>,
         public_parameters: &PublicInputs,
     ) -> eyre::Result<BTreeMap<String, PublicMarker<FieldElement>>> {
         let mut res_map = BTreeMap::new();
-        let mut wit_iter = witness.into_iter();
 
         let mut orig_params = original_abi.parameters.iter();
         let mut offset = 0;
 
-        for param in partial_abi.parameters.iter() {
-            let arg_name = &param.name;
-            let typ_field_len = param.typ.field_count();
-
+        for (arg_name, values) in encoded.into_iter() {
             // Calculate real witness offset for the public parameter marker
-            loop {
+            let typ_field_len = loop {
                 let next = orig_params
                     .next()
                     .ok_or(eyre!("Corrupted Witness: Too few witnesses"))?;
 
-                if &next.name == arg_name {
-                    break;
+                if next.name == arg_name {
+                    break next.typ.field_count();
                 }
                 offset += next.typ.field_count();
+            };
+            if values.len() != typ_field_len as usize {
+                return Err(eyre!("Corrupted Witness: Wrong number of witnesses"));
             }
 
-            for i in 0..typ_field_len {
-                let name = if typ_field_len == 1 {
-                    arg_name.to_owned()
-                } else {
-                    format!("{}[{}]", arg_name, i)
-                };
-
-                let (_, el) = wit_iter
-                    .next()
-                    .ok_or(eyre!("Corrupted Witness: Too few witnesses"))?;
-                if public_parameters.contains((offset) as usize) {
-                    res_map.insert(name, PublicMarker::Public(el));
-                } else {
-                    res_map.insert(name, PublicMarker::Private(el));
+            for (i, el) in (0..typ_field_len).zip(values) {
+                // Members that are not present are contributed by other input owners
+                if let Some(el) = el {
+                    let name = flattened_name(&arg_name, typ_field_len, i);
+                    if public_parameters.contains((offset) as usize) {
+                        res_map.insert(name, PublicMarker::Public(el));
+                    } else {
+                        res_map.insert(name, PublicMarker::Private(el));
+                    }
                 }
                 offset += 1;
             }
         }
-        if wit_iter.next().is_some() {
-            return Err(eyre!("Corrupted Witness: Too much witnesses"));
-        }
 
         Ok(res_map)
     }
 
+    /// Returns the ABI path of an entry of a string map, e.g., `points[1].y` for `points[3]`, or the name itself if it does not belong to a parameter of the ABI.
+    pub fn input_path(name: &str, abi: &Abi) -> String {
+        for param in abi.parameters.iter() {
+            let typ_field_len = param.typ.field_count();
+            let index = if typ_field_len == 1 && name == param.name {
+                Some(0)
+            } else {
+                name.strip_prefix(param.name.as_str())
+                    .and_then(|index| index.strip_prefix('['))
+                    .and_then(|index| index.strip_suffix(']'))
+                    .and_then(|index| index.parse::<u32>().ok())
+                    .filter(|index| typ_field_len > 1 && *index < typ_field_len)
+            };
+            if let Some(index) = index {
+                return member_path(&param.typ, index as usize, param.name.to_owned());
+            }
+        }
+        name.to_owned()
+    }
+
+    /// Checks that a string map, e.g., the result of merging the input shares of multiple input owners, contains exactly the members of all parameters of the ABI.
+    pub fn validate_string_map<I>(witness: &BTreeMap<String, I>, abi: &Abi) -> eyre::Result<()> {
+        let mut missing = Vec::new();
+        let mut num_expected = 0;
+        for param in abi.parameters.iter() {
+            let typ_field_len = param.typ.field_count();
+            for i in 0..typ_field_len {
+                if !witness.contains_key(&flattened_name(&param.name, typ_field_len, i)) {
+                    missing.push(member_path(&param.typ, i as usize, param.name.to_owned()));
+                }
+            }
+            num_expected += typ_field_len as usize;
+        }
+        if !missing.is_empty() {
+            Err(eyre!("Missing inputs: {}", missing.join(", ")))?;
+        }
+
+        if witness.len() != num_expected {
+            let unknown = witness
+                .keys()
+                .filter(|name| {
+                    !abi.parameters.iter().any(|param| {
+                        let typ_field_len = param.typ.field_count();
+                        (0..typ_field_len)
+                            .any(|i| **name == flattened_name(&param.name, typ_field_len, i))
+                    })
+                })
+                .map(String::as_str)
+                .collect::<Vec<_>>();
+            Err(eyre!(
+                "Inputs are not parameters of the circuit: {}",
+                unknown.join(", ")
+            ))?;
+        }
+        Ok(())
+    }
+
     pub fn witness_map_from_string_map<I, O>(
         witness: BTreeMap<String, I>,
         abi: &Abi,
@@ -121,6 +668,7 @@ where
         I: Clone,
         O: From<I> + Default,
     {
+        Self::validate_string_map(&witness, abi)?;
         let mut result = WitnessMap::default();
 
         let mut index = 0;
@@ -128,11 +676,7 @@ where
             let arg_name = &params.name;
             let typ_field_len = params.typ.field_count();
             for i in 0..typ_field_len {
-                let should_name = if typ_field_len == 1 {
-                    arg_name.to_owned()
-                } else {
-                    format!("{}[{}]", arg_name, i)
-                };
+                let should_name = flattened_name(arg_name, typ_field_len, i);
                 let el = witness
                     .get(&should_name)
                     .ok_or(eyre!("Corrupted Witness: Missing witness: {}", should_name))?;
@@ -141,10 +685,63 @@ where
                 index += 1;
             }
         }
-        if index as usize != witness.len() {
-            return Err(eyre!("Corrupted Witness: Too many witnesses"));
-        }
 
         Ok(result)
     }
+
+    /// Reconstructs the ABI-typed JSON input file from an (opened) string map as created by splitting an input file. Parameters that are not present in the string map are omitted.
+    pub fn string_map_to_json(
+        witness: &BTreeMap<String, FieldElement>,
+        abi: &Abi,
+    ) -> eyre::Result<serde_json::Value> {
+        let mut result = serde_json::Map::new();
+        for param in abi.parameters.iter() {
+            let arg_name = &param.name;
+            let typ_field_len = param.typ.field_count();
+            let values = (0..typ_field_len)
+                .map(|i| witness.get(&flattened_name(arg_name, typ_field_len, i)))
+                .collect::<Vec<_>>();
+            if values.iter().all(Option::is_none) {
+                continue;
+            }
+            if let Some(missing) = values.iter().position(Option::is_none) {
+                Err(eyre!(
+                    "Incomplete input {}: missing `{}`",
+                    arg_name,
+                    member_path(&param.typ, missing, arg_name.to_owned())
+                ))?;
+            }
+            let values = values.into_iter().flatten();
+            let value = decode_value_to_json(&param.typ, &mut values.copied(), arg_name)?;
+            result.insert(arg_name.to_owned(), value);
+        }
+        Ok(serde_json::Value::Object(result))
+    }
+
+    /// Reconstructs the ABI-typed JSON return value of the circuit from the (opened) witness. The return witnesses are the return values of the main function of the program. Returns `None` if the circuit has no return value.
+    pub fn return_value_to_json(
+        witness: &WitnessMap<FieldElement>,
+        abi: &Abi,
+        return_witnesses: &PublicInputs,
+    ) -> eyre::Result<Option<serde_json::Value>> {
+        let Some(return_type) = &abi.return_type else {
+            return Ok(None);
+        };
+        let values = return_witnesses
+            .indices()
+            .into_iter()
+            .map(|index| {
+                witness
+                    .get(&Witness(index))
+                    .copied()
+                    .ok_or(eyre!("Corrupted Witness: Missing return value {}", index))
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        let mut values = values.into_iter();
+        let value = decode_value_to_json(&return_type.abi_type, &mut values, MAIN_RETURN_NAME)?;
+        if values.next().is_some() {
+            return Err(eyre!("Corrupted Witness: Too many return values"));
+        }
+        Ok(Some(value))
+    }
 }
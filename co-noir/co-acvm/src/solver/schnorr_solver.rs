@@ -0,0 +1,96 @@
+use acir::{
+    acir_field::GenericFieldElement, circuit::opcodes::FunctionInput, native_types::Witness,
+};
+use ark_ff::{One, PrimeField};
+use mpc_core::gadgets::pedersen::grumpkin_generator;
+
+use crate::mpc::NoirWitnessExtensionProtocol;
+
+use super::{
+    embedded_curve_solver::{EmbeddedCurve, ProjectivePoint},
+    CoAcvmResult, CoSolver,
+};
+
+/// The number of bytes of a limb of the signature scalars and of the hash output.
+const LIMB_BYTES: usize = 16;
+
+impl<T, F> CoSolver<T, F>
+where
+    T: NoirWitnessExtensionProtocol<F>,
+    F: PrimeField,
+{
+    /// Composes big-endian bytes into a field element.
+    fn compose_be_bytes(&mut self, bytes: &[T::AcvmType]) -> T::AcvmType {
+        let mut result = T::public_zero();
+        for byte in bytes {
+            let shifted = self.driver.acvm_mul_with_public(F::from(256u64), result);
+            result = self.driver.add(shifted, byte.to_owned());
+        }
+        result
+    }
+
+    /// Returns the bits of the 256-bit integer given by its 32 big-endian bytes, ordered from the least significant to the most significant one.
+    fn be_bytes_to_bits(&mut self, bytes: &[T::AcvmType]) -> CoAcvmResult<Vec<T::AcvmType>> {
+        let hi = self.compose_be_bytes(&bytes[..LIMB_BYTES]);
+        let lo = self.compose_be_bytes(&bytes[LIMB_BYTES..]);
+        let mut bits = self.driver.decompose(lo, 8 * LIMB_BYTES, 1)?;
+        bits.extend(self.driver.decompose(hi, 8 * LIMB_BYTES, 1)?);
+        Ok(bits)
+    }
+
+    /// Verifies a Schnorr signature over the embedded curve (Grumpkin), following barretenberg: the signature (s, e) is valid if e equals blake2s(pedersen_hash(R.x, pk.x, pk.y) || message) for R = s * G + e * pk.
+    pub(super) fn solve_schnorr_verify(
+        &mut self,
+        public_key_x: &FunctionInput<GenericFieldElement<F>>,
+        public_key_y: &FunctionInput<GenericFieldElement<F>>,
+        signature: &[FunctionInput<GenericFieldElement<F>>; 64],
+        message: &[FunctionInput<GenericFieldElement<F>>],
+        output: Witness,
+    ) -> CoAcvmResult<()> {
+        let curve = EmbeddedCurve::new();
+        let public_key_x = self.get_function_input(public_key_x)?;
+        let public_key_y = self.get_function_input(public_key_y)?;
+        let signature = self.get_function_input_bytes(signature)?;
+        let message = self.get_function_input_bytes(message)?;
+
+        // The signature consists of the big-endian bytes of s followed by the big-endian bytes of e
+        let s_bits = self.be_bytes_to_bits(&signature[..2 * LIMB_BYTES])?;
+        let e_bits = self.be_bytes_to_bits(&signature[2 * LIMB_BYTES..])?;
+        let public_key: ProjectivePoint<T, F> = [
+            public_key_x.to_owned(),
+            public_key_y.to_owned(),
+            T::AcvmType::from(F::one()),
+        ];
+        let generator = EmbeddedCurve::<F>::from_native::<T>(grumpkin_generator());
+        let r = curve.multi_scalar_mul(
+            &mut self.driver,
+            &[generator, public_key],
+            &[s_bits, e_bits],
+        )?;
+        let [r_x, _, _] = EmbeddedCurve::<F>::to_affine(&mut self.driver, r)?;
+
+        let compressed = self.pedersen_hash(&[r_x, public_key_x, public_key_y], 0)?;
+        let mut hash_input = self
+            .driver
+            .decompose(compressed, F::MODULUS_BIT_SIZE as usize, 8)?;
+        hash_input.resize(2 * LIMB_BYTES, T::public_zero());
+        hash_input.reverse();
+        hash_input.extend(message);
+        let state = self.blake2s(&hash_input)?;
+        let mut digest = Vec::with_capacity(2 * LIMB_BYTES);
+        for word in state {
+            digest.extend(self.driver.decompose(word, 32, 8)?);
+        }
+
+        // The signature is valid if the digest equals e
+        let digest_hi = self.compose_be_bytes(&digest[..LIMB_BYTES]);
+        let digest_lo = self.compose_be_bytes(&digest[LIMB_BYTES..]);
+        let e_hi = self.compose_be_bytes(&signature[2 * LIMB_BYTES..3 * LIMB_BYTES]);
+        let e_lo = self.compose_be_bytes(&signature[3 * LIMB_BYTES..]);
+        let hi_equal = self.driver.equal(digest_hi, e_hi)?;
+        let lo_equal = self.driver.equal(digest_lo, e_lo)?;
+        let result = self.driver.mul(hi_equal, lo_equal)?;
+        self.witness().insert(output, result);
+        Ok(())
+    }
+}
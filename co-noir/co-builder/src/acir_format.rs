@@ -1,7 +1,7 @@
 use acir::{
     acir_field::GenericFieldElement,
     circuit::{
-        opcodes::{BlackBoxFuncCall, MemOp},
+        opcodes::{BlackBoxFuncCall, ConstantOrWitnessEnum, FunctionInput, MemOp},
         Circuit,
     },
     native_types::{Expression, Witness, WitnessMap},
@@ -11,8 +11,12 @@ use ark_ff::{PrimeField, Zero};
 use std::collections::{HashMap, HashSet};
 
 use crate::types::types::{
-    AcirFormatOriginalOpcodeIndices, BlockConstraint, BlockType, MulQuad, PolyTriple,
-    RecursionConstraint,
+    AcirFormatOriginalOpcodeIndices, Aes128Constraint, BigIntFromLeBytes, BigIntOperation,
+    BigIntOperationType, BigIntToLeBytes, Blake2sConstraint, Blake3Constraint, BlockConstraint,
+    BlockType, EcAdd, EcdsaConstraint, HashInput, KeccakConstraint, Keccakf1600, LogicConstraint,
+    MulQuad, MultiScalarMul, PedersenConstraint, PedersenHashConstraint, PolyTriple,
+    Poseidon2Constraint, RangeConstraint, RecursionConstraint, SchnorrConstraint,
+    Sha256Compression, Sha256Constraint, Sha256Input, WitnessOrConstant,
 };
 
 #[derive(Default)]
@@ -26,32 +30,35 @@ pub struct AcirFormat<F: PrimeField> {
     /// to be able to verify SNARKs on Ethereum.
     pub(crate) recursive: bool,
     pub(crate) num_acir_opcodes: u32,
+    /// Indices of the ACIR Call opcodes. Calls to other ACIR functions are not constrained by the
+    /// circuit, so we refuse to build it if there are any.
+    pub(crate) call_opcodes: Vec<usize>,
     //  using PolyTripleConstraint = bb::poly_triple_<bb::curve::BN254::ScalarField>;
     pub public_inputs: Vec<u32>,
-    //  std::vector<LogicConstraint> logic_constraints;
-    //  std::vector<RangeConstraint> range_constraints;
-    //  std::vector<AES128Constraint> aes128_constraints;
-    //  std::vector<Sha256Constraint> sha256_constraints;
-    //  std::vector<Sha256Compression> sha256_compression;
-    //  std::vector<SchnorrConstraint> schnorr_constraints;
-    //  std::vector<EcdsaSecp256k1Constraint> ecdsa_k1_constraints;
-    //  std::vector<EcdsaSecp256r1Constraint> ecdsa_r1_constraints;
-    //  std::vector<Blake2sConstraint> blake2s_constraints;
-    //  std::vector<Blake3Constraint> blake3_constraints;
-    //  std::vector<KeccakConstraint> keccak_constraints;
-    //  std::vector<Keccakf1600> keccak_permutations;
-    //  std::vector<PedersenConstraint> pedersen_constraints;
-    //  std::vector<PedersenHashConstraint> pedersen_hash_constraints;
-    //  std::vector<Poseidon2Constraint> poseidon2_constraints;
-    //  std::vector<MultiScalarMul> multi_scalar_mul_constraints;
-    //  std::vector<EcAdd> ec_add_constraints;
+    pub(crate) logic_constraints: Vec<LogicConstraint<F>>,
+    pub(crate) range_constraints: Vec<RangeConstraint>,
+    pub(crate) aes128_constraints: Vec<Aes128Constraint<F>>,
+    pub(crate) sha256_constraints: Vec<Sha256Constraint>,
+    pub(crate) sha256_compression: Vec<Sha256Compression<F>>,
+    pub(crate) schnorr_constraints: Vec<SchnorrConstraint<F>>,
+    pub(crate) ecdsa_k1_constraints: Vec<EcdsaConstraint<F>>,
+    pub(crate) ecdsa_r1_constraints: Vec<EcdsaConstraint<F>>,
+    pub(crate) blake2s_constraints: Vec<Blake2sConstraint>,
+    pub(crate) blake3_constraints: Vec<Blake3Constraint>,
+    pub(crate) keccak_constraints: Vec<KeccakConstraint<F>>,
+    pub(crate) keccak_permutations: Vec<Keccakf1600<F>>,
+    pub(crate) pedersen_constraints: Vec<PedersenConstraint<F>>,
+    pub(crate) pedersen_hash_constraints: Vec<PedersenHashConstraint<F>>,
+    pub(crate) poseidon2_constraints: Vec<Poseidon2Constraint<F>>,
+    pub(crate) multi_scalar_mul_constraints: Vec<MultiScalarMul<F>>,
+    pub(crate) ec_add_constraints: Vec<EcAdd<F>>,
     pub(crate) recursion_constraints: Vec<RecursionConstraint>,
     pub(crate) honk_recursion_constraints: Vec<RecursionConstraint>,
     pub(crate) avm_recursion_constraints: Vec<RecursionConstraint>,
     //  std::vector<RecursionConstraint> ivc_recursion_constraints;
-    //  std::vector<BigIntFromLeBytes> bigint_from_le_bytes_constraints;
-    //  std::vector<BigIntToLeBytes> bigint_to_le_bytes_constraints;
-    //  std::vector<BigIntOperation> bigint_operations;
+    pub(crate) bigint_from_le_bytes_constraints: Vec<BigIntFromLeBytes<F>>,
+    pub(crate) bigint_to_le_bytes_constraints: Vec<BigIntToLeBytes>,
+    pub(crate) bigint_operations: Vec<BigIntOperation>,
     pub(crate) assert_equalities: Vec<PolyTriple<F>>,
 
     /// A standard plonk arithmetic constraint, as defined in the poly_triple struct, consists of selector values
@@ -68,14 +75,17 @@ pub struct AcirFormat<F: PrimeField> {
     /// Set of constrained witnesses
     pub(crate) constrained_witness: HashSet<u32>,
 
+    /// Minimal number of bits a witness is range constrained to, if there are multiple range constraints on it
+    pub(crate) minimal_range: HashMap<u32, u32>,
+
     /// Indices of the original opcode that originated each constraint in AcirFormat.
     pub(crate) original_opcode_indices: AcirFormatOriginalOpcodeIndices,
 }
 
 impl<F: PrimeField> AcirFormat<F> {
-    pub fn witness_map_to_witness_vector(
-        witness_map: WitnessMap<GenericFieldElement<F>>,
-    ) -> Vec<F> {
+    /// Converts the sparse [`WitnessMap`] into a dense witness vector. This works for plain field
+    /// elements as well as for the (possibly shared) values produced by the co-acvm.
+    pub fn witness_map_to_witness_vector<T: From<F>>(witness_map: WitnessMap<T>) -> Vec<T> {
         let mut wv = Vec::new();
         let mut index = 0;
         for (w, f) in witness_map.into_iter() {
@@ -83,10 +93,10 @@ impl<F: PrimeField> AcirFormat<F> {
             // To ensure that witnesses sit at the correct indices in the `WitnessVector`, we fill any indices
             // which do not exist within the `WitnessMap` with the dummy value of zero.
             while index < w.0 {
-                wv.push(F::zero());
+                wv.push(T::from(F::zero()));
                 index += 1;
             }
-            wv.push(f.into_repr());
+            wv.push(f);
             index += 1;
         }
         wv
@@ -146,6 +156,7 @@ impl<F: PrimeField> AcirFormat<F> {
                     let opcode_indices = vec![i];
                     block_id_to_block_constraint.insert(block_id, (block, opcode_indices));
                 }
+                acir::circuit::Opcode::Call { .. } => af.call_opcodes.push(i),
                 _ => {}
             }
         }
@@ -430,96 +441,438 @@ impl<F: PrimeField> AcirFormat<F> {
                 iv,
                 key,
                 outputs,
-            } => todo!("BlackBoxFuncCall::AES128Encrypt "),
-            BlackBoxFuncCall::AND { lhs, rhs, output } => todo!("BlackBoxFuncCall::AND"),
-            BlackBoxFuncCall::XOR { lhs, rhs, output } => todo!("BlackBoxFuncCall::XOR"),
-            BlackBoxFuncCall::RANGE { input } => todo!("BlackBoxFuncCall::RANGE"),
-            BlackBoxFuncCall::SHA256 { inputs, outputs } => todo!("BlackBoxFuncCall::SHA256"),
-            BlackBoxFuncCall::Blake2s { inputs, outputs } => todo!("BlackBoxFuncCall::Blake2s"),
-            BlackBoxFuncCall::Blake3 { inputs, outputs } => todo!("BlackBoxFuncCall::Blake3"),
+            } => {
+                af.aes128_constraints.push(Aes128Constraint {
+                    inputs: inputs.iter().map(Self::parse_input).collect(),
+                    iv: std::array::from_fn(|i| Self::parse_input(&iv[i])),
+                    key: std::array::from_fn(|i| Self::parse_input(&key[i])),
+                    outputs: outputs.iter().map(|e| e.0).collect(),
+                });
+                for output in outputs.iter() {
+                    af.constrained_witness.insert(output.0);
+                }
+                af.original_opcode_indices
+                    .aes128_constraints
+                    .push(opcode_index);
+            }
+            BlackBoxFuncCall::AND { lhs, rhs, output } => {
+                af.logic_constraints.push(LogicConstraint {
+                    a: Self::parse_input(&lhs),
+                    b: Self::parse_input(&rhs),
+                    result: output.0,
+                    num_bits: lhs.num_bits(),
+                    is_xor_gate: false,
+                });
+                af.constrained_witness.insert(output.0);
+                af.original_opcode_indices
+                    .logic_constraints
+                    .push(opcode_index);
+            }
+            BlackBoxFuncCall::XOR { lhs, rhs, output } => {
+                af.logic_constraints.push(LogicConstraint {
+                    a: Self::parse_input(&lhs),
+                    b: Self::parse_input(&rhs),
+                    result: output.0,
+                    num_bits: lhs.num_bits(),
+                    is_xor_gate: true,
+                });
+                af.constrained_witness.insert(output.0);
+                af.original_opcode_indices
+                    .logic_constraints
+                    .push(opcode_index);
+            }
+            BlackBoxFuncCall::RANGE { input } => {
+                let witness = Self::get_witness_from_function_input(&input);
+                af.range_constraints.push(RangeConstraint {
+                    witness,
+                    num_bits: input.num_bits(),
+                });
+                af.original_opcode_indices
+                    .range_constraints
+                    .push(opcode_index);
+                // Keep track of the smallest range per witness, such that we only constrain it once
+                af.minimal_range
+                    .entry(witness)
+                    .and_modify(|range| *range = (*range).min(input.num_bits()))
+                    .or_insert(input.num_bits());
+            }
+            BlackBoxFuncCall::SHA256 { inputs, outputs } => {
+                af.sha256_constraints.push(Sha256Constraint {
+                    inputs: inputs
+                        .iter()
+                        .map(|e| Sha256Input {
+                            witness: Self::get_witness_from_function_input(e),
+                            num_bits: e.num_bits(),
+                        })
+                        .collect(),
+                    result: outputs.map(|e| e.0),
+                });
+                for output in outputs.iter() {
+                    af.constrained_witness.insert(output.0);
+                }
+                af.original_opcode_indices
+                    .sha256_constraints
+                    .push(opcode_index);
+            }
+            BlackBoxFuncCall::Blake2s { inputs, outputs } => {
+                af.blake2s_constraints.push(Blake2sConstraint {
+                    inputs: inputs
+                        .iter()
+                        .map(|e| HashInput {
+                            witness: Self::get_witness_from_function_input(e),
+                            num_bits: e.num_bits(),
+                        })
+                        .collect(),
+                    result: outputs.map(|e| e.0),
+                });
+                for output in outputs.iter() {
+                    af.constrained_witness.insert(output.0);
+                }
+                af.original_opcode_indices
+                    .blake2s_constraints
+                    .push(opcode_index);
+            }
+            BlackBoxFuncCall::Blake3 { inputs, outputs } => {
+                af.blake3_constraints.push(Blake3Constraint {
+                    inputs: inputs
+                        .iter()
+                        .map(|e| HashInput {
+                            witness: Self::get_witness_from_function_input(e),
+                            num_bits: e.num_bits(),
+                        })
+                        .collect(),
+                    result: outputs.map(|e| e.0),
+                });
+                for output in outputs.iter() {
+                    af.constrained_witness.insert(output.0);
+                }
+                af.original_opcode_indices
+                    .blake3_constraints
+                    .push(opcode_index);
+            }
             BlackBoxFuncCall::SchnorrVerify {
                 public_key_x,
                 public_key_y,
                 signature,
                 message,
                 output,
-            } => todo!("BlackBoxFuncCall::SchnorrVerify"),
+            } => {
+                af.schnorr_constraints.push(SchnorrConstraint {
+                    message: message.iter().map(Self::parse_input).collect(),
+                    public_key_x: Self::parse_input(public_key_x),
+                    public_key_y: Self::parse_input(public_key_y),
+                    signature: std::array::from_fn(|i| Self::parse_input(&signature[i])),
+                    result: output.0,
+                });
+                af.constrained_witness.insert(output.0);
+                af.original_opcode_indices
+                    .schnorr_constraints
+                    .push(opcode_index);
+            }
             BlackBoxFuncCall::PedersenCommitment {
                 inputs,
                 domain_separator,
                 outputs,
-            } => todo!("BlackBoxFuncCall::PedersenCommitment"),
+            } => {
+                af.pedersen_constraints.push(PedersenConstraint {
+                    scalars: inputs.iter().map(Self::parse_input).collect(),
+                    hash_index: *domain_separator,
+                    result_x: outputs.0 .0,
+                    result_y: outputs.1 .0,
+                });
+                af.constrained_witness.insert(outputs.0 .0);
+                af.constrained_witness.insert(outputs.1 .0);
+                af.original_opcode_indices
+                    .pedersen_constraints
+                    .push(opcode_index);
+            }
             BlackBoxFuncCall::PedersenHash {
                 inputs,
                 domain_separator,
                 output,
-            } => todo!("BlackBoxFuncCall::PedersenHash"),
+            } => {
+                af.pedersen_hash_constraints.push(PedersenHashConstraint {
+                    scalars: inputs.iter().map(Self::parse_input).collect(),
+                    hash_index: *domain_separator,
+                    result: output.0,
+                });
+                af.constrained_witness.insert(output.0);
+                af.original_opcode_indices
+                    .pedersen_hash_constraints
+                    .push(opcode_index);
+            }
             BlackBoxFuncCall::EcdsaSecp256k1 {
                 public_key_x,
                 public_key_y,
                 signature,
                 hashed_message,
                 output,
-            } => todo!("BlackBoxFuncCall::EcdsaSecp256k1"),
+            } => {
+                af.ecdsa_k1_constraints.push(EcdsaConstraint {
+                    hashed_message: hashed_message.iter().map(Self::parse_input).collect(),
+                    signature: std::array::from_fn(|i| Self::parse_input(&signature[i])),
+                    pub_x_indices: std::array::from_fn(|i| Self::parse_input(&public_key_x[i])),
+                    pub_y_indices: std::array::from_fn(|i| Self::parse_input(&public_key_y[i])),
+                    result: output.0,
+                });
+                af.constrained_witness.insert(output.0);
+                af.original_opcode_indices
+                    .ecdsa_k1_constraints
+                    .push(opcode_index);
+            }
             BlackBoxFuncCall::EcdsaSecp256r1 {
                 public_key_x,
                 public_key_y,
                 signature,
                 hashed_message,
                 output,
-            } => todo!("BlackBoxFuncCall::EcdsaSecp256r1"),
+            } => {
+                af.ecdsa_r1_constraints.push(EcdsaConstraint {
+                    hashed_message: hashed_message.iter().map(Self::parse_input).collect(),
+                    signature: std::array::from_fn(|i| Self::parse_input(&signature[i])),
+                    pub_x_indices: std::array::from_fn(|i| Self::parse_input(&public_key_x[i])),
+                    pub_y_indices: std::array::from_fn(|i| Self::parse_input(&public_key_y[i])),
+                    result: output.0,
+                });
+                af.constrained_witness.insert(output.0);
+                af.original_opcode_indices
+                    .ecdsa_r1_constraints
+                    .push(opcode_index);
+            }
             BlackBoxFuncCall::MultiScalarMul {
                 points,
                 scalars,
                 outputs,
-            } => todo!(),
+            } => {
+                af.multi_scalar_mul_constraints.push(MultiScalarMul {
+                    points: points.iter().map(Self::parse_input).collect(),
+                    scalars: scalars.iter().map(Self::parse_input).collect(),
+                    out_point_x: outputs.0 .0,
+                    out_point_y: outputs.1 .0,
+                    out_point_is_infinite: outputs.2 .0,
+                });
+                af.constrained_witness.insert(outputs.0 .0);
+                af.constrained_witness.insert(outputs.1 .0);
+                af.constrained_witness.insert(outputs.2 .0);
+                af.original_opcode_indices
+                    .multi_scalar_mul_constraints
+                    .push(opcode_index);
+            }
             BlackBoxFuncCall::EmbeddedCurveAdd {
                 input1,
                 input2,
                 outputs,
-            } => todo!("BlackBoxFuncCall::EmbeddedCurveAdd"),
+            } => {
+                af.ec_add_constraints.push(EcAdd {
+                    input1_x: Self::parse_input(&input1[0]),
+                    input1_y: Self::parse_input(&input1[1]),
+                    input1_infinite: Self::parse_input(&input1[2]),
+                    input2_x: Self::parse_input(&input2[0]),
+                    input2_y: Self::parse_input(&input2[1]),
+                    input2_infinite: Self::parse_input(&input2[2]),
+                    result_x: outputs.0 .0,
+                    result_y: outputs.1 .0,
+                    result_infinite: outputs.2 .0,
+                });
+                af.constrained_witness.insert(outputs.0 .0);
+                af.constrained_witness.insert(outputs.1 .0);
+                af.constrained_witness.insert(outputs.2 .0);
+                af.original_opcode_indices
+                    .ec_add_constraints
+                    .push(opcode_index);
+            }
             BlackBoxFuncCall::Keccak256 {
                 inputs,
                 var_message_size,
                 outputs,
-            } => todo!("BlackBoxFuncCall::Keccak256"),
+            } => {
+                af.keccak_constraints.push(KeccakConstraint {
+                    inputs: inputs
+                        .iter()
+                        .map(|e| HashInput {
+                            witness: Self::get_witness_from_function_input(e),
+                            num_bits: e.num_bits(),
+                        })
+                        .collect(),
+                    var_message_size: Self::parse_input(var_message_size),
+                    result: outputs.map(|e| e.0),
+                });
+                for output in outputs.iter() {
+                    af.constrained_witness.insert(output.0);
+                }
+                af.original_opcode_indices
+                    .keccak_constraints
+                    .push(opcode_index);
+            }
             BlackBoxFuncCall::Keccakf1600 { inputs, outputs } => {
-                todo!("BlackBoxFuncCall::Keccakf1600")
+                af.keccak_permutations.push(Keccakf1600 {
+                    state: std::array::from_fn(|i| Self::parse_input(&inputs[i])),
+                    result: outputs.map(|e| e.0),
+                });
+                for output in outputs.iter() {
+                    af.constrained_witness.insert(output.0);
+                }
+                af.original_opcode_indices
+                    .keccak_permutations
+                    .push(opcode_index);
             }
             BlackBoxFuncCall::RecursiveAggregation {
                 verification_key,
                 proof,
                 public_inputs,
                 key_hash,
-            } => todo!("BlackBoxFuncCall::RecursiveAggregation"),
+            } => {
+                let input_witnesses = |inputs: &[FunctionInput<GenericFieldElement<F>>]| {
+                    inputs
+                        .iter()
+                        .map(Self::get_witness_from_function_input)
+                        .collect::<Vec<_>>()
+                };
+                let constraint = RecursionConstraint {
+                    key: input_witnesses(&verification_key),
+                    proof: input_witnesses(&proof),
+                    public_inputs: input_witnesses(&public_inputs),
+                    key_hash: Self::get_witness_from_function_input(&key_hash),
+                    proof_type: if honk_recursive {
+                        RecursionConstraint::HONK
+                    } else {
+                        RecursionConstraint::PLONK
+                    },
+                };
+                if honk_recursive {
+                    af.honk_recursion_constraints.push(constraint);
+                    af.original_opcode_indices
+                        .honk_recursion_constraints
+                        .push(opcode_index);
+                } else {
+                    af.recursion_constraints.push(constraint);
+                    af.original_opcode_indices
+                        .recursion_constraints
+                        .push(opcode_index);
+                }
+            }
             BlackBoxFuncCall::BigIntAdd { lhs, rhs, output } => {
-                todo!("BlackBoxFuncCall::BigIntAdd")
+                af.bigint_operations.push(BigIntOperation {
+                    lhs,
+                    rhs,
+                    result: output,
+                    opcode: BigIntOperationType::Add,
+                });
+                af.original_opcode_indices
+                    .bigint_operations
+                    .push(opcode_index);
             }
             BlackBoxFuncCall::BigIntSub { lhs, rhs, output } => {
-                todo!("BlackBoxFuncCall::BigIntSub")
+                af.bigint_operations.push(BigIntOperation {
+                    lhs,
+                    rhs,
+                    result: output,
+                    opcode: BigIntOperationType::Sub,
+                });
+                af.original_opcode_indices
+                    .bigint_operations
+                    .push(opcode_index);
             }
             BlackBoxFuncCall::BigIntMul { lhs, rhs, output } => {
-                todo!("BlackBoxFuncCall::BigIntMul")
+                af.bigint_operations.push(BigIntOperation {
+                    lhs,
+                    rhs,
+                    result: output,
+                    opcode: BigIntOperationType::Mul,
+                });
+                af.original_opcode_indices
+                    .bigint_operations
+                    .push(opcode_index);
             }
             BlackBoxFuncCall::BigIntDiv { lhs, rhs, output } => {
-                todo!("BlackBoxFuncCall::BigIntDiv")
+                af.bigint_operations.push(BigIntOperation {
+                    lhs,
+                    rhs,
+                    result: output,
+                    opcode: BigIntOperationType::Div,
+                });
+                af.original_opcode_indices
+                    .bigint_operations
+                    .push(opcode_index);
             }
             BlackBoxFuncCall::BigIntFromLeBytes {
                 inputs,
                 modulus,
                 output,
-            } => todo!("BlackBoxFuncCall::BigIntFromLeBytes"),
-            BlackBoxFuncCall::BigIntToLeBytes { input, outputs } => todo!(),
+            } => {
+                af.bigint_from_le_bytes_constraints.push(BigIntFromLeBytes {
+                    inputs: inputs.iter().map(Self::parse_input).collect(),
+                    modulus,
+                    result: output,
+                });
+                af.original_opcode_indices
+                    .bigint_from_le_bytes_constraints
+                    .push(opcode_index);
+            }
+            BlackBoxFuncCall::BigIntToLeBytes { input, outputs } => {
+                af.bigint_to_le_bytes_constraints.push(BigIntToLeBytes {
+                    input,
+                    result: outputs.iter().map(|e| e.0).collect(),
+                });
+                for output in outputs.iter() {
+                    af.constrained_witness.insert(output.0);
+                }
+                af.original_opcode_indices
+                    .bigint_to_le_bytes_constraints
+                    .push(opcode_index);
+            }
             BlackBoxFuncCall::Poseidon2Permutation {
                 inputs,
                 outputs,
                 len,
-            } => todo!("BlackBoxFuncCall::Poseidon2Permutation"),
+            } => {
+                af.poseidon2_constraints.push(Poseidon2Constraint {
+                    state: inputs.iter().map(Self::parse_input).collect(),
+                    result: outputs.iter().map(|e| e.0).collect(),
+                    len: *len,
+                });
+                for output in outputs.iter() {
+                    af.constrained_witness.insert(output.0);
+                }
+                af.original_opcode_indices
+                    .poseidon2_constraints
+                    .push(opcode_index);
+            }
             BlackBoxFuncCall::Sha256Compression {
                 inputs,
                 hash_values,
                 outputs,
-            } => todo!("BlackBoxFuncCall::Sha256Compression"),
+            } => {
+                af.sha256_compression.push(Sha256Compression {
+                    inputs: std::array::from_fn(|i| Self::parse_input(&inputs[i])),
+                    hash_values: std::array::from_fn(|i| Self::parse_input(&hash_values[i])),
+                    result: outputs.map(|e| e.0),
+                });
+                for output in outputs.iter() {
+                    af.constrained_witness.insert(output.0);
+                }
+                af.original_opcode_indices
+                    .sha256_compression
+                    .push(opcode_index);
+            }
+        }
+    }
+
+    fn get_witness_from_function_input(input: &FunctionInput<GenericFieldElement<F>>) -> u32 {
+        match input.input_ref() {
+            ConstantOrWitnessEnum::Witness(witness) => witness.0,
+            ConstantOrWitnessEnum::Constant(_) => {
+                panic!("Expected a witness, but got a constant input")
+            }
+        }
+    }
+
+    fn parse_input(input: &FunctionInput<GenericFieldElement<F>>) -> WitnessOrConstant<F> {
+        match input.input_ref() {
+            ConstantOrWitnessEnum::Witness(witness) => WitnessOrConstant::from_index(witness.0),
+            ConstantOrWitnessEnum::Constant(constant) => {
+                WitnessOrConstant::from_constant(constant.into_repr())
+            }
         }
     }
 }
@@ -7,24 +7,42 @@ use crate::{
     },
     polynomials::polynomial_types::PrecomputedEntities,
     types::{
+        aes,
+        bigfield::{BigField, NUM_BYTES},
+        blake,
+        cycle_group::CycleGroup,
+        ecdsa, honk_recursion, keccak, pedersen,
         plookup::{BasicTableId, MultiTableId, Plookup},
+        poseidon2, schnorr, sha256,
         types::{
-            AddQuad, AddTriple, AggregationObjectIndices, AggregationObjectPubInputIndices,
-            AuxSelectors, BlockConstraint, BlockType, CachedPartialNonNativeFieldMultiplication,
-            ColumnIdx, FieldCT, GateCounter, MulQuad, PlookupBasicTable, PolyTriple, RamTranscript,
-            RangeList, ReadData, RomRecord, RomTable, RomTranscript, UltraTraceBlock,
-            UltraTraceBlocks, NUM_WIRES,
+            AccessType, AddQuad, AddTriple, Aes128Constraint, AggregationObjectIndices,
+            AggregationObjectPubInputIndices, AuxSelectors, BigIntFromLeBytes, BigIntOperation,
+            BigIntOperationType, BigIntToLeBytes, Blake2sConstraint, Blake3Constraint,
+            BlockConstraint, BlockType, CachedPartialNonNativeFieldMultiplication, ColumnIdx,
+            EcAdd, EccAddGate, EccDblGate, EcdsaConstraint, FieldCT, GateCounter, KeccakConstraint,
+            Keccakf1600, LogicConstraint, LookupEntry, MulQuad, MultiScalarMul,
+            NonNativeFieldWitnesses, PedersenConstraint, PedersenHashConstraint, PlookupBasicTable,
+            PolyTriple, Poseidon2Constraint, Poseidon2ExternalGate, Poseidon2InternalGate,
+            RamRecord, RamTable, RamTranscript, RangeList, ReadData, RomRecord, RomTable,
+            RomTranscript, SchnorrConstraint, Sha256Compression, Sha256Constraint, UltraTraceBlock,
+            UltraTraceBlocks, WitnessOrConstant, AGGREGATION_OBJECT_SIZE, NUM_WIRES,
         },
     },
     utils::Utils,
     HonkProofError, HonkProofResult,
 };
-use ark_ec::pairing::Pairing;
-use ark_ff::{One, PrimeField, Zero};
+use acir::native_types::WitnessStack;
+use ark_ec::{pairing::Pairing, short_weierstrass::SWCurveConfig};
+use ark_ff::{Field, One, PrimeField, UniformRand, Zero};
 use co_acvm::{mpc::NoirWitnessExtensionProtocol, PlainAcvmSolver};
 use eyre::OptionExt;
+use itertools::Itertools;
+use mpc_core::{gadgets::aes::AES128_BLOCK_BYTES, lut::LookupTableProvider};
 use num_bigint::BigUint;
-use std::{collections::HashMap, fmt::Debug};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+};
 
 type GateBlocks<F> = UltraTraceBlocks<UltraTraceBlock<F>>;
 
@@ -39,7 +57,7 @@ impl<P: Pairing> UltraCircuitBuilder<P> {
         let contains_recursive_proof = self.contains_recursive_proof;
         let recursive_proof_public_input_indices = self.recursive_proof_public_input_indices;
 
-        let pk = ProvingKey::create(self, crs);
+        let pk = ProvingKey::create(self, crs)?;
         let circuit_size = pk.circuit_size;
 
         let mut commitments = PrecomputedEntities::default();
@@ -70,7 +88,7 @@ impl<P: Pairing> UltraCircuitBuilder<P> {
         };
         let verifier_crs = crs.g2_x;
 
-        let pk = ProvingKey::create(self, prover_crs);
+        let pk = ProvingKey::create(self, prover_crs)?;
         let circuit_size = pk.circuit_size;
 
         let mut commitments = PrecomputedEntities::default();
@@ -109,15 +127,23 @@ pub struct GenericUltraCircuitBuilder<P: Pairing, T: NoirWitnessExtensionProtoco
     pub(crate) zero_idx: u32,
     one_idx: u32,
     pub blocks: GateBlocks<P::ScalarField>, // Storage for wires and selectors for all gate types
-    num_gates: usize,
+    pub(crate) num_gates: usize,
     circuit_finalized: bool,
     pub contains_recursive_proof: bool,
     pub recursive_proof_public_input_indices: AggregationObjectPubInputIndices,
     rom_arrays: Vec<RomTranscript>,
-    ram_arrays: Vec<RamTranscript>,
+    ram_arrays: Vec<
+        RamTranscript<
+            T::AcvmType,
+            <T::Lookup as LookupTableProvider<P::ScalarField>>::SecretSharedMap,
+        >,
+    >,
     pub(crate) lookup_tables: Vec<PlookupBasicTable<P::ScalarField>>,
-    plookup: Plookup<P::ScalarField>,
-    range_lists: HashMap<u64, RangeList>,
+    pub(crate) plookup: Plookup<P::ScalarField>,
+    range_lists: BTreeMap<u64, RangeList>,
+    current_tag: u32,
+    // Stores the accumulated one-hot vectors of lookups with shared keys, indexed by the table index (required by proving key)
+    pub(crate) shared_lookup_read_counts: HashMap<usize, Vec<T::AcvmType>>,
     cached_partial_non_native_field_multiplications:
         Vec<CachedPartialNonNativeFieldMultiplication<P::ScalarField>>,
     // Stores gate index of ROM and RAM reads (required by proving key)
@@ -136,6 +162,10 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
     pub(crate) const NUM_RESERVED_GATES: usize = 4;
     // number of gates created per non-native field operation in process_non_native_field_multiplications
     pub(crate) const GATES_PER_NON_NATIVE_FIELD_MULTIPLICATION_ARITHMETIC: usize = 7;
+    pub(crate) const DEFAULT_NON_NATIVE_FIELD_LIMB_BITS: usize = 68;
+    pub(crate) const DEFAULT_PLOOKUP_RANGE_BITNUM: u64 = 14;
+    pub(crate) const DEFAULT_PLOOKUP_RANGE_STEP_SIZE: u64 = 3;
+    pub(crate) const DEFAULT_PLOOKUP_RANGE_CAP: u64 = (1 << Self::DEFAULT_PLOOKUP_RANGE_BITNUM) - 1;
 
     pub fn create_circuit(
         constraint_system: AcirFormat<P::ScalarField>,
@@ -143,9 +173,16 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         witness: Vec<T::AcvmType>,
         honk_recursion: bool,           // true for ultrahonk
         collect_gates_per_opcode: bool, // false for ultrahonk
-    ) -> Self {
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
         tracing::trace!("Builder create circuit");
 
+        if let Some(opcode_index) = constraint_system.call_opcodes.first() {
+            Err(eyre::eyre!(
+                "opcode {opcode_index} calls another ACIR function, but the constraints of called functions are not added to the circuit"
+            ))?;
+        }
+
         let has_valid_witness_assignments = !witness.is_empty();
 
         let mut builder = Self::init(
@@ -157,13 +194,41 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         );
 
         builder.build_constraints(
+            driver,
             constraint_system,
             has_valid_witness_assignments,
             honk_recursion,
             collect_gates_per_opcode,
-        );
+        )?;
 
-        builder
+        Ok(builder)
+    }
+
+    /// Creates the circuit from the [`WitnessStack`] produced by the co-acvm. The witness of the main
+    /// function is expected on top of the stack. Programs with ACIR Call opcodes are rejected, as the
+    /// called functions would not be constrained by the circuit.
+    pub fn create_circuit_from_witness_stack(
+        constraint_system: AcirFormat<P::ScalarField>,
+        size_hint: usize,
+        mut witness_stack: WitnessStack<T::AcvmType>,
+        honk_recursion: bool,           // true for ultrahonk
+        collect_gates_per_opcode: bool, // false for ultrahonk
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        let witness = witness_stack
+            .pop()
+            .map(|stack_item| {
+                AcirFormat::<P::ScalarField>::witness_map_to_witness_vector(stack_item.witness)
+            })
+            .unwrap_or_default();
+        Self::create_circuit(
+            constraint_system,
+            size_hint,
+            witness,
+            honk_recursion,
+            collect_gates_per_opcode,
+            driver,
+        )
     }
 
     fn new(size_hint: usize) -> Self {
@@ -197,7 +262,9 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
             ram_arrays: Vec::new(),
             lookup_tables: Vec::new(),
             plookup: Default::default(),
-            range_lists: HashMap::new(),
+            range_lists: BTreeMap::new(),
+            current_tag: 0,
+            shared_lookup_read_counts: HashMap::new(),
             cached_partial_non_native_field_multiplications: Vec::new(),
             memory_read_records: Vec::new(),
             memory_write_records: Vec::new(),
@@ -274,7 +341,7 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         }
     }
 
-    fn create_poly_gate(&mut self, inp: &PolyTriple<P::ScalarField>) {
+    pub(crate) fn create_poly_gate(&mut self, inp: &PolyTriple<P::ScalarField>) {
         self.assert_valid_variables(&[inp.a, inp.b, inp.c]);
 
         self.blocks
@@ -392,6 +459,101 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         self.num_gates += 1;
     }
 
+    /**
+     * @brief Create an elliptic curve addition gate
+     *
+     * @details x and y are defined over scalar field. If the previous gate in the elliptic block computed (x1, y1),
+     * e.g., as the output of a previous addition, the gate is fused into it.
+     */
+    pub(crate) fn create_ecc_add_gate(&mut self, inp: &EccAddGate<P::ScalarField>) {
+        self.assert_valid_variables(&[inp.x1, inp.x2, inp.x3, inp.y1, inp.y2, inp.y3]);
+
+        let block = &mut self.blocks.elliptic;
+        let size = block.len();
+        let can_fuse_into_previous_gate = size > 0
+            && block.w_r()[size - 1] == inp.x1
+            && block.w_o()[size - 1] == inp.y1
+            && block.q_3()[size - 1].is_zero()
+            && block.q_4()[size - 1].is_zero()
+            && block.q_1()[size - 1].is_zero()
+            && block.q_arith()[size - 1].is_zero()
+            && block.q_m()[size - 1].is_zero();
+
+        if can_fuse_into_previous_gate {
+            block.q_1()[size - 1] = inp.sign_coefficient;
+            block.q_elliptic()[size - 1] = P::ScalarField::one();
+        } else {
+            block.populate_wires(self.zero_idx, inp.x1, inp.y1, self.zero_idx);
+            block.q_3().push(P::ScalarField::zero());
+            block.q_4().push(P::ScalarField::zero());
+            block.q_1().push(inp.sign_coefficient);
+            block.q_arith().push(P::ScalarField::zero());
+            block.q_2().push(P::ScalarField::zero());
+            block.q_m().push(P::ScalarField::zero());
+            block.q_c().push(P::ScalarField::zero());
+            block.q_delta_range().push(P::ScalarField::zero());
+            block.q_lookup_type().push(P::ScalarField::zero());
+            block.q_elliptic().push(P::ScalarField::one());
+            block.q_aux().push(P::ScalarField::zero());
+            block.q_poseidon2_external().push(P::ScalarField::zero());
+            block.q_poseidon2_internal().push(P::ScalarField::zero());
+            self.check_selector_length_consistency();
+            self.num_gates += 1;
+        }
+        Self::create_dummy_gate(&mut self.blocks.elliptic, inp.x2, inp.x3, inp.y3, inp.y2);
+        self.check_selector_length_consistency();
+        self.num_gates += 1; // necessary because create dummy gate cannot increment num_gates itself
+    }
+
+    /**
+     * @brief Create an elliptic curve doubling gate
+     *
+     * @details If the previous gate in the elliptic block computed (x1, y1), the gate is fused into it.
+     */
+    pub(crate) fn create_ecc_dbl_gate(&mut self, inp: &EccDblGate) {
+        self.assert_valid_variables(&[inp.x1, inp.x3, inp.y1, inp.y3]);
+
+        let block = &mut self.blocks.elliptic;
+        let size = block.len();
+        let can_fuse_into_previous_gate = size > 0
+            && block.w_r()[size - 1] == inp.x1
+            && block.w_o()[size - 1] == inp.y1
+            && block.q_arith()[size - 1].is_zero()
+            && block.q_elliptic()[size - 1].is_zero()
+            && block.q_aux()[size - 1].is_zero();
+
+        if can_fuse_into_previous_gate {
+            block.q_elliptic()[size - 1] = P::ScalarField::one();
+            block.q_m()[size - 1] = P::ScalarField::one();
+        } else {
+            block.populate_wires(self.zero_idx, inp.x1, inp.y1, self.zero_idx);
+            block.q_elliptic().push(P::ScalarField::one());
+            block.q_m().push(P::ScalarField::one());
+            block.q_1().push(P::ScalarField::zero());
+            block.q_2().push(P::ScalarField::zero());
+            block.q_3().push(P::ScalarField::zero());
+            block.q_c().push(P::ScalarField::zero());
+            block.q_arith().push(P::ScalarField::zero());
+            block.q_4().push(P::ScalarField::zero());
+            block.q_delta_range().push(P::ScalarField::zero());
+            block.q_lookup_type().push(P::ScalarField::zero());
+            block.q_aux().push(P::ScalarField::zero());
+            block.q_poseidon2_external().push(P::ScalarField::zero());
+            block.q_poseidon2_internal().push(P::ScalarField::zero());
+            self.check_selector_length_consistency();
+            self.num_gates += 1;
+        }
+        Self::create_dummy_gate(
+            &mut self.blocks.elliptic,
+            self.zero_idx,
+            inp.x3,
+            inp.y3,
+            self.zero_idx,
+        );
+        self.check_selector_length_consistency();
+        self.num_gates += 1; // necessary because create dummy gate cannot increment num_gates itself
+    }
+
     pub(crate) fn create_big_add_gate(
         &mut self,
         inp: &AddQuad<P::ScalarField>,
@@ -442,11 +604,64 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         self.num_gates += 1;
     }
 
+    pub(crate) fn create_poseidon2_external_gate(
+        &mut self,
+        inp: &Poseidon2ExternalGate<P::ScalarField>,
+    ) {
+        self.assert_valid_variables(&[inp.a, inp.b, inp.c, inp.d]);
+
+        let block = &mut self.blocks.poseidon2_external;
+        block.populate_wires(inp.a, inp.b, inp.c, inp.d);
+        block.q_m().push(P::ScalarField::zero());
+        block.q_1().push(inp.round_constants[0]);
+        block.q_2().push(inp.round_constants[1]);
+        block.q_3().push(inp.round_constants[2]);
+        block.q_c().push(P::ScalarField::zero());
+        block.q_arith().push(P::ScalarField::zero());
+        block.q_4().push(inp.round_constants[3]);
+        block.q_delta_range().push(P::ScalarField::zero());
+        block.q_lookup_type().push(P::ScalarField::zero());
+        block.q_elliptic().push(P::ScalarField::zero());
+        block.q_aux().push(P::ScalarField::zero());
+        block.q_poseidon2_external().push(P::ScalarField::one());
+        block.q_poseidon2_internal().push(P::ScalarField::zero());
+
+        self.check_selector_length_consistency();
+        self.num_gates += 1;
+    }
+
+    pub(crate) fn create_poseidon2_internal_gate(
+        &mut self,
+        inp: &Poseidon2InternalGate<P::ScalarField>,
+    ) {
+        self.assert_valid_variables(&[inp.a, inp.b, inp.c, inp.d]);
+
+        let block = &mut self.blocks.poseidon2_internal;
+        block.populate_wires(inp.a, inp.b, inp.c, inp.d);
+        block.q_m().push(P::ScalarField::zero());
+        block.q_1().push(inp.round_constant);
+        block.q_2().push(P::ScalarField::zero());
+        block.q_3().push(P::ScalarField::zero());
+        block.q_c().push(P::ScalarField::zero());
+        block.q_arith().push(P::ScalarField::zero());
+        block.q_4().push(P::ScalarField::zero());
+        block.q_delta_range().push(P::ScalarField::zero());
+        block.q_lookup_type().push(P::ScalarField::zero());
+        block.q_elliptic().push(P::ScalarField::zero());
+        block.q_aux().push(P::ScalarField::zero());
+        block.q_poseidon2_external().push(P::ScalarField::zero());
+        block.q_poseidon2_internal().push(P::ScalarField::one());
+
+        self.check_selector_length_consistency();
+        self.num_gates += 1;
+    }
+
     fn create_block_constraints(
         &mut self,
         constraint: &BlockConstraint<P::ScalarField>,
         has_valid_witness_assignments: bool,
-    ) {
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
         let mut init = Vec::with_capacity(constraint.init.len());
         for inp in constraint.init.iter() {
             let value = self.poly_to_field_ct(inp);
@@ -456,10 +671,665 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         // Note: CallData/ReturnData not supported by Ultra; interpreted as ROM ops instead
         match constraint.type_ {
             BlockType::CallData | BlockType::ReturnData | BlockType::ROM => {
-                self.process_rom_operations(constraint, has_valid_witness_assignments, init)
+                self.process_rom_operations(constraint, has_valid_witness_assignments, init, driver)
+            }
+            BlockType::RAM => self.process_ram_operations(
+                constraint,
+                has_valid_witness_assignments,
+                init,
+                driver,
+            )?,
+        }
+        Ok(())
+    }
+
+    fn create_logic_gate(
+        &mut self,
+        constraint: &LogicConstraint<P::ScalarField>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let left = constraint.a.to_field_ct();
+        let right = constraint.b.to_field_ct();
+
+        let res = self.create_logic_constraint(
+            left,
+            right,
+            constraint.num_bits as usize,
+            constraint.is_xor_gate,
+            driver,
+        )?;
+        let our_res = FieldCT::from_witness_index(constraint.result);
+        res.assert_equal(&our_res, self, driver);
+        Ok(())
+    }
+
+    /**
+     * @brief A logical AND or XOR over a variable number of bits.
+     *
+     * @details Defaults to basic Builder method if not using plookup-compatible builder. If the left and right operands
+     * are larger than num_bit, the result will be truncated to num_bits.
+     */
+    fn create_logic_constraint(
+        &mut self,
+        a: FieldCT<P::ScalarField>,
+        b: FieldCT<P::ScalarField>,
+        num_bits: usize,
+        is_xor_gate: bool,
+        driver: &mut T,
+    ) -> HonkProofResult<FieldCT<P::ScalarField>> {
+        // ensure the number of bits doesn't exceed field size and is not negatove
+        assert!(num_bits < 254);
+        assert!(num_bits > 0);
+
+        if a.is_constant() && b.is_constant() {
+            let a_native: BigUint = a.additive_constant.into();
+            let b_native: BigUint = b.additive_constant.into();
+            let c_native = if is_xor_gate {
+                a_native ^ b_native
+            } else {
+                a_native & b_native
+            };
+            return Ok(FieldCT::from_field(P::ScalarField::from(c_native)));
+        }
+        if a.is_constant() && !b.is_constant() {
+            let a_witness =
+                FieldCT::from_witness_index(self.put_constant_variable(a.additive_constant));
+            return self.create_logic_constraint(a_witness, b, num_bits, is_xor_gate, driver);
+        }
+        if !a.is_constant() && b.is_constant() {
+            let b_witness =
+                FieldCT::from_witness_index(self.put_constant_variable(b.additive_constant));
+            return self.create_logic_constraint(a, b_witness, num_bits, is_xor_gate, driver);
+        }
+
+        // We slice the input values into 32-bit chunks, and then use a multi-table lookup to compute the AND or XOR
+        // of each chunk. Since we perform the lookup from 32-bit multi-tables, the lookup operation implicitly enforces a
+        // 32-bit range constraint on each chunk. However, if num_bits is not a multiple of 32, the last chunk will be
+        // smaller than 32 bits. Therefore, the last chunk needs to be explicitly range-constrained to ensure it is in the
+        // correct range. The result is then reconstructed from the chunks, and checked against the original value.
+        let num_chunks = num_bits.div_ceil(32);
+
+        // The values might be shared, so we decompose them into chunks in MPC
+        let left = a.get_value(self, driver);
+        let right = b.get_value(self, driver);
+        let left_chunks = driver.decompose(left, num_bits, 32)?;
+        let right_chunks = driver.decompose(right, num_bits, 32)?;
+
+        let mut a_accumulator = FieldCT::from_field(P::ScalarField::zero());
+        let mut b_accumulator = FieldCT::from_field(P::ScalarField::zero());
+        let mut res = FieldCT::from_field(P::ScalarField::zero());
+
+        let table_id = if is_xor_gate {
+            MultiTableId::Uint32Xor
+        } else {
+            MultiTableId::Uint32And
+        };
+
+        for (i, (left_chunk, right_chunk)) in left_chunks.into_iter().zip(right_chunks).enumerate()
+        {
+            let chunk_size = if i != num_chunks - 1 {
+                32
+            } else {
+                num_bits - i * 32
+            };
+
+            let a_chunk = FieldCT::from_witness(left_chunk, self);
+            let b_chunk = FieldCT::from_witness(right_chunk, self);
+            let result_chunk = Plookup::read_from_2_to_1_table(
+                self,
+                driver,
+                table_id.to_owned(),
+                &a_chunk,
+                &b_chunk,
+            )?;
+
+            let scaling_factor =
+                FieldCT::from_field(P::ScalarField::from(BigUint::one() << (32 * i)));
+            let scaled = a_chunk.multiply(&scaling_factor, self, driver)?;
+            a_accumulator = a_accumulator.add(&scaled, self, driver);
+            let scaled = b_chunk.multiply(&scaling_factor, self, driver)?;
+            b_accumulator = b_accumulator.add(&scaled, self, driver);
+
+            if chunk_size != 32 {
+                self.create_range_constraint(a_chunk.witness_index, chunk_size as u32, driver)?;
+                self.create_range_constraint(b_chunk.witness_index, chunk_size as u32, driver)?;
+            }
+
+            let scaled = result_chunk.multiply(&scaling_factor, self, driver)?;
+            res = res.add(&scaled, self, driver);
+        }
+
+        a.assert_equal(&a_accumulator, self, driver);
+        b.assert_equal(&b_accumulator, self, driver);
+
+        Ok(res)
+    }
+
+    fn create_aes128_constraints(
+        &mut self,
+        constraint: &Aes128Constraint<P::ScalarField>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        // The plaintext is padded with PKCS#7, i.e., with n bytes of value n, such that the padded length equals the
+        // length of the ciphertext
+        let num_padding_bytes = constraint
+            .outputs
+            .len()
+            .checked_sub(constraint.inputs.len())
+            .filter(|num| {
+                (1..=AES128_BLOCK_BYTES).contains(num)
+                    && constraint.outputs.len() % AES128_BLOCK_BYTES == 0
+            })
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "aes128: the number of outputs does not match the padded input length",
+                )
+            })?;
+        let padding = FieldCT::from_field(P::ScalarField::from(num_padding_bytes as u64));
+        let mut plaintext = constraint
+            .inputs
+            .iter()
+            .map(|inp| inp.to_field_ct())
+            .collect::<Vec<_>>();
+        plaintext.extend(vec![padding; num_padding_bytes]);
+
+        let mut blocks = Vec::with_capacity(plaintext.len() / AES128_BLOCK_BYTES);
+        for block in plaintext.chunks_exact(AES128_BLOCK_BYTES) {
+            blocks.push(aes::pack_block(block, self, driver)?);
+        }
+        let iv = constraint
+            .iv
+            .iter()
+            .map(|inp| inp.to_field_ct())
+            .collect::<Vec<_>>();
+        let iv = aes::pack_block(&iv, self, driver)?;
+        let key = constraint
+            .key
+            .iter()
+            .map(|inp| inp.to_field_ct())
+            .collect::<Vec<_>>();
+        let key = aes::pack_block(&key, self, driver)?;
+
+        let ciphertext = aes::encrypt_buffer_cbc(&blocks, &iv, &key, self, driver)?;
+
+        // Constrain outputs to be the same as the ones specified. The ciphertext is in sparse form, so we compare it
+        // to the sparse form of the outputs. This avoids translating the (possibly shared) ciphertext back.
+        for (sparse_bytes, outputs) in ciphertext
+            .chunks_exact(AES128_BLOCK_BYTES)
+            .zip(constraint.outputs.chunks_exact(AES128_BLOCK_BYTES))
+        {
+            let outputs = outputs
+                .iter()
+                .map(|output| FieldCT::from_witness_index(*output))
+                .collect::<Vec<_>>();
+            let block = aes::pack_block(&outputs, self, driver)?;
+            aes::assert_equal_sparse_bytes(sparse_bytes, &block, self, driver)?;
+        }
+        Ok(())
+    }
+
+    fn create_sha256_constraints(
+        &mut self,
+        constraint: &Sha256Constraint,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let mut input = Vec::new();
+        for inp in constraint.inputs.iter() {
+            // The implementation requires us to truncate the element to the nearest byte and not bit
+            let num_bytes = inp.num_bits.div_ceil(8) as usize;
+            let element = FieldCT::from_witness_index(inp.witness);
+            input.extend(sha256::to_byte_array(&element, num_bytes, self, driver)?);
+        }
+
+        let output_bytes = sha256::sha256(&input, self, driver)?;
+
+        // Constrain outputs to be the same as the ones specified
+        for (output, result) in output_bytes.iter().zip(constraint.result.iter()) {
+            output.assert_equal(&FieldCT::from_witness_index(*result), self, driver);
+        }
+        Ok(())
+    }
+
+    fn create_sha256_compression_constraints(
+        &mut self,
+        constraint: &Sha256Compression<P::ScalarField>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let inputs = constraint.inputs.each_ref().map(|inp| inp.to_field_ct());
+        let hash_inputs = constraint
+            .hash_values
+            .each_ref()
+            .map(|inp| inp.to_field_ct());
+
+        // Compute sha256 compression
+        let output_bytes = sha256::sha256_block(&hash_inputs, &inputs, self, driver)?;
+
+        for (output, result) in output_bytes.iter().zip(constraint.result.iter()) {
+            let normalised_output = output.normalize(self, driver);
+            if normalised_output.is_constant() {
+                self.fix_witness(*result, normalised_output.additive_constant);
+            } else {
+                self.create_poly_gate(&PolyTriple {
+                    a: normalised_output.witness_index,
+                    b: *result,
+                    c: self.zero_idx,
+                    q_m: P::ScalarField::zero(),
+                    q_l: P::ScalarField::one(),
+                    q_r: -P::ScalarField::one(),
+                    q_o: P::ScalarField::zero(),
+                    q_c: P::ScalarField::zero(),
+                });
             }
-            BlockType::RAM => todo!("BLOCK RAM constraint"),
         }
+        Ok(())
+    }
+
+    fn create_keccak_constraints(
+        &mut self,
+        constraint: &KeccakConstraint<P::ScalarField>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let mut input = Vec::new();
+        for inp in constraint.inputs.iter() {
+            // The implementation requires us to truncate the element to the nearest byte and not bit
+            let num_bytes = inp.num_bits.div_ceil(8) as usize;
+            let element = FieldCT::from_witness_index(inp.witness);
+            input.extend(sha256::to_byte_array(&element, num_bytes, self, driver)?);
+        }
+
+        // The padding depends on the message size, so it has to be public
+        let message_size = constraint.var_message_size.to_field_ct();
+        let message_size_value = message_size.get_value(self, driver);
+        let message_size_value =
+            T::get_public(&message_size_value).ok_or(HonkProofError::ExpectedPublicWitness)?;
+        if !message_size.is_constant() {
+            message_size.assert_equal(&FieldCT::from_field(message_size_value), self, driver);
+        }
+        let message_size: BigUint = message_size_value.into();
+        let message_size: usize = message_size
+            .try_into()
+            .expect("Message size fits into usize");
+        assert!(message_size <= input.len());
+        input.truncate(message_size);
+
+        let output_bytes = keccak::keccak256(&input, self, driver)?;
+
+        // Constrain outputs to be the same as the ones specified
+        for (output, result) in output_bytes.iter().zip(constraint.result.iter()) {
+            output.assert_equal(&FieldCT::from_witness_index(*result), self, driver);
+        }
+        Ok(())
+    }
+
+    fn create_blake2s_constraints(
+        &mut self,
+        constraint: &Blake2sConstraint,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let mut input = Vec::new();
+        for inp in constraint.inputs.iter() {
+            // The implementation requires us to truncate the element to the nearest byte and not bit
+            let num_bytes = inp.num_bits.div_ceil(8) as usize;
+            let element = FieldCT::from_witness_index(inp.witness);
+            input.extend(sha256::to_byte_array(&element, num_bytes, self, driver)?);
+        }
+
+        let output_bytes = blake::blake2s(&input, self, driver)?;
+
+        // Constrain outputs to be the same as the ones specified
+        for (output, result) in output_bytes.iter().zip(constraint.result.iter()) {
+            output.assert_equal(&FieldCT::from_witness_index(*result), self, driver);
+        }
+        Ok(())
+    }
+
+    fn create_blake3_constraints(
+        &mut self,
+        constraint: &Blake3Constraint,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let mut input = Vec::new();
+        for inp in constraint.inputs.iter() {
+            // The implementation requires us to truncate the element to the nearest byte and not bit
+            let num_bytes = inp.num_bits.div_ceil(8) as usize;
+            let element = FieldCT::from_witness_index(inp.witness);
+            input.extend(sha256::to_byte_array(&element, num_bytes, self, driver)?);
+        }
+
+        let output_bytes = blake::blake3(&input, self, driver)?;
+
+        // Constrain outputs to be the same as the ones specified
+        for (output, result) in output_bytes.iter().zip(constraint.result.iter()) {
+            output.assert_equal(&FieldCT::from_witness_index(*result), self, driver);
+        }
+        Ok(())
+    }
+
+    fn create_ecdsa_constraints<C: SWCurveConfig>(
+        &mut self,
+        constraint: &EcdsaConstraint<P::ScalarField>,
+        driver: &mut T,
+    ) -> HonkProofResult<()>
+    where
+        C::BaseField: PrimeField,
+    {
+        let to_field_cts = |inputs: &[WitnessOrConstant<P::ScalarField>]| {
+            inputs
+                .iter()
+                .map(|inp| inp.to_field_ct())
+                .collect::<Vec<_>>()
+        };
+        let public_key_x = to_field_cts(&constraint.pub_x_indices);
+        let public_key_y = to_field_cts(&constraint.pub_y_indices);
+        let signature = to_field_cts(&constraint.signature);
+        let hashed_message = to_field_cts(&constraint.hashed_message);
+
+        let result = ecdsa::verify_signature::<P, T, C>(
+            &public_key_x,
+            &public_key_y,
+            &signature,
+            &hashed_message,
+            self,
+            driver,
+        )?;
+        result.assert_equal(
+            &FieldCT::from_witness_index(constraint.result),
+            self,
+            driver,
+        );
+        Ok(())
+    }
+
+    fn create_schnorr_verify_constraints(
+        &mut self,
+        constraint: &SchnorrConstraint<P::ScalarField>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let signature = constraint
+            .signature
+            .iter()
+            .map(|inp| inp.to_field_ct())
+            .collect::<Vec<_>>();
+        let message = constraint
+            .message
+            .iter()
+            .map(|inp| inp.to_field_ct())
+            .collect::<Vec<_>>();
+
+        let result = schnorr::verify_signature(
+            &constraint.public_key_x.to_field_ct(),
+            &constraint.public_key_y.to_field_ct(),
+            &signature,
+            &message,
+            self,
+            driver,
+        )?;
+        result.assert_equal(
+            &FieldCT::from_witness_index(constraint.result),
+            self,
+            driver,
+        );
+        Ok(())
+    }
+
+    fn create_pedersen_constraint(
+        &mut self,
+        constraint: &PedersenConstraint<P::ScalarField>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let scalars = constraint
+            .scalars
+            .iter()
+            .map(|inp| inp.to_field_ct())
+            .collect::<Vec<_>>();
+
+        let result = pedersen::commit(&scalars, constraint.hash_index as usize, self, driver)?;
+        result.x.assert_equal(
+            &FieldCT::from_witness_index(constraint.result_x),
+            self,
+            driver,
+        );
+        result.y.assert_equal(
+            &FieldCT::from_witness_index(constraint.result_y),
+            self,
+            driver,
+        );
+        Ok(())
+    }
+
+    fn create_pedersen_hash_constraint(
+        &mut self,
+        constraint: &PedersenHashConstraint<P::ScalarField>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let scalars = constraint
+            .scalars
+            .iter()
+            .map(|inp| inp.to_field_ct())
+            .collect::<Vec<_>>();
+
+        let result = pedersen::hash(&scalars, constraint.hash_index as usize, self, driver)?;
+        result.assert_equal(
+            &FieldCT::from_witness_index(constraint.result),
+            self,
+            driver,
+        );
+        Ok(())
+    }
+
+    fn create_multi_scalar_mul_constraint(
+        &mut self,
+        constraint: &MultiScalarMul<P::ScalarField>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        // The points are given as (x, y, is_infinite) and the scalars as (lo, hi)
+        let mut points = Vec::with_capacity(constraint.points.len() / 3);
+        let mut scalars = Vec::with_capacity(constraint.scalars.len() / 2);
+        for (point, scalar) in constraint
+            .points
+            .chunks_exact(3)
+            .zip(constraint.scalars.chunks_exact(2))
+        {
+            points.push(CycleGroup::new(
+                point[0].to_field_ct(),
+                point[1].to_field_ct(),
+                point[2].to_field_ct(),
+            ));
+            scalars.push((scalar[0].to_field_ct(), scalar[1].to_field_ct()));
+        }
+
+        let result = CycleGroup::batch_mul(&points, &scalars, self, driver)?;
+        result.x.assert_equal(
+            &FieldCT::from_witness_index(constraint.out_point_x),
+            self,
+            driver,
+        );
+        result.y.assert_equal(
+            &FieldCT::from_witness_index(constraint.out_point_y),
+            self,
+            driver,
+        );
+        result.is_infinity.assert_equal(
+            &FieldCT::from_witness_index(constraint.out_point_is_infinite),
+            self,
+            driver,
+        );
+        Ok(())
+    }
+
+    fn create_ec_add_constraint(
+        &mut self,
+        constraint: &EcAdd<P::ScalarField>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let input1 = CycleGroup::new(
+            constraint.input1_x.to_field_ct(),
+            constraint.input1_y.to_field_ct(),
+            constraint.input1_infinite.to_field_ct(),
+        );
+        let input2 = CycleGroup::new(
+            constraint.input2_x.to_field_ct(),
+            constraint.input2_y.to_field_ct(),
+            constraint.input2_infinite.to_field_ct(),
+        );
+        input1.validate_is_on_curve(self, driver)?;
+        input2.validate_is_on_curve(self, driver)?;
+
+        let result = input1.add(&input2, self, driver)?;
+        result.x.assert_equal(
+            &FieldCT::from_witness_index(constraint.result_x),
+            self,
+            driver,
+        );
+        result.y.assert_equal(
+            &FieldCT::from_witness_index(constraint.result_y),
+            self,
+            driver,
+        );
+        result.is_infinity.assert_equal(
+            &FieldCT::from_witness_index(constraint.result_infinite),
+            self,
+            driver,
+        );
+        Ok(())
+    }
+
+    /// Returns the moduli supported by the bigint constraints, i.e., the base and scalar fields of BN254, secp256k1, and secp256r1.
+    fn supported_bigint_moduli() -> [BigUint; 6] {
+        [
+            ark_bn254::Fq::MODULUS.into(),
+            ark_bn254::Fr::MODULUS.into(),
+            ark_secp256k1::Fq::MODULUS.into(),
+            ark_secp256k1::Fr::MODULUS.into(),
+            ark_secp256r1::Fq::MODULUS.into(),
+            ark_secp256r1::Fr::MODULUS.into(),
+        ]
+    }
+
+    fn create_bigint_from_le_bytes_constraint(
+        &mut self,
+        constraint: &BigIntFromLeBytes<P::ScalarField>,
+        bigints: &mut HashMap<u32, BigField<P::ScalarField>>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let modulus = BigUint::from_bytes_le(&constraint.modulus);
+        if !Self::supported_bigint_moduli().contains(&modulus) {
+            Err(eyre::eyre!(
+                "bigint_from_le_bytes: modulus {modulus} is not supported"
+            ))?;
+        }
+        if constraint.inputs.len() > NUM_BYTES {
+            Err(eyre::eyre!(
+                "bigint_from_le_bytes: expected at most {NUM_BYTES} bytes, got {}",
+                constraint.inputs.len()
+            ))?;
+        }
+
+        // The inputs are little-endian encoded bytes
+        let bytes = constraint
+            .inputs
+            .iter()
+            .rev()
+            .map(|inp| inp.to_field_ct())
+            .collect::<Vec<_>>();
+        let value = BigField::from_be_bytes(&bytes, &modulus, self, driver)?;
+        bigints.insert(constraint.result, value);
+        Ok(())
+    }
+
+    fn create_bigint_to_le_bytes_constraint(
+        &mut self,
+        constraint: &BigIntToLeBytes,
+        bigints: &HashMap<u32, BigField<P::ScalarField>>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let value = bigints
+            .get(&constraint.input)
+            .ok_or_eyre("bigint_to_le_bytes: bigint is not initialized")?;
+        if constraint.result.len() > NUM_BYTES {
+            Err(eyre::eyre!(
+                "bigint_to_le_bytes: expected at most {NUM_BYTES} bytes, got {}",
+                constraint.result.len()
+            ))?;
+        }
+
+        // Constrain outputs to be the same as the ones specified, the remaining bytes have to be zero
+        let bytes = value.to_le_bytes(self, driver)?;
+        for (i, byte) in bytes.iter().enumerate() {
+            let expected = match constraint.result.get(i) {
+                Some(result) => FieldCT::from_witness_index(*result),
+                None => FieldCT::from_field(P::ScalarField::zero()),
+            };
+            byte.assert_equal(&expected, self, driver);
+        }
+        Ok(())
+    }
+
+    fn create_bigint_operations_constraint(
+        &mut self,
+        constraint: &BigIntOperation,
+        bigints: &mut HashMap<u32, BigField<P::ScalarField>>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let lhs = bigints
+            .get(&constraint.lhs)
+            .ok_or_eyre("bigint operation: lhs is not initialized")?;
+        let rhs = bigints
+            .get(&constraint.rhs)
+            .ok_or_eyre("bigint operation: rhs is not initialized")?;
+        if lhs.modulus != rhs.modulus {
+            Err(eyre::eyre!(
+                "bigint operation: the moduli of the operands do not match"
+            ))?;
+        }
+
+        let result = match constraint.opcode {
+            BigIntOperationType::Add => lhs.add(rhs, self, driver)?,
+            BigIntOperationType::Sub => lhs.sub(rhs, self, driver)?,
+            BigIntOperationType::Mul => lhs.mul(rhs, self, driver)?,
+            BigIntOperationType::Div => lhs.div(rhs, self, driver)?,
+        };
+        bigints.insert(constraint.result, result);
+        Ok(())
+    }
+
+    fn create_keccak_permutations(
+        &mut self,
+        constraint: &Keccakf1600<P::ScalarField>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let state = constraint.state.each_ref().map(|inp| inp.to_field_ct());
+
+        // Compute the permutation
+        let output_state = keccak::keccakf1600_permutation(&state, self, driver)?;
+
+        // Constrain outputs to be the same as the ones specified
+        for (output, result) in output_state.iter().zip(constraint.result.iter()) {
+            output.assert_equal(&FieldCT::from_witness_index(*result), self, driver);
+        }
+        Ok(())
+    }
+
+    fn create_poseidon2_permutations(
+        &mut self,
+        constraint: &Poseidon2Constraint<P::ScalarField>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        assert_eq!(constraint.state.len(), constraint.len as usize);
+        assert_eq!(constraint.result.len(), constraint.len as usize);
+        // The permutation is only instantiated for a state size of 4
+        assert_eq!(constraint.len, 4);
+        let state = std::array::from_fn(|i| constraint.state[i].to_field_ct());
+
+        // Compute the permutation
+        let output_state = poseidon2::poseidon2_permutation(&state, self, driver)?;
+
+        // Constrain outputs to be the same as the ones specified
+        for (output, result) in output_state.iter().zip(constraint.result.iter()) {
+            output.assert_equal(&FieldCT::from_witness_index(*result), self, driver);
+        }
+        Ok(())
     }
 
     fn fix_witness(&mut self, witness_index: u32, witness_value: P::ScalarField) {
@@ -514,7 +1384,7 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         variable_index < self.variables.len()
     }
 
-    fn check_selector_length_consistency(&self) {
+    pub(crate) fn check_selector_length_consistency(&self) {
         for block in self.blocks.get() {
             let nominal_size = block.selectors[0].len();
             for selector in block.selectors.iter().skip(1) {
@@ -525,11 +1395,12 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
 
     fn build_constraints(
         &mut self,
+        driver: &mut T,
         mut constraint_system: AcirFormat<P::ScalarField>,
         has_valid_witness_assignments: bool,
         honk_recursion: bool,
         collect_gates_per_opcode: bool,
-    ) {
+    ) -> HonkProofResult<()> {
         tracing::trace!("Builder build constraints");
         if collect_gates_per_opcode {
             constraint_system
@@ -560,90 +1431,206 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         }
 
         // Add logic constraint
-        // for (i, constraint) in constraint_system.logic_constraints.iter().enumerate() {
-        //     todo!("Logic gates");
-        // }
+        for (i, constraint) in constraint_system.logic_constraints.iter().enumerate() {
+            self.create_logic_gate(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system.original_opcode_indices.logic_constraints[i],
+            );
+        }
 
         // Add range constraint
-        // for (i, constraint) in constraint_system.range_constraints.iter().enumerate() {
-        //     todo!("rage gates");
-        // }
+        for (i, constraint) in constraint_system.range_constraints.iter().enumerate() {
+            let range = constraint_system
+                .minimal_range
+                .get(&constraint.witness)
+                .copied()
+                .unwrap_or(constraint.num_bits);
+            self.create_range_constraint(constraint.witness, range, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system.original_opcode_indices.range_constraints[i],
+            );
+        }
 
         // Add aes128 constraints
-        // for (i, constraint) in constraint_system.aes128_constraints.iter().enumerate() {
-        //     todo!("aes128 gates");
-        // }
+        for (i, constraint) in constraint_system.aes128_constraints.iter().enumerate() {
+            self.create_aes128_constraints(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system.original_opcode_indices.aes128_constraints[i],
+            );
+        }
 
         // Add sha256 constraints
-        // for (i, constraint) in constraint_system.sha256_constraints.iter().enumerate() {
-        //     todo!("sha256 gates");
-        // }
+        for (i, constraint) in constraint_system.sha256_constraints.iter().enumerate() {
+            self.create_sha256_constraints(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system.original_opcode_indices.sha256_constraints[i],
+            );
+        }
 
-        // for (i, constraint) in constraint_system.sha256_compression.iter().enumerate() {
-        //     todo!("sha256 compression gates");
-        // }
+        for (i, constraint) in constraint_system.sha256_compression.iter().enumerate() {
+            self.create_sha256_compression_constraints(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system.original_opcode_indices.sha256_compression[i],
+            );
+        }
 
         // Add schnorr constraints
-        // for (i, constraint) in constraint_system.schnorr_constraints.iter().enumerate() {
-        //     todo!("schnorr gates");
-        // }
+        for (i, constraint) in constraint_system.schnorr_constraints.iter().enumerate() {
+            self.create_schnorr_verify_constraints(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system
+                    .original_opcode_indices
+                    .schnorr_constraints[i],
+            );
+        }
 
         // Add ECDSA k1 constraints
-        // for (i, constraint) in constraint_system.ecdsa_k1_constraints.iter().enumerate() {
-        //     todo!("ecdsa k1 gates");
-        // }
+        for (i, constraint) in constraint_system.ecdsa_k1_constraints.iter().enumerate() {
+            self.create_ecdsa_constraints::<ark_secp256k1::Config>(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system
+                    .original_opcode_indices
+                    .ecdsa_k1_constraints[i],
+            );
+        }
 
         // Add ECDSA r1 constraints
-        // for (i, constraint) in constraint_system.ecdsa_r1_constraints.iter().enumerate() {
-        //     todo!("ecdsa r1 gates");
-        // }
+        for (i, constraint) in constraint_system.ecdsa_r1_constraints.iter().enumerate() {
+            self.create_ecdsa_constraints::<ark_secp256r1::Config>(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system
+                    .original_opcode_indices
+                    .ecdsa_r1_constraints[i],
+            );
+        }
 
         // Add blake2s constraints
-        // for (i, constraint) in constraint_system.blake2s_constraints.iter().enumerate() {
-        //     todo!("blake2s gates");
-        // }
+        for (i, constraint) in constraint_system.blake2s_constraints.iter().enumerate() {
+            self.create_blake2s_constraints(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system
+                    .original_opcode_indices
+                    .blake2s_constraints[i],
+            );
+        }
 
         // Add blake3 constraints
-        // for (i, constraint) in constraint_system.blake3_constraints.iter().enumerate() {
-        //     todo!("blake3 gates");
-        // }
+        for (i, constraint) in constraint_system.blake3_constraints.iter().enumerate() {
+            self.create_blake3_constraints(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system.original_opcode_indices.blake3_constraints[i],
+            );
+        }
 
         // Add keccak constraints
-        // for (i, constraint) in constraint_system.keccak_constraints.iter().enumerate() {
-        //     todo!("keccak gates");
-        // }
+        for (i, constraint) in constraint_system.keccak_constraints.iter().enumerate() {
+            self.create_keccak_constraints(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system.original_opcode_indices.keccak_constraints[i],
+            );
+        }
 
-        // for (i, constraint) in constraint_system.keccak_permutations.iter().enumerate() {
-        //     todo!("keccak permutation gates");
-        // }
+        for (i, constraint) in constraint_system.keccak_permutations.iter().enumerate() {
+            self.create_keccak_permutations(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system
+                    .original_opcode_indices
+                    .keccak_permutations[i],
+            );
+        }
 
         // Add pedersen constraints
-        // for (i, constraint) in constraint_system.pedersen_constraints.iter().enumerate() {
-        //     todo!("pederson gates");
-        // }
+        for (i, constraint) in constraint_system.pedersen_constraints.iter().enumerate() {
+            self.create_pedersen_constraint(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system
+                    .original_opcode_indices
+                    .pedersen_constraints[i],
+            );
+        }
 
-        // for (i, constraint) in constraint_system.pedersen_hash_constraints.iter().enumerate() {
-        //     todo!("pedersen hash gates");
-        // }
+        for (i, constraint) in constraint_system
+            .pedersen_hash_constraints
+            .iter()
+            .enumerate()
+        {
+            self.create_pedersen_hash_constraint(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system
+                    .original_opcode_indices
+                    .pedersen_hash_constraints[i],
+            );
+        }
 
         // Add poseidon2 constraints
-        // for (i, constraint) in constraint_system.poseidon2_constraints.iter().enumerate() {
-        //     todo!("poseidon2 gates");
-        // }
+        for (i, constraint) in constraint_system.poseidon2_constraints.iter().enumerate() {
+            self.create_poseidon2_permutations(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system
+                    .original_opcode_indices
+                    .poseidon2_constraints[i],
+            );
+        }
 
         // Add multi scalar mul constraints
-        // for (i, constraint) in constraint_system.multi_scalar_mul_constraints.iter().enumerate() {
-        //     todo!("multi scalar mul gates");
-        // }
+        for (i, constraint) in constraint_system
+            .multi_scalar_mul_constraints
+            .iter()
+            .enumerate()
+        {
+            self.create_multi_scalar_mul_constraint(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system
+                    .original_opcode_indices
+                    .multi_scalar_mul_constraints[i],
+            );
+        }
 
         // Add ec add constraints
-        // for (i, constraint) in constraint_system.ec_add_constraints.iter().enumerate() {
-        //     todo!("ec add gates");
-        // }
+        for (i, constraint) in constraint_system.ec_add_constraints.iter().enumerate() {
+            self.create_ec_add_constraint(constraint, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system.original_opcode_indices.ec_add_constraints[i],
+            );
+        }
 
         // Add block constraints
         for (i, constraint) in constraint_system.block_constraints.iter().enumerate() {
-            self.create_block_constraints(constraint, has_valid_witness_assignments);
+            self.create_block_constraints(constraint, has_valid_witness_assignments, driver)?;
             if collect_gates_per_opcode {
                 let avg_gates_per_opcode = gate_counter.compute_diff(self)
                     / constraint_system.original_opcode_indices.block_constraints[i].len();
@@ -657,17 +1644,45 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         }
 
         // Add big_int constraints
-        // for (i, constraint) in constraint_system.bigint_from_le_bytes_constraints.iter().enumerate() {
-        //     todo!("bigint from le bytes gates");
-        // }
+        let mut bigints = HashMap::new();
+        for (i, constraint) in constraint_system
+            .bigint_from_le_bytes_constraints
+            .iter()
+            .enumerate()
+        {
+            self.create_bigint_from_le_bytes_constraint(constraint, &mut bigints, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system
+                    .original_opcode_indices
+                    .bigint_from_le_bytes_constraints[i],
+            );
+        }
 
-        // for (i, constraint) in constraint_system.bigint_operations.iter().enumerate() {
-        //     todo!("bigint operations gates");
-        // }
+        for (i, constraint) in constraint_system.bigint_operations.iter().enumerate() {
+            self.create_bigint_operations_constraint(constraint, &mut bigints, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system.original_opcode_indices.bigint_operations[i],
+            );
+        }
 
-        // for (i, constraint) in constraint_system.bigint_to_le_bytes_constraints.iter().enumerate() {
-        //     todo!("bigint to le bytes gates");
-        // }
+        for (i, constraint) in constraint_system
+            .bigint_to_le_bytes_constraints
+            .iter()
+            .enumerate()
+        {
+            self.create_bigint_to_le_bytes_constraint(constraint, &bigints, driver)?;
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system
+                    .original_opcode_indices
+                    .bigint_to_le_bytes_constraints[i],
+            );
+        }
 
         // assert equals
         for (i, constraint) in constraint_system.assert_equalities.iter().enumerate() {
@@ -679,17 +1694,18 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
             &constraint_system,
             has_valid_witness_assignments,
             &mut gate_counter,
-        );
+        )?;
         self.process_honk_recursion_constraints(
-            &constraint_system,
+            &mut constraint_system,
             has_valid_witness_assignments,
             &mut gate_counter,
-        );
+            driver,
+        )?;
         self.process_avm_recursion_constraints(
             &constraint_system,
             has_valid_witness_assignments,
             &mut gate_counter,
-        );
+        )?;
 
         // If the circuit does not itself contain honk recursion constraints but is going to be
         // proven with honk then recursively verified, add a default aggregation object
@@ -703,53 +1719,174 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
             // final recursion output.
             self.add_recursive_proof(current_aggregation_object);
         }
+        Ok(())
     }
 
     fn process_plonk_recursion_constraints(
         &mut self,
         constraint_system: &AcirFormat<P::ScalarField>,
-        has_valid_witness_assignments: bool,
-        gate_counter: &mut GateCounter,
-    ) {
-        for (i, constraint) in constraint_system.recursion_constraints.iter().enumerate() {
-            todo!("Plonk recursion");
+        _has_valid_witness_assignments: bool,
+        _gate_counter: &mut GateCounter,
+    ) -> HonkProofResult<()> {
+        if !constraint_system.recursion_constraints.is_empty() {
+            Err(eyre::eyre!(
+                "Recursive verification of Plonk proofs is not supported"
+            ))?;
         }
+        Ok(())
     }
 
     fn process_honk_recursion_constraints(
         &mut self,
-        constraint_system: &AcirFormat<P::ScalarField>,
+        constraint_system: &mut AcirFormat<P::ScalarField>,
         has_valid_witness_assignments: bool,
         gate_counter: &mut GateCounter,
-    ) {
-        {
-            for (i, constraint) in constraint_system
-                .honk_recursion_constraints
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        // Each proof gets aggregated into the output of the previous one, the first one starts from the default aggregation object
+        let mut current_aggregation_object: Option<AggregationObjectIndices> = None;
+        let constraints = std::mem::take(&mut constraint_system.honk_recursion_constraints);
+        for (i, constraint) in constraints.iter().enumerate() {
+            if constraint.proof.len() < honk_recursion::PROOF_PREAMBLE_SIZE {
+                return Err(HonkProofError::InvalidProofLength);
+            }
+            // The public inputs of the inner circuit are placed after the preamble of the proof
+            let proof_indices = constraint.proof[..honk_recursion::PROOF_PREAMBLE_SIZE]
                 .iter()
-                .enumerate()
-            {
-                todo!("Honk recursion");
+                .chain(constraint.public_inputs.iter())
+                .chain(constraint.proof[honk_recursion::PROOF_PREAMBLE_SIZE..].iter())
+                .cloned()
+                .collect::<Vec<_>>();
+            if !has_valid_witness_assignments {
+                self.set_dummy_honk_recursion_witnesses(
+                    &constraint.key,
+                    &proof_indices,
+                    constraint.public_inputs.len(),
+                );
+            }
+
+            let key = constraint
+                .key
+                .iter()
+                .map(|idx| FieldCT::from_witness_index(*idx))
+                .collect::<Vec<_>>();
+            let proof = proof_indices
+                .iter()
+                .map(|idx| FieldCT::from_witness_index(*idx))
+                .collect::<Vec<_>>();
+            let input_aggregation_object = match current_aggregation_object {
+                Some(indices) => indices.map(FieldCT::from_witness_index),
+                None => Self::default_agg_obj_limbs().map(FieldCT::from_field),
+            };
+            let output_aggregation_object =
+                honk_recursion::verify_proof::<P, T, ark_bn254::g1::Config>(
+                    &key,
+                    &proof,
+                    &input_aggregation_object,
+                    self,
+                    driver,
+                )?;
+
+            let mut indices = AggregationObjectIndices::default();
+            for (index, limb) in indices.iter_mut().zip(output_aggregation_object.iter()) {
+                *index = if limb.is_constant() {
+                    self.put_constant_variable(limb.additive_constant)
+                } else {
+                    limb.normalize(self, driver).get_witness_index()
+                };
             }
+            current_aggregation_object = Some(indices);
+            gate_counter.track_diff(
+                self,
+                &mut constraint_system.gates_per_opcode,
+                constraint_system
+                    .original_opcode_indices
+                    .honk_recursion_constraints[i],
+            );
+        }
+        constraint_system.honk_recursion_constraints = constraints;
+
+        // Make sure the verification key records the public input indices of the final recursion output
+        if let Some(indices) = current_aggregation_object {
+            self.add_recursive_proof(indices);
         }
+        Ok(())
     }
 
     fn process_avm_recursion_constraints(
         &mut self,
         constraint_system: &AcirFormat<P::ScalarField>,
-        has_valid_witness_assignments: bool,
-        gate_counter: &mut GateCounter,
+        _has_valid_witness_assignments: bool,
+        _gate_counter: &mut GateCounter,
+    ) -> HonkProofResult<()> {
+        let _current_aggregation_object = self.init_default_agg_obj_indices();
+
+        if !constraint_system.avm_recursion_constraints.is_empty() {
+            Err(eyre::eyre!(
+                "Recursive verification of AVM proofs is not supported"
+            ))?;
+        }
+        Ok(())
+    }
+
+    /// Sets the key and proof of a Honk recursion constraint to valid-looking values if the witness is unknown, e.g., when only the verification key is computed. This prevents divisions by zero while building the verifier circuit.
+    fn set_dummy_honk_recursion_witnesses(
+        &mut self,
+        key: &[u32],
+        proof: &[u32],
+        num_public_inputs: usize,
     ) {
-        let current_aggregation_object = self.init_default_agg_obj_indices();
+        let mut rng = rand::thread_rng();
+        let circuit_size = P::ScalarField::from(1u64 << honk_recursion::CONST_PROOF_SIZE_LOG_N);
+        let public_input_size = P::ScalarField::from(num_public_inputs as u64);
+        // The generator of BN254 as (x_lo, x_hi, y_lo, y_hi)
+        let point = [1u64, 0, 2, 0].map(P::ScalarField::from);
+
+        let mut key_values = vec![circuit_size, public_input_size, P::ScalarField::one()];
+        key_values.resize(4 + AGGREGATION_OBJECT_SIZE, P::ScalarField::zero());
+        while key_values.len() < key.len() {
+            key_values.extend(point);
+        }
+        for (idx, value) in key.iter().zip(key_values) {
+            self.set_variable(*idx, T::AcvmType::from(value));
+        }
 
-        for (i, constraint) in constraint_system
-            .avm_recursion_constraints
+        // The public inputs are skipped, the remaining proof consists of the witness commitments, the sumcheck
+        // univariates and evaluations, the Gemini fold commitments, the Gemini evaluations, and the Shplonk and KZG
+        // commitments
+        let mut proof_values = vec![circuit_size, public_input_size, P::ScalarField::one()];
+        for _ in 0..honk_recursion::NUM_WITNESS_ENTITIES {
+            proof_values.extend(point);
+        }
+        for _ in 0..honk_recursion::NUM_SUMCHECK_SCALARS {
+            proof_values.push(P::ScalarField::rand(&mut rng));
+        }
+        for _ in 0..honk_recursion::CONST_PROOF_SIZE_LOG_N - 1 {
+            proof_values.extend(point);
+        }
+        for _ in 0..honk_recursion::CONST_PROOF_SIZE_LOG_N {
+            proof_values.push(P::ScalarField::rand(&mut rng));
+        }
+        for _ in 0..2 {
+            proof_values.extend(point);
+        }
+        let (header, body) = proof_values.split_at(honk_recursion::PROOF_PREAMBLE_SIZE);
+        let (proof_header, proof_body) = proof.split_at(honk_recursion::PROOF_PREAMBLE_SIZE);
+        let proof_body = &proof_body[num_public_inputs..];
+        for (idx, value) in proof_header
             .iter()
-            .enumerate()
+            .zip(header)
+            .chain(proof_body.iter().zip(body))
         {
-            todo!("avm recursion");
+            self.set_variable(*idx, T::AcvmType::from(*value));
         }
     }
 
+    fn set_variable(&mut self, index: u32, value: T::AcvmType) {
+        let real_index = self.real_variable_index[index as usize] as usize;
+        self.variables[real_index] = value;
+    }
+
     pub(crate) fn get_num_gates(&self) -> usize {
         // if circuit finalized already added extra gates
         if self.circuit_finalized {
@@ -902,6 +2039,18 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
     }
 
     fn init_default_agg_obj_indices(&mut self) -> AggregationObjectIndices {
+        let mut agg_obj_indices = AggregationObjectIndices::default();
+        for (index, limb) in agg_obj_indices
+            .iter_mut()
+            .zip(Self::default_agg_obj_limbs())
+        {
+            *index = self.add_variable(T::AcvmType::from(limb));
+        }
+        agg_obj_indices
+    }
+
+    /// The limbs of the default aggregation object, i.e., the limbs of the coordinates x0, y0, x1, y1 of the two pairing points.
+    fn default_agg_obj_limbs() -> [P::ScalarField; AGGREGATION_OBJECT_SIZE] {
         const NUM_LIMBS: usize = 4;
         const NUM_LIMB_BITS: u32 = 68;
 
@@ -909,7 +2058,7 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
 
         // AZTEC TODO(https://github.com/AztecProtocol/barretenberg/issues/911): These are pairing points extracted from a valid
         // proof. This is a workaround because we can't represent the point at infinity in biggroup yet.
-        let mut agg_obj_indices = AggregationObjectIndices::default();
+        let mut agg_obj_limbs = [P::ScalarField::zero(); AGGREGATION_OBJECT_SIZE];
         let x0 = Utils::field_from_hex_string::<P::BaseField>(
             "0x031e97a575e9d05a107acb64952ecab75c020998797da7842ab5d6d1986846cf",
         )
@@ -927,7 +2076,7 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         )
         .expect("y1 works");
 
-        let mut agg_obj_indices_idx = 0;
+        let mut agg_obj_limbs_idx = 0;
         let aggregation_object_fq_values = [x0, y0, x1, y1];
 
         for val in aggregation_object_fq_values {
@@ -948,12 +2097,11 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
             ];
 
             for val in val_limbs {
-                let idx = self.add_variable(T::AcvmType::from(val));
-                agg_obj_indices[agg_obj_indices_idx] = idx;
-                agg_obj_indices_idx += 1;
+                agg_obj_limbs[agg_obj_limbs_idx] = val;
+                agg_obj_limbs_idx += 1;
             }
         }
-        agg_obj_indices
+        agg_obj_limbs
     }
 
     fn poly_to_field_ct(&self, poly: &PolyTriple<P::ScalarField>) -> FieldCT<P::ScalarField> {
@@ -975,6 +2123,7 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         constraint: &BlockConstraint<P::ScalarField>,
         has_valid_witness_assignments: bool,
         init: Vec<FieldCT<P::ScalarField>>,
+        driver: &mut T,
     ) {
         let mut table = RomTable::new(init);
 
@@ -990,14 +2139,50 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
             // if witness are not assigned, then w will be zero and table[w] will work
             let w_value = if has_valid_witness_assignments {
                 // If witness are assigned, we use the correct value for w
-                index.get_value(self)
+                index.get_value(self, driver)
             } else {
-                P::ScalarField::zero()
+                T::public_zero()
             };
             let w = FieldCT::from_witness(w_value, self);
-            value.assert_equal(&table.index_field_ct(&w, self), self);
-            w.assert_equal(&index, self);
+            let read = table.index_field_ct(&w, self, driver);
+            value.assert_equal(&read, self, driver);
+            w.assert_equal(&index, self, driver);
+        }
+    }
+
+    fn process_ram_operations(
+        &mut self,
+        constraint: &BlockConstraint<P::ScalarField>,
+        has_valid_witness_assignments: bool,
+        init: Vec<FieldCT<P::ScalarField>>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let mut table = RamTable::new(init);
+
+        for op in constraint.trace.iter() {
+            let value = self.poly_to_field_ct(&op.value);
+            let index = self.poly_to_field_ct(&op.index);
+
+            // We create a new witness w to avoid issues with non-valid witness assignements.
+            // If witness are not assigned, then index will be zero and table[index] won't hit bounds check.
+            let index_value = if has_valid_witness_assignments {
+                // If witness are assigned, we use the correct value for w
+                index.get_value(self, driver)
+            } else {
+                T::public_zero()
+            };
+            // Create new witness and ensure equal to index.
+            FieldCT::from_witness(index_value, self).assert_equal(&index, self, driver);
+
+            if op.access_type == 0 {
+                let read = table.read(&index, self, driver)?;
+                value.assert_equal(&read, self, driver);
+            } else {
+                assert_eq!(op.access_type, 1);
+                table.write(&index, &value, self, driver)?;
+            }
         }
+        Ok(())
     }
 
     pub fn get_variable(&self, index: usize) -> T::AcvmType {
@@ -1006,7 +2191,9 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
     }
 
     pub(crate) fn assert_equal_constant(&mut self, a_idx: usize, b: P::ScalarField) {
-        assert_eq!(self.variables[a_idx], T::AcvmType::from(b));
+        if let Some(a) = T::get_public(&self.variables[a_idx]) {
+            assert_eq!(a, b);
+        }
         let b_idx = self.put_constant_variable(b);
         self.assert_equal(a_idx, b_idx as usize);
     }
@@ -1014,7 +2201,13 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
     pub(crate) fn assert_equal(&mut self, a_idx: usize, b_idx: usize) {
         self.is_valid_variable(a_idx);
         self.is_valid_variable(b_idx);
-        assert_eq!(self.get_variable(a_idx), self.get_variable(b_idx));
+        // We can only check the equality of the values if they are public
+        if let (Some(a), Some(b)) = (
+            T::get_public(&self.get_variable(a_idx)),
+            T::get_public(&self.get_variable(b_idx)),
+        ) {
+            assert_eq!(a, b);
+        }
 
         let a_real_idx = self.real_variable_index[a_idx] as usize;
         let b_real_idx = self.real_variable_index[b_idx] as usize;
@@ -1159,46 +2352,443 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         Ok(value_witness)
     }
 
-    fn apply_aux_selectors(&mut self, type_: AuxSelectors) {
-        let block = &mut self.blocks.aux;
-        block.q_aux().push(if type_ == AuxSelectors::None {
-            P::ScalarField::zero()
-        } else {
-            P::ScalarField::one()
-        });
-        // Set to zero the selectors that are not enabled for this gate
-        block.q_delta_range().push(P::ScalarField::zero());
-        block.q_lookup_type().push(P::ScalarField::zero());
-        block.q_elliptic().push(P::ScalarField::zero());
-        block.q_poseidon2_external().push(P::ScalarField::zero());
-        block.q_poseidon2_internal().push(P::ScalarField::zero());
-
-        match type_ {
-            AuxSelectors::RomRead => {
-                // Memory read gate for reading memory cells.
-                // Validates record witness computation (r = read_write_flag + index * \eta + timestamp * \eta^2 + value *
-                // \eta^3)
-                block.q_1().push(P::ScalarField::one());
-                block.q_2().push(P::ScalarField::zero());
-                block.q_3().push(P::ScalarField::zero());
-                block.q_4().push(P::ScalarField::zero());
-                block.q_m().push(P::ScalarField::one()); // validate record witness is correctly computed
-                block.q_c().push(P::ScalarField::zero()); // read/write flag stored in q_c
-                block.q_arith().push(P::ScalarField::zero());
+    fn create_sorted_rom_gate(&mut self, record: &mut RomRecord) {
+        record.record_witness = self.add_variable(T::AcvmType::from(P::ScalarField::zero()));
+        self.apply_aux_selectors(AuxSelectors::RomConsistencyCheck);
+        self.blocks.aux.populate_wires(
+            record.index_witness,
+            record.value_column1_witness,
+            record.value_column2_witness,
+            record.record_witness,
+        );
+        // Note: record the index into the block that contains the RAM/ROM gates
+        record.gate_index = self.blocks.aux.len() - 1;
+        self.num_gates += 1;
+    }
 
-                self.check_selector_length_consistency();
-            }
-            _ => todo!("Aux selectors"),
+    pub(crate) fn create_ram_array(&mut self, array_size: usize) -> usize {
+        let mut new_transcript = RamTranscript::default();
+        for _ in 0..array_size {
+            new_transcript.state.push(Self::UNINITIALIZED_MEMORY_RECORD);
         }
+        self.ram_arrays.push(new_transcript);
+        self.ram_arrays.len() - 1
     }
 
-    fn create_dummy_gate(
-        // &mut self,
-        block: &mut UltraTraceBlock<P::ScalarField>,
-        idx_1: u32,
-        idx_2: u32,
-        idx_3: u32,
-        idx_4: u32,
+    pub(crate) fn init_ram_element(
+        &mut self,
+        ram_id: usize,
+        index_value: usize,
+        value_witness: u32,
+    ) {
+        assert!(self.ram_arrays.len() > ram_id);
+        let index_witness = if index_value == 0 {
+            self.zero_idx
+        } else {
+            self.put_constant_variable(P::ScalarField::from(index_value as u64))
+        };
+
+        assert!(self.ram_arrays[ram_id].state.len() > index_value);
+        assert!(self.ram_arrays[ram_id].state[index_value] == Self::UNINITIALIZED_MEMORY_RECORD);
+        let access_count = self.ram_arrays[ram_id].access_count;
+        let timestamp_witness =
+            self.put_constant_variable(P::ScalarField::from(access_count as u64));
+        let mut new_record = RamRecord {
+            index_witness,
+            timestamp_witness,
+            value_witness,
+            index: T::AcvmType::from(P::ScalarField::from(index_value as u64)),
+            timestamp: access_count as u32,
+            access_type: AccessType::Write,
+            record_witness: 0,
+            gate_index: 0,
+        };
+
+        // The LUT is only created on accesses with shared indices, which require all elements to be initialized
+        assert!(self.ram_arrays[ram_id].lut.is_none());
+        self.ram_arrays[ram_id].state[index_value] = value_witness;
+        self.ram_arrays[ram_id].access_count += 1;
+        self.create_ram_gate(&mut new_record);
+        self.ram_arrays[ram_id].records.push(new_record);
+    }
+
+    fn init_ram_lut(&mut self, ram_id: usize, driver: &mut T) {
+        if self.ram_arrays[ram_id].lut.is_some() {
+            return;
+        }
+        let values = self.ram_arrays[ram_id]
+            .state
+            .iter()
+            .map(|witness| {
+                assert!(*witness != Self::UNINITIALIZED_MEMORY_RECORD);
+                self.get_variable(*witness as usize)
+            })
+            .collect();
+        self.ram_arrays[ram_id].lut = Some(driver.init_lut_by_acvm_type(values));
+    }
+
+    pub(crate) fn read_ram_array(
+        &mut self,
+        ram_id: usize,
+        index_witness: u32,
+        driver: &mut T,
+    ) -> HonkProofResult<u32> {
+        assert!(self.ram_arrays.len() > ram_id);
+        let index = self.get_variable(index_witness as usize);
+
+        let value = match T::get_public(&index) {
+            Some(val) if self.ram_arrays[ram_id].lut.is_none() => {
+                let val: BigUint = val.into();
+                let val: usize = val.try_into().unwrap();
+                assert!(self.ram_arrays[ram_id].state.len() > val);
+                let state = self.ram_arrays[ram_id].state[val];
+                assert!(state != Self::UNINITIALIZED_MEMORY_RECORD);
+                self.get_variable(state as usize)
+            }
+            _ => {
+                // Shared indices (and all accesses after the first shared one) are performed on the LUT
+                self.init_ram_lut(ram_id, driver);
+                let lut = self.ram_arrays[ram_id]
+                    .lut
+                    .as_ref()
+                    .expect("LUT was created");
+                driver.read_lut_by_acvm_type(&index, lut)?
+            }
+        };
+        let value_witness = self.add_variable(value);
+
+        let access_count = self.ram_arrays[ram_id].access_count;
+        let timestamp_witness =
+            self.put_constant_variable(P::ScalarField::from(access_count as u64));
+        let mut new_record = RamRecord {
+            index_witness,
+            timestamp_witness,
+            value_witness,
+            index,
+            timestamp: access_count as u32,
+            access_type: AccessType::Read,
+            record_witness: 0,
+            gate_index: 0,
+        };
+        self.create_ram_gate(&mut new_record);
+        self.ram_arrays[ram_id].records.push(new_record);
+
+        // increment ram array's access count
+        self.ram_arrays[ram_id].access_count += 1;
+
+        // return witness index of the value in the array
+        Ok(value_witness)
+    }
+
+    pub(crate) fn write_ram_array(
+        &mut self,
+        ram_id: usize,
+        index_witness: u32,
+        value_witness: u32,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        assert!(self.ram_arrays.len() > ram_id);
+        let index = self.get_variable(index_witness as usize);
+
+        match T::get_public(&index) {
+            Some(val) if self.ram_arrays[ram_id].lut.is_none() => {
+                let val: BigUint = val.into();
+                let val: usize = val.try_into().unwrap();
+                assert!(self.ram_arrays[ram_id].state.len() > val);
+                self.ram_arrays[ram_id].state[val] = value_witness;
+            }
+            _ => {
+                // Shared indices (and all accesses after the first shared one) are performed on the LUT
+                self.init_ram_lut(ram_id, driver);
+                let value = self.get_variable(value_witness as usize);
+                let lut = self.ram_arrays[ram_id]
+                    .lut
+                    .as_mut()
+                    .expect("LUT was created");
+                driver.write_lut_by_acvm_type(index.to_owned(), value, lut)?;
+            }
+        }
+
+        let access_count = self.ram_arrays[ram_id].access_count;
+        let timestamp_witness =
+            self.put_constant_variable(P::ScalarField::from(access_count as u64));
+        let mut new_record = RamRecord {
+            index_witness,
+            timestamp_witness,
+            value_witness,
+            index,
+            timestamp: access_count as u32,
+            access_type: AccessType::Write,
+            record_witness: 0,
+            gate_index: 0,
+        };
+        self.create_ram_gate(&mut new_record);
+        self.ram_arrays[ram_id].records.push(new_record);
+
+        // increment ram array's access count
+        self.ram_arrays[ram_id].access_count += 1;
+        Ok(())
+    }
+
+    fn create_ram_gate(&mut self, record: &mut RamRecord<T::AcvmType>) {
+        // Record wire value can't yet be computed (uses randomnes generated during proof construction).
+        // However it needs a distinct witness index,
+        // we will be applying copy constraints + set membership constraints.
+        // Later on during proof construction we will compute the record wire value + assign it
+        record.record_witness = self.add_variable(T::AcvmType::from(P::ScalarField::zero()));
+        self.apply_aux_selectors(if record.access_type == AccessType::Read {
+            AuxSelectors::RamRead
+        } else {
+            AuxSelectors::RamWrite
+        });
+        self.blocks.aux.populate_wires(
+            record.index_witness,
+            record.timestamp_witness,
+            record.value_witness,
+            record.record_witness,
+        );
+
+        // Note: record the index into the block that contains the RAM/ROM gates
+        record.gate_index = self.blocks.aux.len() - 1;
+        self.num_gates += 1;
+    }
+
+    fn create_sorted_ram_gate(
+        &mut self,
+        index_witness: u32,
+        timestamp_witness: u32,
+        value_witness: u32,
+        record_witness: u32,
+    ) -> usize {
+        self.apply_aux_selectors(AuxSelectors::RamConsistencyCheck);
+        self.blocks.aux.populate_wires(
+            index_witness,
+            timestamp_witness,
+            value_witness,
+            record_witness,
+        );
+        self.num_gates += 1;
+        self.blocks.aux.len() - 1
+    }
+
+    fn create_final_sorted_ram_gate(
+        &mut self,
+        index_witness: u32,
+        timestamp_witness: u32,
+        value_witness: u32,
+        record_witness: u32,
+        ram_array_size: usize,
+    ) -> usize {
+        // no -1 since we havent added the gate yet
+        let gate_index = self.blocks.aux.len();
+
+        // Create a final gate with all selectors zero (hence unconstrained). In particular, the record_witness does not
+        // need to be constrained here since it will be constrained by the previous gate via the `w_4_shift` term.
+        Self::create_dummy_gate(
+            &mut self.blocks.aux,
+            index_witness,
+            timestamp_witness,
+            value_witness,
+            record_witness,
+        );
+        self.check_selector_length_consistency();
+        self.num_gates += 1;
+
+        // Create an add gate ensuring the final index is consistent with the size of the RAM array
+        self.create_big_add_gate(
+            &AddQuad {
+                a: index_witness,
+                b: self.zero_idx,
+                c: self.zero_idx,
+                d: self.zero_idx,
+                a_scaling: P::ScalarField::one(),
+                b_scaling: P::ScalarField::zero(),
+                c_scaling: P::ScalarField::zero(),
+                d_scaling: P::ScalarField::zero(),
+                const_scaling: -P::ScalarField::from(ram_array_size as u64 - 1),
+            },
+            false,
+        );
+        gate_index
+    }
+
+    fn apply_aux_selectors(&mut self, type_: AuxSelectors) {
+        let block = &mut self.blocks.aux;
+        block.q_aux().push(if type_ == AuxSelectors::None {
+            P::ScalarField::zero()
+        } else {
+            P::ScalarField::one()
+        });
+        // Set to zero the selectors that are not enabled for this gate
+        block.q_delta_range().push(P::ScalarField::zero());
+        block.q_lookup_type().push(P::ScalarField::zero());
+        block.q_elliptic().push(P::ScalarField::zero());
+        block.q_poseidon2_external().push(P::ScalarField::zero());
+        block.q_poseidon2_internal().push(P::ScalarField::zero());
+
+        match type_ {
+            AuxSelectors::RomRead => {
+                // Memory read gate for reading memory cells.
+                // Validates record witness computation (r = read_write_flag + index * \eta + timestamp * \eta^2 + value *
+                // \eta^3)
+                block.q_1().push(P::ScalarField::one());
+                block.q_2().push(P::ScalarField::zero());
+                block.q_3().push(P::ScalarField::zero());
+                block.q_4().push(P::ScalarField::zero());
+                block.q_m().push(P::ScalarField::one()); // validate record witness is correctly computed
+                block.q_c().push(P::ScalarField::zero()); // read/write flag stored in q_c
+                block.q_arith().push(P::ScalarField::zero());
+
+                self.check_selector_length_consistency();
+            }
+            AuxSelectors::RomConsistencyCheck => {
+                // Memory read gate used with the sorted list of memory reads.
+                // Apply sorted memory read checks with the following additional check:
+                // 1. Assert that if index field across two gates does not change, the value field does not change.
+                // Used for ROM reads and RAM reads across write/read boundaries
+                block.q_1().push(P::ScalarField::one());
+                block.q_2().push(P::ScalarField::one());
+                block.q_3().push(P::ScalarField::zero());
+                block.q_4().push(P::ScalarField::zero());
+                block.q_m().push(P::ScalarField::zero());
+                block.q_c().push(P::ScalarField::zero());
+                block.q_arith().push(P::ScalarField::zero());
+
+                self.check_selector_length_consistency();
+            }
+            AuxSelectors::RamConsistencyCheck => {
+                // Memory read gate used with the sorted list of memory reads.
+                // 1. Validate adjacent index values across 2 gates increases by 0 or 1
+                // 2. Validate record computation (r = read_write_flag + index * \eta + \timestamp * \eta^2 + value * \eta^3)
+                // 3. If adjacent index values across 2 gates does not change, and the next gate's read_write_flag is set to
+                // 'read', validate adjacent values do not change Used for ROM reads and RAM reads across read/write boundaries
+                block.q_1().push(P::ScalarField::zero());
+                block.q_2().push(P::ScalarField::zero());
+                block.q_3().push(P::ScalarField::zero());
+                block.q_4().push(P::ScalarField::zero());
+                block.q_m().push(P::ScalarField::zero());
+                block.q_c().push(P::ScalarField::zero());
+                block.q_arith().push(P::ScalarField::one());
+
+                self.check_selector_length_consistency();
+            }
+            AuxSelectors::RamTimestampCheck => {
+                // For two adjacent RAM entries that share the same index, validate the timestamp value is monotonically
+                // increasing
+                block.q_1().push(P::ScalarField::one());
+                block.q_2().push(P::ScalarField::zero());
+                block.q_3().push(P::ScalarField::zero());
+                block.q_4().push(P::ScalarField::one());
+                block.q_m().push(P::ScalarField::zero());
+                block.q_c().push(P::ScalarField::zero());
+                block.q_arith().push(P::ScalarField::zero());
+
+                self.check_selector_length_consistency();
+            }
+            AuxSelectors::RamRead => {
+                // Memory read gate for reading memory cells.
+                // Validates record witness computation (r = read_write_flag + index * \eta + timestamp * \eta^2 + value *
+                // \eta^3)
+                block.q_1().push(P::ScalarField::one());
+                block.q_2().push(P::ScalarField::zero());
+                block.q_3().push(P::ScalarField::zero());
+                block.q_4().push(P::ScalarField::zero());
+                block.q_m().push(P::ScalarField::one()); // validate record witness is correctly computed
+                block.q_c().push(P::ScalarField::zero()); // read/write flag stored in q_c
+                block.q_arith().push(P::ScalarField::zero());
+
+                self.check_selector_length_consistency();
+            }
+            AuxSelectors::RamWrite => {
+                // Memory read gate for writing memory cells.
+                // Validates record witness computation (r = read_write_flag + index * \eta + timestamp * \eta^2 + value *
+                // \eta^3)
+                block.q_1().push(P::ScalarField::one());
+                block.q_2().push(P::ScalarField::zero());
+                block.q_3().push(P::ScalarField::zero());
+                block.q_4().push(P::ScalarField::zero());
+                block.q_m().push(P::ScalarField::one()); // validate record witness is correctly computed
+                block.q_c().push(P::ScalarField::one()); // read/write flag stored in q_c
+                block.q_arith().push(P::ScalarField::zero());
+
+                self.check_selector_length_consistency();
+            }
+            AuxSelectors::LimbAccumulate1 => {
+                block.q_1().push(P::ScalarField::zero());
+                block.q_2().push(P::ScalarField::zero());
+                block.q_3().push(P::ScalarField::one());
+                block.q_4().push(P::ScalarField::one());
+                block.q_m().push(P::ScalarField::zero());
+                block.q_c().push(P::ScalarField::zero());
+                block.q_arith().push(P::ScalarField::zero());
+
+                self.check_selector_length_consistency();
+            }
+            AuxSelectors::LimbAccumulate2 => {
+                block.q_1().push(P::ScalarField::zero());
+                block.q_2().push(P::ScalarField::zero());
+                block.q_3().push(P::ScalarField::one());
+                block.q_4().push(P::ScalarField::zero());
+                block.q_m().push(P::ScalarField::one());
+                block.q_c().push(P::ScalarField::zero());
+                block.q_arith().push(P::ScalarField::zero());
+
+                self.check_selector_length_consistency();
+            }
+            AuxSelectors::NonNativeField1 => {
+                block.q_1().push(P::ScalarField::zero());
+                block.q_2().push(P::ScalarField::one());
+                block.q_3().push(P::ScalarField::one());
+                block.q_4().push(P::ScalarField::zero());
+                block.q_m().push(P::ScalarField::zero());
+                block.q_c().push(P::ScalarField::zero());
+                block.q_arith().push(P::ScalarField::zero());
+
+                self.check_selector_length_consistency();
+            }
+            AuxSelectors::NonNativeField2 => {
+                block.q_1().push(P::ScalarField::zero());
+                block.q_2().push(P::ScalarField::one());
+                block.q_3().push(P::ScalarField::zero());
+                block.q_4().push(P::ScalarField::one());
+                block.q_m().push(P::ScalarField::zero());
+                block.q_c().push(P::ScalarField::zero());
+                block.q_arith().push(P::ScalarField::zero());
+
+                self.check_selector_length_consistency();
+            }
+            AuxSelectors::NonNativeField3 => {
+                block.q_1().push(P::ScalarField::zero());
+                block.q_2().push(P::ScalarField::one());
+                block.q_3().push(P::ScalarField::zero());
+                block.q_4().push(P::ScalarField::zero());
+                block.q_m().push(P::ScalarField::one());
+                block.q_c().push(P::ScalarField::zero());
+                block.q_arith().push(P::ScalarField::zero());
+
+                self.check_selector_length_consistency();
+            }
+            AuxSelectors::None => {
+                block.q_1().push(P::ScalarField::zero());
+                block.q_2().push(P::ScalarField::zero());
+                block.q_3().push(P::ScalarField::zero());
+                block.q_4().push(P::ScalarField::zero());
+                block.q_m().push(P::ScalarField::zero());
+                block.q_c().push(P::ScalarField::zero());
+                block.q_arith().push(P::ScalarField::zero());
+
+                self.check_selector_length_consistency();
+            }
+        }
+    }
+
+    pub(crate) fn create_dummy_gate(
+        // &mut self,
+        block: &mut UltraTraceBlock<P::ScalarField>,
+        idx_1: u32,
+        idx_2: u32,
+        idx_3: u32,
+        idx_4: u32,
     ) {
         block.populate_wires(idx_1, idx_2, idx_3, idx_4);
         block.q_m().push(P::ScalarField::zero());
@@ -1616,7 +3206,7 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         std::cmp::max(minimum_circuit_size, num_filled_gates) + Self::NUM_RESERVED_GATES
     }
 
-    fn get_table(&mut self, id: BasicTableId) -> &mut PlookupBasicTable<P::ScalarField> {
+    pub(crate) fn get_table(&mut self, id: BasicTableId) -> &mut PlookupBasicTable<P::ScalarField> {
         let mut index = self.lookup_tables.len();
         for (i, table) in self.lookup_tables.iter().enumerate() {
             if table.id == id {
@@ -1636,10 +3226,10 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         }
     }
 
-    fn create_gates_from_plookup_accumulators(
+    pub(crate) fn create_gates_from_plookup_accumulators(
         &mut self,
         id: MultiTableId,
-        read_values: ReadData<P::ScalarField>,
+        read_values: ReadData<T::AcvmType>,
         key_a_index: u32,
         key_b_index: Option<u32>,
     ) -> ReadData<u32> {
@@ -1654,24 +3244,31 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
             let basic_table_id = self.plookup.multi_tables[id_usize].basic_table_ids[i].clone();
             let table = self.get_table(basic_table_id);
 
-            table
-                .lookup_gates
-                .push(read_values.lookup_entries[i].to_owned()); // used for constructing sorted polynomials
+            // Lookups with shared keys do not have lookup entries, their read counts are tracked in shared_lookup_read_counts
+            if let Some(entry) = read_values.lookup_entries.get(i) {
+                let entry = LookupEntry {
+                    key: entry.key.to_owned(),
+                    value: entry.value.to_owned().map(|v| {
+                        T::get_public(&v).expect("Lookup entries are only created for public keys")
+                    }),
+                };
+                table.lookup_gates.push(entry); // used for constructing sorted polynomials
+            }
             let table_index = table.table_index;
 
             let first_idx = if i == 0 {
                 key_a_index
             } else {
-                self.add_variable(T::AcvmType::from(read_values[ColumnIdx::C1][i]))
+                self.add_variable(read_values[ColumnIdx::C1][i].to_owned())
             };
 
             #[allow(clippy::unnecessary_unwrap)]
             let second_idx = if i == 0 && (key_b_index.is_some()) {
                 key_b_index.unwrap()
             } else {
-                self.add_variable(T::AcvmType::from(read_values[ColumnIdx::C2][i]))
+                self.add_variable(read_values[ColumnIdx::C2][i].to_owned())
             };
-            let third_idx = self.add_variable(T::AcvmType::from(read_values[ColumnIdx::C3][i]));
+            let third_idx = self.add_variable(read_values[ColumnIdx::C3][i].to_owned());
 
             read_data[ColumnIdx::C1].push(first_idx);
             read_data[ColumnIdx::C2].push(second_idx);
@@ -1728,7 +3325,11 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         read_data
     }
 
-    pub fn finalize_circuit(&mut self, ensure_nonzero: bool) {
+    pub fn finalize_circuit(
+        &mut self,
+        ensure_nonzero: bool,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
         // /**
         //  * First of all, add the gates related to ROM arrays and range lists.
         //  * Note that the total number of rows in an UltraPlonk program can be divided as following:
@@ -1764,28 +3365,959 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
 
             self.process_non_native_field_multiplications();
             self.process_rom_arrays();
-            self.process_ram_arrays();
-            self.process_range_lists();
+            self.process_ram_arrays(driver)?;
+            self.process_range_lists(driver)?;
             self.circuit_finalized = true;
         }
+        Ok(())
     }
 
     fn process_rom_arrays(&mut self) {
-        for _ in self.rom_arrays.iter() {
-            todo!("process rom array");
+        for i in 0..self.rom_arrays.len() {
+            self.process_rom_array(i);
         }
     }
 
-    fn process_ram_arrays(&mut self) {
-        for _ in self.ram_arrays.iter() {
-            todo!("process ram array");
+    fn process_ram_arrays(&mut self, driver: &mut T) -> HonkProofResult<()> {
+        for i in 0..self.ram_arrays.len() {
+            self.process_ram_array(i, driver)?;
         }
+        Ok(())
     }
 
-    fn process_range_lists(&mut self) {
-        for _ in self.range_lists.iter() {
-            todo!("process range lists");
+    fn process_rom_array(&mut self, rom_id: usize) {
+        let read_tag = self.get_new_tag(); // current_tag + 1;
+        let sorted_list_tag = self.get_new_tag(); // current_tag + 2;
+        self.create_tag(read_tag, sorted_list_tag);
+        self.create_tag(sorted_list_tag, read_tag);
+
+        // Make sure that every cell has been initialized
+        for i in 0..self.rom_arrays[rom_id].state.len() {
+            if self.rom_arrays[rom_id].state[i][0] == Self::UNINITIALIZED_MEMORY_RECORD {
+                self.set_rom_element(rom_id, i, self.zero_idx);
+            }
+        }
+
+        // ROM reads are only supported with public indices, so we can sort the records in the clear
+        let mut records = std::mem::take(&mut self.rom_arrays[rom_id].records);
+        records.sort_by_key(|record| record.index);
+
+        for record in records.iter() {
+            let index = record.index;
+            let value1 = self.get_variable(record.value_column1_witness as usize);
+            let value2 = self.get_variable(record.value_column2_witness as usize);
+            let index_witness = self.add_variable(T::AcvmType::from(P::ScalarField::from(index)));
+            let value1_witness = self.add_variable(value1);
+            let value2_witness = self.add_variable(value2);
+            let mut sorted_record = RomRecord {
+                index_witness,
+                value_column1_witness: value1_witness,
+                value_column2_witness: value2_witness,
+                index,
+                record_witness: 0,
+                gate_index: 0,
+            };
+            self.create_sorted_rom_gate(&mut sorted_record);
+
+            self.assign_tag(record.record_witness, read_tag);
+            self.assign_tag(sorted_record.record_witness, sorted_list_tag);
+
+            // For ROM/RAM gates, the 'record' wire value (wire column 4) is a linear combination of the first 3 wire
+            // values. However, the record value uses the random challenge 'eta', generated after the first 3 wires are
+            // committed to. i.e. we can't compute the record witness here because we don't know what `eta` is! Take
+            // the gate indices of the two rom gates (original read gate + sorted gate) and store in `memory_records`.
+            // Once we generate the `eta` challenge, we'll use `memory_records` to figure out which gates need a record
+            // wire value to be computed.
+            self.memory_read_records
+                .push(sorted_record.gate_index as u32);
+            self.memory_read_records.push(record.gate_index as u32);
         }
+        self.rom_arrays[rom_id].records = records;
+
+        // One of the checks we run on the sorted list, is to validate the difference between the index field across
+        // two gates is either 0 or 1. If we add a dummy gate at the end of the sorted list, where we force the first
+        // wire to equal `m + 1`, where `m` is the maximum allowed index in the sorted list, we have validated that all
+        // ROM reads are correctly constrained
+        let max_index_value = P::ScalarField::from(self.rom_arrays[rom_id].state.len() as u64);
+        let max_index = self.add_variable(T::AcvmType::from(max_index_value));
+        Self::create_dummy_gate(
+            &mut self.blocks.aux,
+            max_index,
+            self.zero_idx,
+            self.zero_idx,
+            self.zero_idx,
+        );
+        self.check_selector_length_consistency();
+        self.num_gates += 1; // necessary because create dummy gate cannot increment num_gates itself
+        self.create_big_add_gate(
+            &AddQuad {
+                a: max_index,
+                b: self.zero_idx,
+                c: self.zero_idx,
+                d: self.zero_idx,
+                a_scaling: P::ScalarField::one(),
+                b_scaling: P::ScalarField::zero(),
+                c_scaling: P::ScalarField::zero(),
+                d_scaling: P::ScalarField::zero(),
+                const_scaling: -max_index_value,
+            },
+            false,
+        );
+        // N.B. If the above check holds, we know the sorted list begins with an index value of 0,
+        // because the first cell is explicitly initialized using zero_idx as the index field.
+    }
+
+    fn process_ram_array(&mut self, ram_id: usize, driver: &mut T) -> HonkProofResult<()> {
+        let access_tag = self.get_new_tag(); // current_tag + 1;
+        let sorted_list_tag = self.get_new_tag(); // current_tag + 2;
+        self.create_tag(access_tag, sorted_list_tag);
+        self.create_tag(sorted_list_tag, access_tag);
+
+        // Make sure that every cell has been initialized
+        for i in 0..self.ram_arrays[ram_id].state.len() {
+            if self.ram_arrays[ram_id].state[i] == Self::UNINITIALIZED_MEMORY_RECORD {
+                self.init_ram_element(ram_id, i, self.zero_idx);
+            }
+        }
+
+        let records = std::mem::take(&mut self.ram_arrays[ram_id].records);
+        let ram_array_size = self.ram_arrays[ram_id].state.len();
+        let access_count = self.ram_arrays[ram_id].access_count;
+        // The records are stored in the order they were created, i.e., records[i].timestamp == i
+        debug_assert!(records
+            .iter()
+            .enumerate()
+            .all(|(i, record)| record.timestamp as usize == i));
+
+        // Since the indices of the records may be shared, we cannot sort the records in the clear. Instead, we sort
+        // the keys `index * 2^timestamp_bits + timestamp` (which are unique) with the driver and recover the index and
+        // timestamp of each sorted record by slicing the sorted keys.
+        let timestamp_bits = (usize::BITS - (access_count - 1).leading_zeros()).max(1) as usize;
+        let index_bits = (usize::BITS - (ram_array_size - 1).leading_zeros()).max(1) as usize;
+        let key_bits = timestamp_bits + index_bits;
+        let timestamp_base = 1u64 << timestamp_bits;
+        let index_base = 1u64 << index_bits;
+
+        let mut keys = Vec::with_capacity(records.len());
+        for record in records.iter() {
+            let key = driver.acvm_mul_with_public(
+                P::ScalarField::from(timestamp_base),
+                record.index.to_owned(),
+            );
+            keys.push(driver.add(
+                key,
+                T::AcvmType::from(P::ScalarField::from(record.timestamp)),
+            ));
+        }
+        let sorted_keys = driver.sort(&keys, key_bits)?;
+
+        // LUTs over the values and access types of the records, indexed by the timestamp. They are only required if
+        // the sorted timestamps are shared.
+        let mut value_lut = None;
+        let mut access_type_lut = None;
+
+        let mut sorted_records = Vec::with_capacity(sorted_keys.len());
+        for (i, key) in sorted_keys.into_iter().enumerate() {
+            let slices =
+                driver.slice_variable_bases(key, key_bits, &[timestamp_base, index_base])?;
+            let timestamp = slices[0].to_owned();
+            let index = slices[1].to_owned();
+
+            let (value, access_type) = match T::get_public(&timestamp) {
+                Some(timestamp) => {
+                    let timestamp: BigUint = timestamp.into();
+                    let timestamp: usize = timestamp.try_into().unwrap();
+                    let record = &records[timestamp];
+                    let access_type = match record.access_type {
+                        AccessType::Read => P::ScalarField::zero(),
+                        AccessType::Write => P::ScalarField::one(),
+                    };
+                    (
+                        self.get_variable(record.value_witness as usize),
+                        T::AcvmType::from(access_type),
+                    )
+                }
+                None => {
+                    if value_lut.is_none() {
+                        let values = records
+                            .iter()
+                            .map(|record| self.get_variable(record.value_witness as usize))
+                            .collect();
+                        let access_types = records
+                            .iter()
+                            .map(|record| {
+                                T::AcvmType::from(match record.access_type {
+                                    AccessType::Read => P::ScalarField::zero(),
+                                    AccessType::Write => P::ScalarField::one(),
+                                })
+                            })
+                            .collect();
+                        value_lut = Some(driver.init_lut_by_acvm_type(values));
+                        access_type_lut = Some(driver.init_lut_by_acvm_type(access_types));
+                    }
+                    let value = driver.read_lut_by_acvm_type(
+                        &timestamp,
+                        value_lut.as_ref().expect("LUT was created"),
+                    )?;
+                    let access_type = driver.read_lut_by_acvm_type(
+                        &timestamp,
+                        access_type_lut.as_ref().expect("LUT was created"),
+                    )?;
+                    (value, access_type)
+                }
+            };
+
+            let index_witness = self.add_variable(index.to_owned());
+            let timestamp_witness = self.add_variable(timestamp.to_owned());
+            let value_witness = self.add_variable(value);
+            // If the access type is shared, we put it into the record witness and treat the gate as a read, since the
+            // record values are added to the existing record witness during proof construction.
+            let record_witness = if T::is_shared(&access_type) {
+                self.add_variable(access_type.to_owned())
+            } else {
+                self.add_variable(T::AcvmType::from(P::ScalarField::zero()))
+            };
+
+            // We don't apply the RAM consistency check gate to the final record,
+            // as this gate expects a RAM record to be present at the next gate
+            let gate_index = if i < records.len() - 1 {
+                self.create_sorted_ram_gate(
+                    index_witness,
+                    timestamp_witness,
+                    value_witness,
+                    record_witness,
+                )
+            } else {
+                // For the final record in the sorted list, we do not apply the full consistency check gate.
+                // Only need to check the index value = RAM array size - 1.
+                self.create_final_sorted_ram_gate(
+                    index_witness,
+                    timestamp_witness,
+                    value_witness,
+                    record_witness,
+                    ram_array_size,
+                )
+            };
+
+            // Assign sorted records to tags that we will perform set equivalence checks on
+            self.assign_tag(record_witness, sorted_list_tag);
+
+            // See process_rom_array for why we store the gate indices
+            match T::get_public(&access_type) {
+                Some(access_type) if access_type.is_one() => {
+                    self.memory_write_records.push(gate_index as u32)
+                }
+                _ => self.memory_read_records.push(gate_index as u32),
+            }
+
+            sorted_records.push((index, timestamp, index_witness, timestamp_witness));
+        }
+
+        for record in records.iter() {
+            self.assign_tag(record.record_witness, access_tag);
+            match record.access_type {
+                AccessType::Read => self.memory_read_records.push(record.gate_index as u32),
+                AccessType::Write => self.memory_write_records.push(record.gate_index as u32),
+            }
+        }
+        self.ram_arrays[ram_id].records = records;
+
+        // Create gates that validate correctness of RAM timestamps
+        let mut timestamp_deltas = Vec::with_capacity(sorted_records.len());
+        for (current, next) in sorted_records.iter().tuple_windows() {
+            // Since every index is initialized, adjacent sorted indices differ by either 0 or 1 (which is enforced by
+            // the consistency check gates). Thus, we can compute whether the indices are equal as 1 - (next - current),
+            // which does not require a comparison of (potentially) shared values.
+            let index_delta = driver.sub(next.0.to_owned(), current.0.to_owned());
+            let share_index = driver.sub(T::AcvmType::from(P::ScalarField::one()), index_delta);
+            let timestamp_delta = driver.sub(next.1.to_owned(), current.1.to_owned());
+            let timestamp_delta = driver.mul(share_index, timestamp_delta)?;
+            let timestamp_delta_witness = self.add_variable(timestamp_delta);
+
+            self.apply_aux_selectors(AuxSelectors::RamTimestampCheck);
+            self.blocks.aux.populate_wires(
+                current.2,
+                current.3,
+                timestamp_delta_witness,
+                self.zero_idx,
+            );
+            self.num_gates += 1;
+
+            // store timestamp offsets for later. Need to apply range checks to them, but calling
+            // `create_new_range_constraint` can add gates. Would ruin the structure of our sorted timestamp list.
+            timestamp_deltas.push(timestamp_delta_witness);
+        }
+
+        // add the index/timestamp values of the last sorted record in an empty add gate.
+        // (the previous gate will access the wires on this gate and requires them to be those of the last record)
+        let last = sorted_records
+            .last()
+            .expect("RAM arrays have at least one record");
+        Self::create_dummy_gate(
+            &mut self.blocks.aux,
+            last.2,
+            last.3,
+            self.zero_idx,
+            self.zero_idx,
+        );
+        self.check_selector_length_consistency();
+        self.num_gates += 1; // necessary because create dummy gate cannot increment num_gates itself
+
+        // Validate difference in timestamps is monotonically increasing. i.e. is <= maximum timestamp
+        let max_timestamp = access_count - 1;
+        for w in timestamp_deltas {
+            self.create_new_range_constraint(w, max_timestamp as u64);
+        }
+        Ok(())
+    }
+
+    fn process_range_lists(&mut self, driver: &mut T) -> HonkProofResult<()> {
+        // We take the lists out of the builder to be able to add gates while iterating over them
+        let mut range_lists = std::mem::take(&mut self.range_lists);
+        for list in range_lists.values_mut() {
+            self.process_range_list(list, driver)?;
+        }
+        self.range_lists = range_lists;
+        Ok(())
+    }
+
+    fn process_range_list(&mut self, list: &mut RangeList, driver: &mut T) -> HonkProofResult<()> {
+        self.assert_valid_variables(&list.variable_indices);
+
+        assert!(!list.variable_indices.is_empty());
+
+        // replace witness index in variable_indices with the real variable index i.e. if a copy constraint has been
+        // applied on a variable after it was range constrained, this makes sure the indices in list point to the updated
+        // index in the range list so the set equivalence does not fail
+        for x in list.variable_indices.iter_mut() {
+            *x = self.real_variable_index[*x as usize];
+        }
+
+        // remove duplicate witness indices to prevent the sorted list set size being wrong!
+        list.variable_indices.sort();
+        list.variable_indices.dedup();
+
+        // go over variables
+        // iterate over each variable and create mirror variable with same value - with tau tag
+        // need to make sure that, in original list, increments of at most 3
+        let values = list
+            .variable_indices
+            .iter()
+            .map(|idx| self.get_variable(*idx as usize))
+            .collect::<Vec<_>>();
+        // All values are at most target_range, so we only have to sort the lower bits
+        let bit_size = (u64::BITS - list.target_range.leading_zeros()) as usize;
+        let sorted_list = driver.sort(&values, bit_size)?;
+
+        // list must be padded to a multipe of 4 and larger than 4 (gate_width)
+        const GATE_WIDTH: usize = NUM_WIRES;
+        let mut padding = (GATE_WIDTH - (list.variable_indices.len() % GATE_WIDTH)) % GATE_WIDTH;
+
+        let mut indices = Vec::with_capacity(padding + sorted_list.len());
+
+        // Ensure that the list has at least 2 * GATE_WIDTH elements
+        if list.variable_indices.len() <= GATE_WIDTH {
+            padding += GATE_WIDTH;
+        }
+        for _ in 0..padding {
+            indices.push(self.zero_idx);
+        }
+        for sorted_value in sorted_list {
+            let index = self.add_variable(sorted_value);
+            self.assign_tag(index, list.tau_tag);
+            indices.push(index);
+        }
+        self.create_sort_constraint_with_edges(
+            &indices,
+            P::ScalarField::zero(),
+            P::ScalarField::from(list.target_range),
+        );
+        Ok(())
+    }
+
+    fn create_sort_constraint_with_edges(
+        &mut self,
+        variable_index: &[u32],
+        start: P::ScalarField,
+        end: P::ScalarField,
+    ) {
+        // Convenient to assume size is at least 8 (gate_width = 4) for separate gates for start and end conditions
+        const GATE_WIDTH: usize = NUM_WIRES;
+        assert!(variable_index.len() % GATE_WIDTH == 0 && variable_index.len() > GATE_WIDTH);
+        self.assert_valid_variables(variable_index);
+
+        // Add an arithmetic gate to ensure the first input is equal to the start value of the range being checked
+        self.create_add_gate(&AddTriple {
+            a: variable_index[0],
+            b: self.zero_idx,
+            c: self.zero_idx,
+            a_scaling: P::ScalarField::one(),
+            b_scaling: P::ScalarField::zero(),
+            c_scaling: P::ScalarField::zero(),
+            const_scaling: -start,
+        });
+
+        // enforce range check for all but the final row
+        for i in (0..variable_index.len() - GATE_WIDTH).step_by(GATE_WIDTH) {
+            self.create_delta_range_gate(
+                variable_index[i],
+                variable_index[i + 1],
+                variable_index[i + 2],
+                variable_index[i + 3],
+            );
+        }
+
+        // enforce range checks of last row and ending at end
+        if variable_index.len() > GATE_WIDTH {
+            let len = variable_index.len();
+            self.create_delta_range_gate(
+                variable_index[len - 4],
+                variable_index[len - 3],
+                variable_index[len - 2],
+                variable_index[len - 1],
+            );
+        }
+
+        // dummy gate needed because of sort widget's check of next row
+        // use this gate to check end condition
+        // AZTEC TODO(https://github.com/AztecProtocol/barretenberg/issues/879): This was formerly a single arithmetic gate. A
+        // dummy gate has been added to allow the previous gate to access the required wire data via shifts, allowing the
+        // arithmetic gate to occur out of sequence.
+        Self::create_dummy_gate(
+            &mut self.blocks.delta_range,
+            variable_index[variable_index.len() - 1],
+            self.zero_idx,
+            self.zero_idx,
+            self.zero_idx,
+        );
+        self.check_selector_length_consistency();
+        self.num_gates += 1;
+
+        self.create_add_gate(&AddTriple {
+            a: variable_index[variable_index.len() - 1],
+            b: self.zero_idx,
+            c: self.zero_idx,
+            a_scaling: P::ScalarField::one(),
+            b_scaling: P::ScalarField::zero(),
+            c_scaling: P::ScalarField::zero(),
+            const_scaling: -end,
+        });
+    }
+
+    fn create_delta_range_gate(&mut self, idx_1: u32, idx_2: u32, idx_3: u32, idx_4: u32) {
+        let block = &mut self.blocks.delta_range;
+        block.populate_wires(idx_1, idx_2, idx_3, idx_4);
+        block.q_m().push(P::ScalarField::zero());
+        block.q_1().push(P::ScalarField::zero());
+        block.q_2().push(P::ScalarField::zero());
+        block.q_3().push(P::ScalarField::zero());
+        block.q_c().push(P::ScalarField::zero());
+        block.q_arith().push(P::ScalarField::zero());
+        block.q_4().push(P::ScalarField::zero());
+        block.q_delta_range().push(P::ScalarField::one());
+        block.q_elliptic().push(P::ScalarField::zero());
+        block.q_lookup_type().push(P::ScalarField::zero());
+        block.q_aux().push(P::ScalarField::zero());
+        block.q_poseidon2_external().push(P::ScalarField::zero());
+        block.q_poseidon2_internal().push(P::ScalarField::zero());
+
+        self.check_selector_length_consistency();
+        self.num_gates += 1;
+    }
+
+    fn get_new_tag(&mut self) -> u32 {
+        self.current_tag += 1;
+        self.current_tag
+    }
+
+    fn create_tag(&mut self, tag_index: u32, tau_index: u32) -> u32 {
+        self.tau.insert(tag_index, tau_index);
+        self.current_tag += 1;
+        self.current_tag
+    }
+
+    fn assign_tag(&mut self, variable_index: u32, tag: u32) {
+        assert!(tag <= self.current_tag);
+        let real_index = self.real_variable_index[variable_index as usize] as usize;
+        // If we've already assigned this tag to this variable, return (can happen due to copy constraints)
+        if self.real_variable_tags[real_index] == tag {
+            return;
+        }
+        assert_eq!(self.real_variable_tags[real_index], Self::DUMMY_TAG);
+        self.real_variable_tags[real_index] = tag;
+    }
+
+    fn create_range_list(&mut self, target_range: u64) -> RangeList {
+        let range_tag = self.get_new_tag();
+        let tau_tag = self.get_new_tag();
+        self.create_tag(range_tag, tau_tag);
+        self.create_tag(tau_tag, range_tag);
+
+        let num_multiples_of_three = target_range / Self::DEFAULT_PLOOKUP_RANGE_STEP_SIZE;
+        let mut variable_indices = Vec::with_capacity(num_multiples_of_three as usize + 2);
+        for i in 0..=num_multiples_of_three {
+            let index = self.add_variable(T::AcvmType::from(P::ScalarField::from(
+                i * Self::DEFAULT_PLOOKUP_RANGE_STEP_SIZE,
+            )));
+            variable_indices.push(index);
+            self.assign_tag(index, range_tag);
+        }
+        let index = self.add_variable(T::AcvmType::from(P::ScalarField::from(target_range)));
+        variable_indices.push(index);
+        self.assign_tag(index, range_tag);
+
+        // Need this because these variables will not appear in the witness otherwise
+        self.create_dummy_constraints(&variable_indices);
+
+        RangeList {
+            target_range,
+            range_tag,
+            tau_tag,
+            variable_indices,
+        }
+    }
+
+    fn create_dummy_constraints(&mut self, variable_index: &[u32]) {
+        let mut padded_list = variable_index.to_owned();
+        const GATE_WIDTH: usize = NUM_WIRES;
+        let padding = (GATE_WIDTH - (padded_list.len() % GATE_WIDTH)) % GATE_WIDTH;
+        for _ in 0..padding {
+            padded_list.push(self.zero_idx);
+        }
+        self.assert_valid_variables(variable_index);
+        self.assert_valid_variables(&padded_list);
+
+        for chunk in padded_list.chunks(GATE_WIDTH) {
+            Self::create_dummy_gate(
+                &mut self.blocks.arithmetic,
+                chunk[0],
+                chunk[1],
+                chunk[2],
+                chunk[3],
+            );
+            self.check_selector_length_consistency();
+            self.num_gates += 1;
+        }
+    }
+
+    pub(crate) fn create_range_constraint(
+        &mut self,
+        variable_index: u32,
+        num_bits: u32,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        if num_bits == 1 {
+            self.create_bool_gate(variable_index);
+        } else if num_bits as u64 <= Self::DEFAULT_PLOOKUP_RANGE_BITNUM {
+            // /**
+            //  * N.B. if `variable_index` is not used in any arithmetic constraints, this will create an unsatisfiable
+            //  *      circuit!
+            //  *      this range constraint will increase the size of the 'sorted set' of range-constrained integers by 1.
+            //  *      The 'non-sorted set' of range-constrained integers is a subset of the wire indices of all arithmetic
+            //  *      gates. No arithemtic gate => size imbalance between sorted and non-sorted sets. Checking for this
+            //  *      and throwing an error would require a refactor of the Composer to catelog all 'orphan' variables not
+            //  *      assigned to gates.
+            //  *
+            //  * AZTEC TODO(Suyash):
+            //  *    The following is a temporary fix to make sure the range constraints on numbers with
+            //  *    num_bits <= DEFAULT_PLOOKUP_RANGE_BITNUM is correctly enforced in the circuit.
+            //  *    Longer term, as Zac says, we would need to refactor the composer to fix this.
+            //  **/
+            self.create_poly_gate(&PolyTriple {
+                a: variable_index,
+                b: variable_index,
+                c: variable_index,
+                q_m: P::ScalarField::zero(),
+                q_l: P::ScalarField::one(),
+                q_r: -P::ScalarField::one(),
+                q_o: P::ScalarField::zero(),
+                q_c: P::ScalarField::zero(),
+            });
+            self.create_new_range_constraint(variable_index, (1u64 << num_bits) - 1);
+        } else {
+            self.decompose_into_default_range(
+                driver,
+                variable_index,
+                num_bits as u64,
+                Self::DEFAULT_PLOOKUP_RANGE_BITNUM,
+            )?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn create_bool_gate(&mut self, variable_index: u32) {
+        self.assert_valid_variables(&[variable_index]);
+
+        self.blocks.arithmetic.populate_wires(
+            variable_index,
+            variable_index,
+            self.zero_idx,
+            self.zero_idx,
+        );
+        self.blocks.arithmetic.q_m().push(P::ScalarField::one());
+        self.blocks.arithmetic.q_1().push(-P::ScalarField::one());
+        self.blocks.arithmetic.q_2().push(P::ScalarField::zero());
+        self.blocks.arithmetic.q_3().push(P::ScalarField::zero());
+        self.blocks.arithmetic.q_c().push(P::ScalarField::zero());
+        self.blocks
+            .arithmetic
+            .q_delta_range()
+            .push(P::ScalarField::zero());
+        self.blocks.arithmetic.q_arith().push(P::ScalarField::one());
+        self.blocks.arithmetic.q_4().push(P::ScalarField::zero());
+        self.blocks
+            .arithmetic
+            .q_lookup_type()
+            .push(P::ScalarField::zero());
+        self.blocks
+            .arithmetic
+            .q_elliptic()
+            .push(P::ScalarField::zero());
+        self.blocks.arithmetic.q_aux().push(P::ScalarField::zero());
+        self.blocks
+            .arithmetic
+            .q_poseidon2_external()
+            .push(P::ScalarField::zero());
+        self.blocks
+            .arithmetic
+            .q_poseidon2_internal()
+            .push(P::ScalarField::zero());
+
+        self.check_selector_length_consistency();
+        self.num_gates += 1;
+    }
+
+    pub(crate) fn create_new_range_constraint(&mut self, variable_index: u32, target_range: u64) {
+        // We ignore this check, since the value might be shared
+        // if (uint256_t(this->get_variable(variable_index)).data[0] > target_range) {
+        //     if (!this->failed()) {
+        //         this->failure(msg);
+        //     }
+        // }
+        if !self.range_lists.contains_key(&target_range) {
+            let list = self.create_range_list(target_range);
+            self.range_lists.insert(target_range, list);
+        }
+
+        let existing_tag =
+            self.real_variable_tags[self.real_variable_index[variable_index as usize] as usize];
+        let range_tag = self.range_lists[&target_range].range_tag;
+
+        // If the variable's tag matches the target range list's tag, do nothing.
+        if existing_tag != range_tag {
+            // If the variable is 'untagged' (i.e., it has the dummy tag), assign it the appropriate tag.
+            // Otherwise, find the range for which the variable has already been tagged.
+            if existing_tag != Self::DUMMY_TAG {
+                let existing_range = self
+                    .range_lists
+                    .iter()
+                    .find(|(_, list)| list.range_tag == existing_tag)
+                    .map(|(range, _)| *range)
+                    .expect("Tag not found in range lists");
+                if existing_range < target_range {
+                    // The variable already has a more restrictive range check, so do nothing.
+                    return;
+                }
+                // The range constraint we are trying to impose is more restrictive than the existing range
+                // constraint. It would be difficult to remove an existing range check. Instead deep-copy the
+                // variable and apply a range check to new variable
+                let copied_witness = self.add_variable(self.get_variable(variable_index as usize));
+                self.create_add_gate(&AddTriple {
+                    a: variable_index,
+                    b: copied_witness,
+                    c: self.zero_idx,
+                    a_scaling: P::ScalarField::one(),
+                    b_scaling: -P::ScalarField::one(),
+                    c_scaling: P::ScalarField::zero(),
+                    const_scaling: P::ScalarField::zero(),
+                });
+                // Recurse with new witness that has no tag attached.
+                self.create_new_range_constraint(copied_witness, target_range);
+                return;
+            }
+            self.assign_tag(variable_index, range_tag);
+            self.range_lists
+                .get_mut(&target_range)
+                .expect("Range list exists")
+                .variable_indices
+                .push(variable_index);
+        }
+    }
+
+    pub(crate) fn decompose_into_default_range(
+        &mut self,
+        driver: &mut T,
+        variable_index: u32,
+        num_bits: u64,
+        target_range_bitnum: u64,
+    ) -> HonkProofResult<Vec<u32>> {
+        self.assert_valid_variables(&[variable_index]);
+
+        assert!(num_bits > 0);
+        let val = self.get_variable(variable_index as usize);
+        // We cannot check that the value is in range, since it might be shared
+
+        let has_remainder_bits = num_bits % target_range_bitnum != 0;
+        let num_limbs = num_bits / target_range_bitnum + has_remainder_bits as u64;
+        let last_limb_size = num_bits - ((num_bits / target_range_bitnum) * target_range_bitnum);
+        let last_limb_range = (1u64 << last_limb_size) - 1;
+
+        let sublimbs = driver.decompose(
+            val.to_owned(),
+            num_bits as usize,
+            target_range_bitnum as usize,
+        )?;
+        debug_assert_eq!(sublimbs.len() as u64, num_limbs);
+
+        let mut sublimb_indices = Vec::with_capacity(sublimbs.len());
+        for (i, sublimb) in sublimbs.iter().enumerate() {
+            let limb_idx = self.add_variable(sublimb.to_owned());
+            sublimb_indices.push(limb_idx);
+            if i == sublimbs.len() - 1 && has_remainder_bits {
+                self.create_new_range_constraint(limb_idx, last_limb_range);
+            } else {
+                self.create_new_range_constraint(limb_idx, Self::DEFAULT_PLOOKUP_RANGE_CAP);
+            }
+        }
+
+        let num_limb_triples = num_limbs / 3 + ((num_limbs % 3) != 0) as u64;
+        let leftovers = if num_limbs % 3 == 0 { 3 } else { num_limbs % 3 };
+
+        let mut accumulator = val;
+        let mut accumulator_idx = variable_index;
+
+        for i in 0..num_limb_triples as usize {
+            let is_last = i == num_limb_triples as usize - 1;
+            let real_limbs = [
+                !(is_last && leftovers < 1),
+                !(is_last && leftovers < 2),
+                !(is_last && leftovers < 3),
+            ];
+
+            let mut new_limbs = [self.zero_idx; 3];
+            let mut shifts = [P::ScalarField::zero(); 3];
+            for j in 0..3 {
+                let shift = target_range_bitnum * (3 * i + j) as u64;
+                shifts[j] = P::ScalarField::from(2u64).pow([shift]);
+                if real_limbs[j] {
+                    new_limbs[j] = sublimb_indices[3 * i + j];
+                    let tmp =
+                        driver.acvm_mul_with_public(-shifts[j], sublimbs[3 * i + j].to_owned());
+                    accumulator = driver.add(accumulator, tmp);
+                }
+            }
+
+            self.create_big_add_gate(
+                &AddQuad {
+                    a: new_limbs[0],
+                    b: new_limbs[1],
+                    c: new_limbs[2],
+                    d: accumulator_idx,
+                    a_scaling: shifts[0],
+                    b_scaling: shifts[1],
+                    c_scaling: shifts[2],
+                    d_scaling: -P::ScalarField::one(),
+                    const_scaling: P::ScalarField::zero(),
+                },
+                !is_last,
+            );
+
+            accumulator_idx = self.add_variable(accumulator.to_owned());
+        }
+
+        Ok(sublimb_indices)
+    }
+
+    /**
+     * @brief Queue up non-native field multiplication data.
+     *
+     * @details The data queued represents a non-native field multiplication identity a * b = q * p + r,
+     * where a, b, q, r are all emulated non-native field elements that are each split across 4 distinct witness variables.
+     *
+     * Without this queue some functions, such as bigfield::madd, would not be able to use the non-native field gates.
+     * The identity is checked modulo 2^272 with the neg_modulus = 2^272 - p, the remaining checks (range constraints of
+     * the returned carries and the identity modulo the native modulus) are performed by the caller.
+     *
+     * Returns the witness indices of the carries lo_1 and hi_3.
+     */
+    pub(crate) fn evaluate_non_native_field_multiplication(
+        &mut self,
+        input: &NonNativeFieldWitnesses<P::ScalarField>,
+        driver: &mut T,
+    ) -> HonkProofResult<[u32; 2]> {
+        let a = input.a.map(|idx| self.get_variable(idx as usize));
+        let b = input.b.map(|idx| self.get_variable(idx as usize));
+        let q = input.q.map(|idx| self.get_variable(idx as usize));
+        let r = input.r.map(|idx| self.get_variable(idx as usize));
+        let n = input.neg_modulus;
+
+        let limb_shift =
+            P::ScalarField::from(BigUint::one() << Self::DEFAULT_NON_NATIVE_FIELD_LIMB_BITS);
+        let limb_rshift = limb_shift
+            .inverse()
+            .expect("the limb shift is not zero in the native field");
+        let limb_rshift_2 = limb_rshift * limb_rshift;
+
+        let mut mul = |x: &T::AcvmType, y: &T::AcvmType| driver.mul(x.to_owned(), y.to_owned());
+        let a0b0 = mul(&a[0], &b[0])?;
+        let a1b0 = mul(&a[1], &b[0])?;
+        let a0b1 = mul(&a[0], &b[1])?;
+        let a2b0 = mul(&a[2], &b[0])?;
+        let a0b2 = mul(&a[0], &b[2])?;
+        let a0b3 = mul(&a[0], &b[3])?;
+        let a3b0 = mul(&a[3], &b[0])?;
+        let a1b1 = mul(&a[1], &b[1])?;
+        let a1b2 = mul(&a[1], &b[2])?;
+        let a2b1 = mul(&a[2], &b[1])?;
+
+        // lo_0 = a[0] * b[0] - r[0] + (a[1] * b[0] + a[0] * b[1]) * LIMB_SHIFT
+        let lo_0 = driver.add(a1b0, a0b1);
+        let lo_0 = driver.acvm_mul_with_public(limb_shift, lo_0);
+        let lo_0 = driver.add(lo_0, a0b0);
+        let lo_0 = driver.sub(lo_0, r[0].to_owned());
+
+        // lo_1 = (lo_0 + q[0] * n[0] + (q[1] * n[0] + q[0] * n[1] - r[1]) * LIMB_SHIFT) * LIMB_RSHIFT_2
+        let tmp = driver.acvm_mul_with_public(n[0], q[1].to_owned());
+        let tmp2 = driver.acvm_mul_with_public(n[1], q[0].to_owned());
+        let tmp = driver.add(tmp, tmp2);
+        let tmp = driver.sub(tmp, r[1].to_owned());
+        let tmp = driver.acvm_mul_with_public(limb_shift, tmp);
+        let tmp2 = driver.acvm_mul_with_public(n[0], q[0].to_owned());
+        let lo_1 = driver.add(lo_0.to_owned(), tmp2);
+        let lo_1 = driver.add(lo_1, tmp);
+        let lo_1 = driver.acvm_mul_with_public(limb_rshift_2, lo_1);
+
+        // hi_0 = a[2] * b[0] + a[0] * b[2] + (a[0] * b[3] + a[3] * b[0] - r[3]) * LIMB_SHIFT
+        let hi_0 = driver.add(a0b3, a3b0);
+        let hi_0 = driver.sub(hi_0, r[3].to_owned());
+        let hi_0 = driver.acvm_mul_with_public(limb_shift, hi_0);
+        let hi_0 = driver.add(hi_0, a2b0);
+        let hi_0 = driver.add(hi_0, a0b2);
+
+        // hi_1 = hi_0 + a[1] * b[1] - r[2] + (a[1] * b[2] + a[2] * b[1]) * LIMB_SHIFT
+        let hi_1 = driver.add(a1b2, a2b1);
+        let hi_1 = driver.acvm_mul_with_public(limb_shift, hi_1);
+        let hi_1 = driver.add(hi_1, hi_0.to_owned());
+        let hi_1 = driver.add(hi_1, a1b1);
+        let hi_1 = driver.sub(hi_1, r[2].to_owned());
+
+        // hi_2 = hi_1 + lo_1 + q[2] * n[0] + (q[3] * n[0] + q[2] * n[1]) * LIMB_SHIFT
+        let tmp = driver.acvm_mul_with_public(n[0], q[3].to_owned());
+        let tmp2 = driver.acvm_mul_with_public(n[1], q[2].to_owned());
+        let tmp = driver.add(tmp, tmp2);
+        let tmp = driver.acvm_mul_with_public(limb_shift, tmp);
+        let tmp2 = driver.acvm_mul_with_public(n[0], q[2].to_owned());
+        let hi_2 = driver.add(hi_1.to_owned(), lo_1.to_owned());
+        let hi_2 = driver.add(hi_2, tmp2);
+        let hi_2 = driver.add(hi_2, tmp);
+
+        // hi_3 = (hi_2 + (q[0] * n[3] + q[1] * n[2]) * LIMB_SHIFT + (q[0] * n[2] + q[1] * n[1])) * LIMB_RSHIFT_2
+        let tmp = driver.acvm_mul_with_public(n[3], q[0].to_owned());
+        let tmp2 = driver.acvm_mul_with_public(n[2], q[1].to_owned());
+        let tmp = driver.add(tmp, tmp2);
+        let tmp = driver.acvm_mul_with_public(limb_shift, tmp);
+        let tmp2 = driver.acvm_mul_with_public(n[2], q[0].to_owned());
+        let tmp3 = driver.acvm_mul_with_public(n[1], q[1].to_owned());
+        let hi_3 = driver.add(hi_2.to_owned(), tmp);
+        let hi_3 = driver.add(hi_3, tmp2);
+        let hi_3 = driver.add(hi_3, tmp3);
+        let hi_3 = driver.acvm_mul_with_public(limb_rshift_2, hi_3);
+
+        let lo_0_idx = self.add_variable(lo_0);
+        let lo_1_idx = self.add_variable(lo_1);
+        let hi_0_idx = self.add_variable(hi_0);
+        let hi_1_idx = self.add_variable(hi_1);
+        let hi_2_idx = self.add_variable(hi_2);
+        let hi_3_idx = self.add_variable(hi_3);
+
+        // Multiplication gates
+        self.blocks
+            .aux
+            .populate_wires(input.a[1], input.b[1], input.r[0], lo_0_idx);
+        self.apply_aux_selectors(AuxSelectors::NonNativeField1);
+        self.num_gates += 1;
+
+        self.blocks
+            .aux
+            .populate_wires(input.a[0], input.b[0], input.a[3], input.b[3]);
+        self.apply_aux_selectors(AuxSelectors::NonNativeField2);
+        self.num_gates += 1;
+
+        self.blocks
+            .aux
+            .populate_wires(input.a[2], input.b[2], input.r[3], hi_0_idx);
+        self.apply_aux_selectors(AuxSelectors::NonNativeField3);
+        self.num_gates += 1;
+
+        self.blocks
+            .aux
+            .populate_wires(input.a[1], input.b[1], input.r[2], hi_1_idx);
+        self.apply_aux_selectors(AuxSelectors::None);
+        self.num_gates += 1;
+
+        // product gate 6
+        // hi_2 - hi_1 - lo_1 - q[2](p[1].2^b + p[0]) - q[3](p[0].2^b) = 0
+        self.create_big_add_gate(
+            &AddQuad {
+                a: input.q[2],
+                b: input.q[3],
+                c: lo_1_idx,
+                d: hi_1_idx,
+                a_scaling: -n[1] * limb_shift - n[0],
+                b_scaling: -n[0] * limb_shift,
+                c_scaling: -P::ScalarField::one(),
+                d_scaling: -P::ScalarField::one(),
+                const_scaling: P::ScalarField::zero(),
+            },
+            true,
+        );
+
+        // product gate 7
+        // hi_3 - (hi_2 - q[0](p[3].2^{2b} + p[2].2^b) - q[1](p[2].2^{2b} + p[1].2^b)).2^{-2b}
+        self.create_big_add_gate(
+            &AddQuad {
+                a: hi_3_idx,
+                b: input.q[0],
+                c: input.q[1],
+                d: hi_2_idx,
+                a_scaling: -P::ScalarField::one(),
+                b_scaling: n[3] * limb_rshift + n[2] * limb_rshift_2,
+                c_scaling: n[2] * limb_rshift + n[1] * limb_rshift_2,
+                d_scaling: limb_rshift_2,
+                const_scaling: P::ScalarField::zero(),
+            },
+            false,
+        );
+
+        // product gate 5
+        // (lo_0 + q_0(p_0 + p_1*2^b) + q_1(p_0*2^b) - (r_1)2^b)2^-2b - lo_1 = 0
+        self.create_big_add_gate(
+            &AddQuad {
+                a: input.q[0],
+                b: input.q[1],
+                c: input.r[1],
+                d: lo_1_idx,
+                a_scaling: n[0] + n[1] * limb_shift,
+                b_scaling: n[0] * limb_shift,
+                c_scaling: -limb_shift,
+                d_scaling: -(limb_shift * limb_shift),
+                const_scaling: P::ScalarField::zero(),
+            },
+            true,
+        );
+
+        // Add a dummy gate to ensure lo_0 is in the next row
+        Self::create_dummy_gate(
+            &mut self.blocks.arithmetic,
+            self.zero_idx,
+            self.zero_idx,
+            self.zero_idx,
+            lo_0_idx,
+        );
+        self.check_selector_length_consistency();
+        self.num_gates += 1;
+
+        Ok([lo_1_idx, hi_3_idx])
     }
 
     fn process_non_native_field_multiplications(&mut self) {
@@ -1880,3 +4412,38 @@ impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> GenericUltraCi
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use acir::{
+        circuit::{Circuit, Opcode},
+        native_types::Witness,
+    };
+    use ark_bn254::Bn254;
+
+    #[test]
+    fn create_circuit_rejects_call_opcodes() {
+        let circuit = Circuit {
+            current_witness_index: 1,
+            opcodes: vec![Opcode::Call {
+                id: 1,
+                inputs: vec![Witness(0)],
+                outputs: vec![Witness(1)],
+                predicate: None,
+            }],
+            ..Circuit::default()
+        };
+        let constraint_system = AcirFormat::circuit_serde_to_acir_format(circuit, true);
+        let witness = vec![ark_bn254::Fr::from(1u64), ark_bn254::Fr::from(2u64)];
+        let result = UltraCircuitBuilder::<Bn254>::create_circuit(
+            constraint_system,
+            0,
+            witness,
+            true,
+            false,
+            &mut PlainAcvmSolver::default(),
+        );
+        assert!(result.is_err());
+    }
+}
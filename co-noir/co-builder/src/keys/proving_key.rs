@@ -7,10 +7,11 @@ use crate::{
     },
     types::types::{CyclicPermutation, Mapping, PermutationMapping, TraceData, NUM_WIRES},
     utils::Utils,
+    HonkProofResult,
 };
 use ark_ec::pairing::Pairing;
 use ark_ff::One;
-use co_acvm::mpc::NoirWitnessExtensionProtocol;
+use co_acvm::{mpc::NoirWitnessExtensionProtocol, PlainAcvmSolver};
 use eyre::Result;
 
 pub struct ProvingKey<P: Pairing> {
@@ -26,9 +27,10 @@ pub struct ProvingKey<P: Pairing> {
 
 impl<P: Pairing> ProvingKey<P> {
     // We ignore the TraceStructure for now (it is None in barretenberg for UltraHonk)
-    pub fn create(mut circuit: UltraCircuitBuilder<P>, crs: ProverCrs<P>) -> Self {
+    pub fn create(mut circuit: UltraCircuitBuilder<P>, crs: ProverCrs<P>) -> HonkProofResult<Self> {
         tracing::trace!("ProvingKey create");
-        circuit.finalize_circuit(true);
+        let mut driver = PlainAcvmSolver::default();
+        circuit.finalize_circuit(true, &mut driver)?;
 
         let dyadic_circuit_size = circuit.compute_dyadic_size();
         let mut proving_key = Self::new(dyadic_circuit_size, circuit.public_inputs.len(), crs);
@@ -72,7 +74,7 @@ impl<P: Pairing> ProvingKey<P> {
             proving_key.public_inputs.push(*input);
         }
 
-        proving_key
+        Ok(proving_key)
     }
 
     fn get_crs_size<T: NoirWitnessExtensionProtocol<P::ScalarField>>(
@@ -381,4 +383,29 @@ impl<P: Pairing> ProvingKey<P> {
             table_offset += table.len(); // set the offset of the next table within the polynomials
         }
     }
+
+    pub fn construct_shared_lookup_read_counts<T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+        read_counts: &mut [T::AcvmType],
+        read_tags: &mut Polynomial<P::ScalarField>,
+        circuit: &GenericUltraCircuitBuilder<P, T>,
+        dyadic_circuit_size: usize,
+        driver: &mut T,
+    ) {
+        // The lookups with shared keys are accumulated as shared one-hot vectors during circuit construction. Since the
+        // read tags are public, we set them for the whole table to not leak which entries have been read.
+        let mut table_offset = dyadic_circuit_size - circuit.get_tables_size();
+        for table in circuit.lookup_tables.iter() {
+            if let Some(shared_counts) = circuit.shared_lookup_read_counts.get(&table.table_index) {
+                for (i, shared_count) in shared_counts.iter().enumerate() {
+                    let index_in_poly = table_offset + i;
+                    read_counts[index_in_poly] = driver.add(
+                        read_counts[index_in_poly].to_owned(),
+                        shared_count.to_owned(),
+                    );
+                    read_tags[index_in_poly] = P::ScalarField::one();
+                }
+            }
+            table_offset += table.len();
+        }
+    }
 }
@@ -39,4 +39,7 @@ pub enum HonkProofError {
     ExpectedPublicWitness,
     #[error(transparent)]
     IOError(#[from] std::io::Error),
+    /// An error raised by the MPC driver during the computation of a witness.
+    #[error(transparent)]
+    UnrecoverableError(#[from] eyre::Report),
 }
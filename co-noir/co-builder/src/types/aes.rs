@@ -0,0 +1,276 @@
+use super::{
+    plookup::{AesTables, BasicTableId, MultiTableId, Plookup},
+    sha256::scale,
+    types::{ColumnIdx, FieldCT},
+};
+use crate::{builder::GenericUltraCircuitBuilder, HonkProofResult};
+use ark_ec::pairing::Pairing;
+use ark_ff::PrimeField;
+use co_acvm::mpc::NoirWitnessExtensionProtocol;
+use mpc_core::gadgets::aes::{AES128_BLOCK_BYTES, AES128_ROUNDS, AES128_ROUND_CONSTANTS};
+
+/// The bytes of the AES state are kept in sparse form, where each bit is a base-9 digit, such that XOR becomes
+/// addition. After the S-box, the second element is 3 times the first one in GF(2^8), which is required for
+/// MixColumns. Otherwise, the second element is unused.
+type BytePair<F> = (FieldCT<F>, FieldCT<F>);
+
+/// The number of additions after which a sparse byte of the round key is normalized, such that adding it to the
+/// MixColumns output (which is the sum of 5 sparse bytes) does not overflow a base-9 digit.
+const ROUND_KEY_MAX_ADDITIONS: usize = 3;
+
+fn sparse_constant<F: PrimeField>(byte: u8) -> FieldCT<F> {
+    FieldCT::from_field(BasicTableId::map_into_sparse_form::<F, { AesTables::BASE }>(byte as u64))
+}
+
+fn normalize_sparse_form<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    byte: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    Plookup::read_from_1_to_2_table(builder, driver, MultiTableId::AesNormalize, byte)
+}
+
+fn apply_aes_sbox_map<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    byte: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<BytePair<P::ScalarField>> {
+    Plookup::read_pair_from_table(builder, driver, MultiTableId::AesSbox, byte)
+}
+
+/// Packs 16 bytes into a 128-bit value, where the first byte is the most significant one.
+pub(crate) fn pack_block<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    bytes: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    let mut packed = FieldCT::default();
+    for byte in bytes {
+        let shifted = scale(&packed, P::ScalarField::from(256u64), builder, driver)?;
+        packed = shifted.add(byte, builder, driver);
+    }
+    Ok(packed)
+}
+
+/// Splits a 128-bit value into its 16 bytes in sparse form, where the first byte is the most significant one. The
+/// lookup also constrains the value to 128 bits.
+fn convert_into_sparse_bytes<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    block: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let lookup = Plookup::get_lookup_accumulators_ct(
+        builder,
+        driver,
+        MultiTableId::AesInput,
+        block,
+        &FieldCT::default(),
+        false,
+    )?;
+    Ok(lookup[ColumnIdx::C2].iter().rev().cloned().collect())
+}
+
+/// Constrains the normalized sparse bytes to be the bytes of the 128-bit block, where the first byte is the most
+/// significant one.
+pub(crate) fn assert_equal_sparse_bytes<
+    P: Pairing,
+    T: NoirWitnessExtensionProtocol<P::ScalarField>,
+>(
+    sparse_bytes: &[FieldCT<P::ScalarField>],
+    block: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<()> {
+    let expected = convert_into_sparse_bytes(block, builder, driver)?;
+    for (byte, expected) in sparse_bytes.iter().zip(expected.iter()) {
+        byte.assert_equal(expected, builder, driver);
+    }
+    Ok(())
+}
+
+/// Expands the key into the 11 round keys in sparse form. The bytes are only normalized if required, i.e., if they
+/// are the input to an S-box or if too many additions have been accumulated.
+fn expand_key<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    key: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let num_bytes = AES128_BLOCK_BYTES * (AES128_ROUNDS + 1);
+    let mut round_key = convert_into_sparse_bytes(key, builder, driver)?;
+    round_key.reserve(num_bytes - AES128_BLOCK_BYTES);
+    // The number of normalized sparse bytes which have been added to obtain each byte of the round key
+    let mut add_counts = vec![1; num_bytes];
+
+    for i in 4..num_bytes / 4 {
+        let k = (i - 1) * 4;
+        let mut temp = round_key[k..k + 4].to_vec();
+        let mut temp_add_counts = add_counts[k..k + 4].to_vec();
+
+        if i % 4 == 0 {
+            // The last word of the previous round key is normalized, thus it can be used as S-box input
+            temp.rotate_left(1);
+            for byte in temp.iter_mut() {
+                *byte = apply_aes_sbox_map(byte, builder, driver)?.0;
+            }
+            temp[0] = temp[0].add(
+                &sparse_constant(AES128_ROUND_CONSTANTS[i / 4 - 1]),
+                builder,
+                driver,
+            );
+            temp_add_counts = vec![2, 1, 1, 1];
+        }
+
+        let j = i * 4;
+        let k = (i - 4) * 4;
+        for (m, (temp, temp_add_count)) in temp.iter().zip(temp_add_counts).enumerate() {
+            let mut byte = round_key[k + m].add(temp, builder, driver);
+            add_counts[j + m] = add_counts[k + m] + temp_add_count;
+            // The last word of each round key is the input to the S-boxes of the next one
+            if add_counts[j + m] > ROUND_KEY_MAX_ADDITIONS || (add_counts[j + m] > 1 && i % 4 == 3)
+            {
+                byte = normalize_sparse_form(&byte, builder, driver)?;
+                add_counts[j + m] = 1;
+            }
+            round_key.push(byte);
+        }
+    }
+
+    Ok(round_key)
+}
+
+/// The ShiftRows step, where the state is stored column by column, i.e., state[r + 4 * c] is in row r and column c.
+fn shift_rows<F: PrimeField>(state: &mut [BytePair<F>]) {
+    let old = state.to_vec();
+    for (i, byte) in state.iter_mut().enumerate() {
+        let (row, column) = (i % 4, i / 4);
+        *byte = old[row + 4 * ((column + row) % 4)].to_owned();
+    }
+}
+
+/// MixColumns on a single column followed by AddRoundKey, where each byte pair holds a byte a_r and 3 * a_r after
+/// the S-box. The results are not normalized.
+fn mix_column_and_add_round_key<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    column: &mut [BytePair<P::ScalarField>],
+    round_key: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) {
+    // 2 * a_0 = 3 * a_0 + a_0, hence r_0 = 2 * a_0 + 3 * a_1 + a_2 + a_3 = (a_0 + a_3 + 3 * a_1) + (a_2 + 3 * a_0)
+    let t0 = column[0]
+        .0
+        .add_two(&column[3].0, &column[1].1, builder, driver);
+    let t1 = column[1]
+        .0
+        .add_two(&column[2].0, &column[3].1, builder, driver);
+
+    let r0 = t0.add_two(&column[2].0, &column[0].1, builder, driver);
+    let r1 = t0.add_two(&column[1].0, &column[2].1, builder, driver);
+    let r2 = t1.add_two(&column[0].0, &column[2].1, builder, driver);
+    let r3 = t1.add_two(&column[0].1, &column[3].0, builder, driver);
+
+    for ((byte, _), (result, key)) in column
+        .iter_mut()
+        .zip([r0, r1, r2, r3].iter().zip(round_key))
+    {
+        *byte = result.add(key, builder, driver);
+    }
+}
+
+fn sub_bytes<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    state: &mut [BytePair<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<()> {
+    for byte in state.iter_mut() {
+        *byte = apply_aes_sbox_map(&byte.0, builder, driver)?;
+    }
+    Ok(())
+}
+
+fn add_round_key<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    state: &mut [BytePair<P::ScalarField>],
+    round_key: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) {
+    for ((byte, _), key) in state.iter_mut().zip(round_key) {
+        *byte = byte.add(key, builder, driver);
+    }
+}
+
+fn normalize_state<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    state: &mut [BytePair<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<()> {
+    for (byte, _) in state.iter_mut() {
+        *byte = normalize_sparse_form(byte, builder, driver)?;
+    }
+    Ok(())
+}
+
+/// Encrypts a single block in sparse form with the given round keys. The resulting bytes are not normalized.
+fn aes128_cipher<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    state: &mut [BytePair<P::ScalarField>],
+    round_key: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<()> {
+    add_round_key(state, &round_key[..AES128_BLOCK_BYTES], builder, driver);
+    normalize_state(state, builder, driver)?;
+
+    for round in 1..AES128_ROUNDS {
+        sub_bytes(state, builder, driver)?;
+        shift_rows(state);
+        let round_key = &round_key[round * AES128_BLOCK_BYTES..(round + 1) * AES128_BLOCK_BYTES];
+        for (column, round_key) in state.chunks_exact_mut(4).zip(round_key.chunks_exact(4)) {
+            mix_column_and_add_round_key(column, round_key, builder, driver);
+        }
+        normalize_state(state, builder, driver)?;
+    }
+
+    sub_bytes(state, builder, driver)?;
+    shift_rows(state);
+    add_round_key(
+        state,
+        &round_key[AES128_ROUNDS * AES128_BLOCK_BYTES..],
+        builder,
+        driver,
+    );
+    Ok(())
+}
+
+/// Encrypts the padded input blocks with AES-128 in CBC mode, where the input blocks, the IV and the key are given as
+/// 128-bit values. Returns the bytes of the ciphertext in normalized sparse form.
+pub(crate) fn encrypt_buffer_cbc<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &[FieldCT<P::ScalarField>],
+    iv: &FieldCT<P::ScalarField>,
+    key: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let round_key = expand_key(key, builder, driver)?;
+
+    let mut sparse_state = Vec::with_capacity(input.len() * AES128_BLOCK_BYTES);
+    for block in input {
+        for byte in convert_into_sparse_bytes(block, builder, driver)? {
+            sparse_state.push((byte, FieldCT::default()));
+        }
+    }
+
+    let mut sparse_iv = convert_into_sparse_bytes(iv, builder, driver)?;
+    for round_state in sparse_state.chunks_exact_mut(AES128_BLOCK_BYTES) {
+        add_round_key(round_state, &sparse_iv, builder, driver);
+        aes128_cipher(round_state, &round_key, builder, driver)?;
+        // The ciphertext is the IV of the next block
+        sparse_iv = round_state
+            .iter()
+            .map(|(byte, _)| byte.to_owned())
+            .collect();
+    }
+
+    sparse_state
+        .iter()
+        .map(|(byte, _)| normalize_sparse_form(byte, builder, driver))
+        .collect()
+}
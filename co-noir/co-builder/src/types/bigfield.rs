@@ -0,0 +1,845 @@
+use super::{
+    sha256::scale,
+    types::{FieldCT, NonNativeFieldWitnesses},
+};
+use crate::{builder::GenericUltraCircuitBuilder, HonkProofResult};
+use ark_ec::pairing::Pairing;
+use ark_ff::{One, PrimeField, Zero};
+use co_acvm::mpc::NoirWitnessExtensionProtocol;
+use num_bigint::BigUint;
+
+pub(crate) const NUM_LIMBS: usize = 4;
+pub(crate) const NUM_LIMB_BITS: usize = 68;
+/// The identities of the non-native field gates hold modulo 2^NUM_BINARY_BASIS_BITS
+const NUM_BINARY_BASIS_BITS: usize = NUM_LIMBS * NUM_LIMB_BITS;
+/// The number of bytes of the byte representation of an element, which suffices for moduli of up to 256 bits
+pub(crate) const NUM_BYTES: usize = 32;
+/// Elements whose limbs would grow beyond this size are reduced first
+const MAX_UNREDUCED_LIMB_BITS: u64 = NUM_LIMB_BITS as u64 + 10;
+
+/// An element of a non-native prime field, represented by four limbs of NUM_LIMB_BITS bits (the binary basis).
+/// Additions and subtractions are evaluated lazily on the limbs, thus the limbs may exceed NUM_LIMB_BITS bits and the value may exceed the modulus until the element gets reduced.
+/// The maximum values of the limbs are tracked to decide when a reduction is required.
+#[derive(Clone)]
+pub(crate) struct BigField<F: PrimeField> {
+    pub(crate) limbs: [FieldCT<F>; NUM_LIMBS],
+    pub(crate) max_limb_values: [BigUint; NUM_LIMBS],
+    pub(crate) modulus: BigUint,
+}
+
+impl<F: PrimeField> BigField<F> {
+    fn split_into_limbs(value: &BigUint) -> [BigUint; NUM_LIMBS] {
+        let mask = (BigUint::one() << NUM_LIMB_BITS) - 1u64;
+        std::array::from_fn(|i| (value >> (i * NUM_LIMB_BITS)) & &mask)
+    }
+
+    /// Returns the number of bits of each limb of a value with at most num_bits bits.
+    fn limb_bits(num_bits: u64) -> [usize; NUM_LIMBS] {
+        std::array::from_fn(|i| {
+            (num_bits as usize)
+                .saturating_sub(i * NUM_LIMB_BITS)
+                .min(NUM_LIMB_BITS)
+        })
+    }
+
+    pub(crate) fn from_constant(value: &BigUint, modulus: &BigUint) -> Self {
+        let limbs = Self::split_into_limbs(&(value % modulus));
+        Self {
+            limbs: limbs
+                .each_ref()
+                .map(|limb| FieldCT::from_field(F::from(limb.to_owned()))),
+            max_limb_values: limbs,
+            modulus: modulus.to_owned(),
+        }
+    }
+
+    fn from_witness_limbs<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        limbs: Vec<T::AcvmType>,
+        num_bits: u64,
+        modulus: &BigUint,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        let limb_bits = Self::limb_bits(num_bits);
+        let mut result = Self::from_constant(&BigUint::zero(), modulus);
+        for (i, limb) in limbs.into_iter().enumerate() {
+            let limb = FieldCT::from_witness(limb, builder);
+            limb.create_range_constraint(limb_bits[i], builder, driver)?;
+            result.limbs[i] = limb;
+            result.max_limb_values[i] = (BigUint::one() << limb_bits[i]) - 1u64;
+        }
+        Ok(result)
+    }
+
+    /// Composes an element from big-endian bytes, which get range constrained to 8 bits. Bytes that cross a limb boundary are split into nibbles.
+    pub(crate) fn from_be_bytes<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        bytes: &[FieldCT<F>],
+        modulus: &BigUint,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        assert!(bytes.len() * 8 <= NUM_BINARY_BASIS_BITS);
+        let mut limb_terms: [Vec<FieldCT<F>>; NUM_LIMBS] = Default::default();
+        let mut max_limb_values: [BigUint; NUM_LIMBS] = Default::default();
+        let mut push_term = |term: FieldCT<F>,
+                             offset: usize,
+                             num_bits: usize,
+                             builder: &mut GenericUltraCircuitBuilder<P, T>,
+                             driver: &mut T|
+         -> HonkProofResult<()> {
+            let limb = offset / NUM_LIMB_BITS;
+            let shift = offset % NUM_LIMB_BITS;
+            limb_terms[limb].push(scale(
+                &term,
+                F::from(BigUint::one() << shift),
+                builder,
+                driver,
+            )?);
+            max_limb_values[limb] += ((BigUint::one() << num_bits) - 1u64) << shift;
+            Ok(())
+        };
+
+        for (i, byte) in bytes.iter().rev().enumerate() {
+            let offset = i * 8;
+            if offset / NUM_LIMB_BITS == (offset + 7) / NUM_LIMB_BITS {
+                byte.create_range_constraint(8, builder, driver)?;
+                push_term(byte.to_owned(), offset, 8, builder, driver)?;
+            } else {
+                // The byte crosses a limb boundary, which is always located in its middle
+                let (lo, hi) = if byte.is_constant() {
+                    let value: BigUint = byte.additive_constant.into();
+                    let lo = FieldCT::from_field(F::from(&value & BigUint::from(15u64)));
+                    let hi = FieldCT::from_field(F::from(value >> 4));
+                    (lo, hi)
+                } else {
+                    let value = byte.get_value(builder, driver);
+                    let nibbles = driver.decompose(value, 8, 4)?;
+                    let lo_idx = builder.add_variable(nibbles[0].to_owned());
+                    let hi_idx = builder.add_variable(nibbles[1].to_owned());
+                    builder.create_new_range_constraint(lo_idx, 15);
+                    builder.create_new_range_constraint(hi_idx, 15);
+                    let lo = FieldCT::from_witness_index(lo_idx);
+                    let hi = FieldCT::from_witness_index(hi_idx);
+                    let sum = lo.add(
+                        &scale(&hi, F::from(16u64), builder, driver)?,
+                        builder,
+                        driver,
+                    );
+                    sum.assert_equal(byte, builder, driver);
+                    (lo, hi)
+                };
+                push_term(lo, offset, 4, builder, driver)?;
+                push_term(hi, offset + 4, 4, builder, driver)?;
+            }
+        }
+
+        let mut limbs: [FieldCT<F>; NUM_LIMBS] = Default::default();
+        for (limb, terms) in limbs.iter_mut().zip(limb_terms) {
+            *limb = accumulate(&terms, builder, driver);
+        }
+        Ok(Self {
+            limbs,
+            max_limb_values,
+            modulus: modulus.to_owned(),
+        })
+    }
+
+    /// Composes an element from the given limbs, which get range constrained to the limb sizes of a reduced element.
+    pub(crate) fn from_limbs<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        limbs: &[FieldCT<F>; NUM_LIMBS],
+        modulus: &BigUint,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        let limb_bits = Self::limb_bits(modulus.bits());
+        for (limb, bits) in limbs.iter().zip(limb_bits) {
+            limb.create_range_constraint(bits, builder, driver)?;
+        }
+        Ok(Self {
+            limbs: limbs.to_owned(),
+            max_limb_values: limb_bits.map(|bits| (BigUint::one() << bits) - 1u64),
+            modulus: modulus.to_owned(),
+        })
+    }
+
+    /// Composes an element from its lower 2 * NUM_LIMB_BITS bits and the remaining upper bits, which is the representation of non-native field elements in the transcript.
+    pub(crate) fn from_lo_hi<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        lo: &FieldCT<F>,
+        hi: &FieldCT<F>,
+        modulus: &BigUint,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        let lo_bits = 2 * NUM_LIMB_BITS;
+        if lo.is_constant() && hi.is_constant() {
+            let lo: BigUint = lo.additive_constant.into();
+            let hi: BigUint = hi.additive_constant.into();
+            return Ok(Self::from_constant(&(lo + (hi << lo_bits)), modulus));
+        }
+
+        let num_bits = modulus.bits();
+        let lo_value = lo.get_value(builder, driver);
+        let hi_value = hi.get_value(builder, driver);
+        let mut limbs = driver.decompose(lo_value, lo_bits, NUM_LIMB_BITS)?;
+        limbs.extend(driver.decompose(hi_value, num_bits as usize - lo_bits, NUM_LIMB_BITS)?);
+        let result = Self::from_witness_limbs(limbs, num_bits, modulus, builder, driver)?;
+
+        let shift = F::from(BigUint::one() << NUM_LIMB_BITS);
+        for (i, half) in [(0, lo), (2, hi)] {
+            scale(&result.limbs[i + 1], shift, builder, driver)?
+                .add(&result.limbs[i], builder, driver)
+                .assert_equal(half, builder, driver);
+        }
+        Ok(result)
+    }
+
+    pub(crate) fn is_constant(&self) -> bool {
+        self.limbs.iter().all(|limb| limb.is_constant())
+    }
+
+    /// Returns the value of a constant element as an integer, which is not reduced.
+    fn constant_value(&self) -> BigUint {
+        debug_assert!(self.is_constant());
+        self.limbs.iter().rev().fold(BigUint::zero(), |acc, limb| {
+            let limb: BigUint = limb.additive_constant.into();
+            (acc << NUM_LIMB_BITS) + limb
+        })
+    }
+
+    fn max_value(&self) -> BigUint {
+        self.max_limb_values
+            .iter()
+            .rev()
+            .fold(BigUint::zero(), |acc, limb| (acc << NUM_LIMB_BITS) + limb)
+    }
+
+    fn max_limb_bits(&self) -> usize {
+        self.max_limb_values
+            .iter()
+            .map(|limb| limb.bits() as usize)
+            .max()
+            .expect("there are limbs")
+    }
+
+    /// Returns true if the limbs fit into the sizes of the remainder of a reduction
+    fn is_reduced(&self) -> bool {
+        let limb_bits = Self::limb_bits(self.modulus.bits());
+        self.max_limb_values
+            .iter()
+            .zip(limb_bits)
+            .all(|(max, bits)| max.bits() as usize <= bits)
+    }
+
+    fn exceeds_limb_bound(&self) -> bool {
+        self.max_limb_values
+            .iter()
+            .any(|max| max.bits() > MAX_UNREDUCED_LIMB_BITS)
+    }
+
+    fn limb_values<P: Pairing<ScalarField = F>, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+        &self,
+        builder: &GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> Vec<T::AcvmType> {
+        self.limbs
+            .iter()
+            .map(|limb| limb.get_value(builder, driver))
+            .collect()
+    }
+
+    fn limb_witness_indices<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        limbs: &[FieldCT<F>; NUM_LIMBS],
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> [u32; NUM_LIMBS] {
+        limbs.each_ref().map(|limb| {
+            if limb.is_constant() {
+                builder.put_constant_variable(limb.additive_constant)
+            } else {
+                limb.normalize(builder, driver).get_witness_index()
+            }
+        })
+    }
+
+    /// Computes the value modulo the native modulus from the given limbs.
+    fn prime_basis_limb<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        limbs: &[FieldCT<F>; NUM_LIMBS],
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<FieldCT<F>> {
+        let mut terms = Vec::with_capacity(NUM_LIMBS);
+        for (i, limb) in limbs.iter().enumerate() {
+            let shift = F::from(BigUint::one() << (i * NUM_LIMB_BITS));
+            terms.push(scale(limb, shift, builder, driver)?);
+        }
+        Ok(accumulate(&terms, builder, driver))
+    }
+
+    pub(crate) fn add<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        other: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        let max_limb_values: [BigUint; NUM_LIMBS] =
+            std::array::from_fn(|i| &self.max_limb_values[i] + &other.max_limb_values[i]);
+        if max_limb_values
+            .iter()
+            .any(|max| max.bits() > MAX_UNREDUCED_LIMB_BITS)
+        {
+            let lhs = self.reduce(builder, driver)?;
+            let rhs = other.reduce(builder, driver)?;
+            return lhs.add(&rhs, builder, driver);
+        }
+
+        let mut limbs: [FieldCT<F>; NUM_LIMBS] = Default::default();
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            *limb = self.limbs[i].add(&other.limbs[i], builder, driver);
+        }
+        Ok(Self {
+            limbs,
+            max_limb_values,
+            modulus: self.modulus.to_owned(),
+        })
+    }
+
+    /// Computes self - other as self + m - other, where m is a multiple of the modulus whose limbs are at least as large as the ones of other. Thus, all limbs of the result stay positive.
+    pub(crate) fn sub<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        other: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        let other_limb_bits = other.max_limb_bits().max(NUM_LIMB_BITS) as u64;
+        let borrow = BigUint::one() << other_limb_bits;
+        let borrows =
+            (0..NUM_LIMBS).fold(BigUint::zero(), |acc, _| (acc << NUM_LIMB_BITS) + &borrow);
+        let multiple = (&borrows + &self.modulus - 1u64) / &self.modulus * &self.modulus;
+        let offsets = Self::split_into_limbs(&(multiple - borrows)).map(|limb| limb + &borrow);
+
+        let max_limb_values: [BigUint; NUM_LIMBS] =
+            std::array::from_fn(|i| &self.max_limb_values[i] + &offsets[i]);
+        if max_limb_values
+            .iter()
+            .any(|max| max.bits() > MAX_UNREDUCED_LIMB_BITS)
+        {
+            let lhs = self.reduce(builder, driver)?;
+            let rhs = other.reduce(builder, driver)?;
+            return lhs.sub(&rhs, builder, driver);
+        }
+
+        let mut limbs: [FieldCT<F>; NUM_LIMBS] = Default::default();
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let offset = FieldCT::from_field(F::from(offsets[i].to_owned()));
+            *limb =
+                self.limbs[i]
+                    .add(&offset, builder, driver)
+                    .sub(&other.limbs[i], builder, driver);
+        }
+        Ok(Self {
+            limbs,
+            max_limb_values,
+            modulus: self.modulus.to_owned(),
+        })
+    }
+
+    pub(crate) fn mul<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        other: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        self.madd(other, &[], builder, driver)
+    }
+
+    pub(crate) fn mul_by_constant<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        constant: &BigUint,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        let constant = Self::from_constant(constant, &self.modulus);
+        if constant.constant_value().is_zero() {
+            return Ok(constant);
+        }
+        self.madd(&constant, &[], builder, driver)
+    }
+
+    /// Reduces the element such that the limbs have at most NUM_LIMB_BITS bits and the value is smaller than 2^ceil(log2(p)).
+    pub(crate) fn reduce<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        if self.is_reduced() {
+            return Ok(self.to_owned());
+        }
+        let one = Self::from_constant(&BigUint::one(), &self.modulus);
+        self.madd(&one, &[], builder, driver)
+    }
+
+    /// Computes (self * other + sum(to_add)) mod p. The result is reduced.
+    pub(crate) fn madd<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        other: &Self,
+        to_add: &[Self],
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        let modulus = &self.modulus;
+        if self.is_constant() && other.is_constant() && to_add.iter().all(Self::is_constant) {
+            let value = self.constant_value() * other.constant_value()
+                + to_add.iter().map(Self::constant_value).sum::<BigUint>();
+            return Ok(Self::from_constant(&value, modulus));
+        }
+
+        let mut a = self.to_owned();
+        let mut b = other.to_owned();
+        if a.exceeds_limb_bound() {
+            a = a.reduce(builder, driver)?;
+        }
+        if b.exceeds_limb_bound() {
+            b = b.reduce(builder, driver)?;
+        }
+        let mut summands = Vec::with_capacity(to_add.len());
+        for summand in to_add {
+            summands.push(if summand.exceeds_limb_bound() {
+                summand.reduce(builder, driver)?
+            } else {
+                summand.to_owned()
+            });
+        }
+        let max_sum = summands.iter().map(Self::max_value).sum::<BigUint>();
+
+        // The identity is checked modulo 2^272 and modulo the native modulus, hence it must not wrap around their product
+        let crt_modulus = (BigUint::one() << NUM_BINARY_BASIS_BITS) * BigUint::from(F::MODULUS);
+        while a.max_value() * b.max_value() + &max_sum + modulus >= crt_modulus {
+            assert!(
+                !(a.is_reduced() && b.is_reduced()),
+                "bigfield: the modulus is too large"
+            );
+            if a.max_value() >= b.max_value() {
+                a = a.reduce(builder, driver)?;
+            } else {
+                b = b.reduce(builder, driver)?;
+            }
+        }
+
+        let a_values = a.limb_values(builder, driver);
+        let b_values = b.limb_values(builder, driver);
+        let mut to_add_values = Vec::with_capacity(summands.len() * NUM_LIMBS);
+        for summand in summands.iter() {
+            to_add_values.extend(summand.limb_values(builder, driver));
+        }
+        let input_limb_bits = summands
+            .iter()
+            .chain([&a, &b])
+            .map(Self::max_limb_bits)
+            .max()
+            .expect("there are inputs");
+        let (quotient, remainder) = driver.non_native_mul_add_div_rem(
+            &a_values,
+            &b_values,
+            &to_add_values,
+            &[],
+            modulus,
+            NUM_LIMB_BITS,
+            input_limb_bits,
+        )?;
+
+        let max_quotient = (a.max_value() * b.max_value() + max_sum) / modulus;
+        let quotient =
+            Self::from_witness_limbs(quotient, max_quotient.bits(), modulus, builder, driver)?;
+        let remainder =
+            Self::from_witness_limbs(remainder, modulus.bits(), modulus, builder, driver)?;
+        Self::evaluate_multiply_add(&a, &b, &summands, &quotient, &remainder, builder, driver)?;
+        Ok(remainder)
+    }
+
+    /// Computes self / other mod p by constraining other * result = q * p + self. The result is reduced. The division by zero makes the circuit unsatisfiable, thus this also asserts that other is not zero.
+    pub(crate) fn div<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        other: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        let modulus = &self.modulus;
+        if self.is_constant() && other.is_constant() {
+            let denominator = other.constant_value() % modulus;
+            assert!(!denominator.is_zero(), "bigfield: division by zero");
+            let inverse = denominator.modpow(&(modulus - 2u64), modulus);
+            return Ok(Self::from_constant(
+                &(self.constant_value() * inverse),
+                modulus,
+            ));
+        }
+
+        // The numerator serves as remainder, thus it has to be reduced
+        let numerator = self.reduce(builder, driver)?;
+        let denominator = other.reduce(builder, driver)?;
+        let numerator_values = numerator.limb_values(builder, driver);
+        let denominator_values = denominator.limb_values(builder, driver);
+
+        let inverse = driver.non_native_inverse(
+            &denominator_values,
+            modulus,
+            NUM_LIMB_BITS,
+            NUM_LIMB_BITS,
+        )?;
+        let (_, result) = driver.non_native_mul_add_div_rem(
+            &numerator_values,
+            &inverse,
+            &[],
+            &[],
+            modulus,
+            NUM_LIMB_BITS,
+            NUM_LIMB_BITS,
+        )?;
+        let result = Self::from_witness_limbs(result, modulus.bits(), modulus, builder, driver)?;
+
+        let result_values = result.limb_values(builder, driver);
+        let (quotient, _) = driver.non_native_mul_add_div_rem(
+            &denominator_values,
+            &result_values,
+            &[],
+            &numerator_values,
+            modulus,
+            NUM_LIMB_BITS,
+            NUM_LIMB_BITS,
+        )?;
+        let max_quotient = denominator.max_value() * result.max_value() / modulus;
+        let quotient =
+            Self::from_witness_limbs(quotient, max_quotient.bits(), modulus, builder, driver)?;
+        Self::evaluate_multiply_add(
+            &denominator,
+            &result,
+            &[],
+            &quotient,
+            &numerator,
+            builder,
+            driver,
+        )?;
+        Ok(result)
+    }
+
+    /// Constrains a * b + sum(to_add) = q * p + r. The identity is checked modulo 2^272 with the non-native field gates and modulo the native modulus with standard gates, which implies that it holds over the integers.
+    fn evaluate_multiply_add<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        a: &Self,
+        b: &Self,
+        to_add: &[Self],
+        quotient: &Self,
+        remainder: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let modulus = &a.modulus;
+        let neg_modulus = (BigUint::one() << NUM_BINARY_BASIS_BITS) - modulus;
+        let neg_modulus_limbs = Self::split_into_limbs(&neg_modulus);
+
+        // The summands are moved to the remainder, i.e., we check a * b + q * (2^272 - p) - (r - sum(to_add)) = 0 mod 2^272
+        let mut remainder_limbs = remainder.limbs.to_owned();
+        let mut max_summands: [BigUint; NUM_LIMBS] = Default::default();
+        for summand in to_add {
+            for i in 0..NUM_LIMBS {
+                remainder_limbs[i] = remainder_limbs[i].sub(&summand.limbs[i], builder, driver);
+                max_summands[i] += &summand.max_limb_values[i];
+            }
+        }
+
+        let witnesses = NonNativeFieldWitnesses {
+            a: Self::limb_witness_indices(&a.limbs, builder, driver),
+            b: Self::limb_witness_indices(&b.limbs, builder, driver),
+            q: Self::limb_witness_indices(&quotient.limbs, builder, driver),
+            r: Self::limb_witness_indices(&remainder_limbs, builder, driver),
+            neg_modulus: neg_modulus_limbs
+                .each_ref()
+                .map(|limb| F::from(limb.to_owned())),
+        };
+        let [lo_1, hi_3] = builder.evaluate_non_native_field_multiplication(&witnesses, driver)?;
+
+        // Range constrain the carries. Since the limbs of the remainder are range constrained, the carries are non-negative.
+        let am = &a.max_limb_values;
+        let bm = &b.max_limb_values;
+        let qm = &quotient.max_limb_values;
+        let n = &neg_modulus_limbs;
+        let shift = BigUint::one() << NUM_LIMB_BITS;
+        let max_lo = &am[0] * &bm[0]
+            + (&am[1] * &bm[0] + &am[0] * &bm[1]) * &shift
+            + &qm[0] * &n[0]
+            + (&qm[1] * &n[0] + &qm[0] * &n[1]) * &shift
+            + &max_summands[0]
+            + &max_summands[1] * &shift;
+        let max_lo_carry = max_lo >> (2 * NUM_LIMB_BITS);
+        let max_hi = &am[2] * &bm[0]
+            + &am[0] * &bm[2]
+            + (&am[0] * &bm[3] + &am[3] * &bm[0]) * &shift
+            + &am[1] * &bm[1]
+            + (&am[1] * &bm[2] + &am[2] * &bm[1]) * &shift
+            + &max_lo_carry
+            + &qm[2] * &n[0]
+            + (&qm[3] * &n[0] + &qm[2] * &n[1]) * &shift
+            + (&qm[0] * &n[3] + &qm[1] * &n[2]) * &shift
+            + &qm[0] * &n[2]
+            + &qm[1] * &n[1]
+            + &max_summands[2]
+            + &max_summands[3] * &shift;
+        let max_hi_carry = max_hi >> (2 * NUM_LIMB_BITS);
+        FieldCT::from_witness_index(lo_1).create_range_constraint(
+            max_lo_carry.bits() as usize,
+            builder,
+            driver,
+        )?;
+        FieldCT::from_witness_index(hi_3).create_range_constraint(
+            max_hi_carry.bits() as usize,
+            builder,
+            driver,
+        )?;
+
+        // Check the identity modulo the native modulus
+        let a_prime = Self::prime_basis_limb(&a.limbs, builder, driver)?;
+        let b_prime = Self::prime_basis_limb(&b.limbs, builder, driver)?;
+        let q_prime = Self::prime_basis_limb(&quotient.limbs, builder, driver)?;
+        let r_prime = Self::prime_basis_limb(&remainder_limbs, builder, driver)?;
+        let lhs = a_prime.multiply(&b_prime, builder, driver)?;
+        let rhs = scale(&q_prime, F::from(modulus.to_owned()), builder, driver)?
+            .add(&r_prime, builder, driver);
+        lhs.assert_equal(&rhs, builder, driver);
+        Ok(())
+    }
+
+    /// Constrains self = other mod p.
+    pub(crate) fn assert_equal<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        other: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        let difference = self.sub(other, builder, driver)?;
+        if difference.is_constant() {
+            assert!(
+                (difference.constant_value() % &self.modulus).is_zero(),
+                "bigfield: the constants are not equal"
+            );
+            return Ok(());
+        }
+        let difference = if difference.exceeds_limb_bound() {
+            difference.reduce(builder, driver)?
+        } else {
+            difference
+        };
+
+        // The difference is a multiple of the modulus, i.e., difference * 1 = q * p + 0
+        let modulus = &self.modulus;
+        let one = Self::from_constant(&BigUint::one(), modulus);
+        let zero = Self::from_constant(&BigUint::zero(), modulus);
+        let values = difference.limb_values(builder, driver);
+        let (quotient, _) = driver.non_native_mul_add_div_rem(
+            &values,
+            &one.limb_values(builder, driver),
+            &[],
+            &[],
+            modulus,
+            NUM_LIMB_BITS,
+            difference.max_limb_bits(),
+        )?;
+        let max_quotient = difference.max_value() / modulus;
+        let quotient =
+            Self::from_witness_limbs(quotient, max_quotient.bits(), modulus, builder, driver)?;
+        Self::evaluate_multiply_add(&difference, &one, &[], &quotient, &zero, builder, driver)
+    }
+
+    /// Constrains the value of the element to be smaller than the given upper limit, which implies that the element is fully reduced if the limit is the modulus.
+    pub(crate) fn assert_less_than<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        upper_limit: &BigUint,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        if self.is_constant() {
+            assert!(
+                &self.constant_value() < upper_limit,
+                "bigfield: the constant exceeds the upper limit"
+            );
+            return Ok(());
+        }
+        let reduced = self.reduce(builder, driver)?;
+
+        // Compute upper_limit - 1 - self with borrows between the limbs, all resulting limbs have to be non-negative
+        let upper_bound = Self::split_into_limbs(&(upper_limit - 1u64));
+        let shift = F::from(BigUint::one() << NUM_LIMB_BITS);
+        let mut borrow = FieldCT::default();
+        for (i, limb) in reduced.limbs.iter().enumerate() {
+            let upper = FieldCT::from_field(F::from(upper_bound[i].to_owned()));
+            let mut result = upper
+                .sub(limb, builder, driver)
+                .sub(&borrow, builder, driver);
+            if i + 1 < NUM_LIMBS {
+                // next_borrow = (upper < limb + borrow)
+                let limb_value = limb.get_value(builder, driver);
+                let borrow_value = borrow.get_value(builder, driver);
+                let subtrahend = driver.add(limb_value, borrow_value);
+                let next_borrow = driver.lt(upper.get_value(builder, driver), subtrahend)?;
+                let next_borrow_idx = builder.add_variable(next_borrow);
+                builder.create_bool_gate(next_borrow_idx);
+                borrow = FieldCT::from_witness_index(next_borrow_idx);
+                result = result.add(&scale(&borrow, shift, builder, driver)?, builder, driver);
+                result.create_range_constraint(NUM_LIMB_BITS, builder, driver)?;
+            } else {
+                result.create_range_constraint(upper_bound[i].bits() as usize, builder, driver)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a boolean indicating whether the limbs of both elements are equal. The elements have to be fully reduced (see assert_less_than) for this to be equivalent to equality modulo p.
+    pub(crate) fn is_equal<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        other: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<FieldCT<F>> {
+        let lhs = self.reduce(builder, driver)?;
+        let rhs = other.reduce(builder, driver)?;
+
+        // Two limbs combined still fit into the native field, thus we compare both halves
+        let shift = F::from(BigUint::one() << NUM_LIMB_BITS);
+        let mut result = FieldCT::from_field(F::one());
+        for i in [0, 2] {
+            let lhs_half = scale(&lhs.limbs[i + 1], shift, builder, driver)?.add(
+                &lhs.limbs[i],
+                builder,
+                driver,
+            );
+            let rhs_half = scale(&rhs.limbs[i + 1], shift, builder, driver)?.add(
+                &rhs.limbs[i],
+                builder,
+                driver,
+            );
+            let difference = lhs_half.sub(&rhs_half, builder, driver);
+            let is_zero = difference.is_zero(builder, driver)?;
+            result = result.multiply(&is_zero, builder, driver)?;
+        }
+        Ok(result)
+    }
+
+    /// Decomposes the element into NUM_BYTES little-endian bytes, which get range constrained to 8 bits. The element gets fully reduced first, such that the bytes are the canonical representation.
+    pub(crate) fn to_le_bytes<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Vec<FieldCT<F>>> {
+        assert!(self.modulus.bits() as usize <= NUM_BYTES * 8);
+        if self.is_constant() {
+            let mut bytes = (self.constant_value() % &self.modulus).to_bytes_le();
+            bytes.resize(NUM_BYTES, 0);
+            return Ok(bytes
+                .into_iter()
+                .map(|byte| FieldCT::from_field(F::from(byte)))
+                .collect());
+        }
+        let reduced = self.reduce(builder, driver)?;
+        reduced.assert_less_than(&self.modulus, builder, driver)?;
+
+        // Two limbs combined still fit into the native field, thus we decompose both halves separately. The lower half consists of exactly 17 bytes.
+        let shift = F::from(BigUint::one() << NUM_LIMB_BITS);
+        let lo_bytes = 2 * NUM_LIMB_BITS / 8;
+        let mut bytes = Vec::with_capacity(NUM_BYTES);
+        for (i, num_bytes) in [(0, lo_bytes), (2, NUM_BYTES - lo_bytes)] {
+            let half = scale(&reduced.limbs[i + 1], shift, builder, driver)?.add(
+                &reduced.limbs[i],
+                builder,
+                driver,
+            );
+            let value = half.get_value(builder, driver);
+            let mut terms = Vec::with_capacity(num_bytes);
+            for (j, byte) in driver
+                .decompose(value, num_bytes * 8, 8)?
+                .into_iter()
+                .enumerate()
+            {
+                let byte = FieldCT::from_witness(byte, builder);
+                byte.create_range_constraint(8, builder, driver)?;
+                terms.push(scale(
+                    &byte,
+                    F::from(BigUint::one() << (8 * j)),
+                    builder,
+                    driver,
+                )?);
+                bytes.push(byte);
+            }
+            accumulate(&terms, builder, driver).assert_equal(&half, builder, driver);
+        }
+        Ok(bytes)
+    }
+}
+
+/// Sums up the given terms using as few gates as possible.
+fn accumulate<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    terms: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> FieldCT<P::ScalarField> {
+    let mut result = FieldCT::default();
+    let mut chunks = terms.chunks_exact(2);
+    for chunk in chunks.by_ref() {
+        result = result.add_two(&chunk[0], &chunk[1], builder, driver);
+    }
+    for term in chunks.remainder() {
+        result = result.add(term, builder, driver);
+    }
+    result
+}
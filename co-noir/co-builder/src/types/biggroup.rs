@@ -0,0 +1,328 @@
+use super::{
+    bigfield::{BigField, NUM_LIMB_BITS},
+    types::FieldCT,
+};
+use crate::{builder::GenericUltraCircuitBuilder, HonkProofResult};
+use ark_ec::{pairing::Pairing, short_weierstrass::SWCurveConfig};
+use ark_ff::{One, PrimeField, Zero};
+use co_acvm::mpc::NoirWitnessExtensionProtocol;
+use num_bigint::BigUint;
+
+/// The number of native field elements representing a point in the transcript
+pub(crate) const NUM_POINT_FRS: usize = 4;
+
+/// A point in projective coordinates (X : Y : Z) over a non-native base field.
+#[derive(Clone)]
+pub(crate) struct ProjectivePoint<F: PrimeField> {
+    pub(crate) x: BigField<F>,
+    pub(crate) y: BigField<F>,
+    pub(crate) z: BigField<F>,
+}
+
+/// The parameters of a short Weierstrass curve y^2 = x^3 + ax + b with a non-native base field.
+pub(crate) struct CurveParams {
+    pub(crate) base_modulus: BigUint,
+    pub(crate) scalar_modulus: BigUint,
+    pub(crate) coeff_a: BigUint,
+    pub(crate) coeff_b: BigUint,
+    pub(crate) generator: (BigUint, BigUint),
+}
+
+impl CurveParams {
+    pub(crate) fn new<C: SWCurveConfig>() -> Self
+    where
+        C::BaseField: PrimeField,
+    {
+        Self {
+            base_modulus: C::BaseField::MODULUS.into(),
+            scalar_modulus: C::ScalarField::MODULUS.into(),
+            coeff_a: C::COEFF_A.into_bigint().into(),
+            coeff_b: C::COEFF_B.into_bigint().into(),
+            generator: (
+                C::GENERATOR.x.into_bigint().into(),
+                C::GENERATOR.y.into_bigint().into(),
+            ),
+        }
+    }
+}
+
+impl<F: PrimeField> ProjectivePoint<F> {
+    pub(crate) fn from_affine(x: BigField<F>, y: BigField<F>) -> Self {
+        let z = BigField::from_constant(&BigUint::one(), &x.modulus);
+        Self { x, y, z }
+    }
+
+    pub(crate) fn infinity(modulus: &BigUint) -> Self {
+        Self {
+            x: BigField::from_constant(&BigUint::zero(), modulus),
+            y: BigField::from_constant(&BigUint::one(), modulus),
+            z: BigField::from_constant(&BigUint::zero(), modulus),
+        }
+    }
+
+    /// Adds two points using the complete addition formulas for short Weierstrass curves (Algorithm 1 of Renes, Costello and Batina, "Complete addition formulas for prime order elliptic curves"). The formulas also cover doubling and the point at infinity.
+    pub(crate) fn add<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        other: &Self,
+        params: &CurveParams,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        let b3 = (&params.coeff_b * 3u64) % &params.base_modulus;
+        let a = &params.coeff_a;
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+
+        let t0 = x1.mul(x2, builder, driver)?;
+        let t1 = y1.mul(y2, builder, driver)?;
+        let t2 = z1.mul(z2, builder, driver)?;
+        let t3 = x1.add(y1, builder, driver)?;
+        let t4 = x2.add(y2, builder, driver)?;
+        let t3 = t3.mul(&t4, builder, driver)?;
+        let t4 = t0.add(&t1, builder, driver)?;
+        let t3 = t3.sub(&t4, builder, driver)?;
+        let t4 = x1.add(z1, builder, driver)?;
+        let t5 = x2.add(z2, builder, driver)?;
+        let t4 = t4.mul(&t5, builder, driver)?;
+        let t5 = t0.add(&t2, builder, driver)?;
+        let t4 = t4.sub(&t5, builder, driver)?;
+        let t5 = y1.add(z1, builder, driver)?;
+        let x3 = y2.add(z2, builder, driver)?;
+        let t5 = t5.mul(&x3, builder, driver)?;
+        let x3 = t1.add(&t2, builder, driver)?;
+        let t5 = t5.sub(&x3, builder, driver)?;
+        let z3 = t4.mul_by_constant(a, builder, driver)?;
+        let x3 = t2.mul_by_constant(&b3, builder, driver)?;
+        let z3 = x3.add(&z3, builder, driver)?;
+        let x3 = t1.sub(&z3, builder, driver)?;
+        let z3 = t1.add(&z3, builder, driver)?;
+        let y3 = x3.mul(&z3, builder, driver)?;
+        let t1 = t0.add(&t0, builder, driver)?;
+        let t1 = t1.add(&t0, builder, driver)?;
+        let t2 = t2.mul_by_constant(a, builder, driver)?;
+        let t4 = t4.mul_by_constant(&b3, builder, driver)?;
+        let t1 = t1.add(&t2, builder, driver)?;
+        let t2 = t0.sub(&t2, builder, driver)?;
+        let t2 = t2.mul_by_constant(a, builder, driver)?;
+        let t4 = t4.add(&t2, builder, driver)?;
+        let t0 = t1.mul(&t4, builder, driver)?;
+        let y3 = y3.add(&t0, builder, driver)?;
+        let t0 = t5.mul(&t4, builder, driver)?;
+        let x3 = t3.mul(&x3, builder, driver)?;
+        let x3 = x3.sub(&t0, builder, driver)?;
+        let t0 = t3.mul(&t1, builder, driver)?;
+        let z3 = t5.mul(&z3, builder, driver)?;
+        let z3 = z3.add(&t0, builder, driver)?;
+
+        Ok(Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        })
+    }
+
+    /// Composes a point from its representation in the transcript, i.e., the lower and upper halves of the affine coordinates (x_lo, x_hi, y_lo, y_hi). Following barretenberg, the point at infinity is encoded as (0, 0).
+    pub(crate) fn from_frs<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        frs: &[FieldCT<F>],
+        modulus: &BigUint,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        assert_eq!(frs.len(), NUM_POINT_FRS);
+        let x = BigField::from_lo_hi(&frs[0], &frs[1], modulus, builder, driver)?;
+        let y = BigField::from_lo_hi(&frs[2], &frs[3], modulus, builder, driver)?;
+
+        // The halves are range constrained, thus their sum is zero if and only if all of them are zero
+        let sum = frs[0]
+            .add_two(&frs[1], &frs[2], builder, driver)
+            .add(&frs[3], builder, driver);
+        let is_infinity = sum.is_zero(builder, driver)?;
+        let is_infinity = BigField {
+            limbs: [
+                is_infinity,
+                FieldCT::default(),
+                FieldCT::default(),
+                FieldCT::default(),
+            ],
+            max_limb_values: [
+                BigUint::one(),
+                BigUint::zero(),
+                BigUint::zero(),
+                BigUint::zero(),
+            ],
+            modulus: modulus.to_owned(),
+        };
+
+        // (0 : 1 : 0) represents the point at infinity
+        let one = BigField::from_constant(&BigUint::one(), modulus);
+        let y = y.add(&is_infinity, builder, driver)?;
+        let z = one.sub(&is_infinity, builder, driver)?;
+        Ok(Self { x, y, z })
+    }
+
+    pub(crate) fn neg<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        let zero = BigField::from_constant(&BigUint::zero(), &self.y.modulus);
+        Ok(Self {
+            x: self.x.to_owned(),
+            y: zero.sub(&self.y, builder, driver)?,
+            z: self.z.to_owned(),
+        })
+    }
+
+    /// Returns the fully reduced affine coordinates. Dividing by Z makes the circuit unsatisfiable for the point at infinity.
+    pub(crate) fn to_affine<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<(BigField<F>, BigField<F>)> {
+        let modulus = &self.x.modulus;
+        let one = BigField::from_constant(&BigUint::one(), modulus);
+        let z_inv = one.div(&self.z, builder, driver)?;
+        let x = self.x.mul(&z_inv, builder, driver)?;
+        let y = self.y.mul(&z_inv, builder, driver)?;
+        x.assert_less_than(modulus, builder, driver)?;
+        y.assert_less_than(modulus, builder, driver)?;
+        Ok((x, y))
+    }
+}
+
+/// Decomposes a reduced element into num_bits boolean witnesses, ordered from the least significant to the most significant one.
+pub(crate) fn to_bits<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &BigField<P::ScalarField>,
+    num_bits: usize,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let input = input.reduce(builder, driver)?;
+    let mut bits = Vec::with_capacity(num_bits);
+    for (i, limb) in input.limbs.iter().enumerate() {
+        let limb_bits = num_bits
+            .saturating_sub(i * NUM_LIMB_BITS)
+            .min(NUM_LIMB_BITS);
+        if limb_bits == 0 {
+            limb.assert_is_zero(builder);
+            continue;
+        }
+        bits.extend(limb.decompose_into_bits(limb_bits, builder, driver)?);
+    }
+    Ok(bits)
+}
+
+/// Selects one of the four points depending on the bits, i.e., returns points[b1 + 2 * b2].
+pub(crate) fn select_point<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    b1: &FieldCT<P::ScalarField>,
+    b2: &FieldCT<P::ScalarField>,
+    points: &[ProjectivePoint<P::ScalarField>; 4],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<ProjectivePoint<P::ScalarField>> {
+    let b1_b2 = b1.multiply(b2, builder, driver)?;
+    let mut select = |coordinates: [&BigField<P::ScalarField>; 4]| {
+        let mut result = coordinates[0].to_owned();
+        for (i, limb) in result.limbs.iter_mut().enumerate() {
+            // t0 + b1 * (t1 - t0) + b2 * (t2 - t0) + b1 * b2 * (t3 - t2 - t1 + t0)
+            let [t0, t1, t2, t3] = coordinates.map(|point| &point.limbs[i]);
+            let d1 = t1.sub(t0, builder, driver);
+            let d2 = t2.sub(t0, builder, driver);
+            let d3 = t3.sub(t2, builder, driver).sub(&d1, builder, driver);
+            let s1 = b1.multiply(&d1, builder, driver)?;
+            let s2 = b2.multiply(&d2, builder, driver)?;
+            let s3 = b1_b2.multiply(&d3, builder, driver)?;
+            *limb = s1
+                .add_two(&s2, &s3, builder, driver)
+                .add(t0, builder, driver);
+            result.max_limb_values[i] = coordinates
+                .iter()
+                .map(|point| &point.max_limb_values[i])
+                .max()
+                .expect("there are points")
+                .to_owned();
+        }
+        HonkProofResult::Ok(result)
+    };
+    Ok(ProjectivePoint {
+        x: select(points.each_ref().map(|point| &point.x))?,
+        y: select(points.each_ref().map(|point| &point.y))?,
+        z: select(points.each_ref().map(|point| &point.z))?,
+    })
+}
+
+/// Computes sum(scalars[i] * points[i]) with a joint double-and-add, where each pair of points uses a lookup into {O, P, Q, P + Q}.
+/// The scalars are native field elements, thus the scalar field of the curve has to be the native field.
+pub(crate) fn batch_mul<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    points: &[ProjectivePoint<P::ScalarField>],
+    scalars: &[FieldCT<P::ScalarField>],
+    params: &CurveParams,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<ProjectivePoint<P::ScalarField>> {
+    assert_eq!(points.len(), scalars.len());
+    assert_eq!(
+        params.scalar_modulus,
+        P::ScalarField::MODULUS.into(),
+        "the scalar field has to be the native field"
+    );
+    let fq = &params.base_modulus;
+    let num_bits = params.scalar_modulus.bits() as usize;
+
+    let mut bits = Vec::with_capacity(scalars.len());
+    for scalar in scalars {
+        if scalar.is_constant() {
+            let value: BigUint = scalar.additive_constant.into();
+            bits.push(
+                (0..num_bits)
+                    .map(|i| FieldCT::from_field(P::ScalarField::from(value.bit(i as u64))))
+                    .collect::<Vec<_>>(),
+            );
+        } else {
+            bits.push(scalar.decompose_into_bits(num_bits, builder, driver)?);
+        }
+    }
+
+    // An odd number of points gets padded with the point at infinity
+    let zero_bits = vec![FieldCT::default(); num_bits];
+    let mut tables = Vec::with_capacity(points.len().div_ceil(2));
+    let mut table_bits = Vec::with_capacity(points.len().div_ceil(2));
+    for (pair, pair_bits) in points.chunks(2).zip(bits.chunks(2)) {
+        let (other, other_bits) = match pair.get(1) {
+            Some(point) => (point.to_owned(), &pair_bits[1]),
+            None => (ProjectivePoint::infinity(fq), &zero_bits),
+        };
+        let sum = pair[0].add(&other, params, builder, driver)?;
+        tables.push([
+            ProjectivePoint::infinity(fq),
+            pair[0].to_owned(),
+            other,
+            sum,
+        ]);
+        table_bits.push((&pair_bits[0], other_bits));
+    }
+
+    let mut accumulator = ProjectivePoint::infinity(fq);
+    for i in (0..num_bits).rev() {
+        if i + 1 < num_bits {
+            accumulator = accumulator.add(&accumulator, params, builder, driver)?;
+        }
+        for (table, (b1, b2)) in tables.iter().zip(table_bits.iter()) {
+            let summand = select_point(&b1[i], &b2[i], table, builder, driver)?;
+            accumulator = accumulator.add(&summand, params, builder, driver)?;
+        }
+    }
+    Ok(accumulator)
+}
@@ -0,0 +1,276 @@
+use super::{
+    plookup::{MultiTableId, Plookup},
+    sha256::{scale, to_byte_array},
+    types::{ColumnIdx, FieldCT},
+};
+use crate::{builder::GenericUltraCircuitBuilder, HonkProofResult};
+use ark_ec::pairing::Pairing;
+use ark_ff::{PrimeField, Zero};
+use co_acvm::mpc::NoirWitnessExtensionProtocol;
+use mpc_core::gadgets::blake::{
+    BLAKE2S_PARAM, BLAKE2S_SIGMA, BLAKE3_CHUNK_BYTES, BLAKE3_CHUNK_END, BLAKE3_CHUNK_START,
+    BLAKE3_MSG_PERMUTATION, BLAKE3_ROOT, BLAKE3_ROUNDS, BLAKE_BLOCK_BYTES, BLAKE_G_INDICES,
+    BLAKE_IV,
+};
+use num_bigint::BigUint;
+
+type BlakeState<F> = [FieldCT<F>; 16];
+
+fn word<F: PrimeField>(value: u32) -> FieldCT<F> {
+    FieldCT::from_field(F::from(value))
+}
+
+/// Computes (a ^ b) >>> rotation on the lowest 32 bits of the inputs, which may each have up to 35 bits. The result is
+/// a 32-bit value.
+fn lookup_xor_rotate<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    a: &FieldCT<P::ScalarField>,
+    b: &FieldCT<P::ScalarField>,
+    rotation: u32,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    let id = match rotation {
+        0 | 12 => MultiTableId::BlakeXor,
+        16 => MultiTableId::BlakeXorRotate16,
+        8 => MultiTableId::BlakeXorRotate8,
+        7 => MultiTableId::BlakeXorRotate7,
+        _ => unreachable!("Unsupported blake rotation"),
+    };
+    let lookup = Plookup::get_lookup_accumulators_ct(builder, driver, id, a, b, true)?;
+    let xor = lookup[ColumnIdx::C3][0].to_owned();
+
+    match rotation {
+        0 => Ok(xor),
+        12 => {
+            // The accumulator at index 2 consists of the slices starting at bit 12, i.e., it is (a ^ b) >> 12. Hence,
+            // the rotation is ((a ^ b) - (hi << 12)) << 20 + hi.
+            let hi = lookup[ColumnIdx::C3][2].to_owned();
+            let scaled_hi = scale(&hi, P::ScalarField::from(1u64 << 12), builder, driver)?;
+            let lo = xor.sub(&scaled_hi, builder, driver);
+            let scaled_lo = scale(&lo, P::ScalarField::from(1u64 << 20), builder, driver)?;
+            Ok(scaled_lo.add(&hi, builder, driver))
+        }
+        // The rotation tables return the rotated value divided by 2^(32 - rotation)
+        _ => scale(
+            &xor,
+            P::ScalarField::from(1u64 << (32 - rotation)),
+            builder,
+            driver,
+        ),
+    }
+}
+
+/// Reduces a value of at most 35 bits modulo 2^32, constraining the overflow to be at most 3 bits. The result is not
+/// range constrained, since the XOR lookups only consider the lowest 32 bits of their inputs.
+fn normalize<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    value: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    if value.is_constant() {
+        let value: BigUint = value.additive_constant.into();
+        let normalized = value.iter_u32_digits().next().unwrap_or_default();
+        return Ok(word(normalized));
+    }
+
+    let raw_value = value.get_value(builder, driver);
+    let overflow = driver.decompose(raw_value, 35, 32)?.swap_remove(1);
+    let overflow = FieldCT::from_witness(overflow, builder);
+    overflow.create_range_constraint(3, builder, driver)?;
+
+    let scaled_overflow = scale(
+        &overflow,
+        -P::ScalarField::from(1u64 << 32),
+        builder,
+        driver,
+    )?;
+    Ok(value.add(&scaled_overflow, builder, driver))
+}
+
+/// The mixing function G on the state words at the given positions. The additions are not reduced modulo 2^32 until
+/// the end of the function, since the XOR lookups can handle inputs of up to 35 bits.
+fn g<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    state: &mut BlakeState<P::ScalarField>,
+    [a, b, c, d]: [usize; 4],
+    x: &FieldCT<P::ScalarField>,
+    y: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<()> {
+    let mut state_a = state[a].add_two(&state[b], x, builder, driver);
+    state[d] = lookup_xor_rotate(&state[d], &state_a, 16, builder, driver)?;
+    let mut state_c = state[c].add(&state[d], builder, driver);
+    state[b] = lookup_xor_rotate(&state[b], &state_c, 12, builder, driver)?;
+    state_a = state_a.add_two(&state[b], y, builder, driver);
+    state[d] = lookup_xor_rotate(&state[d], &state_a, 8, builder, driver)?;
+    state_c = state_c.add(&state[d], builder, driver);
+    state[b] = lookup_xor_rotate(&state[b], &state_c, 7, builder, driver)?;
+
+    state[a] = normalize(&state_a, builder, driver)?;
+    state[c] = normalize(&state_c, builder, driver)?;
+    Ok(())
+}
+
+/// Applies one round of G functions to the state, where the i-th G function mixes in the message words at positions
+/// schedule[2i] and schedule[2i + 1].
+fn round<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    state: &mut BlakeState<P::ScalarField>,
+    message: &[FieldCT<P::ScalarField>; 16],
+    schedule: &[usize; 16],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<()> {
+    for (indices, words) in BLAKE_G_INDICES.into_iter().zip(schedule.chunks_exact(2)) {
+        g(
+            state,
+            indices,
+            &message[words[0]],
+            &message[words[1]],
+            builder,
+            driver,
+        )?;
+    }
+    Ok(())
+}
+
+/// Composes a block of at most 64 bytes into 16 little-endian 32-bit words, padding it with zeros.
+fn compose_words<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    block: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<[FieldCT<P::ScalarField>; 16]> {
+    let mut words: [FieldCT<P::ScalarField>; 16] = Default::default();
+    for (word, bytes) in words.iter_mut().zip(block.chunks(4)) {
+        let mut result = FieldCT::from_field(P::ScalarField::zero());
+        for (i, byte) in bytes.iter().enumerate() {
+            let scaled = scale(byte, P::ScalarField::from(1u64 << (8 * i)), builder, driver)?;
+            result = result.add(&scaled, builder, driver);
+        }
+        *word = result;
+    }
+    Ok(words)
+}
+
+/// Decomposes the 32-bit words into their little-endian bytes.
+fn to_le_bytes<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    words: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let mut output = Vec::with_capacity(words.len() * 4);
+    for word in words {
+        let mut bytes = to_byte_array(word, 4, builder, driver)?;
+        bytes.reverse();
+        output.extend(bytes);
+    }
+    Ok(output)
+}
+
+/// Computes the Blake2s hash of the given bytes and returns the 32 bytes of the digest.
+pub(crate) fn blake2s<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let mut iv = BLAKE_IV;
+    iv[0] ^= BLAKE2S_PARAM;
+    let mut state: [FieldCT<P::ScalarField>; 8] = iv.map(word);
+
+    // The empty message is hashed as a single block of zeros
+    let num_blocks = input.len().div_ceil(BLAKE_BLOCK_BYTES).max(1);
+    for i in 0..num_blocks {
+        let end = ((i + 1) * BLAKE_BLOCK_BYTES).min(input.len());
+        let message = compose_words(&input[i * BLAKE_BLOCK_BYTES..end], builder, driver)?;
+
+        // The counter and the finalization flag are public, hence they are XORed into the constants
+        let counter = end as u64;
+        let mut constants = BLAKE_IV;
+        constants[4] ^= counter as u32;
+        constants[5] ^= (counter >> 32) as u32;
+        if i == num_blocks - 1 {
+            constants[6] ^= u32::MAX;
+        }
+        let mut v: BlakeState<P::ScalarField> = std::array::from_fn(|j| {
+            if j < 8 {
+                state[j].to_owned()
+            } else {
+                word(constants[j - 8])
+            }
+        });
+
+        for schedule in BLAKE2S_SIGMA.iter() {
+            round(&mut v, &message, schedule, builder, driver)?;
+        }
+
+        for (j, state) in state.iter_mut().enumerate() {
+            let tmp = lookup_xor_rotate(state, &v[j], 0, builder, driver)?;
+            *state = lookup_xor_rotate(&tmp, &v[j + 8], 0, builder, driver)?;
+        }
+    }
+
+    to_le_bytes(&state, builder, driver)
+}
+
+/// Computes the Blake3 hash of the given bytes and returns the 32 bytes of the digest. Only messages consisting of a
+/// single chunk, i.e., of at most 1024 bytes, are supported.
+pub(crate) fn blake3<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    if input.len() > BLAKE3_CHUNK_BYTES {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "Blake3 is only supported for messages of at most 1024 bytes",
+        )
+        .into());
+    }
+
+    let mut chaining_value: [FieldCT<P::ScalarField>; 8] = BLAKE_IV.map(word);
+
+    // The empty message is hashed as a single block of zeros
+    let num_blocks = input.len().div_ceil(BLAKE_BLOCK_BYTES).max(1);
+    for i in 0..num_blocks {
+        let start = i * BLAKE_BLOCK_BYTES;
+        let end = (start + BLAKE_BLOCK_BYTES).min(input.len());
+        let mut message = compose_words(&input[start..end], builder, driver)?;
+
+        let mut flags = 0;
+        if i == 0 {
+            flags |= BLAKE3_CHUNK_START;
+        }
+        if i == num_blocks - 1 {
+            flags |= BLAKE3_CHUNK_END | BLAKE3_ROOT;
+        }
+        // The single chunk has index 0, hence the counter is 0
+        let constants = [
+            BLAKE_IV[0],
+            BLAKE_IV[1],
+            BLAKE_IV[2],
+            BLAKE_IV[3],
+            0,
+            0,
+            (end - start) as u32,
+            flags,
+        ];
+        let mut v: BlakeState<P::ScalarField> = std::array::from_fn(|j| {
+            if j < 8 {
+                chaining_value[j].to_owned()
+            } else {
+                word(constants[j - 8])
+            }
+        });
+
+        // Instead of using a different schedule per round, Blake3 permutes the message words
+        for _ in 0..BLAKE3_ROUNDS {
+            round(&mut v, &message, &BLAKE2S_SIGMA[0], builder, driver)?;
+            message = BLAKE3_MSG_PERMUTATION.map(|j| message[j].to_owned());
+        }
+
+        for (j, chaining_value) in chaining_value.iter_mut().enumerate() {
+            *chaining_value = lookup_xor_rotate(&v[j], &v[j + 8], 0, builder, driver)?;
+        }
+    }
+
+    to_le_bytes(&chaining_value, builder, driver)
+}
@@ -0,0 +1,657 @@
+use super::{
+    plookup::{FixedBaseParams, Plookup},
+    types::{ColumnIdx, EccAddGate, EccDblGate, FieldCT},
+};
+use crate::{builder::GenericUltraCircuitBuilder, HonkProofResult};
+use ark_ec::pairing::Pairing;
+use ark_ff::{Field, One, PrimeField, Zero};
+use co_acvm::mpc::NoirWitnessExtensionProtocol;
+use mpc_core::gadgets::pedersen::{grumpkin_b, point_add, point_mul, AffinePoint};
+use num_bigint::BigUint;
+
+/// The number of bits of the low limb of a scalar of the embedded curve.
+const SCALAR_LO_BITS: usize = 128;
+/// The number of bits of the high limb of a scalar of the embedded curve.
+const SCALAR_HI_BITS: usize = 126;
+/// The number of scalar bits processed per round of the Straus algorithm. The tables thus hold 2^TABLE_BITS points.
+const TABLE_BITS: usize = 2;
+/// The domain separator from which the offset generators are derived.
+const OFFSET_GENERATOR_DOMAIN_SEPARATOR: &[u8] = b"cycle_group_offset_generator";
+
+/// Derives the index-th offset generator, a point whose discrete logarithm with respect to the inputs is unknown.
+/// Starting the Straus algorithm and its tables at the offset generators keeps all intermediate points away from the point at infinity and from each other.
+fn offset_generator<F: PrimeField>(index: usize) -> (F, F) {
+    let mut x = F::from_be_bytes_mod_order(OFFSET_GENERATOR_DOMAIN_SEPARATOR)
+        + F::from((index as u64) << 32);
+    loop {
+        if let Some(y) = (x.square() * x + grumpkin_b::<F>()).sqrt() {
+            return (x, y);
+        }
+        x += F::one();
+    }
+}
+
+/// Returns the index of a normalized witness holding the value of the field. Constants are added as constant variables.
+fn to_witness_index<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    field: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> u32 {
+    if field.is_constant() {
+        builder.put_constant_variable(field.additive_constant)
+    } else {
+        field.normalize(builder, driver).get_witness_index()
+    }
+}
+
+/// Constrains lo + hi * 2^SCALAR_LO_BITS to be smaller than the modulus.
+/// The limbs have to be range constrained to SCALAR_LO_BITS bits and to the remaining bits of the modulus already.
+pub(crate) fn validate_limbs_less_than<
+    P: Pairing,
+    T: NoirWitnessExtensionProtocol<P::ScalarField>,
+>(
+    lo: &FieldCT<P::ScalarField>,
+    hi: &FieldCT<P::ScalarField>,
+    modulus: BigUint,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<()> {
+    let max = modulus - 1u64;
+    let mask = (BigUint::one() << SCALAR_LO_BITS) - 1u64;
+    let max_hi = &max >> SCALAR_LO_BITS;
+    let hi_bits = max_hi.bits() as usize;
+    let max_lo = P::ScalarField::from(&max & &mask);
+    let max_hi = P::ScalarField::from(max_hi);
+
+    // The low limb borrows from the high limb if it exceeds the low limb of the maximum
+    let lo_value = lo.get_value(builder, driver);
+    let borrow = driver.lt(T::AcvmType::from(max_lo), lo_value)?;
+    let borrow = FieldCT::from_witness(borrow, builder);
+    builder.create_bool_gate(borrow.get_witness_index());
+
+    // max_lo - lo + borrow * 2^SCALAR_LO_BITS and max_hi - hi - borrow have to be positive
+    let shift = FieldCT::from_field(P::ScalarField::from(BigUint::one() << SCALAR_LO_BITS));
+    let shifted_borrow = borrow.multiply(&shift, builder, driver)?;
+    let lo_diff =
+        FieldCT::from_field(max_lo)
+            .sub(lo, builder, driver)
+            .add(&shifted_borrow, builder, driver);
+    let hi_diff = FieldCT::from_field(max_hi)
+        .sub(hi, builder, driver)
+        .sub(&borrow, builder, driver);
+    lo_diff.create_range_constraint(SCALAR_LO_BITS, builder, driver)?;
+    hi_diff.create_range_constraint(hi_bits, builder, driver)?;
+    Ok(())
+}
+
+/// Constrains lo + hi * 2^SCALAR_LO_BITS to be smaller than the order of the embedded curve, which is the modulus of the base field of the pairing.
+/// The limbs have to be range constrained to SCALAR_LO_BITS and SCALAR_HI_BITS bits already.
+fn validate_scalar_is_in_field<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    lo: &FieldCT<P::ScalarField>,
+    hi: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<()> {
+    validate_limbs_less_than(lo, hi, P::BaseField::MODULUS.into(), builder, driver)
+}
+
+/// Splits an element of the native field into a low limb of SCALAR_LO_BITS bits and a high limb, such that it can be used as scalar of the embedded curve.
+/// The composition of the limbs is constrained to be smaller than the native modulus, which makes the split unique. The limbs themselves are range constrained by batch_mul.
+pub(crate) fn split_native_field<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<(FieldCT<P::ScalarField>, FieldCT<P::ScalarField>)> {
+    if input.is_constant() {
+        let value = BigUint::from(input.additive_constant);
+        let mask = (BigUint::one() << SCALAR_LO_BITS) - 1u64;
+        return Ok((
+            FieldCT::from_field(P::ScalarField::from(&value & mask)),
+            FieldCT::from_field(P::ScalarField::from(value >> SCALAR_LO_BITS)),
+        ));
+    }
+
+    let value = input.get_value(builder, driver);
+    let num_bits = P::ScalarField::MODULUS_BIT_SIZE as usize;
+    let limbs = driver.decompose(value, num_bits, SCALAR_LO_BITS)?;
+    let lo = FieldCT::from_witness(limbs[0].to_owned(), builder);
+    let hi = FieldCT::from_witness(limbs[1].to_owned(), builder);
+
+    let shift = FieldCT::from_field(P::ScalarField::from(BigUint::one() << SCALAR_LO_BITS));
+    let shifted_hi = hi.multiply(&shift, builder, driver)?;
+    lo.add(&shifted_hi, builder, driver)
+        .assert_equal(input, builder, driver);
+    validate_limbs_less_than(&lo, &hi, P::ScalarField::MODULUS.into(), builder, driver)?;
+    Ok((lo, hi))
+}
+
+/// A point on the embedded curve (Grumpkin), whose coordinates are elements of the native field.
+#[derive(Clone)]
+pub(crate) struct CycleGroup<F: PrimeField> {
+    pub(crate) x: FieldCT<F>,
+    pub(crate) y: FieldCT<F>,
+    pub(crate) is_infinity: FieldCT<F>,
+}
+
+impl<F: PrimeField> CycleGroup<F> {
+    pub(crate) fn new(x: FieldCT<F>, y: FieldCT<F>, is_infinity: FieldCT<F>) -> Self {
+        Self { x, y, is_infinity }
+    }
+
+    pub(crate) fn from_native(point: AffinePoint<F>) -> Self {
+        match point {
+            Some((x, y)) => Self::new(
+                FieldCT::from_field(x),
+                FieldCT::from_field(y),
+                FieldCT::from_field(F::zero()),
+            ),
+            None => Self::new(
+                FieldCT::from_field(F::zero()),
+                FieldCT::from_field(F::zero()),
+                FieldCT::from_field(F::one()),
+            ),
+        }
+    }
+
+    fn is_constant(&self) -> bool {
+        self.x.is_constant() && self.y.is_constant() && self.is_infinity.is_constant()
+    }
+
+    /// Returns the value of a constant point.
+    fn native_value(&self) -> AffinePoint<F> {
+        debug_assert!(self.is_constant());
+        if self.is_infinity.additive_constant.is_zero() {
+            Some((self.x.additive_constant, self.y.additive_constant))
+        } else {
+            None
+        }
+    }
+
+    fn is_constant_infinity(&self) -> bool {
+        self.is_constant() && self.native_value().is_none()
+    }
+
+    fn neg(&self) -> Self {
+        let mut y = self.y.to_owned();
+        y.additive_constant = -y.additive_constant;
+        y.multiplicative_constant = -y.multiplicative_constant;
+        Self::new(self.x.to_owned(), y, self.is_infinity.to_owned())
+    }
+
+    /// Constrains the point to be on the curve unless it is the point at infinity. The infinity flag is constrained to be a boolean.
+    pub(crate) fn validate_is_on_curve<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        if self.is_constant() {
+            if let Some((x, y)) = self.native_value() {
+                assert_eq!(y.square(), x.square() * x + grumpkin_b::<F>());
+            }
+            return Ok(());
+        }
+        if !self.is_infinity.is_constant() {
+            let is_infinity = self.is_infinity.normalize(builder, driver);
+            builder.create_bool_gate(is_infinity.get_witness_index());
+        }
+
+        // (y^2 - x^3 - b) * (1 - is_infinity) = 0
+        let y_sqr = self.y.multiply(&self.y, builder, driver)?;
+        let x_sqr = self.x.multiply(&self.x, builder, driver)?;
+        let x_cube = x_sqr.multiply(&self.x, builder, driver)?;
+        let difference = y_sqr.sub(&x_cube, builder, driver).add(
+            &FieldCT::from_field(-grumpkin_b::<F>()),
+            builder,
+            driver,
+        );
+        let not_infinity = FieldCT::from_field(F::one()).sub(&self.is_infinity, builder, driver);
+        difference
+            .multiply(&not_infinity, builder, driver)?
+            .assert_is_zero(builder);
+        Ok(())
+    }
+
+    /// Computes the witnesses x3 = lambda^2 - x1 - x2 and y3 = lambda * (x1 - x3) - y1 of an addition or a doubling.
+    fn compute_result<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        lambda: T::AcvmType,
+        x1: T::AcvmType,
+        y1: T::AcvmType,
+        x2: T::AcvmType,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<(u32, u32)> {
+        let lambda_sqr = driver.mul(lambda.to_owned(), lambda.to_owned())?;
+        let x3 = driver.sub(lambda_sqr, x1.to_owned());
+        let x3 = driver.sub(x3, x2);
+        let x_diff = driver.sub(x1, x3.to_owned());
+        let y3 = driver.mul(lambda, x_diff)?;
+        let y3 = driver.sub(y3, y1);
+        Ok((builder.add_variable(x3), builder.add_variable(y3)))
+    }
+
+    /// Adds two points which are not the point at infinity with an elliptic curve addition gate. The x-coordinates are constrained to be distinct, which rules out doublings and the point at infinity as result.
+    fn unconditional_add<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        other: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        if self.is_constant() && other.is_constant() {
+            return Ok(Self::from_native(point_add(
+                self.native_value(),
+                other.native_value(),
+            )));
+        }
+        let x_delta = other.x.sub(&self.x, builder, driver);
+        x_delta.assert_is_not_zero(builder, driver)?;
+
+        let x1 = self.x.get_value(builder, driver);
+        let y1 = self.y.get_value(builder, driver);
+        let x2 = other.x.get_value(builder, driver);
+        let y2 = other.y.get_value(builder, driver);
+
+        // lambda = (y2 - y1) / (x2 - x1)
+        let numerator = driver.sub(y2, y1.to_owned());
+        let denominator = driver.sub(x2.to_owned(), x1.to_owned());
+        let lambda = driver.div(numerator, denominator)?;
+        let (x3, y3) = Self::compute_result(lambda, x1, y1, x2, builder, driver)?;
+
+        let gate = EccAddGate {
+            x1: to_witness_index(&self.x, builder, driver),
+            y1: to_witness_index(&self.y, builder, driver),
+            x2: to_witness_index(&other.x, builder, driver),
+            y2: to_witness_index(&other.y, builder, driver),
+            x3,
+            y3,
+            sign_coefficient: F::one(),
+        };
+        builder.create_ecc_add_gate(&gate);
+        Ok(Self::new(
+            FieldCT::from_witness_index(x3),
+            FieldCT::from_witness_index(y3),
+            FieldCT::from_field(F::zero()),
+        ))
+    }
+
+    /// Doubles a point which is not the point at infinity with an elliptic curve doubling gate. As the curve has no points of order two, the result is never the point at infinity.
+    fn dbl<P: Pairing<ScalarField = F>, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+        &self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        if self.is_constant() {
+            let value = self.native_value();
+            return Ok(Self::from_native(point_add(value, value)));
+        }
+        let x1 = self.x.get_value(builder, driver);
+        let y1 = self.y.get_value(builder, driver);
+
+        // lambda = 3 * x1^2 / (2 * y1)
+        let x1_sqr = driver.mul(x1.to_owned(), x1.to_owned())?;
+        let numerator = driver.acvm_mul_with_public(F::from(3u64), x1_sqr);
+        let denominator = driver.acvm_mul_with_public(F::from(2u64), y1.to_owned());
+        let lambda = driver.div(numerator, denominator)?;
+        let (x3, y3) = Self::compute_result(lambda, x1.to_owned(), y1, x1, builder, driver)?;
+
+        let gate = EccDblGate {
+            x1: to_witness_index(&self.x, builder, driver),
+            y1: to_witness_index(&self.y, builder, driver),
+            x3,
+            y3,
+        };
+        builder.create_ecc_dbl_gate(&gate);
+        Ok(Self::new(
+            FieldCT::from_witness_index(x3),
+            FieldCT::from_witness_index(y3),
+            FieldCT::from_field(F::zero()),
+        ))
+    }
+
+    /// Adds two points. In contrast to the unconditional addition, this covers doublings, inverse points and the point at infinity.
+    pub(crate) fn add<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        other: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        if self.is_constant() && other.is_constant() {
+            return Ok(Self::from_native(point_add(
+                self.native_value(),
+                other.native_value(),
+            )));
+        }
+        if self.is_constant_infinity() {
+            return Ok(other.to_owned());
+        }
+        if other.is_constant_infinity() {
+            return Ok(self.to_owned());
+        }
+
+        let one = FieldCT::from_field(F::one());
+        let x_diff = other.x.sub(&self.x, builder, driver);
+        let y_diff = other.y.sub(&self.y, builder, driver);
+        let x_equal = x_diff.is_zero(builder, driver)?;
+        let y_equal = y_diff.is_zero(builder, driver)?;
+        let both_infinity = self
+            .is_infinity
+            .multiply(&other.is_infinity, builder, driver)?;
+        let any_infinity = self
+            .is_infinity
+            .add(&other.is_infinity, builder, driver)
+            .sub(&both_infinity, builder, driver);
+        let not_infinity = one.sub(&any_infinity, builder, driver);
+
+        // lambda = 3 * x1^2 / (2 * y1) for doublings and (y2 - y1) / (x2 - x1) otherwise. The coordinates of the point at infinity are arbitrary, thus the numerator is set to 0 and the denominator to 1 in this case.
+        let x1_sqr = self.x.multiply(&self.x, builder, driver)?;
+        let x1_sqr_times_3 =
+            x1_sqr.multiply(&FieldCT::from_field(F::from(3u64)), builder, driver)?;
+        let y1_times_2 = self.y.add(&self.y, builder, driver);
+        let numerator =
+            FieldCT::conditional_assign(&x_equal, &x1_sqr_times_3, &y_diff, builder, driver)?
+                .multiply(&not_infinity, builder, driver)?;
+        let denominator =
+            FieldCT::conditional_assign(&x_equal, &y1_times_2, &x_diff, builder, driver)?;
+        let denominator =
+            FieldCT::conditional_assign(&any_infinity, &one, &denominator, builder, driver)?;
+        let numerator_value = numerator.get_value(builder, driver);
+        let denominator_value = denominator.get_value(builder, driver);
+        let lambda = driver.div(numerator_value, denominator_value)?;
+        let lambda = FieldCT::from_witness(lambda, builder);
+        lambda
+            .multiply(&denominator, builder, driver)?
+            .assert_equal(&numerator, builder, driver);
+
+        let lambda_sqr = lambda.multiply(&lambda, builder, driver)?;
+        let x3 = lambda_sqr
+            .sub(&self.x, builder, driver)
+            .sub(&other.x, builder, driver);
+        let x_diff = self.x.sub(&x3, builder, driver);
+        let y3 = lambda
+            .multiply(&x_diff, builder, driver)?
+            .sub(&self.y, builder, driver);
+
+        // The result is the point at infinity if both inputs are, or if the inputs are inverse to each other
+        let not_y_equal = one.sub(&y_equal, builder, driver);
+        let inverse_points = x_equal.multiply(&not_y_equal, builder, driver)?.multiply(
+            &not_infinity,
+            builder,
+            driver,
+        )?;
+        let is_infinity = both_infinity.add(&inverse_points, builder, driver);
+        let not_result_infinity = one.sub(&is_infinity, builder, driver);
+
+        let mut select = |lhs: &FieldCT<F>, rhs: &FieldCT<F>, result: &FieldCT<F>| {
+            let result =
+                FieldCT::conditional_assign(&other.is_infinity, lhs, result, builder, driver)?;
+            let result =
+                FieldCT::conditional_assign(&self.is_infinity, rhs, &result, builder, driver)?;
+            result.multiply(&not_result_infinity, builder, driver)
+        };
+        let x = select(&self.x, &other.x, &x3)?;
+        let y = select(&self.y, &other.y, &y3)?;
+        Ok(Self::new(x, y, is_infinity))
+    }
+
+    pub(crate) fn sub<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        other: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        self.add(&other.neg(), builder, driver)
+    }
+
+    /// Selects an entry of a table of four points depending on the bits, i.e., returns table[b1 + 2 * b2].
+    fn select_entry<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        b1: &FieldCT<F>,
+        b2: &FieldCT<F>,
+        table: &[Self],
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        let b1_b2 = b1.multiply(b2, builder, driver)?;
+        let mut select = |coordinates: [&FieldCT<F>; 4]| {
+            // t0 + b1 * (t1 - t0) + b2 * (t2 - t0) + b1 * b2 * (t3 - t2 - t1 + t0)
+            let [t0, t1, t2, t3] = coordinates;
+            let d1 = t1.sub(t0, builder, driver);
+            let d2 = t2.sub(t0, builder, driver);
+            let d3 = t3.sub(t2, builder, driver).sub(&d1, builder, driver);
+            let s1 = b1.multiply(&d1, builder, driver)?;
+            let s2 = b2.multiply(&d2, builder, driver)?;
+            let s3 = b1_b2.multiply(&d3, builder, driver)?;
+            HonkProofResult::Ok(
+                s1.add_two(&s2, &s3, builder, driver)
+                    .add(t0, builder, driver),
+            )
+        };
+        let x = select([&table[0].x, &table[1].x, &table[2].x, &table[3].x])?;
+        let y = select([&table[0].y, &table[1].y, &table[2].y, &table[3].y])?;
+        Ok(Self::new(x, y, FieldCT::from_field(F::zero())))
+    }
+
+    /// Computes sum_i scalars_i * points_i, where each scalar is given as a low limb of SCALAR_LO_BITS bits and a high limb of SCALAR_HI_BITS bits.
+    ///
+    /// Terms with constant points and scalars are computed natively. Terms with the generator points of the Pedersen commitment read the multiples of the point from the fixed base lookup tables, which also range constrain the limbs of the scalar.
+    /// All other terms are evaluated with the Straus algorithm, which processes TABLE_BITS bits of all scalars per round.
+    /// The multiples of each point are stored in a table that is shifted by an offset generator, so that the additions in the main loop are free of edge cases. The offsets are removed at the end.
+    /// As the scalars may be secret-shared, the table entries are selected with the bits of the scalars instead of a ROM lookup, which requires public indices.
+    pub(crate) fn batch_mul<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        points: &[Self],
+        scalars: &[(FieldCT<F>, FieldCT<F>)],
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        Self::batch_mul_internal(points, scalars, SCALAR_HI_BITS, builder, driver)
+    }
+
+    /// Computes sum_i scalars_i * points_i, where each scalar is a bitstring of 2 * SCALAR_LO_BITS bits given as two limbs of SCALAR_LO_BITS bits.
+    /// In contrast to batch_mul, the scalars are not required to be reduced modulo the order of the embedded curve.
+    pub(crate) fn batch_mul_bitstring<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        points: &[Self],
+        scalars: &[(FieldCT<F>, FieldCT<F>)],
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        Self::batch_mul_internal(points, scalars, SCALAR_LO_BITS, builder, driver)
+    }
+
+    fn batch_mul_internal<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        points: &[Self],
+        scalars: &[(FieldCT<F>, FieldCT<F>)],
+        hi_bits: usize,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        assert_eq!(points.len(), scalars.len());
+        // Only scalars of the embedded curve have to be reduced and fit into the fixed base lookup tables
+        let is_field_scalar = hi_bits == SCALAR_HI_BITS;
+        let one = FieldCT::from_field(F::one());
+        let (replacement_x, replacement_y) = offset_generator::<F>(0);
+
+        let scalar_modulus: BigUint = P::BaseField::MODULUS.into();
+        let mut constant_result = None;
+        let mut fixed_base_points = Vec::new();
+        let mut fixed_base_offset = None;
+        let mut tables = Vec::new();
+        let mut scalar_bits = Vec::new();
+        for (point, (lo, hi)) in points.iter().zip(scalars.iter()) {
+            if point.is_constant() && lo.is_constant() && hi.is_constant() {
+                let scalar = BigUint::from(lo.additive_constant)
+                    + (BigUint::from(hi.additive_constant) << SCALAR_LO_BITS);
+                assert!(!is_field_scalar || scalar < scalar_modulus);
+                constant_result =
+                    point_add(constant_result, point_mul(point.native_value(), &scalar));
+                continue;
+            }
+
+            let table_ids = if is_field_scalar && point.is_constant() {
+                point
+                    .native_value()
+                    .and_then(|point| FixedBaseParams::get_lookup_table_ids_for_point(&point))
+            } else {
+                None
+            };
+            if let Some(table_ids) = table_ids {
+                // Each lookup returns the points offset_j + slice_j * point * 2^(BITS_PER_TABLE * j) for the slices of the limb
+                for (id, limb) in table_ids.into_iter().zip([lo, hi]) {
+                    fixed_base_offset = point_add(
+                        fixed_base_offset,
+                        FixedBaseParams::get_generator_offset_for_table_id(id.to_owned()),
+                    );
+                    let lookup = Plookup::get_lookup_accumulators_ct(
+                        builder,
+                        driver,
+                        id,
+                        limb,
+                        &FieldCT::default(),
+                        false,
+                    )?;
+                    for (x, y) in lookup[ColumnIdx::C2]
+                        .iter()
+                        .zip(lookup[ColumnIdx::C3].iter())
+                    {
+                        fixed_base_points.push(Self::new(
+                            x.to_owned(),
+                            y.to_owned(),
+                            FieldCT::from_field(F::zero()),
+                        ));
+                    }
+                }
+                validate_scalar_is_in_field(lo, hi, builder, driver)?;
+                continue;
+            }
+            point.validate_is_on_curve(builder, driver)?;
+
+            // Points at infinity are replaced by a generator and their scalars by zero
+            let not_infinity = one.sub(&point.is_infinity, builder, driver);
+            let lo = lo.multiply(&not_infinity, builder, driver)?;
+            let hi = hi.multiply(&not_infinity, builder, driver)?;
+            let point = Self::new(
+                FieldCT::conditional_assign(
+                    &point.is_infinity,
+                    &FieldCT::from_field(replacement_x),
+                    &point.x,
+                    builder,
+                    driver,
+                )?,
+                FieldCT::conditional_assign(
+                    &point.is_infinity,
+                    &FieldCT::from_field(replacement_y),
+                    &point.y,
+                    builder,
+                    driver,
+                )?,
+                FieldCT::from_field(F::zero()),
+            );
+
+            let mut bits = lo.decompose_into_bits(SCALAR_LO_BITS, builder, driver)?;
+            bits.extend(hi.decompose_into_bits(hi_bits, builder, driver)?);
+            if is_field_scalar {
+                validate_scalar_is_in_field(&lo, &hi, builder, driver)?;
+            }
+
+            // table[i] = offset + i * point
+            let offset = Self::from_native(Some(offset_generator(tables.len() + 1)));
+            let mut table = vec![offset];
+            for i in 1..1 << TABLE_BITS {
+                let entry = table[i - 1].unconditional_add(&point, builder, driver)?;
+                table.push(entry);
+            }
+            tables.push(table);
+            scalar_bits.push(bits);
+        }
+
+        // The fixed base and the variable base results both contain offsets, hence they can be added without edge cases
+        let mut accumulator: Option<Self> = None;
+        let mut offset = None;
+        if let Some((first, rest)) = fixed_base_points.split_first() {
+            let mut fixed_base_accumulator = first.to_owned();
+            for point in rest {
+                fixed_base_accumulator =
+                    fixed_base_accumulator.unconditional_add(point, builder, driver)?;
+            }
+            accumulator = Some(fixed_base_accumulator);
+            offset = fixed_base_offset;
+        }
+        if !tables.is_empty() {
+            let (variable_base_accumulator, variable_base_offset) =
+                Self::straus(&tables, &scalar_bits, builder, driver)?;
+            accumulator = Some(match accumulator {
+                Some(accumulator) => {
+                    accumulator.unconditional_add(&variable_base_accumulator, builder, driver)?
+                }
+                None => variable_base_accumulator,
+            });
+            offset = point_add(offset, variable_base_offset);
+        }
+        let Some(accumulator) = accumulator else {
+            return Ok(Self::from_native(constant_result));
+        };
+
+        let result = accumulator.sub(&Self::from_native(offset), builder, driver)?;
+        result.add(&Self::from_native(constant_result), builder, driver)
+    }
+
+    /// Evaluates sum_i scalars_i * points_i with the Straus algorithm on the tables table_i[j] = offset_i + j * points_i. Returns the result shifted by an offset, and this offset.
+    fn straus<P: Pairing<ScalarField = F>, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+        tables: &[Vec<Self>],
+        scalar_bits: &[Vec<FieldCT<F>>],
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<(Self, AffinePoint<F>)> {
+        let num_rounds = scalar_bits[0].len() / TABLE_BITS;
+        let accumulator_offset = Some(offset_generator::<F>(0));
+        let mut accumulator = Self::from_native(accumulator_offset);
+        for round in (0..num_rounds).rev() {
+            if round != num_rounds - 1 {
+                for _ in 0..TABLE_BITS {
+                    accumulator = accumulator.dbl(builder, driver)?;
+                }
+            }
+            for (table, bits) in tables.iter().zip(scalar_bits.iter()) {
+                let b1 = &bits[TABLE_BITS * round];
+                let b2 = &bits[TABLE_BITS * round + 1];
+                let entry = Self::select_entry(b1, b2, table, builder, driver)?;
+                accumulator = accumulator.unconditional_add(&entry, builder, driver)?;
+            }
+        }
+
+        // The accumulator offset got doubled in all but the first round, while the table offsets were added in every round
+        let four = BigUint::from(4u64);
+        let accumulator_factor = four.pow(num_rounds as u32 - 1);
+        let table_factor = (four.pow(num_rounds as u32) - 1u64) / 3u64;
+        let mut offset = point_mul(accumulator_offset, &accumulator_factor);
+        for i in 0..tables.len() {
+            let table_offset = point_mul(Some(offset_generator(i + 1)), &table_factor);
+            offset = point_add(offset, table_offset);
+        }
+        Ok((accumulator, offset))
+    }
+}
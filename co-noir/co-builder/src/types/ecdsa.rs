@@ -0,0 +1,97 @@
+use super::{
+    bigfield::BigField,
+    biggroup::{select_point, to_bits, CurveParams, ProjectivePoint},
+    types::FieldCT,
+};
+use crate::{builder::GenericUltraCircuitBuilder, HonkProofResult};
+use ark_ec::{pairing::Pairing, short_weierstrass::SWCurveConfig};
+use ark_ff::{One, PrimeField};
+use co_acvm::mpc::NoirWitnessExtensionProtocol;
+use num_bigint::BigUint;
+
+/// Verifies an ECDSA signature over the curve C in-circuit and returns the result as a boolean.
+///
+/// The inputs are big-endian encoded bytes: the coordinates of the public key, the signature (r, s) and the hashed message, of which at most 32 bytes are used.
+/// Following barretenberg, the result indicates whether x(u1 * G + u2 * Q) equals r modulo the group order, while public keys not on the curve, r or s outside of [1, n) and high s values make the circuit unsatisfiable.
+pub(crate) fn verify_signature<
+    P: Pairing,
+    T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    C: SWCurveConfig,
+>(
+    public_key_x: &[FieldCT<P::ScalarField>],
+    public_key_y: &[FieldCT<P::ScalarField>],
+    signature: &[FieldCT<P::ScalarField>],
+    hashed_message: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>>
+where
+    C::BaseField: PrimeField,
+{
+    assert_eq!(signature.len(), 64);
+    let params = CurveParams::new::<C>();
+    let fq = &params.base_modulus;
+    let fr = &params.scalar_modulus;
+    let hashed_message = &hashed_message[..hashed_message.len().min(32)];
+
+    let x = BigField::from_be_bytes(public_key_x, fq, builder, driver)?;
+    let y = BigField::from_be_bytes(public_key_y, fq, builder, driver)?;
+    let r = BigField::from_be_bytes(&signature[..32], fr, builder, driver)?;
+    let s = BigField::from_be_bytes(&signature[32..], fr, builder, driver)?;
+    let z = BigField::from_be_bytes(hashed_message, fr, builder, driver)?;
+
+    // The public key has to be on the curve: y^2 = x^3 + ax + b
+    let lhs = y.mul(&y, builder, driver)?;
+    let ax_b = x.mul_by_constant(&params.coeff_a, builder, driver)?.add(
+        &BigField::from_constant(&params.coeff_b, fq),
+        builder,
+        driver,
+    )?;
+    let x_squared = x.mul(&x, builder, driver)?;
+    let rhs = x_squared.madd(&x, &[ax_b], builder, driver)?;
+    lhs.assert_equal(&rhs, builder, driver)?;
+
+    // The signature has to satisfy 0 < r < n and 0 < s <= n / 2, where dividing by r and s asserts they are not zero
+    r.assert_less_than(fr, builder, driver)?;
+    s.assert_less_than(&((fr + 1u64) >> 1), builder, driver)?;
+    let one = BigField::from_constant(&BigUint::one(), fr);
+    one.div(&r, builder, driver)?;
+    let u1 = z.div(&s, builder, driver)?;
+    let u2 = r.div(&s, builder, driver)?;
+
+    // Shamir's trick: double-and-add with a lookup into {O, G, Q, G + Q}
+    let num_bits = fr.bits() as usize;
+    let u1_bits = to_bits(&u1, num_bits, builder, driver)?;
+    let u2_bits = to_bits(&u2, num_bits, builder, driver)?;
+    let generator = ProjectivePoint::from_affine(
+        BigField::from_constant(&params.generator.0, fq),
+        BigField::from_constant(&params.generator.1, fq),
+    );
+    let public_key = ProjectivePoint::from_affine(x, y);
+    let sum = generator.add(&public_key, &params, builder, driver)?;
+    let table = [ProjectivePoint::infinity(fq), generator, public_key, sum];
+
+    let mut accumulator = ProjectivePoint::infinity(fq);
+    for (b1, b2) in u1_bits.iter().zip(u2_bits.iter()).rev() {
+        accumulator = accumulator.add(&accumulator, &params, builder, driver)?;
+        let summand = select_point(b1, b2, &table, builder, driver)?;
+        accumulator = accumulator.add(&summand, &params, builder, driver)?;
+    }
+
+    // Dividing by Z asserts that the result is not the point at infinity
+    let one = BigField::from_constant(&BigUint::one(), fq);
+    let z_inv = one.div(&accumulator.z, builder, driver)?;
+    let result_x = accumulator.x.mul(&z_inv, builder, driver)?;
+    result_x.assert_less_than(fq, builder, driver)?;
+
+    // Reduce the x-coordinate modulo the group order and compare it with r
+    let mut result_x_mod_r = result_x;
+    result_x_mod_r.modulus = fr.to_owned();
+    let result_x_mod_r = result_x_mod_r.mul(
+        &BigField::from_constant(&BigUint::one(), fr),
+        builder,
+        driver,
+    )?;
+    result_x_mod_r.assert_less_than(fr, builder, driver)?;
+    result_x_mod_r.is_equal(&r, builder, driver)
+}
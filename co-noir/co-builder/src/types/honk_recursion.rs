@@ -0,0 +1,1219 @@
+use super::{
+    bigfield::{BigField, NUM_LIMBS},
+    biggroup::{batch_mul, CurveParams, ProjectivePoint, NUM_POINT_FRS},
+    transcript::Transcript,
+    types::{FieldCT, AGGREGATION_OBJECT_SIZE},
+};
+use crate::{
+    builder::GenericUltraCircuitBuilder, polynomials::polynomial_types::PRECOMPUTED_ENTITIES_SIZE,
+    HonkProofError, HonkProofResult,
+};
+use ark_ec::{pairing::Pairing, short_weierstrass::SWCurveConfig};
+use ark_ff::{Field, One, PrimeField};
+use co_acvm::mpc::NoirWitnessExtensionProtocol;
+use mpc_core::gadgets::{pedersen::grumpkin_b, poseidon2::Poseidon2};
+use num_bigint::BigUint;
+
+/// The proofs are padded to this number of sumcheck rounds, such that the proof size does not leak the circuit size
+pub(crate) const CONST_PROOF_SIZE_LOG_N: usize = 28;
+const BATCHED_RELATION_PARTIAL_LENGTH: usize = 8;
+const NUM_SUBRELATIONS: usize = 26;
+const NUM_ALPHAS: usize = NUM_SUBRELATIONS - 1;
+/// The index of the lookup subrelation which is not multiplied by the gate separator
+const LINEARLY_DEPENDENT_SUBRELATION: usize = 5;
+
+pub(crate) const NUM_WITNESS_ENTITIES: usize = 8;
+const NUM_SHIFTED_TABLES: usize = 4;
+const NUM_SHIFTED_WITNESSES: usize = 5;
+const NUM_ALL_ENTITIES: usize =
+    PRECOMPUTED_ENTITIES_SIZE + NUM_WITNESS_ENTITIES + NUM_SHIFTED_TABLES + NUM_SHIFTED_WITNESSES;
+/// The number of scalars sent during sumcheck, i.e., the round univariates and the claimed evaluations
+pub(crate) const NUM_SUMCHECK_SCALARS: usize =
+    CONST_PROOF_SIZE_LOG_N * BATCHED_RELATION_PARTIAL_LENGTH + NUM_ALL_ENTITIES;
+
+pub(crate) const PROOF_PREAMBLE_SIZE: usize = 3;
+/// The length of a proof without its public inputs
+pub(crate) const HONK_PROOF_LENGTH: usize = PROOF_PREAMBLE_SIZE
+    + NUM_WITNESS_ENTITIES * NUM_POINT_FRS
+    + NUM_SUMCHECK_SCALARS
+    + (CONST_PROOF_SIZE_LOG_N - 1) * NUM_POINT_FRS
+    + CONST_PROOF_SIZE_LOG_N
+    + 2 * NUM_POINT_FRS;
+/// The length of a verification key, i.e., circuit size, number of public inputs, public inputs offset, the aggregation object and the precomputed commitments
+pub(crate) const HONK_VERIFICATION_KEY_LENGTH: usize =
+    4 + AGGREGATION_OBJECT_SIZE + PRECOMPUTED_ENTITIES_SIZE * NUM_POINT_FRS;
+
+// The indices of the precomputed entities
+const Q_M: usize = 0;
+const Q_C: usize = 1;
+const Q_L: usize = 2;
+const Q_R: usize = 3;
+const Q_O: usize = 4;
+const Q_4: usize = 5;
+const Q_ARITH: usize = 6;
+const Q_DELTA_RANGE: usize = 7;
+const Q_ELLIPTIC: usize = 8;
+const Q_AUX: usize = 9;
+const Q_LOOKUP: usize = 10;
+const Q_POSEIDON2_EXTERNAL: usize = 11;
+const Q_POSEIDON2_INTERNAL: usize = 12;
+const SIGMA_1: usize = 13;
+const ID_1: usize = 17;
+const TABLE_1: usize = 21;
+const LAGRANGE_FIRST: usize = 25;
+const LAGRANGE_LAST: usize = 26;
+
+// The indices of the witness entities, the shifted witnesses use the same order
+const W_L: usize = 0;
+const W_R: usize = 1;
+const W_O: usize = 2;
+const W_4: usize = 3;
+const Z_PERM: usize = 4;
+const LOOKUP_INVERSES: usize = 5;
+const LOOKUP_READ_COUNTS: usize = 6;
+const LOOKUP_READ_TAGS: usize = 7;
+
+struct VerificationKey<F: PrimeField> {
+    circuit_size: FieldCT<F>,
+    num_public_inputs: FieldCT<F>,
+    pub_inputs_offset: FieldCT<F>,
+    contains_recursive_proof: FieldCT<F>,
+    recursive_proof_public_input_indices: Vec<FieldCT<F>>,
+    commitments: Vec<ProjectivePoint<F>>,
+}
+
+impl<F: PrimeField> VerificationKey<F> {
+    fn from_frs<P: Pairing<ScalarField = F>, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+        key: &[FieldCT<F>],
+        modulus: &BigUint,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        if key.len() != HONK_VERIFICATION_KEY_LENGTH {
+            return Err(HonkProofError::InvalidKeyLength);
+        }
+        let (header, commitments) = key.split_at(4 + AGGREGATION_OBJECT_SIZE);
+        let commitments = commitments
+            .chunks_exact(NUM_POINT_FRS)
+            .map(|frs| ProjectivePoint::from_frs(frs, modulus, builder, driver))
+            .collect::<HonkProofResult<Vec<_>>>()?;
+        Ok(Self {
+            circuit_size: header[0].to_owned(),
+            num_public_inputs: header[1].to_owned(),
+            pub_inputs_offset: header[2].to_owned(),
+            contains_recursive_proof: header[3].to_owned(),
+            recursive_proof_public_input_indices: header[4..].to_vec(),
+            commitments,
+        })
+    }
+}
+
+struct ClaimedEvaluations<F: PrimeField> {
+    precomputed: Vec<FieldCT<F>>,
+    witness: Vec<FieldCT<F>>,
+    shifted_tables: Vec<FieldCT<F>>,
+    shifted_witness: Vec<FieldCT<F>>,
+}
+
+impl<F: PrimeField> ClaimedEvaluations<F> {
+    fn new(mut evaluations: Vec<FieldCT<F>>) -> Self {
+        assert_eq!(evaluations.len(), NUM_ALL_ENTITIES);
+        let shifted_witness = evaluations.split_off(NUM_ALL_ENTITIES - NUM_SHIFTED_WITNESSES);
+        let shifted_tables =
+            evaluations.split_off(PRECOMPUTED_ENTITIES_SIZE + NUM_WITNESS_ENTITIES);
+        let witness = evaluations.split_off(PRECOMPUTED_ENTITIES_SIZE);
+        Self {
+            precomputed: evaluations,
+            witness,
+            shifted_tables,
+            shifted_witness,
+        }
+    }
+}
+
+struct RelationParameters<F: PrimeField> {
+    eta: FieldCT<F>,
+    eta_two: FieldCT<F>,
+    eta_three: FieldCT<F>,
+    beta: FieldCT<F>,
+    gamma: FieldCT<F>,
+    public_input_delta: FieldCT<F>,
+}
+
+fn sum<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    terms: &[&FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> FieldCT<P::ScalarField> {
+    terms.iter().fold(FieldCT::default(), |acc, term| {
+        acc.add(term, builder, driver)
+    })
+}
+
+fn product<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    factors: &[&FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    let mut result = FieldCT::from_field(P::ScalarField::one());
+    for factor in factors {
+        result = result.multiply(factor, builder, driver)?;
+    }
+    Ok(result)
+}
+
+fn constant<F: PrimeField>(value: i64) -> FieldCT<F> {
+    let abs = F::from(value.unsigned_abs());
+    FieldCT::from_field(if value < 0 { -abs } else { abs })
+}
+
+/// Computes the indicators of the padded sumcheck rounds, i.e., the i-th entry is 1 if and only if i >= log2(circuit_size). This constrains the circuit size to be a power of two.
+fn padding_indicators<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    circuit_size: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let bits = circuit_size.decompose_into_bits(CONST_PROOF_SIZE_LOG_N + 1, builder, driver)?;
+    // The circuit has at least two rows and exactly one bit is set
+    bits[0].assert_is_zero(builder);
+    let num_set_bits = sum(&bits.iter().collect::<Vec<_>>(), builder, driver);
+    num_set_bits.assert_equal(&constant(1), builder, driver);
+
+    let mut indicators = Vec::with_capacity(CONST_PROOF_SIZE_LOG_N);
+    let mut indicator = FieldCT::default();
+    for bit in bits.iter().take(CONST_PROOF_SIZE_LOG_N) {
+        indicator = indicator.add(bit, builder, driver);
+        indicators.push(indicator.to_owned());
+    }
+    Ok(indicators)
+}
+
+fn compute_public_input_delta<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    public_inputs: &[FieldCT<P::ScalarField>],
+    beta: &FieldCT<P::ScalarField>,
+    gamma: &FieldCT<P::ScalarField>,
+    circuit_size: &FieldCT<P::ScalarField>,
+    offset: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    let mut numerator = constant(1);
+    let mut denominator = constant(1);
+
+    let numerator_shift = circuit_size.add(offset, builder, driver);
+    let numerator_shift = beta.multiply(&numerator_shift, builder, driver)?;
+    let mut numerator_acc = gamma.add(&numerator_shift, builder, driver);
+    let denominator_shift = offset.add(&constant(1), builder, driver);
+    let denominator_shift = beta.multiply(&denominator_shift, builder, driver)?;
+    let mut denominator_acc = gamma.sub(&denominator_shift, builder, driver);
+
+    for input in public_inputs {
+        let factor = numerator_acc.add(input, builder, driver);
+        numerator = numerator.multiply(&factor, builder, driver)?;
+        let factor = denominator_acc.add(input, builder, driver);
+        denominator = denominator.multiply(&factor, builder, driver)?;
+        numerator_acc = numerator_acc.add(beta, builder, driver);
+        denominator_acc = denominator_acc.sub(beta, builder, driver);
+    }
+    numerator.divide(&denominator, builder, driver)
+}
+
+/// Evaluates the polynomial given by its evaluations on {0, ..., n - 1} at the given point.
+fn evaluate_barycentric<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    evaluations: &[FieldCT<P::ScalarField>],
+    point: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    let n = evaluations.len();
+    let differences = (0..n)
+        .map(|j| point.sub(&constant(j as i64), builder, driver))
+        .collect::<Vec<_>>();
+    // prefix[i] = prod_{j < i} (point - j) and suffix[i] = prod_{j > i} (point - j)
+    let mut prefix = vec![constant(1); n];
+    for i in 1..n {
+        prefix[i] = prefix[i - 1].multiply(&differences[i - 1], builder, driver)?;
+    }
+    let mut suffix = vec![constant(1); n];
+    for i in (0..n - 1).rev() {
+        suffix[i] = suffix[i + 1].multiply(&differences[i + 1], builder, driver)?;
+    }
+
+    let mut result = FieldCT::default();
+    for (i, evaluation) in evaluations.iter().enumerate() {
+        let denominator = (0..n)
+            .filter(|&j| j != i)
+            .map(|j| P::ScalarField::from(i as i64 - j as i64))
+            .product::<P::ScalarField>();
+        let weight = denominator
+            .inverse()
+            .expect("the domain consists of distinct points");
+        let term = product(&[&prefix[i], &suffix[i], evaluation], builder, driver)?;
+        let term = term.multiply(&FieldCT::from_field(weight), builder, driver)?;
+        result = result.add(&term, builder, driver);
+    }
+    Ok(result)
+}
+
+fn arithmetic_relation<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &ClaimedEvaluations<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let (w_l, w_r, w_o, w_4) = (
+        &input.witness[W_L],
+        &input.witness[W_R],
+        &input.witness[W_O],
+        &input.witness[W_4],
+    );
+    let w_l_shift = &input.shifted_witness[W_L];
+    let w_4_shift = &input.shifted_witness[W_4];
+    let pre = &input.precomputed;
+    let q_arith = &pre[Q_ARITH];
+
+    let neg_half = -P::ScalarField::from(2u64)
+        .inverse()
+        .expect("two is invertible");
+    let q_arith_minus_1 = q_arith.sub(&constant(1), builder, driver);
+    let q_arith_minus_2 = q_arith.sub(&constant(2), builder, driver);
+    let q_arith_minus_3 = q_arith.sub(&constant(3), builder, driver);
+
+    let mul_term = product(&[&q_arith_minus_3, &pre[Q_M], w_r, w_l], builder, driver)?;
+    let mul_term = mul_term.multiply(&FieldCT::from_field(neg_half), builder, driver)?;
+    let linear_terms = [
+        pre[Q_L].multiply(w_l, builder, driver)?,
+        pre[Q_R].multiply(w_r, builder, driver)?,
+        pre[Q_O].multiply(w_o, builder, driver)?,
+        pre[Q_4].multiply(w_4, builder, driver)?,
+        q_arith_minus_1.multiply(w_4_shift, builder, driver)?,
+    ];
+    let mut identity = mul_term.add(&pre[Q_C], builder, driver);
+    for term in linear_terms.iter() {
+        identity = identity.add(term, builder, driver);
+    }
+    let r0 = identity.multiply(q_arith, builder, driver)?;
+
+    let tmp = sum(&[w_l, w_4, &pre[Q_M]], builder, driver).sub(w_l_shift, builder, driver);
+    let r1 = product(
+        &[&tmp, &q_arith_minus_2, &q_arith_minus_1, q_arith],
+        builder,
+        driver,
+    )?;
+    Ok(vec![r0, r1])
+}
+
+fn permutation_relation<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &ClaimedEvaluations<P::ScalarField>,
+    params: &RelationParameters<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let pre = &input.precomputed;
+    let z_perm = &input.witness[Z_PERM];
+    let z_perm_shift = &input.shifted_witness[Z_PERM];
+
+    // prod_i (w_i + beta * id_i + gamma) and prod_i (w_i + beta * sigma_i + gamma)
+    let mut numerator = constant(1);
+    let mut denominator = constant(1);
+    for i in 0..4 {
+        let wire = &input.witness[W_L + i];
+        let id = params.beta.multiply(&pre[ID_1 + i], builder, driver)?;
+        let factor = sum(&[wire, &id, &params.gamma], builder, driver);
+        numerator = numerator.multiply(&factor, builder, driver)?;
+        let sigma = params.beta.multiply(&pre[SIGMA_1 + i], builder, driver)?;
+        let factor = sum(&[wire, &sigma, &params.gamma], builder, driver);
+        denominator = denominator.multiply(&factor, builder, driver)?;
+    }
+
+    let lhs = z_perm.add(&pre[LAGRANGE_FIRST], builder, driver);
+    let lhs = lhs.multiply(&numerator, builder, driver)?;
+    let rhs = pre[LAGRANGE_LAST].multiply(&params.public_input_delta, builder, driver)?;
+    let rhs = rhs.add(z_perm_shift, builder, driver);
+    let rhs = rhs.multiply(&denominator, builder, driver)?;
+    let r0 = lhs.sub(&rhs, builder, driver);
+    let r1 = pre[LAGRANGE_LAST].multiply(z_perm_shift, builder, driver)?;
+    Ok(vec![r0, r1])
+}
+
+fn lookup_relation<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &ClaimedEvaluations<P::ScalarField>,
+    params: &RelationParameters<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let pre = &input.precomputed;
+    let q_lookup = &pre[Q_LOOKUP];
+    let inverses = &input.witness[LOOKUP_INVERSES];
+    let read_counts = &input.witness[LOOKUP_READ_COUNTS];
+    let read_tags = &input.witness[LOOKUP_READ_TAGS];
+
+    let tags_times_lookup = read_tags.multiply(q_lookup, builder, driver)?;
+    let inverse_exists =
+        read_tags
+            .add(q_lookup, builder, driver)
+            .sub(&tags_times_lookup, builder, driver);
+
+    // The read term: w_1 + gamma + q_r * w_1_shift + (w_2 + q_m * w_2_shift) * eta + (w_3 + q_c * w_3_shift) * eta_2 + q_o * eta_3
+    let mut read_term = input.witness[W_L].add(&params.gamma, builder, driver);
+    let step = pre[Q_R].multiply(&input.shifted_witness[W_L], builder, driver)?;
+    read_term = read_term.add(&step, builder, driver);
+    for (wire, step_size, eta) in [(W_R, Q_M, &params.eta), (W_O, Q_C, &params.eta_two)] {
+        let step = pre[step_size].multiply(&input.shifted_witness[wire], builder, driver)?;
+        let entry = input.witness[wire].add(&step, builder, driver);
+        let entry = entry.multiply(eta, builder, driver)?;
+        read_term = read_term.add(&entry, builder, driver);
+    }
+    let table_index = pre[Q_O].multiply(&params.eta_three, builder, driver)?;
+    read_term = read_term.add(&table_index, builder, driver);
+
+    // The write term: t_1 + gamma + t_2 * eta + t_3 * eta_2 + t_4 * eta_3
+    let mut write_term = pre[TABLE_1].add(&params.gamma, builder, driver);
+    for (i, eta) in [&params.eta, &params.eta_two, &params.eta_three]
+        .into_iter()
+        .enumerate()
+    {
+        let entry = pre[TABLE_1 + i + 1].multiply(eta, builder, driver)?;
+        write_term = write_term.add(&entry, builder, driver);
+    }
+
+    let write_inverse = write_term.multiply(inverses, builder, driver)?;
+    let read_inverse = read_term.multiply(inverses, builder, driver)?;
+    let r0 =
+        read_term
+            .multiply(&write_inverse, builder, driver)?
+            .sub(&inverse_exists, builder, driver);
+    let r1 = q_lookup.multiply(&write_inverse, builder, driver)?.sub(
+        &read_counts.multiply(&read_inverse, builder, driver)?,
+        builder,
+        driver,
+    );
+    Ok(vec![r0, r1])
+}
+
+fn delta_range_constraint_relation<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &ClaimedEvaluations<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let w = &input.witness;
+    let deltas = [
+        w[W_R].sub(&w[W_L], builder, driver),
+        w[W_O].sub(&w[W_R], builder, driver),
+        w[W_4].sub(&w[W_O], builder, driver),
+        input.shifted_witness[W_L].sub(&w[W_4], builder, driver),
+    ];
+    let q_delta_range = &input.precomputed[Q_DELTA_RANGE];
+
+    let mut result = Vec::with_capacity(deltas.len());
+    for delta in deltas.iter() {
+        // delta * (delta - 1) * (delta - 2) * (delta - 3)
+        let minus_1 = delta.sub(&constant(1), builder, driver);
+        let minus_2 = delta.sub(&constant(2), builder, driver);
+        let minus_3 = delta.sub(&constant(3), builder, driver);
+        result.push(product(
+            &[delta, &minus_1, &minus_2, &minus_3, q_delta_range],
+            builder,
+            driver,
+        )?);
+    }
+    Ok(result)
+}
+
+fn elliptic_relation<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &ClaimedEvaluations<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let x_1 = &input.witness[W_R];
+    let y_1 = &input.witness[W_O];
+    let x_2 = &input.shifted_witness[W_L];
+    let y_2 = &input.shifted_witness[W_4];
+    let y_3 = &input.shifted_witness[W_O];
+    let x_3 = &input.shifted_witness[W_R];
+    let q_sign = &input.precomputed[Q_L];
+    let q_is_double = &input.precomputed[Q_M];
+    let q_elliptic = &input.precomputed[Q_ELLIPTIC];
+
+    // Point addition
+    let x_diff = x_2.sub(x_1, builder, driver);
+    let y2_sqr = y_2.multiply(y_2, builder, driver)?;
+    let y1_sqr = y_1.multiply(y_1, builder, driver)?;
+    let y1y2 = product(&[y_1, y_2, q_sign], builder, driver)?;
+    let x_sum = sum(&[x_3, x_2, x_1], builder, driver);
+    let x_add_identity = product(&[&x_sum, &x_diff, &x_diff], builder, driver)?
+        .sub(&y2_sqr, builder, driver)
+        .sub(&y1_sqr, builder, driver)
+        .add(&y1y2, builder, driver)
+        .add(&y1y2, builder, driver);
+
+    let y1_plus_y3 = y_1.add(y_3, builder, driver);
+    let y_diff = y_2
+        .multiply(q_sign, builder, driver)?
+        .sub(y_1, builder, driver);
+    let x3_minus_x1 = x_3.sub(x_1, builder, driver);
+    let y_add_identity = y1_plus_y3.multiply(&x_diff, builder, driver)?.add(
+        &x3_minus_x1.multiply(&y_diff, builder, driver)?,
+        builder,
+        driver,
+    );
+
+    // Point doubling, where x_1^4 = (y_1^2 - b) * x_1
+    let b = FieldCT::from_field(grumpkin_b::<P::ScalarField>());
+    let x1_pow_4 = y1_sqr
+        .sub(&b, builder, driver)
+        .multiply(x_1, builder, driver)?;
+    let x_double_sum = sum(&[x_3, x_1, x_1], builder, driver);
+    let y1_sqr_mul_4 = y1_sqr.multiply(&constant(4), builder, driver)?;
+    let x_double_identity = x_double_sum.multiply(&y1_sqr_mul_4, builder, driver)?.sub(
+        &x1_pow_4.multiply(&constant(9), builder, driver)?,
+        builder,
+        driver,
+    );
+
+    let x1_sqr_mul_3 = product(&[x_1, x_1, &constant(3)], builder, driver)?;
+    let x1_minus_x3 = x_1.sub(x_3, builder, driver);
+    let y1_mul_2 = y_1.multiply(&constant(2), builder, driver)?;
+    let y_double_identity = x1_sqr_mul_3.multiply(&x1_minus_x3, builder, driver)?.sub(
+        &y1_mul_2.multiply(&y1_plus_y3, builder, driver)?,
+        builder,
+        driver,
+    );
+
+    let q_elliptic_by_double = q_elliptic.multiply(q_is_double, builder, driver)?;
+    let q_elliptic_not_double = q_elliptic.sub(&q_elliptic_by_double, builder, driver);
+    let mut result = Vec::with_capacity(2);
+    for (add_identity, double_identity) in [
+        (x_add_identity, x_double_identity),
+        (y_add_identity, y_double_identity),
+    ] {
+        let add = add_identity.multiply(&q_elliptic_not_double, builder, driver)?;
+        let double = double_identity.multiply(&q_elliptic_by_double, builder, driver)?;
+        result.push(add.add(&double, builder, driver));
+    }
+    Ok(result)
+}
+
+fn auxiliary_relation<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &ClaimedEvaluations<P::ScalarField>,
+    params: &RelationParameters<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let (w_1, w_2, w_3, w_4) = (
+        &input.witness[W_L],
+        &input.witness[W_R],
+        &input.witness[W_O],
+        &input.witness[W_4],
+    );
+    let (w_1_shift, w_2_shift, w_3_shift, w_4_shift) = (
+        &input.shifted_witness[W_L],
+        &input.shifted_witness[W_R],
+        &input.shifted_witness[W_O],
+        &input.shifted_witness[W_4],
+    );
+    let pre = &input.precomputed;
+    let (q_1, q_2, q_3, q_4) = (&pre[Q_L], &pre[Q_R], &pre[Q_O], &pre[Q_4]);
+    let (q_m, q_c, q_arith, q_aux) = (&pre[Q_M], &pre[Q_C], &pre[Q_ARITH], &pre[Q_AUX]);
+    let limb_size = FieldCT::from_field(P::ScalarField::from(BigUint::one() << 68));
+    let sublimb_shift = constant(1 << 14);
+
+    // Non native field arithmetic gates
+    let limb_subproduct = sum(
+        &[
+            &w_1.multiply(w_2_shift, builder, driver)?,
+            &w_1_shift.multiply(w_2, builder, driver)?,
+        ],
+        builder,
+        driver,
+    );
+    let non_native_field_gate_2 = sum(
+        &[
+            &w_1.multiply(w_4, builder, driver)?,
+            &w_2.multiply(w_3, builder, driver)?,
+        ],
+        builder,
+        driver,
+    )
+    .sub(w_3_shift, builder, driver)
+    .multiply(&limb_size, builder, driver)?
+    .sub(w_4_shift, builder, driver)
+    .add(&limb_subproduct, builder, driver)
+    .multiply(q_4, builder, driver)?;
+
+    let limb_subproduct = limb_subproduct.multiply(&limb_size, builder, driver)?.add(
+        &w_1_shift.multiply(w_2_shift, builder, driver)?,
+        builder,
+        driver,
+    );
+    let non_native_field_gate_1 = limb_subproduct
+        .sub(&w_3.add(w_4, builder, driver), builder, driver)
+        .multiply(q_3, builder, driver)?;
+    let non_native_field_gate_3 = limb_subproduct
+        .add(w_4, builder, driver)
+        .sub(&w_3_shift.add(w_4_shift, builder, driver), builder, driver)
+        .multiply(q_m, builder, driver)?;
+    let non_native_field_identity = sum(
+        &[
+            &non_native_field_gate_1,
+            &non_native_field_gate_2,
+            &non_native_field_gate_3,
+        ],
+        builder,
+        driver,
+    )
+    .multiply(q_2, builder, driver)?;
+
+    // Limb accumulators, which accumulate the sublimbs in the order given by the wires
+    let mut limb_accumulator_1 = w_2_shift.multiply(&sublimb_shift, builder, driver)?;
+    for wire in [w_1_shift, w_3, w_2] {
+        limb_accumulator_1 = limb_accumulator_1.add(wire, builder, driver).multiply(
+            &sublimb_shift,
+            builder,
+            driver,
+        )?;
+    }
+    let limb_accumulator_1 = limb_accumulator_1
+        .add(w_1, builder, driver)
+        .sub(w_4, builder, driver)
+        .multiply(q_4, builder, driver)?;
+    let mut limb_accumulator_2 = w_3_shift.multiply(&sublimb_shift, builder, driver)?;
+    for wire in [w_2_shift, w_1_shift, w_4] {
+        limb_accumulator_2 = limb_accumulator_2.add(wire, builder, driver).multiply(
+            &sublimb_shift,
+            builder,
+            driver,
+        )?;
+    }
+    let limb_accumulator_2 = limb_accumulator_2
+        .add(w_3, builder, driver)
+        .sub(w_4_shift, builder, driver)
+        .multiply(q_m, builder, driver)?;
+    let limb_accumulator_identity = limb_accumulator_1
+        .add(&limb_accumulator_2, builder, driver)
+        .multiply(q_3, builder, driver)?;
+
+    // Memory record check: q_c + w_1 * eta + w_2 * eta_2 + w_3 * eta_3 - w_4
+    let partial_record_check = sum(
+        &[
+            &w_3.multiply(&params.eta_three, builder, driver)?,
+            &w_2.multiply(&params.eta_two, builder, driver)?,
+            &w_1.multiply(&params.eta, builder, driver)?,
+            q_c,
+        ],
+        builder,
+        driver,
+    );
+    let memory_record_check = partial_record_check.sub(w_4, builder, driver);
+
+    // ROM consistency check
+    let index_delta = w_1_shift.sub(w_1, builder, driver);
+    let record_delta = w_4_shift.sub(w_4, builder, driver);
+    let index_is_monotonically_increasing = index_delta
+        .multiply(&index_delta, builder, driver)?
+        .sub(&index_delta, builder, driver);
+    let index_delta_one = constant(1).sub(&index_delta, builder, driver);
+    let adjacent_values_match_if_adjacent_indices_match =
+        record_delta.multiply(&index_delta_one, builder, driver)?;
+    let q_one_by_two = q_1.multiply(q_2, builder, driver)?;
+    let q_one_by_two_by_aux = q_one_by_two.multiply(q_aux, builder, driver)?;
+    let r1 = adjacent_values_match_if_adjacent_indices_match.multiply(
+        &q_one_by_two_by_aux,
+        builder,
+        driver,
+    )?;
+    let r2 = index_is_monotonically_increasing.multiply(&q_one_by_two_by_aux, builder, driver)?;
+    let rom_consistency_check_identity =
+        q_one_by_two.multiply(&memory_record_check, builder, driver)?;
+
+    // RAM consistency check
+    let access_type = w_4.sub(&partial_record_check, builder, driver);
+    let access_check =
+        access_type
+            .multiply(&access_type, builder, driver)?
+            .sub(&access_type, builder, driver);
+    let next_gate_access_type = sum(
+        &[
+            &w_3_shift.multiply(&params.eta_three, builder, driver)?,
+            &w_2_shift.multiply(&params.eta_two, builder, driver)?,
+            &w_1_shift.multiply(&params.eta, builder, driver)?,
+        ],
+        builder,
+        driver,
+    );
+    let next_gate_access_type = w_4_shift.sub(&next_gate_access_type, builder, driver);
+    let value_delta = w_3_shift.sub(w_3, builder, driver);
+    let next_gate_is_read = constant(1).sub(&next_gate_access_type, builder, driver);
+    let adjacent_values_match_if_adjacent_indices_match_and_next_access_is_a_read_operation =
+        product(
+            &[&value_delta, &index_delta_one, &next_gate_is_read],
+            builder,
+            driver,
+        )?;
+    let next_gate_access_type_is_boolean = next_gate_access_type
+        .multiply(&next_gate_access_type, builder, driver)?
+        .sub(&next_gate_access_type, builder, driver);
+    let q_arith_by_aux = q_arith.multiply(q_aux, builder, driver)?;
+    let r3 = adjacent_values_match_if_adjacent_indices_match_and_next_access_is_a_read_operation
+        .multiply(&q_arith_by_aux, builder, driver)?;
+    let r4 = index_is_monotonically_increasing.multiply(&q_arith_by_aux, builder, driver)?;
+    let r5 = next_gate_access_type_is_boolean.multiply(&q_arith_by_aux, builder, driver)?;
+    let ram_consistency_check_identity = access_check.multiply(q_arith, builder, driver)?;
+
+    // RAM timestamp check
+    let timestamp_delta = w_2_shift.sub(w_2, builder, driver);
+    let ram_timestamp_check_identity = index_delta_one
+        .multiply(&timestamp_delta, builder, driver)?
+        .sub(w_3, builder, driver);
+
+    let memory_identity = sum(
+        &[
+            &rom_consistency_check_identity,
+            &product(&[&ram_timestamp_check_identity, q_4, q_1], builder, driver)?,
+            &product(&[&memory_record_check, q_m, q_1], builder, driver)?,
+            &ram_consistency_check_identity,
+        ],
+        builder,
+        driver,
+    );
+    let r0 = sum(
+        &[
+            &memory_identity,
+            &non_native_field_identity,
+            &limb_accumulator_identity,
+        ],
+        builder,
+        driver,
+    )
+    .multiply(q_aux, builder, driver)?;
+    Ok(vec![r0, r1, r2, r3, r4, r5])
+}
+
+fn pow_5<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    let square = input.multiply(input, builder, driver)?;
+    let quad = square.multiply(&square, builder, driver)?;
+    quad.multiply(input, builder, driver)
+}
+
+fn poseidon2_external_relation<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &ClaimedEvaluations<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let pre = &input.precomputed;
+    let mut u = Vec::with_capacity(4);
+    for (wire, selector) in [(W_L, Q_L), (W_R, Q_R), (W_O, Q_O), (W_4, Q_4)] {
+        let s = input.witness[wire].add(&pre[selector], builder, driver);
+        u.push(pow_5(&s, builder, driver)?);
+    }
+
+    // Multiplication with the external matrix
+    let two = constant(2);
+    let four = constant(4);
+    let t0 = u[0].add(&u[1], builder, driver);
+    let t1 = u[2].add(&u[3], builder, driver);
+    let t2 = u[1]
+        .multiply(&two, builder, driver)?
+        .add(&t1, builder, driver);
+    let t3 = u[3]
+        .multiply(&two, builder, driver)?
+        .add(&t0, builder, driver);
+    let v4 = t1
+        .multiply(&four, builder, driver)?
+        .add(&t3, builder, driver);
+    let v2 = t0
+        .multiply(&four, builder, driver)?
+        .add(&t2, builder, driver);
+    let v1 = t3.add(&v2, builder, driver);
+    let v3 = t2.add(&v4, builder, driver);
+
+    let q_pos_by_scaling = &pre[Q_POSEIDON2_EXTERNAL];
+    let mut result = Vec::with_capacity(4);
+    for (v, wire) in [(v1, W_L), (v2, W_R), (v3, W_O), (v4, W_4)] {
+        let tmp = v.sub(&input.shifted_witness[wire], builder, driver);
+        result.push(tmp.multiply(q_pos_by_scaling, builder, driver)?);
+    }
+    Ok(result)
+}
+
+fn poseidon2_internal_relation<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &ClaimedEvaluations<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let pre = &input.precomputed;
+    let s1 = input.witness[W_L].add(&pre[Q_L], builder, driver);
+    let u = [
+        pow_5(&s1, builder, driver)?,
+        input.witness[W_R].to_owned(),
+        input.witness[W_O].to_owned(),
+        input.witness[W_4].to_owned(),
+    ];
+    let u_sum = sum(&u.each_ref(), builder, driver);
+
+    // Multiplication with the internal matrix
+    let poseidon2 = Poseidon2::<P::ScalarField, 4, 5>::default();
+    let q_pos_by_scaling = &pre[Q_POSEIDON2_INTERNAL];
+    let mut result = Vec::with_capacity(4);
+    for (i, (u, diag)) in u
+        .iter()
+        .zip(poseidon2.params.mat_internal_diag_m_1.iter())
+        .enumerate()
+    {
+        let v = u
+            .multiply(&FieldCT::from_field(*diag), builder, driver)?
+            .add(&u_sum, builder, driver);
+        let tmp = v.sub(&input.shifted_witness[W_L + i], builder, driver);
+        result.push(tmp.multiply(q_pos_by_scaling, builder, driver)?);
+    }
+    Ok(result)
+}
+
+/// Evaluates the batched relations at the sumcheck challenge, where all subrelations except the linearly dependent one are multiplied by the evaluation of the gate separator polynomial.
+fn compute_full_relation_purported_value<
+    P: Pairing,
+    T: NoirWitnessExtensionProtocol<P::ScalarField>,
+>(
+    input: &ClaimedEvaluations<P::ScalarField>,
+    params: &RelationParameters<P::ScalarField>,
+    alphas: &[FieldCT<P::ScalarField>],
+    pow_partial_evaluation: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    let mut subrelations = Vec::with_capacity(NUM_SUBRELATIONS);
+    subrelations.extend(arithmetic_relation(input, builder, driver)?);
+    subrelations.extend(permutation_relation(input, params, builder, driver)?);
+    subrelations.extend(lookup_relation(input, params, builder, driver)?);
+    subrelations.extend(delta_range_constraint_relation(input, builder, driver)?);
+    subrelations.extend(elliptic_relation(input, builder, driver)?);
+    subrelations.extend(auxiliary_relation(input, params, builder, driver)?);
+    subrelations.extend(poseidon2_external_relation(input, builder, driver)?);
+    subrelations.extend(poseidon2_internal_relation(input, builder, driver)?);
+    assert_eq!(subrelations.len(), NUM_SUBRELATIONS);
+
+    let one = constant(1);
+    let mut linearly_independent = FieldCT::default();
+    let mut linearly_dependent = FieldCT::default();
+    for (i, (subrelation, alpha)) in subrelations
+        .iter()
+        .zip(std::iter::once(&one).chain(alphas))
+        .enumerate()
+    {
+        let scaled = subrelation.multiply(alpha, builder, driver)?;
+        if i == LINEARLY_DEPENDENT_SUBRELATION {
+            linearly_dependent = linearly_dependent.add(&scaled, builder, driver);
+        } else {
+            linearly_independent = linearly_independent.add(&scaled, builder, driver);
+        }
+    }
+    Ok(linearly_independent
+        .multiply(pow_partial_evaluation, builder, driver)?
+        .add(&linearly_dependent, builder, driver))
+}
+
+/// Verifies the sumcheck rounds and the claimed evaluations. The padded rounds do not contribute to the target sum and the gate separator polynomial. Returns the claimed evaluations and the multivariate challenge.
+#[allow(clippy::type_complexity)]
+fn sumcheck_verify<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    transcript: &mut Transcript<P::ScalarField>,
+    params: &RelationParameters<P::ScalarField>,
+    alphas: &[FieldCT<P::ScalarField>],
+    gate_challenges: &[FieldCT<P::ScalarField>],
+    padding: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<(
+    ClaimedEvaluations<P::ScalarField>,
+    Vec<FieldCT<P::ScalarField>>,
+)> {
+    let mut target_total_sum = FieldCT::default();
+    let mut pow_partial_evaluation = constant(1);
+    let mut multivariate_challenge = Vec::with_capacity(CONST_PROOF_SIZE_LOG_N);
+    for round in 0..CONST_PROOF_SIZE_LOG_N {
+        let univariate = transcript.receive_n_from_prover(BATCHED_RELATION_PARTIAL_LENGTH)?;
+        let challenge = transcript.get_challenge(builder, driver)?;
+
+        // (S(0) + S(1) - target) * (1 - padding) = 0
+        let total_sum = univariate[0].add(&univariate[1], builder, driver);
+        let not_padding = constant(1).sub(&padding[round], builder, driver);
+        total_sum
+            .sub(&target_total_sum, builder, driver)
+            .multiply(&not_padding, builder, driver)?
+            .assert_is_zero(builder);
+
+        let next_target = evaluate_barycentric(&univariate, &challenge, builder, driver)?;
+        target_total_sum = FieldCT::conditional_assign(
+            &padding[round],
+            &target_total_sum,
+            &next_target,
+            builder,
+            driver,
+        )?;
+
+        // pow *= 1 + u * (beta_i - 1)
+        let beta_minus_one = gate_challenges[round].sub(&constant(1), builder, driver);
+        let factor = challenge.multiply(&beta_minus_one, builder, driver)?.add(
+            &constant(1),
+            builder,
+            driver,
+        );
+        let next_pow = pow_partial_evaluation.multiply(&factor, builder, driver)?;
+        pow_partial_evaluation = FieldCT::conditional_assign(
+            &padding[round],
+            &pow_partial_evaluation,
+            &next_pow,
+            builder,
+            driver,
+        )?;
+        multivariate_challenge.push(challenge);
+    }
+
+    let evaluations = ClaimedEvaluations::new(transcript.receive_n_from_prover(NUM_ALL_ENTITIES)?);
+    let full_honk_purported_value = compute_full_relation_purported_value(
+        &evaluations,
+        params,
+        alphas,
+        &pow_partial_evaluation,
+        builder,
+        driver,
+    )?;
+    full_honk_purported_value.assert_equal(&target_total_sum, builder, driver);
+    Ok((evaluations, multivariate_challenge))
+}
+
+/// Reduces the opening claims of the sumcheck evaluations to the pairing points (P0, P1) using Shplemini. Returns the commitments and scalars whose MSM is P0, and the commitment W, where P1 = -W.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn compute_batch_opening_claim<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    transcript: &mut Transcript<P::ScalarField>,
+    commitments: &[ProjectivePoint<P::ScalarField>],
+    evaluations: &ClaimedEvaluations<P::ScalarField>,
+    multivariate_challenge: &[FieldCT<P::ScalarField>],
+    padding: &[FieldCT<P::ScalarField>],
+    curve_params: &CurveParams,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<(
+    Vec<ProjectivePoint<P::ScalarField>>,
+    Vec<FieldCT<P::ScalarField>>,
+    ProjectivePoint<P::ScalarField>,
+)> {
+    let fq = &curve_params.base_modulus;
+    let rho = transcript.get_challenge(builder, driver)?;
+    let fold_commitments = (0..CONST_PROOF_SIZE_LOG_N - 1)
+        .map(|_| transcript.receive_point_from_prover(fq, builder, driver))
+        .collect::<HonkProofResult<Vec<_>>>()?;
+    let r = transcript.get_challenge(builder, driver)?;
+    let gemini_evaluations = transcript.receive_n_from_prover(CONST_PROOF_SIZE_LOG_N)?;
+    let nu = transcript.get_challenge(builder, driver)?;
+    let q_commitment = transcript.receive_point_from_prover(fq, builder, driver)?;
+    let z = transcript.get_challenge(builder, driver)?;
+
+    // r, r^2, ..., r^(2^(d-1))
+    let mut r_powers = Vec::with_capacity(CONST_PROOF_SIZE_LOG_N);
+    r_powers.push(r.to_owned());
+    for i in 1..CONST_PROOF_SIZE_LOG_N {
+        let square = r_powers[i - 1].multiply(&r_powers[i - 1], builder, driver)?;
+        r_powers.push(square);
+    }
+
+    // 1/(z - r), 1/(z + r), 1/(z + r^2), ..., 1/(z + r^(2^(d-1)))
+    let one = constant(1);
+    let mut inverse_vanishing_evals = Vec::with_capacity(CONST_PROOF_SIZE_LOG_N + 1);
+    let denominator = z.sub(&r, builder, driver);
+    inverse_vanishing_evals.push(one.divide(&denominator, builder, driver)?);
+    for r_power in r_powers.iter() {
+        let denominator = z.add(r_power, builder, driver);
+        inverse_vanishing_evals.push(one.divide(&denominator, builder, driver)?);
+    }
+
+    let nu_inverse_vanishing = nu.multiply(&inverse_vanishing_evals[1], builder, driver)?;
+    let unshifted_scalar = inverse_vanishing_evals[0].add(&nu_inverse_vanishing, builder, driver);
+    let shifted_scalar = inverse_vanishing_evals[0]
+        .sub(&nu_inverse_vanishing, builder, driver)
+        .divide(&r, builder, driver)?;
+
+    // The shifted polynomials share their commitments with the unshifted ones, thus the scalars get merged
+    let mut points = vec![q_commitment];
+    let mut scalars = vec![one.to_owned()];
+    let mut batched_evaluation = FieldCT::default();
+    let mut batching_challenge = one.to_owned();
+    for (commitment, evaluation) in commitments
+        .iter()
+        .zip(evaluations.precomputed.iter().chain(&evaluations.witness))
+    {
+        let scalar = batching_challenge.multiply(&unshifted_scalar, builder, driver)?;
+        points.push(commitment.to_owned());
+        scalars.push(FieldCT::default().sub(&scalar, builder, driver));
+        let term = evaluation.multiply(&batching_challenge, builder, driver)?;
+        batched_evaluation = batched_evaluation.add(&term, builder, driver);
+        batching_challenge = batching_challenge.multiply(&rho, builder, driver)?;
+    }
+    let shifted_indices = (TABLE_1..TABLE_1 + NUM_SHIFTED_TABLES)
+        .chain((W_L..W_L + NUM_SHIFTED_WITNESSES).map(|i| PRECOMPUTED_ENTITIES_SIZE + i));
+    for (index, evaluation) in shifted_indices.zip(
+        evaluations
+            .shifted_tables
+            .iter()
+            .chain(&evaluations.shifted_witness),
+    ) {
+        let scalar = batching_challenge.multiply(&shifted_scalar, builder, driver)?;
+        // The first entry is the quotient commitment
+        scalars[index + 1] = scalars[index + 1].sub(&scalar, builder, driver);
+        let term = evaluation.multiply(&batching_challenge, builder, driver)?;
+        batched_evaluation = batched_evaluation.add(&term, builder, driver);
+        batching_challenge = batching_challenge.multiply(&rho, builder, driver)?;
+    }
+
+    // The fold commitments A_1, ..., A_(d-1), where the ones of padded rounds get a zero scalar
+    let mut constant_term_accumulator = FieldCT::default();
+    let mut batching_challenge = nu.multiply(&nu, builder, driver)?;
+    for (j, commitment) in fold_commitments.into_iter().enumerate() {
+        let scaling_factor =
+            batching_challenge.multiply(&inverse_vanishing_evals[j + 2], builder, driver)?;
+        let term = scaling_factor.multiply(&gemini_evaluations[j + 1], builder, driver)?;
+        constant_term_accumulator = constant_term_accumulator.add(&term, builder, driver);
+        batching_challenge = batching_challenge.multiply(&nu, builder, driver)?;
+
+        let scalar = FieldCT::default().sub(&scaling_factor, builder, driver);
+        let scalar = FieldCT::conditional_assign(
+            &padding[j + 1],
+            &FieldCT::default(),
+            &scalar,
+            builder,
+            driver,
+        )?;
+        points.push(commitment);
+        scalars.push(scalar);
+    }
+
+    // Recover A_0(r) from the folding equations
+    let mut a_0_pos = batched_evaluation;
+    for l in (1..=CONST_PROOF_SIZE_LOG_N).rev() {
+        let r_power = &r_powers[l - 1];
+        let u = &multivariate_challenge[l - 1];
+        let eval_neg = &gemini_evaluations[l - 1];
+        let one_minus_u = one.sub(u, builder, driver);
+        let one_minus_u_r = one_minus_u.multiply(r_power, builder, driver)?;
+        let numerator = product(&[&constant(2), &a_0_pos, r_power], builder, driver)?.sub(
+            &eval_neg.multiply(&one_minus_u_r.sub(u, builder, driver), builder, driver)?,
+            builder,
+            driver,
+        );
+        let denominator = one_minus_u_r.add(u, builder, driver);
+        let next = numerator.divide(&denominator, builder, driver)?;
+        a_0_pos = FieldCT::conditional_assign(&padding[l - 1], &a_0_pos, &next, builder, driver)?;
+    }
+    let term = a_0_pos.multiply(&inverse_vanishing_evals[0], builder, driver)?;
+    constant_term_accumulator = constant_term_accumulator.add(&term, builder, driver);
+    let term = product(
+        &[&gemini_evaluations[0], &nu, &inverse_vanishing_evals[1]],
+        builder,
+        driver,
+    )?;
+    constant_term_accumulator = constant_term_accumulator.add(&term, builder, driver);
+
+    let (generator_x, generator_y) = &curve_params.generator;
+    points.push(ProjectivePoint::from_affine(
+        BigField::from_constant(generator_x, fq),
+        BigField::from_constant(generator_y, fq),
+    ));
+    scalars.push(constant_term_accumulator);
+
+    // KZG: P0 = C + z * W and P1 = -W
+    let w_commitment = transcript.receive_point_from_prover(fq, builder, driver)?;
+    points.push(w_commitment.to_owned());
+    scalars.push(z);
+    Ok((points, scalars, w_commitment))
+}
+
+fn aggregation_points<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    limbs: &[FieldCT<P::ScalarField>],
+    modulus: &BigUint,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<[ProjectivePoint<P::ScalarField>; 2]> {
+    assert_eq!(limbs.len(), AGGREGATION_OBJECT_SIZE);
+    let mut coordinates = Vec::with_capacity(4);
+    for chunk in limbs.chunks_exact(NUM_LIMBS) {
+        let chunk: &[FieldCT<P::ScalarField>; NUM_LIMBS] =
+            chunk.try_into().expect("chunk has NUM_LIMBS elements");
+        coordinates.push(BigField::from_limbs(chunk, modulus, builder, driver)?);
+    }
+    let [x0, y0, x1, y1]: [BigField<P::ScalarField>; 4] = coordinates
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("there are four coordinates"));
+    Ok([
+        ProjectivePoint::from_affine(x0, y0),
+        ProjectivePoint::from_affine(x1, y1),
+    ])
+}
+
+/// Verifies an UltraHonk proof in the circuit and aggregates its pairing points, the ones of a proof it recursively verified itself, and the input aggregation object into the output aggregation object. The aggregation objects consist of the limbs of the affine coordinates of the two pairing points.
+pub(crate) fn verify_proof<
+    P: Pairing,
+    T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    C: SWCurveConfig,
+>(
+    key: &[FieldCT<P::ScalarField>],
+    proof: &[FieldCT<P::ScalarField>],
+    input_aggregation_object: &[FieldCT<P::ScalarField>; AGGREGATION_OBJECT_SIZE],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<[FieldCT<P::ScalarField>; AGGREGATION_OBJECT_SIZE]>
+where
+    C::BaseField: PrimeField,
+{
+    let curve_params = CurveParams::new::<C>();
+    let fq = &curve_params.base_modulus;
+    if proof.len() < HONK_PROOF_LENGTH {
+        return Err(HonkProofError::InvalidProofLength);
+    }
+    let num_public_inputs = proof.len() - HONK_PROOF_LENGTH;
+    let key = VerificationKey::from_frs(key, fq, builder, driver)?;
+
+    // Oink
+    let mut transcript = Transcript::new(proof.to_vec());
+    let circuit_size = transcript.receive_fr_from_prover()?;
+    let public_input_size = transcript.receive_fr_from_prover()?;
+    let pub_inputs_offset = transcript.receive_fr_from_prover()?;
+    circuit_size.assert_equal(&key.circuit_size, builder, driver);
+    public_input_size.assert_equal(&key.num_public_inputs, builder, driver);
+    public_input_size.assert_equal(&constant(num_public_inputs as i64), builder, driver);
+    pub_inputs_offset.assert_equal(&key.pub_inputs_offset, builder, driver);
+    let public_inputs = transcript.receive_n_from_prover(num_public_inputs)?;
+
+    let mut witness_commitments = vec![None; NUM_WITNESS_ENTITIES];
+    for wire in [W_L, W_R, W_O] {
+        witness_commitments[wire] =
+            Some(transcript.receive_point_from_prover(fq, builder, driver)?);
+    }
+    let etas = transcript.get_challenges(3, builder, driver)?;
+    for entity in [LOOKUP_READ_COUNTS, LOOKUP_READ_TAGS, W_4] {
+        witness_commitments[entity] =
+            Some(transcript.receive_point_from_prover(fq, builder, driver)?);
+    }
+    let beta_gamma = transcript.get_challenges(2, builder, driver)?;
+    witness_commitments[LOOKUP_INVERSES] =
+        Some(transcript.receive_point_from_prover(fq, builder, driver)?);
+    let public_input_delta = compute_public_input_delta(
+        &public_inputs,
+        &beta_gamma[0],
+        &beta_gamma[1],
+        &key.circuit_size,
+        &key.pub_inputs_offset,
+        builder,
+        driver,
+    )?;
+    witness_commitments[Z_PERM] = Some(transcript.receive_point_from_prover(fq, builder, driver)?);
+    let alphas = transcript.get_challenges(NUM_ALPHAS, builder, driver)?;
+    let gate_challenges = (0..CONST_PROOF_SIZE_LOG_N)
+        .map(|_| transcript.get_challenge(builder, driver))
+        .collect::<HonkProofResult<Vec<_>>>()?;
+    let params = RelationParameters {
+        eta: etas[0].to_owned(),
+        eta_two: etas[1].to_owned(),
+        eta_three: etas[2].to_owned(),
+        beta: beta_gamma[0].to_owned(),
+        gamma: beta_gamma[1].to_owned(),
+        public_input_delta,
+    };
+
+    // Sumcheck
+    let padding = padding_indicators(&key.circuit_size, builder, driver)?;
+    let (evaluations, multivariate_challenge) = sumcheck_verify(
+        &mut transcript,
+        &params,
+        &alphas,
+        &gate_challenges,
+        &padding,
+        builder,
+        driver,
+    )?;
+
+    // Shplemini
+    let commitments = key
+        .commitments
+        .iter()
+        .cloned()
+        .chain(
+            witness_commitments
+                .into_iter()
+                .map(|commitment| commitment.expect("all witness commitments are received")),
+        )
+        .collect::<Vec<_>>();
+    let (p0_points, p0_scalars, w_commitment) = compute_batch_opening_claim(
+        &mut transcript,
+        &commitments,
+        &evaluations,
+        &multivariate_challenge,
+        &padding,
+        &curve_params,
+        builder,
+        driver,
+    )?;
+    let recursion_separator = transcript.get_challenge(builder, driver)?;
+    if !transcript.is_consumed() {
+        return Err(HonkProofError::InvalidProofLength);
+    }
+
+    // Aggregation: the nested pairing points are scaled by the separator and the new ones by its square
+    let contains = &key.contains_recursive_proof;
+    let not_contains = constant(1).sub(contains, builder, driver);
+    contains
+        .multiply(&not_contains, builder, driver)?
+        .assert_is_zero(builder);
+    let separator_sqr = recursion_separator.multiply(&recursion_separator, builder, driver)?;
+    let mut p0_all_points = Vec::with_capacity(p0_points.len() + 1);
+    let mut p0_all_scalars = Vec::with_capacity(p0_points.len() + 1);
+    let mut p1_points = vec![w_commitment.neg(builder, driver)?];
+    let mut p1_scalars = vec![separator_sqr.to_owned()];
+    if num_public_inputs >= AGGREGATION_OBJECT_SIZE {
+        let start = num_public_inputs - AGGREGATION_OBJECT_SIZE;
+        let mut nested_limbs = Vec::with_capacity(AGGREGATION_OBJECT_SIZE);
+        for (i, index) in key.recursive_proof_public_input_indices.iter().enumerate() {
+            // The nested aggregation object has to be the last public inputs of the proof
+            index
+                .sub(&constant((start + i) as i64), builder, driver)
+                .multiply(contains, builder, driver)?
+                .assert_is_zero(builder);
+            nested_limbs.push(public_inputs[start + i].multiply(contains, builder, driver)?);
+        }
+        let [nested_p0, nested_p1] = aggregation_points(&nested_limbs, fq, builder, driver)?;
+        let nested_scalar = recursion_separator.multiply(contains, builder, driver)?;
+        p0_all_points.push(nested_p0);
+        p0_all_scalars.push(nested_scalar.to_owned());
+        p1_points.push(nested_p1);
+        p1_scalars.push(nested_scalar);
+    } else {
+        contains.assert_is_zero(builder);
+    }
+    p0_all_points.extend(p0_points);
+    for scalar in p0_scalars {
+        p0_all_scalars.push(scalar.multiply(&separator_sqr, builder, driver)?);
+    }
+
+    let [input_p0, input_p1] = aggregation_points(input_aggregation_object, fq, builder, driver)?;
+    let p0 = batch_mul(
+        &p0_all_points,
+        &p0_all_scalars,
+        &curve_params,
+        builder,
+        driver,
+    )?;
+    let p0 = p0.add(&input_p0, &curve_params, builder, driver)?;
+    let p1 = batch_mul(&p1_points, &p1_scalars, &curve_params, builder, driver)?;
+    let p1 = p1.add(&input_p1, &curve_params, builder, driver)?;
+
+    let mut output = Vec::with_capacity(AGGREGATION_OBJECT_SIZE);
+    for point in [p0, p1] {
+        let (x, y) = point.to_affine(builder, driver)?;
+        output.extend(x.limbs);
+        output.extend(y.limbs);
+    }
+    Ok(output
+        .try_into()
+        .expect("the aggregation object consists of four coordinates"))
+}
@@ -0,0 +1,443 @@
+use super::{
+    plookup::{BasicTableId, KeccakTables, MultiTableId, Plookup},
+    sha256::{scale, to_byte_array},
+    types::{ColumnIdx, FieldCT},
+};
+use crate::{builder::GenericUltraCircuitBuilder, HonkProofResult};
+use ark_ec::pairing::Pairing;
+use ark_ff::{Field, PrimeField, Zero};
+use co_acvm::mpc::NoirWitnessExtensionProtocol;
+
+const NUM_KECCAK_ROUNDS: usize = 24;
+const NUM_KECCAK_LANES: usize = 25;
+/// The rate of Keccak256 in bytes, i.e., the number of message bytes absorbed per permutation
+const BLOCK_SIZE: usize = 136;
+const LIMBS_PER_BLOCK: usize = BLOCK_SIZE / 8;
+
+const RC: [u64; NUM_KECCAK_ROUNDS] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The state of the Keccak-f[1600] permutation. The lanes are stored in sparse form, i.e., each bit is represented
+/// by a base-11 digit. The twisted state is the sparse state multiplied by 11 plus the most significant bit of the
+/// lane, which gives a cheap left-rotation by 1 bit in the theta step.
+struct KeccakState<F: PrimeField> {
+    state: [FieldCT<F>; NUM_KECCAK_LANES],
+    state_msb: [FieldCT<F>; NUM_KECCAK_LANES],
+    twisted_state: [FieldCT<F>; NUM_KECCAK_LANES],
+}
+
+/// Normalizes the base-11 digits of a lane to bits and rotates the lane by the rho rotation offset of the given lane
+/// index. Returns the new lane and its most significant bit.
+fn normalize_and_rotate<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    lane_index: usize,
+    limb: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<(FieldCT<P::ScalarField>, FieldCT<P::ScalarField>)> {
+    let left_bits = KeccakTables::ROTATIONS[lane_index];
+    let right_bits = 64 - left_bits;
+    let num_right_tables = right_bits.div_ceil(KeccakTables::RHO_MAXIMUM_MULTITABLE_BITS);
+
+    let accumulators = Plookup::get_lookup_accumulators_ct(
+        builder,
+        driver,
+        KeccakTables::NORMALIZE_AND_ROTATE[lane_index].to_owned(),
+        limb,
+        &FieldCT::default(),
+        false,
+    )?;
+
+    // The right part holds the lower 64 - r digits of the lane, which become the upper digits after the rotation
+    let right_output = accumulators[ColumnIdx::C2][0].to_owned();
+    let msb = accumulators[ColumnIdx::C3][num_right_tables - 1].to_owned();
+    if left_bits == 0 {
+        return Ok((right_output, msb));
+    }
+
+    let left_output = &accumulators[ColumnIdx::C2][num_right_tables];
+    let shift = P::ScalarField::from(KeccakTables::BASE).pow([left_bits as u64]);
+    let output = scale(&right_output, shift, builder, driver)?.add(left_output, builder, driver);
+    Ok((output, msb))
+}
+
+fn compute_twisted_state<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    internal: &mut KeccakState<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<()> {
+    let base = P::ScalarField::from(KeccakTables::BASE);
+    for (twisted, (state, msb)) in internal
+        .twisted_state
+        .iter_mut()
+        .zip(internal.state.iter().zip(internal.state_msb.iter()))
+    {
+        *twisted = scale(state, base, builder, driver)?
+            .add(msb, builder, driver)
+            .normalize(builder, driver);
+    }
+    Ok(())
+}
+
+/// THETA round
+///
+/// C[x] = A[x, 0] ^ A[x, 1] ^ A[x, 2] ^ A[x, 3] ^ A[x, 4]
+/// D[x] = C[x - 1] ^ ROTL(C[x + 1], 1)
+/// A[x, y] = A[x, y] ^ D[x]
+///
+/// The XORs are additions of sparse values, which are normalized with a lookup table. The rotation of C[x + 1] is
+/// computed from the twisted state, which requires removing the two most significant and the least significant digit
+/// of D[x] before normalizing it.
+fn theta<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    internal: &mut KeccakState<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<()> {
+    let base = P::ScalarField::from(KeccakTables::BASE);
+    let base_inv = base.inverse().expect("11 is invertible");
+    let multiplicand = base.pow([65]);
+
+    let twisted_state = &internal.twisted_state;
+    let mut c = Vec::with_capacity(5);
+    for i in 0..5 {
+        let sum = twisted_state[i].add_two(
+            &twisted_state[5 + i],
+            &twisted_state[10 + i],
+            builder,
+            driver,
+        );
+        c.push(sum.add_two(
+            &twisted_state[15 + i],
+            &twisted_state[20 + i],
+            builder,
+            driver,
+        ));
+    }
+
+    let mut d = Vec::with_capacity(5);
+    for i in 0..5 {
+        let non_shifted_equivalent = &c[(i + 4) % 5];
+        let shifted_equivalent = scale(&c[(i + 1) % 5], base, builder, driver)?;
+        let d_i = non_shifted_equivalent.add(&shifted_equivalent, builder, driver);
+
+        // D contains 66 base-11 digits. The least significant digit is the sum of the msbs of column i - 1 and the
+        // most significant one is the sum of the msbs of column i + 1, both are artifacts of the twisted state.
+        // We split D into lo + mid * 11 + hi * 11^65 and normalize mid.
+        let mut lo_value = T::public_zero();
+        let mut hi_value = T::public_zero();
+        for j in 0..5 {
+            let lo_msb = internal.state_msb[5 * j + (i + 4) % 5].get_value(builder, driver);
+            lo_value = driver.add(lo_value, lo_msb);
+            let hi_msb = internal.state_msb[5 * j + (i + 1) % 5].get_value(builder, driver);
+            hi_value = driver.add(hi_value, hi_msb);
+        }
+        let d_value = d_i.get_value(builder, driver);
+        let mid_value = driver.sub(d_value, lo_value.to_owned());
+        let hi_scaled = driver.acvm_mul_with_public(multiplicand, hi_value.to_owned());
+        let mid_value = driver.sub(mid_value, hi_scaled);
+        let mid_value = driver.acvm_mul_with_public(base_inv, mid_value);
+
+        let hi = FieldCT::from_witness(hi_value, builder);
+        let mid = FieldCT::from_witness(mid_value, builder);
+        let lo = FieldCT::from_witness(lo_value, builder);
+
+        let hi_scaled = scale(&hi, multiplicand, builder, driver)?;
+        let mid_scaled = scale(&mid, base, builder, driver)?;
+        let recomposed = hi_scaled.add_two(&mid_scaled, &lo, builder, driver);
+        d_i.assert_equal(&recomposed, builder, driver);
+        builder.create_new_range_constraint(hi.get_witness_index(), KeccakTables::BASE);
+        builder.create_new_range_constraint(lo.get_witness_index(), KeccakTables::BASE);
+
+        // The lookup table also ensures that mid < 11^64
+        d.push(Plookup::read_from_1_to_2_table(
+            builder,
+            driver,
+            MultiTableId::KeccakThetaOutput,
+            &mid,
+        )?);
+    }
+
+    // Compute state[j * 5 + i] XOR D[i] in partially normalized (base-11) form
+    for i in 0..5 {
+        for j in 0..5 {
+            internal.state[j * 5 + i] = internal.state[j * 5 + i].add(&d[i], builder, driver);
+        }
+    }
+    Ok(())
+}
+
+/// RHO round
+///
+/// The rotations are combined with the normalization of the output of the theta round.
+fn rho<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    internal: &mut KeccakState<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<()> {
+    for i in 0..NUM_KECCAK_LANES {
+        let (state, msb) = normalize_and_rotate(i, &internal.state[i], builder, driver)?;
+        internal.state[i] = state;
+        internal.state_msb[i] = msb;
+    }
+    Ok(())
+}
+
+/// PI round
+///
+/// Permutes the lanes, which does not require any constraints.
+fn pi<F: PrimeField>(internal: &mut KeccakState<F>) {
+    let b = internal.state.to_owned();
+    for y in 0..5 {
+        for x in 0..5 {
+            let u = y;
+            let v = (2 * x + 3 * y) % 5;
+            internal.state[v * 5 + u] = b[5 * y + x].to_owned();
+        }
+    }
+}
+
+/// CHI round
+///
+/// A[x, y] = A[x, y] ^ (~A[x + 1, y] & A[x + 2, y])
+///
+/// For bits a, b, c, the value 2 * a - b + c + 1 lies in [0, 4] and uniquely determines the output bit. Thus, we
+/// compute this linear combination on the sparse lanes and normalize it with a lookup table.
+fn chi<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    internal: &mut KeccakState<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<()> {
+    // The offset adds 1 to every digit, such that no digit becomes negative
+    let base = P::ScalarField::from(KeccakTables::BASE);
+    let chi_offset = (0..64).fold(P::ScalarField::zero(), |acc, _| {
+        acc * base + P::ScalarField::from(1u64)
+    });
+    let chi_offset = FieldCT::from_field(chi_offset);
+
+    for y in 0..5 {
+        let mut lane_outputs = Vec::with_capacity(5);
+        for x in 0..5 {
+            let a = &internal.state[y * 5 + x];
+            let b = &internal.state[y * 5 + (x + 1) % 5];
+            let c = &internal.state[y * 5 + (x + 2) % 5];
+
+            let minus_b = scale(b, -P::ScalarField::from(1u64), builder, driver)?;
+            let lane_output = a
+                .add(a, builder, driver)
+                .add(&chi_offset, builder, driver)
+                .add_two(&minus_b, c, builder, driver);
+            lane_outputs.push(lane_output);
+        }
+        for (x, lane_output) in lane_outputs.iter().enumerate() {
+            let accumulators = Plookup::get_lookup_accumulators_ct(
+                builder,
+                driver,
+                MultiTableId::KeccakChiOutput,
+                lane_output,
+                &FieldCT::default(),
+                false,
+            )?;
+            internal.state[y * 5 + x] = accumulators[ColumnIdx::C2][0].to_owned();
+            internal.state_msb[y * 5 + x] = accumulators[ColumnIdx::C3]
+                .last()
+                .expect("Lookup is not empty")
+                .to_owned();
+        }
+    }
+    Ok(())
+}
+
+/// IOTA round
+///
+/// A[0, 0] = A[0, 0] ^ RC
+fn iota<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    internal: &mut KeccakState<P::ScalarField>,
+    round: usize,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<()> {
+    let sparse_rc =
+        BasicTableId::map_into_sparse_form::<P::ScalarField, { KeccakTables::BASE }>(RC[round]);
+    let xor_result = internal.state[0].add(&FieldCT::from_field(sparse_rc), builder, driver);
+
+    // Normalize the lane, such that the digits do not overflow in the next round
+    let (state, msb) = normalize_and_rotate(0, &xor_result, builder, driver)?;
+    internal.state[0] = state;
+    internal.state_msb[0] = msb;
+
+    // No need to compute the twisted state if this is the last round
+    if round != NUM_KECCAK_ROUNDS - 1 {
+        compute_twisted_state(internal, builder, driver)?;
+    }
+    Ok(())
+}
+
+fn keccakf1600<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    internal: &mut KeccakState<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<()> {
+    for round in 0..NUM_KECCAK_ROUNDS {
+        theta(internal, builder, driver)?;
+        rho(internal, builder, driver)?;
+        pi(internal);
+        chi(internal, builder, driver)?;
+        iota(internal, round, builder, driver)?;
+    }
+    Ok(())
+}
+
+/// Converts a 64-bit lane into its sparse form. Returns the sparse lane and its most significant bit.
+fn convert_to_sparse<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    lane: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<(FieldCT<P::ScalarField>, FieldCT<P::ScalarField>)> {
+    let accumulators = Plookup::get_lookup_accumulators_ct(
+        builder,
+        driver,
+        MultiTableId::KeccakFormatInput,
+        lane,
+        &FieldCT::default(),
+        false,
+    )?;
+    let sparse = accumulators[ColumnIdx::C2][0].to_owned();
+    let msb = accumulators[ColumnIdx::C3]
+        .last()
+        .expect("Lookup is not empty")
+        .to_owned();
+    Ok((sparse, msb))
+}
+
+/// Converts the sparse lanes of the state back into 64-bit lanes.
+fn convert_from_sparse<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    lanes: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    lanes
+        .iter()
+        .map(|lane| {
+            Plookup::read_from_1_to_2_table(builder, driver, MultiTableId::KeccakFormatOutput, lane)
+        })
+        .collect()
+}
+
+/// Applies the Keccak-f[1600] permutation to the given state of 25 64-bit lanes in-circuit.
+pub(crate) fn keccakf1600_permutation<
+    P: Pairing,
+    T: NoirWitnessExtensionProtocol<P::ScalarField>,
+>(
+    state: &[FieldCT<P::ScalarField>; NUM_KECCAK_LANES],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<[FieldCT<P::ScalarField>; NUM_KECCAK_LANES]> {
+    let mut internal = KeccakState {
+        state: Default::default(),
+        state_msb: Default::default(),
+        twisted_state: Default::default(),
+    };
+    for (i, lane) in state.iter().enumerate() {
+        let (sparse, msb) = convert_to_sparse(lane, builder, driver)?;
+        internal.state[i] = sparse;
+        internal.state_msb[i] = msb;
+    }
+    compute_twisted_state(&mut internal, builder, driver)?;
+    keccakf1600(&mut internal, builder, driver)?;
+
+    let output = convert_from_sparse(&internal.state, builder, driver)?;
+    Ok(output.try_into().expect("25 lanes"))
+}
+
+/// Computes the Keccak256 hash of the given bytes and returns the 32 bytes of the digest.
+pub(crate) fn keccak256<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    // Padding: 0x01, then zeros, and 0x80 in the last byte of the last block. The message size is known, hence the
+    // padding only consists of constants.
+    let num_blocks = input.len() / BLOCK_SIZE + 1;
+    let mut padded = input.to_vec();
+    padded.resize(
+        num_blocks * BLOCK_SIZE,
+        FieldCT::from_field(P::ScalarField::zero()),
+    );
+    let last = padded.len() - 1;
+    if input.len() == last {
+        padded[last] = FieldCT::from_field(P::ScalarField::from(0x81u64));
+    } else {
+        padded[input.len()] = FieldCT::from_field(P::ScalarField::from(0x01u64));
+        padded[last] = FieldCT::from_field(P::ScalarField::from(0x80u64));
+    }
+
+    // The lanes are the little-endian composition of 8 bytes
+    let byte_shift = P::ScalarField::from(256u64);
+    let mut sparse_lanes = Vec::with_capacity(num_blocks * LIMBS_PER_BLOCK);
+    for bytes in padded.chunks_exact(8) {
+        let mut lane = FieldCT::from_field(P::ScalarField::zero());
+        let mut scaling_factor = P::ScalarField::from(1u64);
+        for byte in bytes {
+            let scaled = scale(byte, scaling_factor, builder, driver)?;
+            lane = lane.add(&scaled, builder, driver);
+            scaling_factor *= byte_shift;
+        }
+        sparse_lanes.push(convert_to_sparse(&lane, builder, driver)?);
+    }
+
+    let mut internal = KeccakState {
+        state: Default::default(),
+        state_msb: Default::default(),
+        twisted_state: Default::default(),
+    };
+    for (i, block) in sparse_lanes.chunks_exact(LIMBS_PER_BLOCK).enumerate() {
+        if i == 0 {
+            for (j, (sparse, msb)) in block.iter().enumerate() {
+                internal.state[j] = sparse.to_owned();
+                internal.state_msb[j] = msb.to_owned();
+            }
+        } else {
+            for (j, (sparse, _)) in block.iter().enumerate() {
+                let xor_result = internal.state[j].add(sparse, builder, driver);
+                let (state, msb) = normalize_and_rotate(0, &xor_result, builder, driver)?;
+                internal.state[j] = state;
+                internal.state_msb[j] = msb;
+            }
+        }
+        compute_twisted_state(&mut internal, builder, driver)?;
+        keccakf1600(&mut internal, builder, driver)?;
+    }
+
+    // The output is the little-endian byte representation of the first 4 lanes
+    let output_lanes = convert_from_sparse(&internal.state[..4], builder, driver)?;
+    let mut result = Vec::with_capacity(32);
+    for lane in output_lanes.iter() {
+        let mut bytes = to_byte_array(lane, 8, builder, driver)?;
+        bytes.reverse();
+        result.extend(bytes);
+    }
+    Ok(result)
+}
@@ -1,4 +1,17 @@
+pub(crate) mod aes;
+pub(crate) mod bigfield;
+pub(crate) mod biggroup;
+pub(crate) mod blake;
+pub(crate) mod cycle_group;
+pub(crate) mod ecdsa;
+pub(crate) mod honk_recursion;
+pub(crate) mod keccak;
+pub(crate) mod pedersen;
 #[allow(unused)] // TACEO TODO remove later
 pub(crate) mod plookup;
+pub(crate) mod poseidon2;
+pub(crate) mod schnorr;
+pub(crate) mod sha256;
+pub(crate) mod transcript;
 #[allow(unused, clippy::module_inception)] // TACEO TODO remove later
 pub(crate) mod types;
@@ -0,0 +1,56 @@
+use super::{
+    cycle_group::{split_native_field, CycleGroup},
+    types::FieldCT,
+};
+use crate::{builder::GenericUltraCircuitBuilder, HonkProofResult};
+use ark_ec::pairing::Pairing;
+use ark_ff::PrimeField;
+use co_acvm::mpc::NoirWitnessExtensionProtocol;
+use mpc_core::gadgets::pedersen::{
+    derive_generators, DEFAULT_DOMAIN_SEPARATOR, LENGTH_GENERATOR_DOMAIN_SEPARATOR,
+};
+
+/// Returns the generators G_{hash_index}, ..., G_{hash_index + num_generators - 1} of the default domain separator as constant points.
+fn generator_points<F: PrimeField>(num_generators: usize, hash_index: usize) -> Vec<CycleGroup<F>> {
+    derive_generators(DEFAULT_DOMAIN_SEPARATOR, num_generators, hash_index)
+        .into_iter()
+        .map(|generator| CycleGroup::from_native(Some(generator)))
+        .collect()
+}
+
+/// Computes the Pedersen commitment sum_i inputs_i * G_{hash_index + i} in-circuit, where G are the generators of the default domain separator.
+/// The first two generators have fixed base lookup tables, hence commitments with a hash index of 0 or 1 are cheaper.
+pub(crate) fn commit<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    inputs: &[FieldCT<P::ScalarField>],
+    hash_index: usize,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<CycleGroup<P::ScalarField>> {
+    let points = generator_points(inputs.len(), hash_index);
+    let scalars = inputs
+        .iter()
+        .map(|input| split_native_field(input, builder, driver))
+        .collect::<HonkProofResult<Vec<_>>>()?;
+    CycleGroup::batch_mul(&points, &scalars, builder, driver)
+}
+
+/// Computes the Pedersen hash in-circuit, i.e., the x-coordinate of the Pedersen commitment to the inputs to which the number of inputs times the length generator is added.
+pub(crate) fn hash<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    inputs: &[FieldCT<P::ScalarField>],
+    hash_index: usize,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    let length_generator = derive_generators(LENGTH_GENERATOR_DOMAIN_SEPARATOR, 1, 0)[0];
+    let mut points = vec![CycleGroup::from_native(Some(length_generator))];
+    let mut scalars = vec![(
+        FieldCT::from_field(P::ScalarField::from(inputs.len() as u64)),
+        FieldCT::default(),
+    )];
+    points.extend(generator_points(inputs.len(), hash_index));
+    for input in inputs {
+        scalars.push(split_native_field(input, builder, driver)?);
+    }
+    let result = CycleGroup::batch_mul(&points, &scalars, builder, driver)?;
+    Ok(result.x)
+}
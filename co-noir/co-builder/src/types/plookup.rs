@@ -1,8 +1,19 @@
-use ark_ff::PrimeField;
+use crate::builder::GenericUltraCircuitBuilder;
+use crate::HonkProofResult;
+use ark_ec::pairing::Pairing;
+use ark_ff::{Field, One, PrimeField, Zero};
+use co_acvm::mpc::NoirWitnessExtensionProtocol;
+use mpc_core::gadgets::{
+    aes::{xtime, AES128_SBOX},
+    pedersen::{
+        derive_generators, point_add, point_mul, serialize_point, AffinePoint,
+        DEFAULT_DOMAIN_SEPARATOR,
+    },
+};
 use num_bigint::BigUint;
 use std::array::from_fn;
 
-use super::types::{ColumnIdx, LookupEntry, PlookupMultiTable, ReadData};
+use super::types::{ColumnIdx, FieldCT, LookupEntry, PlookupMultiTable, ReadData};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub(crate) enum BasicTableId {
@@ -51,12 +62,66 @@ pub(crate) enum BasicTableId {
     BlakeXorRotate2,
     BlakeXorRotate4,
     FixedBase0_0,
+    FixedBase0_1,
+    FixedBase0_2,
+    FixedBase0_3,
+    FixedBase0_4,
+    FixedBase0_5,
+    FixedBase0_6,
+    FixedBase0_7,
+    FixedBase0_8,
+    FixedBase0_9,
+    FixedBase0_10,
+    FixedBase0_11,
+    FixedBase0_12,
+    FixedBase0_13,
+    FixedBase0_14,
     FixedBase1_0 = BasicTableId::FixedBase0_0 as isize
         + FixedBaseParams::NUM_TABLES_PER_LO_MULTITABLE as isize,
+    FixedBase1_1,
+    FixedBase1_2,
+    FixedBase1_3,
+    FixedBase1_4,
+    FixedBase1_5,
+    FixedBase1_6,
+    FixedBase1_7,
+    FixedBase1_8,
+    FixedBase1_9,
+    FixedBase1_10,
+    FixedBase1_11,
+    FixedBase1_12,
+    FixedBase1_13,
     FixedBase2_0 = BasicTableId::FixedBase1_0 as isize
         + FixedBaseParams::NUM_TABLES_PER_HI_MULTITABLE as isize,
+    FixedBase2_1,
+    FixedBase2_2,
+    FixedBase2_3,
+    FixedBase2_4,
+    FixedBase2_5,
+    FixedBase2_6,
+    FixedBase2_7,
+    FixedBase2_8,
+    FixedBase2_9,
+    FixedBase2_10,
+    FixedBase2_11,
+    FixedBase2_12,
+    FixedBase2_13,
+    FixedBase2_14,
     FixedBase3_0 = BasicTableId::FixedBase2_0 as isize
         + FixedBaseParams::NUM_TABLES_PER_LO_MULTITABLE as isize,
+    FixedBase3_1,
+    FixedBase3_2,
+    FixedBase3_3,
+    FixedBase3_4,
+    FixedBase3_5,
+    FixedBase3_6,
+    FixedBase3_7,
+    FixedBase3_8,
+    FixedBase3_9,
+    FixedBase3_10,
+    FixedBase3_11,
+    FixedBase3_12,
+    FixedBase3_13,
     HonkDummyBasic1 = BasicTableId::FixedBase3_0 as isize
         + FixedBaseParams::NUM_TABLES_PER_HI_MULTITABLE as isize,
     HonkDummyBasic2,
@@ -89,9 +154,388 @@ impl BasicTableId {
 
         [value1, value2]
     }
+
+    pub(crate) fn get_xor_rotate_values_from_key<
+        F: PrimeField,
+        const NUM_ROTATED_OUTPUT_BITS: u32,
+    >(
+        key: [u64; 2],
+    ) -> [F; 2] {
+        [
+            F::from((key[0] ^ key[1]).rotate_right(NUM_ROTATED_OUTPUT_BITS)),
+            F::zero(),
+        ]
+    }
+
+    /// XORs the keys and rotates the result as a 32-bit word. With FILTER, only the lowest 2 bits of the keys are used, which removes the overflow of non-normalized 32-bit inputs in the last slice.
+    pub(crate) fn get_blake_xor_rotate_values_from_key<
+        F: PrimeField,
+        const NUM_ROTATED_OUTPUT_BITS: u32,
+        const FILTER: bool,
+    >(
+        key: [u64; 2],
+    ) -> [F; 2] {
+        let (a, b) = if FILTER {
+            (key[0] & 3, key[1] & 3)
+        } else {
+            (key[0], key[1])
+        };
+        [
+            F::from(((a ^ b) as u32).rotate_right(NUM_ROTATED_OUTPUT_BITS)),
+            F::zero(),
+        ]
+    }
+
+    pub(crate) fn get_and_rotate_values_from_key<
+        F: PrimeField,
+        const NUM_ROTATED_OUTPUT_BITS: u32,
+    >(
+        key: [u64; 2],
+    ) -> [F; 2] {
+        [
+            F::from((key[0] & key[1]).rotate_right(NUM_ROTATED_OUTPUT_BITS)),
+            F::zero(),
+        ]
+    }
+
+    /// Maps the bits of the input into the digits of a base-BASE number, i.e., computes sum_i bit_i * BASE^i.
+    pub(crate) fn map_into_sparse_form<F: PrimeField, const BASE: u64>(input: u64) -> F {
+        let base = F::from(BASE);
+        let mut out = F::zero();
+        let mut power = F::one();
+        let mut input = input;
+        while input > 0 {
+            if input & 1 == 1 {
+                out += power;
+            }
+            power *= base;
+            input >>= 1;
+        }
+        out
+    }
+
+    pub(crate) fn get_sparse_table_with_rotation_values<
+        F: PrimeField,
+        const BASE: u64,
+        const NUM_ROTATED_BITS: u32,
+    >(
+        key: [u64; 2],
+    ) -> [F; 2] {
+        let t0 = Self::map_into_sparse_form::<F, BASE>(key[0]);
+        let t1 = if NUM_ROTATED_BITS > 0 {
+            Self::map_into_sparse_form::<F, BASE>(
+                (key[0] as u32).rotate_right(NUM_ROTATED_BITS) as u64
+            )
+        } else {
+            t0
+        };
+        [t0, t1]
+    }
+
+    fn get_sparse_normalization_values<F: PrimeField, const BASE: u64>(
+        key: [u64; 2],
+        base_table: &[u64],
+    ) -> [F; 2] {
+        let mut accumulator = 0u64;
+        let mut input = key[0];
+        let mut count = 0u64;
+        while input > 0 {
+            let slice = input % BASE;
+            let bit = base_table[slice as usize];
+            accumulator += bit << count;
+            input -= slice;
+            input /= BASE;
+            count += 1;
+        }
+        [F::from(accumulator), F::zero()]
+    }
+
+    pub(crate) fn get_witness_extension_normalization_values<F: PrimeField>(
+        key: [u64; 2],
+    ) -> [F; 2] {
+        Self::get_sparse_normalization_values::<F, 16>(
+            key,
+            &Sha256Tables::WITNESS_EXTENSION_NORMALIZATION_TABLE,
+        )
+    }
+
+    pub(crate) fn get_choose_normalization_values<F: PrimeField>(key: [u64; 2]) -> [F; 2] {
+        Self::get_sparse_normalization_values::<F, 28>(
+            key,
+            &Sha256Tables::CHOOSE_NORMALIZATION_TABLE,
+        )
+    }
+
+    pub(crate) fn get_majority_normalization_values<F: PrimeField>(key: [u64; 2]) -> [F; 2] {
+        Self::get_sparse_normalization_values::<F, 16>(
+            key,
+            &Sha256Tables::MAJORITY_NORMALIZATION_TABLE,
+        )
+    }
+
+    /// Maps each base-9 digit of the input to its parity, i.e., returns the byte whose bits are the parities of the digits.
+    fn map_from_aes_sparse_form(input: u64) -> u8 {
+        let mut result = 0u8;
+        let mut input = input;
+        let mut count = 0;
+        while input > 0 {
+            result |= ((input % AesTables::BASE) as u8 & 1) << count;
+            input /= AesTables::BASE;
+            count += 1;
+        }
+        result
+    }
+
+    pub(crate) fn get_aes_sparse_values_from_key<F: PrimeField>(key: [u64; 2]) -> [F; 2] {
+        [
+            Self::map_into_sparse_form::<F, { AesTables::BASE }>(key[0]),
+            F::zero(),
+        ]
+    }
+
+    pub(crate) fn get_aes_sparse_normalization_values_from_key<F: PrimeField>(
+        key: [u64; 2],
+    ) -> [F; 2] {
+        let byte = Self::map_from_aes_sparse_form(key[0]);
+        [
+            Self::map_into_sparse_form::<F, { AesTables::BASE }>(byte as u64),
+            F::zero(),
+        ]
+    }
+
+    /// Returns the S-box output of the byte in sparse form and the S-box output multiplied by 3 in GF(2^8), both in
+    /// sparse form.
+    pub(crate) fn get_aes_sbox_values_from_key<F: PrimeField>(key: [u64; 2]) -> [F; 2] {
+        let byte = Self::map_from_aes_sparse_form(key[0]);
+        let sbox_value = AES128_SBOX[byte as usize];
+        let tripled = sbox_value ^ xtime(sbox_value);
+        [
+            Self::map_into_sparse_form::<F, { AesTables::BASE }>(sbox_value as u64),
+            Self::map_into_sparse_form::<F, { AesTables::BASE }>(tripled as u64),
+        ]
+    }
+
+    /// Returns the base of the digits of the keys and the number of values a digit can take, if the keys of the table
+    /// are sparse. For these tables, the row index of an entry is not equal to its key.
+    pub(crate) fn sparse_key_bases(&self) -> Option<(u64, u64)> {
+        match self {
+            BasicTableId::AesSboxMap => Some((AesTables::BASE, 2)),
+            BasicTableId::KeccakChi => Some((KeccakTables::BASE, KeccakTables::CHI_EFFECTIVE_BASE)),
+            BasicTableId::KeccakOutput => Some((KeccakTables::BASE, 2)),
+            BasicTableId::KeccakRho1
+            | BasicTableId::KeccakRho2
+            | BasicTableId::KeccakRho3
+            | BasicTableId::KeccakRho4
+            | BasicTableId::KeccakRho5
+            | BasicTableId::KeccakRho6
+            | BasicTableId::KeccakRho7
+            | BasicTableId::KeccakRho8 => {
+                Some((KeccakTables::BASE, KeccakTables::RHO_EFFECTIVE_BASE))
+            }
+            _ => None,
+        }
+    }
+
+    /// Maps each base-11 digit of the input with the given table and recomposes the result in base 11.
+    fn normalize_keccak_sparse_form(input: u64, base_table: &[u64]) -> u64 {
+        let mut accumulator = 0u64;
+        let mut input = input;
+        let mut base_shift = 1u64;
+        while input > 0 {
+            let slice = input % KeccakTables::BASE;
+            accumulator += base_table[slice as usize] * base_shift;
+            input /= KeccakTables::BASE;
+            base_shift *= KeccakTables::BASE;
+        }
+        accumulator
+    }
+
+    pub(crate) fn get_keccak_input_values<F: PrimeField>(key: [u64; 2]) -> [F; 2] {
+        [
+            Self::map_into_sparse_form::<F, { KeccakTables::BASE }>(key[0]),
+            F::from(key[0] >> (KeccakTables::FORMAT_TABLE_BITS - 1)),
+        ]
+    }
+
+    pub(crate) fn get_keccak_theta_values<F: PrimeField>(key: [u64; 2]) -> [F; 2] {
+        let normalized =
+            Self::normalize_keccak_sparse_form(key[0], &KeccakTables::THETA_NORMALIZATION_TABLE);
+        [F::from(normalized), F::zero()]
+    }
+
+    pub(crate) fn get_keccak_chi_values<F: PrimeField>(key: [u64; 2]) -> [F; 2] {
+        // The third column holds the most significant digit of the lane, which is in the last slice of the chi
+        // output table
+        const DIVISOR_EXPONENT: u32 = if 64 % KeccakTables::CHI_TABLE_BITS == 0 {
+            KeccakTables::CHI_TABLE_BITS as u32 - 1
+        } else {
+            (64 % KeccakTables::CHI_TABLE_BITS) as u32 - 1
+        };
+        let normalized =
+            Self::normalize_keccak_sparse_form(key[0], &KeccakTables::CHI_NORMALIZATION_TABLE);
+        [
+            F::from(normalized),
+            F::from(normalized / KeccakTables::BASE.pow(DIVISOR_EXPONENT)),
+        ]
+    }
+
+    pub(crate) fn get_keccak_rho_values<F: PrimeField, const TABLE_BITS: u32>(
+        key: [u64; 2],
+    ) -> [F; 2] {
+        let normalized =
+            Self::normalize_keccak_sparse_form(key[0], &KeccakTables::RHO_NORMALIZATION_TABLE);
+        [
+            F::from(normalized),
+            F::from(normalized / KeccakTables::BASE.pow(TABLE_BITS - 1)),
+        ]
+    }
+
+    pub(crate) fn get_keccak_output_values<F: PrimeField>(key: [u64; 2]) -> [F; 2] {
+        let mut accumulator = 0u64;
+        let mut input = key[0];
+        let mut count = 0;
+        while input > 0 {
+            let slice = input % KeccakTables::BASE;
+            accumulator += slice << count;
+            input /= KeccakTables::BASE;
+            count += 1;
+        }
+        [F::from(accumulator), F::zero()]
+    }
+}
+
+pub(crate) struct Sha256Tables {}
+impl Sha256Tables {
+    pub(crate) const WITNESS_EXTENSION_NORMALIZATION_TABLE: [u64; 16] =
+        [0, 1, 0, 1, 1, 2, 1, 2, 0, 1, 0, 1, 1, 2, 1, 2];
+    pub(crate) const CHOOSE_NORMALIZATION_TABLE: [u64; 28] = [
+        0, 0, 0, 1, 0, 1, 1, 1, 1, 1, 2, 1, 2, 2, 0, 0, 0, 1, 0, 1, 1, 1, 1, 1, 2, 1, 2, 2,
+    ];
+    pub(crate) const MAJORITY_NORMALIZATION_TABLE: [u64; 16] =
+        [0, 0, 1, 1, 1, 1, 2, 2, 0, 0, 1, 1, 1, 1, 2, 2];
+
+    // Multipliers that map the sparse limbs of `e` (resp. `a`) onto the sum of its three rotations that are required
+    // by the `choose` (resp. `majority`) step of the SHA256 compression function.
+    pub(crate) fn get_choose_rotation_multipliers<F: PrimeField>() -> [F; 3] {
+        let base = F::from(28u64);
+        let column_2_row_3_coefficients = [F::one(), base.pow([11]), base.pow([22])];
+
+        // scaling factors applied to a's sparse limbs, excluding the rotated limb
+        let rot6_coefficients = [F::zero(), base.pow([11 - 6]), base.pow([22 - 6])];
+        let rot11_coefficients = [base.pow([32 - 11]), F::zero(), base.pow([22 - 11])];
+        let rot25_coefficients = [base.pow([32 - 25]), base.pow([32 - 25 + 11]), F::zero()];
+
+        // these are the coefficients that we want
+        let target_rotation_coefficients = [
+            rot6_coefficients[0] + rot11_coefficients[0] + rot25_coefficients[0],
+            rot6_coefficients[1] + rot11_coefficients[1] + rot25_coefficients[1],
+            rot6_coefficients[2] + rot11_coefficients[2] + rot25_coefficients[2],
+        ];
+
+        let column_2_row_1_multiplier = target_rotation_coefficients[0];
+
+        // this gives us the correct scaling factor for a0's 1st limb
+        let current_coefficients = [
+            column_2_row_3_coefficients[0] * column_2_row_1_multiplier,
+            column_2_row_3_coefficients[1] * column_2_row_1_multiplier,
+            column_2_row_3_coefficients[2] * column_2_row_1_multiplier,
+        ];
+
+        let column_2_row_3_multiplier = -current_coefficients[2] + target_rotation_coefficients[2];
+
+        [
+            column_2_row_1_multiplier,
+            F::zero(),
+            column_2_row_3_multiplier,
+        ]
+    }
+
+    pub(crate) fn get_majority_rotation_multipliers<F: PrimeField>() -> [F; 3] {
+        let base = F::from(16u64);
+
+        // scaling factors applied to a's sparse limbs, excluding the rotated limb
+        let rot2_coefficients = [F::zero(), base.pow([11 - 2]), base.pow([22 - 2])];
+        let rot13_coefficients = [base.pow([32 - 13]), F::zero(), base.pow([22 - 13])];
+        let rot22_coefficients = [base.pow([32 - 22]), base.pow([32 - 22 + 11]), F::zero()];
+
+        // these are the coefficients that we want
+        let target_rotation_coefficients = [
+            rot2_coefficients[0] + rot13_coefficients[0] + rot22_coefficients[0],
+            rot2_coefficients[1] + rot13_coefficients[1] + rot22_coefficients[1],
+            rot2_coefficients[2] + rot13_coefficients[2] + rot22_coefficients[2],
+        ];
+
+        let column_2_row_1_multiplier = target_rotation_coefficients[0];
+        let column_2_row_2_multiplier =
+            target_rotation_coefficients[0] * (-base.pow([11])) + target_rotation_coefficients[1];
+
+        [
+            column_2_row_1_multiplier,
+            column_2_row_2_multiplier,
+            F::zero(),
+        ]
+    }
 }
 
-struct FixedBaseParams {}
+pub(crate) struct AesTables {}
+impl AesTables {
+    /// Each bit of a byte is represented by a base-9 digit in the sparse form, such that up to 8 sparse bytes can be
+    /// added before they have to be normalized.
+    pub(crate) const BASE: u64 = 9;
+    pub(crate) const NORMALIZATION_TABLE_DIGITS: u32 = 4;
+}
+
+pub(crate) struct KeccakTables {}
+impl KeccakTables {
+    /// Each bit of a lane is represented by a base-11 digit in the sparse form
+    pub(crate) const BASE: u64 = 11;
+    pub(crate) const CHI_EFFECTIVE_BASE: u64 = 5;
+    pub(crate) const RHO_EFFECTIVE_BASE: u64 = 3;
+    pub(crate) const FORMAT_TABLE_BITS: u64 = 8;
+    pub(crate) const THETA_TABLE_BITS: u64 = 4;
+    pub(crate) const CHI_TABLE_BITS: u64 = 6;
+    pub(crate) const RHO_MAXIMUM_MULTITABLE_BITS: usize = 8;
+
+    pub(crate) const THETA_NORMALIZATION_TABLE: [u64; 11] = [0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0];
+    pub(crate) const CHI_NORMALIZATION_TABLE: [u64; 5] = [0, 0, 1, 1, 0];
+    pub(crate) const RHO_NORMALIZATION_TABLE: [u64; 3] = [0, 1, 0];
+
+    /// The rotation offsets of the rho step, indexed by x + 5 * y
+    pub(crate) const ROTATIONS: [usize; 25] = [
+        0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56,
+        14,
+    ];
+
+    pub(crate) const NORMALIZE_AND_ROTATE: [MultiTableId; 25] = [
+        MultiTableId::KeccakNormalizeAndRotate0,
+        MultiTableId::KeccakNormalizeAndRotate1,
+        MultiTableId::KeccakNormalizeAndRotate2,
+        MultiTableId::KeccakNormalizeAndRotate3,
+        MultiTableId::KeccakNormalizeAndRotate4,
+        MultiTableId::KeccakNormalizeAndRotate5,
+        MultiTableId::KeccakNormalizeAndRotate6,
+        MultiTableId::KeccakNormalizeAndRotate7,
+        MultiTableId::KeccakNormalizeAndRotate8,
+        MultiTableId::KeccakNormalizeAndRotate9,
+        MultiTableId::KeccakNormalizeAndRotate10,
+        MultiTableId::KeccakNormalizeAndRotate11,
+        MultiTableId::KeccakNormalizeAndRotate12,
+        MultiTableId::KeccakNormalizeAndRotate13,
+        MultiTableId::KeccakNormalizeAndRotate14,
+        MultiTableId::KeccakNormalizeAndRotate15,
+        MultiTableId::KeccakNormalizeAndRotate16,
+        MultiTableId::KeccakNormalizeAndRotate17,
+        MultiTableId::KeccakNormalizeAndRotate18,
+        MultiTableId::KeccakNormalizeAndRotate19,
+        MultiTableId::KeccakNormalizeAndRotate20,
+        MultiTableId::KeccakNormalizeAndRotate21,
+        MultiTableId::KeccakNormalizeAndRotate22,
+        MultiTableId::KeccakNormalizeAndRotate23,
+        MultiTableId::KeccakNormalizeAndRotate24,
+    ];
+}
+
+pub(crate) struct FixedBaseParams {}
 impl FixedBaseParams {
     const BITS_PER_TABLE: usize = 9;
     const BITS_ON_CURVE: usize = 254;
@@ -107,7 +551,7 @@ impl FixedBaseParams {
     const BITS_PER_HI_SCALAR: usize = Self::BITS_ON_CURVE - Self::BITS_PER_LO_SCALAR;
     // max table size because the last lookup table might be smaller (BITS_PER_TABLE does not neatly divide
     // BITS_PER_LO_SCALAR)
-    const MAX_TABLE_SIZE: usize = 1 << Self::BITS_PER_TABLE;
+    pub(crate) const MAX_TABLE_SIZE: usize = 1 << Self::BITS_PER_TABLE;
     // how many BITS_PER_TABLE lookup tables do we need to traverse BITS_PER_LO_SCALAR-amount of bits?
     // (we implicitly assume BITS_PER_LO_SCALAR > BITS_PER_HI_SCALAR)
     const MAX_NUM_TABLES_IN_MULTITABLE: usize = (Self::BITS_PER_LO_SCALAR / Self::BITS_PER_TABLE)
@@ -139,6 +583,243 @@ impl FixedBaseParams {
     // points.
     const NUM_FIXED_BASE_BASIC_TABLES: usize =
         Self::NUM_BASIC_TABLES_PER_BASE_POINT * Self::NUM_POINTS;
+
+    /// The multitables in the order of the base points, i.e., the lo and hi multitables of the lhs and of the rhs
+    /// generator point.
+    const MULTI_TABLE_IDS: [MultiTableId; Self::NUM_FIXED_BASE_MULTI_TABLES] = [
+        MultiTableId::FixedBaseLeftLo,
+        MultiTableId::FixedBaseLeftHi,
+        MultiTableId::FixedBaseRightLo,
+        MultiTableId::FixedBaseRightHi,
+    ];
+
+    const BASIC_TABLE_IDS: [&'static [BasicTableId]; Self::NUM_FIXED_BASE_MULTI_TABLES] = [
+        &[
+            BasicTableId::FixedBase0_0,
+            BasicTableId::FixedBase0_1,
+            BasicTableId::FixedBase0_2,
+            BasicTableId::FixedBase0_3,
+            BasicTableId::FixedBase0_4,
+            BasicTableId::FixedBase0_5,
+            BasicTableId::FixedBase0_6,
+            BasicTableId::FixedBase0_7,
+            BasicTableId::FixedBase0_8,
+            BasicTableId::FixedBase0_9,
+            BasicTableId::FixedBase0_10,
+            BasicTableId::FixedBase0_11,
+            BasicTableId::FixedBase0_12,
+            BasicTableId::FixedBase0_13,
+            BasicTableId::FixedBase0_14,
+        ],
+        &[
+            BasicTableId::FixedBase1_0,
+            BasicTableId::FixedBase1_1,
+            BasicTableId::FixedBase1_2,
+            BasicTableId::FixedBase1_3,
+            BasicTableId::FixedBase1_4,
+            BasicTableId::FixedBase1_5,
+            BasicTableId::FixedBase1_6,
+            BasicTableId::FixedBase1_7,
+            BasicTableId::FixedBase1_8,
+            BasicTableId::FixedBase1_9,
+            BasicTableId::FixedBase1_10,
+            BasicTableId::FixedBase1_11,
+            BasicTableId::FixedBase1_12,
+            BasicTableId::FixedBase1_13,
+        ],
+        &[
+            BasicTableId::FixedBase2_0,
+            BasicTableId::FixedBase2_1,
+            BasicTableId::FixedBase2_2,
+            BasicTableId::FixedBase2_3,
+            BasicTableId::FixedBase2_4,
+            BasicTableId::FixedBase2_5,
+            BasicTableId::FixedBase2_6,
+            BasicTableId::FixedBase2_7,
+            BasicTableId::FixedBase2_8,
+            BasicTableId::FixedBase2_9,
+            BasicTableId::FixedBase2_10,
+            BasicTableId::FixedBase2_11,
+            BasicTableId::FixedBase2_12,
+            BasicTableId::FixedBase2_13,
+            BasicTableId::FixedBase2_14,
+        ],
+        &[
+            BasicTableId::FixedBase3_0,
+            BasicTableId::FixedBase3_1,
+            BasicTableId::FixedBase3_2,
+            BasicTableId::FixedBase3_3,
+            BasicTableId::FixedBase3_4,
+            BasicTableId::FixedBase3_5,
+            BasicTableId::FixedBase3_6,
+            BasicTableId::FixedBase3_7,
+            BasicTableId::FixedBase3_8,
+            BasicTableId::FixedBase3_9,
+            BasicTableId::FixedBase3_10,
+            BasicTableId::FixedBase3_11,
+            BasicTableId::FixedBase3_12,
+            BasicTableId::FixedBase3_13,
+        ],
+    ];
+
+    /// The number of scalar bits the multitable covers, i.e., BITS_PER_LO_SCALAR for the lo and BITS_PER_HI_SCALAR for
+    /// the hi multitables.
+    fn multitable_bits(multitable_index: usize) -> usize {
+        if multitable_index % 2 == 0 {
+            Self::BITS_PER_LO_SCALAR
+        } else {
+            Self::BITS_PER_HI_SCALAR
+        }
+    }
+
+    /// The generator points with lookup tables are the first two generators of the default domain separator, which are
+    /// the first two generators of the Pedersen commitment and hash.
+    pub(crate) fn lhs_generator_point<F: PrimeField>() -> (F, F) {
+        derive_generators(DEFAULT_DOMAIN_SEPARATOR, 1, 0)[0]
+    }
+
+    pub(crate) fn rhs_generator_point<F: PrimeField>() -> (F, F) {
+        derive_generators(DEFAULT_DOMAIN_SEPARATOR, 1, 1)[0]
+    }
+
+    /// The base point of the multitable, which is the generator point for the lo and the generator point times
+    /// 2^BITS_PER_LO_SCALAR for the hi multitables.
+    fn base_point<F: PrimeField>(multitable_index: usize) -> (F, F) {
+        let generator = if multitable_index < 2 {
+            Self::lhs_generator_point()
+        } else {
+            Self::rhs_generator_point()
+        };
+        if multitable_index % 2 == 0 {
+            generator
+        } else {
+            point_mul(
+                Some(generator),
+                &(BigUint::one() << Self::BITS_PER_LO_SCALAR),
+            )
+            .expect("Generator has prime order")
+        }
+    }
+
+    /// The offset generator of the basic table, which is derived from the serialized base point of the multitable.
+    fn offset_generator<F: PrimeField>(multitable_index: usize, table_index: usize) -> (F, F) {
+        let base_point = Self::base_point::<F>(multitable_index);
+        derive_generators(&serialize_point(&base_point), 1, table_index)[0]
+    }
+
+    /// Returns the multitables of the lo and hi scalar limbs if the point is a generator point with lookup tables.
+    pub(crate) fn get_lookup_table_ids_for_point<F: PrimeField>(
+        point: &(F, F),
+    ) -> Option<[MultiTableId; 2]> {
+        if *point == Self::lhs_generator_point() {
+            Some([MultiTableId::FixedBaseLeftLo, MultiTableId::FixedBaseLeftHi])
+        } else if *point == Self::rhs_generator_point() {
+            Some([
+                MultiTableId::FixedBaseRightLo,
+                MultiTableId::FixedBaseRightHi,
+            ])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the sum of the offset generators of the basic tables of the multitable, which is contained in the sum of
+    /// the points read from the multitable.
+    pub(crate) fn get_generator_offset_for_table_id<F: PrimeField>(
+        id: MultiTableId,
+    ) -> AffinePoint<F> {
+        let multitable_index = Self::MULTI_TABLE_IDS
+            .iter()
+            .position(|table_id| *table_id == id)
+            .expect("Fixed base multitable");
+        let base_point = Self::base_point::<F>(multitable_index);
+        derive_generators(
+            &serialize_point(&base_point),
+            Self::BASIC_TABLE_IDS[multitable_index].len(),
+            0,
+        )
+        .into_iter()
+        .fold(None, |acc, offset| point_add(acc, Some(offset)))
+    }
+
+    /// Returns the indices of the multitable and of the basic table within the multitable for a fixed base table.
+    pub(crate) fn get_table_indices(id: &BasicTableId) -> Option<(usize, usize)> {
+        Self::BASIC_TABLE_IDS
+            .iter()
+            .enumerate()
+            .find_map(|(multitable_index, ids)| {
+                ids.iter()
+                    .position(|table_id| table_id == id)
+                    .map(|table_index| (multitable_index, table_index))
+            })
+    }
+
+    /// The number of entries of the basic table, where the last table of a multitable covers only the remaining bits.
+    pub(crate) fn get_table_size(multitable_index: usize, table_index: usize) -> usize {
+        let remaining_bits =
+            Self::multitable_bits(multitable_index) - Self::BITS_PER_TABLE * table_index;
+        1 << remaining_bits.min(Self::BITS_PER_TABLE)
+    }
+
+    /// Returns the points offset + i * base_point * 2^(BITS_PER_TABLE * table_index) of the basic table.
+    pub(crate) fn generate_table_points<F: PrimeField>(
+        multitable_index: usize,
+        table_index: usize,
+    ) -> Vec<(F, F)> {
+        let base_point = point_mul(
+            Some(Self::base_point(multitable_index)),
+            &(BigUint::one() << (Self::BITS_PER_TABLE * table_index)),
+        );
+        let offset = Self::offset_generator(multitable_index, table_index);
+        let mut accumulator = Some(offset);
+        let mut points = Vec::with_capacity(Self::get_table_size(multitable_index, table_index));
+        for _ in 0..Self::get_table_size(multitable_index, table_index) {
+            points.push(accumulator.expect("Offset is not a multiple of the base point"));
+            accumulator = point_add(accumulator, base_point);
+        }
+        points
+    }
+
+    /// Computes the entry of the basic table with the given indices for the key, i.e., the coordinates of
+    /// offset + key * base_point * 2^(BITS_PER_TABLE * TABLE_INDEX).
+    fn get_basic_fixed_base_table_values<
+        F: PrimeField,
+        const MULTITABLE_INDEX: usize,
+        const TABLE_INDEX: usize,
+    >(
+        key: [u64; 2],
+    ) -> [F; 2] {
+        let base_point = point_mul(
+            Some(Self::base_point(MULTITABLE_INDEX)),
+            &(BigUint::one() << (Self::BITS_PER_TABLE * TABLE_INDEX)),
+        );
+        let offset = Self::offset_generator(MULTITABLE_INDEX, TABLE_INDEX);
+        let (x, y) = point_add(Some(offset), point_mul(base_point, &BigUint::from(key[0])))
+            .expect("Offset is not a multiple of the base point");
+        [x, y]
+    }
+
+    /// Returns the functions which compute the entries of the basic tables of the multitable from the keys.
+    pub(crate) fn get_values_from_key_table<F: PrimeField, const MULTITABLE_INDEX: usize>(
+    ) -> [fn([u64; 2]) -> [F; 2]; Self::MAX_NUM_TABLES_IN_MULTITABLE] {
+        [
+            Self::get_basic_fixed_base_table_values::<F, MULTITABLE_INDEX, 0>,
+            Self::get_basic_fixed_base_table_values::<F, MULTITABLE_INDEX, 1>,
+            Self::get_basic_fixed_base_table_values::<F, MULTITABLE_INDEX, 2>,
+            Self::get_basic_fixed_base_table_values::<F, MULTITABLE_INDEX, 3>,
+            Self::get_basic_fixed_base_table_values::<F, MULTITABLE_INDEX, 4>,
+            Self::get_basic_fixed_base_table_values::<F, MULTITABLE_INDEX, 5>,
+            Self::get_basic_fixed_base_table_values::<F, MULTITABLE_INDEX, 6>,
+            Self::get_basic_fixed_base_table_values::<F, MULTITABLE_INDEX, 7>,
+            Self::get_basic_fixed_base_table_values::<F, MULTITABLE_INDEX, 8>,
+            Self::get_basic_fixed_base_table_values::<F, MULTITABLE_INDEX, 9>,
+            Self::get_basic_fixed_base_table_values::<F, MULTITABLE_INDEX, 10>,
+            Self::get_basic_fixed_base_table_values::<F, MULTITABLE_INDEX, 11>,
+            Self::get_basic_fixed_base_table_values::<F, MULTITABLE_INDEX, 12>,
+            Self::get_basic_fixed_base_table_values::<F, MULTITABLE_INDEX, 13>,
+            Self::get_basic_fixed_base_table_values::<F, MULTITABLE_INDEX, 14>,
+        ]
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -184,8 +865,32 @@ pub(crate) enum MultiTableId {
     KeccakChiOutput,
     KeccakFormatInput,
     KeccakFormatOutput,
-    KeccakNormalizeAndRotate,
-    NumMultiTables = MultiTableId::KeccakNormalizeAndRotate as isize + 25,
+    KeccakNormalizeAndRotate0,
+    KeccakNormalizeAndRotate1,
+    KeccakNormalizeAndRotate2,
+    KeccakNormalizeAndRotate3,
+    KeccakNormalizeAndRotate4,
+    KeccakNormalizeAndRotate5,
+    KeccakNormalizeAndRotate6,
+    KeccakNormalizeAndRotate7,
+    KeccakNormalizeAndRotate8,
+    KeccakNormalizeAndRotate9,
+    KeccakNormalizeAndRotate10,
+    KeccakNormalizeAndRotate11,
+    KeccakNormalizeAndRotate12,
+    KeccakNormalizeAndRotate13,
+    KeccakNormalizeAndRotate14,
+    KeccakNormalizeAndRotate15,
+    KeccakNormalizeAndRotate16,
+    KeccakNormalizeAndRotate17,
+    KeccakNormalizeAndRotate18,
+    KeccakNormalizeAndRotate19,
+    KeccakNormalizeAndRotate20,
+    KeccakNormalizeAndRotate21,
+    KeccakNormalizeAndRotate22,
+    KeccakNormalizeAndRotate23,
+    KeccakNormalizeAndRotate24,
+    NumMultiTables = MultiTableId::KeccakNormalizeAndRotate0 as isize + 25,
 }
 
 impl From<MultiTableId> for usize {
@@ -232,15 +937,610 @@ impl<F: PrimeField> Plookup<F> {
         table
     }
 
+    /// The multitable of the fixed base scalar multiplication, which reads the points
+    /// offset_i + slice_i * base_point * 2^(BITS_PER_TABLE * i) for the BITS_PER_TABLE-bit slices of a scalar limb.
+    /// As the points are accumulated by additions instead of the lookup, the step sizes of the coordinates are 0.
+    fn get_fixed_base_table<const MULTITABLE_INDEX: usize>(
+        id: MultiTableId,
+    ) -> PlookupMultiTable<F> {
+        let basic_table_ids = FixedBaseParams::BASIC_TABLE_IDS[MULTITABLE_INDEX];
+        let num_tables = basic_table_ids.len();
+        let mut table = PlookupMultiTable::new(
+            F::from(FixedBaseParams::MAX_TABLE_SIZE as u64),
+            F::zero(),
+            F::zero(),
+            num_tables,
+        );
+        table.id = id;
+        let get_values_from_key =
+            FixedBaseParams::get_values_from_key_table::<F, MULTITABLE_INDEX>();
+        for (basic_table_id, get_values) in basic_table_ids.iter().zip(get_values_from_key) {
+            table
+                .slice_sizes
+                .push(FixedBaseParams::MAX_TABLE_SIZE as u64);
+            table.basic_table_ids.push(basic_table_id.to_owned());
+            table.get_table_values.push(get_values);
+        }
+        table
+    }
+
+    fn get_witness_extension_output_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        let num_entries = 11;
+        let mut table = PlookupMultiTable::new(
+            F::from(16u64.pow(3)),
+            F::from(1u64 << 3),
+            F::zero(),
+            num_entries,
+        );
+        table.id = id;
+        for _ in 0..num_entries {
+            table.slice_sizes.push(16u64.pow(3));
+            table
+                .basic_table_ids
+                .push(BasicTableId::Sha256WitnessNormalize);
+            table
+                .get_table_values
+                .push(BasicTableId::get_witness_extension_normalization_values::<F>);
+        }
+        table
+    }
+
+    fn get_choose_output_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        let num_entries = 16;
+        let mut table = PlookupMultiTable::new(
+            F::from(28u64.pow(2)),
+            F::from(1u64 << 2),
+            F::zero(),
+            num_entries,
+        );
+        table.id = id;
+        for _ in 0..num_entries {
+            table.slice_sizes.push(28u64.pow(2));
+            table.basic_table_ids.push(BasicTableId::Sha256ChNormalize);
+            table
+                .get_table_values
+                .push(BasicTableId::get_choose_normalization_values::<F>);
+        }
+        table
+    }
+
+    fn get_majority_output_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        let num_entries = 11;
+        let mut table = PlookupMultiTable::new(
+            F::from(16u64.pow(3)),
+            F::from(1u64 << 3),
+            F::zero(),
+            num_entries,
+        );
+        table.id = id;
+        for _ in 0..num_entries {
+            table.slice_sizes.push(16u64.pow(3));
+            table.basic_table_ids.push(BasicTableId::Sha256MajNormalize);
+            table
+                .get_table_values
+                .push(BasicTableId::get_majority_normalization_values::<F>);
+        }
+        table
+    }
+
+    fn get_witness_extension_input_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        let column_1_coefficients = vec![
+            F::one(),
+            F::from(1u64 << 3),
+            F::from(1u64 << 10),
+            F::from(1u64 << 18),
+        ];
+        let column_2_coefficients = vec![F::zero(); 4];
+        let column_3_coefficients = vec![F::zero(); 4];
+        let mut table = PlookupMultiTable::new_from_vec(
+            column_1_coefficients,
+            column_2_coefficients,
+            column_3_coefficients,
+        );
+        table.id = id;
+        table.slice_sizes = vec![1 << 3, 1 << 7, 1 << 8, 1 << 14];
+        table.basic_table_ids = vec![
+            BasicTableId::Sha256WitnessSlice3,
+            BasicTableId::Sha256WitnessSlice7Rotate4,
+            BasicTableId::Sha256WitnessSlice8Rotate7,
+            BasicTableId::Sha256WitnessSlice14Rotate1,
+        ];
+        table.get_table_values = vec![
+            BasicTableId::get_sparse_table_with_rotation_values::<F, 16, 0>,
+            BasicTableId::get_sparse_table_with_rotation_values::<F, 16, 4>,
+            BasicTableId::get_sparse_table_with_rotation_values::<F, 16, 7>,
+            BasicTableId::get_sparse_table_with_rotation_values::<F, 16, 1>,
+        ];
+        table
+    }
+
+    fn get_choose_input_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        let base = F::from(28u64);
+        let rotation_multipliers = Sha256Tables::get_choose_rotation_multipliers::<F>();
+        let target_rotation_coefficient = base.pow([11 - 6]) + base.pow([32 - 25 + 11]);
+        let column_3_row_2_multiplier =
+            -(base.pow([11]) * rotation_multipliers[0]) + target_rotation_coefficient;
+
+        let column_1_coefficients = vec![F::one(), F::from(1u64 << 11), F::from(1u64 << 22)];
+        let column_2_coefficients = vec![F::one(), base.pow([11]), base.pow([22])];
+        let column_3_coefficients = vec![F::one(), column_3_row_2_multiplier + F::one(), F::one()];
+        let mut table = PlookupMultiTable::new_from_vec(
+            column_1_coefficients,
+            column_2_coefficients,
+            column_3_coefficients,
+        );
+        table.id = id;
+        table.slice_sizes = vec![1 << 11, 1 << 11, 1 << 10];
+        table.basic_table_ids = vec![
+            BasicTableId::Sha256Base28Rotate6,
+            BasicTableId::Sha256Base28,
+            BasicTableId::Sha256Base28Rotate3,
+        ];
+        table.get_table_values = vec![
+            BasicTableId::get_sparse_table_with_rotation_values::<F, 28, 6>,
+            BasicTableId::get_sparse_table_with_rotation_values::<F, 28, 0>,
+            BasicTableId::get_sparse_table_with_rotation_values::<F, 28, 3>,
+        ];
+        table
+    }
+
+    fn get_majority_input_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        let base = F::from(16u64);
+        let rotation_multipliers = Sha256Tables::get_majority_rotation_multipliers::<F>();
+        let target_rotation_coefficient = base.pow([22 - 2]) + base.pow([22 - 13]);
+        let column_3_row_3_multiplier = -(base.pow([22]) * rotation_multipliers[0])
+            - (base.pow([11]) * rotation_multipliers[1])
+            + target_rotation_coefficient;
+
+        let column_1_coefficients = vec![F::one(), F::from(1u64 << 11), F::from(1u64 << 22)];
+        let column_2_coefficients = vec![F::one(), base.pow([11]), base.pow([22])];
+        let column_3_coefficients = vec![F::one(), F::one(), F::one() + column_3_row_3_multiplier];
+        let mut table = PlookupMultiTable::new_from_vec(
+            column_1_coefficients,
+            column_2_coefficients,
+            column_3_coefficients,
+        );
+        table.id = id;
+        table.slice_sizes = vec![1 << 11, 1 << 11, 1 << 10];
+        table.basic_table_ids = vec![
+            BasicTableId::Sha256Base16Rotate2,
+            BasicTableId::Sha256Base16Rotate2,
+            BasicTableId::Sha256Base16,
+        ];
+        table.get_table_values = vec![
+            BasicTableId::get_sparse_table_with_rotation_values::<F, 16, 2>,
+            BasicTableId::get_sparse_table_with_rotation_values::<F, 16, 2>,
+            BasicTableId::get_sparse_table_with_rotation_values::<F, 16, 0>,
+        ];
+        table
+    }
+
+    fn get_uint32_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        const TABLE_BIT_SIZE: u64 = 6;
+        let num_entries = (32 / TABLE_BIT_SIZE) as usize;
+        let base = 1u64 << TABLE_BIT_SIZE;
+        let mut table =
+            PlookupMultiTable::new(F::from(base), F::from(base), F::from(base), num_entries);
+        table.id = id.to_owned();
+
+        let (basic_table_id, get_table_values): (_, fn([u64; 2]) -> [F; 2]) = match id {
+            MultiTableId::Uint32Xor => (
+                BasicTableId::UintXorRotate0,
+                BasicTableId::get_xor_rotate_values_from_key::<F, 0>,
+            ),
+            MultiTableId::Uint32And => (
+                BasicTableId::UintAndRotate0,
+                BasicTableId::get_and_rotate_values_from_key::<F, 0>,
+            ),
+            _ => unreachable!("Not a uint32 table"),
+        };
+
+        for _ in 0..num_entries {
+            table.slice_sizes.push(base);
+            table.basic_table_ids.push(basic_table_id.to_owned());
+            table.get_table_values.push(get_table_values);
+        }
+
+        // 32 = 5 * 6 + 2
+        // all remaining bits
+        const LAST_TABLE_BIT_SIZE: u64 = 32 - TABLE_BIT_SIZE * (32 / TABLE_BIT_SIZE);
+        table.slice_sizes.push(1u64 << LAST_TABLE_BIT_SIZE);
+        table.basic_table_ids.push(basic_table_id);
+        table.get_table_values.push(get_table_values);
+        table
+    }
+
+    /// The XOR of two 32-bit values, which may each overflow 32 bits by up to 3 bits. The inputs are sliced into 5 slices of 6 bits and a last slice of 5 bits, of which only the lowest 2 bits are used.
+    fn get_blake_xor_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        const TABLE_BIT_SIZE: u64 = 6;
+        let num_entries = (32 / TABLE_BIT_SIZE) as usize;
+        let base = 1u64 << TABLE_BIT_SIZE;
+        let mut table =
+            PlookupMultiTable::new(F::from(base), F::from(base), F::from(base), num_entries);
+        table.id = id;
+
+        for _ in 0..num_entries {
+            table.slice_sizes.push(base);
+            table.basic_table_ids.push(BasicTableId::BlakeXorRotate0);
+            table
+                .get_table_values
+                .push(BasicTableId::get_blake_xor_rotate_values_from_key::<F, 0, false>);
+        }
+
+        table.slice_sizes.push(1u64 << 5);
+        table
+            .basic_table_ids
+            .push(BasicTableId::BlakeXorRotate0Slice5Mod4);
+        table
+            .get_table_values
+            .push(BasicTableId::get_blake_xor_rotate_values_from_key::<F, 0, true>);
+        table
+    }
+
+    /// The XOR of two 32-bit values followed by a right rotation, where the inputs are sliced as in get_blake_xor_table. The slice containing the rotation boundary is rotated by its basic table, all other slices are moved to their rotated position by the column 3 coefficients. Since the first coefficient has to be 1, the output is the rotated value divided by 2^(32 - rotation).
+    fn get_blake_xor_rotate_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        const TABLE_BIT_SIZE: u32 = 6;
+        let (rotation, rotated_table_id, get_rotated_values): (_, _, fn([u64; 2]) -> [F; 2]) =
+            match id {
+                MultiTableId::BlakeXorRotate16 => (
+                    16,
+                    BasicTableId::BlakeXorRotate4,
+                    BasicTableId::get_blake_xor_rotate_values_from_key::<F, 4, false>,
+                ),
+                MultiTableId::BlakeXorRotate8 => (
+                    8,
+                    BasicTableId::BlakeXorRotate2,
+                    BasicTableId::get_blake_xor_rotate_values_from_key::<F, 2, false>,
+                ),
+                MultiTableId::BlakeXorRotate7 => (
+                    7,
+                    BasicTableId::BlakeXorRotate1,
+                    BasicTableId::get_blake_xor_rotate_values_from_key::<F, 1, false>,
+                ),
+                _ => unreachable!("Not a blake rotation table"),
+            };
+        let num_entries = 32 / TABLE_BIT_SIZE + 1;
+        let base = 1u64 << TABLE_BIT_SIZE;
+        let scaling_factor_inv = F::from(1u64 << (32 - rotation))
+            .inverse()
+            .expect("Non-zero");
+
+        let mut column_1_coefficients = Vec::with_capacity(num_entries as usize);
+        let mut column_3_coefficients = Vec::with_capacity(num_entries as usize);
+        for i in 0..num_entries {
+            let offset = i * TABLE_BIT_SIZE;
+            column_1_coefficients.push(F::from(1u64 << offset));
+            // Slices below the rotation are moved to the top, slices above it are moved down
+            let coefficient = if offset + TABLE_BIT_SIZE <= rotation {
+                F::from(1u64 << (offset + 32 - rotation))
+            } else if offset >= rotation {
+                F::from(1u64 << (offset - rotation))
+            } else {
+                F::one()
+            };
+            column_3_coefficients.push(coefficient * scaling_factor_inv);
+        }
+        let mut table = PlookupMultiTable::new_from_vec(
+            column_1_coefficients.to_owned(),
+            column_1_coefficients,
+            column_3_coefficients,
+        );
+        table.id = id;
+
+        for i in 0..num_entries - 1 {
+            let offset = i * TABLE_BIT_SIZE;
+            table.slice_sizes.push(base);
+            if offset < rotation && rotation < offset + TABLE_BIT_SIZE {
+                table.basic_table_ids.push(rotated_table_id.to_owned());
+                table.get_table_values.push(get_rotated_values);
+            } else {
+                table.basic_table_ids.push(BasicTableId::BlakeXorRotate0);
+                table
+                    .get_table_values
+                    .push(BasicTableId::get_blake_xor_rotate_values_from_key::<F, 0, false>);
+            }
+        }
+
+        table.slice_sizes.push(1u64 << 5);
+        table
+            .basic_table_ids
+            .push(BasicTableId::BlakeXorRotate0Slice5Mod4);
+        table
+            .get_table_values
+            .push(BasicTableId::get_blake_xor_rotate_values_from_key::<F, 0, true>);
+        table
+    }
+
+    fn get_aes_normalization_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        let num_entries = 2;
+        let slice_size = AesTables::BASE.pow(AesTables::NORMALIZATION_TABLE_DIGITS);
+        let mut table = PlookupMultiTable::new(
+            F::from(slice_size),
+            F::from(slice_size),
+            F::zero(),
+            num_entries,
+        );
+        table.id = id;
+        for _ in 0..num_entries {
+            table.slice_sizes.push(slice_size);
+            table.basic_table_ids.push(BasicTableId::AesSparseNormalize);
+            table
+                .get_table_values
+                .push(BasicTableId::get_aes_sparse_normalization_values_from_key::<F>);
+        }
+        table
+    }
+
+    fn get_aes_input_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        // The sparse bytes are not accumulated, i.e., C2[i] is the i-th least significant byte in sparse form
+        let num_entries = 16;
+        let mut table = PlookupMultiTable::new(F::from(256u64), F::zero(), F::zero(), num_entries);
+        table.id = id;
+        for _ in 0..num_entries {
+            table.slice_sizes.push(256);
+            table.basic_table_ids.push(BasicTableId::AesSparseMap);
+            table
+                .get_table_values
+                .push(BasicTableId::get_aes_sparse_values_from_key::<F>);
+        }
+        table
+    }
+
+    fn get_aes_sbox_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        let mut table = PlookupMultiTable::new(F::zero(), F::zero(), F::zero(), 1);
+        table.id = id;
+        table.slice_sizes.push(AesTables::BASE.pow(8));
+        table.basic_table_ids.push(BasicTableId::AesSboxMap);
+        table
+            .get_table_values
+            .push(BasicTableId::get_aes_sbox_values_from_key::<F>);
+        table
+    }
+
+    fn get_keccak_input_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        let table_bits = KeccakTables::FORMAT_TABLE_BITS;
+        let num_entries = (64 / table_bits) as usize;
+        let mut table = PlookupMultiTable::new(
+            F::from(1u64 << table_bits),
+            F::from(KeccakTables::BASE.pow(table_bits as u32)),
+            F::zero(),
+            num_entries,
+        );
+        table.id = id;
+        for _ in 0..num_entries {
+            table.slice_sizes.push(1u64 << table_bits);
+            table.basic_table_ids.push(BasicTableId::KeccakInput);
+            table
+                .get_table_values
+                .push(BasicTableId::get_keccak_input_values::<F>);
+        }
+        table
+    }
+
+    fn get_keccak_output_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        let table_bits = KeccakTables::FORMAT_TABLE_BITS;
+        let num_entries = (64 / table_bits) as usize;
+        let mut table = PlookupMultiTable::new(
+            F::from(KeccakTables::BASE.pow(table_bits as u32)),
+            F::from(1u64 << table_bits),
+            F::zero(),
+            num_entries,
+        );
+        table.id = id;
+        for _ in 0..num_entries {
+            table
+                .slice_sizes
+                .push(KeccakTables::BASE.pow(table_bits as u32));
+            table.basic_table_ids.push(BasicTableId::KeccakOutput);
+            table
+                .get_table_values
+                .push(BasicTableId::get_keccak_output_values::<F>);
+        }
+        table
+    }
+
+    fn get_keccak_theta_output_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        let table_bits = KeccakTables::THETA_TABLE_BITS;
+        let num_entries = 64u64.div_ceil(table_bits) as usize;
+        let column_multiplier = KeccakTables::BASE.pow(table_bits as u32);
+        let mut table = PlookupMultiTable::new(
+            F::from(column_multiplier),
+            F::from(column_multiplier),
+            F::zero(),
+            num_entries,
+        );
+        table.id = id;
+        for _ in 0..num_entries {
+            table.slice_sizes.push(column_multiplier);
+            table.basic_table_ids.push(BasicTableId::KeccakTheta);
+            table
+                .get_table_values
+                .push(BasicTableId::get_keccak_theta_values::<F>);
+        }
+        table
+    }
+
+    fn get_keccak_chi_output_table(id: MultiTableId) -> PlookupMultiTable<F> {
+        let table_bits = KeccakTables::CHI_TABLE_BITS;
+        let num_entries = 64u64.div_ceil(table_bits) as usize;
+        let column_multiplier = KeccakTables::BASE.pow(table_bits as u32);
+        let mut table = PlookupMultiTable::new(
+            F::from(column_multiplier),
+            F::from(column_multiplier),
+            F::zero(),
+            num_entries,
+        );
+        table.id = id;
+        for _ in 0..num_entries {
+            table.slice_sizes.push(column_multiplier);
+            table.basic_table_ids.push(BasicTableId::KeccakChi);
+            table
+                .get_table_values
+                .push(BasicTableId::get_keccak_chi_values::<F>);
+        }
+        table
+    }
+
+    /// The normalize and rotate table of a lane splits the lane into a right part of 64 - r digits and a left part of
+    /// r digits, where r is the rotation offset of the lane. Both parts are normalized separately, such that the
+    /// rotated lane can be recomposed from the two outputs.
+    fn get_keccak_rho_output_table(id: MultiTableId, lane_index: usize) -> PlookupMultiTable<F> {
+        let left_bits = KeccakTables::ROTATIONS[lane_index];
+        let right_bits = 64 - left_bits;
+        let max_bits = KeccakTables::RHO_MAXIMUM_MULTITABLE_BITS;
+
+        let mut table = PlookupMultiTable::default();
+        table.id = id;
+        table.column_1_step_sizes.push(F::one());
+        table.column_2_step_sizes.push(F::one());
+        table.column_3_step_sizes.push(F::one());
+
+        for part_bits in [right_bits, left_bits] {
+            let num_tables = part_bits.div_ceil(max_bits);
+            for i in 0..num_tables {
+                let bit_slice = max_bits.min(part_bits - i * max_bits);
+                let scaled_base = KeccakTables::BASE.pow(bit_slice as u32);
+                table.column_1_step_sizes.push(F::from(scaled_base));
+                // The accumulation of the output restarts at the boundary between the right and the left part
+                table.column_2_step_sizes.push(if i == num_tables - 1 {
+                    F::zero()
+                } else {
+                    F::from(scaled_base)
+                });
+                table.column_3_step_sizes.push(F::zero());
+                table.slice_sizes.push(scaled_base);
+
+                let (basic_table_id, get_table_values): (_, fn([u64; 2]) -> [F; 2]) =
+                    match bit_slice {
+                        1 => (
+                            BasicTableId::KeccakRho1,
+                            BasicTableId::get_keccak_rho_values::<F, 1>,
+                        ),
+                        2 => (
+                            BasicTableId::KeccakRho2,
+                            BasicTableId::get_keccak_rho_values::<F, 2>,
+                        ),
+                        3 => (
+                            BasicTableId::KeccakRho3,
+                            BasicTableId::get_keccak_rho_values::<F, 3>,
+                        ),
+                        4 => (
+                            BasicTableId::KeccakRho4,
+                            BasicTableId::get_keccak_rho_values::<F, 4>,
+                        ),
+                        5 => (
+                            BasicTableId::KeccakRho5,
+                            BasicTableId::get_keccak_rho_values::<F, 5>,
+                        ),
+                        6 => (
+                            BasicTableId::KeccakRho6,
+                            BasicTableId::get_keccak_rho_values::<F, 6>,
+                        ),
+                        7 => (
+                            BasicTableId::KeccakRho7,
+                            BasicTableId::get_keccak_rho_values::<F, 7>,
+                        ),
+                        8 => (
+                            BasicTableId::KeccakRho8,
+                            BasicTableId::get_keccak_rho_values::<F, 8>,
+                        ),
+                        _ => unreachable!("Rho tables have at most 8 bits"),
+                    };
+                table.basic_table_ids.push(basic_table_id);
+                table.get_table_values.push(get_table_values);
+            }
+        }
+        table
+    }
+
     fn init_multi_tables() -> [PlookupMultiTable<F>; MultiTableId::NumMultiTables as usize] {
         // TACEO TODO not all are initialized here!
         let mut multi_tables = from_fn(|_| PlookupMultiTable::default());
         multi_tables[usize::from(MultiTableId::HonkDummyMulti)] = Self::get_honk_dummy_multitable();
+        multi_tables[usize::from(MultiTableId::Sha256ChInput)] =
+            Self::get_choose_input_table(MultiTableId::Sha256ChInput);
+        multi_tables[usize::from(MultiTableId::Sha256ChOutput)] =
+            Self::get_choose_output_table(MultiTableId::Sha256ChOutput);
+        multi_tables[usize::from(MultiTableId::Sha256MajInput)] =
+            Self::get_majority_input_table(MultiTableId::Sha256MajInput);
+        multi_tables[usize::from(MultiTableId::Sha256MajOutput)] =
+            Self::get_majority_output_table(MultiTableId::Sha256MajOutput);
+        multi_tables[usize::from(MultiTableId::Sha256WitnessInput)] =
+            Self::get_witness_extension_input_table(MultiTableId::Sha256WitnessInput);
+        multi_tables[usize::from(MultiTableId::Sha256WitnessOutput)] =
+            Self::get_witness_extension_output_table(MultiTableId::Sha256WitnessOutput);
+        multi_tables[usize::from(MultiTableId::Uint32Xor)] =
+            Self::get_uint32_table(MultiTableId::Uint32Xor);
+        multi_tables[usize::from(MultiTableId::Uint32And)] =
+            Self::get_uint32_table(MultiTableId::Uint32And);
+        multi_tables[usize::from(MultiTableId::AesNormalize)] =
+            Self::get_aes_normalization_table(MultiTableId::AesNormalize);
+        multi_tables[usize::from(MultiTableId::AesInput)] =
+            Self::get_aes_input_table(MultiTableId::AesInput);
+        multi_tables[usize::from(MultiTableId::AesSbox)] =
+            Self::get_aes_sbox_table(MultiTableId::AesSbox);
+        multi_tables[usize::from(MultiTableId::KeccakFormatInput)] =
+            Self::get_keccak_input_table(MultiTableId::KeccakFormatInput);
+        multi_tables[usize::from(MultiTableId::KeccakFormatOutput)] =
+            Self::get_keccak_output_table(MultiTableId::KeccakFormatOutput);
+        multi_tables[usize::from(MultiTableId::KeccakThetaOutput)] =
+            Self::get_keccak_theta_output_table(MultiTableId::KeccakThetaOutput);
+        multi_tables[usize::from(MultiTableId::KeccakChiOutput)] =
+            Self::get_keccak_chi_output_table(MultiTableId::KeccakChiOutput);
+        multi_tables[usize::from(MultiTableId::BlakeXor)] =
+            Self::get_blake_xor_table(MultiTableId::BlakeXor);
+        multi_tables[usize::from(MultiTableId::BlakeXorRotate16)] =
+            Self::get_blake_xor_rotate_table(MultiTableId::BlakeXorRotate16);
+        multi_tables[usize::from(MultiTableId::BlakeXorRotate8)] =
+            Self::get_blake_xor_rotate_table(MultiTableId::BlakeXorRotate8);
+        multi_tables[usize::from(MultiTableId::BlakeXorRotate7)] =
+            Self::get_blake_xor_rotate_table(MultiTableId::BlakeXorRotate7);
+        multi_tables[usize::from(MultiTableId::FixedBaseLeftLo)] =
+            Self::get_fixed_base_table::<0>(MultiTableId::FixedBaseLeftLo);
+        multi_tables[usize::from(MultiTableId::FixedBaseLeftHi)] =
+            Self::get_fixed_base_table::<1>(MultiTableId::FixedBaseLeftHi);
+        multi_tables[usize::from(MultiTableId::FixedBaseRightLo)] =
+            Self::get_fixed_base_table::<2>(MultiTableId::FixedBaseRightLo);
+        multi_tables[usize::from(MultiTableId::FixedBaseRightHi)] =
+            Self::get_fixed_base_table::<3>(MultiTableId::FixedBaseRightHi);
+        for (lane_index, id) in KeccakTables::NORMALIZE_AND_ROTATE.into_iter().enumerate() {
+            multi_tables[usize::from(id.to_owned())] =
+                Self::get_keccak_rho_output_table(id, lane_index);
+        }
         multi_tables
     }
 
     pub(crate) fn get_multitable(&self, id: MultiTableId) -> &PlookupMultiTable<F> {
-        assert_eq!(id, MultiTableId::HonkDummyMulti); // The only one implemented so far
+        // The only ones implemented so far
+        assert!(
+            matches!(
+                id,
+                MultiTableId::HonkDummyMulti
+                    | MultiTableId::Sha256ChInput
+                    | MultiTableId::Sha256ChOutput
+                    | MultiTableId::Sha256MajInput
+                    | MultiTableId::Sha256MajOutput
+                    | MultiTableId::Sha256WitnessInput
+                    | MultiTableId::Sha256WitnessOutput
+                    | MultiTableId::Uint32Xor
+                    | MultiTableId::Uint32And
+                    | MultiTableId::AesNormalize
+                    | MultiTableId::AesInput
+                    | MultiTableId::AesSbox
+                    | MultiTableId::KeccakFormatInput
+                    | MultiTableId::KeccakFormatOutput
+                    | MultiTableId::KeccakThetaOutput
+                    | MultiTableId::KeccakChiOutput
+                    | MultiTableId::BlakeXor
+                    | MultiTableId::BlakeXorRotate16
+                    | MultiTableId::BlakeXorRotate8
+                    | MultiTableId::BlakeXorRotate7
+                    | MultiTableId::FixedBaseLeftLo
+                    | MultiTableId::FixedBaseLeftHi
+                    | MultiTableId::FixedBaseRightLo
+                    | MultiTableId::FixedBaseRightHi
+            ) || KeccakTables::NORMALIZE_AND_ROTATE.contains(&id)
+        );
         &self.multi_tables[usize::from(id)]
     }
 
@@ -359,4 +1659,296 @@ impl<F: PrimeField> Plookup<F> {
         }
         lookup
     }
+
+    fn get_lookup_accumulators_acvm<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+        id: MultiTableId,
+        key_a: T::AcvmType,
+        key_b: T::AcvmType,
+        is_2_to_1_lookup: bool,
+    ) -> HonkProofResult<ReadData<T::AcvmType>> {
+        if let (Some(key_a), Some(key_b)) = (T::get_public(&key_a), T::get_public(&key_b)) {
+            let lookup =
+                builder
+                    .plookup
+                    .get_lookup_accumulators(id, key_a, key_b, is_2_to_1_lookup);
+            return Ok(lookup.into_acvm_type());
+        }
+
+        // A key is shared, so we can neither slice it in the clear nor record the accessed table rows in
+        // lookup_entries. Instead, we slice the keys in MPC, read the table values with a shared one-hot vector and
+        // accumulate the one-hot vectors as shared read counts for the proving key.
+        let multi_table = builder.plookup.get_multitable(id);
+        let num_lookups = multi_table.basic_table_ids.len();
+        let slice_sizes = multi_table.slice_sizes.to_owned();
+        let basic_table_ids = multi_table.basic_table_ids.to_owned();
+        let step_sizes = [
+            multi_table.column_1_step_sizes.to_owned(),
+            multi_table.column_2_step_sizes.to_owned(),
+            multi_table.column_3_step_sizes.to_owned(),
+        ];
+
+        let max_key = slice_sizes
+            .iter()
+            .fold(BigUint::one(), |acc, size| acc * *size)
+            - BigUint::one();
+
+        // The rows of tables with sparse keys are not indexed by the key itself, but by its digits. Hence, we slice
+        // these keys further into their digits.
+        let mut key_a_bases = Vec::with_capacity(num_lookups);
+        for (slice_size, basic_table_id) in slice_sizes.iter().zip(basic_table_ids.iter()) {
+            match basic_table_id.sparse_key_bases() {
+                Some((base, _)) => {
+                    key_a_bases.extend(vec![base; slice_size.ilog(base) as usize]);
+                }
+                None => key_a_bases.push(*slice_size),
+            }
+        }
+        let mut key_a_digits = driver
+            .slice_variable_bases(key_a, max_key.bits() as usize, &key_a_bases)?
+            .into_iter();
+        let key_b_slices = if is_2_to_1_lookup {
+            driver.slice_variable_bases(key_b, max_key.bits() as usize, &slice_sizes)?
+        } else {
+            vec![T::public_zero(); num_lookups]
+        };
+
+        let mut column_1_raw_values = Vec::with_capacity(num_lookups);
+        let mut column_2_raw_values = Vec::with_capacity(num_lookups);
+        let mut column_3_raw_values = Vec::with_capacity(num_lookups);
+
+        for ((slice_size, slice_b), basic_table_id) in slice_sizes
+            .into_iter()
+            .zip(key_b_slices)
+            .zip(basic_table_ids)
+        {
+            let sparse_key_bases = basic_table_id.sparse_key_bases();
+            let table = builder.get_table(basic_table_id);
+            let table_index = table.table_index;
+            let table_size = table.len();
+
+            let (slice_a, index) = if let Some((base, effective_base)) = sparse_key_bases {
+                // The rows are ordered by the digits of the key, hence the row index of the key sum_j d_j * base^j is
+                // sum_j d_j * effective_base^j.
+                let digits = key_a_digits
+                    .by_ref()
+                    .take(slice_size.ilog(base) as usize)
+                    .collect::<Vec<_>>();
+                let mut slice_a = T::public_zero();
+                let mut index = T::public_zero();
+                for digit in digits.into_iter().rev() {
+                    let shifted = driver.acvm_mul_with_public(F::from(base), slice_a);
+                    slice_a = driver.add(shifted, digit.to_owned());
+                    let shifted = driver.acvm_mul_with_public(F::from(effective_base), index);
+                    index = driver.add(shifted, digit);
+                }
+                (slice_a, index)
+            } else {
+                let slice_a = key_a_digits.next().expect("One slice per basic table");
+                // The row index of an entry is equal to its key. Tables with twin keys are square and ordered by
+                // (key_a, key_b), hence the row index is key_a * sqrt(table_size) + key_b.
+                let index = if table.use_twin_keys {
+                    let base = F::from(1u64 << (table_size.ilog2() / 2));
+                    let shifted = driver.acvm_mul_with_public(base, slice_a.to_owned());
+                    driver.add(shifted, slice_b)
+                } else {
+                    slice_a.to_owned()
+                };
+                (slice_a, index)
+            };
+            column_1_raw_values.push(slice_a);
+
+            let (values, ohv) = if let Some(index) = T::get_public(&index) {
+                let index: BigUint = index.into();
+                let index: usize = index.try_into().expect("Invalid index");
+                let values = [
+                    T::AcvmType::from(table.column_2[index]),
+                    T::AcvmType::from(table.column_3[index]),
+                ];
+                let mut ohv = vec![T::public_zero(); table_size];
+                ohv[index] = T::AcvmType::from(F::one());
+                (values, ohv)
+            } else {
+                let index = T::get_shared(&index).expect("Not public, thus shared");
+                let ohv = driver
+                    .one_hot_vector_from_shared_index(index, table_size)?
+                    .into_iter()
+                    .map(T::AcvmType::from)
+                    .collect::<Vec<_>>();
+
+                let mut values = [T::public_zero(), T::public_zero()];
+                for (des, column) in values.iter_mut().zip([&table.column_2, &table.column_3]) {
+                    for (ohv_j, column_j) in ohv.iter().zip(column.iter()) {
+                        if column_j.is_zero() {
+                            continue;
+                        }
+                        let tmp = driver.acvm_mul_with_public(*column_j, ohv_j.to_owned());
+                        *des = driver.add(des.to_owned(), tmp);
+                    }
+                }
+                (values, ohv)
+            };
+
+            let counts = builder
+                .shared_lookup_read_counts
+                .entry(table_index)
+                .or_insert_with(|| vec![T::public_zero(); table_size]);
+            for (count, ohv_j) in counts.iter_mut().zip(ohv) {
+                *count = driver.add(count.to_owned(), ohv_j);
+            }
+
+            let [value_2, value_3] = values;
+            column_2_raw_values.push(value_2);
+            column_3_raw_values.push(value_3);
+        }
+
+        // Compute the accumulating sums of the slices as in get_lookup_accumulators
+        let mut lookup = ReadData::default();
+        for (column, (raw_values, step_sizes)) in [ColumnIdx::C1, ColumnIdx::C2, ColumnIdx::C3]
+            .into_iter()
+            .zip([
+                (column_1_raw_values, &step_sizes[0]),
+                (column_2_raw_values, &step_sizes[1]),
+                (column_3_raw_values, &step_sizes[2]),
+            ])
+        {
+            let mut accumulators = raw_values;
+            for i in (1..num_lookups).rev() {
+                let tmp = driver.acvm_mul_with_public(step_sizes[i], accumulators[i].to_owned());
+                accumulators[i - 1] = driver.add(accumulators[i - 1].to_owned(), tmp);
+            }
+            lookup[column] = accumulators;
+        }
+        Ok(lookup)
+    }
+
+    pub(crate) fn get_lookup_accumulators_ct<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+        id: MultiTableId,
+        key_a_in: &FieldCT<F>,
+        key_b_in: &FieldCT<F>,
+        is_2_to_1_lookup: bool,
+    ) -> HonkProofResult<ReadData<FieldCT<F>>> {
+        let key_a = key_a_in.normalize(builder, driver);
+        let key_b = key_b_in.normalize(builder, driver);
+        let key_a_value = key_a.get_value(builder, driver);
+        let key_b_value = key_b.get_value(builder, driver);
+
+        let lookup_data = Self::get_lookup_accumulators_acvm(
+            builder,
+            driver,
+            id.to_owned(),
+            key_a_value,
+            key_b_value,
+            is_2_to_1_lookup,
+        )?;
+
+        let is_key_a_constant = key_a.is_constant();
+        let num_lookups = lookup_data[ColumnIdx::C1].len();
+        let mut lookup = ReadData::default();
+
+        if is_key_a_constant && (key_b.is_constant() || !is_2_to_1_lookup) {
+            for column in [ColumnIdx::C1, ColumnIdx::C2, ColumnIdx::C3] {
+                for value in lookup_data[column.to_owned()].iter() {
+                    let value = T::get_public(value).expect("Constant keys give public values");
+                    lookup[column.to_owned()].push(FieldCT::from_field(value));
+                }
+            }
+        } else {
+            let mut lhs_index = key_a.witness_index;
+            let mut rhs_index = key_b.witness_index;
+            // If only one lookup key is constant, we need to instantiate it as a real witness
+            if is_key_a_constant {
+                lhs_index = builder.put_constant_variable(key_a.additive_constant);
+            }
+            if key_b.is_constant() && is_2_to_1_lookup {
+                rhs_index = builder.put_constant_variable(key_b.additive_constant);
+            }
+
+            let key_b_witness = if rhs_index == FieldCT::<F>::IS_CONSTANT {
+                None
+            } else {
+                Some(rhs_index)
+            };
+
+            let accumulator_witnesses = builder.create_gates_from_plookup_accumulators(
+                id,
+                lookup_data,
+                lhs_index,
+                key_b_witness,
+            );
+
+            for i in 0..num_lookups {
+                lookup[ColumnIdx::C1].push(FieldCT::from_witness_index(
+                    accumulator_witnesses[ColumnIdx::C1][i],
+                ));
+                lookup[ColumnIdx::C2].push(FieldCT::from_witness_index(
+                    accumulator_witnesses[ColumnIdx::C2][i],
+                ));
+                lookup[ColumnIdx::C3].push(FieldCT::from_witness_index(
+                    accumulator_witnesses[ColumnIdx::C3][i],
+                ));
+            }
+        }
+        Ok(lookup)
+    }
+
+    pub(crate) fn read_from_1_to_2_table<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+        id: MultiTableId,
+        key_a: &FieldCT<F>,
+    ) -> HonkProofResult<FieldCT<F>> {
+        let lookup = Self::get_lookup_accumulators_ct(
+            builder,
+            driver,
+            id,
+            key_a,
+            &FieldCT::default(),
+            false,
+        )?;
+        Ok(lookup[ColumnIdx::C2][0].to_owned())
+    }
+
+    pub(crate) fn read_from_2_to_1_table<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+        id: MultiTableId,
+        key_a: &FieldCT<F>,
+        key_b: &FieldCT<F>,
+    ) -> HonkProofResult<FieldCT<F>> {
+        let lookup = Self::get_lookup_accumulators_ct(builder, driver, id, key_a, key_b, true)?;
+        Ok(lookup[ColumnIdx::C3][0].to_owned())
+    }
+
+    pub(crate) fn read_pair_from_table<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+        id: MultiTableId,
+        key: &FieldCT<F>,
+    ) -> HonkProofResult<(FieldCT<F>, FieldCT<F>)> {
+        let lookup =
+            Self::get_lookup_accumulators_ct(builder, driver, id, key, &FieldCT::default(), false)?;
+        Ok((
+            lookup[ColumnIdx::C2][0].to_owned(),
+            lookup[ColumnIdx::C3][0].to_owned(),
+        ))
+    }
 }
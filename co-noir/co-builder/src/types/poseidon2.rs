@@ -0,0 +1,224 @@
+use super::types::{AddQuad, FieldCT, Poseidon2ExternalGate, Poseidon2InternalGate};
+use crate::{builder::GenericUltraCircuitBuilder, HonkProofResult};
+use ark_ec::pairing::Pairing;
+use ark_ff::{One, Zero};
+use co_acvm::mpc::NoirWitnessExtensionProtocol;
+use mpc_core::gadgets::poseidon2::Poseidon2;
+
+/// In-circuit version of the external matrix multiplication, which is applied once before the first round. We
+/// need 6 arithmetic gates to compute M_E * state, the resulting state is written into new witnesses.
+fn matrix_multiplication_external<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    state: &mut [u32; 4],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) {
+    let one = P::ScalarField::one();
+    let two = P::ScalarField::from(2u64);
+    let four = P::ScalarField::from(4u64);
+    let s = state.map(|idx| builder.get_variable(idx as usize));
+
+    // gate 1: Compute tmp1 = state[0] + state[1] + 2 * state[3]
+    let tmp = driver.add(s[0].to_owned(), s[1].to_owned());
+    let scaled = driver.acvm_mul_with_public(two, s[3].to_owned());
+    let tmp1_value = driver.add(tmp, scaled);
+    let tmp1 = builder.add_variable(tmp1_value.to_owned());
+    builder.create_big_add_gate(
+        &AddQuad {
+            a: state[0],
+            b: state[1],
+            c: state[3],
+            d: tmp1,
+            a_scaling: one,
+            b_scaling: one,
+            c_scaling: two,
+            d_scaling: -one,
+            const_scaling: P::ScalarField::zero(),
+        },
+        false,
+    );
+
+    // gate 2: Compute tmp2 = 2 * state[1] + state[2] + state[3]
+    let scaled = driver.acvm_mul_with_public(two, s[1].to_owned());
+    let tmp = driver.add(scaled, s[2].to_owned());
+    let tmp2_value = driver.add(tmp, s[3].to_owned());
+    let tmp2 = builder.add_variable(tmp2_value.to_owned());
+    builder.create_big_add_gate(
+        &AddQuad {
+            a: state[1],
+            b: state[2],
+            c: state[3],
+            d: tmp2,
+            a_scaling: two,
+            b_scaling: one,
+            c_scaling: one,
+            d_scaling: -one,
+            const_scaling: P::ScalarField::zero(),
+        },
+        false,
+    );
+
+    // gate 3: Compute v2 = 4 * state[0] + 4 * state[1] + tmp2
+    let tmp = driver.add(s[0].to_owned(), s[1].to_owned());
+    let scaled = driver.acvm_mul_with_public(four, tmp);
+    let v2_value = driver.add(scaled, tmp2_value.to_owned());
+    let v2 = builder.add_variable(v2_value.to_owned());
+    builder.create_big_add_gate(
+        &AddQuad {
+            a: state[0],
+            b: state[1],
+            c: tmp2,
+            d: v2,
+            a_scaling: four,
+            b_scaling: four,
+            c_scaling: one,
+            d_scaling: -one,
+            const_scaling: P::ScalarField::zero(),
+        },
+        false,
+    );
+
+    // gate 4: Compute v1 = v2 + tmp1
+    let v1_value = driver.add(v2_value, tmp1_value.to_owned());
+    let v1 = builder.add_variable(v1_value);
+    builder.create_big_add_gate(
+        &AddQuad {
+            a: v2,
+            b: tmp1,
+            c: v1,
+            d: builder.zero_idx,
+            a_scaling: one,
+            b_scaling: one,
+            c_scaling: -one,
+            d_scaling: P::ScalarField::zero(),
+            const_scaling: P::ScalarField::zero(),
+        },
+        false,
+    );
+
+    // gate 5: Compute v4 = tmp1 + 4 * state[2] + 4 * state[3]
+    let tmp = driver.add(s[2].to_owned(), s[3].to_owned());
+    let scaled = driver.acvm_mul_with_public(four, tmp);
+    let v4_value = driver.add(tmp1_value, scaled);
+    let v4 = builder.add_variable(v4_value.to_owned());
+    builder.create_big_add_gate(
+        &AddQuad {
+            a: tmp1,
+            b: state[2],
+            c: state[3],
+            d: v4,
+            a_scaling: one,
+            b_scaling: four,
+            c_scaling: four,
+            d_scaling: -one,
+            const_scaling: P::ScalarField::zero(),
+        },
+        false,
+    );
+
+    // gate 6: Compute v3 = v4 + tmp2
+    let v3_value = driver.add(v4_value, tmp2_value);
+    let v3 = builder.add_variable(v3_value);
+    builder.create_big_add_gate(
+        &AddQuad {
+            a: v4,
+            b: tmp2,
+            c: v3,
+            d: builder.zero_idx,
+            a_scaling: one,
+            b_scaling: one,
+            c_scaling: -one,
+            d_scaling: P::ScalarField::zero(),
+            const_scaling: P::ScalarField::zero(),
+        },
+        false,
+    );
+
+    *state = [v1, v2, v3, v4];
+}
+
+/// Applies the Poseidon2 permutation (t = 4, d = 5) to the given state in-circuit. Each round is represented by a
+/// single Poseidon2 gate, whose output is read from the next row via the shifted wires. The new state after each
+/// round is computed by the driver, such that the S-boxes on shared values are evaluated in MPC.
+pub(crate) fn poseidon2_permutation<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &[FieldCT<P::ScalarField>; 4],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<[FieldCT<P::ScalarField>; 4]> {
+    let poseidon2 = Poseidon2::<P::ScalarField, 4, 5>::default();
+    let params = poseidon2.params;
+
+    // The gates operate on witnesses, so constants are converted to fixed witnesses first
+    let mut state = input.each_ref().map(|inp| {
+        if inp.is_constant() {
+            builder.put_constant_variable(inp.additive_constant)
+        } else {
+            inp.normalize(builder, driver).witness_index
+        }
+    });
+
+    // Apply 1st linear layer
+    matrix_multiplication_external(&mut state, builder, driver);
+    let mut native_state = state.map(|idx| builder.get_variable(idx as usize));
+
+    // First set of external rounds
+    for r in 0..params.rounds_f_beginning {
+        builder.create_poseidon2_external_gate(&Poseidon2ExternalGate {
+            a: state[0],
+            b: state[1],
+            c: state[2],
+            d: state[3],
+            round_constants: params.round_constants_external[r],
+        });
+        // calculate the new witnesses
+        driver.poseidon2_external_round_inplace(&mut native_state, r, &poseidon2)?;
+        state = native_state
+            .each_ref()
+            .map(|value| builder.add_variable(value.to_owned()));
+    }
+
+    // Internal rounds
+    for r in 0..params.rounds_p {
+        builder.create_poseidon2_internal_gate(&Poseidon2InternalGate {
+            a: state[0],
+            b: state[1],
+            c: state[2],
+            d: state[3],
+            round_constant: params.round_constants_internal[r],
+        });
+        driver.poseidon2_internal_round_inplace(&mut native_state, r, &poseidon2)?;
+        state = native_state
+            .each_ref()
+            .map(|value| builder.add_variable(value.to_owned()));
+    }
+
+    // Remaining external rounds
+    for r in params.rounds_f_beginning..params.rounds_f_beginning + params.rounds_f_end {
+        builder.create_poseidon2_external_gate(&Poseidon2ExternalGate {
+            a: state[0],
+            b: state[1],
+            c: state[2],
+            d: state[3],
+            round_constants: params.round_constants_external[r],
+        });
+        driver.poseidon2_external_round_inplace(&mut native_state, r, &poseidon2)?;
+        state = native_state
+            .each_ref()
+            .map(|value| builder.add_variable(value.to_owned()));
+    }
+
+    // The Poseidon2 permutation has 64 rounds, but needs to be a block of 65 rows, since the result of applying a
+    // round is stored in the next row (the shifted row). As a result, we need this end row to compare with the
+    // result of the last round. It does not activate any selectors since it only serves as a comparison through the
+    // shifted wires.
+    GenericUltraCircuitBuilder::<P, T>::create_dummy_gate(
+        &mut builder.blocks.poseidon2_external,
+        state[0],
+        state[1],
+        state[2],
+        state[3],
+    );
+    builder.check_selector_length_consistency();
+    builder.num_gates += 1; // necessary because create dummy gate cannot increment num_gates itself
+
+    Ok(state.map(FieldCT::from_witness_index))
+}
@@ -0,0 +1,118 @@
+use super::{
+    blake,
+    cycle_group::{validate_limbs_less_than, CycleGroup},
+    pedersen,
+    sha256::scale,
+    types::FieldCT,
+};
+use crate::{builder::GenericUltraCircuitBuilder, HonkProofResult};
+use ark_ec::pairing::Pairing;
+use ark_ff::{One, PrimeField, Zero};
+use co_acvm::mpc::NoirWitnessExtensionProtocol;
+use mpc_core::gadgets::pedersen::grumpkin_generator;
+use num_bigint::BigUint;
+
+/// The number of bytes of a limb of the signature scalars and of the hash output.
+const LIMB_BYTES: usize = 16;
+
+/// Composes big-endian bytes into a field element.
+fn compose_be_bytes<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    bytes: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    let mut result = FieldCT::from_field(P::ScalarField::zero());
+    for byte in bytes {
+        result = scale(&result, P::ScalarField::from(256u64), builder, driver)?
+            .add(byte, builder, driver);
+    }
+    Ok(result)
+}
+
+/// Decomposes a field element into its 32 big-endian bytes. The bytes are constrained to represent an integer smaller than the modulus, such that the decomposition is unique.
+fn field_to_be_bytes<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    let value = input.get_value(builder, driver);
+    let num_bits = P::ScalarField::MODULUS_BIT_SIZE as usize;
+    let mut decomposed = driver.decompose(value, num_bits, 8)?;
+    decomposed.resize(2 * LIMB_BYTES, T::public_zero());
+
+    let mut bytes = Vec::with_capacity(2 * LIMB_BYTES);
+    for byte in decomposed.into_iter().rev() {
+        let byte = FieldCT::from_witness(byte, builder);
+        byte.create_range_constraint(8, builder, driver)?;
+        bytes.push(byte);
+    }
+
+    let hi = compose_be_bytes(&bytes[..LIMB_BYTES], builder, driver)?;
+    let lo = compose_be_bytes(&bytes[LIMB_BYTES..], builder, driver)?;
+    let shift = P::ScalarField::from(BigUint::one() << (8 * LIMB_BYTES));
+    lo.add(&scale(&hi, shift, builder, driver)?, builder, driver)
+        .assert_equal(input, builder, driver);
+    validate_limbs_less_than(&lo, &hi, P::ScalarField::MODULUS.into(), builder, driver)?;
+    Ok(bytes)
+}
+
+/// Verifies a Schnorr signature over the embedded curve (Grumpkin) in-circuit and returns the result as a boolean.
+///
+/// The signature consists of the 32 big-endian bytes of s followed by the 32 big-endian bytes of e, and the message is given as bytes.
+/// Following barretenberg, the signature is valid if e equals blake2s(pedersen_hash(R.x, pk.x, pk.y) || message) for R = s * G + e * pk, where s and e are used as 256-bit strings.
+/// Public keys not on the curve make the circuit unsatisfiable.
+pub(crate) fn verify_signature<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    public_key_x: &FieldCT<P::ScalarField>,
+    public_key_y: &FieldCT<P::ScalarField>,
+    signature: &[FieldCT<P::ScalarField>],
+    message: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    assert_eq!(signature.len(), 4 * LIMB_BYTES);
+    for byte in signature.iter().chain(message.iter()) {
+        byte.create_range_constraint(8, builder, driver)?;
+    }
+    let s_hi = compose_be_bytes(&signature[..LIMB_BYTES], builder, driver)?;
+    let s_lo = compose_be_bytes(&signature[LIMB_BYTES..2 * LIMB_BYTES], builder, driver)?;
+    let e_hi = compose_be_bytes(&signature[2 * LIMB_BYTES..3 * LIMB_BYTES], builder, driver)?;
+    let e_lo = compose_be_bytes(&signature[3 * LIMB_BYTES..], builder, driver)?;
+
+    let public_key = CycleGroup::new(
+        public_key_x.to_owned(),
+        public_key_y.to_owned(),
+        FieldCT::from_field(P::ScalarField::zero()),
+    );
+    public_key.validate_is_on_curve(builder, driver)?;
+    let generator = CycleGroup::from_native(Some(grumpkin_generator()));
+
+    // R = s * G + e * pk
+    let r = CycleGroup::batch_mul_bitstring(
+        &[generator, public_key],
+        &[(s_lo, s_hi), (e_lo.to_owned(), e_hi.to_owned())],
+        builder,
+        driver,
+    )?;
+
+    // Hashing the coordinates with Pedersen first keeps the preimage of blake2s small
+    let compressed = pedersen::hash(
+        &[r.x, public_key_x.to_owned(), public_key_y.to_owned()],
+        0,
+        builder,
+        driver,
+    )?;
+    let mut hash_input = field_to_be_bytes(&compressed, builder, driver)?;
+    hash_input.extend_from_slice(message);
+    let output = blake::blake2s(&hash_input, builder, driver)?;
+
+    // The signature is valid if the hash output equals e
+    let output_hi = compose_be_bytes(&output[..LIMB_BYTES], builder, driver)?;
+    let output_lo = compose_be_bytes(&output[LIMB_BYTES..], builder, driver)?;
+    let hi_equal = output_hi
+        .sub(&e_hi, builder, driver)
+        .is_zero(builder, driver)?;
+    let lo_equal = output_lo
+        .sub(&e_lo, builder, driver)
+        .is_zero(builder, driver)?;
+    lo_equal.multiply(&hi_equal, builder, driver)
+}
@@ -0,0 +1,494 @@
+use super::{
+    plookup::{MultiTableId, Plookup, Sha256Tables},
+    types::{ColumnIdx, FieldCT},
+};
+use crate::{builder::GenericUltraCircuitBuilder, HonkProofResult};
+use ark_ec::pairing::Pairing;
+use ark_ff::{Field, PrimeField, Zero};
+use co_acvm::mpc::NoirWitnessExtensionProtocol;
+
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const INIT_CONSTANTS: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+#[derive(Clone, Default)]
+struct SparseValue<F: PrimeField> {
+    normal: FieldCT<F>,
+    sparse: FieldCT<F>,
+}
+
+impl<F: PrimeField> SparseValue<F> {
+    fn new(normal: FieldCT<F>) -> Self {
+        Self {
+            normal,
+            sparse: FieldCT::default(),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct SparseWitnessLimbs<F: PrimeField> {
+    normal: FieldCT<F>,
+    sparse_limbs: [FieldCT<F>; 4],
+    rotated_limbs: [FieldCT<F>; 4],
+    has_sparse_limbs: bool,
+}
+
+impl<F: PrimeField> SparseWitnessLimbs<F> {
+    fn new(normal: FieldCT<F>) -> Self {
+        Self {
+            normal,
+            ..Default::default()
+        }
+    }
+}
+
+pub(crate) fn scale<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    value: &FieldCT<P::ScalarField>,
+    scalar: P::ScalarField,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    // Multiplication with a constant does not add a gate
+    value.multiply(&FieldCT::from_field(scalar), builder, driver)
+}
+
+fn convert_witness<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    w: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<SparseWitnessLimbs<P::ScalarField>> {
+    let lookup = Plookup::get_lookup_accumulators_ct(
+        builder,
+        driver,
+        MultiTableId::Sha256WitnessInput,
+        w,
+        &FieldCT::default(),
+        false,
+    )?;
+
+    Ok(SparseWitnessLimbs {
+        normal: w.to_owned(),
+        sparse_limbs: std::array::from_fn(|i| lookup[ColumnIdx::C2][i].to_owned()),
+        rotated_limbs: std::array::from_fn(|i| lookup[ColumnIdx::C3][i].to_owned()),
+        has_sparse_limbs: true,
+    })
+}
+
+fn extend_witness<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    w_in: &[FieldCT<P::ScalarField>; 16],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<[FieldCT<P::ScalarField>; 64]> {
+    let base = P::ScalarField::from(16u64);
+    let one = P::ScalarField::from(1u64);
+
+    // Multipliers to compute the rotations (sigma_0 and sigma_1) of the sparse limbs
+    let left_multipliers = [
+        base.pow([32 - 7]) + base.pow([32 - 18]),
+        base.pow([32 - 18 + 3]) + one,
+        base.pow([32 - 18 + 10]) + base.pow([10 - 7]) + base.pow([10 - 3]),
+        base.pow([18 - 7]) + base.pow([18 - 3]) + one,
+    ];
+    let right_multipliers = [
+        base.pow([32 - 17]) + base.pow([32 - 19]),
+        base.pow([32 - 17 + 3]) + base.pow([32 - 19 + 3]),
+        base.pow([32 - 19 + 10]) + one,
+        base.pow([18 - 17]) + base.pow([18 - 10]),
+    ];
+    let inv_pow_two = P::ScalarField::from(1u64 << 32)
+        .inverse()
+        .expect("2^32 is invertible");
+
+    let mut w_sparse: Vec<SparseWitnessLimbs<P::ScalarField>> = w_in
+        .iter()
+        .map(|w| SparseWitnessLimbs::new(w.to_owned()))
+        .collect();
+    w_sparse.resize(64, SparseWitnessLimbs::default());
+
+    for i in 16..64 {
+        if !w_sparse[i - 15].has_sparse_limbs {
+            w_sparse[i - 15] = convert_witness(&w_sparse[i - 15].normal, builder, driver)?;
+        }
+        if !w_sparse[i - 2].has_sparse_limbs {
+            w_sparse[i - 2] = convert_witness(&w_sparse[i - 2].normal, builder, driver)?;
+        }
+        let w_left = w_sparse[i - 15].to_owned();
+        let w_right = w_sparse[i - 2].to_owned();
+
+        let mut left = Vec::with_capacity(4);
+        let mut right = Vec::with_capacity(4);
+        for j in 0..4 {
+            left.push(scale(
+                &w_left.sparse_limbs[j],
+                left_multipliers[j],
+                builder,
+                driver,
+            )?);
+            right.push(scale(
+                &w_right.sparse_limbs[j],
+                right_multipliers[j],
+                builder,
+                driver,
+            )?);
+        }
+
+        let left_xor_sparse = left[0]
+            .add_two(&left[1], &left[2], builder, driver)
+            .add_two(&left[3], &w_left.rotated_limbs[1], builder, driver);
+        let left_xor_sparse = scale(
+            &left_xor_sparse,
+            P::ScalarField::from(4u64),
+            builder,
+            driver,
+        )?;
+
+        let xor_result_sparse = right[0]
+            .add_two(&right[1], &right[2], builder, driver)
+            .add_two(&right[3], &w_right.rotated_limbs[2], builder, driver)
+            .add_two(&w_right.rotated_limbs[3], &left_xor_sparse, builder, driver)
+            .normalize(builder, driver);
+
+        let xor_result = Plookup::read_from_1_to_2_table(
+            builder,
+            driver,
+            MultiTableId::Sha256WitnessOutput,
+            &xor_result_sparse,
+        )?;
+
+        let w_out_raw = xor_result.add_two(
+            &w_sparse[i - 16].normal,
+            &w_sparse[i - 7].normal,
+            builder,
+            driver,
+        );
+
+        let w_out = if w_out_raw.is_constant() {
+            let raw: num_bigint::BigUint = w_out_raw.additive_constant.into();
+            let truncated = raw.iter_u32_digits().next().unwrap_or_default();
+            FieldCT::from_field(P::ScalarField::from(truncated))
+        } else {
+            // The sum of three 32-bit values fits into 34 bits
+            let raw = w_out_raw.get_value(builder, driver);
+            let truncated = driver.decompose(raw, 34, 32)?.swap_remove(0);
+            let w_out = FieldCT::from_witness(truncated, builder);
+
+            let w_out_raw_inv_pow_two = scale(&w_out_raw, inv_pow_two, builder, driver)?;
+            let w_out_inv_pow_two = scale(&w_out, inv_pow_two, builder, driver)?;
+            let divisor = w_out_raw_inv_pow_two
+                .sub(&w_out_inv_pow_two, builder, driver)
+                .normalize(builder, driver);
+            builder.create_new_range_constraint(divisor.witness_index, 3);
+            w_out
+        };
+        w_sparse[i] = SparseWitnessLimbs::new(w_out);
+    }
+
+    Ok(std::array::from_fn(|i| w_sparse[i].normal.to_owned()))
+}
+
+fn choose<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    e: &mut SparseValue<P::ScalarField>,
+    f: &SparseValue<P::ScalarField>,
+    g: &SparseValue<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    let lookup = Plookup::get_lookup_accumulators_ct(
+        builder,
+        driver,
+        MultiTableId::Sha256ChInput,
+        &e.normal,
+        &FieldCT::default(),
+        false,
+    )?;
+    let rotation_coefficients = Sha256Tables::get_choose_rotation_multipliers::<P::ScalarField>();
+    let seven = P::ScalarField::from(7u64);
+
+    let rotation_result = lookup[ColumnIdx::C3][0].to_owned();
+    e.sparse = lookup[ColumnIdx::C2][0].to_owned();
+    let sparse_l2 = lookup[ColumnIdx::C2][2].to_owned();
+
+    let lhs = scale(&rotation_result, seven, builder, driver)?;
+    let mid = scale(
+        &e.sparse,
+        rotation_coefficients[0] * seven + P::ScalarField::from(1u64),
+        builder,
+        driver,
+    )?;
+    let rhs = scale(
+        &sparse_l2,
+        rotation_coefficients[2] * seven,
+        builder,
+        driver,
+    )?;
+    let xor_result = lhs.add_two(&mid, &rhs, builder, driver);
+
+    let f_sparse = scale(&f.sparse, P::ScalarField::from(2u64), builder, driver)?;
+    let g_sparse = scale(&g.sparse, P::ScalarField::from(3u64), builder, driver)?;
+    let choose_result_sparse = xor_result
+        .add_two(&f_sparse, &g_sparse, builder, driver)
+        .normalize(builder, driver);
+
+    Plookup::read_from_1_to_2_table(
+        builder,
+        driver,
+        MultiTableId::Sha256ChOutput,
+        &choose_result_sparse,
+    )
+}
+
+fn majority<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    a: &mut SparseValue<P::ScalarField>,
+    b: &SparseValue<P::ScalarField>,
+    c: &SparseValue<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    let lookup = Plookup::get_lookup_accumulators_ct(
+        builder,
+        driver,
+        MultiTableId::Sha256MajInput,
+        &a.normal,
+        &FieldCT::default(),
+        false,
+    )?;
+    let rotation_coefficients = Sha256Tables::get_majority_rotation_multipliers::<P::ScalarField>();
+    let four = P::ScalarField::from(4u64);
+
+    let rotation_result = lookup[ColumnIdx::C3][0].to_owned();
+    a.sparse = lookup[ColumnIdx::C2][0].to_owned();
+    let sparse_accumulator_2 = lookup[ColumnIdx::C2][1].to_owned();
+
+    let lhs = scale(&rotation_result, four, builder, driver)?;
+    let mid = scale(
+        &a.sparse,
+        rotation_coefficients[0] * four + P::ScalarField::from(1u64),
+        builder,
+        driver,
+    )?;
+    let rhs = scale(
+        &sparse_accumulator_2,
+        rotation_coefficients[1] * four,
+        builder,
+        driver,
+    )?;
+    let xor_result = lhs.add_two(&mid, &rhs, builder, driver);
+
+    let majority_result_sparse = xor_result
+        .add_two(&b.sparse, &c.sparse, builder, driver)
+        .normalize(builder, driver);
+
+    Plookup::read_from_1_to_2_table(
+        builder,
+        driver,
+        MultiTableId::Sha256MajOutput,
+        &majority_result_sparse,
+    )
+}
+
+/// Computes (a + b) mod 2^32, constraining the overflow to be at most 3 bits.
+fn add_normalize<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    a: &FieldCT<P::ScalarField>,
+    b: &FieldCT<P::ScalarField>,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<FieldCT<P::ScalarField>> {
+    if a.is_constant() && b.is_constant() {
+        let sum: num_bigint::BigUint = (a.additive_constant + b.additive_constant).into();
+        let normalized_sum = sum.iter_u32_digits().next().unwrap_or_default();
+        return Ok(FieldCT::from_field(P::ScalarField::from(normalized_sum)));
+    }
+
+    let a_value = a.get_value(builder, driver);
+    let b_value = b.get_value(builder, driver);
+    let sum = driver.add(a_value, b_value);
+    let overflow = driver.decompose(sum, 35, 32)?.swap_remove(1);
+    let overflow = FieldCT::from_witness(overflow, builder);
+
+    let scaled_overflow = scale(
+        &overflow,
+        -P::ScalarField::from(1u64 << 32),
+        builder,
+        driver,
+    )?;
+    let result = a.add_two(b, &scaled_overflow, builder, driver);
+    overflow.create_range_constraint(3, builder, driver)?;
+    Ok(result)
+}
+
+/// Computes the SHA-256 compression function on a single block of 16 words with the given state.
+pub(crate) fn sha256_block<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    h_init: &[FieldCT<P::ScalarField>; 8],
+    input: &[FieldCT<P::ScalarField>; 16],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<[FieldCT<P::ScalarField>; 8]> {
+    // Initialize round variables with previous block output.
+    // We do not need to range constrain these as they are either constants or outputs of a previous block.
+    let mut a = SparseValue::new(h_init[0].to_owned());
+    a.sparse =
+        Plookup::read_from_1_to_2_table(builder, driver, MultiTableId::Sha256MajInput, &a.normal)?;
+    let mut b = SparseValue::new(h_init[1].to_owned());
+    b.sparse =
+        Plookup::read_from_1_to_2_table(builder, driver, MultiTableId::Sha256MajInput, &b.normal)?;
+    let mut c = SparseValue::new(h_init[2].to_owned());
+    c.sparse =
+        Plookup::read_from_1_to_2_table(builder, driver, MultiTableId::Sha256MajInput, &c.normal)?;
+    let mut d = SparseValue::new(h_init[3].to_owned());
+    let mut e = SparseValue::new(h_init[4].to_owned());
+    e.sparse =
+        Plookup::read_from_1_to_2_table(builder, driver, MultiTableId::Sha256ChInput, &e.normal)?;
+    let mut f = SparseValue::new(h_init[5].to_owned());
+    f.sparse =
+        Plookup::read_from_1_to_2_table(builder, driver, MultiTableId::Sha256ChInput, &f.normal)?;
+    let mut g = SparseValue::new(h_init[6].to_owned());
+    g.sparse =
+        Plookup::read_from_1_to_2_table(builder, driver, MultiTableId::Sha256ChInput, &g.normal)?;
+    let mut h = SparseValue::new(h_init[7].to_owned());
+
+    // Extend the input words to the full message schedule
+    let w = extend_witness(input, builder, driver)?;
+
+    // Apply the 64 rounds of the compression function
+    for (w, round_constant) in w.iter().zip(ROUND_CONSTANTS) {
+        let ch = choose(&mut e, &f, &g, builder, driver)?;
+        let maj = majority(&mut a, &b, &c, builder, driver)?;
+        let w_plus_k = w.add(
+            &FieldCT::from_field(P::ScalarField::from(round_constant)),
+            builder,
+            driver,
+        );
+        let temp1 = ch.add_two(&h.normal, &w_plus_k, builder, driver);
+
+        h = g;
+        g = f;
+        f = e.to_owned();
+        e.normal = add_normalize(&d.normal, &temp1, builder, driver)?;
+        d = c;
+        c = b;
+        b = a.to_owned();
+        a.normal = add_normalize(&temp1, &maj, builder, driver)?;
+    }
+
+    // Add into the previous state
+    let state = [a, b, c, d, e, f, g, h];
+    let mut output: [FieldCT<P::ScalarField>; 8] = Default::default();
+    for (out, (state, h_init)) in output.iter_mut().zip(state.iter().zip(h_init.iter())) {
+        *out = add_normalize(&state.normal, h_init, builder, driver)?;
+    }
+
+    // Range constrain the outputs
+    for out in output.iter() {
+        out.create_range_constraint(32, builder, driver)?;
+    }
+
+    Ok(output)
+}
+
+/// Decomposes a field element into `num_bytes` big-endian bytes, each constrained to 8 bits.
+pub(crate) fn to_byte_array<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &FieldCT<P::ScalarField>,
+    num_bytes: usize,
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    if input.is_constant() {
+        let value: num_bigint::BigUint = input.additive_constant.into();
+        let mut bytes = value.to_bytes_le();
+        bytes.resize(num_bytes, 0);
+        return Ok(bytes
+            .into_iter()
+            .rev()
+            .map(|byte| FieldCT::from_field(P::ScalarField::from(byte)))
+            .collect());
+    }
+
+    if num_bytes * 8 >= P::ScalarField::MODULUS_BIT_SIZE as usize {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "Byte decomposition of full field elements is not supported",
+        )
+        .into());
+    }
+
+    let value = input.get_value(builder, driver);
+    let decomposed = driver.decompose(value, num_bytes * 8, 8)?;
+
+    let byte_shift = P::ScalarField::from(256u64);
+    let mut scaling_factor = P::ScalarField::from(1u64);
+    let mut validator = FieldCT::from_field(P::ScalarField::zero());
+    let mut bytes = Vec::with_capacity(num_bytes);
+    for byte in decomposed.into_iter() {
+        let byte = FieldCT::from_witness(byte, builder);
+        byte.create_range_constraint(8, builder, driver)?;
+        let scaled = scale(&byte, scaling_factor, builder, driver)?;
+        validator = validator.add(&scaled, builder, driver);
+        scaling_factor *= byte_shift;
+        bytes.push(byte);
+    }
+    validator.assert_equal(input, builder, driver);
+
+    // The decomposition is little-endian
+    bytes.reverse();
+    Ok(bytes)
+}
+
+/// Computes the SHA-256 hash of the given bytes and returns the 32 bytes of the digest.
+pub(crate) fn sha256<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    input: &[FieldCT<P::ScalarField>],
+    builder: &mut GenericUltraCircuitBuilder<P, T>,
+    driver: &mut T,
+) -> HonkProofResult<Vec<FieldCT<P::ScalarField>>> {
+    const BYTES_PER_BLOCK: usize = 64;
+
+    // Pad the message: 0x80, zeros and the 64-bit big-endian bit length
+    let mut message_schedule = input.to_vec();
+    message_schedule.push(FieldCT::from_field(P::ScalarField::from(128u64)));
+    let num_bytes = message_schedule.len() + 8;
+    let num_blocks = num_bytes.div_ceil(BYTES_PER_BLOCK);
+    message_schedule.resize(
+        num_blocks * BYTES_PER_BLOCK - 8,
+        FieldCT::from_field(P::ScalarField::zero()),
+    );
+    let message_bits = input.len() as u64 * 8;
+    for byte in message_bits.to_be_bytes() {
+        message_schedule.push(FieldCT::from_field(P::ScalarField::from(byte)));
+    }
+
+    // Compose the 32-bit words
+    let mut slices = Vec::with_capacity(message_schedule.len() / 4);
+    for chunk in message_schedule.chunks_exact(4) {
+        let b0 = scale(&chunk[0], P::ScalarField::from(1u64 << 24), builder, driver)?;
+        let b1 = scale(&chunk[1], P::ScalarField::from(1u64 << 16), builder, driver)?;
+        let b2 = scale(&chunk[2], P::ScalarField::from(1u64 << 8), builder, driver)?;
+        let word = b0
+            .add_two(&b1, &b2, builder, driver)
+            .add(&chunk[3], builder, driver);
+        slices.push(word);
+    }
+
+    let mut rolling_hash: [FieldCT<P::ScalarField>; 8] =
+        INIT_CONSTANTS.map(|c| FieldCT::from_field(P::ScalarField::from(c)));
+    for block in slices.chunks_exact(16) {
+        let block: [FieldCT<P::ScalarField>; 16] = std::array::from_fn(|i| block[i].to_owned());
+        rolling_hash = sha256_block(&rolling_hash, &block, builder, driver)?;
+    }
+
+    let mut output = Vec::with_capacity(32);
+    for word in rolling_hash.iter() {
+        output.extend(to_byte_array(word, 4, builder, driver)?);
+    }
+    Ok(output)
+}
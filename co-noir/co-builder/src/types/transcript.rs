@@ -0,0 +1,174 @@
+use super::{
+    biggroup::{ProjectivePoint, NUM_POINT_FRS},
+    poseidon2::poseidon2_permutation,
+    types::FieldCT,
+};
+use crate::{builder::GenericUltraCircuitBuilder, HonkProofError, HonkProofResult};
+use ark_ec::pairing::Pairing;
+use ark_ff::PrimeField;
+use co_acvm::mpc::NoirWitnessExtensionProtocol;
+use num_bigint::BigUint;
+
+/// The number of bits of the lower half of a challenge, which is used as the challenge
+const CHALLENGE_LO_BITS: usize = 128;
+
+/// The in-circuit version of the Poseidon2 based transcript of the UltraHonk verifier. The proof is given as a vector of field elements, which get absorbed round by round. The challenges of a round are squeezed from the previous challenge and the data received in this round.
+pub(crate) struct Transcript<F: PrimeField> {
+    proof_data: Vec<FieldCT<F>>,
+    num_frs_read: usize,
+    is_first_challenge: bool,
+    current_round_data: Vec<FieldCT<F>>,
+    previous_challenge: FieldCT<F>,
+}
+
+impl<F: PrimeField> Transcript<F> {
+    pub(crate) fn new(proof_data: Vec<FieldCT<F>>) -> Self {
+        Self {
+            proof_data,
+            num_frs_read: 0,
+            is_first_challenge: true,
+            current_round_data: Vec::new(),
+            previous_challenge: FieldCT::default(),
+        }
+    }
+
+    pub(crate) fn receive_n_from_prover(&mut self, n: usize) -> HonkProofResult<Vec<FieldCT<F>>> {
+        if self.num_frs_read + n > self.proof_data.len() {
+            return Err(HonkProofError::ProofTooSmall);
+        }
+        let elements = self.proof_data[self.num_frs_read..self.num_frs_read + n].to_vec();
+        self.num_frs_read += n;
+        self.current_round_data.extend_from_slice(&elements);
+        Ok(elements)
+    }
+
+    pub(crate) fn receive_fr_from_prover(&mut self) -> HonkProofResult<FieldCT<F>> {
+        Ok(self.receive_n_from_prover(1)?.remove(0))
+    }
+
+    /// Receives a point on a curve whose base field has the given modulus.
+    pub(crate) fn receive_point_from_prover<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &mut self,
+        modulus: &BigUint,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<ProjectivePoint<F>> {
+        let frs = self.receive_n_from_prover(NUM_POINT_FRS)?;
+        ProjectivePoint::from_frs(&frs, modulus, builder, driver)
+    }
+
+    /// Returns true if the whole proof has been read.
+    pub(crate) fn is_consumed(&self) -> bool {
+        self.num_frs_read == self.proof_data.len()
+    }
+
+    pub(crate) fn get_challenge<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &mut self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<FieldCT<F>> {
+        let [lo, _] = self.get_next_duplex_challenge_buffer(builder, driver)?;
+        Ok(lo)
+    }
+
+    /// Returns n challenges, where two consecutive challenges are the two halves of the same hash.
+    pub(crate) fn get_challenges<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &mut self,
+        n: usize,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Vec<FieldCT<F>>> {
+        let mut challenges = Vec::with_capacity(n);
+        for _ in 0..n / 2 {
+            let [lo, hi] = self.get_next_duplex_challenge_buffer(builder, driver)?;
+            challenges.push(lo);
+            challenges.push(hi);
+        }
+        if n % 2 == 1 {
+            challenges.push(self.get_challenge(builder, driver)?);
+        }
+        Ok(challenges)
+    }
+
+    fn get_next_duplex_challenge_buffer<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &mut self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<[FieldCT<F>; 2]> {
+        // The challenges of a round also depend on the previous challenge, which links the rounds
+        let mut full_buffer = Vec::with_capacity(self.current_round_data.len() + 1);
+        if !self.is_first_challenge {
+            full_buffer.push(self.previous_challenge.to_owned());
+        }
+        full_buffer.append(&mut self.current_round_data);
+        self.is_first_challenge = false;
+
+        let challenge = Self::hash(&full_buffer, builder, driver)?;
+        let halves = Self::split_challenge(&challenge, builder, driver)?;
+        self.previous_challenge = challenge;
+        Ok(halves)
+    }
+
+    /// The Poseidon2 sponge with rate 3 and capacity 1, whose capacity is initialized with the input length.
+    fn hash<P: Pairing<ScalarField = F>, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+        input: &[FieldCT<F>],
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<FieldCT<F>> {
+        let iv = F::from(BigUint::from(input.len()) << 64);
+        let mut state = [
+            FieldCT::default(),
+            FieldCT::default(),
+            FieldCT::default(),
+            FieldCT::from_field(iv),
+        ];
+        let mut chunks = input.chunks(3).peekable();
+        if chunks.peek().is_none() {
+            state = poseidon2_permutation(&state, builder, driver)?;
+        }
+        for chunk in chunks {
+            for (state, element) in state.iter_mut().zip(chunk) {
+                *state = state.add(element, builder, driver);
+            }
+            state = poseidon2_permutation(&state, builder, driver)?;
+        }
+        let [result, _, _, _] = state;
+        Ok(result)
+    }
+
+    /// Splits a challenge into its lower 128 and upper 126 bits.
+    fn split_challenge<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        challenge: &FieldCT<F>,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<[FieldCT<F>; 2]> {
+        let num_bits = F::MODULUS_BIT_SIZE as usize;
+        let value = challenge.get_value(builder, driver);
+        let mut halves = driver.decompose(value, num_bits, CHALLENGE_LO_BITS)?;
+        let hi = FieldCT::from_witness(halves.pop().expect("there are two halves"), builder);
+        let lo = FieldCT::from_witness(halves.pop().expect("there are two halves"), builder);
+        lo.create_range_constraint(CHALLENGE_LO_BITS, builder, driver)?;
+        hi.create_range_constraint(num_bits - CHALLENGE_LO_BITS, builder, driver)?;
+
+        let shift = FieldCT::from_field(F::from(BigUint::from(1u64) << CHALLENGE_LO_BITS));
+        hi.multiply(&shift, builder, driver)?
+            .add(&lo, builder, driver)
+            .assert_equal(challenge, builder, driver);
+        Ok([lo, hi])
+    }
+}
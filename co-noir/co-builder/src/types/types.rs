@@ -1,8 +1,9 @@
 use crate::builder::{GenericUltraCircuitBuilder, UltraCircuitBuilder};
 use crate::keys::proving_key::ProvingKey;
 use crate::polynomials::polynomial::Polynomial;
-use crate::types::plookup::BasicTableId;
+use crate::types::plookup::{AesTables, BasicTableId, FixedBaseParams, KeccakTables, Sha256Tables};
 use crate::utils::Utils;
+use crate::HonkProofResult;
 use ark_ec::pairing::Pairing;
 use ark_ff::{One, PrimeField, Zero};
 use co_acvm::mpc::NoirWitnessExtensionProtocol;
@@ -66,6 +67,23 @@ pub(crate) struct MulQuad<F: PrimeField> {
     pub(crate) const_scaling: F,
 }
 
+pub(crate) struct EccAddGate<F: PrimeField> {
+    pub(crate) x1: u32,
+    pub(crate) y1: u32,
+    pub(crate) x2: u32,
+    pub(crate) y2: u32,
+    pub(crate) x3: u32,
+    pub(crate) y3: u32,
+    pub(crate) sign_coefficient: F,
+}
+
+pub(crate) struct EccDblGate {
+    pub(crate) x1: u32,
+    pub(crate) y1: u32,
+    pub(crate) x3: u32,
+    pub(crate) y3: u32,
+}
+
 pub(crate) struct MemOp<F: PrimeField> {
     pub(crate) access_type: u8,
     pub(crate) index: PolyTriple<F>,
@@ -95,32 +113,224 @@ pub(crate) struct BlockConstraint<F: PrimeField> {
     pub(crate) calldata: u32,
 }
 
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct WitnessOrConstant<F: PrimeField> {
+    pub(crate) index: u32,
+    pub(crate) value: F,
+    pub(crate) is_constant: bool,
+}
+
+impl<F: PrimeField> WitnessOrConstant<F> {
+    pub(crate) fn from_index(index: u32) -> Self {
+        Self {
+            index,
+            value: F::zero(),
+            is_constant: false,
+        }
+    }
+
+    pub(crate) fn from_constant(constant: F) -> Self {
+        Self {
+            index: FieldCT::<F>::IS_CONSTANT,
+            value: constant,
+            is_constant: true,
+        }
+    }
+
+    pub(crate) fn to_field_ct(&self) -> FieldCT<F> {
+        if self.is_constant {
+            FieldCT::from_field(self.value)
+        } else {
+            FieldCT::from_witness_index(self.index)
+        }
+    }
+}
+
+pub(crate) struct LogicConstraint<F: PrimeField> {
+    pub(crate) a: WitnessOrConstant<F>,
+    pub(crate) b: WitnessOrConstant<F>,
+    pub(crate) result: u32,
+    pub(crate) num_bits: u32,
+    pub(crate) is_xor_gate: bool,
+}
+
+pub(crate) struct RangeConstraint {
+    pub(crate) witness: u32,
+    pub(crate) num_bits: u32,
+}
+
+pub(crate) struct Aes128Constraint<F: PrimeField> {
+    pub(crate) inputs: Vec<WitnessOrConstant<F>>,
+    pub(crate) iv: [WitnessOrConstant<F>; 16],
+    pub(crate) key: [WitnessOrConstant<F>; 16],
+    pub(crate) outputs: Vec<u32>,
+}
+
+pub(crate) struct Sha256Input {
+    pub(crate) witness: u32,
+    pub(crate) num_bits: u32,
+}
+
+pub(crate) struct Sha256Constraint {
+    pub(crate) inputs: Vec<Sha256Input>,
+    pub(crate) result: [u32; 32],
+}
+
+pub(crate) struct Sha256Compression<F: PrimeField> {
+    pub(crate) inputs: [WitnessOrConstant<F>; 16],
+    pub(crate) hash_values: [WitnessOrConstant<F>; 8],
+    pub(crate) result: [u32; 8],
+}
+
+pub(crate) struct EcdsaConstraint<F: PrimeField> {
+    pub(crate) hashed_message: Vec<WitnessOrConstant<F>>,
+    pub(crate) signature: [WitnessOrConstant<F>; 64],
+    pub(crate) pub_x_indices: [WitnessOrConstant<F>; 32],
+    pub(crate) pub_y_indices: [WitnessOrConstant<F>; 32],
+    pub(crate) result: u32,
+}
+
+pub(crate) struct SchnorrConstraint<F: PrimeField> {
+    pub(crate) message: Vec<WitnessOrConstant<F>>,
+    pub(crate) public_key_x: WitnessOrConstant<F>,
+    pub(crate) public_key_y: WitnessOrConstant<F>,
+    pub(crate) signature: [WitnessOrConstant<F>; 64],
+    pub(crate) result: u32,
+}
+
+pub(crate) struct PedersenConstraint<F: PrimeField> {
+    pub(crate) scalars: Vec<WitnessOrConstant<F>>,
+    pub(crate) hash_index: u32,
+    pub(crate) result_x: u32,
+    pub(crate) result_y: u32,
+}
+
+pub(crate) struct PedersenHashConstraint<F: PrimeField> {
+    pub(crate) scalars: Vec<WitnessOrConstant<F>>,
+    pub(crate) hash_index: u32,
+    pub(crate) result: u32,
+}
+
+pub(crate) struct MultiScalarMul<F: PrimeField> {
+    pub(crate) points: Vec<WitnessOrConstant<F>>,
+    pub(crate) scalars: Vec<WitnessOrConstant<F>>,
+    pub(crate) out_point_x: u32,
+    pub(crate) out_point_y: u32,
+    pub(crate) out_point_is_infinite: u32,
+}
+
+pub(crate) struct EcAdd<F: PrimeField> {
+    pub(crate) input1_x: WitnessOrConstant<F>,
+    pub(crate) input1_y: WitnessOrConstant<F>,
+    pub(crate) input1_infinite: WitnessOrConstant<F>,
+    pub(crate) input2_x: WitnessOrConstant<F>,
+    pub(crate) input2_y: WitnessOrConstant<F>,
+    pub(crate) input2_infinite: WitnessOrConstant<F>,
+    pub(crate) result_x: u32,
+    pub(crate) result_y: u32,
+    pub(crate) result_infinite: u32,
+}
+
+pub(crate) struct BigIntFromLeBytes<F: PrimeField> {
+    pub(crate) inputs: Vec<WitnessOrConstant<F>>,
+    pub(crate) modulus: Vec<u8>,
+    pub(crate) result: u32,
+}
+
+pub(crate) struct BigIntToLeBytes {
+    pub(crate) input: u32,
+    pub(crate) result: Vec<u32>,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum BigIntOperationType {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+pub(crate) struct BigIntOperation {
+    pub(crate) lhs: u32,
+    pub(crate) rhs: u32,
+    pub(crate) result: u32,
+    pub(crate) opcode: BigIntOperationType,
+}
+
+pub(crate) struct HashInput {
+    pub(crate) witness: u32,
+    pub(crate) num_bits: u32,
+}
+
+pub(crate) struct Blake2sConstraint {
+    pub(crate) inputs: Vec<HashInput>,
+    pub(crate) result: [u32; 32],
+}
+
+pub(crate) struct Blake3Constraint {
+    pub(crate) inputs: Vec<HashInput>,
+    pub(crate) result: [u32; 32],
+}
+
+pub(crate) struct KeccakConstraint<F: PrimeField> {
+    pub(crate) inputs: Vec<HashInput>,
+    pub(crate) var_message_size: WitnessOrConstant<F>,
+    pub(crate) result: [u32; 32],
+}
+
+pub(crate) struct Keccakf1600<F: PrimeField> {
+    pub(crate) state: [WitnessOrConstant<F>; 25],
+    pub(crate) result: [u32; 25],
+}
+
+pub(crate) struct Poseidon2Constraint<F: PrimeField> {
+    pub(crate) state: Vec<WitnessOrConstant<F>>,
+    pub(crate) result: Vec<u32>,
+    pub(crate) len: u32,
+}
+
+pub(crate) struct Poseidon2ExternalGate<F: PrimeField> {
+    pub(crate) a: u32,
+    pub(crate) b: u32,
+    pub(crate) c: u32,
+    pub(crate) d: u32,
+    pub(crate) round_constants: [F; 4],
+}
+
+pub(crate) struct Poseidon2InternalGate<F: PrimeField> {
+    pub(crate) a: u32,
+    pub(crate) b: u32,
+    pub(crate) c: u32,
+    pub(crate) d: u32,
+    pub(crate) round_constant: F,
+}
+
 #[derive(Default)]
 pub(crate) struct AcirFormatOriginalOpcodeIndices {
-    // pub(crate)logic_constraints: Vec<usize>,
-    // pub(crate)range_constraints: Vec<usize>,
-    // pub(crate)aes128_constraints: Vec<usize>,
-    // pub(crate)sha256_constraints: Vec<usize>,
-    // pub(crate)sha256_compression: Vec<usize>,
-    // pub(crate)schnorr_constraints: Vec<usize>,
-    // pub(crate)ecdsa_k1_constraints: Vec<usize>,
-    // pub(crate)ecdsa_r1_constraints: Vec<usize>,
-    // pub(crate)blake2s_constraints: Vec<usize>,
-    // pub(crate)blake3_constraints: Vec<usize>,
-    // pub(crate)keccak_constraints: Vec<usize>,
-    // pub(crate)keccak_permutations: Vec<usize>,
-    // pub(crate)pedersen_constraints: Vec<usize>,
-    // pub(crate)pedersen_hash_constraints: Vec<usize>,
-    // pub(crate)poseidon2_constraints: Vec<usize>,
-    // pub(crate)multi_scalar_mul_constraints: Vec<usize>,
-    // pub(crate)ec_add_constraints: Vec<usize>,
-    // pub(crate)recursion_constraints: Vec<usize>,
-    // pub(crate)honk_recursion_constraints: Vec<usize>,
-    // pub(crate)avm_recursion_constraints: Vec<usize>,
+    pub(crate) logic_constraints: Vec<usize>,
+    pub(crate) range_constraints: Vec<usize>,
+    pub(crate) aes128_constraints: Vec<usize>,
+    pub(crate) sha256_constraints: Vec<usize>,
+    pub(crate) sha256_compression: Vec<usize>,
+    pub(crate) schnorr_constraints: Vec<usize>,
+    pub(crate) ecdsa_k1_constraints: Vec<usize>,
+    pub(crate) ecdsa_r1_constraints: Vec<usize>,
+    pub(crate) blake2s_constraints: Vec<usize>,
+    pub(crate) blake3_constraints: Vec<usize>,
+    pub(crate) keccak_constraints: Vec<usize>,
+    pub(crate) keccak_permutations: Vec<usize>,
+    pub(crate) pedersen_constraints: Vec<usize>,
+    pub(crate) pedersen_hash_constraints: Vec<usize>,
+    pub(crate) poseidon2_constraints: Vec<usize>,
+    pub(crate) multi_scalar_mul_constraints: Vec<usize>,
+    pub(crate) ec_add_constraints: Vec<usize>,
+    pub(crate) recursion_constraints: Vec<usize>,
+    pub(crate) honk_recursion_constraints: Vec<usize>,
+    pub(crate) avm_recursion_constraints: Vec<usize>,
     // pub(crate)ivc_recursion_constraints: Vec<usize>,
-    // pub(crate)bigint_from_le_bytes_constraints: Vec<usize>,
-    // pub(crate)bigint_to_le_bytes_constraints: Vec<usize>,
-    // pub(crate)bigint_operations: Vec<usize>,
+    pub(crate) bigint_from_le_bytes_constraints: Vec<usize>,
+    pub(crate) bigint_to_le_bytes_constraints: Vec<usize>,
+    pub(crate) bigint_operations: Vec<usize>,
     pub(crate) assert_equalities: Vec<usize>,
     pub(crate) poly_triple_constraints: Vec<usize>,
     pub(crate) quad_constraints: Vec<usize>,
@@ -369,15 +579,17 @@ impl GateCounter {
 pub(crate) struct RecursionConstraint {
     // An aggregation state is represented by two G1 affine elements. Each G1 point has
     // two field element coordinates (x, y). Thus, four field elements
-    key: Vec<u32>,
-    proof: Vec<u32>,
-    public_inputs: Vec<u32>,
-    key_hash: u32,
-    proof_type: u32,
+    pub(crate) key: Vec<u32>,
+    pub(crate) proof: Vec<u32>,
+    pub(crate) public_inputs: Vec<u32>,
+    pub(crate) key_hash: u32,
+    pub(crate) proof_type: u32,
 }
 
 impl RecursionConstraint {
     const NUM_AGGREGATION_ELEMENTS: usize = 4;
+    pub(crate) const PLONK: u32 = 0;
+    pub(crate) const HONK: u32 = 1;
 }
 
 pub(crate) const AGGREGATION_OBJECT_SIZE: usize = 16;
@@ -418,18 +630,21 @@ impl<F: PrimeField> RomTable<F> {
         &mut self,
         index: &FieldCT<F>,
         builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
     ) -> FieldCT<F> {
         if index.is_constant() {
-            let val: BigUint = index.get_value(builder).into();
+            let val: BigUint = index.additive_constant.into();
             let val: usize = val.try_into().expect("Invalid index");
             return self[val].to_owned();
         }
-        self.initialize_table(builder);
+        self.initialize_table(builder, driver);
 
-        let val: BigUint = index.get_value(builder).into();
-        assert!(val < BigUint::from(self.length));
+        if let Some(val) = T::get_public(&index.get_value(builder, driver)) {
+            let val: BigUint = val.into();
+            assert!(val < BigUint::from(self.length));
+        }
 
-        let witness_index = index.normalize(builder).get_witness_index();
+        let witness_index = index.normalize(builder, driver).get_witness_index();
         let output_idx = builder
             .read_rom_array(self.rom_id, witness_index)
             .expect("Not implemented for other cases");
@@ -442,6 +657,7 @@ impl<F: PrimeField> RomTable<F> {
     >(
         &mut self,
         builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
     ) {
         if self.initialized {
             return;
@@ -449,12 +665,11 @@ impl<F: PrimeField> RomTable<F> {
         // populate table. Table entries must be normalized and cannot be constants
         for entry in self.raw_entries.iter() {
             if entry.is_constant() {
-                let val = entry.get_value(builder);
                 self.entries.push(FieldCT::from_witness_index(
-                    builder.put_constant_variable(val),
+                    builder.put_constant_variable(entry.additive_constant),
                 ));
             } else {
-                self.entries.push(entry.normalize(builder));
+                self.entries.push(entry.normalize(builder, driver));
             }
         }
         self.rom_id = builder.create_rom_array(self.length);
@@ -504,6 +719,133 @@ impl<F: PrimeField> RamTable<F> {
             all_entries_written_to_with_constant_index: false,
         }
     }
+
+    fn check_indices_initialized(&mut self) -> bool {
+        if self.all_entries_written_to_with_constant_index {
+            return true;
+        }
+        if self.length == 0 {
+            return false;
+        }
+        self.all_entries_written_to_with_constant_index = self.index_initialized.iter().all(|x| *x);
+        self.all_entries_written_to_with_constant_index
+    }
+
+    fn initialize_table<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &mut self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) {
+        if self.ram_table_generated_in_builder {
+            return;
+        }
+
+        self.ram_id = builder.create_ram_array(self.length);
+
+        for (i, (raw, ind)) in self
+            .raw_entries
+            .iter()
+            .zip(self.index_initialized.iter_mut())
+            .enumerate()
+        {
+            if *ind {
+                continue;
+            }
+            // Table entries must be normalized and cannot be constants
+            let entry = if raw.is_constant() {
+                FieldCT::from_witness_index(builder.put_constant_variable(raw.additive_constant))
+            } else {
+                raw.normalize(builder, driver)
+            };
+            builder.init_ram_element(self.ram_id, i, entry.get_witness_index());
+            *ind = true;
+        }
+
+        self.ram_table_generated_in_builder = true;
+    }
+
+    pub(crate) fn read<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &mut self,
+        index: &FieldCT<F>,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<FieldCT<F>> {
+        // We can only check the bounds of the index if it is public
+        if let Some(val) = T::get_public(&index.get_value(builder, driver)) {
+            let val: BigUint = val.into();
+            assert!(val < BigUint::from(self.length));
+        }
+
+        self.initialize_table(builder, driver);
+        assert!(self.check_indices_initialized());
+
+        let index_wire = if index.is_constant() {
+            FieldCT::from_witness_index(builder.put_constant_variable(index.additive_constant))
+        } else {
+            index.to_owned()
+        };
+
+        let index_witness = index_wire.normalize(builder, driver).get_witness_index();
+        let output_idx = builder.read_ram_array(self.ram_id, index_witness, driver)?;
+        Ok(FieldCT::from_witness_index(output_idx))
+    }
+
+    pub(crate) fn write<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &mut self,
+        index: &FieldCT<F>,
+        value: &FieldCT<F>,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        // We can only check the bounds of the index if it is public
+        if let Some(val) = T::get_public(&index.get_value(builder, driver)) {
+            let val: BigUint = val.into();
+            assert!(val < BigUint::from(self.length));
+        }
+
+        self.initialize_table(builder, driver);
+
+        let index_wire = if index.is_constant() {
+            FieldCT::from_witness_index(builder.put_constant_variable(index.additive_constant))
+        } else {
+            // Need to write every array element at a constant index before doing reads/writes at prover-defined indices
+            assert!(self.check_indices_initialized());
+            index.to_owned()
+        };
+
+        let value_wire = if value.is_constant() {
+            FieldCT::from_witness_index(builder.put_constant_variable(value.additive_constant))
+        } else {
+            value.to_owned()
+        };
+
+        if index.is_constant() {
+            let cast_index: BigUint = index.additive_constant.into();
+            let cast_index: usize = cast_index.try_into().expect("Invalid index");
+            if !self.index_initialized[cast_index] {
+                builder.init_ram_element(
+                    self.ram_id,
+                    cast_index,
+                    value_wire.normalize(builder, driver).get_witness_index(),
+                );
+                self.index_initialized[cast_index] = true;
+                return Ok(());
+            }
+        }
+
+        let index_witness = index_wire.normalize(builder, driver).get_witness_index();
+        let value_witness = value_wire.normalize(builder, driver).get_witness_index();
+        builder.write_ram_array(self.ram_id, index_witness, value_witness, driver)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -514,7 +856,7 @@ pub(crate) struct FieldCT<F: PrimeField> {
 }
 
 impl<F: PrimeField> FieldCT<F> {
-    const IS_CONSTANT: u32 = u32::MAX;
+    pub(crate) const IS_CONSTANT: u32 = u32::MAX;
 
     pub(crate) fn from_field(value: F) -> Self {
         Self {
@@ -532,19 +874,23 @@ impl<F: PrimeField> FieldCT<F> {
         }
     }
 
-    // TACEO TODO this is just implemented for the plain backend
     pub(crate) fn from_witness<
         P: Pairing<ScalarField = F>,
         T: NoirWitnessExtensionProtocol<P::ScalarField>,
     >(
-        input: P::ScalarField,
+        input: T::AcvmType,
         builder: &mut GenericUltraCircuitBuilder<P, T>,
     ) -> Self {
-        let witness = WitnessCT::from_field(input, builder);
+        let witness = WitnessCT::from_acvm_type(input, builder);
         Self::from_witness_ct(witness)
     }
 
-    pub(crate) fn from_witness_ct(value: WitnessCT<F>) -> Self {
+    pub(crate) fn from_witness_ct<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        value: WitnessCT<P, T>,
+    ) -> Self {
         Self {
             additive_constant: F::zero(),
             multiplicative_constant: F::one(),
@@ -558,13 +904,15 @@ impl<F: PrimeField> FieldCT<F> {
     >(
         &self,
         builder: &GenericUltraCircuitBuilder<P, T>,
-    ) -> F {
-        if self.witness_index != Self::IS_CONSTANT {
-            let variable = T::get_public(&builder.get_variable(self.witness_index as usize))
-                .expect("Not implemented for other cases"); // TACEO TODO this is just implemented for the Plain backend
-            self.multiplicative_constant * variable + self.additive_constant
+        driver: &mut T,
+    ) -> T::AcvmType {
+        if !self.is_constant() {
+            let variable = builder.get_variable(self.witness_index as usize);
+            let mut res = driver.acvm_mul_with_public(self.multiplicative_constant, variable);
+            driver.acvm_add_assign_with_public(self.additive_constant, &mut res);
+            res
         } else {
-            self.additive_constant.to_owned()
+            T::AcvmType::from(self.additive_constant)
         }
     }
 
@@ -586,31 +934,34 @@ impl<F: PrimeField> FieldCT<F> {
         &self,
         other: &Self,
         builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
     ) {
         if self.is_constant() && other.is_constant() {
-            assert_eq!(self.get_value(builder), other.get_value(builder));
+            assert_eq!(self.additive_constant, other.additive_constant);
         } else if self.is_constant() {
-            let right = other.normalize(builder);
-            let left = self.get_value(builder);
-            builder.assert_equal_constant(right.witness_index as usize, left);
+            let right = other.normalize(builder, driver);
+            builder.assert_equal_constant(right.witness_index as usize, self.additive_constant);
         } else if other.is_constant() {
-            let left = self.normalize(builder);
-            let right = other.get_value(builder);
-            builder.assert_equal_constant(left.witness_index as usize, right);
+            let left = self.normalize(builder, driver);
+            builder.assert_equal_constant(left.witness_index as usize, other.additive_constant);
         } else {
-            let left = self.normalize(builder);
-            let right = other.normalize(builder);
+            let left = self.normalize(builder, driver);
+            let right = other.normalize(builder, driver);
             builder.assert_equal(left.witness_index as usize, right.witness_index as usize);
         }
     }
 
-    fn is_constant(&self) -> bool {
+    pub(crate) fn is_constant(&self) -> bool {
         self.witness_index == Self::IS_CONSTANT
     }
 
-    fn normalize<P: Pairing<ScalarField = F>, T: NoirWitnessExtensionProtocol<P::ScalarField>>(
+    pub(crate) fn normalize<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
         &self,
         builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
     ) -> Self {
         if self.is_constant()
             || ((self.multiplicative_constant == F::one()) && (self.additive_constant == F::zero()))
@@ -623,11 +974,9 @@ impl<F: PrimeField> FieldCT<F> {
         // We need a new gate to enforce that the `result` was correctly calculated from `this`.
 
         let mut result = FieldCT::default();
-        let value = T::get_public(&builder.get_variable(self.witness_index as usize))
-            .expect("Not implemented for other cases"); // TACEO TODO this is just implemented for the Plain backend
-        let out = self.multiplicative_constant * value + self.additive_constant;
+        let out = self.get_value(builder, driver);
 
-        result.witness_index = builder.add_variable(T::AcvmType::from(out));
+        result.witness_index = builder.add_variable(out);
         result.additive_constant = F::zero();
         result.multiplicative_constant = F::one();
 
@@ -647,6 +996,447 @@ impl<F: PrimeField> FieldCT<F> {
         });
         result
     }
+
+    pub(crate) fn add<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        other: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> Self {
+        let mut result = Self::default();
+
+        if self.witness_index == other.witness_index && !self.is_constant() {
+            result.additive_constant = self.additive_constant + other.additive_constant;
+            result.multiplicative_constant =
+                self.multiplicative_constant + other.multiplicative_constant;
+            result.witness_index = self.witness_index;
+        } else if self.is_constant() && other.is_constant() {
+            // both inputs are constant - don't add a gate
+            result.additive_constant = self.additive_constant + other.additive_constant;
+        } else if !self.is_constant() && other.is_constant() {
+            // one input is constant - don't add a gate, but update scaling factors
+            result = self.to_owned();
+            result.additive_constant += other.additive_constant;
+        } else if self.is_constant() && !other.is_constant() {
+            result = other.to_owned();
+            result.additive_constant += self.additive_constant;
+        } else {
+            let lhs = self.get_value(builder, driver);
+            let rhs = other.get_value(builder, driver);
+            let out = driver.add(lhs, rhs);
+
+            result.witness_index = builder.add_variable(out);
+            builder.create_add_gate(&AddTriple {
+                a: self.witness_index,
+                b: other.witness_index,
+                c: result.witness_index,
+                a_scaling: self.multiplicative_constant,
+                b_scaling: other.multiplicative_constant,
+                c_scaling: -P::ScalarField::one(),
+                const_scaling: self.additive_constant + other.additive_constant,
+            });
+        }
+        result
+    }
+
+    pub(crate) fn sub<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        other: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> Self {
+        let mut rhs = other.to_owned();
+        rhs.additive_constant = -rhs.additive_constant;
+        rhs.multiplicative_constant = -rhs.multiplicative_constant;
+        self.add(&rhs, builder, driver)
+    }
+
+    pub(crate) fn multiply<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        other: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        let mut result = Self::default();
+
+        if self.is_constant() && other.is_constant() {
+            // Both inputs are constant - don't add a gate.
+            // The value of a constant is tracked in `.additive_constant`.
+            result.additive_constant = self.additive_constant * other.additive_constant;
+        } else if !self.is_constant() && other.is_constant() {
+            // One input is constant: don't add a gate, but update scaling factors.
+
+            // /**
+            //  * Let:
+            //  *   a := this;
+            //  *   b := other;
+            //  *   a.v := ctx->variables[this.witness_index];
+            //  *   b.v := ctx->variables[other.witness_index];
+            //  *   .mul = .multiplicative_constant
+            //  *   .add = .additive_constant
+            //  */
+            //
+            // /**
+            //  * Value of this   = a.v * a.mul + a.add;
+            //  * Value of other  = b.add
+            //  * Value of result = a * b = [a.v * a.mul + a.add] * b.add
+            //  *                          = a.v * [a.mul * b.add] + [a.add * b.add]
+            //  *                             ^     ^result.mul       ^result.add
+            //  *                             ^result.v
+            //  */
+            result.additive_constant = self.additive_constant * other.additive_constant;
+            result.multiplicative_constant = self.multiplicative_constant * other.additive_constant;
+            result.witness_index = self.witness_index;
+        } else if self.is_constant() && !other.is_constant() {
+            // One input is constant: don't add a gate, but update scaling factors.
+            result.additive_constant = self.additive_constant * other.additive_constant;
+            result.multiplicative_constant = other.multiplicative_constant * self.additive_constant;
+            result.witness_index = other.witness_index;
+        } else {
+            // Both inputs map to circuit varaibles: create a `*` constraint.
+
+            // /**
+            //  * Value of this   = a.v * a.mul + a.add;
+            //  * Value of other  = b.v * b.mul + b.add;
+            //  * Value of result = a * b
+            //  *            = [a.v * a.mul + a.add] * [b.v * b.mul + b.add]
+            //  *            = a.v * b.v * [a.mul * b.mul] + a.v * [a.mul * b.add] + b.v * [a.add * b.mul] + [a.ac * b.add]
+            //  *            = a.v * b.v * [      q_m    ] + a.v * [     q_l     ] + b.v * [     q_r     ] + [    q_c     ]
+            //  *            ^               ^Notice the add/mul_constants form selectors when a gate is created.
+            //  *            |                Only the witnesses (pointed-to by the witness_indexes) form the wires in/out of
+            //  *            |                the gate.
+            //  *            ^This entire value is pushed to ctx->variables as a new witness. The
+            //  *             implied additive & multiplicative constants of the new witness are 0 & 1 resp.
+            //  * Left wire value: a.v
+            //  * Right wire value: b.v
+            //  * Output wire value: result.v (with q_o = -1)
+            //  */
+            let q_c = self.additive_constant * other.additive_constant;
+            let q_r = self.additive_constant * other.multiplicative_constant;
+            let q_l = self.multiplicative_constant * other.additive_constant;
+            let q_m = self.multiplicative_constant * other.multiplicative_constant;
+
+            let lhs = self.get_value(builder, driver);
+            let rhs = other.get_value(builder, driver);
+            let out = driver.mul(lhs, rhs)?;
+
+            result.witness_index = builder.add_variable(out);
+            builder.create_poly_gate(&PolyTriple {
+                a: self.witness_index,
+                b: other.witness_index,
+                c: result.witness_index,
+                q_m,
+                q_l,
+                q_r,
+                q_o: -F::one(),
+                q_c,
+            });
+        }
+        Ok(result)
+    }
+
+    /**
+     * Efficiently compute (this + a + b) using big_mul gate
+     **/
+    pub(crate) fn add_two<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        add_a: &Self,
+        add_b: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> Self {
+        if (add_a.witness_index == add_b.witness_index)
+            || (add_a.witness_index == self.witness_index)
+            || (add_b.witness_index == self.witness_index)
+        {
+            return self.add(add_a, builder, driver).add(add_b, builder, driver);
+        }
+
+        let q_1 = self.multiplicative_constant;
+        let q_2 = add_a.multiplicative_constant;
+        let q_3 = add_b.multiplicative_constant;
+        let q_c = self.additive_constant + add_a.additive_constant + add_b.additive_constant;
+
+        let a = self.get_value(builder, driver);
+        let b = add_a.get_value(builder, driver);
+        let c = add_b.get_value(builder, driver);
+        let out = driver.add(a, b);
+        let out = driver.add(out, c);
+
+        let mut result = Self::default();
+        result.witness_index = builder.add_variable(out);
+
+        builder.create_big_add_gate(
+            &AddQuad {
+                a: if self.is_constant() {
+                    builder.zero_idx
+                } else {
+                    self.witness_index
+                },
+                b: if add_a.is_constant() {
+                    builder.zero_idx
+                } else {
+                    add_a.witness_index
+                },
+                c: if add_b.is_constant() {
+                    builder.zero_idx
+                } else {
+                    add_b.witness_index
+                },
+                d: result.witness_index,
+                a_scaling: q_1,
+                b_scaling: q_2,
+                c_scaling: q_3,
+                d_scaling: -F::one(),
+                const_scaling: q_c,
+            },
+            false,
+        );
+        result
+    }
+
+    /**
+     * @brief Constrain this field to be zero
+     */
+    pub(crate) fn assert_is_zero<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+    ) {
+        if self.is_constant() {
+            assert!(self.additive_constant.is_zero());
+            return;
+        }
+
+        builder.create_poly_gate(&PolyTriple {
+            a: self.witness_index,
+            b: builder.zero_idx,
+            c: builder.zero_idx,
+            q_m: F::zero(),
+            q_l: self.multiplicative_constant,
+            q_r: F::zero(),
+            q_o: F::zero(),
+            q_c: self.additive_constant,
+        });
+    }
+
+    /**
+     * @brief Returns a boolean indicating whether this field is zero
+     */
+    pub(crate) fn is_zero<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        if self.is_constant() {
+            return Ok(Self::from_field(F::from(self.additive_constant.is_zero())));
+        }
+        let input = self.normalize(builder, driver);
+        let value = input.get_value(builder, driver);
+
+        // inverse = 1 / (input + is_zero) - is_zero, which is 0 if the input is 0 and 1 / input otherwise
+        let is_zero = driver.equal(value.to_owned(), T::public_zero())?;
+        let shifted = driver.add(value, is_zero.to_owned());
+        let inverse = driver.div(T::AcvmType::from(F::one()), shifted)?;
+        let inverse = driver.sub(inverse, is_zero.to_owned());
+        let is_zero_idx = builder.add_variable(is_zero);
+        let inverse_idx = builder.add_variable(inverse);
+
+        // input * inverse + is_zero - 1 = 0
+        builder.create_poly_gate(&PolyTriple {
+            a: input.witness_index,
+            b: inverse_idx,
+            c: is_zero_idx,
+            q_m: F::one(),
+            q_l: F::zero(),
+            q_r: F::zero(),
+            q_o: F::one(),
+            q_c: -F::one(),
+        });
+        // is_zero * input = 0
+        builder.create_poly_gate(&PolyTriple {
+            a: is_zero_idx,
+            b: input.witness_index,
+            c: builder.zero_idx,
+            q_m: F::one(),
+            q_l: F::zero(),
+            q_r: F::zero(),
+            q_o: F::zero(),
+            q_c: F::zero(),
+        });
+        Ok(Self::from_witness_index(is_zero_idx))
+    }
+
+    /**
+     * @brief Constrain this field to be non-zero by providing its inverse
+     */
+    pub(crate) fn assert_is_not_zero<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        if self.is_constant() {
+            assert!(!self.additive_constant.is_zero());
+            return Ok(());
+        }
+
+        let value = self.get_value(builder, driver);
+        let inverse = driver.div(T::AcvmType::from(F::one()), value)?;
+        let inverse_idx = builder.add_variable(inverse);
+
+        // (a * mul_const + add_const) * inverse - 1 = 0
+        builder.create_poly_gate(&PolyTriple {
+            a: self.witness_index,
+            b: inverse_idx,
+            c: builder.zero_idx,
+            q_m: self.multiplicative_constant,
+            q_l: F::zero(),
+            q_r: self.additive_constant,
+            q_o: F::zero(),
+            q_c: -F::one(),
+        });
+        Ok(())
+    }
+
+    /**
+     * @brief Returns lhs if the predicate is true and rhs otherwise. The predicate must be a boolean.
+     */
+    pub(crate) fn conditional_assign<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        predicate: &Self,
+        lhs: &Self,
+        rhs: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        if predicate.is_constant() {
+            return Ok(if predicate.additive_constant.is_zero() {
+                rhs.to_owned()
+            } else {
+                lhs.to_owned()
+            });
+        }
+        // rhs + predicate * (lhs - rhs)
+        let difference = lhs.sub(rhs, builder, driver);
+        Ok(predicate
+            .multiply(&difference, builder, driver)?
+            .add(rhs, builder, driver))
+    }
+
+    /**
+     * @brief Computes this / other by constraining result * other = this. A zero denominator makes the circuit
+     * unsatisfiable unless the numerator is zero as well.
+     */
+    pub(crate) fn divide<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        other: &Self,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Self> {
+        if other.is_constant() {
+            let inverse = other
+                .additive_constant
+                .inverse()
+                .expect("field_t: division by zero");
+            return self.multiply(&Self::from_field(inverse), builder, driver);
+        }
+        if self.is_constant() && self.additive_constant.is_zero() {
+            return Ok(Self::default());
+        }
+
+        let numerator = self.get_value(builder, driver);
+        let denominator = other.get_value(builder, driver);
+        let result = driver.div(numerator, denominator)?;
+        let result = Self::from_witness(result, builder);
+        result
+            .multiply(other, builder, driver)?
+            .assert_equal(self, builder, driver);
+        Ok(result)
+    }
+
+    /**
+     * @brief Decomposes this field into num_bits boolean witnesses, ordered from the least significant to the most
+     * significant one. This also constrains the field to num_bits bits.
+     */
+    pub(crate) fn decompose_into_bits<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        num_bits: usize,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<Vec<Self>> {
+        let value = self.get_value(builder, driver);
+        let decomposed = driver.decompose(value, num_bits, 1)?;
+        let mut bits = Vec::with_capacity(num_bits);
+        let mut sum = Self::default();
+        for (i, bit) in decomposed.into_iter().enumerate() {
+            let bit_idx = builder.add_variable(bit);
+            builder.create_bool_gate(bit_idx);
+            let bit = Self::from_witness_index(bit_idx);
+            let term = bit.multiply(
+                &Self::from_field(F::from(BigUint::one() << i)),
+                builder,
+                driver,
+            )?;
+            sum = sum.add(&term, builder, driver);
+            bits.push(bit);
+        }
+        sum.assert_equal(self, builder, driver);
+        Ok(bits)
+    }
+
+    pub(crate) fn create_range_constraint<
+        P: Pairing<ScalarField = F>,
+        T: NoirWitnessExtensionProtocol<P::ScalarField>,
+    >(
+        &self,
+        num_bits: usize,
+        builder: &mut GenericUltraCircuitBuilder<P, T>,
+        driver: &mut T,
+    ) -> HonkProofResult<()> {
+        if num_bits == 0 {
+            self.assert_is_zero(builder);
+        } else if self.is_constant() {
+            let val: BigUint = self.additive_constant.into();
+            assert!((val.bits() as usize) <= num_bits);
+        } else {
+            let index = self.normalize(builder, driver).get_witness_index();
+            builder.decompose_into_default_range(
+                driver,
+                index,
+                num_bits as u64,
+                GenericUltraCircuitBuilder::<P, T>::DEFAULT_PLOOKUP_RANGE_BITNUM,
+            )?;
+        }
+        Ok(())
+    }
 }
 
 impl<F: PrimeField> From<F> for FieldCT<F> {
@@ -665,26 +1455,20 @@ impl<F: PrimeField> Default for FieldCT<F> {
     }
 }
 
-// TACEO TODO this is just implemented for the Plain backend
-pub(crate) struct WitnessCT<F: PrimeField> {
-    pub(crate) witness: F,
+pub(crate) struct WitnessCT<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> {
+    pub(crate) witness: T::AcvmType,
     pub(crate) witness_index: u32,
 }
 
-impl<F: PrimeField> WitnessCT<F> {
-    const IS_CONSTANT: u32 = FieldCT::<F>::IS_CONSTANT;
-
-    pub(crate) fn from_field<
-        P: Pairing<ScalarField = F>,
-        T: NoirWitnessExtensionProtocol<P::ScalarField>,
-    >(
-        value: P::ScalarField,
+impl<P: Pairing, T: NoirWitnessExtensionProtocol<P::ScalarField>> WitnessCT<P, T> {
+    pub(crate) fn from_acvm_type(
+        value: T::AcvmType,
         builder: &mut GenericUltraCircuitBuilder<P, T>,
     ) -> Self {
-        builder.add_variable(T::AcvmType::from(value));
+        let witness_index = builder.add_variable(value.to_owned());
         Self {
             witness: value,
-            witness_index: Self::IS_CONSTANT,
+            witness_index,
         }
     }
 }
@@ -711,28 +1495,33 @@ pub(crate) struct RomTranscript {
     pub(crate) records: Vec<RomRecord>,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub(crate) enum AccessType {
     Read,
     Write,
 }
 
-pub(crate) struct RamRecord {
+// The index of a RAM record may be shared, since RAM arrays can be accessed with shared indices.
+// The timestamp is always public, since it is the position of the record in the access transcript.
+pub(crate) struct RamRecord<F> {
     pub(crate) index_witness: u32,
     pub(crate) timestamp_witness: u32,
     pub(crate) value_witness: u32,
-    pub(crate) index: u32,
+    pub(crate) index: F,
+    pub(crate) timestamp: u32,
     pub(crate) access_type: AccessType,
     pub(crate) record_witness: u32,
     pub(crate) gate_index: usize,
 }
 
-impl Default for RamRecord {
+impl<F: Default> Default for RamRecord<F> {
     fn default() -> Self {
         Self {
             index_witness: 0,
             timestamp_witness: 0,
             value_witness: 0,
-            index: 0,
+            index: F::default(),
+            timestamp: 0,
             access_type: AccessType::Read,
             record_witness: 0,
             gate_index: 0,
@@ -740,8 +1529,7 @@ impl Default for RamRecord {
     }
 }
 
-#[derive(Default)]
-pub(crate) struct RamTranscript {
+pub(crate) struct RamTranscript<U, M> {
     // Contains the value of each index of the array
     pub(crate) state: Vec<u32>,
 
@@ -749,10 +1537,25 @@ pub(crate) struct RamTranscript {
     // + The constant witness with the index
     // + The value in the memory slot
     // + The actual index value
-    pub(crate) records: Vec<RamRecord>,
+    pub(crate) records: Vec<RamRecord<U>>,
 
     // used for RAM records, to compute the timestamp when performing a read/write
     pub(crate) access_count: usize,
+
+    // A LUT holding the current values of the array. It is created once the array is accessed with a shared
+    // index, afterwards all reads and writes are performed on it.
+    pub(crate) lut: Option<M>,
+}
+
+impl<U, M> Default for RamTranscript<U, M> {
+    fn default() -> Self {
+        Self {
+            state: Vec::new(),
+            records: Vec::new(),
+            access_count: 0,
+            lut: None,
+        }
+    }
 }
 
 #[derive(PartialEq, Eq)]
@@ -874,10 +1677,375 @@ impl<F: PrimeField> PlookupBasicTable<F> {
         table
     }
 
-    pub(crate) fn create_basic_table(id: BasicTableId, index: usize) -> Self {
-        // TACEO TODO this is a dummy implementation
-        assert!(id == BasicTableId::HonkDummyBasic1 || id == BasicTableId::HonkDummyBasic2);
+    fn generate_sparse_table_with_rotation<
+        const BASE: u64,
+        const BITS_PER_SLICE: u64,
+        const NUM_ROTATED_BITS: u32,
+    >(
+        id: BasicTableId,
+        table_index: usize,
+    ) -> PlookupBasicTable<F> {
+        let mut table = PlookupBasicTable::new();
+        table.id = id;
+        table.table_index = table_index;
+        let table_size = 1u64 << BITS_PER_SLICE;
+        table.use_twin_keys = false;
+
+        for i in 0..table_size {
+            let source = i;
+            let target = BasicTableId::map_into_sparse_form::<F, BASE>(source);
+            table.column_1.push(F::from(source));
+            table.column_2.push(target);
+
+            if NUM_ROTATED_BITS != 0 {
+                let rotated = BasicTableId::map_into_sparse_form::<F, BASE>(
+                    (source as u32).rotate_right(NUM_ROTATED_BITS) as u64,
+                );
+                table.column_3.push(rotated);
+            } else {
+                table.column_3.push(target);
+            }
+        }
+
+        table.get_values_from_key =
+            BasicTableId::get_sparse_table_with_rotation_values::<F, BASE, NUM_ROTATED_BITS>;
+
+        let sparse_step_size = F::from(BASE).pow([BITS_PER_SLICE]);
+        table.column_1_step_size = F::from(1u64 << 11);
+        table.column_2_step_size = sparse_step_size;
+        table.column_3_step_size = sparse_step_size;
+
+        table
+    }
+
+    fn generate_sparse_normalization_table<const BASE: u64, const NUM_BITS: u64>(
+        id: BasicTableId,
+        table_index: usize,
+        base_table: &[u64],
+        get_values_from_key: fn([u64; 2]) -> [F; 2],
+    ) -> PlookupBasicTable<F> {
+        // If the sparse form is the sum of k values, then every sparse limb will hold a value in the range [0, k]. The
+        // base table maps each of these limb values to its normalized output bit.
+        let mut table = PlookupBasicTable::new();
+        table.id = id;
+        table.table_index = table_index;
+        let table_size = BASE.pow(NUM_BITS as u32);
+        table.use_twin_keys = false;
+
+        for i in 0..table_size {
+            let mut limbs = i;
+            let mut key = 0u64;
+            for j in 0..NUM_BITS {
+                let table_idx = (limbs % BASE) as usize;
+                limbs /= BASE;
+                key += base_table[table_idx] << j;
+            }
+
+            table.column_1.push(F::from(i));
+            table.column_2.push(F::from(key));
+            table.column_3.push(F::zero());
+        }
+
+        table.get_values_from_key = get_values_from_key;
 
+        table.column_1_step_size = F::from(table_size);
+        table.column_2_step_size = F::from(1u64 << NUM_BITS);
+        table.column_3_step_size = F::zero();
+
+        table
+    }
+
+    fn generate_xor_rotate_table<const BITS_PER_SLICE: u64, const NUM_ROTATED_OUTPUT_BITS: u32>(
+        id: BasicTableId,
+        table_index: usize,
+    ) -> PlookupBasicTable<F> {
+        let base = 1u64 << BITS_PER_SLICE;
+        let mut table = PlookupBasicTable::new();
+        table.id = id;
+        table.table_index = table_index;
+        table.use_twin_keys = true;
+
+        for i in 0..base {
+            for j in 0..base {
+                table.column_1.push(F::from(i));
+                table.column_2.push(F::from(j));
+                table
+                    .column_3
+                    .push(F::from((i ^ j).rotate_right(NUM_ROTATED_OUTPUT_BITS)));
+            }
+        }
+
+        table.get_values_from_key =
+            BasicTableId::get_xor_rotate_values_from_key::<F, NUM_ROTATED_OUTPUT_BITS>;
+
+        let base = F::from(base);
+        table.column_1_step_size = base;
+        table.column_2_step_size = base;
+        table.column_3_step_size = base;
+
+        table
+    }
+
+    fn generate_blake_xor_rotate_table<
+        const BITS_PER_SLICE: u64,
+        const NUM_ROTATED_OUTPUT_BITS: u32,
+        const FILTER: bool,
+    >(
+        id: BasicTableId,
+        table_index: usize,
+    ) -> PlookupBasicTable<F> {
+        let base = 1u64 << BITS_PER_SLICE;
+        let mut table = PlookupBasicTable::new();
+        table.id = id;
+        table.table_index = table_index;
+        table.use_twin_keys = true;
+
+        for i in 0..base {
+            for j in 0..base {
+                let [value, _] = BasicTableId::get_blake_xor_rotate_values_from_key::<
+                    F,
+                    NUM_ROTATED_OUTPUT_BITS,
+                    FILTER,
+                >([i, j]);
+                table.column_1.push(F::from(i));
+                table.column_2.push(F::from(j));
+                table.column_3.push(value);
+            }
+        }
+
+        table.get_values_from_key = BasicTableId::get_blake_xor_rotate_values_from_key::<
+            F,
+            NUM_ROTATED_OUTPUT_BITS,
+            FILTER,
+        >;
+
+        let base = F::from(base);
+        table.column_1_step_size = base;
+        table.column_2_step_size = base;
+        table.column_3_step_size = base;
+
+        table
+    }
+
+    fn generate_and_rotate_table<const BITS_PER_SLICE: u64, const NUM_ROTATED_OUTPUT_BITS: u32>(
+        id: BasicTableId,
+        table_index: usize,
+    ) -> PlookupBasicTable<F> {
+        let base = 1u64 << BITS_PER_SLICE;
+        let mut table = PlookupBasicTable::new();
+        table.id = id;
+        table.table_index = table_index;
+        table.use_twin_keys = true;
+
+        for i in 0..base {
+            for j in 0..base {
+                table.column_1.push(F::from(i));
+                table.column_2.push(F::from(j));
+                table
+                    .column_3
+                    .push(F::from((i & j).rotate_right(NUM_ROTATED_OUTPUT_BITS)));
+            }
+        }
+
+        table.get_values_from_key =
+            BasicTableId::get_and_rotate_values_from_key::<F, NUM_ROTATED_OUTPUT_BITS>;
+
+        let base = F::from(base);
+        table.column_1_step_size = base;
+        table.column_2_step_size = base;
+        table.column_3_step_size = base;
+
+        table
+    }
+
+    fn generate_aes_sparse_table(id: BasicTableId, table_index: usize) -> PlookupBasicTable<F> {
+        let mut table = PlookupBasicTable::new();
+        table.id = id;
+        table.table_index = table_index;
+        table.use_twin_keys = false;
+
+        for i in 0..256u64 {
+            let [sparse, _] = BasicTableId::get_aes_sparse_values_from_key::<F>([i, 0]);
+            table.column_1.push(F::from(i));
+            table.column_2.push(sparse);
+            table.column_3.push(F::zero());
+        }
+
+        table.get_values_from_key = BasicTableId::get_aes_sparse_values_from_key::<F>;
+
+        table.column_1_step_size = F::from(256u64);
+        table.column_2_step_size = F::zero();
+        table.column_3_step_size = F::zero();
+
+        table
+    }
+
+    /// Generates the table which maps sparse values with 4 base-9 digits to the sparse form of the parities of their
+    /// digits. Since every digit can take all 9 values, the row index of an entry is equal to its key.
+    fn generate_aes_sparse_normalization_table(
+        id: BasicTableId,
+        table_index: usize,
+    ) -> PlookupBasicTable<F> {
+        let mut table = PlookupBasicTable::new();
+        table.id = id;
+        table.table_index = table_index;
+        table.use_twin_keys = false;
+
+        let table_size = AesTables::BASE.pow(AesTables::NORMALIZATION_TABLE_DIGITS);
+        for i in 0..table_size {
+            let [normalized, _] =
+                BasicTableId::get_aes_sparse_normalization_values_from_key::<F>([i, 0]);
+            table.column_1.push(F::from(i));
+            table.column_2.push(normalized);
+            table.column_3.push(F::zero());
+        }
+
+        table.get_values_from_key = BasicTableId::get_aes_sparse_normalization_values_from_key::<F>;
+
+        table.column_1_step_size = F::from(table_size);
+        table.column_2_step_size = F::from(table_size);
+        table.column_3_step_size = F::zero();
+
+        table
+    }
+
+    /// Generates the table which maps a byte in sparse form to its S-box output and 3 times its S-box output, both in
+    /// sparse form. The row index of an entry is the byte itself.
+    fn generate_aes_sbox_table(id: BasicTableId, table_index: usize) -> PlookupBasicTable<F> {
+        let mut table = PlookupBasicTable::new();
+        table.id = id;
+        table.table_index = table_index;
+        table.use_twin_keys = false;
+
+        for i in 0..256u64 {
+            let key = (0..8)
+                .filter(|j| (i >> j) & 1 == 1)
+                .map(|j| AesTables::BASE.pow(j))
+                .sum::<u64>();
+            let [sbox_value, tripled] = BasicTableId::get_aes_sbox_values_from_key::<F>([key, 0]);
+            table.column_1.push(F::from(key));
+            table.column_2.push(sbox_value);
+            table.column_3.push(tripled);
+        }
+
+        table.get_values_from_key = BasicTableId::get_aes_sbox_values_from_key::<F>;
+
+        table.column_1_step_size = F::zero();
+        table.column_2_step_size = F::zero();
+        table.column_3_step_size = F::zero();
+
+        table
+    }
+
+    /// Generates the table of the points offset + i * base_point * 2^(BITS_PER_TABLE * table_index) of a fixed base
+    /// multitable. The row index of an entry is equal to its key.
+    fn generate_fixed_base_table(id: BasicTableId, table_index: usize) -> PlookupBasicTable<F> {
+        let (multitable_index, index_in_multitable) =
+            FixedBaseParams::get_table_indices(&id).expect("Fixed base table");
+        let mut table = PlookupBasicTable::new();
+        table.id = id;
+        table.table_index = table_index;
+        table.use_twin_keys = false;
+
+        let points =
+            FixedBaseParams::generate_table_points::<F>(multitable_index, index_in_multitable);
+        for (i, (x, y)) in points.into_iter().enumerate() {
+            table.column_1.push(F::from(i as u64));
+            table.column_2.push(x);
+            table.column_3.push(y);
+        }
+
+        table.get_values_from_key = match multitable_index {
+            0 => FixedBaseParams::get_values_from_key_table::<F, 0>(),
+            1 => FixedBaseParams::get_values_from_key_table::<F, 1>(),
+            2 => FixedBaseParams::get_values_from_key_table::<F, 2>(),
+            3 => FixedBaseParams::get_values_from_key_table::<F, 3>(),
+            _ => unreachable!("There are four fixed base multitables"),
+        }[index_in_multitable];
+
+        table.column_1_step_size = F::from(FixedBaseParams::MAX_TABLE_SIZE as u64);
+        table.column_2_step_size = F::zero();
+        table.column_3_step_size = F::zero();
+
+        table
+    }
+
+    fn generate_keccak_input_table(id: BasicTableId, table_index: usize) -> PlookupBasicTable<F> {
+        let mut table = PlookupBasicTable::new();
+        table.id = id;
+        table.table_index = table_index;
+        table.use_twin_keys = false;
+
+        let table_bits = KeccakTables::FORMAT_TABLE_BITS;
+        for i in 0..1u64 << table_bits {
+            let [sparse, msb] = BasicTableId::get_keccak_input_values::<F>([i, 0]);
+            table.column_1.push(F::from(i));
+            table.column_2.push(sparse);
+            table.column_3.push(msb);
+        }
+
+        table.get_values_from_key = BasicTableId::get_keccak_input_values::<F>;
+
+        table.column_1_step_size = F::from(1u64 << table_bits);
+        table.column_2_step_size = F::from(KeccakTables::BASE.pow(table_bits as u32));
+        table.column_3_step_size = F::zero();
+
+        table
+    }
+
+    /// Generates a table whose keys are base-11 numbers with TABLE_BITS digits, where each digit is smaller than
+    /// EFFECTIVE_BASE. The rows are ordered by the digits of the key, starting with the least significant one.
+    fn generate_keccak_sparse_table<const EFFECTIVE_BASE: u64, const TABLE_BITS: u32>(
+        id: BasicTableId,
+        table_index: usize,
+        get_values_from_key: fn([u64; 2]) -> [F; 2],
+        column_2_step_size: F,
+    ) -> PlookupBasicTable<F> {
+        let mut table = PlookupBasicTable::new();
+        table.id = id;
+        table.table_index = table_index;
+        table.use_twin_keys = false;
+
+        let table_size = EFFECTIVE_BASE.pow(TABLE_BITS);
+        for i in 0..table_size {
+            let mut digits = i;
+            let mut key = 0u64;
+            let mut base_shift = 1u64;
+            for _ in 0..TABLE_BITS {
+                key += (digits % EFFECTIVE_BASE) * base_shift;
+                digits /= EFFECTIVE_BASE;
+                base_shift *= KeccakTables::BASE;
+            }
+
+            let [value_2, value_3] = get_values_from_key([key, 0]);
+            table.column_1.push(F::from(key));
+            table.column_2.push(value_2);
+            table.column_3.push(value_3);
+        }
+
+        table.get_values_from_key = get_values_from_key;
+
+        table.column_1_step_size = F::from(KeccakTables::BASE.pow(TABLE_BITS));
+        table.column_2_step_size = column_2_step_size;
+        table.column_3_step_size = F::zero();
+
+        table
+    }
+
+    fn generate_keccak_rho_table<const TABLE_BITS: u32>(
+        id: BasicTableId,
+        table_index: usize,
+    ) -> PlookupBasicTable<F> {
+        Self::generate_keccak_sparse_table::<{ KeccakTables::RHO_EFFECTIVE_BASE }, TABLE_BITS>(
+            id,
+            table_index,
+            BasicTableId::get_keccak_rho_values::<F, TABLE_BITS>,
+            F::from(KeccakTables::BASE.pow(TABLE_BITS)),
+        )
+    }
+
+    pub(crate) fn create_basic_table(id: BasicTableId, index: usize) -> Self {
+        // TACEO TODO not all tables are implemented
         match id {
             BasicTableId::HonkDummyBasic1 => Self::generate_honk_dummy_table::<
                 { BasicTableId::HonkDummyBasic1 as u64 },
@@ -885,6 +2053,113 @@ impl<F: PrimeField> PlookupBasicTable<F> {
             BasicTableId::HonkDummyBasic2 => Self::generate_honk_dummy_table::<
                 { BasicTableId::HonkDummyBasic2 as u64 },
             >(id, index),
+            BasicTableId::Sha256WitnessSlice3 => {
+                Self::generate_sparse_table_with_rotation::<16, 3, 0>(id, index)
+            }
+            BasicTableId::Sha256WitnessSlice7Rotate4 => {
+                Self::generate_sparse_table_with_rotation::<16, 7, 4>(id, index)
+            }
+            BasicTableId::Sha256WitnessSlice8Rotate7 => {
+                Self::generate_sparse_table_with_rotation::<16, 8, 7>(id, index)
+            }
+            BasicTableId::Sha256WitnessSlice14Rotate1 => {
+                Self::generate_sparse_table_with_rotation::<16, 14, 1>(id, index)
+            }
+            BasicTableId::Sha256ChNormalize => Self::generate_sparse_normalization_table::<28, 2>(
+                id,
+                index,
+                &Sha256Tables::CHOOSE_NORMALIZATION_TABLE,
+                BasicTableId::get_choose_normalization_values::<F>,
+            ),
+            BasicTableId::Sha256MajNormalize => Self::generate_sparse_normalization_table::<16, 3>(
+                id,
+                index,
+                &Sha256Tables::MAJORITY_NORMALIZATION_TABLE,
+                BasicTableId::get_majority_normalization_values::<F>,
+            ),
+            BasicTableId::Sha256WitnessNormalize => {
+                Self::generate_sparse_normalization_table::<16, 3>(
+                    id,
+                    index,
+                    &Sha256Tables::WITNESS_EXTENSION_NORMALIZATION_TABLE,
+                    BasicTableId::get_witness_extension_normalization_values::<F>,
+                )
+            }
+            BasicTableId::Sha256Base28 => {
+                Self::generate_sparse_table_with_rotation::<28, 11, 0>(id, index)
+            }
+            BasicTableId::Sha256Base28Rotate6 => {
+                Self::generate_sparse_table_with_rotation::<28, 11, 6>(id, index)
+            }
+            BasicTableId::Sha256Base28Rotate3 => {
+                Self::generate_sparse_table_with_rotation::<28, 11, 3>(id, index)
+            }
+            BasicTableId::Sha256Base16 => {
+                Self::generate_sparse_table_with_rotation::<16, 11, 0>(id, index)
+            }
+            BasicTableId::Sha256Base16Rotate2 => {
+                Self::generate_sparse_table_with_rotation::<16, 11, 2>(id, index)
+            }
+            BasicTableId::UintXorRotate0 => Self::generate_xor_rotate_table::<6, 0>(id, index),
+            BasicTableId::UintAndRotate0 => Self::generate_and_rotate_table::<6, 0>(id, index),
+            BasicTableId::BlakeXorRotate0 => {
+                Self::generate_blake_xor_rotate_table::<6, 0, false>(id, index)
+            }
+            BasicTableId::BlakeXorRotate0Slice5Mod4 => {
+                Self::generate_blake_xor_rotate_table::<5, 0, true>(id, index)
+            }
+            BasicTableId::BlakeXorRotate1 => {
+                Self::generate_blake_xor_rotate_table::<6, 1, false>(id, index)
+            }
+            BasicTableId::BlakeXorRotate2 => {
+                Self::generate_blake_xor_rotate_table::<6, 2, false>(id, index)
+            }
+            BasicTableId::BlakeXorRotate4 => {
+                Self::generate_blake_xor_rotate_table::<6, 4, false>(id, index)
+            }
+            BasicTableId::AesSparseMap => Self::generate_aes_sparse_table(id, index),
+            BasicTableId::AesSparseNormalize => {
+                Self::generate_aes_sparse_normalization_table(id, index)
+            }
+            BasicTableId::AesSboxMap => Self::generate_aes_sbox_table(id, index),
+            BasicTableId::KeccakInput => Self::generate_keccak_input_table(id, index),
+            BasicTableId::KeccakTheta => Self::generate_keccak_sparse_table::<
+                { KeccakTables::BASE },
+                { KeccakTables::THETA_TABLE_BITS as u32 },
+            >(
+                id,
+                index,
+                BasicTableId::get_keccak_theta_values::<F>,
+                F::from(KeccakTables::BASE.pow(KeccakTables::THETA_TABLE_BITS as u32)),
+            ),
+            BasicTableId::KeccakChi => Self::generate_keccak_sparse_table::<
+                { KeccakTables::CHI_EFFECTIVE_BASE },
+                { KeccakTables::CHI_TABLE_BITS as u32 },
+            >(
+                id,
+                index,
+                BasicTableId::get_keccak_chi_values::<F>,
+                F::from(KeccakTables::BASE.pow(KeccakTables::CHI_TABLE_BITS as u32)),
+            ),
+            BasicTableId::KeccakOutput => {
+                Self::generate_keccak_sparse_table::<2, { KeccakTables::FORMAT_TABLE_BITS as u32 }>(
+                    id,
+                    index,
+                    BasicTableId::get_keccak_output_values::<F>,
+                    F::from(1u64 << KeccakTables::FORMAT_TABLE_BITS),
+                )
+            }
+            BasicTableId::KeccakRho1 => Self::generate_keccak_rho_table::<1>(id, index),
+            BasicTableId::KeccakRho2 => Self::generate_keccak_rho_table::<2>(id, index),
+            BasicTableId::KeccakRho3 => Self::generate_keccak_rho_table::<3>(id, index),
+            BasicTableId::KeccakRho4 => Self::generate_keccak_rho_table::<4>(id, index),
+            BasicTableId::KeccakRho5 => Self::generate_keccak_rho_table::<5>(id, index),
+            BasicTableId::KeccakRho6 => Self::generate_keccak_rho_table::<6>(id, index),
+            BasicTableId::KeccakRho7 => Self::generate_keccak_rho_table::<7>(id, index),
+            BasicTableId::KeccakRho8 => Self::generate_keccak_rho_table::<8>(id, index),
+            _ if FixedBaseParams::get_table_indices(&id).is_some() => {
+                Self::generate_fixed_base_table(id, index)
+            }
             _ => {
                 todo!()
             }
@@ -978,6 +2253,21 @@ impl<F: PrimeField> PlookupMultiTable<F> {
         res
     }
 
+    pub(crate) fn new_from_vec(
+        column_1_coefficients: Vec<F>,
+        column_2_coefficients: Vec<F>,
+        column_3_coefficients: Vec<F>,
+    ) -> Self {
+        let mut res = Self {
+            column_1_coefficients,
+            column_2_coefficients,
+            column_3_coefficients,
+            ..Default::default()
+        };
+        res.init_step_sizes();
+        res
+    }
+
     fn init_step_sizes(&mut self) {
         let num_lookups = self.column_1_coefficients.len();
         self.column_1_step_sizes.push(F::one());
@@ -1008,6 +2298,27 @@ pub(crate) struct ReadData<F: Clone> {
     pub(crate) columns: [Vec<F>; 3],
 }
 
+impl<F: PrimeField> ReadData<F> {
+    pub(crate) fn into_acvm_type<A: From<F> + Clone>(self) -> ReadData<A> {
+        let [column_1, column_2, column_3] = self.columns;
+        ReadData {
+            lookup_entries: self
+                .lookup_entries
+                .into_iter()
+                .map(|entry| LookupEntry {
+                    key: entry.key,
+                    value: entry.value.map(A::from),
+                })
+                .collect(),
+            columns: [
+                column_1.into_iter().map(A::from).collect(),
+                column_2.into_iter().map(A::from).collect(),
+                column_3.into_iter().map(A::from).collect(),
+            ],
+        }
+    }
+}
+
 impl<F: Clone> Index<ColumnIdx> for ReadData<F> {
     type Output = Vec<F>;
 
@@ -1022,6 +2333,7 @@ impl<F: Clone> IndexMut<ColumnIdx> for ReadData<F> {
     }
 }
 
+#[derive(Clone)]
 pub(crate) enum ColumnIdx {
     C1,
     C2,
@@ -1035,6 +2347,14 @@ pub(crate) struct RangeList {
     pub(crate) variable_indices: Vec<u32>,
 }
 
+pub(crate) struct NonNativeFieldWitnesses<F: PrimeField> {
+    pub(crate) a: [u32; 4],
+    pub(crate) b: [u32; 4],
+    pub(crate) q: [u32; 4],
+    pub(crate) r: [u32; 4],
+    pub(crate) neg_modulus: [F; 4],
+}
+
 #[derive(Clone)]
 pub(crate) struct CachedPartialNonNativeFieldMultiplication<F: PrimeField> {
     pub(crate) a: [u32; 5],
@@ -2,9 +2,9 @@
 cargo run --release --bin co-noir -- split-input --circuit test_vectors/poseidon_input2/poseidon_input2.json --input test_vectors/poseidon_input2/Prover1.toml --protocol REP3 --out-dir test_vectors/poseidon_input2
 cargo run --release --bin co-noir -- split-input --circuit test_vectors/poseidon_input2/poseidon_input2.json --input test_vectors/poseidon_input2/Prover2.toml --protocol REP3 --out-dir test_vectors/poseidon_input2
 # merge inputs into single input file
-cargo run --release --bin co-noir -- merge-input-shares --inputs test_vectors/poseidon_input2/Prover1.toml.0.shared --inputs test_vectors/poseidon_input2/Prover2.toml.0.shared --protocol REP3 --out test_vectors/poseidon_input2/Prover.toml.0.shared
-cargo run --release --bin co-noir -- merge-input-shares --inputs test_vectors/poseidon_input2/Prover1.toml.1.shared --inputs test_vectors/poseidon_input2/Prover2.toml.1.shared --protocol REP3 --out test_vectors/poseidon_input2/Prover.toml.1.shared
-cargo run --release --bin co-noir -- merge-input-shares --inputs test_vectors/poseidon_input2/Prover1.toml.2.shared --inputs test_vectors/poseidon_input2/Prover2.toml.2.shared --protocol REP3 --out test_vectors/poseidon_input2/Prover.toml.2.shared
+cargo run --release --bin co-noir -- merge-input-shares --inputs test_vectors/poseidon_input2/Prover1.toml.0.shared --inputs test_vectors/poseidon_input2/Prover2.toml.0.shared --circuit test_vectors/poseidon_input2/poseidon_input2.json --protocol REP3 --out test_vectors/poseidon_input2/Prover.toml.0.shared
+cargo run --release --bin co-noir -- merge-input-shares --inputs test_vectors/poseidon_input2/Prover1.toml.1.shared --inputs test_vectors/poseidon_input2/Prover2.toml.1.shared --circuit test_vectors/poseidon_input2/poseidon_input2.json --protocol REP3 --out test_vectors/poseidon_input2/Prover.toml.1.shared
+cargo run --release --bin co-noir -- merge-input-shares --inputs test_vectors/poseidon_input2/Prover1.toml.2.shared --inputs test_vectors/poseidon_input2/Prover2.toml.2.shared --circuit test_vectors/poseidon_input2/poseidon_input2.json --protocol REP3 --out test_vectors/poseidon_input2/Prover.toml.2.shared
 # run witness extension in MPC
 cargo run --release --bin co-noir -- generate-witness --input test_vectors/poseidon_input2/Prover.toml.0.shared --circuit test_vectors/poseidon_input2/poseidon_input2.json --protocol REP3 --config configs/party1.toml --out test_vectors/poseidon_input2/poseidon_input2.gz.0.shared &
 cargo run --release --bin co-noir -- generate-witness --input test_vectors/poseidon_input2/Prover.toml.1.shared --circuit test_vectors/poseidon_input2/poseidon_input2.json --protocol REP3 --config configs/party2.toml --out test_vectors/poseidon_input2/poseidon_input2.gz.1.shared &
@@ -1,14 +1,18 @@
 use ark_bn254::Bn254;
 use ark_ff::Zero;
 use clap::{Parser, Subcommand};
-use co_acvm::{solver::Rep3CoSolver, Rep3AcvmType, ShamirAcvmType};
+use co_acvm::{
+    solver::{differential, Rep3CoSolver},
+    PlainAcvmSolver, Rep3AcvmSolver, Rep3AcvmType, ShamirAcvmSolver, ShamirAcvmType,
+};
 use co_noir::{
-    convert_witness_to_vec_rep3, file_utils, share_input_rep3, share_rep3, share_shamir,
-    translate_witness_share_rep3, BuildAndGenerateProofCli, BuildAndGenerateProofConfig,
-    BuildProvingKeyCLi, BuildProvingKeyConfig, CreateVKCli, CreateVKConfig, GenerateProofCli,
-    GenerateProofConfig, GenerateWitnessCli, GenerateWitnessConfig, MPCProtocol,
-    MergeInputSharesCli, MergeInputSharesConfig, PubShared, SplitInputCli, SplitInputConfig,
-    SplitProvingKeyCli, SplitProvingKeyConfig, SplitWitnessCli, SplitWitnessConfig, TranscriptHash,
+    convert_witness_to_vec_rep3, file_utils, read_proving_key_share, share_input_rep3, share_rep3,
+    share_shamir, translate_witness_share_rep3, write_proving_key_share, BuildAndGenerateProofCli,
+    BuildAndGenerateProofConfig, BuildProvingKeyCLi, BuildProvingKeyConfig, CompareWitnessCli,
+    CompareWitnessConfig, CreateVKCli, CreateVKConfig, GenerateProofCli, GenerateProofConfig,
+    GenerateWitnessCli, GenerateWitnessConfig, MPCProtocol, MergeInputSharesCli,
+    MergeInputSharesConfig, PubShared, SplitInputCli, SplitInputConfig, SplitProvingKeyCli,
+    SplitProvingKeyConfig, SplitWitnessCli, SplitWitnessConfig, TranscriptHash,
     TranslateProvingKeyCli, TranslateProvingKeyConfig, TranslateWitnessCli, TranslateWitnessConfig,
     VerifyCli, VerifyConfig,
 };
@@ -81,6 +85,8 @@ enum Commands {
     MergeInputShares(MergeInputSharesCli),
     /// Evaluates the extended witness generation for the specified circuit and input share in MPC
     GenerateWitness(GenerateWitnessCli),
+    /// Evaluates the extended witness generation for the specified circuit and input in plain and in an in-process 3-party REP3 MPC, and reports the first witness where both differ
+    CompareWitness(CompareWitnessCli),
     /// Translates the witness generated with one MPC protocol to a witness for a different one
     TranslateWitness(TranslateWitnessCli),
     /// Translates the proving key generated with one MPC protocol to a proving key for a different one
@@ -125,6 +131,10 @@ fn main() -> color_eyre::Result<ExitCode> {
             let config = GenerateWitnessConfig::parse(cli).context("while parsing config")?;
             run_generate_witness(config)
         }
+        Commands::CompareWitness(cli) => {
+            let config = CompareWitnessConfig::parse(cli).context("while parsing config")?;
+            run_compare_witness(config)
+        }
         Commands::TranslateWitness(cli) => {
             let config = TranslateWitnessConfig::parse(cli).context("while parsing config")?;
             run_translate_witness(config)
@@ -275,21 +285,32 @@ fn run_split_proving_key(config: SplitProvingKeyConfig) -> color_eyre::Result<Ex
     // parse witness
     let witness = Utils::get_witness_from_file(&witness_path).context("while parsing witness")?;
 
-    let builder =
-        UltraCircuitBuilder::<Bn254>::create_circuit(constraint_system, 0, witness, true, false);
+    let mut driver = PlainAcvmSolver::default();
+    let builder = UltraCircuitBuilder::<Bn254>::create_circuit(
+        constraint_system,
+        0,
+        witness,
+        true,
+        false,
+        &mut driver,
+    )?;
     // parse the crs
     let prover_crs = PlainProvingKey::get_prover_crs(
         &builder,
         crs_path.to_str().context("while opening crs file")?,
     )
     .context("failed to get prover crs")?;
-    let proving_key = PlainProvingKey::create(builder, prover_crs);
+    let proving_key = PlainProvingKey::create(builder, prover_crs)?;
 
+    // The wires and the lookup read counts depend on the witness and are therefore shared
     let witness_entities = proving_key
         .polynomials
         .witness
         .get_wires()
         .iter()
+        .chain(std::iter::once(
+            proving_key.polynomials.witness.lookup_read_counts(),
+        ))
         .flat_map(|el| el.iter().cloned())
         .collect::<Vec<_>>();
 
@@ -322,8 +343,7 @@ fn run_split_proving_key(config: SplitProvingKeyConfig) -> color_eyre::Result<Ex
                 let path = out_dir.join(format!("{}.{}.shared", base_name, i));
                 let out_file =
                     BufWriter::new(File::create(&path).context("while creating output file")?);
-                bincode::serialize_into(out_file, &key)
-                    .context("while serializing proving_key share")?;
+                write_proving_key_share(out_file, &key)?;
                 tracing::info!("Wrote proving_key share {} to file {}", i, path.display());
             }
         }
@@ -346,8 +366,7 @@ fn run_split_proving_key(config: SplitProvingKeyConfig) -> color_eyre::Result<Ex
                 let path = out_dir.join(format!("{}.{}.shared", base_name, i));
                 let out_file =
                     BufWriter::new(File::create(&path).context("while creating output file")?);
-                bincode::serialize_into(out_file, &key)
-                    .context("while serializing proving_key share")?;
+                write_proving_key_share(out_file, &key)?;
                 tracing::info!("Wrote proving_key share {} to file {}", i, path.display());
             }
         }
@@ -411,6 +430,7 @@ fn run_split_input(config: SplitInputConfig) -> color_eyre::Result<ExitCode> {
 #[instrument(skip(config))]
 fn run_merge_input_shares(config: MergeInputSharesConfig) -> color_eyre::Result<ExitCode> {
     let inputs = config.inputs;
+    let circuit = config.circuit;
     let protocol = config.protocol;
     let out = config.out;
 
@@ -427,6 +447,18 @@ fn run_merge_input_shares(config: MergeInputSharesConfig) -> color_eyre::Result<
         file_utils::check_file_exists(input)?;
     }
 
+    // parse the ABI of the constraint system if we validate the merged input
+    let abi = match circuit {
+        Some(circuit) => {
+            let circuit_path = PathBuf::from(&circuit);
+            file_utils::check_file_exists(&circuit_path)?;
+            let compiled_program = Utils::get_program_artifact_from_file(&circuit_path)
+                .context("while parsing program artifact")?;
+            Some(compiled_program.abi)
+        }
+        None => None,
+    };
+
     let start = Instant::now();
     let input_shares = inputs
         .iter()
@@ -441,15 +473,30 @@ fn run_merge_input_shares(config: MergeInputSharesConfig) -> color_eyre::Result<
         })
         .collect::<Result<Vec<_>, _>>()?;
     let mut result = BTreeMap::new();
+    let mut sources = BTreeMap::new();
 
-    for input_share in input_shares.into_iter() {
+    for (input, input_share) in inputs.iter().zip(input_shares) {
         for (wit, share) in input_share.into_iter() {
-            if result.contains_key(&wit) {
-                return Err(eyre!("Duplicate witness found in input shares"));
+            if let Some(source) = sources.get(&wit) {
+                let name = match &abi {
+                    Some(abi) => Rep3CoSolver::<ark_bn254::Fr, Rep3MpcNet>::input_path(&wit, abi),
+                    None => wit,
+                };
+                return Err(eyre!(
+                    "Duplicate input {} found in input shares {} and {}",
+                    name,
+                    source.display(),
+                    input.display()
+                ));
             }
+            sources.insert(wit.clone(), input);
             result.insert(wit, share);
         }
     }
+    if let Some(abi) = &abi {
+        Rep3CoSolver::<ark_bn254::Fr, Rep3MpcNet>::validate_string_map(&result, abi)
+            .context("while validating the merged input share")?;
+    }
     let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
     tracing::info!("Merging took {} ms", duration_ms);
 
@@ -468,6 +515,7 @@ fn run_generate_witness(config: GenerateWitnessConfig) -> color_eyre::Result<Exi
     let circuit = config.circuit;
     let protocol = config.protocol;
     let out = config.out;
+    let open_assertions = config.open_assertions;
 
     if protocol != MPCProtocol::REP3 {
         return Err(eyre!(
@@ -500,7 +548,8 @@ fn run_generate_witness(config: GenerateWitnessConfig) -> color_eyre::Result<Exi
 
     // init MPC protocol
     let rep3_vm = Rep3CoSolver::from_network_with_witness(net, compiled_program, input_share)
-        .context("while creating VM")?;
+        .context("while creating VM")?
+        .with_opened_assertions(open_assertions);
 
     // execute witness generation in MPC
     let start = Instant::now();
@@ -520,6 +569,36 @@ fn run_generate_witness(config: GenerateWitnessConfig) -> color_eyre::Result<Exi
     Ok(ExitCode::SUCCESS)
 }
 
+#[instrument(skip(config))]
+fn run_compare_witness(config: CompareWitnessConfig) -> color_eyre::Result<ExitCode> {
+    let input = config.input;
+    let circuit = config.circuit;
+
+    file_utils::check_file_exists(&input)?;
+    let circuit_path = PathBuf::from(&circuit);
+    file_utils::check_file_exists(&circuit_path)?;
+
+    // parse constraint system
+    let compiled_program = Utils::get_program_artifact_from_file(&circuit_path)
+        .context("while parsing program artifact")?;
+
+    // run the witness extension in plain and in MPC
+    let start = Instant::now();
+    let mut rng = rand::thread_rng();
+    let divergence = differential::compare_rep3_with_plain(&compiled_program, &input, &mut rng)
+        .context("while comparing the witness extensions")?;
+    let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
+    tracing::info!("Comparing witness extensions took {} ms", duration_ms);
+
+    if let Some(divergence) = divergence {
+        tracing::error!("The witness extension in MPC diverges: {divergence}");
+        Ok(ExitCode::FAILURE)
+    } else {
+        tracing::info!("The witness extension in MPC matches the plain one");
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
 #[instrument(skip(config))]
 fn run_translate_witness(config: TranslateWitnessConfig) -> color_eyre::Result<ExitCode> {
     let witness = config.witness;
@@ -603,7 +682,7 @@ fn run_translate_proving_key(config: TranslateProvingKeyConfig) -> color_eyre::R
     let proving_key_file =
         BufReader::new(File::open(proving_key).context("trying to open witness share file")?);
     let proving_key: ProvingKey<Rep3UltraHonkDriver<Rep3MpcNet>, Bn254> =
-        bincode::deserialize_from(proving_key_file).context("while deserializing witness share")?;
+        read_proving_key_share(proving_key_file)?;
 
     // extract shares
     let shares = proving_key
@@ -636,7 +715,7 @@ fn run_translate_proving_key(config: TranslateProvingKeyConfig) -> color_eyre::R
     let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
     tracing::info!("Party {}: Translating shares took {} ms", id, duration_ms);
 
-    if translated_shares.len() != 4 * proving_key.circuit_size as usize {
+    if translated_shares.len() != 5 * proving_key.circuit_size as usize {
         return Err(eyre!("Invalid number of shares translated"));
     };
 
@@ -666,7 +745,7 @@ fn run_translate_proving_key(config: TranslateProvingKeyConfig) -> color_eyre::R
 
     // write result to output file
     let out_file = BufWriter::new(std::fs::File::create(&out)?);
-    bincode::serialize_into(out_file, &result)?;
+    write_proving_key_share(out_file, &result)?;
     tracing::info!("Proving_key successfully written to {}", out.display());
     Ok(ExitCode::SUCCESS)
 }
@@ -708,6 +787,7 @@ fn run_build_proving_key(config: BuildProvingKeyConfig) -> color_eyre::Result<Ex
             // connect to network
             let net = Rep3MpcNet::new(network_config)?;
             let id = net.get_id();
+            let mut driver = Rep3AcvmSolver::new(net)?;
 
             // Create the circuit
             tracing::info!("Party {}: starting to generate proving key..", id);
@@ -718,7 +798,8 @@ fn run_build_proving_key(config: BuildProvingKeyConfig) -> color_eyre::Result<Ex
                 witness_share,
                 true,
                 false,
-            );
+                &mut driver,
+            )?;
 
             // parse the crs
             let prover_crs = ProvingKey::<Rep3UltraHonkDriver<Rep3MpcNet>, _>::get_prover_crs(
@@ -729,7 +810,7 @@ fn run_build_proving_key(config: BuildProvingKeyConfig) -> color_eyre::Result<Ex
 
             // Get the proving key and prover
             let proving_key: ProvingKey<Rep3UltraHonkDriver<Rep3MpcNet>, _> =
-                ProvingKey::create(id, builder, prover_crs)?;
+                ProvingKey::create(id, builder, prover_crs, &mut driver)?;
             let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
             tracing::info!(
                 "Party {}: Proving key generation took {} ms",
@@ -738,7 +819,7 @@ fn run_build_proving_key(config: BuildProvingKeyConfig) -> color_eyre::Result<Ex
             );
             // write result to output file
             let out_file = BufWriter::new(std::fs::File::create(&out)?);
-            bincode::serialize_into(out_file, &proving_key)?;
+            write_proving_key_share(out_file, &proving_key)?;
             tracing::info!("Proving Key successfully written to {}", out.display());
         }
         MPCProtocol::SHAMIR => {
@@ -747,6 +828,8 @@ fn run_build_proving_key(config: BuildProvingKeyConfig) -> color_eyre::Result<Ex
             // connect to network
             let net = ShamirMpcNet::new(network_config)?;
             let id = net.get_id();
+            let preprocessing = ShamirPreprocessing::new(t, net, 0)?;
+            let mut driver = ShamirAcvmSolver::new(ShamirProtocol::from(preprocessing));
 
             // Create the circuit
             tracing::info!("Party {}: starting to generate proving key..", id);
@@ -757,7 +840,8 @@ fn run_build_proving_key(config: BuildProvingKeyConfig) -> color_eyre::Result<Ex
                 witness_share,
                 true,
                 false,
-            );
+                &mut driver,
+            )?;
 
             // parse the crs
             let prover_crs =
@@ -769,7 +853,7 @@ fn run_build_proving_key(config: BuildProvingKeyConfig) -> color_eyre::Result<Ex
 
             // Get the proving key and prover
             let proving_key: ProvingKey<ShamirUltraHonkDriver<_, ShamirMpcNet>, _> =
-                ProvingKey::create(id, builder, prover_crs)?;
+                ProvingKey::create(id, builder, prover_crs, &mut driver)?;
             let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
             tracing::info!(
                 "Party {}: Proving key generation took {} ms",
@@ -778,7 +862,7 @@ fn run_build_proving_key(config: BuildProvingKeyConfig) -> color_eyre::Result<Ex
             );
             // write result to output file
             let out_file = BufWriter::new(std::fs::File::create(&out)?);
-            bincode::serialize_into(out_file, &proving_key)?;
+            write_proving_key_share(out_file, &proving_key)?;
             tracing::info!("Proving Key successfully written to {}", out.display());
         }
     };
@@ -823,8 +907,7 @@ fn run_generate_proof(config: GenerateProofConfig) -> color_eyre::Result<ExitCod
 
             // Get the proving key and prover
             let proving_key: ProvingKey<Rep3UltraHonkDriver<Rep3MpcNet>, Bn254> =
-                bincode::deserialize_from(proving_key_file)
-                    .context("while deserializing input share")?;
+                read_proving_key_share(proving_key_file)?;
             let public_input = proving_key.get_public_inputs();
             match hasher {
                 TranscriptHash::POSEIDON => {
@@ -858,8 +941,7 @@ fn run_generate_proof(config: GenerateProofConfig) -> color_eyre::Result<ExitCod
 
             // Get the proving key and prover
             let proving_key: ProvingKey<ShamirUltraHonkDriver<ark_bn254::Fr, ShamirMpcNet>, Bn254> =
-                bincode::deserialize_from(proving_key_file)
-                    .context("while deserializing input share")?;
+                read_proving_key_share(proving_key_file)?;
             let public_input = proving_key.get_public_inputs();
 
             // init MPC protocol
@@ -983,11 +1065,7 @@ fn run_build_and_generate_proof(
             // connect to network
             let net = Rep3MpcNet::new(network_config)?;
             let id = net.get_id();
-
-            let mut io_context0 = IoContext::init(net)?;
-            let io_context1 = io_context0.fork()?;
-            // init MPC protocol
-            let driver = Rep3UltraHonkDriver::new(io_context0, io_context1);
+            let mut acvm_driver = Rep3AcvmSolver::new(net)?;
 
             // Create the circuit
             tracing::info!("Party {}: starting to generate proving key..", id);
@@ -998,7 +1076,8 @@ fn run_build_and_generate_proof(
                 witness_share,
                 true,
                 false,
-            );
+                &mut acvm_driver,
+            )?;
 
             // parse the crs
             let prover_crs = ProvingKey::<Rep3UltraHonkDriver<Rep3MpcNet>, _>::get_prover_crs(
@@ -1008,8 +1087,14 @@ fn run_build_and_generate_proof(
             .context("failed to get prover crs")?;
 
             // Get the proving key and prover
-            let proving_key = ProvingKey::create(id, builder, prover_crs)?;
+            let proving_key = ProvingKey::create(id, builder, prover_crs, &mut acvm_driver)?;
             let public_input = proving_key.get_public_inputs();
+
+            // init MPC protocol
+            let mut io_context0 = IoContext::init(acvm_driver.into_network())?;
+            let io_context1 = io_context0.fork()?;
+            let driver = Rep3UltraHonkDriver::new(io_context0, io_context1);
+
             let (proof, public_input) = match hasher {
                 TranscriptHash::POSEIDON => {
                     let prover = CoUltraHonk::<_, _, Poseidon2Sponge>::new(driver);
@@ -1055,6 +1140,8 @@ fn run_build_and_generate_proof(
             // connect to network
             let net = ShamirMpcNet::new(network_config)?;
             let id = net.get_id();
+            let preprocessing = ShamirPreprocessing::new(t, net, 0)?;
+            let mut driver = ShamirAcvmSolver::new(ShamirProtocol::from(preprocessing));
 
             // Create the circuit
             tracing::info!("Party {}: starting to generate proving key..", id);
@@ -1065,7 +1152,8 @@ fn run_build_and_generate_proof(
                 witness_share,
                 true,
                 false,
-            );
+                &mut driver,
+            )?;
 
             // parse the crs
             let prover_crs =
@@ -1076,7 +1164,7 @@ fn run_build_and_generate_proof(
                 .context("failed to get prover crs")?;
 
             // Get the proving key and prover
-            let proving_key = ProvingKey::create(id, builder, prover_crs)?;
+            let proving_key = ProvingKey::create(id, builder, prover_crs, &mut driver)?;
             let public_input = proving_key.get_public_inputs();
             let duration_ms = start.elapsed().as_micros() as f64 / 1000.;
             tracing::info!(
@@ -1095,7 +1183,7 @@ fn run_build_and_generate_proof(
             let num_pairs_sumcheck_prove =
                 SUMCHECK_ROUND_CRAND_PAIRS_FACTOR * MAX_PARTIAL_RELATION_LENGTH * (n - 1);
             let num_pairs = num_pairs_oink_prove + num_pairs_sumcheck_prove;
-            let preprocessing = ShamirPreprocessing::new(t, net, num_pairs)?;
+            let preprocessing = ShamirPreprocessing::new(t, driver.into_network(), num_pairs)?;
             let mut protocol0 = ShamirProtocol::from(preprocessing);
             let protocol1 = protocol0.fork_with_pairs(0)?;
             let driver = ShamirUltraHonkDriver::new(protocol0, protocol1);
@@ -1183,8 +1271,15 @@ fn run_generate_vk(config: CreateVKConfig) -> color_eyre::Result<ExitCode> {
     // get builder
     tracing::info!("Starting to generate verification key..");
     let start = Instant::now();
-    let builder =
-        UltraCircuitBuilder::<Bn254>::create_circuit(constraint_system, 0, vec![], true, false);
+    let mut driver = PlainAcvmSolver::default();
+    let builder = UltraCircuitBuilder::<Bn254>::create_circuit(
+        constraint_system,
+        0,
+        vec![],
+        true,
+        false,
+        &mut driver,
+    )?;
 
     // parse the crs
     let prover_crs = VerifyingKey::get_prover_crs(
@@ -10,6 +10,8 @@ use co_acvm::{
     solver::{partial_abi::PublicMarker, Rep3CoSolver},
     Rep3AcvmType, ShamirAcvmType,
 };
+use co_ultrahonk::prelude::{NoirUltraHonkProver, ProvingKey};
+use color_eyre::eyre::{eyre, Context};
 use figment::{
     providers::{Env, Format, Serialized, Toml},
     Figment,
@@ -25,7 +27,12 @@ use mpc_net::config::NetworkConfigFile;
 use noirc_abi::Abi;
 use rand::{CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
-use std::{array, collections::BTreeMap, path::PathBuf};
+use std::{
+    array,
+    collections::BTreeMap,
+    io::{Read, Write},
+    path::PathBuf,
+};
 
 #[derive(Clone, Debug)]
 pub enum PubShared<F: Clone> {
@@ -138,7 +145,7 @@ pub struct SplitInputCli {
     #[arg(long)]
     #[serde(skip_serializing_if = "::std::option::Option::is_none")]
     pub config: Option<PathBuf>,
-    /// The path to the input JSON file
+    /// The path to the input file, either a Prover.toml or a JSON file (`.json`) in the format accepted by noir_js
     #[arg(long)]
     #[serde(skip_serializing_if = "::std::option::Option::is_none")]
     pub input: Option<PathBuf>,
@@ -159,7 +166,7 @@ pub struct SplitInputCli {
 /// Config for `split_input`
 #[derive(Debug, Clone, Deserialize)]
 pub struct SplitInputConfig {
-    /// The path to the input JSON file
+    /// The path to the input file, either a Prover.toml or a JSON file (`.json`) in the format accepted by noir_js
     pub input: PathBuf,
     /// The path to the circuit file
     pub circuit: String,
@@ -230,9 +237,13 @@ pub struct MergeInputSharesCli {
     #[arg(long)]
     #[serde(skip_serializing_if = "::std::option::Option::is_none")]
     pub config: Option<PathBuf>,
-    /// The path to the input JSON file
+    /// The paths to the input share files
     #[arg(long)]
     pub inputs: Vec<PathBuf>,
+    /// The path to the circuit file. If passed, the merged input share is validated against the ABI of the circuit.
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub circuit: Option<String>,
     /// The MPC protocol to be used
     #[arg(long, value_enum)]
     #[serde(skip_serializing_if = "::std::option::Option::is_none")]
@@ -246,8 +257,10 @@ pub struct MergeInputSharesCli {
 /// Config for `merge_input_shares`
 #[derive(Debug, Deserialize)]
 pub struct MergeInputSharesConfig {
-    /// The path to the input JSON file
+    /// The paths to the input share files
     pub inputs: Vec<PathBuf>,
+    /// The path to the circuit file. If passed, the merged input share is validated against the ABI of the circuit.
+    pub circuit: Option<String>,
     /// The MPC protocol to be used
     pub protocol: MPCProtocol,
     /// The output file where the merged input share is written to
@@ -277,6 +290,9 @@ pub struct GenerateWitnessCli {
     #[arg(long)]
     #[serde(skip_serializing_if = "::std::option::Option::is_none")]
     pub out: Option<PathBuf>,
+    /// Open the shared values of assertions to report violated ones. This reveals whether each assertion holds and is only intended for debugging
+    #[arg(long, default_value_t = false)]
+    pub open_assertions: bool,
 }
 
 /// Config for `generate_witness`
@@ -290,10 +306,39 @@ pub struct GenerateWitnessConfig {
     pub protocol: MPCProtocol,
     /// The output file where the final witness share is written to
     pub out: PathBuf,
+    /// Open the shared values of assertions to report violated ones. This reveals whether each assertion holds and is only intended for debugging
+    #[serde(default)]
+    pub open_assertions: bool,
     /// Network config
     pub network: NetworkConfigFile,
 }
 
+/// Cli arguments for `compare_witness`
+#[derive(Debug, Default, Serialize, Args)]
+pub struct CompareWitnessCli {
+    /// The path to the config file
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub config: Option<PathBuf>,
+    /// The path to the input file (Prover.toml or JSON)
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub input: Option<PathBuf>,
+    /// The path to the circuit file
+    #[arg(long)]
+    #[serde(skip_serializing_if = "::std::option::Option::is_none")]
+    pub circuit: Option<String>,
+}
+
+/// Config for `compare_witness`
+#[derive(Debug, Deserialize)]
+pub struct CompareWitnessConfig {
+    /// The path to the input file (Prover.toml or JSON)
+    pub input: PathBuf,
+    /// The path to the circuit file
+    pub circuit: String,
+}
+
 /// Cli arguments for `translate_witness`
 #[derive(Debug, Serialize, Args)]
 pub struct TranslateWitnessCli {
@@ -650,6 +695,7 @@ impl_config!(SplitWitnessCli, SplitWitnessConfig);
 impl_config!(SplitProvingKeyCli, SplitProvingKeyConfig);
 impl_config!(MergeInputSharesCli, MergeInputSharesConfig);
 impl_config!(GenerateWitnessCli, GenerateWitnessConfig);
+impl_config!(CompareWitnessCli, CompareWitnessConfig);
 impl_config!(TranslateWitnessCli, TranslateWitnessConfig);
 impl_config!(TranslateProvingKeyCli, TranslateProvingKeyConfig);
 impl_config!(BuildProvingKeyCLi, BuildProvingKeyConfig);
@@ -766,3 +812,29 @@ pub fn convert_witness_to_vec_rep3<F: PrimeField>(
     }
     wv
 }
+
+/// Serializes a proving key share, prefixed with [`ProvingKey::SHARE_FORMAT_VERSION`].
+pub fn write_proving_key_share<T: NoirUltraHonkProver<P>, P: Pairing, W: Write>(
+    mut writer: W,
+    proving_key: &ProvingKey<T, P>,
+) -> color_eyre::Result<()> {
+    bincode::serialize_into(&mut writer, &ProvingKey::<T, P>::SHARE_FORMAT_VERSION)
+        .context("while serializing proving key share version")?;
+    bincode::serialize_into(writer, proving_key).context("while serializing proving key share")
+}
+
+/// Deserializes a proving key share written by [`write_proving_key_share`]. Shares of a different
+/// format version are rejected, as they cannot be interpreted correctly.
+pub fn read_proving_key_share<T: NoirUltraHonkProver<P>, P: Pairing, R: Read>(
+    mut reader: R,
+) -> color_eyre::Result<ProvingKey<T, P>> {
+    let version: u32 = bincode::deserialize_from(&mut reader)
+        .context("while deserializing proving key share version")?;
+    if version != ProvingKey::<T, P>::SHARE_FORMAT_VERSION {
+        return Err(eyre!(
+            "proving key share has format version {version}, but version {} is required - please split or build the proving key again",
+            ProvingKey::<T, P>::SHARE_FORMAT_VERSION
+        ));
+    }
+    bincode::deserialize_from(reader).context("while deserializing proving key share")
+}
@@ -63,7 +63,7 @@ pub(crate) struct LogDerivLookupRelation {}
 
 impl LogDerivLookupRelation {
     pub(crate) const NUM_RELATIONS: usize = 2;
-    pub(crate) const CRAND_PAIRS_FACTOR: usize = 1;
+    pub(crate) const CRAND_PAIRS_FACTOR: usize = 2;
 }
 
 impl LogDerivLookupRelation {
@@ -153,7 +153,8 @@ impl<T: NoirUltraHonkProver<P>, P: HonkCurve<TranscriptFieldType>> Relation<T, P
 
     fn skip(input: &ProverUnivariates<T, P>) -> bool {
         <Self as Relation<T, P>>::check_skippable();
-        input.precomputed.q_lookup().is_zero() && input.witness.lookup_read_counts().is_zero()
+        // The read counts are shared, but they can only be non-zero where the read tags are non-zero
+        input.precomputed.q_lookup().is_zero() && input.witness.lookup_read_tags().is_zero()
     }
 
     /**
@@ -230,7 +231,8 @@ impl<T: NoirUltraHonkProver<P>, P: HonkCurve<TranscriptFieldType>> Relation<T, P
         // Establish validity of the read. Note: no scaling factor here since this constraint is 'linearly dependent,
         // i.e. enforced across the entire trace, not on a per-row basis.
         // Degrees:                       1            2 (3)            1            3 (4)
-        let tmp = write_inverse.mul_public(driver, read_counts);
+        let mul = driver.mul_many(write_inverse.as_ref(), read_counts.as_ref())?;
+        let tmp = SharedUnivariate::from_vec(&mul);
         let tmp = read_inverse
             .mul_public(driver, read_selector)
             .sub(driver, &tmp); // Deg 4 (5)
@@ -508,28 +508,25 @@ impl<
         self.compute_w4(proving_key);
 
         // Commit to lookup argument polynomials and the finalized (i.e. with memory records) fourth wire polynomial
-        let lookup_read_counts = Utils::commit(
+        let lookup_read_counts = CoUtils::commit::<T, P>(
             proving_key
                 .polynomials
                 .witness
                 .lookup_read_counts()
                 .as_ref(),
             &proving_key.crs,
-        )?;
+        );
         let lookup_read_tags = Utils::commit(
             proving_key.polynomials.witness.lookup_read_tags().as_ref(),
             &proving_key.crs,
         )?;
         let w_4 = CoUtils::commit::<T, P>(self.memory.w_4.as_ref(), &proving_key.crs);
-        let w_4 = self.driver.open_point(w_4)?;
+        let open = self.driver.open_point_many(&[lookup_read_counts, w_4])?;
 
-        transcript.send_point_to_verifier::<P>(
-            "LOOKUP_READ_COUNTS".to_string(),
-            lookup_read_counts.into(),
-        );
+        transcript.send_point_to_verifier::<P>("LOOKUP_READ_COUNTS".to_string(), open[0].into());
         transcript
             .send_point_to_verifier::<P>("LOOKUP_READ_TAGS".to_string(), lookup_read_tags.into());
-        transcript.send_point_to_verifier::<P>("W_4".to_string(), w_4.into());
+        transcript.send_point_to_verifier::<P>("W_4".to_string(), open[1].into());
 
         Ok(())
     }
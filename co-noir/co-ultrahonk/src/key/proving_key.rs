@@ -39,6 +39,11 @@ pub struct ProvingKey<T: NoirUltraHonkProver<P>, P: Pairing> {
 }
 
 impl<T: NoirUltraHonkProver<P>, P: Pairing> ProvingKey<T, P> {
+    /// The version of the serialized proving key shares. Version 2 moved the lookup read counts
+    /// from the public to the shared witness polynomials, so the shares contain 5 instead of 4
+    /// polynomials of length circuit_size.
+    pub const SHARE_FORMAT_VERSION: u32 = 2;
+
     const PUBLIC_INPUT_WIRE_INDEX: usize =
         ProverWitnessEntities::<T::ArithmeticShare, P::ScalarField>::W_R;
 
@@ -49,9 +54,10 @@ impl<T: NoirUltraHonkProver<P>, P: Pairing> ProvingKey<T, P> {
         id: T::PartyID,
         mut circuit: GenericUltraCircuitBuilder<P, U>,
         crs: ProverCrs<P>,
+        driver: &mut U,
     ) -> HonkProofResult<Self> {
         tracing::trace!("ProvingKey create");
-        circuit.finalize_circuit(true);
+        circuit.finalize_circuit(true, driver)?;
 
         let dyadic_circuit_size = circuit.compute_dyadic_size();
         let mut proving_key = Self::new(dyadic_circuit_size, circuit.public_inputs.len(), crs);
@@ -72,15 +78,12 @@ impl<T: NoirUltraHonkProver<P>, P: Pairing> ProvingKey<T, P> {
             dyadic_circuit_size,
             0,
         );
-        PlainProvingKey::construct_lookup_read_counts(
-            proving_key
-                .polynomials
-                .witness
-                .lookup_read_counts_and_tags_mut()
-                .try_into()
-                .unwrap(),
+        Self::construct_lookup_read_counts(
+            id,
+            &mut proving_key.polynomials.witness,
             &mut circuit,
             dyadic_circuit_size,
+            driver,
         );
 
         // Construct the public inputs array
@@ -105,13 +108,14 @@ impl<T: NoirUltraHonkProver<P>, P: Pairing> ProvingKey<T, P> {
         id: T::PartyID,
         circuit: GenericUltraCircuitBuilder<P, U>,
         crs: Crs<P>,
+        driver: &mut U,
     ) -> HonkProofResult<(Self, VerifyingKey<P>)> {
         let prover_crs = ProverCrs {
             monomials: crs.monomials,
         };
         let verifier_crs = crs.g2_x;
 
-        let pk = ProvingKey::create(id, circuit, prover_crs)?;
+        let pk = ProvingKey::create(id, circuit, prover_crs, driver)?;
         let circuit_size = pk.circuit_size;
 
         let mut commitments = PrecomputedEntities::default();
@@ -209,6 +213,61 @@ impl<T: NoirUltraHonkProver<P>, P: Pairing> ProvingKey<T, P> {
         );
     }
 
+    fn construct_lookup_read_counts<
+        U: NoirWitnessExtensionProtocol<P::ScalarField, ArithmeticShare = T::ArithmeticShare>,
+    >(
+        id: T::PartyID,
+        witness: &mut ProverWitnessEntities<
+            Polynomial<T::ArithmeticShare>,
+            Polynomial<P::ScalarField>,
+        >,
+        circuit: &mut GenericUltraCircuitBuilder<P, U>,
+        dyadic_circuit_size: usize,
+        driver: &mut U,
+    ) {
+        // The lookups with public keys are counted in the clear
+        let mut public_counts_and_tags = [
+            Polynomial::new_zero(dyadic_circuit_size),
+            Polynomial::new_zero(dyadic_circuit_size),
+        ];
+        PlainProvingKey::construct_lookup_read_counts(
+            &mut public_counts_and_tags,
+            circuit,
+            dyadic_circuit_size,
+        );
+        let [public_counts, tags] = public_counts_and_tags;
+        *witness.lookup_read_tags_mut() = tags;
+
+        let mut counts = public_counts
+            .into_vec()
+            .into_iter()
+            .map(U::AcvmType::from)
+            .collect::<Vec<_>>();
+        PlainProvingKey::construct_shared_lookup_read_counts(
+            &mut counts,
+            witness.lookup_read_tags_mut(),
+            circuit,
+            dyadic_circuit_size,
+            driver,
+        );
+
+        *witness.lookup_read_counts_mut() = Polynomial::new(
+            counts
+                .into_iter()
+                .map(|count| {
+                    if U::is_shared(&count) {
+                        U::get_shared(&count).expect("Already checked it is shared")
+                    } else {
+                        T::promote_to_trivial_share(
+                            id,
+                            U::get_public(&count).expect("Already checked it is public"),
+                        )
+                    }
+                })
+                .collect(),
+        );
+    }
+
     pub fn from_plain_key_and_shares(
         plain_key: &PlainProvingKey<P>,
         shares: Vec<T::ArithmeticShare>,
@@ -221,8 +280,8 @@ impl<T: NoirUltraHonkProver<P>, P: Pairing> ProvingKey<T, P> {
         let memory_read_records = plain_key.memory_read_records.to_owned();
         let memory_write_records = plain_key.memory_write_records.to_owned();
 
-        if shares.len() != circuit_size as usize * 4 {
-            return Err(eyre::eyre!("Share length is not 4 times circuit size"));
+        if shares.len() != circuit_size as usize * 5 {
+            return Err(eyre::eyre!("Share length is not 5 times circuit size"));
         }
 
         let mut polynomials = Polynomials::default();
@@ -234,27 +293,16 @@ impl<T: NoirUltraHonkProver<P>, P: Pairing> ProvingKey<T, P> {
         {
             *des = src.to_owned();
         }
-        for (src, des) in plain_key
-            .polynomials
-            .witness
-            .lookup_read_counts_and_tags()
-            .iter()
-            .zip(
-                polynomials
-                    .witness
-                    .lookup_read_counts_and_tags_mut()
-                    .iter_mut(),
-            )
-        {
-            *des = src.to_owned();
-        }
+        *polynomials.witness.lookup_read_tags_mut() =
+            plain_key.polynomials.witness.lookup_read_tags().to_owned();
 
-        for (src, des) in shares
-            .chunks_exact(circuit_size as usize)
-            .zip(polynomials.witness.get_wires_mut().iter_mut())
-        {
-            *des = Polynomial::new(src.to_owned());
+        // The shares contain the wires followed by the lookup read counts
+        let mut chunks = shares.chunks_exact(circuit_size as usize);
+        for des in polynomials.witness.get_wires_mut().iter_mut() {
+            *des = Polynomial::new(chunks.next().expect("Length already checked").to_owned());
         }
+        *polynomials.witness.lookup_read_counts_mut() =
+            Polynomial::new(chunks.next().expect("Length already checked").to_owned());
 
         Ok(Self {
             crs,
@@ -25,14 +25,23 @@ fn poseidon_plaindriver_test<H: TranscriptHasher<TranscriptFieldType>>(proof_fil
 
     let witness = promote_public_witness_vector::<_, PlainAcvmSolver<ark_bn254::Fr>>(witness);
 
-    let builder =
-        PlainCoBuilder::<Bn254>::create_circuit(constraint_system, 0, witness, true, false);
+    let mut acvm_driver = PlainAcvmSolver::default();
+    let builder = PlainCoBuilder::<Bn254>::create_circuit(
+        constraint_system,
+        0,
+        witness,
+        true,
+        false,
+        &mut acvm_driver,
+    )
+    .unwrap();
 
     let driver = PlainUltraHonkDriver;
 
     let crs =
         ProvingKey::<PlainUltraHonkDriver, _>::get_crs(&builder, CRS_PATH_G1, CRS_PATH_G2).unwrap();
-    let (proving_key, verifying_key) = ProvingKey::create_keys(0, builder, crs).unwrap();
+    let (proving_key, verifying_key) =
+        ProvingKey::create_keys(0, builder, crs, &mut acvm_driver).unwrap();
 
     let prover = CoUltraHonk::<_, _, H>::new(driver);
     let proof = prover.prove(proving_key).unwrap();
@@ -1,7 +1,6 @@
 pub(crate) mod decider;
 pub(crate) mod keccak_hash;
 pub(crate) mod oink;
-pub(crate) mod poseidon2;
 pub mod prelude;
 pub(crate) mod prover;
 pub(crate) mod sponge_hasher;
@@ -9,16 +8,18 @@ mod transcript;
 pub(crate) mod types;
 pub(crate) mod verifier;
 
-use acir::{native_types::WitnessStack, FieldElement};
+use acir::{
+    native_types::{WitnessMap, WitnessStack},
+    FieldElement,
+};
 use ark_ec::pairing::Pairing;
 use ark_ff::PrimeField;
 use co_builder::{
     prelude::{AcirFormat, ProverCrs},
     HonkProofResult,
 };
-use eyre::Error;
 use noirc_artifacts::program::ProgramArtifact;
-use std::{io, path::Path};
+use std::{collections::BTreeMap, io, path::Path};
 
 pub const NUM_ALPHAS: usize = decider::relations::NUM_SUBRELATIONS - 1;
 /// The log of the max circuit size assumed in order to achieve constant sized Honk proofs
@@ -29,10 +30,6 @@ pub const N_MAX: usize = 1 << 25;
 pub struct Utils {}
 
 impl Utils {
-    pub(crate) fn field_from_hex_string<F: PrimeField>(str: &str) -> Result<F, Error> {
-        co_builder::prelude::Utils::field_from_hex_string(str)
-    }
-
     pub fn get_program_artifact_from_file(path: impl AsRef<Path>) -> io::Result<ProgramArtifact> {
         let program = std::fs::read_to_string(path)?;
         Ok(serde_json::from_str::<ProgramArtifact>(&program)?)
@@ -71,7 +68,13 @@ impl Utils {
             .pop()
             .expect("Witness should be present")
             .witness;
-        let witness = AcirFormat::witness_map_to_witness_vector(witness_map);
+        let witness_map = WitnessMap::from(
+            witness_map
+                .into_iter()
+                .map(|(witness, value)| (witness, value.into_repr()))
+                .collect::<BTreeMap<_, _>>(),
+        );
+        let witness = AcirFormat::<ark_bn254::Fr>::witness_map_to_witness_vector(witness_map);
         Ok(witness)
     }
 
@@ -1,3 +0,0 @@
-pub(crate) mod poseidon2_bn254;
-pub(crate) mod poseidon2_params;
-pub(crate) mod poseidon2_permutation;
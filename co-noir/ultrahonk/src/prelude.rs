@@ -1,7 +1,6 @@
 pub use crate::decider::barycentric::Barycentric;
 pub use crate::decider::types::GateSeparatorPolynomial;
 pub use crate::decider::univariate::Univariate;
-pub use crate::poseidon2::poseidon2_bn254::POSEIDON2_BN254_T4_PARAMS;
 pub use crate::prover::UltraHonk;
 pub use crate::transcript::Poseidon2Sponge;
 pub use crate::transcript::{Transcript, TranscriptHasher};
@@ -10,3 +9,4 @@ pub use crate::types::{ShiftedTableEntities, ShiftedWitnessEntities};
 pub use co_builder::prelude::VerifyingKeyBarretenberg;
 pub use co_builder::prelude::{ProvingKey, UltraCircuitBuilder};
 pub use co_builder::TranscriptFieldType;
+pub use mpc_core::gadgets::poseidon2::POSEIDON2_BN254_T4_PARAMS;
@@ -1,4 +1,5 @@
 use ark_ff::{One, PrimeField};
+use mpc_core::gadgets::poseidon2::Poseidon2;
 use num_bigint::BigUint;
 
 pub trait FieldHash<F: PrimeField, const T: usize> {
@@ -11,6 +12,12 @@ pub trait FieldHash<F: PrimeField, const T: usize> {
     fn permutation_in_place(&self, input: &mut [F; T]);
 }
 
+impl<F: PrimeField, const T: usize, const D: u64> FieldHash<F, T> for Poseidon2<F, T, D> {
+    fn permutation_in_place(&self, state: &mut [F; T]) {
+        Poseidon2::permutation_in_place(self, state)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 enum SpongeMode {
     Absorb,
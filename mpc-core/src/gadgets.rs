@@ -0,0 +1,8 @@
+//! # MPC Gadgets
+//!
+//! Contains the plain implementations of cryptographic primitives which are required by the MPC protocols, e.g., for evaluating them on secret-shared inputs.
+
+pub mod aes;
+pub mod blake;
+pub mod pedersen;
+pub mod poseidon2;
@@ -0,0 +1,122 @@
+//! AES
+//!
+//! This module contains AES-128 encryption of single blocks and of padded buffers in CBC mode.
+
+/// The number of bytes in an AES block.
+pub const AES128_BLOCK_BYTES: usize = 16;
+
+/// The number of rounds of AES-128.
+pub const AES128_ROUNDS: usize = 10;
+
+/// The round constants of the AES-128 key schedule, which are XORed into the first byte of every fourth word of the expanded key.
+pub const AES128_ROUND_CONSTANTS: [u8; AES128_ROUNDS] =
+    [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+/// The AES S-box, i.e., the affine transformation of the inverse in GF(2^8).
+pub const AES128_SBOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+/// Multiplies by x in GF(2^8) modulo the AES polynomial x^8 + x^4 + x^3 + x + 1.
+pub fn xtime(byte: u8) -> u8 {
+    (byte << 1) ^ ((byte >> 7) * 0x1b)
+}
+
+/// Expands the key into the 11 round keys of AES-128.
+pub fn aes128_expand_key(
+    key: &[u8; AES128_BLOCK_BYTES],
+) -> [[u8; AES128_BLOCK_BYTES]; AES128_ROUNDS + 1] {
+    let mut words = Vec::with_capacity(4 * (AES128_ROUNDS + 1));
+    for word in key.chunks_exact(4) {
+        words.push([word[0], word[1], word[2], word[3]]);
+    }
+    for i in 4..4 * (AES128_ROUNDS + 1) {
+        let mut temp: [u8; 4] = words[i - 1];
+        if i % 4 == 0 {
+            temp.rotate_left(1);
+            temp = temp.map(|byte| AES128_SBOX[byte as usize]);
+            temp[0] ^= AES128_ROUND_CONSTANTS[i / 4 - 1];
+        }
+        let prev = words[i - 4];
+        words.push(std::array::from_fn(|j| prev[j] ^ temp[j]));
+    }
+    std::array::from_fn(|round| std::array::from_fn(|i| words[4 * round + i / 4][i % 4]))
+}
+
+/// The ShiftRows step, where the state is stored column by column, i.e., state[r + 4 * c] is in row r and column c.
+fn shift_rows(state: &[u8; AES128_BLOCK_BYTES]) -> [u8; AES128_BLOCK_BYTES] {
+    std::array::from_fn(|i| {
+        let (row, column) = (i % 4, i / 4);
+        state[row + 4 * ((column + row) % 4)]
+    })
+}
+
+/// The MixColumns step, where the state is stored column by column.
+fn mix_columns(state: &[u8; AES128_BLOCK_BYTES]) -> [u8; AES128_BLOCK_BYTES] {
+    let mut result = [0u8; AES128_BLOCK_BYTES];
+    for (des, column) in result.chunks_exact_mut(4).zip(state.chunks_exact(4)) {
+        for (row, des) in des.iter_mut().enumerate() {
+            // 2 * a_r + 3 * a_{r+1} + a_{r+2} + a_{r+3}
+            let a = column[row];
+            let b = column[(row + 1) % 4];
+            *des = xtime(a) ^ xtime(b) ^ b ^ column[(row + 2) % 4] ^ column[(row + 3) % 4];
+        }
+    }
+    result
+}
+
+/// Encrypts a single block with the given round keys.
+pub fn aes128_encrypt_block(
+    round_keys: &[[u8; AES128_BLOCK_BYTES]; AES128_ROUNDS + 1],
+    block: &[u8; AES128_BLOCK_BYTES],
+) -> [u8; AES128_BLOCK_BYTES] {
+    let mut state: [u8; AES128_BLOCK_BYTES] = std::array::from_fn(|i| block[i] ^ round_keys[0][i]);
+    for (round, round_key) in round_keys.iter().enumerate().skip(1) {
+        state = shift_rows(&state.map(|byte| AES128_SBOX[byte as usize]));
+        if round != AES128_ROUNDS {
+            state = mix_columns(&state);
+        }
+        for (state, key) in state.iter_mut().zip(round_key) {
+            *state ^= key;
+        }
+    }
+    state
+}
+
+/// Encrypts the input in CBC mode, where the length of the input has to be a multiple of the block size, i.e., the input is already padded.
+pub fn aes128_encrypt_cbc(
+    input: &[u8],
+    iv: &[u8; AES128_BLOCK_BYTES],
+    key: &[u8; AES128_BLOCK_BYTES],
+) -> Vec<u8> {
+    assert_eq!(input.len() % AES128_BLOCK_BYTES, 0);
+    let round_keys = aes128_expand_key(key);
+    let mut result = Vec::with_capacity(input.len());
+    let mut previous = iv.to_owned();
+    for block in input.chunks_exact(AES128_BLOCK_BYTES) {
+        let block = std::array::from_fn(|i| block[i] ^ previous[i]);
+        previous = aes128_encrypt_block(&round_keys, &block);
+        result.extend(previous);
+    }
+    result
+}
+
+/// Returns the number of PKCS#7 padding bytes for an input of the given length. Each of these bytes has the number of padding bytes as its value, and an input whose length is a multiple of the block size is padded with a full block.
+pub fn aes128_padding_len(input_len: usize) -> usize {
+    AES128_BLOCK_BYTES - input_len % AES128_BLOCK_BYTES
+}
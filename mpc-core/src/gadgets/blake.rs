@@ -0,0 +1,162 @@
+//! Blake
+//!
+//! This module contains the compression functions of Blake2s and Blake3 on 32-bit words. Both hash functions share the initialization vector and the mixing function G.
+
+/// The initialization vector of Blake2s and Blake3.
+pub const BLAKE_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// The message schedules of the ten rounds of the Blake2s compression function.
+pub const BLAKE2S_SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// The permutation which is applied to the message words after each round of the Blake3 compression function.
+pub const BLAKE3_MSG_PERMUTATION: [usize; 16] =
+    [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+/// The number of rounds of the Blake3 compression function.
+pub const BLAKE3_ROUNDS: usize = 7;
+
+/// The Blake3 flag of the first block of a chunk.
+pub const BLAKE3_CHUNK_START: u32 = 1 << 0;
+/// The Blake3 flag of the last block of a chunk.
+pub const BLAKE3_CHUNK_END: u32 = 1 << 1;
+/// The Blake3 flag of the block producing the root of the hash tree.
+pub const BLAKE3_ROOT: u32 = 1 << 3;
+
+/// The number of bytes in a block of Blake2s and Blake3.
+pub const BLAKE_BLOCK_BYTES: usize = 64;
+
+/// The number of bytes in a Blake3 chunk.
+pub const BLAKE3_CHUNK_BYTES: usize = 1024;
+
+/// The parameter block word of Blake2s with a digest length of 32 bytes and no key, which is XORed into the first word of the initialization vector.
+pub const BLAKE2S_PARAM: u32 = 0x01010020;
+
+/// The positions (a, b, c, d) of the state words mixed by the G functions of a round. The first four mix the columns, the last four the diagonals of the state.
+pub const BLAKE_G_INDICES: [[usize; 4]; 8] = [
+    [0, 4, 8, 12],
+    [1, 5, 9, 13],
+    [2, 6, 10, 14],
+    [3, 7, 11, 15],
+    [0, 5, 10, 15],
+    [1, 6, 11, 12],
+    [2, 7, 8, 13],
+    [3, 4, 9, 14],
+];
+
+fn g(v: &mut [u32; 16], [a, b, c, d]: [usize; 4], x: u32, y: u32) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(12);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(8);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(7);
+}
+
+/// Applies one round of G functions to the state, where the i-th G function mixes in the message words at positions schedule[2i] and schedule[2i + 1].
+fn round(v: &mut [u32; 16], message: &[u32; 16], schedule: &[usize; 16]) {
+    for (indices, words) in BLAKE_G_INDICES.into_iter().zip(schedule.chunks_exact(2)) {
+        g(v, indices, message[words[0]], message[words[1]]);
+    }
+}
+
+/// The Blake2s compression function on the 8 words of the state and the 16 words of the message block. The counter is the number of bytes hashed so far, including the current block. Returns the new state.
+pub fn blake2s_compression(
+    state: &[u32; 8],
+    message: &[u32; 16],
+    counter: u64,
+    last_block: bool,
+) -> [u32; 8] {
+    let mut v = [0u32; 16];
+    v[..8].copy_from_slice(state);
+    v[8..].copy_from_slice(&BLAKE_IV);
+    v[12] ^= counter as u32;
+    v[13] ^= (counter >> 32) as u32;
+    if last_block {
+        v[14] ^= u32::MAX;
+    }
+
+    for schedule in BLAKE2S_SIGMA.iter() {
+        round(&mut v, message, schedule);
+    }
+
+    std::array::from_fn(|i| state[i] ^ v[i] ^ v[i + 8])
+}
+
+/// The Blake3 compression function on the 8 words of the chaining value and the 16 words of the message block, where block_len is the number of message bytes in the block and counter is the index of the chunk. Returns the new chaining value, i.e., the first 8 words of the output.
+pub fn blake3_compression(
+    chaining_value: &[u32; 8],
+    message: &[u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+) -> [u32; 8] {
+    let mut v = [0u32; 16];
+    v[..8].copy_from_slice(chaining_value);
+    v[8..12].copy_from_slice(&BLAKE_IV[..4]);
+    v[12] = counter as u32;
+    v[13] = (counter >> 32) as u32;
+    v[14] = block_len;
+    v[15] = flags;
+
+    let mut message = message.to_owned();
+    // Instead of using a different schedule per round, Blake3 permutes the message words
+    for _ in 0..BLAKE3_ROUNDS {
+        round(&mut v, &message, &BLAKE2S_SIGMA[0]);
+        message = BLAKE3_MSG_PERMUTATION.map(|i| message[i]);
+    }
+
+    std::array::from_fn(|i| v[i] ^ v[i + 8])
+}
+
+/// Computes the Blake3 hash of a message consisting of a single chunk, i.e., of at most 1024 bytes.
+pub fn blake3(message: &[u8]) -> [u8; 32] {
+    assert!(message.len() <= BLAKE3_CHUNK_BYTES);
+    let mut chaining_value = BLAKE_IV;
+    // The empty message is hashed as a single block of zeros
+    let num_blocks = message.len().div_ceil(BLAKE_BLOCK_BYTES).max(1);
+    for i in 0..num_blocks {
+        let start = i * BLAKE_BLOCK_BYTES;
+        let end = (start + BLAKE_BLOCK_BYTES).min(message.len());
+        let mut block = [0u8; BLAKE_BLOCK_BYTES];
+        block[..end - start].copy_from_slice(&message[start..end]);
+        let words = std::array::from_fn(|j| {
+            u32::from_le_bytes([
+                block[4 * j],
+                block[4 * j + 1],
+                block[4 * j + 2],
+                block[4 * j + 3],
+            ])
+        });
+
+        let mut flags = 0;
+        if i == 0 {
+            flags |= BLAKE3_CHUNK_START;
+        }
+        if i == num_blocks - 1 {
+            flags |= BLAKE3_CHUNK_END | BLAKE3_ROOT;
+        }
+        chaining_value =
+            blake3_compression(&chaining_value, &words, 0, (end - start) as u32, flags);
+    }
+
+    let mut result = [0u8; 32];
+    for (des, word) in result.chunks_exact_mut(4).zip(chaining_value) {
+        des.copy_from_slice(&word.to_le_bytes());
+    }
+    result
+}
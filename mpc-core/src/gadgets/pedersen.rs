@@ -0,0 +1,166 @@
+//! Pedersen
+//!
+//! This module contains the arithmetic on the embedded curve Grumpkin, the derivation of its generators via hashing to the curve, and the Pedersen commitment and hash, all following barretenberg. The base field of Grumpkin is the scalar field of BN254, hence the coordinates of the points are elements of the native field F.
+
+use ark_ff::{BigInteger, PrimeField};
+use num_bigint::BigUint;
+
+use super::blake::blake3;
+
+/// The domain separator of the generators of the Pedersen commitment and hash.
+pub const DEFAULT_DOMAIN_SEPARATOR: &[u8] = b"DEFAULT_DOMAIN_SEPARATOR";
+
+/// The domain separator of the generator which the Pedersen hash uses to commit to the number of inputs.
+pub const LENGTH_GENERATOR_DOMAIN_SEPARATOR: &[u8] = b"pedersen_hash_length";
+
+/// An affine point on Grumpkin, where None is the point at infinity.
+pub type AffinePoint<F> = Option<(F, F)>;
+
+/// The coefficient b of Grumpkin y^2 = x^3 - 17.
+pub fn grumpkin_b<F: PrimeField>() -> F {
+    -F::from(17u64)
+}
+
+/// The generator (1, sqrt(-16)) of Grumpkin.
+pub fn grumpkin_generator<F: PrimeField>() -> (F, F) {
+    let y = BigUint::parse_bytes(b"2cf135e7506a45d632d270d45f1181294833fc48d823f272c", 16)
+        .expect("valid hex");
+    (F::one(), F::from(y))
+}
+
+/// Adds two points of Grumpkin.
+pub fn point_add<F: PrimeField>(p: AffinePoint<F>, q: AffinePoint<F>) -> AffinePoint<F> {
+    let ((x1, y1), (x2, y2)) = match (p, q) {
+        (None, q) => return q,
+        (p, None) => return p,
+        (Some(p), Some(q)) => (p, q),
+    };
+    let lambda = if x1 == x2 {
+        if y1 != y2 || y1.is_zero() {
+            return None;
+        }
+        x1.square() * F::from(3u64) / y1.double()
+    } else {
+        (y2 - y1) / (x2 - x1)
+    };
+    let x3 = lambda.square() - x1 - x2;
+    let y3 = lambda * (x1 - x3) - y1;
+    Some((x3, y3))
+}
+
+/// Multiplies a point of Grumpkin with a scalar using double-and-add.
+pub fn point_mul<F: PrimeField>(point: AffinePoint<F>, scalar: &BigUint) -> AffinePoint<F> {
+    let mut result = None;
+    for i in (0..scalar.bits()).rev() {
+        result = point_add(result, result);
+        if scalar.bit(i) {
+            result = point_add(result, point);
+        }
+    }
+    result
+}
+
+/// Serializes a point as the big-endian bytes of its coordinates.
+pub fn serialize_point<F: PrimeField>((x, y): &(F, F)) -> Vec<u8> {
+    let mut result = x.into_bigint().to_bytes_be();
+    result.extend(y.into_bigint().to_bytes_be());
+    result
+}
+
+/// Hashes the seed to a point of Grumpkin. The x-coordinate is derived from two Blake3 hashes of the seed and the attempt count, and the sign of the y-coordinate from the first bit of the first hash. If there is no point with this x-coordinate, the next attempt is made.
+pub fn hash_to_curve<F: PrimeField>(seed: &[u8], attempt_count: u8) -> (F, F) {
+    let mut target_seed = seed.to_vec();
+    target_seed.extend([attempt_count, 0]);
+    let hash_hi = blake3(&target_seed);
+    *target_seed.last_mut().expect("Seed is not empty") = 1;
+    let hash_lo = blake3(&target_seed);
+
+    let mut hash = hash_hi.to_vec();
+    hash.extend(hash_lo);
+    let x = F::from_be_bytes_mod_order(&hash);
+    let sign_bit = hash_hi[0] > 127;
+    match (x.square() * x + grumpkin_b::<F>()).sqrt() {
+        Some(y) => {
+            let y = if y.into_bigint().is_odd() == sign_bit {
+                y
+            } else {
+                -y
+            };
+            (x, y)
+        }
+        None => hash_to_curve(seed, attempt_count + 1),
+    }
+}
+
+/// Derives num_generators generators of Grumpkin for the given domain separator, starting at the generator with the given index. The discrete logarithms of the generators with respect to each other are unknown.
+pub fn derive_generators<F: PrimeField>(
+    domain_separator: &[u8],
+    num_generators: usize,
+    starting_index: usize,
+) -> Vec<(F, F)> {
+    let mut generator_preimage = [0u8; 64];
+    generator_preimage[..32].copy_from_slice(&blake3(domain_separator));
+    (starting_index..starting_index + num_generators)
+        .map(|index| {
+            let index = u32::try_from(index).expect("Generator index fits into u32");
+            generator_preimage[32..36].copy_from_slice(&index.to_be_bytes());
+            hash_to_curve(&generator_preimage, 0)
+        })
+        .collect()
+}
+
+/// Computes the Pedersen commitment sum_i inputs_i * G_{starting_index + i}, where G are the generators of the default domain separator.
+pub fn pedersen_commitment<F: PrimeField>(inputs: &[F], starting_index: usize) -> AffinePoint<F> {
+    let generators = derive_generators(DEFAULT_DOMAIN_SEPARATOR, inputs.len(), starting_index);
+    inputs
+        .iter()
+        .zip(generators)
+        .fold(None, |acc, (input, generator)| {
+            point_add(acc, point_mul(Some(generator), &(*input).into()))
+        })
+}
+
+/// Computes the Pedersen hash, i.e., the x-coordinate of the Pedersen commitment to the inputs to which the number of inputs times the length generator is added. The point at infinity has the x-coordinate 0.
+pub fn pedersen_hash<F: PrimeField>(inputs: &[F], starting_index: usize) -> F {
+    let length_generator = derive_generators(LENGTH_GENERATOR_DOMAIN_SEPARATOR, 1, 0)[0];
+    let length = point_mul(Some(length_generator), &BigUint::from(inputs.len()));
+    let commitment = point_add(pedersen_commitment(inputs, starting_index), length);
+    commitment.map(|(x, _)| x).unwrap_or(F::zero())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fr;
+    use num_traits::Num;
+
+    fn field_from_hex_string(str: &str) -> Fr {
+        BigUint::from_str_radix(str.strip_prefix("0x").unwrap(), 16)
+            .unwrap()
+            .into()
+    }
+
+    // Test vectors from barretenberg
+    #[test]
+    fn pedersen_commitment_kat() {
+        let result = pedersen_commitment(&[Fr::from(1u64), Fr::from(1u64)], 0);
+        let expected = (
+            field_from_hex_string(
+                "0x2f7a8f9a6c96926682205fb73ee43215bf13523c19d7afe36f12760266cdfe15",
+            ),
+            field_from_hex_string(
+                "0x01916b316adbbf0e10e39b18c1d24b33ec84b46daddf72f43878bcc92b6057e6",
+            ),
+        );
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn pedersen_hash_kat() {
+        let result = pedersen_hash(&[Fr::from(1u64), Fr::from(1u64)], 0);
+        let expected = field_from_hex_string(
+            "0x07ebfbf4df29888c6cd6dca13d4bb9d1a923013ddbbcbdc3378ab8845463297b",
+        );
+        assert_eq!(result, expected);
+    }
+}
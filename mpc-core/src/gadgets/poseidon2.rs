@@ -0,0 +1,11 @@
+//! Poseidon2
+//!
+//! This module contains the Poseidon2 permutation and the parameters for the BN254 scalar field.
+
+mod poseidon2_bn254;
+mod poseidon2_params;
+mod poseidon2_permutation;
+
+pub use poseidon2_bn254::POSEIDON2_BN254_T4_PARAMS;
+pub use poseidon2_params::Poseidon2Params;
+pub use poseidon2_permutation::Poseidon2;
@@ -1,17 +1,25 @@
 use ark_ff::PrimeField;
 
+/// The parameters of the Poseidon2 permutation with state size T and S-box degree D.
 #[derive(Clone, Debug)]
 pub struct Poseidon2Params<F: PrimeField, const T: usize, const D: u64> {
-    pub(crate) rounds_f_beginning: usize,
-    pub(crate) rounds_f_end: usize,
-    pub(crate) rounds_p: usize,
-    pub mat_internal_diag_m_1: &'static [F; T], // The diagonal of the internal matrix, each element taken minus 1 for more efficient implementations
-    pub(crate) round_constants_external: &'static Vec<[F; T]>,
-    pub(crate) round_constants_internal: &'static Vec<F>,
+    /// The number of external rounds before the internal rounds
+    pub rounds_f_beginning: usize,
+    /// The number of external rounds after the internal rounds
+    pub rounds_f_end: usize,
+    /// The number of internal rounds
+    pub rounds_p: usize,
+    /// The diagonal of the internal matrix, each element taken minus 1 for more efficient implementations
+    pub mat_internal_diag_m_1: &'static [F; T],
+    /// The round constants of the external rounds
+    pub round_constants_external: &'static Vec<[F; T]>,
+    /// The round constants of the internal rounds
+    pub round_constants_internal: &'static Vec<F>,
 }
 
 impl<F: PrimeField, const T: usize, const D: u64> Poseidon2Params<F, T, D> {
-    pub(crate) fn new(
+    /// Creates new Poseidon2 parameters. Panics if the parameters are inconsistent.
+    pub fn new(
         rounds_f: usize,
         rounds_p: usize,
         mat_internal_diag_m_1: &'static [F; T],
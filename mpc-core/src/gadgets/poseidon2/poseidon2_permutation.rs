@@ -1,22 +1,27 @@
 use super::poseidon2_params::Poseidon2Params;
-use crate::sponge_hasher::FieldHash;
 use ark_ff::PrimeField;
+use std::any::Any;
 
+/// The Poseidon2 permutation with state size T and S-box degree D.
 #[derive(Clone, Debug)]
 pub struct Poseidon2<F: PrimeField, const T: usize, const D: u64> {
-    pub(crate) params: &'static Poseidon2Params<F, T, D>,
+    /// The parameters of the permutation
+    pub params: &'static Poseidon2Params<F, T, D>,
 }
 
 impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
-    pub(crate) fn new(params: &'static Poseidon2Params<F, T, D>) -> Self {
+    /// Creates a new Poseidon2 permutation from the given parameters.
+    pub fn new(params: &'static Poseidon2Params<F, T, D>) -> Self {
         Self { params }
     }
 
-    fn sbox(input: &mut [F; T]) {
+    /// Applies the S-box to all elements of the state.
+    pub fn sbox(input: &mut [F; T]) {
         input.iter_mut().for_each(Self::single_sbox);
     }
 
-    fn single_sbox(input: &mut F) {
+    /// Applies the S-box to a single element, i.e., computes x^D.
+    pub fn single_sbox(input: &mut F) {
         match D {
             3 => {
                 let input2 = input.square();
@@ -65,7 +70,9 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
         input[3] = t_4;
     }
 
-    fn matmul_external(input: &mut [F; T]) {
+    /// Multiplies the state with the external MDS matrix. Since this is a linear operation, it can also be applied to
+    /// the components of additive or linear secret shares.
+    pub fn matmul_external(input: &mut [F; T]) {
         match T {
             2 => {
                 // Matrix circ(2, 1)
@@ -107,7 +114,9 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
         }
     }
 
-    fn matmul_internal(&self, input: &mut [F; T]) {
+    /// Multiplies the state with the internal matrix. Since this is a linear operation, it can also be applied to the
+    /// components of additive or linear secret shares.
+    pub fn matmul_internal(&self, input: &mut [F; T]) {
         // Compute input sum
         let sum: F = input.iter().sum();
         // Add sum + diag entry * element to each element
@@ -121,7 +130,8 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
         }
     }
 
-    fn add_rc_external(&self, input: &mut [F; T], rc_offset: usize) {
+    /// Adds the round constants of the external round `rc_offset` to the state.
+    pub fn add_rc_external(&self, input: &mut [F; T], rc_offset: usize) {
         for (s, rc) in input
             .iter_mut()
             .zip(self.params.round_constants_external[rc_offset].iter())
@@ -130,52 +140,76 @@ impl<F: PrimeField, const T: usize, const D: u64> Poseidon2<F, T, D> {
         }
     }
 
-    fn add_rc_internal(&self, input: &mut [F; T], rc_offset: usize) {
+    /// Adds the round constant of the internal round `rc_offset` to the state.
+    pub fn add_rc_internal(&self, input: &mut [F; T], rc_offset: usize) {
         input[0] += &self.params.round_constants_internal[rc_offset];
     }
-}
 
-impl<F: PrimeField, const T: usize, const D: u64> FieldHash<F, T> for Poseidon2<F, T, D> {
-    fn permutation_in_place(&self, state: &mut [F; T]) {
+    /// Computes one external round, i.e., the addition of the round constants, the S-box on the full state and the
+    /// external matrix multiplication.
+    pub fn external_round(&self, state: &mut [F; T], r: usize) {
+        self.add_rc_external(state, r);
+        Self::sbox(state);
+        Self::matmul_external(state);
+    }
+
+    /// Computes one internal round, i.e., the addition of the round constant, the S-box on the first element and the
+    /// internal matrix multiplication.
+    pub fn internal_round(&self, state: &mut [F; T], r: usize) {
+        self.add_rc_internal(state, r);
+        Self::single_sbox(&mut state[0]);
+        self.matmul_internal(state);
+    }
+
+    /// Computes the Poseidon2 permutation on the given state.
+    pub fn permutation(&self, input: &[F; T]) -> [F; T] {
+        let mut state = *input;
+        self.permutation_in_place(&mut state);
+        state
+    }
+
+    /// Computes the Poseidon2 permutation on the given state in place.
+    pub fn permutation_in_place(&self, state: &mut [F; T]) {
         // Linear layer at beginning
         Self::matmul_external(state);
 
         // First set of external rounds
         for r in 0..self.params.rounds_f_beginning {
-            self.add_rc_external(state, r);
-            Self::sbox(state);
-            Self::matmul_external(state);
+            self.external_round(state, r);
         }
 
         // Internal rounds
         for r in 0..self.params.rounds_p {
-            self.add_rc_internal(state, r);
-            Self::single_sbox(&mut state[0]);
-            self.matmul_internal(state);
+            self.internal_round(state, r);
         }
 
         // Remaining external rounds
         for r in self.params.rounds_f_beginning
             ..self.params.rounds_f_beginning + self.params.rounds_f_end
         {
-            self.add_rc_external(state, r);
-            Self::sbox(state);
-            Self::matmul_external(state);
+            self.external_round(state, r);
         }
     }
 }
 
-impl Default for Poseidon2<ark_bn254::Fr, 4, 5> {
+impl<F: PrimeField> Default for Poseidon2<F, 4, 5> {
     fn default() -> Self {
-        let params = &crate::poseidon2::poseidon2_bn254::POSEIDON2_BN254_T4_PARAMS;
-        Poseidon2::new(params)
+        // Parameters are only available for the BN254 scalar field, thus we panic for other fields
+        let params = &super::poseidon2_bn254::POSEIDON2_BN254_T4_PARAMS;
+        let poseidon2 = Poseidon2::<ark_bn254::Fr, 4, 5>::new(params);
+        (&poseidon2 as &dyn Any)
+            .downcast_ref::<Self>()
+            .expect("Poseidon2 parameters are only available for the BN254 scalar field")
+            .to_owned()
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{poseidon2::poseidon2_bn254::POSEIDON2_BN254_T4_PARAMS, Utils};
+    use crate::gadgets::poseidon2::poseidon2_bn254::{
+        field_from_hex_string, POSEIDON2_BN254_T4_PARAMS,
+    };
     use rand::thread_rng;
 
     const TESTRUNS: usize = 10;
@@ -223,19 +257,19 @@ mod test {
             ark_bn254::Fr::from(3u64),
         ];
         let expected = [
-            Utils::field_from_hex_string(
+            field_from_hex_string(
                 "0x01bd538c2ee014ed5141b29e9ae240bf8db3fe5b9a38629a9647cf8d76c01737",
             )
             .unwrap(),
-            Utils::field_from_hex_string(
+            field_from_hex_string(
                 "0x239b62e7db98aa3a2a8f6a0d2fa1709e7a35959aa6c7034814d9daa90cbac662",
             )
             .unwrap(),
-            Utils::field_from_hex_string(
+            field_from_hex_string(
                 "0x04cbb44c61d928ed06808456bf758cbf0c18d1e15a7b6dbc8245fa7515d5e3cb",
             )
             .unwrap(),
-            Utils::field_from_hex_string(
+            field_from_hex_string(
                 "0x2e11c5cff2a22c64d01304b778d78f6998eff1ab73163a35603f54794c30847a",
             )
             .unwrap(),
@@ -247,37 +281,37 @@ mod test {
     #[test]
     fn posedon2_bn254_t4_kat2() {
         let input = [
-            Utils::field_from_hex_string(
+            field_from_hex_string(
                 "9a807b615c4d3e2fa0b1c2d3e4f56789fedcba9876543210abcdef0123456789",
             )
             .unwrap(),
-            Utils::field_from_hex_string(
+            field_from_hex_string(
                 "9a807b615c4d3e2fa0b1c2d3e4f56789fedcba9876543210abcdef0123456789",
             )
             .unwrap(),
-            Utils::field_from_hex_string(
+            field_from_hex_string(
                 "0x9a807b615c4d3e2fa0b1c2d3e4f56789fedcba9876543210abcdef0123456789",
             )
             .unwrap(),
-            Utils::field_from_hex_string(
+            field_from_hex_string(
                 "0x9a807b615c4d3e2fa0b1c2d3e4f56789fedcba9876543210abcdef0123456789",
             )
             .unwrap(),
         ];
         let expected = [
-            Utils::field_from_hex_string(
+            field_from_hex_string(
                 "0x2bf1eaf87f7d27e8dc4056e9af975985bccc89077a21891d6c7b6ccce0631f95",
             )
             .unwrap(),
-            Utils::field_from_hex_string(
+            field_from_hex_string(
                 "0x0c01fa1b8d0748becafbe452c0cb0231c38224ea824554c9362518eebdd5701f",
             )
             .unwrap(),
-            Utils::field_from_hex_string(
+            field_from_hex_string(
                 "0x018555a8eb50cf07f64b019ebaf3af3c925c93e631f3ecd455db07bbb52bbdd3",
             )
             .unwrap(),
-            Utils::field_from_hex_string(
+            field_from_hex_string(
                 "0x0cbea457c91c22c6c31fd89afd2541efc2edf31736b9f721e823b2165c90fd41",
             )
             .unwrap(),
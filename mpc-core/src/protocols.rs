@@ -3,6 +3,7 @@
 //! Contains the implementations of the different MPC protocols. Currently, semi-honest 3-party replicated sharing (REP3) and semi-honest n-party Shamir secret sharing are implemented.
 
 pub mod bridges;
+pub mod local_network;
 pub mod rep3;
 pub(crate) mod serde_compat;
 pub mod shamir;
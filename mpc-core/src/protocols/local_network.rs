@@ -0,0 +1,476 @@
+//! # Local Network
+//!
+//! This module contains in-process implementations of the [Rep3Network] and [ShamirNetwork] traits. All parties run in the same process, e.g., in separate threads, and are connected by channels. This allows to test protocols and to simulate MPC on a single machine without setting up a network.
+
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    mpsc::{self, Receiver, Sender},
+    Arc,
+};
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use super::{
+    bridges::network::RepToShamirNetwork,
+    rep3::{id::PartyID, network::Rep3Network},
+    shamir::network::ShamirNetwork,
+};
+
+/// A message sent between two parties: either serialized data or the receiving end of a forked channel.
+#[derive(Debug)]
+enum Msg {
+    Data(Vec<u8>),
+    Recv(Receiver<Msg>),
+}
+
+/// The number of bytes sent to and received from another party.
+#[derive(Debug, Default)]
+struct ConnectionStats {
+    sent: AtomicU64,
+    received: AtomicU64,
+}
+
+/// The channels of a party to all other parties. The statistics are shared between all forks of the network.
+#[derive(Debug)]
+struct LocalConnections {
+    id: usize,
+    num_parties: usize,
+    // indexed by the id of the other party, None for the party itself
+    send: Vec<Option<Sender<Msg>>>,
+    recv: Vec<Option<Receiver<Msg>>>,
+    stats: Arc<Vec<ConnectionStats>>,
+}
+
+impl LocalConnections {
+    fn new(num_parties: usize) -> Vec<Self> {
+        let mut parties = (0..num_parties)
+            .map(|id| Self {
+                id,
+                num_parties,
+                send: (0..num_parties).map(|_| None).collect(),
+                recv: (0..num_parties).map(|_| None).collect(),
+                stats: Arc::new((0..num_parties).map(|_| Default::default()).collect()),
+            })
+            .collect::<Vec<_>>();
+        for from in 0..num_parties {
+            for to in 0..num_parties {
+                if from != to {
+                    let (send, recv) = mpsc::channel();
+                    parties[from].send[to] = Some(send);
+                    parties[to].recv[from] = Some(recv);
+                }
+            }
+        }
+        parties
+    }
+
+    fn send_msg(&self, target: usize, msg: Msg) -> std::io::Result<()> {
+        let sender = self
+            .send
+            .get(target)
+            .and_then(Option::as_ref)
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("No channel found for party id={}", target),
+                )
+            })?;
+        sender.send(msg).map_err(|_| {
+            std::io::Error::new(std::io::ErrorKind::BrokenPipe, "receive channel end died")
+        })
+    }
+
+    fn recv_msg(&self, from: usize) -> std::io::Result<Msg> {
+        let receiver = self
+            .recv
+            .get(from)
+            .and_then(Option::as_ref)
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("No channel found for party id={}", from),
+                )
+            })?;
+        receiver.recv().map_err(|_| {
+            std::io::Error::new(std::io::ErrorKind::BrokenPipe, "send channel end died")
+        })
+    }
+
+    fn send_bytes(&self, target: usize, data: Vec<u8>) -> std::io::Result<()> {
+        let len = data.len() as u64;
+        self.send_msg(target, Msg::Data(data))?;
+        self.stats[target].sent.fetch_add(len, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn recv_bytes(&self, from: usize) -> std::io::Result<Vec<u8>> {
+        let Msg::Data(data) = self.recv_msg(from)? else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Expected data, got a channel",
+            ));
+        };
+        self.stats[from]
+            .received
+            .fetch_add(data.len() as u64, Ordering::Relaxed);
+        Ok(data)
+    }
+
+    fn send_many<F: CanonicalSerialize>(&self, target: usize, data: &[F]) -> std::io::Result<()> {
+        self.send_bytes(target, serialize(data)?)
+    }
+
+    fn recv_many<F: CanonicalDeserialize>(&self, from: usize) -> std::io::Result<Vec<F>> {
+        deserialize(&self.recv_bytes(from)?)
+    }
+
+    fn fork(&self) -> std::io::Result<Self> {
+        // every party creates the channels it sends on and hands the receiving ends to the other parties
+        let mut send = Vec::with_capacity(self.num_parties);
+        for other_id in 0..self.num_parties {
+            if other_id == self.id {
+                send.push(None);
+            } else {
+                let (sender, receiver) = mpsc::channel();
+                self.send_msg(other_id, Msg::Recv(receiver))?;
+                send.push(Some(sender));
+            }
+        }
+        let mut recv = Vec::with_capacity(self.num_parties);
+        for other_id in 0..self.num_parties {
+            if other_id == self.id {
+                recv.push(None);
+            } else {
+                let Msg::Recv(receiver) = self.recv_msg(other_id)? else {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "Expected a channel, got data",
+                    ));
+                };
+                recv.push(Some(receiver));
+            }
+        }
+        Ok(Self {
+            id: self.id,
+            num_parties: self.num_parties,
+            send,
+            recv,
+            stats: Arc::clone(&self.stats),
+        })
+    }
+
+    fn get_send_receive(&self, other_id: usize) -> std::io::Result<(u64, u64)> {
+        if other_id == self.id || other_id >= self.num_parties {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "no such connection",
+            ));
+        }
+        let stats = &self.stats[other_id];
+        Ok((
+            stats.sent.load(Ordering::Relaxed),
+            stats.received.load(Ordering::Relaxed),
+        ))
+    }
+
+    fn print_connection_stats(&self, out: &mut impl std::io::Write) -> std::io::Result<()> {
+        for other_id in (0..self.num_parties).filter(|other_id| *other_id != self.id) {
+            let (sent, received) = self.get_send_receive(other_id)?;
+            writeln!(
+                out,
+                "Connection {} stats:\n\tSENT: {} bytes\n\tRECV: {} bytes",
+                other_id, sent, received
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn serialize<F: CanonicalSerialize + ?Sized>(data: &F) -> std::io::Result<Vec<u8>> {
+    let size = data.serialized_size(ark_serialize::Compress::No);
+    let mut ser_data = Vec::with_capacity(size);
+    data.serialize_uncompressed(&mut ser_data)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+    Ok(ser_data)
+}
+
+fn deserialize<F: CanonicalDeserialize>(data: &[u8]) -> std::io::Result<F> {
+    F::deserialize_uncompressed(data)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+}
+
+/// An in-process network for the REP3 MPC protocol, where the three parties are connected by channels.
+#[derive(Debug)]
+pub struct Rep3LocalNetwork {
+    id: PartyID,
+    connections: LocalConnections,
+}
+
+impl Rep3LocalNetwork {
+    /// Creates the connected networks of the three parties, ordered by their id.
+    pub fn new() -> [Self; 3] {
+        let mut connections = LocalConnections::new(3).into_iter();
+        [PartyID::ID0, PartyID::ID1, PartyID::ID2].map(|id| Self {
+            id,
+            connections: connections.next().expect("we created three parties"),
+        })
+    }
+
+    /// Returns the number of bytes sent to and received from the given party, including all forks of this network.
+    pub fn get_send_receive(&self, other_id: PartyID) -> std::io::Result<(u64, u64)> {
+        self.connections.get_send_receive(other_id.into())
+    }
+
+    /// Prints the number of bytes sent to and received from the other parties.
+    pub fn print_connection_stats(&self, out: &mut impl std::io::Write) -> std::io::Result<()> {
+        self.connections.print_connection_stats(out)
+    }
+}
+
+impl Rep3Network for Rep3LocalNetwork {
+    fn get_id(&self) -> PartyID {
+        self.id
+    }
+
+    fn reshare_many<F: CanonicalSerialize + CanonicalDeserialize>(
+        &mut self,
+        data: &[F],
+    ) -> std::io::Result<Vec<F>> {
+        self.send_many(self.id.next_id(), data)?;
+        self.recv_many(self.id.prev_id())
+    }
+
+    fn broadcast_many<F: CanonicalSerialize + CanonicalDeserialize>(
+        &mut self,
+        data: &[F],
+    ) -> std::io::Result<(Vec<F>, Vec<F>)> {
+        self.send_many(self.id.next_id(), data)?;
+        self.send_many(self.id.prev_id(), data)?;
+        let recv_next = self.recv_many(self.id.next_id())?;
+        let recv_prev = self.recv_many(self.id.prev_id())?;
+        Ok((recv_prev, recv_next))
+    }
+
+    fn send_many<F: CanonicalSerialize>(
+        &mut self,
+        target: PartyID,
+        data: &[F],
+    ) -> std::io::Result<()> {
+        if target == self.id {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Cannot send to self",
+            ));
+        }
+        self.connections.send_many(target.into(), data)
+    }
+
+    fn recv_many<F: CanonicalDeserialize>(&mut self, from: PartyID) -> std::io::Result<Vec<F>> {
+        if from == self.id {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Cannot recv from self",
+            ));
+        }
+        self.connections.recv_many(from.into())
+    }
+
+    fn fork(&mut self) -> std::io::Result<Self> {
+        Ok(Self {
+            id: self.id,
+            connections: self.connections.fork()?,
+        })
+    }
+}
+
+impl RepToShamirNetwork<ShamirLocalNetwork> for Rep3LocalNetwork {
+    fn to_shamir_net(self) -> ShamirLocalNetwork {
+        ShamirLocalNetwork {
+            connections: self.connections,
+        }
+    }
+}
+
+/// An in-process network for the Shamir MPC protocol, where all parties are connected by channels.
+#[derive(Debug)]
+pub struct ShamirLocalNetwork {
+    connections: LocalConnections,
+}
+
+impl ShamirLocalNetwork {
+    /// Creates the connected networks of the given number of parties, ordered by their id.
+    pub fn new(num_parties: usize) -> Vec<Self> {
+        LocalConnections::new(num_parties)
+            .into_iter()
+            .map(|connections| Self { connections })
+            .collect()
+    }
+
+    /// Returns the number of bytes sent to and received from the given party, including all forks of this network.
+    pub fn get_send_receive(&self, other_id: usize) -> std::io::Result<(u64, u64)> {
+        self.connections.get_send_receive(other_id)
+    }
+
+    /// Prints the number of bytes sent to and received from the other parties.
+    pub fn print_connection_stats(&self, out: &mut impl std::io::Write) -> std::io::Result<()> {
+        self.connections.print_connection_stats(out)
+    }
+}
+
+impl ShamirNetwork for ShamirLocalNetwork {
+    fn get_id(&self) -> usize {
+        self.connections.id
+    }
+
+    fn get_num_parties(&self) -> usize {
+        self.connections.num_parties
+    }
+
+    fn send_many<F: CanonicalSerialize>(
+        &mut self,
+        target: usize,
+        data: &[F],
+    ) -> std::io::Result<()> {
+        self.connections.send_many(target, data)
+    }
+
+    fn recv_many<F: CanonicalDeserialize>(&mut self, from: usize) -> std::io::Result<Vec<F>> {
+        self.connections.recv_many(from)
+    }
+
+    fn broadcast<F: CanonicalSerialize + CanonicalDeserialize + Clone>(
+        &mut self,
+        data: F,
+    ) -> std::io::Result<Vec<F>> {
+        let id = self.get_id();
+        let num_parties = self.get_num_parties();
+        let send_data = serialize(&data)?;
+        for other_id in (0..num_parties).filter(|other_id| *other_id != id) {
+            self.connections
+                .send_bytes(other_id, send_data.to_owned())?;
+        }
+
+        let mut res = Vec::with_capacity(num_parties);
+        for other_id in 0..num_parties {
+            if other_id == id {
+                res.push(data.to_owned());
+            } else {
+                res.push(deserialize(&self.connections.recv_bytes(other_id)?)?);
+            }
+        }
+        Ok(res)
+    }
+
+    fn broadcast_next<F: CanonicalSerialize + CanonicalDeserialize + Clone>(
+        &mut self,
+        data: F,
+        num: usize,
+    ) -> std::io::Result<Vec<F>> {
+        let id = self.get_id();
+        let num_parties = self.get_num_parties();
+        let send_data = serialize(&data)?;
+        for s in 1..num {
+            let other_id = (id + s) % num_parties;
+            self.connections
+                .send_bytes(other_id, send_data.to_owned())?;
+        }
+
+        let mut res = Vec::with_capacity(num);
+        res.push(data);
+        for r in 1..num {
+            let other_id = (id + num_parties - r) % num_parties;
+            res.push(deserialize(&self.connections.recv_bytes(other_id)?)?);
+        }
+        Ok(res)
+    }
+
+    fn send_and_recv_each_many<
+        F: CanonicalSerialize + CanonicalDeserialize + Clone + Send + 'static,
+    >(
+        &mut self,
+        data: Vec<Vec<F>>,
+    ) -> std::io::Result<Vec<Vec<F>>> {
+        debug_assert_eq!(data.len(), self.get_num_parties());
+        let id = self.get_id();
+        // the channels are unbounded, so we can first send to everyone and then receive
+        for (other_id, data) in data.iter().enumerate() {
+            if other_id != id {
+                self.connections.send_many(other_id, data)?;
+            }
+        }
+        let mut res = Vec::with_capacity(data.len());
+        for (other_id, data) in data.into_iter().enumerate() {
+            if other_id == id {
+                res.push(data);
+            } else {
+                res.push(self.connections.recv_many(other_id)?);
+            }
+        }
+        Ok(res)
+    }
+
+    fn fork(&mut self) -> std::io::Result<Self> {
+        Ok(Self {
+            connections: self.connections.fork()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rep3_local_network() {
+        let parties = Rep3LocalNetwork::new().map(|mut net| {
+            std::thread::spawn(move || {
+                let id = usize::from(net.get_id()) as u64;
+                let prev: u64 = net.reshare(id).unwrap();
+                assert_eq!(prev, (id + 2) % 3);
+                let mut forked = net.fork().unwrap();
+                let (prev, next): (u64, u64) = forked.broadcast(10 * id).unwrap();
+                assert_eq!(prev, 10 * ((id + 2) % 3));
+                assert_eq!(next, 10 * ((id + 1) % 3));
+                // the original network is still usable after forking
+                let prev: u64 = net.reshare(id + 100).unwrap();
+                assert_eq!(prev, (id + 2) % 3 + 100);
+                let next_id = net.get_id().next_id();
+                let prev_id = net.get_id().prev_id();
+                // two reshares and one broadcast of a u64 each
+                assert_eq!(net.get_send_receive(next_id).unwrap(), (3 * 16, 16));
+                assert_eq!(net.get_send_receive(prev_id).unwrap(), (16, 3 * 16));
+            })
+        });
+        for party in parties {
+            party.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn shamir_local_network() {
+        let num_parties = 4;
+        let parties = ShamirLocalNetwork::new(num_parties)
+            .into_iter()
+            .map(|mut net| {
+                std::thread::spawn(move || {
+                    let id = net.get_id() as u64;
+                    let all: Vec<u64> = net.broadcast(id).unwrap();
+                    assert_eq!(all, vec![0, 1, 2, 3]);
+                    let mut forked = net.fork().unwrap();
+                    let data = (0..num_parties as u64)
+                        .map(|other_id| vec![10 * id + other_id])
+                        .collect();
+                    let received = forked.send_and_recv_each_many(data).unwrap();
+                    for (other_id, received) in received.into_iter().enumerate() {
+                        assert_eq!(received, vec![10 * other_id as u64 + id]);
+                    }
+                    let next: Vec<u64> = net.broadcast_next(id, 2).unwrap();
+                    assert_eq!(next, vec![id, (id + 3) % 4]);
+                })
+            })
+            .collect::<Vec<_>>();
+        for party in parties {
+            party.join().unwrap();
+        }
+    }
+}
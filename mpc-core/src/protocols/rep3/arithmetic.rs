@@ -619,3 +619,16 @@ pub(crate) fn arithmetic_xor<F: PrimeField, N: Rep3Network>(
     let d = sub(e, d);
     Ok(d)
 }
+
+/// computes XOR using arithmetic operations for vectors, only valid when x and y are known to be 0 or 1.
+pub(crate) fn arithmetic_xor_many<F: PrimeField, N: Rep3Network>(
+    x: &[Rep3PrimeFieldShare<F>],
+    y: &[Rep3PrimeFieldShare<F>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    debug_assert_eq!(x.len(), y.len());
+    let d = mul_vec(x, y, io_context)?;
+    Ok(izip!(x, y, d)
+        .map(|(x, y, d)| sub(add(*x, *y), add(d, d)))
+        .collect())
+}
@@ -119,64 +119,89 @@ pub fn shift_l_public<F: PrimeField>(shared: &BinaryShare<F>, public: F) -> Bina
     shared << shift
 }
 
-/// Shifts a public value `F` by a share to the left.
+/// Shifts a public value `F` by a share to the left, i.e., computes `public * 2^shared` modulo the prime. All `F::MODULUS_BIT_SIZE` bits of the shift are considered, so the result is the same as shifting in plain and reducing afterwards.
 pub fn shift_l_public_by_shared<F: PrimeField, N: Rep3Network>(
     public: F,
     shared: &BinaryShare<F>,
     io_context: &mut IoContext<N>,
 ) -> IoResult<ArithmeticShare<F>> {
     // This case is equivalent to a*2^b
-    // Strategy: limit size of b to k bits
-    // bit-decompose b into bits b_i
-
-    // TODO: this sucks... we need something better here...
-    let io_0 = io_context.fork()?;
-    let io_1 = io_context.fork()?;
-    let io_2 = io_context.fork()?;
-    let io_3 = io_context.fork()?;
-    let io_4 = io_context.fork()?;
-    let io_5 = io_context.fork()?;
-    let io_6 = io_context.fork()?;
-    let io_7 = io_context.fork()?;
-    let mut contexts = [io_0, io_1, io_2, io_3, io_4, io_5, io_6, io_7];
+    // Strategy: bit-decompose b into bits b_i, then 2^b = \prod 2^(2^i * b_i)
+    let bit_len = F::MODULUS_BIT_SIZE as usize;
+    let bits = (0..bit_len)
+        .map(|i| {
+            Rep3BigUintShare::new(
+                (shared.a.clone() >> i) & BigUint::one(),
+                (shared.b.clone() >> i) & BigUint::one(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let individual_bit_shares = conversion::bit_inject_many(&bits, io_context)?;
+    // v_i = 2^2^i * <b_i> + 1 - <b_i>
     let party_id = io_context.id;
-    let mut individual_bit_shares = Vec::with_capacity(8);
-    for (i, context) in izip!((0..8), contexts.iter_mut()) {
-        let bit = Rep3BigUintShare::new(
-            (shared.a.clone() >> i) & BigUint::one(),
-            (shared.b.clone() >> i) & BigUint::one(),
-        );
-        individual_bit_shares.push(conversion::b2a_selector(&bit, context)?);
+    let mut two_to_two_to_i = F::from(2u64);
+    let mut vs = Vec::with_capacity(bit_len);
+    for b_i in individual_bit_shares {
+        let v = arithmetic::mul_public(b_i, two_to_two_to_i - F::one());
+        vs.push(arithmetic::add_public(v, F::one(), party_id));
+        two_to_two_to_i.square_in_place();
     }
-    // v_i = 2^2^i * <b_i> + 1 - <b_i>
-    let mut vs: Vec<_> = individual_bit_shares
-        .into_iter()
-        .enumerate()
-        .map(|(i, b_i)| {
-            let two = F::from(2u64);
-            // i is 8 at most there `as u32` is ok
-            let two_to_two_to_i = two.pow([2u64.pow(i as u32)]);
-            let v = arithmetic::mul_public(b_i, two_to_two_to_i);
-            let v = arithmetic::add_public(v, F::one(), party_id);
-            arithmetic::sub(v, b_i)
-        })
-        .collect();
 
-    // v = \prod v_i
-    // TODO: This should be done in a multiplication tree
-    let mut v = vs.pop().unwrap();
-    for v_i in vs {
-        v = arithmetic::mul(v, v_i, io_context)?;
+    // v = \prod v_i in a multiplication tree
+    while vs.len() > 1 {
+        let half = vs.len() / 2;
+        let (lhs, rhs) = vs.split_at(half);
+        let mut res = arithmetic::mul_vec(lhs, &rhs[..half], io_context)?;
+        res.extend_from_slice(&rhs[half..]);
+        vs = res;
+    }
+    Ok(arithmetic::mul_public(vs[0], public))
+}
+
+/// Shifts a share by a shared value to the left, i.e., computes `shared * 2^shift` modulo the prime. As in [`shift_l_public_by_shared`], all bits of the shift are considered.
+pub fn shift_l_by_shared<F: PrimeField, N: Rep3Network>(
+    shared: ArithmeticShare<F>,
+    shift: &BinaryShare<F>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<ArithmeticShare<F>> {
+    let pow = shift_l_public_by_shared(F::one(), shift, io_context)?;
+    arithmetic::mul(shared, pow, io_context)
+}
+
+/// Expands a binary share of one bit to a binary share of a value where all bits are set to this bit.
+fn expand_bit<F: PrimeField>(bit: &BinaryShare<F>) -> BinaryShare<F> {
+    let bit_len = F::MODULUS_BIT_SIZE as usize;
+    let mask = (BigUint::from(1u64) << bit_len) - BigUint::one();
+    let expand = |x: &BigUint| {
+        if x.bit(0) {
+            mask.to_owned()
+        } else {
+            BigUint::ZERO
+        }
+    };
+    BinaryShare::new(expand(&bit.a), expand(&bit.b))
+}
+
+/// Shifts a share by a shared value to the right using a logarithmic barrel shifter. All shifts larger than the bit size of the underlying `PrimeField`'s modulus result in zero.
+pub fn shift_r_by_shared<F: PrimeField, N: Rep3Network>(
+    shared: &BinaryShare<F>,
+    shift: &BinaryShare<F>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<BinaryShare<F>> {
+    // Number of bits required to represent all meaningful shifts
+    let shift_bits = (usize::BITS - (F::MODULUS_BIT_SIZE as usize).leading_zeros()) as usize;
+
+    let mut result = shared.to_owned();
+    for i in 0..shift_bits {
+        let bit = expand_bit(&((shift >> i) & BigUint::one()));
+        let shifted = &result >> (1 << i);
+        let xor = &result ^ &shifted;
+        result ^= and(&bit, &xor, io_context)?;
     }
-    // TODO could use try_fold from futures::stream
-    // let last = vs.pop().unwrap();
-    // let v = self.runtime.block_on(
-    //     futures::stream::iter(vs.into_iter().map(|v| Ok(v)))
-    //         .try_fold(last, |a, b|  move {
-    //             arithmetic::mul(a, b, &mut self.io_context)
-    //         }),
-    // )?;
-    Ok(arithmetic::mul_public(v, public))
+
+    // The result is zero if any of the higher bits of the shift is set
+    let in_range = is_zero(&(shift >> shift_bits), io_context)?;
+    and(&result, &expand_bit(&in_range), io_context)
 }
 
 //pub  fn and_vec(
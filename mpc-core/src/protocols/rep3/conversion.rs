@@ -17,6 +17,7 @@ use super::{
 };
 use ark_ff::PrimeField;
 use fancy_garbling::{BinaryBundle, WireMod2};
+use itertools::izip;
 use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
 
@@ -199,6 +200,40 @@ pub fn bit_inject<F: PrimeField, N: Rep3Network>(
     Ok(e)
 }
 
+/// Translates a vector of shared bits into arithmetic sharings of the same bits. See [`bit_inject`] for details.
+pub fn bit_inject_many<F: PrimeField, N: Rep3Network>(
+    x: &[Rep3BigUintShare<F>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    // standard bit inject
+    assert!(x.iter().all(|x| x.a.bits() <= 1));
+
+    let mut b0 = vec![Rep3PrimeFieldShare::<F>::default(); x.len()];
+    let mut b1 = vec![Rep3PrimeFieldShare::<F>::default(); x.len()];
+    let mut b2 = vec![Rep3PrimeFieldShare::<F>::default(); x.len()];
+
+    for (x, b0, b1, b2) in izip!(x, b0.iter_mut(), b1.iter_mut(), b2.iter_mut()) {
+        match io_context.id {
+            PartyID::ID0 => {
+                b0.a = x.a.to_owned().into();
+                b2.b = x.b.to_owned().into();
+            }
+            PartyID::ID1 => {
+                b1.a = x.a.to_owned().into();
+                b0.b = x.b.to_owned().into();
+            }
+            PartyID::ID2 => {
+                b2.a = x.a.to_owned().into();
+                b1.b = x.b.to_owned().into();
+            }
+        };
+    }
+
+    let d = arithmetic::arithmetic_xor_many(&b0, &b1, io_context)?;
+    let e = arithmetic::arithmetic_xor_many(&d, &b2, io_context)?;
+    Ok(e)
+}
+
 /// Transforms the replicated shared value x from an arithmetic sharing to a yao sharing. I.e., x = x_1 + x_2 + x_3 gets transformed into wires, such that the garbler have keys (k_0, delta) for each bit of x, while the evaluator has k_x = k_0 xor delta * x.
 pub fn a2y<F: PrimeField, N: Rep3Network>(
     x: Rep3PrimeFieldShare<F>,
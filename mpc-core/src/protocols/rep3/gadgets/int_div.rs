@@ -0,0 +1,104 @@
+//! Integer division
+//!
+//! This module contains gadgets for the integer division with remainder of shared field elements for the Rep3 protocol. Thereby, the field elements are interpreted as unsigned integers in the range [0, p).
+
+use crate::protocols::rep3::{
+    arithmetic::FieldShare,
+    network::{IoContext, Rep3Network},
+    yao::{self, circuits::GarbledCircuits},
+    IoResult,
+};
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+
+/// Computes the integer division with remainder of the shared dividend by the shared divisor. Returns the quotient and the remainder.
+///
+/// A shared divisor of zero is not detected, since this would require to open whether it is zero. Instead, the quotient is `2^F::MODULUS_BIT_SIZE - 1` reduced modulo the prime and the remainder equals the dividend. Callers which have to reject a zero divisor need to check it themselves.
+pub fn int_div_rem<F: PrimeField, N: Rep3Network>(
+    dividend: FieldShare<F>,
+    divisor: FieldShare<F>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<(FieldShare<F>, FieldShare<F>)> {
+    let (quotients, remainders) = int_div_rem_many(&[dividend], &[divisor], io_context)?;
+    Ok((quotients[0], remainders[0]))
+}
+
+/// Computes the integer division with remainder of the shared dividends by the shared divisors element-wise. Returns the quotients and the remainders.
+///
+/// As in [`int_div_rem`], a shared divisor of zero does not result in an error, but in the quotient `2^F::MODULUS_BIT_SIZE - 1` (reduced modulo the prime) and a remainder equal to the dividend.
+pub fn int_div_rem_many<F: PrimeField, N: Rep3Network>(
+    dividends: &[FieldShare<F>],
+    divisors: &[FieldShare<F>],
+    io_context: &mut IoContext<N>,
+) -> IoResult<(Vec<FieldShare<F>>, Vec<FieldShare<F>>)> {
+    if dividends.len() != divisors.len() {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "The number of dividends and divisors must be equal",
+        ))?;
+    }
+    if dividends.is_empty() {
+        return Ok((Vec::new(), Vec::new()));
+    }
+    let num_inputs = dividends.len();
+    let inputs = dividends
+        .iter()
+        .chain(divisors.iter())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut quotients: Vec<FieldShare<F>> = yao::decompose_circuit_compose_blueprint!(
+        &inputs,
+        io_context,
+        2 * num_inputs,
+        GarbledCircuits::int_div_rem_many::<_, F>,
+        ()
+    )?;
+    let remainders = quotients.split_off(num_inputs);
+    Ok((quotients, remainders))
+}
+
+/// Computes the integer division with remainder of the shared dividend by the public divisor. Returns the quotient and the remainder. Fails if the divisor is zero.
+pub fn int_div_rem_by_public<F: PrimeField, N: Rep3Network>(
+    dividend: FieldShare<F>,
+    divisor: F,
+    io_context: &mut IoContext<N>,
+) -> IoResult<(FieldShare<F>, FieldShare<F>)> {
+    let (quotients, remainders) = int_div_rem_by_public_many(&[dividend], divisor, io_context)?;
+    Ok((quotients[0], remainders[0]))
+}
+
+/// Computes the integer division with remainder of the shared dividends by the public divisor element-wise. Returns the quotients and the remainders. Fails if the divisor is zero.
+pub fn int_div_rem_by_public_many<F: PrimeField, N: Rep3Network>(
+    dividends: &[FieldShare<F>],
+    divisor: F,
+    io_context: &mut IoContext<N>,
+) -> IoResult<(Vec<FieldShare<F>>, Vec<FieldShare<F>>)> {
+    if divisor.is_zero() {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Cannot divide by zero",
+        ))?;
+    }
+    if divisor.is_one() {
+        return Ok((
+            dividends.to_vec(),
+            vec![FieldShare::zero_share(); dividends.len()],
+        ));
+    }
+    if dividends.is_empty() {
+        return Ok((Vec::new(), Vec::new()));
+    }
+    let num_inputs = dividends.len();
+    let divisor: BigUint = divisor.into();
+
+    let mut quotients: Vec<FieldShare<F>> = yao::decompose_circuit_compose_blueprint!(
+        dividends,
+        io_context,
+        2 * num_inputs,
+        GarbledCircuits::int_div_rem_by_public_many::<_, F>,
+        (&divisor)
+    )?;
+    let remainders = quotients.split_off(num_inputs);
+    Ok((quotients, remainders))
+}
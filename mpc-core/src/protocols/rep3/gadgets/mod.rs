@@ -2,4 +2,7 @@
 //!
 //! This module contains some commonly used gadgets for the Rep3 protocol.
 
+pub mod int_div;
+pub mod ohv;
+pub mod pow;
 pub mod sort;
@@ -0,0 +1,42 @@
+//! One-hot vector
+//!
+//! This module contains a gadget to translate a shared index into a shared one-hot vector for the Rep3 protocol.
+
+use crate::protocols::rep3::{
+    arithmetic::{self, FieldShare},
+    network::{IoContext, Rep3Network},
+    yao, IoResult,
+};
+use ark_ff::PrimeField;
+
+/// Translates a shared index into a shared one-hot vector of size `len`, i.e., the resulting vector is 1 at the position of the index and 0 everywhere else. The index must be smaller than `len`.
+pub fn ohv_from_index<F: PrimeField, N: Rep3Network>(
+    index: FieldShare<F>,
+    len: usize,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<FieldShare<F>>> {
+    let num_bits = len.next_power_of_two().ilog2() as usize;
+    let mut ohv = vec![arithmetic::promote_to_trivial_share(
+        io_context.id,
+        F::one(),
+    )];
+    if num_bits == 0 {
+        return Ok(ohv);
+    }
+
+    let bits = yao::decompose_arithmetic(index, io_context, num_bits, 1)?;
+
+    // We start with the most significant bit, such that the index of the one in the vector grows with the lower bits
+    for bit in bits.into_iter().rev() {
+        let bit_vec = vec![bit; ohv.len()];
+        let products = arithmetic::mul_vec(&ohv, &bit_vec, io_context)?;
+        ohv = ohv
+            .into_iter()
+            .zip(products)
+            .flat_map(|(v, p)| [arithmetic::sub(v, p), p])
+            .collect();
+    }
+    ohv.truncate(len);
+
+    Ok(ohv)
+}
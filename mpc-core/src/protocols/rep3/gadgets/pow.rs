@@ -0,0 +1,79 @@
+//! Pow
+//!
+//! This module contains gadgets to compute powers with a shared exponent for the Rep3 protocol.
+
+use crate::protocols::rep3::{
+    arithmetic::{self, FieldShare},
+    network::{IoContext, Rep3Network},
+    yao, IoResult,
+};
+use ark_ff::PrimeField;
+
+/// Multiplies all given shares in a multiplication tree. The input must not be empty.
+fn product_tree<F: PrimeField, N: Rep3Network>(
+    mut values: Vec<FieldShare<F>>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<FieldShare<F>> {
+    debug_assert!(!values.is_empty());
+    while values.len() > 1 {
+        let leftover = if values.len() % 2 == 1 {
+            values.pop()
+        } else {
+            None
+        };
+        let (lhs, rhs) = values.split_at(values.len() / 2);
+        values = arithmetic::mul_vec(lhs, rhs, io_context)?;
+        values.extend(leftover);
+    }
+    Ok(values[0])
+}
+
+/// Computes `base^exponent` for a public base and a shared exponent. The exponent is decomposed into its bits b_i and the result is the product of the factors b_i ? base^(2^i) : 1, which are computed without communication since the powers of the base are public.
+pub fn pow_public_by_shared<F: PrimeField, N: Rep3Network>(
+    base: F,
+    exponent: FieldShare<F>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<FieldShare<F>> {
+    let bitlen = F::MODULUS_BIT_SIZE as usize;
+    let bits = yao::decompose_arithmetic(exponent, io_context, bitlen, 1)?;
+
+    let mut power = base;
+    let mut factors = Vec::with_capacity(bitlen);
+    for bit in bits {
+        // cmux(bit, power, 1) = 1 + bit * (power - 1)
+        let factor = arithmetic::mul_public(bit, power - F::one());
+        factors.push(arithmetic::add_public(factor, F::one(), io_context.id));
+        power.square_in_place();
+    }
+    product_tree(factors, io_context)
+}
+
+/// Computes `base^exponent` for a shared base and a shared exponent using square-and-multiply. The exponent is decomposed into its bits b_i, and the factors b_i ? base^(2^i) : 1 are selected with a batched cmux and multiplied in a multiplication tree.
+pub fn pow_by_shared<F: PrimeField, N: Rep3Network>(
+    base: FieldShare<F>,
+    exponent: FieldShare<F>,
+    io_context: &mut IoContext<N>,
+) -> IoResult<FieldShare<F>> {
+    let bitlen = F::MODULUS_BIT_SIZE as usize;
+    let bits = yao::decompose_arithmetic(exponent, io_context, bitlen, 1)?;
+
+    let mut powers = Vec::with_capacity(bitlen);
+    let mut power = base;
+    for _ in 0..bitlen {
+        powers.push(arithmetic::sub_shared_by_public(
+            power,
+            F::one(),
+            io_context.id,
+        ));
+        if powers.len() < bitlen {
+            power = arithmetic::mul(power, power, io_context)?;
+        }
+    }
+
+    // cmux(bit, power, 1) = 1 + bit * (power - 1)
+    let factors = arithmetic::mul_vec(&bits, &powers, io_context)?
+        .into_iter()
+        .map(|factor| arithmetic::add_public(factor, F::one(), io_context.id))
+        .collect();
+    product_tree(factors, io_context)
+}
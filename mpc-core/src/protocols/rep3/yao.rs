@@ -797,3 +797,228 @@ pub fn decompose_arithmetic_many<F: PrimeField, N: Rep3Network>(
         (decompose_bit_size, total_bit_size_per_field)
     )
 }
+
+/// Slices a shared field element with respect to the given public bases, i.e., x = s_0 + b_0 * (s_1 + b_1 * (...)). The slices are also represented as shared field elements. The input is assumed to have at most input_bit_size bits.
+pub fn slice_arithmetic_variable_bases<F: PrimeField, N: Rep3Network>(
+    input: Rep3PrimeFieldShare<F>,
+    io_context: &mut IoContext<N>,
+    input_bit_size: usize,
+    bases: &[u64],
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    slice_arithmetic_variable_bases_many(&[input], io_context, input_bit_size, bases)
+}
+
+/// Slices a vector of shared field elements with respect to the given public bases, i.e., x = s_0 + b_0 * (s_1 + b_1 * (...)). The slices are also represented as shared field elements. Each input is assumed to have at most input_bit_size bits.
+pub fn slice_arithmetic_variable_bases_many<F: PrimeField, N: Rep3Network>(
+    inputs: &[Rep3PrimeFieldShare<F>],
+    io_context: &mut IoContext<N>,
+    input_bit_size: usize,
+    bases: &[u64],
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    if input_bit_size > F::MODULUS_BIT_SIZE as usize {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Bit size is larger than field size",
+        ))?;
+    }
+    let total_output_elements = bases.len() * inputs.len();
+
+    decompose_circuit_compose_blueprint!(
+        inputs,
+        io_context,
+        total_output_elements,
+        GarbledCircuits::slice_field_element_variable_bases_many::<_, F>,
+        (input_bit_size, bases)
+    )
+}
+
+/// Computes the SHA256 compression function on a shared state of 8 words and a shared message block of 16 words, where each 32-bit word is represented as a shared field element. Returns the new state as 8 shared field elements.
+pub fn sha256_compression<F: PrimeField, N: Rep3Network>(
+    state: &[Rep3PrimeFieldShare<F>; 8],
+    message: &[Rep3PrimeFieldShare<F>; 16],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    let inputs = state
+        .iter()
+        .chain(message.iter())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    decompose_circuit_compose_blueprint!(
+        &inputs,
+        io_context,
+        8,
+        GarbledCircuits::sha256_compression::<_, F>,
+        ()
+    )
+}
+
+/// Computes the Keccak-f[1600] permutation on a shared state of 25 lanes, where each 64-bit lane is represented as a shared field element. Returns the new state as 25 shared field elements.
+pub fn keccakf1600<F: PrimeField, N: Rep3Network>(
+    state: &[Rep3PrimeFieldShare<F>; 25],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    decompose_circuit_compose_blueprint!(
+        state,
+        io_context,
+        25,
+        GarbledCircuits::keccakf1600::<_, F>,
+        ()
+    )
+}
+
+/// Computes the Blake2s compression function on a shared state of 8 words and a shared message block of 16 words, where each 32-bit word is represented as a shared field element. The counter (i.e., the number of bytes hashed so far including this block) and whether this is the last block are public. Returns the new state as 8 shared field elements.
+pub fn blake2s_compression<F: PrimeField, N: Rep3Network>(
+    state: &[Rep3PrimeFieldShare<F>; 8],
+    message: &[Rep3PrimeFieldShare<F>; 16],
+    counter: u64,
+    last_block: bool,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    let inputs = state
+        .iter()
+        .chain(message.iter())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    decompose_circuit_compose_blueprint!(
+        &inputs,
+        io_context,
+        8,
+        GarbledCircuits::blake2s_compression::<_, F>,
+        (counter, last_block)
+    )
+}
+
+/// Computes the Blake3 compression function on a shared chaining value of 8 words and a shared message block of 16 words, where each 32-bit word is represented as a shared field element. The counter, the number of bytes in the block and the flags are public. Returns the new chaining value as 8 shared field elements.
+pub fn blake3_compression<F: PrimeField, N: Rep3Network>(
+    chaining_value: &[Rep3PrimeFieldShare<F>; 8],
+    message: &[Rep3PrimeFieldShare<F>; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    let inputs = chaining_value
+        .iter()
+        .chain(message.iter())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    decompose_circuit_compose_blueprint!(
+        &inputs,
+        io_context,
+        8,
+        GarbledCircuits::blake3_compression::<_, F>,
+        (counter, block_len, flags)
+    )
+}
+
+/// Encrypts a shared plaintext under a shared key and a shared IV with AES-128 in CBC mode, where each byte is represented as a shared field element. The plaintext has to be padded already, i.e., its length is a multiple of 16. Returns the ciphertext as shared bytes.
+pub fn aes128_encrypt_cbc<F: PrimeField, N: Rep3Network>(
+    plaintext: &[Rep3PrimeFieldShare<F>],
+    iv: &[Rep3PrimeFieldShare<F>; 16],
+    key: &[Rep3PrimeFieldShare<F>; 16],
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    if plaintext.is_empty() || plaintext.len() % 16 != 0 {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "The plaintext length has to be a non-zero multiple of 16",
+        ))?;
+    }
+    let inputs = key
+        .iter()
+        .chain(iv.iter())
+        .chain(plaintext.iter())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    decompose_circuit_compose_blueprint!(
+        &inputs,
+        io_context,
+        plaintext.len(),
+        GarbledCircuits::aes128_encrypt_cbc::<_, F>,
+        ()
+    )
+}
+
+/// Computes (a * b + sum(to_add) - sum(to_sub)) / modulus over the integers, where all non-native values are given as shared little-endian limbs of limb_bits bits each. The limbs of the inputs may have up to input_limb_bits bits (i.e., they do not need to be normalized), to_add and to_sub contain the limbs of multiple values each, and the numerator is required to be non-negative. Returns the limbs of the quotient and the remainder, where both need to fit into the same number of limbs as a.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn non_native_mul_add_div_rem<F: PrimeField, N: Rep3Network>(
+    a: &[Rep3PrimeFieldShare<F>],
+    b: &[Rep3PrimeFieldShare<F>],
+    to_add: &[Rep3PrimeFieldShare<F>],
+    to_sub: &[Rep3PrimeFieldShare<F>],
+    modulus: &BigUint,
+    limb_bits: usize,
+    input_limb_bits: usize,
+    io_context: &mut IoContext<N>,
+) -> IoResult<(Vec<Rep3PrimeFieldShare<F>>, Vec<Rep3PrimeFieldShare<F>>)> {
+    let num_limbs = a.len();
+    if num_limbs == 0
+        || b.len() != num_limbs
+        || to_add.len() % num_limbs != 0
+        || to_sub.len() % num_limbs != 0
+    {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "All non-native values must have the same number of limbs",
+        ))?;
+    }
+    if input_limb_bits > F::MODULUS_BIT_SIZE as usize || limb_bits > input_limb_bits {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Limb size is larger than field size",
+        ))?;
+    }
+    let num_to_add = to_add.len() / num_limbs;
+    let inputs = a
+        .iter()
+        .chain(b.iter())
+        .chain(to_add.iter())
+        .chain(to_sub.iter())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut quotient: Vec<Rep3PrimeFieldShare<F>> = decompose_circuit_compose_blueprint!(
+        &inputs,
+        io_context,
+        2 * num_limbs,
+        GarbledCircuits::non_native_mul_add_div_rem::<_, F>,
+        (num_limbs, num_to_add, limb_bits, input_limb_bits, modulus)
+    )?;
+    let remainder = quotient.split_off(num_limbs);
+    Ok((quotient, remainder))
+}
+
+/// Computes the inverse of a non-native field element modulo the prime modulus, where the element is given as shared little-endian limbs of limb_bits bits each. The limbs of the input may have up to input_limb_bits bits. Zero is mapped to zero. Returns the limbs of the inverse.
+pub fn non_native_inverse<F: PrimeField, N: Rep3Network>(
+    a: &[Rep3PrimeFieldShare<F>],
+    modulus: &BigUint,
+    limb_bits: usize,
+    input_limb_bits: usize,
+    io_context: &mut IoContext<N>,
+) -> IoResult<Vec<Rep3PrimeFieldShare<F>>> {
+    let num_limbs = a.len();
+    if num_limbs == 0 {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Non-native value must have at least one limb",
+        ))?;
+    }
+    if input_limb_bits > F::MODULUS_BIT_SIZE as usize || limb_bits > input_limb_bits {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Limb size is larger than field size",
+        ))?;
+    }
+
+    decompose_circuit_compose_blueprint!(
+        a,
+        io_context,
+        num_limbs,
+        GarbledCircuits::non_native_inverse::<_, F>,
+        (num_limbs, limb_bits, input_limb_bits, modulus)
+    )
+}
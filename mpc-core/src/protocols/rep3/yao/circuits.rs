@@ -2,12 +2,61 @@
 //!
 //! This module contains some garbled circuit implementations.
 
+use crate::gadgets::aes::{AES128_BLOCK_BYTES, AES128_ROUNDS, AES128_ROUND_CONSTANTS};
+use crate::gadgets::blake::{
+    BLAKE2S_SIGMA, BLAKE3_MSG_PERMUTATION, BLAKE3_ROUNDS, BLAKE_G_INDICES, BLAKE_IV,
+};
 use crate::protocols::rep3::yao::GCUtils;
 use ark_ff::PrimeField;
 use fancy_garbling::{BinaryBundle, FancyBinary};
 use itertools::izip;
 use num_bigint::BigUint;
 
+/// The round constants of the SHA256 compression function.
+const SHA256_ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// The round constants of the Keccak-f[1600] permutation.
+const KECCAK_ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets of the rho step of the Keccak-f[1600] permutation, indexed by x + 5 * y.
+const KECCAK_ROTATIONS: [usize; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
 /// This struct contains some predefined garbled circuits.
 pub struct GarbledCircuits {}
 
@@ -127,7 +176,7 @@ impl GarbledCircuits {
 
     /// Binary subtraction. Returns the result and whether it underflowed.
     /// I.e., calculates 2^k + x1 - x2
-    #[allow(unused, clippy::type_complexity)]
+    #[allow(clippy::type_complexity)]
     fn bin_subtraction<G: FancyBinary>(
         g: &mut G,
         xs: &[G::Item],
@@ -394,6 +443,1047 @@ impl GarbledCircuits {
         Ok(BinaryBundle::new(results))
     }
 
+    /// Divides the value represented by the bits `dividend` by the public constant `divisor` using a restoring long division. Returns the quotient bits and the remainder bits, where the remainder has bitlen(divisor) bits.
+    fn div_by_public<G: FancyBinary>(
+        g: &mut G,
+        dividend: &[G::Item],
+        divisor: &BigUint,
+        zero: &G::Item,
+        one: &G::Item,
+    ) -> Result<(Vec<G::Item>, Vec<G::Item>), G::Error> {
+        debug_assert!(*divisor > BigUint::from(1u64));
+        let divisor_bitlen = divisor.bits() as usize;
+        let dividend_bitlen = dividend.len();
+
+        if dividend_bitlen < divisor_bitlen {
+            // The dividend is smaller than 2^(bitlen(divisor) - 1) <= divisor
+            let mut rem = dividend.to_vec();
+            rem.resize(divisor_bitlen, zero.to_owned());
+            return Ok((Vec::new(), rem));
+        }
+
+        // The upmost bitlen(divisor) - 1 bits are always smaller than the divisor
+        let mut rem = dividend[dividend_bitlen - divisor_bitlen + 1..].to_vec();
+        rem.push(zero.to_owned());
+
+        // We subtract by adding the two's complement of the divisor
+        let neg_divisor_bits = (0..=divisor_bitlen)
+            .map(|i| !divisor.bit(i as u64))
+            .collect::<Vec<_>>();
+
+        let mut quotient = vec![zero.to_owned(); dividend_bitlen - divisor_bitlen + 1];
+        for (i, quotient_bit) in quotient.iter_mut().enumerate().rev() {
+            let mut extended = Vec::with_capacity(divisor_bitlen + 1);
+            extended.push(dividend[i].to_owned());
+            extended.extend(rem);
+
+            let mut subtracted = Vec::with_capacity(divisor_bitlen + 1);
+            let mut c = one.to_owned();
+            for (a, b) in extended.iter().zip(neg_divisor_bits.iter()) {
+                let (s, c_) = Self::full_adder_const(g, a, *b, &c)?;
+                c = c_;
+                subtracted.push(s);
+            }
+
+            // The carry is set if extended >= divisor
+            rem = Vec::with_capacity(divisor_bitlen);
+            for (x, y) in extended.iter().zip(subtracted.iter()).take(divisor_bitlen) {
+                rem.push(Self::mux(g, &c, x, y)?);
+            }
+            *quotient_bit = c;
+        }
+
+        Ok((quotient, rem))
+    }
+
+    /// Divides the unsigned integer represented by the bits `dividend` by the unsigned integer represented by the bits `divisor` using a restoring long division. Returns the quotient bits and the remainder bits, both having bitlen(dividend) bits. If the divisor is zero, the quotient consists of all ones and the remainder equals the dividend.
+    fn bin_div_rem<G: FancyBinary>(
+        g: &mut G,
+        dividend: &[G::Item],
+        divisor: &[G::Item],
+        zero: &G::Item,
+    ) -> Result<(Vec<G::Item>, Vec<G::Item>), G::Error> {
+        debug_assert_eq!(dividend.len(), divisor.len());
+        let bitlen = dividend.len();
+
+        // The shifted remainder can have one bit more than the divisor
+        let mut divisor = divisor.to_vec();
+        divisor.push(zero.to_owned());
+
+        let mut rem = vec![zero.to_owned(); bitlen];
+        let mut quotient = vec![zero.to_owned(); bitlen];
+        for (i, quotient_bit) in quotient.iter_mut().enumerate().rev() {
+            let mut extended = Vec::with_capacity(bitlen + 1);
+            extended.push(dividend[i].to_owned());
+            extended.extend(rem);
+
+            // The carry is set if extended >= divisor
+            let (subtracted, c) = Self::bin_subtraction(g, &extended, &divisor)?;
+            rem = Vec::with_capacity(bitlen);
+            for (x, y) in extended.iter().zip(subtracted.iter()).take(bitlen) {
+                rem.push(Self::mux(g, &c, x, y)?);
+            }
+            *quotient_bit = c;
+        }
+
+        Ok((quotient, rem))
+    }
+
+    /// Computes the integer division with remainder of field elements (represented as two bitdecompositions wires_a, wires_b which need to be added first), where the field elements are interpreted as unsigned integers. The inputs are ordered as all dividends followed by all divisors. Finally, all quotients followed by all remainders are composed to shared field elements using wires_c.
+    pub(crate) fn int_div_rem_many<G: FancyBinary, F: PrimeField>(
+        g: &mut G,
+        wires_a: &BinaryBundle<G::Item>,
+        wires_b: &BinaryBundle<G::Item>,
+        wires_c: &BinaryBundle<G::Item>,
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        debug_assert_eq!(wires_a.size(), wires_b.size());
+        debug_assert_eq!(wires_a.size(), wires_c.size());
+        let input_size = wires_a.size();
+        let input_bitlen = F::MODULUS_BIT_SIZE as usize;
+        let num_inputs = input_size / input_bitlen;
+
+        debug_assert_eq!(input_size % input_bitlen, 0);
+        debug_assert_eq!(num_inputs % 2, 0);
+
+        let mut inputs = Vec::with_capacity(num_inputs);
+        for (chunk_a, chunk_b) in izip!(
+            wires_a.wires().chunks(input_bitlen),
+            wires_b.wires().chunks(input_bitlen),
+        ) {
+            let input_bits =
+                Self::adder_mod_p_with_output_size::<_, F>(g, chunk_a, chunk_b, input_bitlen)?;
+            inputs.push(input_bits);
+        }
+        let zero = g.xor(&inputs[0][0], &inputs[0][0])?;
+
+        let (dividends, divisors) = inputs.split_at(num_inputs / 2);
+        let mut quotients = Vec::with_capacity(dividends.len());
+        let mut remainders = Vec::with_capacity(dividends.len());
+        for (dividend, divisor) in izip!(dividends, divisors) {
+            let (quotient, remainder) = Self::bin_div_rem(g, dividend, divisor, &zero)?;
+            quotients.push(quotient);
+            remainders.push(remainder);
+        }
+
+        let mut results = Vec::with_capacity(input_size);
+        for (xs, ys) in izip!(
+            quotients.into_iter().chain(remainders),
+            wires_c.wires().chunks(input_bitlen),
+        ) {
+            let result = Self::compose_field_element::<_, F>(g, &xs, ys)?;
+            results.extend(result);
+        }
+
+        Ok(BinaryBundle::new(results))
+    }
+
+    /// Computes the integer division with remainder of field elements (represented as two bitdecompositions wires_a, wires_b which need to be added first) by the public divisor, where the field elements are interpreted as unsigned integers. The divisor has to be larger than one. Finally, all quotients followed by all remainders are composed to shared field elements using wires_c.
+    pub(crate) fn int_div_rem_by_public_many<G: FancyBinary, F: PrimeField>(
+        g: &mut G,
+        wires_a: &BinaryBundle<G::Item>,
+        wires_b: &BinaryBundle<G::Item>,
+        wires_c: &BinaryBundle<G::Item>,
+        divisor: &BigUint,
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        debug_assert_eq!(wires_a.size(), wires_b.size());
+        debug_assert_eq!(2 * wires_a.size(), wires_c.size());
+        let input_size = wires_a.size();
+        let input_bitlen = F::MODULUS_BIT_SIZE as usize;
+        let num_inputs = input_size / input_bitlen;
+
+        debug_assert_eq!(input_size % input_bitlen, 0);
+
+        let mut quotients = Vec::with_capacity(num_inputs);
+        let mut remainders = Vec::with_capacity(num_inputs);
+        for (chunk_a, chunk_b) in izip!(
+            wires_a.wires().chunks(input_bitlen),
+            wires_b.wires().chunks(input_bitlen),
+        ) {
+            let dividend =
+                Self::adder_mod_p_with_output_size::<_, F>(g, chunk_a, chunk_b, input_bitlen)?;
+            let zero = g.xor(&dividend[0], &dividend[0])?;
+            let one = g.negate(&zero)?;
+            let (mut quotient, remainder) =
+                Self::div_by_public(g, &dividend, divisor, &zero, &one)?;
+            if quotient.is_empty() {
+                quotient.push(zero);
+            }
+            quotients.push(quotient);
+            remainders.push(remainder);
+        }
+
+        let mut results = Vec::with_capacity(wires_c.size());
+        for (xs, ys) in izip!(
+            quotients.into_iter().chain(remainders),
+            wires_c.wires().chunks(input_bitlen),
+        ) {
+            let result = Self::compose_field_element::<_, F>(g, &xs, ys)?;
+            results.extend(result);
+        }
+
+        Ok(BinaryBundle::new(results))
+    }
+
+    /// Slices a field element (represented as two bitdecompositions wires_a, wires_b which need to be added first) with respect to the given bases, i.e., x = s_0 + b_0 * (s_1 + b_1 * (...)). The field element is assumed to have at most input_bitlen bits. For the bitcomposition of the slices, wires_c are used.
+    fn slice_field_element_variable_bases<G: FancyBinary, F: PrimeField>(
+        g: &mut G,
+        wires_a: &[G::Item],
+        wires_b: &[G::Item],
+        wires_c: &[G::Item],
+        input_bitlen: usize,
+        bases: &[u64],
+    ) -> Result<Vec<G::Item>, G::Error> {
+        debug_assert_eq!(wires_a.len(), wires_b.len());
+        debug_assert_eq!(wires_a.len(), F::MODULUS_BIT_SIZE as usize);
+        debug_assert!(input_bitlen <= F::MODULUS_BIT_SIZE as usize);
+        debug_assert_eq!(wires_c.len(), wires_a.len() * bases.len());
+
+        let mut rest =
+            Self::adder_mod_p_with_output_size::<_, F>(g, wires_a, wires_b, input_bitlen)?;
+        let zero = g.xor(&rest[0], &rest[0])?;
+        let one = g.negate(&zero)?;
+
+        let mut results = Vec::with_capacity(wires_c.len());
+        for (base, ys) in izip!(bases, wires_c.chunks(F::MODULUS_BIT_SIZE as usize)) {
+            let mut slice = if base.is_power_of_two() {
+                let bits = (base.trailing_zeros() as usize).min(rest.len());
+                let slice = rest[..bits].to_vec();
+                rest = rest[bits..].to_vec();
+                slice
+            } else {
+                let (quotient, rem) =
+                    Self::div_by_public(g, &rest, &BigUint::from(*base), &zero, &one)?;
+                rest = quotient;
+                rem
+            };
+            if slice.is_empty() {
+                slice.push(zero.to_owned());
+            }
+            let result = Self::compose_field_element::<_, F>(g, &slice, ys)?;
+            results.extend(result);
+        }
+
+        Ok(results)
+    }
+
+    /// Slices a vector of field elements (represented as two bitdecompositions wires_a, wires_b which need to be added first) with respect to the given bases. Each field element is assumed to have at most input_bitlen bits. For the bitcomposition of the slices, wires_c are used.
+    pub(crate) fn slice_field_element_variable_bases_many<G: FancyBinary, F: PrimeField>(
+        g: &mut G,
+        wires_a: &BinaryBundle<G::Item>,
+        wires_b: &BinaryBundle<G::Item>,
+        wires_c: &BinaryBundle<G::Item>,
+        input_bitlen: usize,
+        bases: &[u64],
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        debug_assert_eq!(wires_a.size(), wires_b.size());
+        let input_size = wires_a.size();
+        let field_bitlen = F::MODULUS_BIT_SIZE as usize;
+        debug_assert_eq!(input_size % field_bitlen, 0);
+        let num_inputs = input_size / field_bitlen;
+        debug_assert_eq!(wires_c.size(), field_bitlen * bases.len() * num_inputs);
+
+        let mut results = Vec::with_capacity(wires_c.size());
+
+        for (chunk_a, chunk_b, chunk_c) in izip!(
+            wires_a.wires().chunks(field_bitlen),
+            wires_b.wires().chunks(field_bitlen),
+            wires_c.wires().chunks(field_bitlen * bases.len())
+        ) {
+            let sliced = Self::slice_field_element_variable_bases::<_, F>(
+                g,
+                chunk_a,
+                chunk_b,
+                chunk_c,
+                input_bitlen,
+                bases,
+            )?;
+            results.extend(sliced);
+        }
+
+        Ok(BinaryBundle::new(results))
+    }
+
+    /// Adds two 32-bit words (little-endian bit order) modulo 2^32.
+    fn add_mod_2_32<G: FancyBinary>(
+        g: &mut G,
+        a: &[G::Item],
+        b: &[G::Item],
+    ) -> Result<Vec<G::Item>, G::Error> {
+        debug_assert_eq!(a.len(), 32);
+        debug_assert_eq!(b.len(), 32);
+        let mut result = Vec::with_capacity(32);
+
+        let (s, mut c) = Self::half_adder(g, &a[0], &b[0])?;
+        result.push(s);
+        for (x, y) in a.iter().zip(b.iter()).skip(1).take(30) {
+            let (s, c_) = Self::full_adder(g, x, y, &c)?;
+            c = c_;
+            result.push(s);
+        }
+        // The final carry is not required
+        let s = g.xor(&a[31], &b[31])?;
+        result.push(g.xor(&s, &c)?);
+
+        Ok(result)
+    }
+
+    /// Adds a public 32-bit word to a 32-bit word (little-endian bit order) modulo 2^32.
+    fn add_const_mod_2_32<G: FancyBinary>(
+        g: &mut G,
+        a: &[G::Item],
+        b: u32,
+        zero: &G::Item,
+    ) -> Result<Vec<G::Item>, G::Error> {
+        debug_assert_eq!(a.len(), 32);
+        let mut result = Vec::with_capacity(32);
+
+        let mut c = zero.to_owned();
+        for (i, x) in a.iter().enumerate() {
+            let (s, c_) = Self::full_adder_const(g, x, (b >> i) & 1 == 1, &c)?;
+            c = c_;
+            result.push(s);
+        }
+
+        Ok(result)
+    }
+
+    /// Rotates a 32-bit word (little-endian bit order) to the right. Does not require any gates.
+    fn rotr_32<G: FancyBinary>(a: &[G::Item], shift: usize) -> Vec<G::Item> {
+        (0..32).map(|i| a[(i + shift) % 32].to_owned()).collect()
+    }
+
+    /// Shifts a 32-bit word (little-endian bit order) to the right. Does not require any gates.
+    fn shr_32<G: FancyBinary>(a: &[G::Item], shift: usize, zero: &G::Item) -> Vec<G::Item> {
+        (0..32)
+            .map(|i| {
+                if i + shift < 32 {
+                    a[i + shift].to_owned()
+                } else {
+                    zero.to_owned()
+                }
+            })
+            .collect()
+    }
+
+    /// XORs three 32-bit words. Does not require any network interaction.
+    fn xor3_32<G: FancyBinary>(
+        g: &mut G,
+        a: &[G::Item],
+        b: &[G::Item],
+        c: &[G::Item],
+    ) -> Result<Vec<G::Item>, G::Error> {
+        izip!(a, b, c)
+            .map(|(a, b, c)| {
+                let x = g.xor(a, b)?;
+                g.xor(&x, c)
+            })
+            .collect()
+    }
+
+    /// The SHA256 compression function on 32-bit words in little-endian bit order, i.e., one block of the SHA256 hash function without the padding. Returns the new state.
+    fn sha256_compression_inner<G: FancyBinary>(
+        g: &mut G,
+        state: &[Vec<G::Item>],
+        message: &[Vec<G::Item>],
+        zero: &G::Item,
+    ) -> Result<Vec<Vec<G::Item>>, G::Error> {
+        debug_assert_eq!(state.len(), 8);
+        debug_assert_eq!(message.len(), 16);
+
+        // Extend the message schedule
+        let mut w = Vec::with_capacity(64);
+        w.extend(message.iter().cloned());
+        for i in 16..64 {
+            let w15 = &w[i - 15];
+            let s0 = Self::xor3_32(
+                g,
+                &Self::rotr_32::<G>(w15, 7),
+                &Self::rotr_32::<G>(w15, 18),
+                &Self::shr_32::<G>(w15, 3, zero),
+            )?;
+            let w2 = &w[i - 2];
+            let s1 = Self::xor3_32(
+                g,
+                &Self::rotr_32::<G>(w2, 17),
+                &Self::rotr_32::<G>(w2, 19),
+                &Self::shr_32::<G>(w2, 10, zero),
+            )?;
+            let tmp = Self::add_mod_2_32(g, &w[i - 16], &s0)?;
+            let tmp = Self::add_mod_2_32(g, &tmp, &w[i - 7])?;
+            let wi = Self::add_mod_2_32(g, &tmp, &s1)?;
+            w.push(wi);
+        }
+
+        let mut vars = state.to_vec();
+        for (wi, ki) in izip!(w.iter(), SHA256_ROUND_CONSTANTS) {
+            let (a, b, c, d) = (&vars[0], &vars[1], &vars[2], &vars[3]);
+            let (e, f, g_, h) = (&vars[4], &vars[5], &vars[6], &vars[7]);
+
+            let s1 = Self::xor3_32(
+                g,
+                &Self::rotr_32::<G>(e, 6),
+                &Self::rotr_32::<G>(e, 11),
+                &Self::rotr_32::<G>(e, 25),
+            )?;
+            // ch = (e & f) ^ (!e & g) = g ^ (e & (f ^ g))
+            let mut ch = Vec::with_capacity(32);
+            for (e, f, g_) in izip!(e, f, g_) {
+                let x = g.xor(f, g_)?;
+                let x = g.and(e, &x)?;
+                ch.push(g.xor(&x, g_)?);
+            }
+            let temp1 = Self::add_mod_2_32(g, h, &s1)?;
+            let temp1 = Self::add_mod_2_32(g, &temp1, &ch)?;
+            let wk = Self::add_const_mod_2_32(g, wi, ki, zero)?;
+            let temp1 = Self::add_mod_2_32(g, &temp1, &wk)?;
+
+            let s0 = Self::xor3_32(
+                g,
+                &Self::rotr_32::<G>(a, 2),
+                &Self::rotr_32::<G>(a, 13),
+                &Self::rotr_32::<G>(a, 22),
+            )?;
+            // maj = (a & b) ^ (a & c) ^ (b & c) = a ^ ((a ^ b) & (a ^ c))
+            let mut maj = Vec::with_capacity(32);
+            for (a, b, c) in izip!(a, b, c) {
+                let x = g.xor(a, b)?;
+                let y = g.xor(a, c)?;
+                let x = g.and(&x, &y)?;
+                maj.push(g.xor(&x, a)?);
+            }
+            let temp2 = Self::add_mod_2_32(g, &s0, &maj)?;
+
+            let new_e = Self::add_mod_2_32(g, d, &temp1)?;
+            let new_a = Self::add_mod_2_32(g, &temp1, &temp2)?;
+            // (a, b, c, d, e, f, g, h) <- (new_a, a, b, c, new_e, e, f, g)
+            vars.rotate_right(1);
+            vars[0] = new_a;
+            vars[4] = new_e;
+        }
+
+        let mut result = Vec::with_capacity(8);
+        for (s, x) in izip!(state, vars) {
+            result.push(Self::add_mod_2_32(g, s, &x)?);
+        }
+
+        Ok(result)
+    }
+
+    /// Computes the SHA256 compression function, where the inputs are a vector of 24 field elements (represented as two bitdecompositions wires_a, wires_b which need to be added first). The first 8 elements are the state and the remaining 16 elements are the message block, each being a 32-bit word. The 8 resulting words are composed to shared field elements using wires_c.
+    pub(crate) fn sha256_compression<G: FancyBinary, F: PrimeField>(
+        g: &mut G,
+        wires_a: &BinaryBundle<G::Item>,
+        wires_b: &BinaryBundle<G::Item>,
+        wires_c: &BinaryBundle<G::Item>,
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        let input_bitlen = F::MODULUS_BIT_SIZE as usize;
+        debug_assert_eq!(wires_a.size(), wires_b.size());
+        debug_assert_eq!(wires_a.size(), 24 * input_bitlen);
+        debug_assert_eq!(wires_c.size(), 8 * input_bitlen);
+
+        let mut words = Vec::with_capacity(24);
+        for (chunk_a, chunk_b) in izip!(
+            wires_a.wires().chunks(input_bitlen),
+            wires_b.wires().chunks(input_bitlen),
+        ) {
+            let word = Self::adder_mod_p_with_output_size::<_, F>(g, chunk_a, chunk_b, 32)?;
+            words.push(word);
+        }
+        let zero = g.xor(&words[0][0], &words[0][0])?;
+
+        let (state, message) = words.split_at(8);
+        let state = Self::sha256_compression_inner(g, state, message, &zero)?;
+
+        let mut results = Vec::with_capacity(wires_c.size());
+        for (xs, ys) in izip!(state, wires_c.wires().chunks(input_bitlen)) {
+            let result = Self::compose_field_element::<_, F>(g, &xs, ys)?;
+            results.extend(result);
+        }
+
+        Ok(BinaryBundle::new(results))
+    }
+
+    /// Rotates a 64-bit word (little-endian bit order) to the left. Does not require any gates.
+    fn rotl_64<G: FancyBinary>(a: &[G::Item], shift: usize) -> Vec<G::Item> {
+        (0..64)
+            .map(|i| a[(i + 64 - shift) % 64].to_owned())
+            .collect()
+    }
+
+    /// XORs three 64-bit words. Does not require any network interaction.
+    fn xor3_64<G: FancyBinary>(
+        g: &mut G,
+        a: &[G::Item],
+        b: &[G::Item],
+        c: &[G::Item],
+    ) -> Result<Vec<G::Item>, G::Error> {
+        izip!(a, b, c)
+            .map(|(a, b, c)| {
+                let x = g.xor(a, b)?;
+                g.xor(&x, c)
+            })
+            .collect()
+    }
+
+    /// The Keccak-f[1600] permutation on 25 lanes of 64 bits each in little-endian bit order. The lane at position (x, y) is stored at index x + 5 * y. Returns the new state.
+    fn keccakf1600_inner<G: FancyBinary>(
+        g: &mut G,
+        state: &[Vec<G::Item>],
+    ) -> Result<Vec<Vec<G::Item>>, G::Error> {
+        debug_assert_eq!(state.len(), 25);
+
+        let mut a = state.to_vec();
+        for rc in KECCAK_ROUND_CONSTANTS {
+            // theta
+            let mut c = Vec::with_capacity(5);
+            for x in 0..5 {
+                let tmp = Self::xor3_64(g, &a[x], &a[x + 5], &a[x + 10])?;
+                c.push(Self::xor3_64(g, &tmp, &a[x + 15], &a[x + 20])?);
+            }
+            for x in 0..5 {
+                let rot = Self::rotl_64::<G>(&c[(x + 1) % 5], 1);
+                let d = izip!(&c[(x + 4) % 5], &rot)
+                    .map(|(l, r)| g.xor(l, r))
+                    .collect::<Result<Vec<_>, _>>()?;
+                for y in 0..5 {
+                    for (lane, d) in izip!(a[x + 5 * y].iter_mut(), &d) {
+                        *lane = g.xor(lane, d)?;
+                    }
+                }
+            }
+
+            // rho and pi
+            let mut b = a.clone();
+            for x in 0..5 {
+                for y in 0..5 {
+                    b[y + 5 * ((2 * x + 3 * y) % 5)] =
+                        Self::rotl_64::<G>(&a[x + 5 * y], KECCAK_ROTATIONS[x + 5 * y]);
+                }
+            }
+
+            // chi: a = b ^ (!b1 & b2) = b ^ b2 ^ (b1 & b2)
+            for y in 0..5 {
+                for x in 0..5 {
+                    let b0 = &b[x + 5 * y];
+                    let b1 = &b[(x + 1) % 5 + 5 * y];
+                    let b2 = &b[(x + 2) % 5 + 5 * y];
+                    let mut lane = Vec::with_capacity(64);
+                    for (b0, b1, b2) in izip!(b0, b1, b2) {
+                        let and = g.and(b1, b2)?;
+                        let tmp = g.xor(&and, b2)?;
+                        lane.push(g.xor(&tmp, b0)?);
+                    }
+                    a[x + 5 * y] = lane;
+                }
+            }
+
+            // iota
+            for (i, bit) in a[0].iter_mut().enumerate() {
+                if (rc >> i) & 1 == 1 {
+                    *bit = g.negate(bit)?;
+                }
+            }
+        }
+
+        Ok(a)
+    }
+
+    /// Computes the Keccak-f[1600] permutation, where the inputs are a vector of 25 field elements (represented as two bitdecompositions wires_a, wires_b which need to be added first), each being a 64-bit lane. The 25 resulting lanes are composed to shared field elements using wires_c.
+    pub(crate) fn keccakf1600<G: FancyBinary, F: PrimeField>(
+        g: &mut G,
+        wires_a: &BinaryBundle<G::Item>,
+        wires_b: &BinaryBundle<G::Item>,
+        wires_c: &BinaryBundle<G::Item>,
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        let input_bitlen = F::MODULUS_BIT_SIZE as usize;
+        debug_assert_eq!(wires_a.size(), wires_b.size());
+        debug_assert_eq!(wires_a.size(), 25 * input_bitlen);
+        debug_assert_eq!(wires_c.size(), 25 * input_bitlen);
+
+        let mut lanes = Vec::with_capacity(25);
+        for (chunk_a, chunk_b) in izip!(
+            wires_a.wires().chunks(input_bitlen),
+            wires_b.wires().chunks(input_bitlen),
+        ) {
+            let lane = Self::adder_mod_p_with_output_size::<_, F>(g, chunk_a, chunk_b, 64)?;
+            lanes.push(lane);
+        }
+
+        let state = Self::keccakf1600_inner(g, &lanes)?;
+
+        let mut results = Vec::with_capacity(wires_c.size());
+        for (xs, ys) in izip!(state, wires_c.wires().chunks(input_bitlen)) {
+            let result = Self::compose_field_element::<_, F>(g, &xs, ys)?;
+            results.extend(result);
+        }
+
+        Ok(BinaryBundle::new(results))
+    }
+
+    /// XORs two 32-bit words. Does not require any network interaction.
+    fn xor_32<G: FancyBinary>(
+        g: &mut G,
+        a: &[G::Item],
+        b: &[G::Item],
+    ) -> Result<Vec<G::Item>, G::Error> {
+        izip!(a, b).map(|(a, b)| g.xor(a, b)).collect()
+    }
+
+    /// Translates a public 32-bit word into wires (little-endian bit order), given the wires of a zero and a one.
+    fn const_32<G: FancyBinary>(value: u32, zero: &G::Item, one: &G::Item) -> Vec<G::Item> {
+        (0..32)
+            .map(|i| {
+                if (value >> i) & 1 == 1 {
+                    one.to_owned()
+                } else {
+                    zero.to_owned()
+                }
+            })
+            .collect()
+    }
+
+    /// The mixing function G of Blake2s and Blake3 on the state words at the given positions, where x and y are the message words mixed into the state.
+    fn blake_g<G: FancyBinary>(
+        g: &mut G,
+        v: &mut [Vec<G::Item>],
+        [a, b, c, d]: [usize; 4],
+        x: &[G::Item],
+        y: &[G::Item],
+    ) -> Result<(), G::Error> {
+        let tmp = Self::add_mod_2_32(g, &v[a], &v[b])?;
+        v[a] = Self::add_mod_2_32(g, &tmp, x)?;
+        v[d] = Self::rotr_32::<G>(&Self::xor_32(g, &v[d], &v[a])?, 16);
+        v[c] = Self::add_mod_2_32(g, &v[c], &v[d])?;
+        v[b] = Self::rotr_32::<G>(&Self::xor_32(g, &v[b], &v[c])?, 12);
+        let tmp = Self::add_mod_2_32(g, &v[a], &v[b])?;
+        v[a] = Self::add_mod_2_32(g, &tmp, y)?;
+        v[d] = Self::rotr_32::<G>(&Self::xor_32(g, &v[d], &v[a])?, 8);
+        v[c] = Self::add_mod_2_32(g, &v[c], &v[d])?;
+        v[b] = Self::rotr_32::<G>(&Self::xor_32(g, &v[b], &v[c])?, 7);
+        Ok(())
+    }
+
+    /// One round of the Blake2s and Blake3 compression functions, where the i-th G function mixes in the message words at positions schedule[2i] and schedule[2i + 1].
+    fn blake_round<G: FancyBinary>(
+        g: &mut G,
+        v: &mut [Vec<G::Item>],
+        message: &[Vec<G::Item>],
+        schedule: &[usize; 16],
+    ) -> Result<(), G::Error> {
+        for (indices, words) in izip!(BLAKE_G_INDICES, schedule.chunks_exact(2)) {
+            Self::blake_g(g, v, indices, &message[words[0]], &message[words[1]])?;
+        }
+        Ok(())
+    }
+
+    /// The Blake2s compression function on 32-bit words in little-endian bit order. The counter and whether the block is the last one are public. Returns the new state.
+    fn blake2s_compression_inner<G: FancyBinary>(
+        g: &mut G,
+        state: &[Vec<G::Item>],
+        message: &[Vec<G::Item>],
+        counter: u64,
+        last_block: bool,
+        zero: &G::Item,
+    ) -> Result<Vec<Vec<G::Item>>, G::Error> {
+        debug_assert_eq!(state.len(), 8);
+        debug_assert_eq!(message.len(), 16);
+        let one = g.negate(zero)?;
+
+        let mut iv = BLAKE_IV;
+        iv[4] ^= counter as u32;
+        iv[5] ^= (counter >> 32) as u32;
+        if last_block {
+            iv[6] ^= u32::MAX;
+        }
+        let mut v = state.to_vec();
+        v.extend(iv.map(|word| Self::const_32::<G>(word, zero, &one)));
+
+        for schedule in BLAKE2S_SIGMA.iter() {
+            Self::blake_round(g, &mut v, message, schedule)?;
+        }
+
+        let mut result = Vec::with_capacity(8);
+        for (i, s) in state.iter().enumerate() {
+            result.push(Self::xor3_32(g, s, &v[i], &v[i + 8])?);
+        }
+        Ok(result)
+    }
+
+    /// The Blake3 compression function on 32-bit words in little-endian bit order. The counter, block length and flags are public. Returns the new chaining value.
+    fn blake3_compression_inner<G: FancyBinary>(
+        g: &mut G,
+        chaining_value: &[Vec<G::Item>],
+        message: &[Vec<G::Item>],
+        counter: u64,
+        block_len: u32,
+        flags: u32,
+        zero: &G::Item,
+    ) -> Result<Vec<Vec<G::Item>>, G::Error> {
+        debug_assert_eq!(chaining_value.len(), 8);
+        debug_assert_eq!(message.len(), 16);
+        let one = g.negate(zero)?;
+
+        let mut v = chaining_value.to_vec();
+        for word in BLAKE_IV[..4]
+            .iter()
+            .chain([counter as u32, (counter >> 32) as u32, block_len, flags].iter())
+        {
+            v.push(Self::const_32::<G>(*word, zero, &one));
+        }
+
+        let mut message = message.to_vec();
+        for _ in 0..BLAKE3_ROUNDS {
+            Self::blake_round(g, &mut v, &message, &BLAKE2S_SIGMA[0])?;
+            message = BLAKE3_MSG_PERMUTATION
+                .iter()
+                .map(|i| message[*i].to_owned())
+                .collect();
+        }
+
+        let (lo, hi) = v.split_at(8);
+        let mut result = Vec::with_capacity(8);
+        for (lo, hi) in izip!(lo, hi) {
+            result.push(Self::xor_32(g, lo, hi)?);
+        }
+        Ok(result)
+    }
+
+    /// Computes the Blake2s compression function, where the inputs are a vector of 24 field elements (represented as two bitdecompositions wires_a, wires_b which need to be added first). The first 8 elements are the state and the remaining 16 elements are the message block, each being a 32-bit word. The 8 resulting words are composed to shared field elements using wires_c.
+    pub(crate) fn blake2s_compression<G: FancyBinary, F: PrimeField>(
+        g: &mut G,
+        wires_a: &BinaryBundle<G::Item>,
+        wires_b: &BinaryBundle<G::Item>,
+        wires_c: &BinaryBundle<G::Item>,
+        counter: u64,
+        last_block: bool,
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        let input_bitlen = F::MODULUS_BIT_SIZE as usize;
+        debug_assert_eq!(wires_a.size(), wires_b.size());
+        debug_assert_eq!(wires_a.size(), 24 * input_bitlen);
+        debug_assert_eq!(wires_c.size(), 8 * input_bitlen);
+
+        let mut words = Vec::with_capacity(24);
+        for (chunk_a, chunk_b) in izip!(
+            wires_a.wires().chunks(input_bitlen),
+            wires_b.wires().chunks(input_bitlen),
+        ) {
+            let word = Self::adder_mod_p_with_output_size::<_, F>(g, chunk_a, chunk_b, 32)?;
+            words.push(word);
+        }
+        let zero = g.xor(&words[0][0], &words[0][0])?;
+
+        let (state, message) = words.split_at(8);
+        let state = Self::blake2s_compression_inner(g, state, message, counter, last_block, &zero)?;
+
+        let mut results = Vec::with_capacity(wires_c.size());
+        for (xs, ys) in izip!(state, wires_c.wires().chunks(input_bitlen)) {
+            let result = Self::compose_field_element::<_, F>(g, &xs, ys)?;
+            results.extend(result);
+        }
+
+        Ok(BinaryBundle::new(results))
+    }
+
+    /// Computes the Blake3 compression function, where the inputs are a vector of 24 field elements (represented as two bitdecompositions wires_a, wires_b which need to be added first). The first 8 elements are the chaining value and the remaining 16 elements are the message block, each being a 32-bit word. The 8 resulting words are composed to shared field elements using wires_c.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn blake3_compression<G: FancyBinary, F: PrimeField>(
+        g: &mut G,
+        wires_a: &BinaryBundle<G::Item>,
+        wires_b: &BinaryBundle<G::Item>,
+        wires_c: &BinaryBundle<G::Item>,
+        counter: u64,
+        block_len: u32,
+        flags: u32,
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        let input_bitlen = F::MODULUS_BIT_SIZE as usize;
+        debug_assert_eq!(wires_a.size(), wires_b.size());
+        debug_assert_eq!(wires_a.size(), 24 * input_bitlen);
+        debug_assert_eq!(wires_c.size(), 8 * input_bitlen);
+
+        let mut words = Vec::with_capacity(24);
+        for (chunk_a, chunk_b) in izip!(
+            wires_a.wires().chunks(input_bitlen),
+            wires_b.wires().chunks(input_bitlen),
+        ) {
+            let word = Self::adder_mod_p_with_output_size::<_, F>(g, chunk_a, chunk_b, 32)?;
+            words.push(word);
+        }
+        let zero = g.xor(&words[0][0], &words[0][0])?;
+
+        let (chaining_value, message) = words.split_at(8);
+        let chaining_value = Self::blake3_compression_inner(
+            g,
+            chaining_value,
+            message,
+            counter,
+            block_len,
+            flags,
+            &zero,
+        )?;
+
+        let mut results = Vec::with_capacity(wires_c.size());
+        for (xs, ys) in izip!(chaining_value, wires_c.wires().chunks(input_bitlen)) {
+            let result = Self::compose_field_element::<_, F>(g, &xs, ys)?;
+            results.extend(result);
+        }
+
+        Ok(BinaryBundle::new(results))
+    }
+
+    /// XORs two bytes (little-endian bit order). Does not require any network interaction.
+    fn xor_bytes<G: FancyBinary>(
+        g: &mut G,
+        a: &[G::Item],
+        b: &[G::Item],
+    ) -> Result<Vec<G::Item>, G::Error> {
+        izip!(a, b).map(|(a, b)| g.xor(a, b)).collect()
+    }
+
+    /// XORs a public byte to a byte (little-endian bit order). Does not require any network interaction.
+    fn xor_const_byte<G: FancyBinary>(
+        g: &mut G,
+        a: &[G::Item],
+        b: u8,
+    ) -> Result<Vec<G::Item>, G::Error> {
+        a.iter()
+            .enumerate()
+            .map(|(i, a)| {
+                if (b >> i) & 1 == 1 {
+                    g.negate(a)
+                } else {
+                    Ok(a.to_owned())
+                }
+            })
+            .collect()
+    }
+
+    /// Reduces a polynomial over GF(2) of degree at most 14 (little-endian coefficients) modulo the AES polynomial x^8 + x^4 + x^3 + x + 1. Does not require any network interaction.
+    fn gf_2_8_reduce<G: FancyBinary>(
+        g: &mut G,
+        mut product: Vec<G::Item>,
+    ) -> Result<Vec<G::Item>, G::Error> {
+        // x^k = x^(k-4) + x^(k-5) + x^(k-7) + x^(k-8) for k >= 8
+        for k in (8..product.len()).rev() {
+            for shift in [4, 5, 7, 8] {
+                product[k - shift] = g.xor(&product[k - shift], &product[k])?;
+            }
+        }
+        product.truncate(8);
+        Ok(product)
+    }
+
+    /// Multiplies two elements of GF(2^8) (little-endian bit order), requiring 64 AND gates.
+    fn gf_2_8_mul<G: FancyBinary>(
+        g: &mut G,
+        a: &[G::Item],
+        b: &[G::Item],
+    ) -> Result<Vec<G::Item>, G::Error> {
+        debug_assert_eq!(a.len(), 8);
+        debug_assert_eq!(b.len(), 8);
+        let mut product: Vec<Option<G::Item>> = vec![None; 15];
+        for (i, a) in a.iter().enumerate() {
+            for (j, b) in b.iter().enumerate() {
+                let and = g.and(a, b)?;
+                product[i + j] = Some(match &product[i + j] {
+                    Some(sum) => g.xor(sum, &and)?,
+                    None => and,
+                });
+            }
+        }
+        let product = product
+            .into_iter()
+            .map(|x| x.expect("All coefficients are set"))
+            .collect();
+        Self::gf_2_8_reduce(g, product)
+    }
+
+    /// Squares an element of GF(2^8) (little-endian bit order). Squaring is linear, hence this does not require any network interaction.
+    fn gf_2_8_square<G: FancyBinary>(
+        g: &mut G,
+        a: &[G::Item],
+        zero: &G::Item,
+    ) -> Result<Vec<G::Item>, G::Error> {
+        debug_assert_eq!(a.len(), 8);
+        let mut product = vec![zero.to_owned(); 15];
+        for (i, a) in a.iter().enumerate() {
+            product[2 * i] = a.to_owned();
+        }
+        Self::gf_2_8_reduce(g, product)
+    }
+
+    /// The AES S-box on a byte (little-endian bit order), i.e., the inverse in GF(2^8) (where 0 is mapped to 0) followed by the affine transformation. The inverse is computed as a^254 using 4 multiplications.
+    fn aes_sbox<G: FancyBinary>(
+        g: &mut G,
+        a: &[G::Item],
+        zero: &G::Item,
+    ) -> Result<Vec<G::Item>, G::Error> {
+        let a2 = Self::gf_2_8_square(g, a, zero)?;
+        let a3 = Self::gf_2_8_mul(g, &a2, a)?;
+        let a6 = Self::gf_2_8_square(g, &a3, zero)?;
+        let a12 = Self::gf_2_8_square(g, &a6, zero)?;
+        let a15 = Self::gf_2_8_mul(g, &a12, &a3)?;
+        let mut a240 = a15;
+        for _ in 0..4 {
+            a240 = Self::gf_2_8_square(g, &a240, zero)?;
+        }
+        let a252 = Self::gf_2_8_mul(g, &a240, &a12)?;
+        let inv = Self::gf_2_8_mul(g, &a252, &a2)?;
+
+        // b_i + b_{i+4} + b_{i+5} + b_{i+6} + b_{i+7} + c_i with c = 0x63
+        let mut result = Vec::with_capacity(8);
+        for (i, bit) in inv.iter().enumerate() {
+            let mut bit = bit.to_owned();
+            for shift in 4..8 {
+                bit = g.xor(&bit, &inv[(i + shift) % 8])?;
+            }
+            result.push(bit);
+        }
+        Self::xor_const_byte(g, &result, 0x63)
+    }
+
+    /// Multiplies an element of GF(2^8) (little-endian bit order) by x. Does not require any network interaction.
+    fn aes_xtime<G: FancyBinary>(g: &mut G, a: &[G::Item]) -> Result<Vec<G::Item>, G::Error> {
+        Ok(vec![
+            a[7].to_owned(),
+            g.xor(&a[0], &a[7])?,
+            a[1].to_owned(),
+            g.xor(&a[2], &a[7])?,
+            g.xor(&a[3], &a[7])?,
+            a[4].to_owned(),
+            a[5].to_owned(),
+            a[6].to_owned(),
+        ])
+    }
+
+    /// Expands the AES-128 key (16 bytes in little-endian bit order) into the 11 round keys of 16 bytes each.
+    fn aes128_expand_key<G: FancyBinary>(
+        g: &mut G,
+        key: &[Vec<G::Item>],
+        zero: &G::Item,
+    ) -> Result<Vec<Vec<Vec<G::Item>>>, G::Error> {
+        debug_assert_eq!(key.len(), AES128_BLOCK_BYTES);
+        let mut words = key.chunks_exact(4).map(|w| w.to_vec()).collect::<Vec<_>>();
+        for i in 4..4 * (AES128_ROUNDS + 1) {
+            let mut temp = words[i - 1].to_owned();
+            if i % 4 == 0 {
+                temp.rotate_left(1);
+                temp = temp
+                    .iter()
+                    .map(|byte| Self::aes_sbox(g, byte, zero))
+                    .collect::<Result<_, _>>()?;
+                temp[0] = Self::xor_const_byte(g, &temp[0], AES128_ROUND_CONSTANTS[i / 4 - 1])?;
+            }
+            let word = izip!(&words[i - 4], &temp)
+                .map(|(a, b)| Self::xor_bytes(g, a, b))
+                .collect::<Result<_, _>>()?;
+            words.push(word);
+        }
+        Ok(words
+            .chunks_exact(4)
+            .map(|round_key| round_key.concat())
+            .collect())
+    }
+
+    /// Encrypts a single AES block (16 bytes in little-endian bit order, stored column by column) with the given round keys.
+    fn aes128_encrypt_block<G: FancyBinary>(
+        g: &mut G,
+        round_keys: &[Vec<Vec<G::Item>>],
+        block: &[Vec<G::Item>],
+        zero: &G::Item,
+    ) -> Result<Vec<Vec<G::Item>>, G::Error> {
+        let mut state = izip!(block, &round_keys[0])
+            .map(|(a, b)| Self::xor_bytes(g, a, b))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (round, round_key) in round_keys.iter().enumerate().skip(1) {
+            // SubBytes and ShiftRows
+            let sboxed = state
+                .iter()
+                .map(|byte| Self::aes_sbox(g, byte, zero))
+                .collect::<Result<Vec<_>, _>>()?;
+            state = (0..AES128_BLOCK_BYTES)
+                .map(|i| {
+                    let (row, column) = (i % 4, i / 4);
+                    sboxed[row + 4 * ((column + row) % 4)].to_owned()
+                })
+                .collect();
+
+            // MixColumns
+            if round != AES128_ROUNDS {
+                let mut mixed = Vec::with_capacity(AES128_BLOCK_BYTES);
+                for column in state.chunks_exact(4) {
+                    let doubled = column
+                        .iter()
+                        .map(|byte| Self::aes_xtime(g, byte))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    for (row, double) in doubled.iter().enumerate() {
+                        // 2 * a_r + 3 * a_{r+1} + a_{r+2} + a_{r+3}
+                        let mut byte = Self::xor_bytes(g, double, &doubled[(row + 1) % 4])?;
+                        for other in 1..4 {
+                            byte = Self::xor_bytes(g, &byte, &column[(row + other) % 4])?;
+                        }
+                        mixed.push(byte);
+                    }
+                }
+                state = mixed;
+            }
+
+            // AddRoundKey
+            state = izip!(&state, round_key)
+                .map(|(a, b)| Self::xor_bytes(g, a, b))
+                .collect::<Result<_, _>>()?;
+        }
+        Ok(state)
+    }
+
+    /// Encrypts a padded buffer with AES-128 in CBC mode, where the inputs are a vector of field elements (represented as two bitdecompositions wires_a, wires_b which need to be added first), each being a byte. The first 16 elements are the key, the next 16 elements are the IV, and the remaining elements are the plaintext, whose length is a multiple of 16. The resulting ciphertext bytes are composed to shared field elements using wires_c.
+    pub(crate) fn aes128_encrypt_cbc<G: FancyBinary, F: PrimeField>(
+        g: &mut G,
+        wires_a: &BinaryBundle<G::Item>,
+        wires_b: &BinaryBundle<G::Item>,
+        wires_c: &BinaryBundle<G::Item>,
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        let input_bitlen = F::MODULUS_BIT_SIZE as usize;
+        debug_assert_eq!(wires_a.size(), wires_b.size());
+        debug_assert_eq!(wires_a.size() % (AES128_BLOCK_BYTES * input_bitlen), 0);
+        debug_assert!(wires_a.size() >= 3 * AES128_BLOCK_BYTES * input_bitlen);
+        debug_assert_eq!(
+            wires_c.size(),
+            wires_a.size() - 2 * AES128_BLOCK_BYTES * input_bitlen
+        );
+
+        let mut bytes = Vec::with_capacity(wires_a.size() / input_bitlen);
+        for (chunk_a, chunk_b) in izip!(
+            wires_a.wires().chunks(input_bitlen),
+            wires_b.wires().chunks(input_bitlen),
+        ) {
+            let byte = Self::adder_mod_p_with_output_size::<_, F>(g, chunk_a, chunk_b, 8)?;
+            bytes.push(byte);
+        }
+        let zero = g.xor(&bytes[0][0], &bytes[0][0])?;
+
+        let (key, rest) = bytes.split_at(AES128_BLOCK_BYTES);
+        let (iv, plaintext) = rest.split_at(AES128_BLOCK_BYTES);
+        let round_keys = Self::aes128_expand_key(g, key, &zero)?;
+
+        let mut ciphertext = Vec::with_capacity(plaintext.len());
+        let mut previous = iv.to_vec();
+        for block in plaintext.chunks_exact(AES128_BLOCK_BYTES) {
+            let block = izip!(block, &previous)
+                .map(|(a, b)| Self::xor_bytes(g, a, b))
+                .collect::<Result<Vec<_>, _>>()?;
+            previous = Self::aes128_encrypt_block(g, &round_keys, &block, &zero)?;
+            ciphertext.extend(previous.iter().cloned());
+        }
+
+        let mut results = Vec::with_capacity(wires_c.size());
+        for (xs, ys) in izip!(ciphertext, wires_c.wires().chunks(input_bitlen)) {
+            let result = Self::compose_field_element::<_, F>(g, &xs, ys)?;
+            results.extend(result);
+        }
+
+        Ok(BinaryBundle::new(results))
+    }
+
     fn unsigned_ge<G: FancyBinary>(
         g: &mut G,
         a: &[G::Item],
@@ -527,6 +1617,236 @@ impl GarbledCircuits {
 
         Ok(BinaryBundle::new(results))
     }
+
+    /// Adds the bits xs, shifted to the left by shift positions, to the bits of acc in place. An overflow beyond the length of acc is discarded.
+    fn add_shifted_assign<G: FancyBinary>(
+        g: &mut G,
+        acc: &mut [G::Item],
+        xs: &[G::Item],
+        shift: usize,
+    ) -> Result<(), G::Error> {
+        debug_assert!(acc.len() >= shift + xs.len());
+        if xs.is_empty() {
+            return Ok(());
+        }
+
+        let (s, mut c) = Self::half_adder(g, &acc[shift], &xs[0])?;
+        acc[shift] = s;
+        for (a, x) in acc[shift + 1..shift + xs.len()]
+            .iter_mut()
+            .zip(xs.iter().skip(1))
+        {
+            let (s, c_) = Self::full_adder(g, a, x, &c)?;
+            *a = s;
+            c = c_;
+        }
+        for a in acc[shift + xs.len()..].iter_mut() {
+            let (s, c_) = Self::half_adder(g, a, &c)?;
+            *a = s;
+            c = c_;
+        }
+        Ok(())
+    }
+
+    /// Multiplies two unsigned integers given as bits (little-endian bit order). The result has bitlen(xs) + bitlen(ys) bits.
+    fn bin_mul<G: FancyBinary>(
+        g: &mut G,
+        xs: &[G::Item],
+        ys: &[G::Item],
+        zero: &G::Item,
+    ) -> Result<Vec<G::Item>, G::Error> {
+        let mut result = vec![zero.to_owned(); xs.len() + ys.len()];
+        for (i, y) in ys.iter().enumerate() {
+            let partial = xs
+                .iter()
+                .map(|x| g.and(x, y))
+                .collect::<Result<Vec<_>, _>>()?;
+            // The intermediate result is smaller than 2^(bitlen(xs) + i + 1)
+            Self::add_shifted_assign(g, &mut result[..xs.len() + i + 1], &partial, i)?;
+        }
+        Ok(result)
+    }
+
+    /// Multiplies two unsigned integers given as bits (little-endian bit order) modulo the public modulus. The result has bitlen(modulus) bits.
+    fn mul_mod_public<G: FancyBinary>(
+        g: &mut G,
+        xs: &[G::Item],
+        ys: &[G::Item],
+        modulus: &BigUint,
+        zero: &G::Item,
+        one: &G::Item,
+    ) -> Result<Vec<G::Item>, G::Error> {
+        let product = Self::bin_mul(g, xs, ys, zero)?;
+        let (_, remainder) = Self::div_by_public(g, &product, modulus, zero, one)?;
+        Ok(remainder)
+    }
+
+    /// Translates the limbs of non-native field elements into the bits of the values. The limbs are field elements (represented as two bitdecompositions wires_a, wires_b which need to be added first) with at most input_limb_bits bits, and each non-native value consists of num_limbs limbs of limb_bits bits. The limbs of a value may be larger than limb_bits bits, i.e., the values do not need to be normalized.
+    fn non_native_limbs_to_values<G: FancyBinary, F: PrimeField>(
+        g: &mut G,
+        wires_a: &BinaryBundle<G::Item>,
+        wires_b: &BinaryBundle<G::Item>,
+        num_limbs: usize,
+        limb_bits: usize,
+        input_limb_bits: usize,
+    ) -> Result<Vec<Vec<G::Item>>, G::Error> {
+        let input_bitlen = F::MODULUS_BIT_SIZE as usize;
+        debug_assert_eq!(wires_a.size(), wires_b.size());
+        debug_assert_eq!(wires_a.size() % (num_limbs * input_bitlen), 0);
+        debug_assert!(input_limb_bits <= input_bitlen);
+
+        let mut limbs = Vec::with_capacity(wires_a.size() / input_bitlen);
+        for (chunk_a, chunk_b) in izip!(
+            wires_a.wires().chunks(input_bitlen),
+            wires_b.wires().chunks(input_bitlen),
+        ) {
+            let limb =
+                Self::adder_mod_p_with_output_size::<_, F>(g, chunk_a, chunk_b, input_limb_bits)?;
+            limbs.push(limb);
+        }
+        let zero = g.xor(&limbs[0][0], &limbs[0][0])?;
+
+        // The sum of all limbs is smaller than 2^(input_limb_bits + (num_limbs - 1) * limb_bits + 1)
+        let value_bitlen = input_limb_bits + (num_limbs - 1) * limb_bits + 1;
+        let mut values = Vec::with_capacity(limbs.len() / num_limbs);
+        for value_limbs in limbs.chunks(num_limbs) {
+            let mut value = vec![zero.to_owned(); value_bitlen];
+            for (i, limb) in value_limbs.iter().enumerate() {
+                Self::add_shifted_assign(g, &mut value, limb, i * limb_bits)?;
+            }
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    /// Splits the given values (given as bits) into num_limbs limbs of limb_bits bits each and composes them to shared field elements using wires_c. The values must be smaller than 2^(num_limbs * limb_bits).
+    fn non_native_values_to_limbs<G: FancyBinary, F: PrimeField>(
+        g: &mut G,
+        values: &[Vec<G::Item>],
+        wires_c: &BinaryBundle<G::Item>,
+        num_limbs: usize,
+        limb_bits: usize,
+        zero: &G::Item,
+    ) -> Result<Vec<G::Item>, G::Error> {
+        let input_bitlen = F::MODULUS_BIT_SIZE as usize;
+        debug_assert!(limb_bits <= input_bitlen);
+        debug_assert_eq!(wires_c.size(), values.len() * num_limbs * input_bitlen);
+
+        let mut results = Vec::with_capacity(wires_c.size());
+        let mut rand_wires = wires_c.wires().chunks(input_bitlen);
+        for value in values {
+            let mut limbs = value.chunks(limb_bits);
+            for _ in 0..num_limbs {
+                let limb = match limbs.next() {
+                    Some(limb) => limb.to_vec(),
+                    None => vec![zero.to_owned()],
+                };
+                let ys = rand_wires.next().expect("Enough wires_c provided");
+                let result = Self::compose_field_element::<_, F>(g, &limb, ys)?;
+                results.extend(result);
+            }
+        }
+        Ok(results)
+    }
+
+    /// Computes (a * b + sum(to_add) - sum(to_sub)) / modulus over the integers and returns the quotient and the remainder. The inputs are num_limbs limbs of limb_bits bits each per non-native value, where the limbs are field elements (represented as two bitdecompositions wires_a, wires_b which need to be added first) with at most input_limb_bits bits. The values are ordered as a, b, the num_to_add values of to_add, and the remaining values of to_sub. The numerator is required to be non-negative, and both the quotient and the remainder are required to fit into num_limbs limbs. The limbs of the quotient and the remainder are composed to shared field elements using wires_c.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn non_native_mul_add_div_rem<G: FancyBinary, F: PrimeField>(
+        g: &mut G,
+        wires_a: &BinaryBundle<G::Item>,
+        wires_b: &BinaryBundle<G::Item>,
+        wires_c: &BinaryBundle<G::Item>,
+        num_limbs: usize,
+        num_to_add: usize,
+        limb_bits: usize,
+        input_limb_bits: usize,
+        modulus: &BigUint,
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        let values = Self::non_native_limbs_to_values::<_, F>(
+            g,
+            wires_a,
+            wires_b,
+            num_limbs,
+            limb_bits,
+            input_limb_bits,
+        )?;
+        debug_assert!(values.len() >= 2 + num_to_add);
+        let zero = g.xor(&values[0][0], &values[0][0])?;
+        let one = g.negate(&zero)?;
+
+        let product = Self::bin_mul(g, &values[0], &values[1], &zero)?;
+        let extra_bits = (usize::BITS - num_to_add.leading_zeros()) as usize;
+        let mut numerator = product;
+        numerator.resize(numerator.len() + extra_bits, zero.to_owned());
+        for value in values[2..2 + num_to_add].iter() {
+            Self::add_shifted_assign(g, &mut numerator, value, 0)?;
+        }
+        for value in values[2 + num_to_add..].iter() {
+            let mut value = value.to_owned();
+            value.resize(numerator.len(), zero.to_owned());
+            // The numerator is non-negative, so we can ignore the underflow bit
+            let (difference, _) = Self::bin_subtraction(g, &numerator, &value)?;
+            numerator = difference;
+        }
+
+        let (quotient, remainder) = Self::div_by_public(g, &numerator, modulus, &zero, &one)?;
+        let results = Self::non_native_values_to_limbs::<_, F>(
+            g,
+            &[quotient, remainder],
+            wires_c,
+            num_limbs,
+            limb_bits,
+            &zero,
+        )?;
+        Ok(BinaryBundle::new(results))
+    }
+
+    /// Computes the inverse of a non-native field element modulo the public prime modulus using Fermat's little theorem, where zero is mapped to zero. The input consists of num_limbs limbs of limb_bits bits each, where the limbs are field elements (represented as two bitdecompositions wires_a, wires_b which need to be added first) with at most input_limb_bits bits. The num_limbs limbs of the result are composed to shared field elements using wires_c.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn non_native_inverse<G: FancyBinary, F: PrimeField>(
+        g: &mut G,
+        wires_a: &BinaryBundle<G::Item>,
+        wires_b: &BinaryBundle<G::Item>,
+        wires_c: &BinaryBundle<G::Item>,
+        num_limbs: usize,
+        limb_bits: usize,
+        input_limb_bits: usize,
+        modulus: &BigUint,
+    ) -> Result<BinaryBundle<G::Item>, G::Error> {
+        let values = Self::non_native_limbs_to_values::<_, F>(
+            g,
+            wires_a,
+            wires_b,
+            num_limbs,
+            limb_bits,
+            input_limb_bits,
+        )?;
+        debug_assert_eq!(values.len(), 1);
+        let zero = g.xor(&values[0][0], &values[0][0])?;
+        let one = g.negate(&zero)?;
+
+        let (_, base) = Self::div_by_public(g, &values[0], modulus, &zero, &one)?;
+
+        // Square and multiply with the public exponent modulus - 2
+        let exponent = modulus - 2u64;
+        let mut result = base.to_owned();
+        for i in (0..exponent.bits() - 1).rev() {
+            result = Self::mul_mod_public(g, &result, &result, modulus, &zero, &one)?;
+            if exponent.bit(i) {
+                result = Self::mul_mod_public(g, &result, &base, modulus, &zero, &one)?;
+            }
+        }
+
+        let results = Self::non_native_values_to_limbs::<_, F>(
+            g,
+            &[result],
+            wires_c,
+            num_limbs,
+            limb_bits,
+            &zero,
+        )?;
+        Ok(BinaryBundle::new(results))
+    }
 }
 
 #[cfg(test)]
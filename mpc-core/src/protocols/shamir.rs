@@ -15,6 +15,7 @@ use crate::RngType;
 
 pub mod arithmetic;
 pub mod core;
+pub mod lut;
 pub mod network;
 pub mod pointshare;
 pub mod poly;
@@ -0,0 +1,223 @@
+//! Lookup Table
+//!
+//! This module contains implementation of a LUT
+
+use ark_ff::PrimeField;
+
+use crate::lut::LookupTableProvider;
+
+use super::{arithmetic, network::ShamirNetwork, IoResult, ShamirPrimeFieldShare, ShamirProtocol};
+
+type ShamirShare<F> = ShamirPrimeFieldShare<F>;
+
+/// A map of the [`ShamirLookupTable`]. The keys are public and pairwise distinct, which allows to translate a shared needle into a one-hot vector by evaluating the Lagrange basis polynomials over the keys.
+#[derive(Clone, Debug, Default)]
+pub struct ShamirLutMap<F: PrimeField> {
+    keys: Vec<F>,
+    values: Vec<ShamirShare<F>>,
+}
+
+impl<F: PrimeField> ShamirLutMap<F> {
+    /// Construct a new [`ShamirLutMap`] from the public keys and the shared values.
+    pub fn new(keys: Vec<F>, values: Vec<ShamirShare<F>>) -> Self {
+        assert_eq!(keys.len(), values.len());
+        Self { keys, values }
+    }
+
+    /// Returns the public keys of the map.
+    pub fn keys(&self) -> &[F] {
+        &self.keys
+    }
+
+    /// Returns the shared values of the map.
+    pub fn values(&self) -> &[ShamirShare<F>] {
+        &self.values
+    }
+
+    /// Returns the shared values of the map as mutable slice.
+    pub fn values_mut(&mut self) -> &mut [ShamirShare<F>] {
+        &mut self.values
+    }
+
+    /// Returns the position of a public key in the map.
+    pub fn position(&self, key: F) -> Option<usize> {
+        self.keys.iter().position(|k| *k == key)
+    }
+}
+
+/// Returns the coefficients of the Lagrange basis polynomials over the given points, i.e., the j-th polynomial evaluates to 1 at points\[j\] and to 0 at all other points. The coefficients are ordered from the constant term to the highest degree.
+fn lagrange_basis<F: PrimeField>(points: &[F]) -> Vec<Vec<F>> {
+    // The polynomial vanishing on all points
+    let mut vanishing = vec![F::one()];
+    for point in points {
+        vanishing.insert(0, F::zero());
+        for i in 0..vanishing.len() - 1 {
+            let next = vanishing[i + 1];
+            vanishing[i] -= next * point;
+        }
+    }
+
+    // Divide the vanishing polynomial by (x - points[j]) and normalize the result to 1 at points[j]
+    let mut basis = Vec::with_capacity(points.len());
+    for point in points {
+        let mut quotient = vec![F::zero(); points.len()];
+        let mut carry = F::zero();
+        for i in (0..points.len()).rev() {
+            carry = vanishing[i + 1] + carry * point;
+            quotient[i] = carry;
+        }
+        let denominator = super::core::evaluate_poly(&quotient, *point);
+        let denominator_inv = denominator
+            .inverse()
+            .expect("the points are pairwise distinct");
+        for coeff in quotient.iter_mut() {
+            *coeff *= denominator_inv;
+        }
+        basis.push(quotient);
+    }
+    basis
+}
+
+/// Translates a shared needle into a shared one-hot vector over the public keys, i.e., the resulting vector is 1 at the position of the key equal to the needle and 0 everywhere else. The keys have to be pairwise distinct and the needle has to be one of the keys, otherwise the result is undefined.
+///
+/// The one-hot vector is computed by evaluating the Lagrange basis polynomials over the keys at the needle. This requires the powers of the needle up to keys.len() - 1, which are computed with keys.len() - 2 multiplications in a logarithmic number of rounds.
+pub fn one_hot_vector<F: PrimeField, N: ShamirNetwork>(
+    needle: ShamirShare<F>,
+    keys: &[F],
+    shamir: &mut ShamirProtocol<F, N>,
+) -> IoResult<Vec<ShamirShare<F>>> {
+    let len = keys.len();
+    if len == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut powers = vec![arithmetic::promote_to_trivial_share(F::one())];
+    if len > 1 {
+        powers.push(needle);
+    }
+    // With x^0, ..., x^(m-1) we get x^m, ..., x^(2m-2) by multiplying with x^(m-1)
+    while powers.len() < len {
+        let m = powers.len();
+        let count = (len - m).min(m - 1);
+        let highest = vec![powers[m - 1]; count];
+        let next = arithmetic::mul_vec(&powers[1..=count], &highest, shamir)?;
+        powers.extend(next);
+    }
+
+    let result = lagrange_basis(keys)
+        .into_iter()
+        .map(|coeffs| {
+            coeffs
+                .into_iter()
+                .zip(powers.iter())
+                .fold(ShamirShare::zero_share(), |acc, (coeff, power)| {
+                    arithmetic::add(acc, arithmetic::mul_public(*power, coeff))
+                })
+        })
+        .collect();
+    Ok(result)
+}
+
+/// Reads the value associated with the shared needle from the map. The needle has to be one of the keys of the map, otherwise the result is undefined.
+pub fn read_from_lut<F: PrimeField, N: ShamirNetwork>(
+    needle: ShamirShare<F>,
+    map: &ShamirLutMap<F>,
+    shamir: &mut ShamirProtocol<F, N>,
+) -> IoResult<ShamirShare<F>> {
+    tracing::debug!("doing read on LUT-map of size {}", map.keys.len());
+    let one_hot = one_hot_vector(needle, &map.keys, shamir)?;
+    // The inner product only requires a single degree reduction
+    let inner_product = arithmetic::local_mul_vec(&one_hot, &map.values)
+        .into_iter()
+        .sum();
+    shamir.degree_reduce(inner_product)
+}
+
+/// Writes the value to the position of the shared needle in the map. The needle has to be one of the keys of the map, otherwise the map gets corrupted.
+pub fn write_to_lut<F: PrimeField, N: ShamirNetwork>(
+    needle: ShamirShare<F>,
+    value: ShamirShare<F>,
+    map: &mut ShamirLutMap<F>,
+    shamir: &mut ShamirProtocol<F, N>,
+) -> IoResult<()> {
+    tracing::debug!("doing write on LUT-map of size {}", map.keys.len());
+    let one_hot = one_hot_vector(needle, &map.keys, shamir)?;
+    // values[i] += one_hot[i] * (value - values[i])
+    let differences = map
+        .values
+        .iter()
+        .map(|old| arithmetic::sub(value, *old))
+        .collect::<Vec<_>>();
+    let updates = arithmetic::mul_vec(&one_hot, &differences, shamir)?;
+    arithmetic::add_vec_assign(&mut map.values, &updates);
+    Ok(())
+}
+
+/// Shamir lookup table
+///
+/// In contrast to the REP3 lookup table, the keys of the maps have to be public. They are passed as trivial shares (see [`arithmetic::promote_to_trivial_share`]) to [`LookupTableProvider::init_map`]. Membership checks on sets are not supported.
+pub struct ShamirLookupTable<F: PrimeField, N: ShamirNetwork> {
+    shamir: ShamirProtocol<F, N>,
+}
+
+impl<F: PrimeField, N: ShamirNetwork> ShamirLookupTable<F, N> {
+    /// Construct a new [`ShamirLookupTable`]
+    pub fn new(shamir: ShamirProtocol<F, N>) -> Self {
+        Self { shamir }
+    }
+}
+
+impl<F: PrimeField, N: ShamirNetwork> LookupTableProvider<F> for ShamirLookupTable<F, N> {
+    type SecretShare = ShamirShare<F>;
+    type SecretSharedSet = Vec<ShamirShare<F>>;
+
+    type SecretSharedMap = ShamirLutMap<F>;
+
+    fn init_set(
+        &self,
+        values: impl IntoIterator<Item = Self::SecretShare>,
+    ) -> Self::SecretSharedSet {
+        tracing::debug!("initiating LUT-set");
+        values.into_iter().collect()
+    }
+
+    fn contains_set(
+        &mut self,
+        _needle: &Self::SecretShare,
+        _set: &Self::SecretSharedSet,
+    ) -> IoResult<Self::SecretShare> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "contains_set: Operation atm not supported for Shamir shares",
+        ))
+    }
+
+    fn init_map(
+        &self,
+        values: impl IntoIterator<Item = (Self::SecretShare, Self::SecretShare)>,
+    ) -> Self::SecretSharedMap {
+        tracing::debug!("initiating LUT-map");
+        let (keys, values): (Vec<_>, Vec<_>) = values
+            .into_iter()
+            .map(|(key, value)| (key.inner(), value))
+            .unzip();
+        ShamirLutMap::new(keys, values)
+    }
+
+    fn get_from_lut(
+        &mut self,
+        needle: Self::SecretShare,
+        map: &Self::SecretSharedMap,
+    ) -> IoResult<Self::SecretShare> {
+        read_from_lut(needle, map, &mut self.shamir)
+    }
+
+    fn write_to_lut(
+        &mut self,
+        needle: Self::SecretShare,
+        value: Self::SecretShare,
+        map: &mut Self::SecretSharedMap,
+    ) -> IoResult<()> {
+        write_to_lut(needle, value, map, &mut self.shamir)
+    }
+}
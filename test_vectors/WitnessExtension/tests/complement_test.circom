@@ -0,0 +1,16 @@
+pragma circom 2.0.0;
+
+template Complement() {
+    signal input in[2];
+    signal output complement;
+    signal output not_lt;
+    signal output not_eq;
+    signal output not_and;
+
+    complement <-- ~in[0];
+    not_lt <-- !(in[0] < in[1]);
+    not_eq <-- !(in[0] == in[1]);
+    not_and <-- (~in[0]) & in[1];
+}
+
+component main = Complement();
@@ -0,0 +1,19 @@
+pragma circom 2.0.0;
+
+template IntDiv() {
+    signal input in[2];
+    signal output quotient;
+    signal output remainder;
+    signal output quotient_public;
+    signal output remainder_public;
+
+    quotient <-- in[0] \ in[1];
+    remainder <-- in[0] % in[1];
+    in[0] === quotient * in[1] + remainder;
+
+    quotient_public <-- in[0] \ 7;
+    remainder_public <-- in[0] % 7;
+    in[0] === quotient_public * 7 + remainder_public;
+}
+
+component main = IntDiv();
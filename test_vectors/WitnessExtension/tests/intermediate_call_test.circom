@@ -0,0 +1,44 @@
+pragma circom 2.0.0;
+
+function square(x) {
+    return x * x;
+}
+
+function max(x, y) {
+    if (x > y) {
+        return x;
+    }
+    return y;
+}
+
+function pair(x) {
+    var result[2];
+    result[0] = x + 1;
+    result[1] = x * 2;
+    return result;
+}
+
+template Sum() {
+    signal input in[2];
+    signal output out;
+
+    out <-- in[0] + in[1];
+}
+
+template IntermediateCall() {
+    signal input in[2];
+    signal output squares;
+    signal output max_plus_one;
+    signal output pair_out[2];
+    signal output sum;
+
+    squares <-- square(in[0]) + 3 * square(in[1]);
+    max_plus_one <-- max(in[0], in[1]) + 1;
+    pair_out <-- pair(in[0]);
+
+    component sum_cmp = Sum();
+    sum_cmp.in <-- pair(in[1]);
+    sum <-- sum_cmp.out;
+}
+
+component main = IntermediateCall();
@@ -0,0 +1,12 @@
+pragma circom 2.0.0;
+
+template Pow() {
+    signal input in[2];
+    signal output pow;
+    signal output pow_public;
+
+    pow <-- in[0] ** in[1];
+    pow_public <-- 3 ** in[1];
+}
+
+component main = Pow();
@@ -0,0 +1,21 @@
+pragma circom 2.0.0;
+
+template SharedIndexBounds() {
+    signal input in[5];
+    signal output updated[4];
+
+    var arr[4];
+    // declared after the array, must stay public after writing with a shared index
+    var n = 0;
+    while (n < 4) {
+        arr[n] = in[n + 1] * 2;
+        n++;
+    }
+    arr[in[0]] = 42;
+    while (n > 0) {
+        n--;
+        updated[n] <-- arr[n];
+    }
+}
+
+component main = SharedIndexBounds();
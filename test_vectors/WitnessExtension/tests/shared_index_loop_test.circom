@@ -0,0 +1,37 @@
+pragma circom 2.0.0;
+
+function lookup(values, idx) {
+    var squares[4];
+    for (var i = 0; i < 4; i++) {
+        squares[i] = values[i] * values[i];
+    }
+    var sum = 0;
+    for (var i = 0; i < 4; i++) {
+        sum += values[idx];
+    }
+    return squares[idx] + sum;
+}
+
+template SharedIndexLoop() {
+    signal input in[6];
+    signal output matrix_lookup;
+    signal output fun_lookup;
+    signal output updated[6];
+
+    var m[2][3];
+    for (var i = 0; i < 2; i++) {
+        for (var j = 0; j < 3; j++) {
+            m[i][j] = in[3 * i + j] + i;
+        }
+    }
+    matrix_lookup <-- m[in[0]][in[1]];
+    fun_lookup <-- lookup([in[2], in[3], in[4], in[5]], in[1]);
+    m[in[1]][in[0]] = 0;
+    for (var i = 0; i < 2; i++) {
+        for (var j = 0; j < 3; j++) {
+            updated[3 * i + j] <-- m[i][j];
+        }
+    }
+}
+
+component main = SharedIndexLoop();
@@ -0,0 +1,21 @@
+pragma circom 2.0.0;
+
+template SharedIndex() {
+    signal input in[5];
+    signal output lookup;
+    signal output signal_lookup;
+    signal output updated[4];
+
+    var arr[4];
+    for (var i = 0; i < 4; i++) {
+        arr[i] = in[i + 1] * 2;
+    }
+    lookup <-- arr[in[0]];
+    signal_lookup <-- in[in[0] + 1];
+    arr[in[0]] = 42;
+    for (var i = 0; i < 4; i++) {
+        updated[i] <-- arr[i];
+    }
+}
+
+component main = SharedIndex();
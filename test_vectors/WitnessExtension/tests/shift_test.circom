@@ -0,0 +1,23 @@
+pragma circom 2.0.0;
+
+template Shift() {
+    signal input in[2];
+    signal output shift_r;
+    signal output shift_l;
+    signal output shift_r_public;
+    signal output shift_l_public;
+    signal output shift_l_binary_val;
+    signal output shift_l_binary_shift;
+    signal output shift_l_binary;
+
+    shift_r <-- in[0] >> in[1];
+    shift_l <-- in[0] << in[1];
+    shift_r_public <-- 123456789 >> in[1];
+    shift_l_public <-- 123456789 << in[1];
+    // the bitwise ANDs result in binary shares
+    shift_l_binary_val <-- (in[0] & 0xffffffffffff) << in[1];
+    shift_l_binary_shift <-- in[0] << (in[1] & 511);
+    shift_l_binary <-- (in[0] & 0xffffffffffff) << (in[1] & 511);
+}
+
+component main = Shift();
@@ -113,6 +113,7 @@ witness_extension_test_plain!(babycheck_test);
 witness_extension_test_plain!(babypbk_test);
 witness_extension_test_plain!(binsub_test);
 witness_extension_test_plain!(binsum_test);
+witness_extension_test_plain!(complement_test);
 witness_extension_test_plain!(constants_test);
 witness_extension_test_plain!(control_flow);
 witness_extension_test_plain!(eddsa_test);
@@ -130,6 +131,8 @@ witness_extension_test_plain!(escalarmulw4table_test3);
 witness_extension_test_plain!(functions);
 witness_extension_test_plain!(greatereqthan);
 witness_extension_test_plain!(greaterthan);
+witness_extension_test_plain!(int_div_test);
+witness_extension_test_plain!(intermediate_call_test);
 witness_extension_test_plain!(isequal);
 witness_extension_test_plain!(iszero);
 witness_extension_test_plain!(lesseqthan);
@@ -151,6 +154,7 @@ witness_extension_test_plain!(pedersen2_test);
 witness_extension_test_plain!(pedersen_hasher);
 witness_extension_test_plain!(pedersen_test);
 witness_extension_test_plain!(pointbits_loopback);
+witness_extension_test_plain!(pow_test);
 witness_extension_test_plain!(poseidon3_test);
 witness_extension_test_plain!(poseidon6_test);
 witness_extension_test_plain!(poseidon_hasher1);
@@ -162,6 +166,10 @@ witness_extension_test_plain!(sha256_test448);
 witness_extension_test_plain!(sha256_test512);
 witness_extension_test_plain!(shared_control_flow);
 witness_extension_test_plain!(shared_control_flow_arrays);
+witness_extension_test_plain!(shared_index_bounds_test);
+witness_extension_test_plain!(shared_index_loop_test);
+witness_extension_test_plain!(shared_index_test);
+witness_extension_test_plain!(shift_test);
 witness_extension_test_plain!(sign_test);
 witness_extension_test_plain!(sqrt_test);
 witness_extension_test_plain!(smtprocessor10_test);
@@ -169,3 +177,19 @@ witness_extension_test_plain!(smtverifier10_test);
 witness_extension_test_plain!(sum_test);
 witness_extension_test_plain!(winner);
 witness_extension_test_plain!(bitonic_sort);
+
+#[test]
+fn int_div_by_zero_test() {
+    let mut compiler_config = CompilerConfig::default();
+    compiler_config.simplification = circom_mpc_compiler::SimplificationLevel::O2(usize::MAX);
+    let parsed = CoCircomCompiler::<Bn254>::parse(
+        "../test_vectors/WitnessExtension/tests/int_div_test.circom",
+        compiler_config,
+    )
+    .unwrap();
+    let result = parsed.to_plain_vm(VMConfig::default()).run_with_flat(
+        vec![ark_bn254::Fr::from(100u64), ark_bn254::Fr::from(0u64)],
+        0,
+    );
+    assert!(result.is_err());
+}
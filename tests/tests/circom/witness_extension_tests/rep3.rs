@@ -201,6 +201,7 @@ witness_extension_test_rep3!(babycheck_test);
 witness_extension_test_rep3!(babypbk_test);
 witness_extension_test_rep3!(binsub_test);
 witness_extension_test_rep3!(binsum_test);
+witness_extension_test_rep3!(complement_test);
 witness_extension_test_rep3!(constants_test);
 witness_extension_test_rep3!(control_flow);
 witness_extension_test_rep3_ignored!(eddsa_test);
@@ -218,6 +219,8 @@ witness_extension_test_rep3!(escalarmulw4table_test3);
 witness_extension_test_rep3!(functions);
 witness_extension_test_rep3!(greatereqthan);
 witness_extension_test_rep3!(greaterthan);
+witness_extension_test_rep3!(int_div_test);
+witness_extension_test_rep3!(intermediate_call_test);
 witness_extension_test_rep3!(isequal);
 witness_extension_test_rep3!(iszero);
 witness_extension_test_rep3!(lesseqthan);
@@ -239,6 +242,7 @@ witness_extension_test_rep3_ignored!(pedersen2_test);
 witness_extension_test_rep3!(pedersen_hasher);
 witness_extension_test_rep3_ignored!(pedersen_test);
 witness_extension_test_rep3!(pointbits_loopback);
+witness_extension_test_rep3!(pow_test);
 witness_extension_test_rep3!(poseidon3_test);
 witness_extension_test_rep3!(poseidon6_test);
 witness_extension_test_rep3!(poseidon_hasher1);
@@ -250,6 +254,10 @@ witness_extension_test_rep3_ignored!(sha256_test448);
 witness_extension_test_rep3_ignored!(sha256_test512);
 witness_extension_test_rep3!(shared_control_flow);
 witness_extension_test_rep3!(shared_control_flow_arrays);
+witness_extension_test_rep3!(shared_index_bounds_test);
+witness_extension_test_rep3!(shared_index_loop_test);
+witness_extension_test_rep3!(shared_index_test);
+witness_extension_test_rep3!(shift_test);
 witness_extension_test_rep3!(sign_test);
 witness_extension_test_rep3!(sqrt_test);
 witness_extension_test_rep3!(smtprocessor10_test);
@@ -257,3 +265,34 @@ witness_extension_test_rep3!(smtverifier10_test);
 witness_extension_test_rep3!(sum_test);
 witness_extension_test_rep3!(winner);
 witness_extension_test_rep3!(bitonic_sort);
+
+#[test]
+fn int_div_by_shared_zero_test() {
+    let mut rng = thread_rng();
+    let inputs = rep3::share_field_elements(
+        &[ark_bn254::Fr::from(100u64), ark_bn254::Fr::from(0u64)],
+        &mut rng,
+    );
+    let test_network = Rep3TestNetwork::default();
+    let mut threads = vec![];
+    for (net, input) in izip!(test_network.get_party_networks(), inputs) {
+        threads.push(thread::spawn(move || {
+            let mut compiler_config = CompilerConfig::default();
+            compiler_config.simplification =
+                circom_mpc_compiler::SimplificationLevel::O2(usize::MAX);
+            CoCircomCompiler::<Bn254>::parse(
+                "../test_vectors/WitnessExtension/tests/int_div_test.circom",
+                compiler_config,
+            )
+            .unwrap()
+            .to_rep3_vm_with_network(net, VMConfig::default())
+            .unwrap()
+            .run_with_flat(input.into_iter().map(Rep3VmType::Arithmetic).collect(), 0)
+            .is_err()
+        }));
+    }
+    // same as the plain VM, all parties fail after opening that the divisor is zero
+    for thread in threads {
+        assert!(thread.join().unwrap());
+    }
+}
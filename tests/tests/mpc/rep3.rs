@@ -5,6 +5,9 @@ mod field_share {
     use ark_std::{UniformRand, Zero};
     use itertools::izip;
     use itertools::Itertools;
+    use mpc_core::gadgets::aes;
+    use mpc_core::gadgets::blake;
+    use mpc_core::protocols::rep3::binary;
     use mpc_core::protocols::rep3::conversion;
     use mpc_core::protocols::rep3::gadgets;
     use mpc_core::protocols::rep3::id::PartyID;
@@ -17,7 +20,8 @@ mod field_share {
     use mpc_core::protocols::rep3::yao::GCUtils;
     use mpc_core::protocols::rep3::{self, arithmetic, network::IoContext};
     use num_bigint::BigUint;
-    use rand::thread_rng;
+    use rand::{thread_rng, Rng};
+    use sha2::digest::generic_array::GenericArray;
     use std::sync::mpsc;
     use std::thread;
     use tests::rep3_network::Rep3TestNetwork;
@@ -623,6 +627,149 @@ mod field_share {
         assert_eq!(is_result_f, x);
     }
 
+    #[test]
+    fn rep3_shift_r_by_shared() {
+        const SHIFTS: [u64; 4] = [0, 17, 253, 300];
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = ark_bn254::Fr::rand(&mut rng);
+        let x_big: BigUint = x.into();
+        let should_result = SHIFTS
+            .iter()
+            .map(|shift| ark_bn254::Fr::from(&x_big >> *shift))
+            .collect_vec();
+        let x_shares = rep3::share_biguint(x, &mut rng);
+        let shift_shares = SHIFTS
+            .iter()
+            .map(|shift| rep3::share_biguint(ark_bn254::Fr::from(*shift), &mut rng))
+            .collect_vec();
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (i, (net, tx, x)) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter()
+        )
+        .enumerate()
+        {
+            let shifts = shift_shares
+                .iter()
+                .map(|shares| shares[i].to_owned())
+                .collect_vec();
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let results = shifts
+                    .iter()
+                    .map(|shift| binary::shift_r_by_shared(&x, shift, &mut rep3).unwrap())
+                    .collect_vec();
+                tx.send(results)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        for (should_result, result1, result2, result3) in
+            izip!(should_result, result1, result2, result3)
+        {
+            let is_result = rep3::combine_binary_element(result1, result2, result3);
+            assert_eq!(ark_bn254::Fr::from(is_result), should_result);
+        }
+    }
+
+    #[test]
+    fn rep3_shift_l_by_shared() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = ark_bn254::Fr::rand(&mut rng);
+        let shift = rng.gen_range(0..256u64);
+        let should_result = x * ark_bn254::Fr::from(2u64).pow([shift]);
+        let x_shares = rep3::share_field_element(x, &mut rng);
+        let shift_shares = rep3::share_biguint(ark_bn254::Fr::from(shift), &mut rng);
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, x, shift) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter(),
+            shift_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+                tx.send(binary::shift_l_by_shared(x, &shift, &mut rep3).unwrap())
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_element(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_shift_l_by_shared_large_shifts() {
+        const SHIFTS: [u64; 4] = [255, 256, 300, 1000];
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = ark_bn254::Fr::rand(&mut rng);
+        let x_big: BigUint = x.into();
+        let should_result = SHIFTS
+            .iter()
+            .map(|shift| ark_bn254::Fr::from(&x_big << *shift))
+            .collect_vec();
+        let x_shares = rep3::share_field_element(x, &mut rng);
+        let shift_shares = SHIFTS
+            .iter()
+            .map(|shift| rep3::share_biguint(ark_bn254::Fr::from(*shift), &mut rng))
+            .collect_vec();
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (i, (net, tx, x)) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter()
+        )
+        .enumerate()
+        {
+            let shifts = shift_shares
+                .iter()
+                .map(|shares| shares[i].to_owned())
+                .collect_vec();
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let results = shifts
+                    .iter()
+                    .map(|shift| binary::shift_l_by_shared(x, shift, &mut rep3).unwrap())
+                    .collect_vec();
+                tx.send(results)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        for (should_result, result1, result2, result3) in
+            izip!(should_result, result1, result2, result3)
+        {
+            let is_result = rep3::combine_field_element(result1, result2, result3);
+            assert_eq!(is_result, should_result);
+        }
+    }
+
     #[test]
     fn rep3_a2y2b() {
         let test_network = Rep3TestNetwork::default();
@@ -1312,6 +1459,665 @@ mod field_share {
         let is_result = rep3::combine_field_elements(result1, result2, result3);
         assert_eq!(is_result, should_result);
     }
+
+    #[test]
+    fn rep3_pow_by_shared() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = ark_bn254::Fr::rand(&mut rng);
+        let y = ark_bn254::Fr::rand(&mut rng);
+        let should_result = x.pow(y.into_bigint());
+        let x_shares = rep3::share_field_element(x, &mut rng);
+        let y_shares = rep3::share_field_element(y, &mut rng);
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, x, y) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter(),
+            y_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+                tx.send(gadgets::pow::pow_by_shared(x, y, &mut rep3).unwrap())
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_element(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_pow_public_by_shared() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = ark_bn254::Fr::rand(&mut rng);
+        let y = ark_bn254::Fr::from(rng.gen::<u64>());
+        let should_result = x.pow(y.into_bigint());
+        let y_shares = rep3::share_field_element(y, &mut rng);
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, y) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            y_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+                tx.send(gadgets::pow::pow_public_by_shared(x, y, &mut rep3).unwrap())
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_element(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_int_div_rem_via_yao() {
+        const VEC_SIZE: usize = 4;
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = (0..VEC_SIZE)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        // Small and full-size divisors
+        let y = (0..VEC_SIZE)
+            .map(|i| match i % 2 {
+                0 => ark_bn254::Fr::from(rng.gen_range(1..u64::MAX)),
+                _ => ark_bn254::Fr::rand(&mut rng),
+            })
+            .collect_vec();
+        let x_shares = rep3::share_field_elements(&x, &mut rng);
+        let y_shares = rep3::share_field_elements(&y, &mut rng);
+
+        let mut should_result = Vec::with_capacity(2 * VEC_SIZE);
+        for (x, y) in x.iter().zip(y.iter()) {
+            let x: BigUint = (*x).into();
+            let y: BigUint = (*y).into();
+            should_result.push(ark_bn254::Fr::from(x / y));
+        }
+        for (x, y) in x.into_iter().zip(y) {
+            let x: BigUint = x.into();
+            let y: BigUint = y.into();
+            should_result.push(ark_bn254::Fr::from(x % y));
+        }
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, x, y) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter(),
+            y_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let (mut quotients, remainders) =
+                    gadgets::int_div::int_div_rem_many(&x, &y, &mut rep3).unwrap();
+                quotients.extend(remainders);
+                tx.send(quotients)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_elements(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_int_div_rem_by_shared_zero_via_yao() {
+        const VEC_SIZE: usize = 2;
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = (0..VEC_SIZE)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let y = vec![ark_bn254::Fr::zero(); VEC_SIZE];
+        let x_shares = rep3::share_field_elements(&x, &mut rng);
+        let y_shares = rep3::share_field_elements(&y, &mut rng);
+
+        // A zero divisor is not detected: all quotient bits are set and the remainder is the dividend
+        let all_ones = (BigUint::one() << ark_bn254::Fr::MODULUS_BIT_SIZE) - BigUint::one();
+        let mut should_result = vec![ark_bn254::Fr::from(all_ones); VEC_SIZE];
+        should_result.extend(x);
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, x, y) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter(),
+            y_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let (mut quotients, remainders) =
+                    gadgets::int_div::int_div_rem_many(&x, &y, &mut rep3).unwrap();
+                quotients.extend(remainders);
+                tx.send(quotients)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_elements(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_int_div_rem_by_public_via_yao() {
+        const VEC_SIZE: usize = 4;
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = (0..VEC_SIZE)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect_vec();
+        let y = ark_bn254::Fr::from(rng.gen_range(2..u64::MAX));
+        let x_shares = rep3::share_field_elements(&x, &mut rng);
+
+        let divisor: BigUint = y.into();
+        let mut should_result = Vec::with_capacity(2 * VEC_SIZE);
+        for x in x.iter() {
+            let x: BigUint = (*x).into();
+            should_result.push(ark_bn254::Fr::from(x / &divisor));
+        }
+        for x in x.into_iter() {
+            let x: BigUint = x.into();
+            should_result.push(ark_bn254::Fr::from(x % &divisor));
+        }
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, x) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let (mut quotients, remainders) =
+                    gadgets::int_div::int_div_rem_by_public_many(&x, y, &mut rep3).unwrap();
+                quotients.extend(remainders);
+                tx.send(quotients)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_elements(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_slice_arithmetic_variable_bases_via_yao() {
+        const VEC_SIZE: usize = 10;
+        const TOTAL_BIT_SIZE: usize = 64;
+        const BASES: [u64; 5] = [784, 8, 28, 16384, 1000];
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let x = (0..VEC_SIZE)
+            .map(|_| ark_bn254::Fr::from(rng.gen::<u64>()))
+            .collect_vec();
+        let x_shares = rep3::share_field_elements(&x, &mut rng);
+
+        let mut should_result = Vec::with_capacity(VEC_SIZE * BASES.len());
+        for x in x.into_iter() {
+            let mut x: BigUint = x.into();
+            for base in BASES {
+                should_result.push(ark_bn254::Fr::from(&x % base));
+                x /= base;
+            }
+        }
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, x) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let sliced = yao::slice_arithmetic_variable_bases_many(
+                    &x,
+                    &mut rep3,
+                    TOTAL_BIT_SIZE,
+                    &BASES,
+                )
+                .unwrap();
+                tx.send(sliced)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_elements(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_ohv_from_index() {
+        const LEN: usize = 28;
+
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let index = rng.gen_range(0..LEN);
+        let x_shares = rep3::share_field_element(ark_bn254::Fr::from(index as u64), &mut rng);
+
+        let mut should_result = vec![ark_bn254::Fr::zero(); LEN];
+        should_result[index] = ark_bn254::Fr::one();
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, x) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let ohv = gadgets::ohv::ohv_from_index(x, LEN, &mut rep3).unwrap();
+                tx.send(ohv)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_elements(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_sha256_compression_via_yao() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let state: [u32; 8] = rng.gen();
+        let message: [u32; 16] = rng.gen();
+
+        let mut should_result = state;
+        let mut block = [0u8; 64];
+        for (chunk, word) in block.chunks_exact_mut(4).zip(message.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        sha2::compress256(
+            &mut should_result,
+            &[GenericArray::clone_from_slice(&block)],
+        );
+        let should_result = should_result
+            .into_iter()
+            .map(ark_bn254::Fr::from)
+            .collect_vec();
+
+        let state_shares = rep3::share_field_elements(&state.map(ark_bn254::Fr::from), &mut rng);
+        let message_shares =
+            rep3::share_field_elements(&message.map(ark_bn254::Fr::from), &mut rng);
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, state, message) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            state_shares.into_iter(),
+            message_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let state: [_; 8] = state.try_into().unwrap();
+                let message: [_; 16] = message.try_into().unwrap();
+                let result = yao::sha256_compression(&state, &message, &mut rep3).unwrap();
+                tx.send(result)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_elements(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_keccakf1600_via_yao() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let state: [u64; 25] = rng.gen();
+
+        let mut should_result = state;
+        keccak::f1600(&mut should_result);
+        let should_result = should_result
+            .into_iter()
+            .map(ark_bn254::Fr::from)
+            .collect_vec();
+
+        let state_shares = rep3::share_field_elements(&state.map(ark_bn254::Fr::from), &mut rng);
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, state) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            state_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let state: [_; 25] = state.try_into().unwrap();
+                let result = yao::keccakf1600(&state, &mut rep3).unwrap();
+                tx.send(result)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_elements(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_blake2s_compression_via_yao() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let state: [u32; 8] = rng.gen();
+        let message: [u32; 16] = rng.gen();
+        let counter: u64 = rng.gen();
+        let last_block: bool = rng.gen();
+
+        let should_result = blake::blake2s_compression(&state, &message, counter, last_block)
+            .into_iter()
+            .map(ark_bn254::Fr::from)
+            .collect_vec();
+
+        let state_shares = rep3::share_field_elements(&state.map(ark_bn254::Fr::from), &mut rng);
+        let message_shares =
+            rep3::share_field_elements(&message.map(ark_bn254::Fr::from), &mut rng);
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, state, message) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            state_shares.into_iter(),
+            message_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let state: [_; 8] = state.try_into().unwrap();
+                let message: [_; 16] = message.try_into().unwrap();
+                let result =
+                    yao::blake2s_compression(&state, &message, counter, last_block, &mut rep3)
+                        .unwrap();
+                tx.send(result)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_elements(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_blake3_compression_via_yao() {
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let chaining_value: [u32; 8] = rng.gen();
+        let message: [u32; 16] = rng.gen();
+        let counter: u64 = rng.gen();
+        let block_len = rng.gen_range(0..=64);
+        let flags = blake::BLAKE3_CHUNK_START | blake::BLAKE3_CHUNK_END | blake::BLAKE3_ROOT;
+
+        let should_result =
+            blake::blake3_compression(&chaining_value, &message, counter, block_len, flags)
+                .into_iter()
+                .map(ark_bn254::Fr::from)
+                .collect_vec();
+
+        let chaining_value_shares =
+            rep3::share_field_elements(&chaining_value.map(ark_bn254::Fr::from), &mut rng);
+        let message_shares =
+            rep3::share_field_elements(&message.map(ark_bn254::Fr::from), &mut rng);
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, chaining_value, message) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            chaining_value_shares.into_iter(),
+            message_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let chaining_value: [_; 8] = chaining_value.try_into().unwrap();
+                let message: [_; 16] = message.try_into().unwrap();
+                let result = yao::blake3_compression(
+                    &chaining_value,
+                    &message,
+                    counter,
+                    block_len,
+                    flags,
+                    &mut rep3,
+                )
+                .unwrap();
+                tx.send(result)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_elements(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_aes128_encrypt_cbc_via_yao() {
+        const NUM_BLOCKS: usize = 2;
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let key: [u8; aes::AES128_BLOCK_BYTES] = rng.gen();
+        let iv: [u8; aes::AES128_BLOCK_BYTES] = rng.gen();
+        let plaintext = (0..NUM_BLOCKS * aes::AES128_BLOCK_BYTES)
+            .map(|_| rng.gen::<u8>())
+            .collect_vec();
+
+        let should_result = aes::aes128_encrypt_cbc(&plaintext, &iv, &key)
+            .into_iter()
+            .map(ark_bn254::Fr::from)
+            .collect_vec();
+
+        let key_shares = rep3::share_field_elements(&key.map(ark_bn254::Fr::from), &mut rng);
+        let iv_shares = rep3::share_field_elements(&iv.map(ark_bn254::Fr::from), &mut rng);
+        let plaintext_shares = rep3::share_field_elements(
+            &plaintext
+                .iter()
+                .map(|x| ark_bn254::Fr::from(*x))
+                .collect_vec(),
+            &mut rng,
+        );
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, key, iv, plaintext) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            key_shares.into_iter(),
+            iv_shares.into_iter(),
+            plaintext_shares.into_iter()
+        ) {
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let key: [_; aes::AES128_BLOCK_BYTES] = key.try_into().unwrap();
+                let iv: [_; aes::AES128_BLOCK_BYTES] = iv.try_into().unwrap();
+                let result = yao::aes128_encrypt_cbc(&plaintext, &iv, &key, &mut rep3).unwrap();
+                tx.send(result)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_elements(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    fn split_into_limbs(x: &BigUint, num_limbs: usize, limb_bits: usize) -> Vec<ark_bn254::Fr> {
+        let mask = (BigUint::from(1u64) << limb_bits) - 1u64;
+        (0..num_limbs)
+            .map(|i| ark_bn254::Fr::from((x >> (i * limb_bits)) & &mask))
+            .collect()
+    }
+
+    #[test]
+    fn rep3_non_native_mul_add_div_rem_via_yao() {
+        const NUM_LIMBS: usize = 2;
+        const LIMB_BITS: usize = 68;
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let modulus = (BigUint::from(1u64) << 127) - 1u64;
+        let a = BigUint::from(rng.gen::<u128>()) % &modulus;
+        let b = BigUint::from(rng.gen::<u128>()) % &modulus;
+        let c = BigUint::from(rng.gen::<u128>()) % &modulus;
+        // The numerator must not be negative
+        let d = BigUint::from(rng.gen::<u128>()) % (&a * &b + &c + 1u64);
+
+        let numerator = &a * &b + &c - &d;
+        let mut should_result = split_into_limbs(&(&numerator / &modulus), NUM_LIMBS, LIMB_BITS);
+        should_result.extend(split_into_limbs(
+            &(&numerator % &modulus),
+            NUM_LIMBS,
+            LIMB_BITS,
+        ));
+
+        let [a_shares, b_shares, c_shares, d_shares] = [a, b, c, d].map(|x| {
+            rep3::share_field_elements(&split_into_limbs(&x, NUM_LIMBS, LIMB_BITS), &mut rng)
+        });
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, a, b, c, d) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            a_shares.into_iter(),
+            b_shares.into_iter(),
+            c_shares.into_iter(),
+            d_shares.into_iter()
+        ) {
+            let modulus = modulus.clone();
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let (mut quotient, remainder) = yao::non_native_mul_add_div_rem(
+                    &a, &b, &c, &d, &modulus, LIMB_BITS, LIMB_BITS, &mut rep3,
+                )
+                .unwrap();
+                quotient.extend(remainder);
+                tx.send(quotient)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_elements(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn rep3_non_native_inverse_via_yao() {
+        const NUM_LIMBS: usize = 2;
+        const LIMB_BITS: usize = 68;
+        let test_network = Rep3TestNetwork::default();
+        let mut rng = thread_rng();
+        let modulus = (BigUint::from(1u64) << 127) - 1u64;
+        let x = BigUint::from(rng.gen::<u128>()) % &modulus;
+
+        let inverse = x.modpow(&(&modulus - 2u64), &modulus);
+        assert_eq!((&x * &inverse) % &modulus, BigUint::from(1u64));
+        let should_result = split_into_limbs(&inverse, NUM_LIMBS, LIMB_BITS);
+
+        let x_shares =
+            rep3::share_field_elements(&split_into_limbs(&x, NUM_LIMBS, LIMB_BITS), &mut rng);
+
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        let (tx3, rx3) = mpsc::channel();
+
+        for (net, tx, x) in izip!(
+            test_network.get_party_networks().into_iter(),
+            [tx1, tx2, tx3],
+            x_shares.into_iter()
+        ) {
+            let modulus = modulus.clone();
+            thread::spawn(move || {
+                let mut rep3 = IoContext::init(net).unwrap();
+
+                let result =
+                    yao::non_native_inverse(&x, &modulus, LIMB_BITS, LIMB_BITS, &mut rep3).unwrap();
+                tx.send(result)
+            });
+        }
+
+        let result1 = rx1.recv().unwrap();
+        let result2 = rx2.recv().unwrap();
+        let result3 = rx3.recv().unwrap();
+        let is_result = rep3::combine_field_elements(result1, result2, result3);
+        assert_eq!(is_result, should_result);
+    }
 }
 
 mod curve_share {
@@ -2,8 +2,8 @@ mod field_share {
     use ark_ff::Field;
     use ark_std::{UniformRand, Zero};
     use itertools::{izip, Itertools};
-    use mpc_core::protocols::shamir::{self, arithmetic, ShamirPreprocessing};
-    use rand::thread_rng;
+    use mpc_core::protocols::shamir::{self, arithmetic, lut, ShamirPreprocessing};
+    use rand::{thread_rng, Rng};
     use std::{str::FromStr, sync::mpsc, thread};
     use tests::shamir_network::ShamirTestNetwork;
 
@@ -357,6 +357,129 @@ mod field_share {
         shamir_inv_inner(3, 1);
         shamir_inv_inner(10, 4);
     }
+
+    fn shamir_lut_read_inner(num_parties: usize, threshold: usize) {
+        const LUT_SIZE: usize = 8;
+        let test_network = ShamirTestNetwork::new(num_parties);
+        let mut rng = thread_rng();
+        let keys = (0..LUT_SIZE as u64)
+            .map(ark_bn254::Fr::from)
+            .collect::<Vec<_>>();
+        let values = (0..LUT_SIZE)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let index = rng.gen_range(0..LUT_SIZE);
+        let should_result = values[index];
+
+        let needle_shares =
+            shamir::share_field_element(keys[index], threshold, num_parties, &mut rng);
+        let value_shares = shamir::share_field_elements(&values, threshold, num_parties, &mut rng);
+
+        let mut tx = Vec::with_capacity(num_parties);
+        let mut rx = Vec::with_capacity(num_parties);
+        for _ in 0..num_parties {
+            let (t, r) = mpsc::channel();
+            tx.push(t);
+            rx.push(r);
+        }
+
+        for (net, tx, needle, values) in izip!(
+            test_network.get_party_networks(),
+            tx,
+            needle_shares,
+            value_shares
+        ) {
+            let keys = keys.clone();
+            thread::spawn(move || {
+                let mut shamir = ShamirPreprocessing::new(threshold, net, LUT_SIZE - 1)
+                    .unwrap()
+                    .into();
+                let map = lut::ShamirLutMap::new(keys, values);
+                tx.send(lut::read_from_lut(needle, &map, &mut shamir).unwrap())
+            });
+        }
+
+        let mut results = Vec::with_capacity(num_parties);
+        for r in rx {
+            results.push(r.recv().unwrap());
+        }
+
+        let is_result =
+            shamir::combine_field_element(&results, &(1..=num_parties).collect_vec(), threshold)
+                .unwrap();
+
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn shamir_lut_read() {
+        shamir_lut_read_inner(3, 1);
+        shamir_lut_read_inner(10, 4);
+    }
+
+    fn shamir_lut_write_inner(num_parties: usize, threshold: usize) {
+        const LUT_SIZE: usize = 8;
+        let test_network = ShamirTestNetwork::new(num_parties);
+        let mut rng = thread_rng();
+        let keys = (0..LUT_SIZE as u64)
+            .map(ark_bn254::Fr::from)
+            .collect::<Vec<_>>();
+        let values = (0..LUT_SIZE)
+            .map(|_| ark_bn254::Fr::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let index = rng.gen_range(0..LUT_SIZE);
+        let value = ark_bn254::Fr::rand(&mut rng);
+        let mut should_result = values.clone();
+        should_result[index] = value;
+
+        let needle_shares =
+            shamir::share_field_element(keys[index], threshold, num_parties, &mut rng);
+        let value_shares = shamir::share_field_element(value, threshold, num_parties, &mut rng);
+        let values_shares = shamir::share_field_elements(&values, threshold, num_parties, &mut rng);
+
+        let mut tx = Vec::with_capacity(num_parties);
+        let mut rx = Vec::with_capacity(num_parties);
+        for _ in 0..num_parties {
+            let (t, r) = mpsc::channel();
+            tx.push(t);
+            rx.push(r);
+        }
+
+        for (net, tx, needle, value, values) in izip!(
+            test_network.get_party_networks(),
+            tx,
+            needle_shares,
+            value_shares,
+            values_shares
+        ) {
+            let keys = keys.clone();
+            thread::spawn(move || {
+                let mut shamir = ShamirPreprocessing::new(threshold, net, 2 * LUT_SIZE - 2)
+                    .unwrap()
+                    .into();
+                let mut map = lut::ShamirLutMap::new(keys, values);
+                lut::write_to_lut(needle, value, &mut map, &mut shamir).unwrap();
+                tx.send(map.values().to_vec())
+            });
+        }
+
+        let mut results = Vec::with_capacity(num_parties);
+        for r in rx {
+            results.push(r.recv().unwrap());
+        }
+
+        let is_result =
+            shamir::combine_field_elements(&results, &(1..=num_parties).collect_vec(), threshold)
+                .unwrap();
+
+        assert_eq!(is_result, should_result);
+    }
+
+    #[test]
+    fn shamir_lut_write() {
+        shamir_lut_write_inner(3, 1);
+        shamir_lut_write_inner(10, 4);
+    }
 }
 
 mod curve_share {
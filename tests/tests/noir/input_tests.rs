@@ -0,0 +1,235 @@
+use acir::{
+    circuit::{Circuit, Program},
+    native_types::Witness,
+    AcirField, FieldElement,
+};
+use co_acvm::solver::{partial_abi::PublicMarker, PlainCoSolver};
+use noirc_abi::Abi;
+use noirc_artifacts::program::ProgramArtifact;
+use std::{
+    collections::BTreeMap,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+type Solver = PlainCoSolver<ark_bn254::Fr>;
+
+fn read_abi() -> Abi {
+    let abi = std::fs::read_to_string("../test_vectors/noir/abi_inputs/abi.json").unwrap();
+    serde_json::from_str(&abi).unwrap()
+}
+
+fn read_program(name: &str) -> ProgramArtifact {
+    let program =
+        std::fs::read_to_string(format!("../test_vectors/noir/{name}/kat/{name}.json")).unwrap();
+    serde_json::from_str(&program).unwrap()
+}
+
+fn unwrap_markers(
+    map: BTreeMap<String, PublicMarker<FieldElement>>,
+) -> BTreeMap<String, (bool, FieldElement)> {
+    map.into_iter()
+        .map(|(name, value)| match value {
+            PublicMarker::Public(value) => (name, (true, value)),
+            PublicMarker::Private(value) => (name, (false, value)),
+        })
+        .collect()
+}
+
+/// Reads a fragment of the input of the nested ABI, where the program only provides the public parameters.
+fn read_fragment(name: &str) -> BTreeMap<String, FieldElement> {
+    let program = Program {
+        functions: vec![Circuit::default()],
+        unconstrained_functions: vec![],
+    };
+    let fragment = Solver::partially_read_abi_bn254_fieldelement(
+        format!("../test_vectors/noir/abi_inputs/{name}"),
+        &read_abi(),
+        &program,
+    )
+    .unwrap();
+    unwrap_markers(fragment)
+        .into_iter()
+        .map(|(name, (_, value))| (name, value))
+        .collect()
+}
+
+fn read_invalid_input(input: &str) -> String {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let path = std::env::temp_dir().join(format!(
+        "co_noir_invalid_input_{}_{}.json",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::write(&path, input).unwrap();
+    let result = Solver::read_abi_bn254_fieldelement(&path, &read_abi());
+    std::fs::remove_file(&path).unwrap();
+    result.unwrap_err().to_string()
+}
+
+#[test]
+fn json_and_toml_inputs_match() {
+    let program = read_program("poseidon_stdlib");
+    let from_toml = Solver::partially_read_abi_bn254_fieldelement(
+        "../test_vectors/noir/poseidon_stdlib/Prover.toml",
+        &program.abi,
+        &program.bytecode,
+    )
+    .unwrap();
+    let from_json = Solver::partially_read_abi_bn254_fieldelement(
+        "../test_vectors/noir/poseidon_stdlib/Prover.json",
+        &program.abi,
+        &program.bytecode,
+    )
+    .unwrap();
+    assert_eq!(unwrap_markers(from_toml), unwrap_markers(from_json));
+}
+
+#[test]
+fn nested_abi_inputs() {
+    let abi = read_abi();
+    let from_json =
+        Solver::read_abi_bn254_fieldelement("../test_vectors/noir/abi_inputs/Prover.json", &abi)
+            .unwrap();
+    let from_toml =
+        Solver::read_abi_bn254_fieldelement("../test_vectors/noir/abi_inputs/Prover.toml", &abi)
+            .unwrap();
+    assert_eq!(from_json, from_toml);
+
+    let expected = [
+        FieldElement::from(104u128),
+        FieldElement::from(101u128),
+        FieldElement::from(108u128),
+        FieldElement::from(108u128),
+        FieldElement::from(111u128),
+        FieldElement::one(),
+        FieldElement::from(253u128),
+        FieldElement::from(7u128),
+        FieldElement::from(16u128),
+        FieldElement::from(1u128),
+        FieldElement::from(2u128),
+        FieldElement::from(3u128),
+        -FieldElement::one(),
+        FieldElement::from(42u128),
+    ];
+    for (i, expected) in expected.into_iter().enumerate() {
+        assert_eq!(from_json.get(&Witness(i as u32)), Some(&expected));
+    }
+    assert!(from_json.get(&Witness(expected.len() as u32)).is_none());
+}
+
+#[test]
+fn nested_abi_inputs_to_json() {
+    let abi = read_abi();
+    let witness =
+        Solver::read_abi_bn254_fieldelement("../test_vectors/noir/abi_inputs/Prover.json", &abi)
+            .unwrap();
+    let mut string_map = BTreeMap::new();
+    let mut values = witness.into_iter().map(|(_, value)| value);
+    for param in abi.parameters.iter() {
+        let field_count = param.typ.field_count();
+        for i in 0..field_count {
+            let name = if field_count == 1 {
+                param.name.to_owned()
+            } else {
+                format!("{}[{}]", param.name, i)
+            };
+            string_map.insert(name, values.next().unwrap());
+        }
+    }
+
+    let json = Solver::string_map_to_json(&string_map, &abi).unwrap();
+    assert_eq!(json["name"], "hello");
+    assert_eq!(json["flag"], true);
+    assert_eq!(json["delta"], "-3");
+    assert_eq!(json["pair"][0], "7");
+    assert_eq!(json["total"], "42");
+
+    // Reading the reconstructed JSON results in the same witness
+    let path = std::env::temp_dir().join("co_noir_reconstructed_input.json");
+    std::fs::write(&path, serde_json::to_string(&json).unwrap()).unwrap();
+    let reconstructed = Solver::read_abi_bn254_fieldelement(&path, &abi).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        reconstructed,
+        Solver::read_abi_bn254_fieldelement("../test_vectors/noir/abi_inputs/Prover.json", &abi)
+            .unwrap()
+    );
+}
+
+#[test]
+fn invalid_inputs_name_the_field() {
+    let valid = std::fs::read_to_string("../test_vectors/noir/abi_inputs/Prover.json").unwrap();
+    let mut input: serde_json::Value = serde_json::from_str(&valid).unwrap();
+
+    input["points"][1]["y"] = serde_json::json!([1]);
+    let err = read_invalid_input(&input.to_string());
+    assert!(err.contains("`points[1].y`"), "{err}");
+
+    input["points"][1]["y"] = serde_json::json!("1");
+    input["delta"] = serde_json::json!(128);
+    let err = read_invalid_input(&input.to_string());
+    assert!(err.contains("`delta`") && err.contains("i8"), "{err}");
+
+    input["delta"] = serde_json::json!(-128);
+    input["pair"] = serde_json::json!([1]);
+    let err = read_invalid_input(&input.to_string());
+    assert!(err.contains("`pair`") && err.contains("tuple"), "{err}");
+
+    input["pair"] = serde_json::json!([1, 2]);
+    input["name"] = serde_json::json!("hi");
+    let err = read_invalid_input(&input.to_string());
+    assert!(err.contains("`name`") && err.contains("5 bytes"), "{err}");
+
+    input["name"] = serde_json::json!("hello");
+    input.as_object_mut().unwrap().remove("total");
+    let err = read_invalid_input(&input.to_string());
+    assert!(err.contains("`total`"), "{err}");
+}
+
+#[test]
+fn merge_nested_fragments() {
+    let abi = read_abi();
+    let mut merged = read_fragment("Fragment1.toml");
+    let fragment2 = read_fragment("Fragment2.json");
+    for (name, value) in fragment2 {
+        assert!(merged.insert(name, value).is_none());
+    }
+
+    let witness = Solver::witness_map_from_string_map::<_, FieldElement>(merged, &abi).unwrap();
+    let should_witness =
+        Solver::read_abi_bn254_fieldelement("../test_vectors/noir/abi_inputs/Prover.json", &abi)
+            .unwrap();
+    assert_eq!(witness, should_witness);
+}
+
+#[test]
+fn incomplete_fragments_name_the_missing_members() {
+    let abi = read_abi();
+    let fragment = read_fragment("Fragment1.toml");
+    let err = Solver::validate_string_map(&fragment, &abi)
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("delta") && err.contains("points[1].y"),
+        "{err}"
+    );
+    assert!(!err.contains("points[1].x"), "{err}");
+
+    assert_eq!(Solver::input_path("points[3]", &abi), "points[1].y");
+    assert_eq!(Solver::input_path("pair[1]", &abi), "pair.1");
+    assert_eq!(Solver::input_path("total", &abi), "total");
+}
+
+#[test]
+fn duplicate_members_are_rejected() {
+    let valid = std::fs::read_to_string("../test_vectors/noir/abi_inputs/Prover.json").unwrap();
+    let mut input: serde_json::Value = serde_json::from_str(&valid).unwrap();
+    input["points[1].y"] = serde_json::json!("5");
+    let err = read_invalid_input(&input.to_string());
+    assert!(err.contains("duplicate input for `points[1].y`"), "{err}");
+
+    input.as_object_mut().unwrap().remove("points[1].y");
+    input["points[2]"] = serde_json::json!({ "x": "1" });
+    let err = read_invalid_input(&input.to_string());
+    assert!(err.contains("out of bounds"), "{err}");
+}
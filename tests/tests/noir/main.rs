@@ -1,4 +1,6 @@
 #[cfg(test)]
+mod input_tests;
+#[cfg(test)]
 mod proof_tests;
 #[cfg(test)]
 mod witness_extension_tests;
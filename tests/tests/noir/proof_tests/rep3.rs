@@ -1,8 +1,6 @@
 use crate::proof_tests::{CRS_PATH_G1, CRS_PATH_G2};
-use acir::native_types::{WitnessMap, WitnessStack};
 use ark_bn254::Bn254;
-use ark_ff::PrimeField;
-use co_acvm::{solver::Rep3CoSolver, Rep3AcvmType};
+use co_acvm::{solver::Rep3CoSolver, PlainAcvmSolver, Rep3AcvmSolver, Rep3AcvmType};
 use co_ultrahonk::prelude::{
     CoUltraHonk, Poseidon2Sponge, ProvingKey, Rep3CoBuilder, Rep3UltraHonkDriver,
     TranscriptFieldType, TranscriptHasher, UltraCircuitBuilder, UltraHonk, Utils, VerifyingKey,
@@ -12,36 +10,6 @@ use sha3::Keccak256;
 use std::thread;
 use tests::rep3_network::{PartyTestNetwork, Rep3TestNetwork};
 
-fn witness_map_to_witness_vector<F: PrimeField>(
-    witness_map: WitnessMap<Rep3AcvmType<F>>,
-) -> Vec<Rep3AcvmType<F>> {
-    let mut wv = Vec::new();
-    let mut index = 0;
-    for (w, f) in witness_map.into_iter() {
-        // ACIR uses a sparse format for WitnessMap where unused witness indices may be left unassigned.
-        // To ensure that witnesses sit at the correct indices in the `WitnessVector`, we fill any indices
-        // which do not exist within the `WitnessMap` with the dummy value of zero.
-        while index < w.0 {
-            wv.push(Rep3AcvmType::from(F::zero()));
-            index += 1;
-        }
-
-        wv.push(f);
-        index += 1;
-    }
-    wv
-}
-
-fn convert_witness_rep3<F: PrimeField>(
-    mut witness_stack: WitnessStack<Rep3AcvmType<F>>,
-) -> Vec<Rep3AcvmType<F>> {
-    let witness_map = witness_stack
-        .pop()
-        .expect("Witness should be present")
-        .witness;
-    witness_map_to_witness_vector(witness_map)
-}
-
 fn proof_test<H: TranscriptHasher<TranscriptFieldType>>(name: &str) {
     let circuit_file = format!("../test_vectors/noir/{}/kat/{}.json", name, name);
     let witness_file = format!("../test_vectors/noir/{}/kat/{}.gz", name, name);
@@ -64,13 +32,17 @@ fn proof_test<H: TranscriptHasher<TranscriptFieldType>>(name: &str) {
         threads.push(thread::spawn(move || {
             let constraint_system = Utils::get_constraint_system_from_artifact(&artifact, true);
 
+            let id = net.id;
+            let mut acvm_driver = Rep3AcvmSolver::new(net).unwrap();
             let builder = Rep3CoBuilder::<Bn254, PartyTestNetwork>::create_circuit(
                 constraint_system,
                 0,
                 witness,
                 true,
                 false,
-            );
+                &mut acvm_driver,
+            )
+            .unwrap();
 
             let crs = ProvingKey::<Rep3UltraHonkDriver<PartyTestNetwork>, _>::get_prover_crs(
                 &builder,
@@ -78,12 +50,11 @@ fn proof_test<H: TranscriptHasher<TranscriptFieldType>>(name: &str) {
             )
             .expect("failed to get prover crs");
 
-            let id = net.id;
+            let proving_key = ProvingKey::create(id, builder, crs, &mut acvm_driver).unwrap();
 
-            let mut io_context0 = IoContext::init(net).unwrap();
+            let mut io_context0 = IoContext::init(acvm_driver.into_network()).unwrap();
             let io_context1 = io_context0.fork().unwrap();
             let driver = Rep3UltraHonkDriver::new(io_context0, io_context1);
-            let proving_key = ProvingKey::create(id, builder, crs).unwrap();
 
             let prover = CoUltraHonk::<_, _, H>::new(driver);
             prover.prove(proving_key).unwrap()
@@ -101,8 +72,15 @@ fn proof_test<H: TranscriptHasher<TranscriptFieldType>>(name: &str) {
 
     // Get vk
     let constraint_system = Utils::get_constraint_system_from_artifact(&program_artifact, true);
-    let builder =
-        UltraCircuitBuilder::<Bn254>::create_circuit(constraint_system, 0, vec![], true, false);
+    let builder = UltraCircuitBuilder::<Bn254>::create_circuit(
+        constraint_system,
+        0,
+        vec![],
+        true,
+        false,
+        &mut PlainAcvmSolver::default(),
+    )
+    .unwrap();
     let crs = VerifyingKey::get_crs(&builder, CRS_PATH_G1, CRS_PATH_G2).unwrap();
     let verifying_key = VerifyingKey::create(builder, crs).unwrap();
 
@@ -131,15 +109,19 @@ fn witness_and_proof_test<H: TranscriptHasher<TranscriptFieldType>>(name: &str)
             let constraint_system = Utils::get_constraint_system_from_artifact(&artifact, true);
             let solver = Rep3CoSolver::from_network(net1, artifact, prover_toml).unwrap();
             let witness = solver.solve().unwrap();
-            let witness = convert_witness_rep3(witness);
 
-            let builder = Rep3CoBuilder::<Bn254, PartyTestNetwork>::create_circuit(
-                constraint_system,
-                0,
-                witness,
-                true,
-                false,
-            );
+            let id = net2.id;
+            let mut acvm_driver = Rep3AcvmSolver::new(net2).unwrap();
+            let builder =
+                Rep3CoBuilder::<Bn254, PartyTestNetwork>::create_circuit_from_witness_stack(
+                    constraint_system,
+                    0,
+                    witness,
+                    true,
+                    false,
+                    &mut acvm_driver,
+                )
+                .unwrap();
 
             let prover_crs =
                 ProvingKey::<Rep3UltraHonkDriver<PartyTestNetwork>, _>::get_prover_crs(
@@ -148,12 +130,12 @@ fn witness_and_proof_test<H: TranscriptHasher<TranscriptFieldType>>(name: &str)
                 )
                 .expect("failed to get prover crs");
 
-            let id = net2.id;
+            let proving_key =
+                ProvingKey::create(id, builder, prover_crs, &mut acvm_driver).unwrap();
 
-            let mut io_context0 = IoContext::init(net2).unwrap();
+            let mut io_context0 = IoContext::init(acvm_driver.into_network()).unwrap();
             let io_context1 = io_context0.fork().unwrap();
             let driver = Rep3UltraHonkDriver::new(io_context0, io_context1);
-            let proving_key = ProvingKey::create(id, builder, prover_crs).unwrap();
 
             let prover = CoUltraHonk::<_, _, H>::new(driver);
             prover.prove(proving_key).unwrap()
@@ -171,8 +153,15 @@ fn witness_and_proof_test<H: TranscriptHasher<TranscriptFieldType>>(name: &str)
 
     // Get vk
     let constraint_system = Utils::get_constraint_system_from_artifact(&program_artifact, true);
-    let builder =
-        UltraCircuitBuilder::<Bn254>::create_circuit(constraint_system, 0, vec![], true, false);
+    let builder = UltraCircuitBuilder::<Bn254>::create_circuit(
+        constraint_system,
+        0,
+        vec![],
+        true,
+        false,
+        &mut PlainAcvmSolver::default(),
+    )
+    .unwrap();
     let crs = VerifyingKey::get_crs(&builder, CRS_PATH_G1, CRS_PATH_G2).unwrap();
     let verifying_key = VerifyingKey::create(builder, crs).unwrap();
 
@@ -199,3 +188,28 @@ fn poseidon_witness_and_proof_test_keccak256() {
 fn poseidon_proof_test_keccak256() {
     proof_test::<Keccak256>("poseidon");
 }
+
+#[test]
+fn random_access_witness_and_proof_test_poseidon2sponge() {
+    witness_and_proof_test::<Poseidon2Sponge>("random_access");
+}
+
+#[test]
+fn write_access_witness_and_proof_test_poseidon2sponge() {
+    witness_and_proof_test::<Poseidon2Sponge>("write_access");
+}
+
+#[test]
+fn write_access_proof_test_poseidon2sponge() {
+    proof_test::<Poseidon2Sponge>("write_access");
+}
+
+#[test]
+fn poseidon2_witness_and_proof_test_poseidon2sponge() {
+    witness_and_proof_test::<Poseidon2Sponge>("poseidon2");
+}
+
+#[test]
+fn poseidon2_proof_test_poseidon2sponge() {
+    proof_test::<Poseidon2Sponge>("poseidon2");
+}
@@ -1,6 +1,6 @@
 use crate::proof_tests::{CRS_PATH_G1, CRS_PATH_G2};
 use ark_bn254::Bn254;
-use co_acvm::ShamirAcvmType;
+use co_acvm::{PlainAcvmSolver, ShamirAcvmSolver, ShamirAcvmType};
 use co_ultrahonk::{
     prelude::{
         CoUltraHonk, Poseidon2Sponge, ProvingKey, ShamirCoBuilder, ShamirUltraHonkDriver,
@@ -40,13 +40,18 @@ fn proof_test<H: TranscriptHasher<TranscriptFieldType>>(
         threads.push(thread::spawn(move || {
             let constraint_system = Utils::get_constraint_system_from_artifact(&artifact, true);
 
+            let id = net.id;
+            let preprocessing = ShamirPreprocessing::new(threshold, net, 0).unwrap();
+            let mut acvm_driver = ShamirAcvmSolver::new(ShamirProtocol::from(preprocessing));
             let builder = ShamirCoBuilder::<Bn254, PartyTestNetwork>::create_circuit(
                 constraint_system,
                 0,
                 witness,
                 true,
                 false,
-            );
+                &mut acvm_driver,
+            )
+            .unwrap();
 
             let prover_crs =
                 ProvingKey::<ShamirUltraHonkDriver<_, PartyTestNetwork>, _>::get_prover_crs(
@@ -55,9 +60,8 @@ fn proof_test<H: TranscriptHasher<TranscriptFieldType>>(
                 )
                 .expect("failed to get prover crs");
 
-            let id = net.id;
-
-            let proving_key = ProvingKey::create(id, builder, prover_crs).unwrap();
+            let proving_key =
+                ProvingKey::create(id, builder, prover_crs, &mut acvm_driver).unwrap();
 
             let n = proving_key.circuit_size as usize;
             let num_pairs_oink_prove = OINK_CRAND_PAIRS_FACTOR_N * n
@@ -67,7 +71,8 @@ fn proof_test<H: TranscriptHasher<TranscriptFieldType>>(
             let num_pairs_sumcheck_prove =
                 SUMCHECK_ROUND_CRAND_PAIRS_FACTOR * MAX_PARTIAL_RELATION_LENGTH * (n - 1);
             let num_pairs = num_pairs_oink_prove + num_pairs_sumcheck_prove;
-            let preprocessing = ShamirPreprocessing::new(threshold, net, num_pairs).unwrap();
+            let preprocessing =
+                ShamirPreprocessing::new(threshold, acvm_driver.into_network(), num_pairs).unwrap();
             let mut io_context0 = ShamirProtocol::from(preprocessing);
             let io_context1 = io_context0.fork_with_pairs(0).unwrap();
             let driver = ShamirUltraHonkDriver::new(io_context0, io_context1);
@@ -88,8 +93,15 @@ fn proof_test<H: TranscriptHasher<TranscriptFieldType>>(
 
     // Get vk
     let constraint_system = Utils::get_constraint_system_from_artifact(&program_artifact, true);
-    let builder =
-        UltraCircuitBuilder::<Bn254>::create_circuit(constraint_system, 0, vec![], true, false);
+    let builder = UltraCircuitBuilder::<Bn254>::create_circuit(
+        constraint_system,
+        0,
+        vec![],
+        true,
+        false,
+        &mut PlainAcvmSolver::default(),
+    )
+    .unwrap();
     let crs = VerifyingKey::get_crs(&builder, CRS_PATH_G1, CRS_PATH_G2).unwrap();
     let verifying_key = VerifyingKey::create(builder, crs).unwrap();
 
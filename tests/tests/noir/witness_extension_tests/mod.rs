@@ -1,10 +1,11 @@
 use acir::native_types::{WitnessMap, WitnessStack};
 use ark_ff::PrimeField;
-use co_acvm::Rep3AcvmType;
+use co_acvm::{Rep3AcvmType, ShamirAcvmType};
 use itertools::izip;
 
 mod plain_solver;
 mod rep3;
+mod shamir;
 
 macro_rules! add_plain_acvm_test {
         ($name: expr) => {
@@ -80,6 +81,54 @@ macro_rules! add_rep3_acvm_test {
     };
 }
 
+macro_rules! add_shamir_acvm_test {
+    ($name: expr, $num_parties: expr, $threshold: expr) => {
+        paste::item! {
+            #[test]
+            fn [< test_shamir_ $name _ $num_parties _ $threshold >]() {
+                let program = std::fs::read_to_string(format!(
+                    "../test_vectors/noir/{}/kat/{}.json",
+                    $name, $name
+                ))
+                .unwrap();
+                let program_artifact = serde_json::from_str::<ProgramArtifact>(&program)
+                    .expect("failed to parse program artifact");
+
+                let should_witness =
+                    std::fs::read(format!("../test_vectors/noir/{}/kat/{}.gz", $name, $name)).unwrap();
+
+                let should_witness =
+                    WitnessStack::<FieldElement>::try_from(should_witness.as_slice()).unwrap();
+                let prover_toml = format!("../test_vectors/noir/{}/Prover.toml", $name);
+                let test_network = ShamirTestNetwork::new($num_parties);
+                let mut threads = Vec::with_capacity($num_parties);
+                for net in test_network.get_party_networks() {
+                    let program_artifact = program_artifact.clone();
+                    let prover_toml = prover_toml.clone();
+                    threads.push(thread::spawn(move || {
+                        let solver = ShamirCoSolver::from_network(
+                            net,
+                            $threshold,
+                            program_artifact,
+                            prover_toml,
+                        )
+                        .unwrap();
+                        solver.solve()
+                    }));
+                }
+
+                let results = threads
+                    .into_iter()
+                    .map(|t| t.join().unwrap().unwrap())
+                    .collect::<Vec<_>>();
+                let is_witness = super::combine_shamir_field_elements_for_acvm(results, $threshold);
+                let is_witness = PlainCoSolver::convert_to_plain_acvm_witness(is_witness);
+                assert_eq!(should_witness, is_witness)
+            }
+        }
+    };
+}
+
 fn combine_field_elements_for_acvm<F: PrimeField>(
     mut a: WitnessStack<Rep3AcvmType<F>>,
     mut b: WitnessStack<Rep3AcvmType<F>>,
@@ -121,5 +170,67 @@ fn combine_field_elements_for_acvm<F: PrimeField>(
     res
 }
 
+fn combine_shamir_field_elements_for_acvm<F: PrimeField>(
+    mut shares: Vec<WitnessStack<ShamirAcvmType<F>>>,
+    threshold: usize,
+) -> WitnessStack<F> {
+    let num_parties = shares.len();
+    let party_ids = (1..=num_parties).collect::<Vec<_>>();
+    let mut res = WitnessStack::default();
+    for stack in shares.iter() {
+        assert_eq!(stack.length(), shares[0].length());
+    }
+    while let Some(stack_item) = shares[0].pop() {
+        let others = shares[1..]
+            .iter_mut()
+            .map(|stack| stack.pop().unwrap())
+            .collect::<Vec<_>>();
+        let mut witnesses = others
+            .into_iter()
+            .map(|item| {
+                assert_eq!(item.index, stack_item.index);
+                item.witness.into_iter()
+            })
+            .collect::<Vec<_>>();
+        let mut witness_map = WitnessMap::default();
+        for (witness, share) in stack_item.witness.into_iter() {
+            let mut party_shares = vec![share];
+            for other in witnesses.iter_mut() {
+                let (other_witness, other_share) = other.next().unwrap();
+                assert_eq!(witness, other_witness);
+                party_shares.push(other_share);
+            }
+            let reconstructed = if party_shares
+                .iter()
+                .all(|share| matches!(share, ShamirAcvmType::Public(_)))
+            {
+                let public = party_shares
+                    .into_iter()
+                    .map(|share| match share {
+                        ShamirAcvmType::Public(public) => public,
+                        ShamirAcvmType::Shared(_) => unreachable!(),
+                    })
+                    .collect::<Vec<_>>();
+                assert!(public.iter().all(|x| *x == public[0]), "must be all public");
+                public[0]
+            } else {
+                let shared = party_shares
+                    .into_iter()
+                    .map(|share| match share {
+                        ShamirAcvmType::Shared(shared) => shared,
+                        ShamirAcvmType::Public(_) => unimplemented!(),
+                    })
+                    .collect::<Vec<_>>();
+                mpc_core::protocols::shamir::combine_field_element(&shared, &party_ids, threshold)
+                    .unwrap()
+            };
+            witness_map.insert(witness, reconstructed);
+        }
+        res.push(stack_item.index, witness_map);
+    }
+    res
+}
+
 use add_plain_acvm_test;
 use add_rep3_acvm_test;
+use add_shamir_acvm_test;
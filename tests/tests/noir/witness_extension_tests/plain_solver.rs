@@ -1,5 +1,9 @@
-use acir::{native_types::WitnessStack, FieldElement};
-use co_acvm::solver::PlainCoSolver;
+use acir::{
+    circuit::{AssertionPayload, Opcode, OpcodeLocation},
+    native_types::{Expression, Witness, WitnessStack},
+    AcirField, FieldElement,
+};
+use co_acvm::solver::{CoAcvmError, PlainCoSolver};
 use noirc_artifacts::program::ProgramArtifact;
 
 use super::add_plain_acvm_test;
@@ -14,3 +18,49 @@ add_plain_acvm_test!("poseidon_stdlib");
 add_plain_acvm_test!("slice");
 add_plain_acvm_test!("random_access");
 add_plain_acvm_test!("write_access");
+
+/// Reads the addition_multiplication program and appends an assertion x == value with the given message.
+fn program_with_assertion(value: u128, message: &str) -> ProgramArtifact {
+    let program = std::fs::read_to_string(
+        "../test_vectors/noir/addition_multiplication/kat/addition_multiplication.json",
+    )
+    .unwrap();
+    let mut program_artifact = serde_json::from_str::<ProgramArtifact>(&program).unwrap();
+    let main = &mut program_artifact.bytecode.functions[0];
+    main.assert_messages.push((
+        OpcodeLocation::Acir(main.opcodes.len()),
+        AssertionPayload::StaticString(message.to_owned()),
+    ));
+    main.opcodes.push(Opcode::AssertZero(Expression {
+        mul_terms: vec![],
+        linear_combinations: vec![(FieldElement::one(), Witness(0))],
+        q_c: -FieldElement::from(value),
+    }));
+    program_artifact
+}
+
+#[test]
+fn test_plain_assertion_holds() {
+    let solver = PlainCoSolver::init_plain_driver(
+        program_with_assertion(1, "x must be one"),
+        "../test_vectors/noir/addition_multiplication/Prover.toml",
+    )
+    .unwrap();
+    assert!(solver.solve().is_ok());
+}
+
+#[test]
+fn test_plain_failing_assertion() {
+    let solver = PlainCoSolver::init_plain_driver(
+        program_with_assertion(2, "x must be two"),
+        "../test_vectors/noir/addition_multiplication/Prover.toml",
+    )
+    .unwrap();
+    let Err(CoAcvmError::UnsatisfiedConstraint(err)) = solver.solve() else {
+        panic!("the assertion must fail");
+    };
+    assert_eq!(err.function_index, 0);
+    assert_eq!(err.assert_message.as_deref(), Some("x must be two"));
+    assert_eq!(err.value, Some((-ark_bn254::Fr::from(1u64)).to_string()));
+    assert!(err.to_string().contains("x must be two"));
+}
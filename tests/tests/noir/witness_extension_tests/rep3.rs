@@ -1,9 +1,17 @@
 use std::thread;
 
-use acir::{native_types::WitnessStack, FieldElement};
-use co_acvm::solver::PlainCoSolver;
-use co_acvm::solver::Rep3CoSolver;
+use acir::{
+    native_types::{Witness, WitnessStack},
+    FieldElement,
+};
+use ark_ff::Zero;
+use co_acvm::{
+    mpc::NoirWitnessExtensionProtocol,
+    solver::{differential, PlainCoSolver, Rep3CoSolver},
+    Rep3AcvmSolver, Rep3AcvmType,
+};
 use itertools::izip;
+use mpc_core::protocols::rep3;
 use noirc_artifacts::program::ProgramArtifact;
 use tests::rep3_network::Rep3TestNetwork;
 
@@ -19,3 +27,107 @@ add_rep3_acvm_test!("poseidon_stdlib");
 add_rep3_acvm_test!("slice");
 add_rep3_acvm_test!("random_access");
 add_rep3_acvm_test!("write_access");
+
+/// The shared integer division and radix decompositions used by the brillig hints.
+#[test]
+fn test_rep3_brillig_hints_on_shared_values() {
+    type F = ark_bn254::Fr;
+    let mut rng = rand::thread_rng();
+    let x_shares = rep3::share_field_element(F::from(1234u64), &mut rng);
+    let y_shares = rep3::share_field_element(F::from(7u64), &mut rng);
+
+    let test_network = Rep3TestNetwork::default();
+    let mut threads = Vec::with_capacity(3);
+    for (net, x, y) in izip!(test_network.get_party_networks(), x_shares, y_shares) {
+        threads.push(thread::spawn(move || {
+            let mut driver = Rep3AcvmSolver::<F, _>::new(net).unwrap();
+            let shared_div = driver.int_div(x.into(), y.into(), 32).unwrap();
+            let public_div = driver.int_div(x.into(), F::from(7u64).into(), 32).unwrap();
+            let bits = driver.decompose(x.into(), 16, 1).unwrap();
+            let digits = driver
+                .slice_variable_bases(x.into(), 254, &[10; 4])
+                .unwrap();
+            assert!(driver.int_div(x.into(), F::zero().into(), 32).is_err());
+            [shared_div, public_div]
+                .into_iter()
+                .chain(bits)
+                .chain(digits)
+                .map(|value| match value {
+                    Rep3AcvmType::Shared(share) => share,
+                    Rep3AcvmType::Public(_) => panic!("expected a shared value"),
+                })
+                .collect::<Vec<_>>()
+        }));
+    }
+    let [result1, result2, result3]: [Vec<_>; 3] = threads
+        .into_iter()
+        .map(|t| t.join().unwrap())
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
+    let result = izip!(result1, result2, result3)
+        .map(|(a, b, c)| rep3::combine_field_element(a, b, c))
+        .collect::<Vec<_>>();
+
+    let expected = [176u64, 176]
+        .into_iter()
+        .chain((0..16).map(|i| (1234u64 >> i) & 1))
+        .chain([4, 3, 2, 1])
+        .map(F::from)
+        .collect::<Vec<_>>();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_rep3_differential_matches_plain() {
+    let mut rng = rand::thread_rng();
+    for name in [
+        "addition_multiplication",
+        "poseidon",
+        "random_access",
+        "write_access",
+    ] {
+        let program =
+            std::fs::read_to_string(format!("../test_vectors/noir/{name}/kat/{name}.json"))
+                .unwrap();
+        let program_artifact = serde_json::from_str::<ProgramArtifact>(&program).unwrap();
+        let divergence = differential::compare_rep3_with_plain(
+            &program_artifact,
+            format!("../test_vectors/noir/{name}/Prover.toml"),
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(divergence, None, "{name}");
+    }
+}
+
+#[test]
+fn test_rep3_differential_reports_first_divergence() {
+    let solve = || {
+        let program = std::fs::read_to_string(
+            "../test_vectors/noir/addition_multiplication/kat/addition_multiplication.json",
+        )
+        .unwrap();
+        let program_artifact = serde_json::from_str::<ProgramArtifact>(&program).unwrap();
+        PlainCoSolver::init_plain_driver(
+            program_artifact,
+            "../test_vectors/noir/addition_multiplication/Prover.toml",
+        )
+        .unwrap()
+        .solve()
+        .unwrap()
+    };
+    let plain = solve();
+    let mut tampered = solve();
+    let mut main = tampered.pop().unwrap();
+    main.witness.insert(Witness(2), ark_bn254::Fr::from(42u64));
+    tampered.push(main.index, main.witness);
+
+    let divergence = differential::first_divergence(plain, tampered)
+        .unwrap()
+        .unwrap();
+    assert_eq!(divergence.function_index, 0);
+    assert_eq!(divergence.witness, Witness(2));
+    assert_eq!(divergence.mpc, Some(ark_bn254::Fr::from(42u64)));
+    assert_ne!(divergence.plain, divergence.mpc);
+}
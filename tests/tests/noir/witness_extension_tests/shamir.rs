@@ -0,0 +1,19 @@
+use std::thread;
+
+use acir::{native_types::WitnessStack, FieldElement};
+use co_acvm::solver::PlainCoSolver;
+use co_acvm::solver::ShamirCoSolver;
+use noirc_artifacts::program::ProgramArtifact;
+use tests::shamir_network::ShamirTestNetwork;
+
+use super::add_shamir_acvm_test;
+
+// basic assert zero tests
+add_shamir_acvm_test!("addition_multiplication", 3, 1);
+
+// memory tests
+add_shamir_acvm_test!("slice", 3, 1);
+add_shamir_acvm_test!("random_access", 3, 1);
+add_shamir_acvm_test!("random_access", 5, 2);
+add_shamir_acvm_test!("write_access", 3, 1);
+add_shamir_acvm_test!("write_access", 5, 2);